@@ -1,5 +1,6 @@
 pub mod dormandprince;
 pub mod ode;
 pub mod ode_problems;
+pub mod work_precision;
 
 pub use ode::ode;
\ No newline at end of file
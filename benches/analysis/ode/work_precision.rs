@@ -0,0 +1,86 @@
+use criterion::Criterion;
+
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::ordinary::{
+        problem::{Arenstorf, Pleiades},
+        solver::{
+            runge_kutta::{AdaptiveStepper, BogackiShampine32, DormandPrince54, ExplicitRKEmbeddedMethod},
+            Solution,
+        },
+        ExplicitODE,
+    },
+};
+
+criterion_group!(work_precision, arenstorf, pleiades);
+
+/// Returns the number of right hand side evaluations and the maximum absolute
+/// deviation of the solution at the end of the time span from the reference
+/// solution
+fn work_and_error<F, M>(solver: &AdaptiveStepper<f64>,
+                        problem: &F,
+                        method: &M,
+                        reference: &Vector<f64>)
+                        -> (usize, f64)
+    where F: ExplicitODE<f64>,
+          M: ExplicitRKEmbeddedMethod<f64>
+{
+    let solution: Solution<f64> = solver.solve(problem, method);
+    assert!(solution.is_success());
+
+    let error: f64 = solution.x()
+                             .last()
+                             .unwrap()
+                             .iter()
+                             .zip(reference.iter())
+                             .fold(0.0, |e, (x_i, r_i)| e.max((x_i - r_i).abs()));
+
+    (solution.statistics().rhs_evaluations(), error)
+}
+
+/// Solves the reference problems with decreasing tolerances, the name of each
+/// benchmark contains the number of right hand side evaluations and the error
+/// of the solution, so that the run times can be compared against the
+/// achieved precision
+fn arenstorf(bench: &mut Criterion)
+{
+    let problem: Arenstorf<f64> = Arenstorf::default();
+    let reference: Vector<f64> = problem.reference_solution();
+
+    for tol in [1.0e-4, 1.0e-6, 1.0e-8]
+    {
+        let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(100000, 0.001, tol, tol);
+
+        let (evaluations, error): (usize, f64) =
+            work_and_error(&solver, &problem, &DormandPrince54::default(), &reference);
+        let name: String = format!("Arenstorf DormandPrince54 tol={:e} rhs={} error={:.2e}", tol, evaluations, error);
+        bench.bench_function(&name, |bh| {
+                 bh.iter(|| solver.solve(&problem, &DormandPrince54::default()))
+             });
+
+        let (evaluations, error): (usize, f64) =
+            work_and_error(&solver, &problem, &BogackiShampine32::default(), &reference);
+        let name: String = format!("Arenstorf BogackiShampine32 tol={:e} rhs={} error={:.2e}", tol, evaluations, error);
+        bench.bench_function(&name, |bh| {
+                 bh.iter(|| solver.solve(&problem, &BogackiShampine32::default()))
+             });
+    }
+}
+
+fn pleiades(bench: &mut Criterion)
+{
+    let problem: Pleiades<f64> = Pleiades::default();
+    let reference: Vector<f64> = problem.reference_solution();
+
+    for tol in [1.0e-4, 1.0e-6, 1.0e-8]
+    {
+        let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(100000, 0.001, tol, tol);
+
+        let (evaluations, error): (usize, f64) =
+            work_and_error(&solver, &problem, &DormandPrince54::default(), &reference);
+        let name: String = format!("Pleiades DormandPrince54 tol={:e} rhs={} error={:.2e}", tol, evaluations, error);
+        bench.bench_function(&name, |bh| {
+                 bh.iter(|| solver.solve(&problem, &DormandPrince54::default()))
+             });
+    }
+}
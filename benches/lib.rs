@@ -25,4 +25,5 @@ criterion_main!(
     analysis::ode::dormandprince::dormandprince,
     algebra::linear::matrix::matrix,
     analysis::ode::ode,
+    analysis::ode::work_precision::work_precision,
 );
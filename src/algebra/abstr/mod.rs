@@ -22,7 +22,7 @@ pub use self::{
     ring::{CommutativeRing, Ring},
     scalar::Scalar,
     sign::Sign,
-    polynomial::Polynomial,
+    polynomial::{PartialFraction, Polynomial, Rational},
 };
 #[cfg(feature = "lapack")]
 pub use self::scalar::{Blas, Lapack};
@@ -0,0 +1,142 @@
+//! Division with remainder and greatest common divisor
+use crate::algebra::abstr::{AbsDiffEq, Field, Real, Scalar, Zero};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+    where T: Field + Scalar + AbsDiffEq<Epsilon = T>
+{
+    /// Divides the polynomial by divisor with remainder
+    ///
+    /// Returns the quotient q and the remainder r with
+    /// ```math
+    /// p = qd + r, \quad \deg r < \deg d
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// (x^3 - 2x + 1) = (x - 2)(x^2 + 2x + 2) + 5
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, -2.0, 1.0]);
+    /// let d: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -2.0]);
+    ///
+    /// let (q, r): (Polynomial<f64>, Polynomial<f64>) = p.div_rem(&d);
+    ///
+    /// assert_eq!(Polynomial::from_coef(vec![1.0, 2.0, 2.0]), q);
+    /// assert_eq!(Polynomial::from_coef(vec![5.0]), r);
+    /// ```
+    pub fn div_rem(&self, divisor: &Polynomial<T>) -> (Polynomial<T>, Polynomial<T>)
+    {
+        let mut d: Vec<T> = divisor.coef.clone();
+        while d.len() > 1 && d[d.len() - 1] == T::zero()
+        {
+            d.pop();
+        }
+        let n: usize = d.len() - 1;
+        if d[n] == T::zero()
+        {
+            panic!()
+        }
+
+        if n > self.degree()
+        {
+            return (Polynomial::zero(), self.clone())
+        }
+
+        let mut remainder: Vec<T> = self.coef.clone();
+        let quotient_degree: usize = self.degree() - n;
+        let mut quotient = vec![T::zero(); quotient_degree + 1];
+
+        for i in (0..(quotient_degree + 1)).rev()
+        {
+            let q: T = remainder[n + i] / d[n];
+
+            quotient[i] = q;
+
+            for (k, v_k) in d.iter().enumerate()
+            {
+                remainder[k + i] -= *v_k * q;
+            }
+        }
+        remainder.truncate(n);
+        if remainder.is_empty()
+        {
+            remainder.push(T::zero());
+        }
+
+        (Polynomial{coef: quotient}, Polynomial{coef: Polynomial::reduce_coef(remainder)})
+    }
+}
+
+impl<T> Polynomial<T>
+    where T: Real
+{
+    /// Computes the monic greatest common divisor with the Euclidean
+    /// algorithm
+    ///
+    /// Remainders, whose coefficients vanish relative to the dividend up to
+    /// rounding errors, are treated as zero. The greatest common divisor of
+    /// two zero polynomials is zero.
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// \gcd((x - 1)(x - 2)(x + 3), (x - 1)(x + 3)(x + 4)) = x^2 + 2x - 3
+    /// ```
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let a: Polynomial<f64> = Polynomial::from_root(vec![1.0, 2.0, -3.0]);
+    /// let b: Polynomial<f64> = Polynomial::from_root(vec![1.0, -3.0, -4.0]);
+    ///
+    /// assert_relative_eq!(Polynomial::from_coef(vec![1.0, 2.0, -3.0]), a.gcd(&b), epsilon = 1.0e-14);
+    /// # }
+    /// ```
+    pub fn gcd(&self, other: &Polynomial<T>) -> Polynomial<T>
+    {
+        let mut a: Polynomial<T> = self.trimmed().normalized();
+        let mut b: Polynomial<T> = other.trimmed().normalized();
+        if a.degree() < b.degree()
+        {
+            std::mem::swap(&mut a, &mut b);
+        }
+
+        while !b.is_zero()
+        {
+            if b.degree() == 0
+            {
+                return Polynomial { coef: vec![T::one()] };
+            }
+            let r: Polynomial<T> = a.remainder(&b);
+            a = b;
+            b = r.normalized();
+        }
+
+        if a.is_zero()
+        {
+            return a;
+        }
+        let leading: T = a.coef[a.degree()];
+        Polynomial { coef: a.coef.into_iter().map(|a_i| a_i / leading).collect() }
+    }
+
+    /// Returns true if all coefficients are zero
+    pub(super) fn is_zero(&self) -> bool
+    {
+        self.coef.iter().all(|a_i| *a_i == T::zero())
+    }
+}
@@ -0,0 +1,110 @@
+//! Composition and evaluation at complex numbers and matrices
+use crate::algebra::{
+    abstr::{Complex, Field, Real, Scalar},
+    linear::Matrix,
+};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+    where T: Field + Scalar
+{
+    /// Composes two polynomials
+    ///
+    /// Computes $p(q(x))$ with Horner's rule.
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = x^2 + 1, \quad q(x) = 2x - 1, \quad p(q(x)) = 4x^2 - 4x + 2
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+    /// let q: Polynomial<f64> = Polynomial::from_coef(vec![2.0, -1.0]);
+    ///
+    /// assert_eq!(Polynomial::from_coef(vec![4.0, -4.0, 2.0]), p.compose(&q));
+    /// ```
+    pub fn compose(&self, q: &Polynomial<T>) -> Polynomial<T>
+    {
+        let mut coef = self.coef.iter().rev();
+        let mut composition: Polynomial<T> = Polynomial { coef: vec![*coef.next().unwrap()] };
+        for a_i in coef
+        {
+            composition = &(&composition * q) + &Polynomial { coef: vec![*a_i] };
+        }
+        composition
+    }
+
+    /// Evaluates the polynomial at a square matrix with Horner's rule
+    ///
+    /// ```math
+    /// p(A) = a_0I + a_1A + \cdots + a_nA^n
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the matrix is not square
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::{abstr::Polynomial, linear::Matrix};
+    ///
+    /// // p is the characteristic polynomial of A
+    /// let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 3.0, 2.0, 4.0]);
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -5.0, -2.0]);
+    ///
+    /// assert_eq!(Matrix::zero(2, 2), p.eval_matrix(&a));
+    /// ```
+    pub fn eval_matrix(&self, a: &Matrix<T>) -> Matrix<T>
+    {
+        let (m, n): (usize, usize) = a.dim();
+        if m != n
+        {
+            panic!()
+        }
+
+        let mut s: Matrix<T> = Matrix::zero(n, n);
+        for v in self.coef.iter().rev()
+        {
+            s = &s * a;
+            for i in 0..n
+            {
+                s[[i, i]] += *v;
+            }
+        }
+        s
+    }
+}
+
+impl<T> Polynomial<T>
+    where T: Real
+{
+    /// Evaluates the polynomial at a complex number with Horner's rule
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = x^2 + 1, \quad p(i) = 0
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Polynomial};
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+    ///
+    /// assert_eq!(Complex::new(0.0, 0.0), p.eval_complex(Complex::new(0.0, 1.0)));
+    /// ```
+    pub fn eval_complex(&self, z: Complex<T>) -> Complex<T>
+    {
+        let mut s: Complex<T> = Complex::new(T::zero(), T::zero());
+        for v in self.coef.iter().rev()
+        {
+            s = s * z + Complex::new(*v, T::zero());
+        }
+        s
+    }
+}
@@ -0,0 +1,111 @@
+//! Least squares polynomial fitting
+use crate::algebra::abstr::Real;
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+    where T: Real
+{
+    /// Least squares fit of a polynomial with the given degree to the data
+    /// $(x_i, y_i)$
+    /// ```math
+    /// \min_{p}\sum_{i}\left(y_i - p(x_i)\right)^2
+    /// ```
+    /// The points are mapped to $[-1, 1]$ and the Vandermonde system is
+    /// solved with a Householder QR decomposition, which avoids the squared
+    /// condition number of the normal equations. The polynomial is
+    /// transformed back by composition with the affine map.
+    ///
+    /// # Panics
+    ///
+    /// If the number of points and values differ or there are not more
+    /// distinct points than the degree
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let x: Vec<f64> = vec![0.0, 1.0, 2.0, 3.0, 4.0];
+    /// let y: Vec<f64> = x.iter().map(|x_i| 2.0 * x_i * x_i - x_i + 3.0).collect();
+    ///
+    /// let p: Polynomial<f64> = Polynomial::fit(&x, &y, 2);
+    ///
+    /// assert_relative_eq!(Polynomial::from_coef(vec![2.0, -1.0, 3.0]), p, epsilon = 1.0e-12);
+    /// # }
+    /// ```
+    pub fn fit(x: &[T], y: &[T], degree: usize) -> Polynomial<T>
+    {
+        if x.len() != y.len()
+        {
+            panic!("The number of points and values differ");
+        }
+        let m: usize = x.len();
+        let n: usize = degree + 1;
+        if m < n
+        {
+            panic!("The number of points is not greater than the degree");
+        }
+
+        // affine map of [min, max] to [-1, 1]
+        let min: T = x.iter().fold(T::infinity(), |min, x_i| min.min(*x_i));
+        let max: T = x.iter().fold(T::neg_infinity(), |max, x_i| max.max(*x_i));
+        let center: T = (max + min) / T::from_f64(2.0);
+        let half_width: T = if max > min { (max - min) / T::from_f64(2.0) } else { T::one() };
+
+        // Vandermonde matrix, column major
+        let mut a: Vec<Vec<T>> = Vec::with_capacity(n);
+        a.push(vec![T::one(); m]);
+        for j in 1..n
+        {
+            let column: Vec<T> = a[j - 1].iter()
+                                         .zip(x.iter())
+                                         .map(|(a_ij, x_i)| *a_ij * (*x_i - center) / half_width)
+                                         .collect();
+            a.push(column);
+        }
+        let mut b: Vec<T> = y.to_vec();
+
+        // Householder QR, b is overwritten with Q^T y
+        let scale: T = T::from_f64(m as f64).sqrt();
+        for k in 0..n
+        {
+            let norm: T = a[k][k..].iter().fold(T::zero(), |s, a_ik| s + *a_ik * *a_ik).sqrt();
+            if norm <= T::from_f64(1.0e2) * T::default_epsilon() * scale
+            {
+                panic!("The number of distinct points is not greater than the degree");
+            }
+            let alpha: T = if a[k][k] > T::zero() { -norm } else { norm };
+
+            let mut v: Vec<T> = a[k][k..].to_vec();
+            v[0] -= alpha;
+            let v_norm_sqr: T = v.iter().fold(T::zero(), |s, v_i| s + *v_i * *v_i);
+
+            for column in a.iter_mut().skip(k).chain(std::iter::once(&mut b))
+            {
+                let dot: T = v.iter().zip(column[k..].iter()).fold(T::zero(), |s, (v_i, c_i)| s + *v_i * *c_i);
+                let factor: T = T::from_f64(2.0) * dot / v_norm_sqr;
+                for (c_i, v_i) in column[k..].iter_mut().zip(v.iter())
+                {
+                    *c_i -= factor * *v_i;
+                }
+            }
+        }
+
+        // back substitution R c = (Q^T y)[0..n]
+        let mut c: Vec<T> = vec![T::zero(); n];
+        for i in (0..n).rev()
+        {
+            let s: T = ((i + 1)..n).fold(b[i], |s, j| s - a[j][i] * c[j]);
+            c[i] = s / a[i][i];
+        }
+
+        let q: Polynomial<T> = Polynomial { coef: c };
+        let affine: Polynomial<T> = Polynomial { coef: vec![-center / half_width, T::one() / half_width] };
+        q.compose(&affine)
+    }
+}
@@ -13,6 +13,16 @@ use crate::algebra::abstr::operator::{Addition, Multiplication};
 use crate::algebra::abstr::group::{Group, GroupAdd};
 use crate::algebra::abstr::loop_::{Loop};
 
+mod euclid;
+mod eval;
+mod fit;
+mod orthogonal;
+mod rational;
+mod root;
+mod sturm;
+
+pub use self::rational::{PartialFraction, Rational};
+
 
 /// Polynomial expression
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -673,28 +683,7 @@ impl<'a, 'b, T> Div<&'b Polynomial<T>> for &'a Polynomial<T>
     /// ```
     fn div(self, rhs: &'b Polynomial<T>) -> Self::Output
     {
-        if rhs.degree() > self.degree()
-        {
-            return (Polynomial::zero(), self.clone())
-        }
-
-        let mut remainder: Vec<T> = self.coef.clone();
-        let quotient_degree: usize = self.degree() - rhs.degree();
-        let mut quotient = vec![T::zero(); quotient_degree + 1];
-
-        for i in (0..(quotient_degree + 1)).rev()
-        {
-            let q: T = remainder[rhs.degree()  + i] / rhs.coef[rhs.degree()];
-
-            quotient[i] = q;
-
-            for (k, v_k) in rhs.coef.iter().enumerate()
-            {
-                remainder[k + i] -= *v_k * q;
-            }
-        }
-
-        (Polynomial{coef: quotient}, Polynomial{coef: Polynomial::reduce_coef(remainder)})
+        self.div_rem(rhs)
     }
 }
 
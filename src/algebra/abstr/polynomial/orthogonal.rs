@@ -0,0 +1,244 @@
+//! Classical orthogonal polynomials
+use crate::algebra::abstr::{AbsDiffEq, Field, Scalar};
+
+use super::Polynomial;
+
+impl<T> Polynomial<T>
+    where T: Field + Scalar + AbsDiffEq<Epsilon = T>
+{
+    /// Creates a physicists' Hermite polynomial with the given degree
+    ///
+    /// ```math
+    /// H_0 = 1, \quad H_1 = 2x, \quad H_{n + 1} = 2xH_n - 2nH_{n - 1}
+    /// ```
+    ///
+    /// <https://en.wikipedia.org/wiki/Hermite_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Hermite polynomial
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p = Polynomial::from_hermite_h(3);
+    /// let p_ref = Polynomial::from_coef(vec![8.0, 0.0, -12.0, 0.0]);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_hermite_h(n: u32) -> Polynomial<T>
+    {
+        let p_1: Polynomial<T> = Polynomial { coef: vec![T::zero(), T::from_f64(2.0)] };
+        Polynomial::from_recurrence(n, p_1, |k| (T::zero(), T::from_f64(2.0), T::from_f64(2.0 * k)))
+    }
+
+    /// Creates a probabilists' Hermite polynomial with the given degree
+    ///
+    /// ```math
+    /// He_0 = 1, \quad He_1 = x, \quad He_{n + 1} = xHe_n - nHe_{n - 1}
+    /// ```
+    ///
+    /// <https://en.wikipedia.org/wiki/Hermite_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Hermite polynomial
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p = Polynomial::from_hermite_he(3);
+    /// let p_ref = Polynomial::from_coef(vec![1.0, 0.0, -3.0, 0.0]);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_hermite_he(n: u32) -> Polynomial<T>
+    {
+        let p_1: Polynomial<T> = Polynomial { coef: vec![T::zero(), T::one()] };
+        Polynomial::from_recurrence(n, p_1, |k| (T::zero(), T::one(), T::from_f64(k)))
+    }
+
+    /// Creates a Laguerre polynomial with the given degree
+    ///
+    /// <https://en.wikipedia.org/wiki/Laguerre_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Laguerre polynomial
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p = Polynomial::from_laguerre(2);
+    /// let p_ref = Polynomial::from_coef(vec![0.5, -2.0, 1.0]);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_laguerre(n: u32) -> Polynomial<T>
+    {
+        Polynomial::from_generalized_laguerre(n, T::zero())
+    }
+
+    /// Creates a generalized Laguerre polynomial with the given degree
+    ///
+    /// ```math
+    /// L_0^{(\alpha)} = 1, \quad L_1^{(\alpha)} = 1 + \alpha - x, \quad
+    /// L_{n + 1}^{(\alpha)} = \frac{(2n + 1 + \alpha - x)L_n^{(\alpha)} - (n + \alpha)L_{n - 1}^{(\alpha)}}{n + 1}
+    /// ```
+    ///
+    /// <https://en.wikipedia.org/wiki/Laguerre_polynomials#Generalized_Laguerre_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Laguerre polynomial
+    /// * `alpha`: Parameter $\alpha > -1$
+    ///
+    /// # Panics
+    ///
+    /// If $\alpha \leq -1$
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p = Polynomial::from_generalized_laguerre(2, 1.0);
+    /// let p_ref = Polynomial::from_coef(vec![0.5, -3.0, 3.0]);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_generalized_laguerre(n: u32, alpha: T) -> Polynomial<T>
+    {
+        if alpha <= -T::one()
+        {
+            panic!("The parameter alpha is not greater than -1");
+        }
+
+        let p_1: Polynomial<T> = Polynomial { coef: vec![T::one() + alpha, -T::one()] };
+        Polynomial::from_recurrence(n, p_1, |k| {
+            let k_1: T = T::from_f64(k + 1.0);
+            ((T::from_f64(2.0 * k + 1.0) + alpha) / k_1, -T::one() / k_1, (T::from_f64(k) + alpha) / k_1)
+        })
+    }
+
+    /// Creates a Gegenbauer polynomial with the given degree
+    ///
+    /// ```math
+    /// C_0^{(\lambda)} = 1, \quad C_1^{(\lambda)} = 2\lambda x, \quad
+    /// C_{n + 1}^{(\lambda)} = \frac{2(n + \lambda)xC_n^{(\lambda)} - (n + 2\lambda - 1)C_{n - 1}^{(\lambda)}}{n + 1}
+    /// ```
+    ///
+    /// <https://en.wikipedia.org/wiki/Gegenbauer_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Gegenbauer polynomial
+    /// * `lambda`: Parameter $\lambda > -1/2, \lambda \neq 0$
+    ///
+    /// # Panics
+    ///
+    /// If $\lambda \leq -1/2$ or $\lambda = 0$
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// // C_n^(1/2) is the Legendre polynomial
+    /// let p = Polynomial::from_gegenbauer(2, 0.5);
+    /// let p_ref = Polynomial::from_legendre(2);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_gegenbauer(n: u32, lambda: T) -> Polynomial<T>
+    {
+        if lambda <= T::from_f64(-0.5) || lambda == T::zero()
+        {
+            panic!("The parameter lambda is not greater than -1/2 or zero");
+        }
+
+        let p_1: Polynomial<T> = Polynomial { coef: vec![T::zero(), T::from_f64(2.0) * lambda] };
+        Polynomial::from_recurrence(n, p_1, |k| {
+            let k_1: T = T::from_f64(k + 1.0);
+            (T::zero(),
+             T::from_f64(2.0) * (T::from_f64(k) + lambda) / k_1,
+             (T::from_f64(k - 1.0) + T::from_f64(2.0) * lambda) / k_1)
+        })
+    }
+
+    /// Creates a Jacobi polynomial with the given degree
+    ///
+    /// ```math
+    /// P_0^{(\alpha, \beta)} = 1, \quad P_1^{(\alpha, \beta)} = (\alpha + 1) + (\alpha + \beta + 2)\frac{x - 1}{2}
+    /// ```
+    /// The higher degrees follow from the three-term recurrence.
+    ///
+    /// <https://en.wikipedia.org/wiki/Jacobi_polynomials>
+    ///
+    /// # Arguments
+    /// * `n`: Degree of the Jacobi polynomial
+    /// * `alpha`: Parameter $\alpha > -1$
+    /// * `beta`: Parameter $\beta > -1$
+    ///
+    /// # Panics
+    ///
+    /// If $\alpha \leq -1$ or $\beta \leq -1$
+    ///
+    /// # Example
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// // P_n^(0, 0) is the Legendre polynomial
+    /// let p = Polynomial::from_jacobi(3, 0.0, 0.0);
+    /// let p_ref = Polynomial::from_legendre(3);
+    ///
+    /// assert_eq!(p_ref, p)
+    /// ```
+    pub fn from_jacobi(n: u32, alpha: T, beta: T) -> Polynomial<T>
+    {
+        if alpha <= -T::one() || beta <= -T::one()
+        {
+            panic!("The parameter alpha or beta is not greater than -1");
+        }
+
+        let two: T = T::from_f64(2.0);
+        let p_1: Polynomial<T> = Polynomial { coef: vec![(alpha - beta) / two, (alpha + beta + two) / two] };
+        Polynomial::from_recurrence(n, p_1, |k| {
+            let s: T = T::from_f64(2.0 * k) + alpha + beta;
+            let d: T = two * T::from_f64(k + 1.0) * (T::from_f64(k + 1.0) + alpha + beta) * s;
+            ((s + T::one()) * (alpha * alpha - beta * beta) / d,
+             (s + T::one()) * (s + two) * s / d,
+             two * (T::from_f64(k) + alpha) * (T::from_f64(k) + beta) * (s + two) / d)
+        })
+    }
+
+    /// Evaluates the three-term recurrence
+    /// ```math
+    /// p_0 = 1, \quad p_{k + 1} = (a_k + b_kx)p_k - c_kp_{k - 1}
+    /// ```
+    /// with the coefficients $(a_k, b_k, c_k)$ of the closure for k >= 1
+    fn from_recurrence<F>(n: u32, p_1: Polynomial<T>, coefficients: F) -> Polynomial<T>
+        where F: Fn(f64) -> (T, T, T)
+    {
+        let mut p_k_1: Polynomial<T> = Polynomial { coef: vec![T::one()] };
+        if n == 0
+        {
+            return p_k_1;
+        }
+
+        let mut p_k: Polynomial<T> = p_1;
+        for k in 1..n
+        {
+            let (a, b, c): (T, T, T) = coefficients(k as f64);
+            let mut coef: Vec<T> = vec![T::zero(); k as usize + 2];
+            for (i, p_i) in p_k.coef.iter().enumerate()
+            {
+                coef[i] += a * *p_i;
+                coef[i + 1] += b * *p_i;
+            }
+            for (i, p_i) in p_k_1.coef.iter().enumerate()
+            {
+                coef[i] -= c * *p_i;
+            }
+            p_k_1 = p_k;
+            p_k = Polynomial { coef };
+        }
+        p_k
+    }
+}
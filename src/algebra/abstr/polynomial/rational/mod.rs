@@ -0,0 +1,369 @@
+//! Rational functions
+use crate::algebra::abstr::{AbsDiffEq, Complex, Real, RelativeEq};
+use std::{
+    fmt::{Display, Formatter, Result},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use super::Polynomial;
+
+mod pade;
+mod partial_fraction;
+
+pub use self::partial_fraction::PartialFraction;
+
+/// Rational function
+///
+/// Quotient of two polynomials
+/// ```math
+/// r(x) = \frac{p(x)}{q(x)}
+/// ```
+/// The arithmetic operations do not cancel common factors of the numerator
+/// and the denominator, see [`reduce`].
+///
+/// [`reduce`]: Rational::reduce
+///
+/// # Example
+///
+/// ```
+/// use mathru::algebra::abstr::{Polynomial, Rational};
+///
+/// // r(x) = 1 / (x + 1)
+/// let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, 1.0]));
+/// let s: Rational<f64> = &r * &r;
+///
+/// assert_eq!(0.25, s.eval(1.0));
+/// assert_eq!(Polynomial::from_coef(vec![1.0, 2.0, 1.0]), *s.denominator());
+/// ```
+#[derive(PartialEq, Clone, Debug)]
+pub struct Rational<T>
+{
+    num: Polynomial<T>,
+    den: Polynomial<T>,
+}
+
+impl<T> Rational<T>
+    where T: Real
+{
+    /// Creates a rational function from the numerator and the denominator
+    ///
+    /// Leading zero coefficients are removed.
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is zero
+    pub fn new(num: Polynomial<T>, den: Polynomial<T>) -> Rational<T>
+    {
+        if den.is_zero()
+        {
+            panic!("The denominator is zero");
+        }
+        Rational { num: num.trimmed(),
+                   den: den.trimmed() }
+    }
+
+    /// Creates a rational function with the denominator one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Polynomial, Rational};
+    ///
+    /// let r: Rational<f64> = Rational::from_polynomial(Polynomial::from_coef(vec![1.0, 2.0]));
+    ///
+    /// assert_eq!(5.0, r.eval(3.0));
+    /// ```
+    pub fn from_polynomial(p: Polynomial<T>) -> Rational<T>
+    {
+        Rational { num: p.trimmed(),
+                   den: Polynomial { coef: vec![T::one()] } }
+    }
+
+    /// Returns the numerator
+    pub fn numerator(&self) -> &Polynomial<T>
+    {
+        &self.num
+    }
+
+    /// Returns the denominator
+    pub fn denominator(&self) -> &Polynomial<T>
+    {
+        &self.den
+    }
+
+    /// Evaluates the rational function
+    ///
+    /// The result is infinite or NaN at poles.
+    pub fn eval(&self, x: T) -> T
+    {
+        self.num.eval(x) / self.den.eval(x)
+    }
+
+    /// Evaluates the rational function at a complex number
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// r(x) = \frac{1}{x^2 + 1}, \quad r(2i) = -\frac{1}{3}
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Polynomial, Rational};
+    ///
+    /// let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]),
+    ///                                      Polynomial::from_coef(vec![1.0, 0.0, 1.0]));
+    ///
+    /// assert_eq!(Complex::new(-1.0 / 3.0, 0.0), r.eval_complex(Complex::new(0.0, 2.0)));
+    /// ```
+    pub fn eval_complex(&self, z: Complex<T>) -> Complex<T>
+    {
+        self.num.eval_complex(z) / self.den.eval_complex(z)
+    }
+
+    /// Differentiates the rational function with the quotient rule
+    ///
+    /// ```math
+    /// \left(\frac{p}{q}\right)' = \frac{p'q - pq'}{q^2}
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Polynomial, Rational};
+    ///
+    /// // r(x) = x / (x + 1), r'(x) = 1 / (x + 1)^2
+    /// let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0]),
+    ///                                      Polynomial::from_coef(vec![1.0, 1.0]));
+    /// let dr: Rational<f64> = r.differentiate();
+    ///
+    /// assert_eq!(Polynomial::from_coef(vec![1.0]), *dr.numerator());
+    /// assert_eq!(Polynomial::from_coef(vec![1.0, 2.0, 1.0]), *dr.denominator());
+    /// ```
+    pub fn differentiate(&self) -> Rational<T>
+    {
+        let num: Polynomial<T> = &(&self.num.differentiate() * &self.den) - &(&self.num * &self.den.differentiate());
+        Rational::new(num, &self.den * &self.den)
+    }
+
+    /// Cancels the common factors of the numerator and the denominator
+    ///
+    /// The numerator and the denominator are divided by their greatest common
+    /// divisor, see [`Polynomial::gcd`], and scaled to a monic denominator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::{Polynomial, Rational};
+    ///
+    /// // r(x) = (x^2 - 1) / (2x^2 - 2x)
+    /// let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0, -1.0]),
+    ///                                      Polynomial::from_coef(vec![2.0, -2.0, 0.0]));
+    /// let reduced: Rational<f64> = r.reduce();
+    ///
+    /// assert_relative_eq!(Polynomial::from_coef(vec![0.5, 0.5]), *reduced.numerator(), epsilon = 1.0e-14);
+    /// assert_relative_eq!(Polynomial::from_coef(vec![1.0, 0.0]), *reduced.denominator(), epsilon = 1.0e-14);
+    /// # }
+    /// ```
+    pub fn reduce(&self) -> Rational<T>
+    {
+        let gcd: Polynomial<T> = self.num.gcd(&self.den);
+        let (num, den): (Polynomial<T>, Polynomial<T>) = if gcd.degree() > 0
+        {
+            (self.num.div_rem(&gcd).0.trimmed(), self.den.div_rem(&gcd).0.trimmed())
+        }
+        else
+        {
+            (self.num.clone(), self.den.clone())
+        };
+
+        let leading: T = den.coef[den.degree()];
+        Rational { num: Polynomial { coef: num.coef.into_iter().map(|a_i| a_i / leading).collect() },
+                   den: Polynomial { coef: den.coef.into_iter().map(|b_i| b_i / leading).collect() } }
+    }
+}
+
+impl<T> Display for Rational<T>
+    where T: Display + Real
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result
+    {
+        write!(f, "({}) / ({})", self.num, self.den)
+    }
+}
+
+impl<T> Add<Rational<T>> for Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    /// Adds two rational functions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Polynomial, Rational};
+    ///
+    /// // 1 / x + 1 / (x + 1) = (2x + 1) / (x^2 + x)
+    /// let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, 0.0]));
+    /// let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, 1.0]));
+    /// let c: Rational<f64> = a + b;
+    ///
+    /// assert_eq!(Polynomial::from_coef(vec![2.0, 1.0]), *c.numerator());
+    /// assert_eq!(Polynomial::from_coef(vec![1.0, 1.0, 0.0]), *c.denominator());
+    /// ```
+    fn add(self, rhs: Rational<T>) -> Self::Output
+    {
+        &self + &rhs
+    }
+}
+
+impl<T> Add<&Rational<T>> for &Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn add(self, rhs: &Rational<T>) -> Self::Output
+    {
+        if self.den == rhs.den
+        {
+            return Rational::new(&self.num + &rhs.num, self.den.clone());
+        }
+        Rational::new(&(&self.num * &rhs.den) + &(&rhs.num * &self.den), &self.den * &rhs.den)
+    }
+}
+
+impl<T> Sub<Rational<T>> for Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn sub(self, rhs: Rational<T>) -> Self::Output
+    {
+        &self - &rhs
+    }
+}
+
+impl<T> Sub<&Rational<T>> for &Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn sub(self, rhs: &Rational<T>) -> Self::Output
+    {
+        if self.den == rhs.den
+        {
+            return Rational::new(&self.num - &rhs.num, self.den.clone());
+        }
+        Rational::new(&(&self.num * &rhs.den) - &(&rhs.num * &self.den), &self.den * &rhs.den)
+    }
+}
+
+impl<T> Mul<Rational<T>> for Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: Rational<T>) -> Self::Output
+    {
+        &self * &rhs
+    }
+}
+
+impl<T> Mul<&Rational<T>> for &Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn mul(self, rhs: &Rational<T>) -> Self::Output
+    {
+        Rational::new(&self.num * &rhs.num, &self.den * &rhs.den)
+    }
+}
+
+impl<T> Div<Rational<T>> for Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    /// Divides two rational functions
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero
+    fn div(self, rhs: Rational<T>) -> Self::Output
+    {
+        &self / &rhs
+    }
+}
+
+impl<T> Div<&Rational<T>> for &Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn div(self, rhs: &Rational<T>) -> Self::Output
+    {
+        Rational::new(&self.num * &rhs.den, &self.den * &rhs.num)
+    }
+}
+
+impl<T> Neg for Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output
+    {
+        Rational { num: -self.num,
+                   den: self.den }
+    }
+}
+
+impl<T> Neg for &Rational<T>
+    where T: Real
+{
+    type Output = Rational<T>;
+
+    fn neg(self) -> Self::Output
+    {
+        Rational { num: -&self.num,
+                   den: self.den.clone() }
+    }
+}
+
+impl<T> AbsDiffEq for Rational<T>
+    where T: AbsDiffEq<Epsilon = T> + Clone
+{
+    type Epsilon = T;
+
+    fn default_epsilon() -> T
+    {
+        T::default_epsilon()
+    }
+
+    /// Compares the numerators and the denominators
+    fn abs_diff_eq(&self, other: &Rational<T>, epsilon: T) -> bool
+    {
+        self.num.abs_diff_eq(&other.num, epsilon.clone()) && self.den.abs_diff_eq(&other.den, epsilon)
+    }
+}
+
+impl<T> RelativeEq for Rational<T>
+    where T: RelativeEq<Epsilon = T> + Clone
+{
+    fn default_max_relative() -> T
+    {
+        T::default_epsilon()
+    }
+
+    /// Compares the numerators and the denominators
+    fn relative_eq(&self, other: &Rational<T>, epsilon: T, max_relative: T) -> bool
+    {
+        self.num.relative_eq(&other.num, epsilon.clone(), max_relative.clone())
+        && self.den.relative_eq(&other.den, epsilon, max_relative)
+    }
+}
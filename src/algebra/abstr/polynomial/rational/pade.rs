@@ -0,0 +1,136 @@
+//! Padé approximants
+use crate::algebra::{
+    abstr::Real,
+    linear::{matrix::Solve, Matrix, Vector},
+};
+
+use super::{super::Polynomial, Rational};
+
+impl<T> Rational<T>
+    where T: Real
+{
+    /// Creates the Padé approximant $[m/n]$ from the Taylor coefficients
+    /// $c_0, c_1, \dots$ of a function f at zero
+    ///
+    /// The approximant $p/q$ with $\deg p \leq m$, $\deg q \leq n$ and
+    /// $q(0) = 1$ agrees with the Taylor series of f up to the order m + n:
+    /// ```math
+    /// f(x)q(x) - p(x) = O(x^{m + n + 1})
+    /// ```
+    /// The denominator coefficients solve the linear system
+    /// $\sum_{j = 1}^{n} c_{m + k - j}q_j = -c_{m + k}$ for $k = 1, \dots, n$
+    /// with $c_i = 0$ for i < 0. If the system is singular, but consistent,
+    /// one of its solutions is chosen.
+    ///
+    /// Baker, G. A., Graves-Morris, P.: Padé Approximants, Cambridge
+    /// University Press, 1996
+    ///
+    /// # Arguments
+    ///
+    /// * 'taylor': Taylor coefficients $c_0, \dots, c_{m + n}$, further
+    ///   coefficients are ignored
+    /// * 'm': degree of the numerator
+    /// * 'n': degree of the denominator
+    ///
+    /// # Panics
+    ///
+    /// If there are less than m + n + 1 Taylor coefficients or the linear
+    /// system has no solution
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// e^x \approx \frac{1 + x/2 + x^2/12}{1 - x/2 + x^2/12}
+    /// ```
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::algebra::abstr::{Polynomial, Rational};
+    ///
+    /// let taylor: Vec<f64> = vec![1.0, 1.0, 1.0 / 2.0, 1.0 / 6.0, 1.0 / 24.0];
+    /// let r: Rational<f64> = Rational::from_pade(&taylor, 2, 2);
+    ///
+    /// assert_relative_eq!(Polynomial::from_coef(vec![1.0 / 12.0, 0.5, 1.0]), *r.numerator(), epsilon = 1.0e-14);
+    /// assert_relative_eq!(Polynomial::from_coef(vec![1.0 / 12.0, -0.5, 1.0]), *r.denominator(), epsilon = 1.0e-14);
+    /// assert!((r.eval(0.5) - 0.5f64.exp()).abs() < 1.0e-4);
+    /// # }
+    /// ```
+    pub fn from_pade(taylor: &[T], m: usize, n: usize) -> Rational<T>
+    {
+        if taylor.len() < m + n + 1
+        {
+            panic!("The number of Taylor coefficients is less than m + n + 1");
+        }
+        let c = |i: isize| -> T {
+            if i < 0
+            {
+                T::zero()
+            }
+            else
+            {
+                taylor[i as usize]
+            }
+        };
+
+        let mut q: Vec<T> = vec![T::one()];
+        if n > 0
+        {
+            let mut a: Matrix<T> = Matrix::zero(n, n);
+            let mut b: Vector<T> = Vector::zero(n);
+            for k in 1..(n + 1)
+            {
+                for j in 1..(n + 1)
+                {
+                    a[[k - 1, j - 1]] = c((m + k) as isize - j as isize);
+                }
+                b[k - 1] = -c((m + k) as isize);
+            }
+
+            let solution: Vector<T> = match a.solve(&b)
+            {
+                Ok(solution) if is_solution(&a, &b, &solution) => solution,
+                _ => panic!("The linear system of the denominator has no solution"),
+            };
+            q.extend((0..n).map(|j| solution[j]));
+        }
+
+        let p: Vec<T> = (0..(m + 1)).map(|i| {
+                                        (0..(std::cmp::min(i, n) + 1)).fold(T::zero(), |s, j| s + q[j] * taylor[i - j])
+                                    })
+                                    .collect();
+
+        Rational::new(Polynomial { coef: p }, Polynomial { coef: q })
+    }
+}
+
+/// Returns true if x solves $Ax = b$
+///
+/// The LU decomposition does not report singular matrices, so the residual
+/// of the linear system is checked.
+fn is_solution<T>(a: &Matrix<T>, b: &Vector<T>, x: &Vector<T>) -> bool
+    where T: Real
+{
+    if !x.iter().all(|x_i| x_i.abs() < T::infinity())
+    {
+        return false;
+    }
+
+    let (n, _): (usize, usize) = a.dim();
+    let mut defect: T = T::zero();
+    let mut scale: T = T::zero();
+    for i in 0..n
+    {
+        let mut s: T = -b[i];
+        for j in 0..n
+        {
+            s += a[[i, j]] * x[j];
+            scale = scale.max((a[[i, j]] * x[j]).abs());
+        }
+        defect = defect.max(s.abs());
+        scale = scale.max(b[i].abs());
+    }
+    defect <= T::default_epsilon().sqrt() * scale
+}
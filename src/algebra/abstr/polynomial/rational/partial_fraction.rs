@@ -0,0 +1,251 @@
+//! Partial fraction decomposition
+use crate::algebra::abstr::{Complex, Real};
+
+use super::{
+    super::{root::cmp_complex, Polynomial},
+    Rational,
+};
+
+/// Partial fraction decomposition of a rational function
+///
+/// ```math
+/// r(x) = s(x) + \sum_k \sum_{j = 1}^{m_k} \frac{c_{k, j}}{(x - p_k)^j}
+/// ```
+/// with the polynomial part s, the distinct poles $p_k$ with multiplicities
+/// $m_k$ and the coefficients $c_{k, j}$. The coefficient $c_{k, 1}$ is the
+/// residue of r at $p_k$.
+#[derive(Clone, Debug)]
+pub struct PartialFraction<T>
+{
+    polynomial: Polynomial<T>,
+    poles: Vec<Complex<T>>,
+    coefficients: Vec<Vec<Complex<T>>>,
+}
+
+impl<T> PartialFraction<T>
+    where T: Real
+{
+    /// Returns the polynomial part s
+    pub fn polynomial(&self) -> &Polynomial<T>
+    {
+        &self.polynomial
+    }
+
+    /// Returns the distinct poles $p_k$ sorted by their real and imaginary
+    /// parts
+    pub fn poles(&self) -> &Vec<Complex<T>>
+    {
+        &self.poles
+    }
+
+    /// Returns the coefficients $c_{k, 1}, \dots, c_{k, m_k}$ for every pole
+    ///
+    /// The number of coefficients of a pole is its multiplicity.
+    pub fn coefficients(&self) -> &Vec<Vec<Complex<T>>>
+    {
+        &self.coefficients
+    }
+
+    /// Returns the residues $c_{k, 1}$
+    pub fn residues(&self) -> Vec<Complex<T>>
+    {
+        self.coefficients.iter().map(|c_k| c_k[0]).collect()
+    }
+
+    /// Evaluates the decomposition at a complex number
+    pub fn eval_complex(&self, z: Complex<T>) -> Complex<T>
+    {
+        let mut s: Complex<T> = self.polynomial.eval_complex(z);
+        for (p_k, c_k) in self.poles.iter().zip(self.coefficients.iter())
+        {
+            let d: Complex<T> = Complex::new(T::one(), T::zero()) / (z - *p_k);
+            let mut d_j: Complex<T> = d;
+            for c_kj in c_k.iter()
+            {
+                s += *c_kj * d_j;
+                d_j *= d;
+            }
+        }
+        s
+    }
+}
+
+impl<T> Rational<T>
+    where T: Real
+{
+    /// Computes the partial fraction decomposition
+    ///
+    /// The rational function is reduced, see [`reduce`], and the polynomial
+    /// part is the quotient of the division of the numerator by the
+    /// denominator. The multiplicities of the poles follow from the
+    /// square-free factorization of the denominator with Yun's algorithm, and
+    /// the poles are the roots of the square-free factors, see
+    /// [`Polynomial::roots`]. The coefficients of a pole p with multiplicity
+    /// m are the first m Taylor coefficients of $(x - p)^mr(x)$ at p.
+    ///
+    /// [`reduce`]: Rational::reduce
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// \frac{x^3 + 1}{x(x - 1)^2} = 1 + \frac{1}{x} + \frac{1}{x - 1} + \frac{2}{(x - 1)^2}
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, PartialFraction, Polynomial, Rational};
+    ///
+    /// let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0, 0.0, 1.0]),
+    ///                                      Polynomial::from_root(vec![0.0, 1.0, 1.0]));
+    /// let decomposition: PartialFraction<f64> = r.partial_fraction();
+    ///
+    /// assert_eq!(Polynomial::from_coef(vec![1.0]), *decomposition.polynomial());
+    /// assert_eq!(2, decomposition.poles().len());
+    ///
+    /// let c: &Vec<Vec<Complex<f64>>> = decomposition.coefficients();
+    /// assert!((c[0][0].re - 1.0).abs() < 1.0e-10);
+    /// assert!((c[1][0].re - 1.0).abs() < 1.0e-10);
+    /// assert!((c[1][1].re - 2.0).abs() < 1.0e-10);
+    /// ```
+    pub fn partial_fraction(&self) -> PartialFraction<T>
+    {
+        let reduced: Rational<T> = self.reduce();
+        let (polynomial, remainder): (Polynomial<T>, Polynomial<T>) = reduced.num.div_rem(&reduced.den);
+
+        let mut poles: Vec<(Complex<T>, usize)> = Vec::new();
+        for (factor, m) in square_free(&reduced.den).into_iter()
+        {
+            poles.extend(factor.roots().into_iter().map(|p| (p, m)));
+        }
+        poles.sort_by(|(a, _), (b, _)| cmp_complex(a, b));
+
+        let coefficients: Vec<Vec<Complex<T>>> = poles.iter()
+                                                      .enumerate()
+                                                      .map(|(k, (p_k, m_k))| {
+                                                          let a: Vec<Complex<T>> = taylor(&remainder, *p_k, *m_k);
+
+                                                          // Taylor coefficients of prod_{l != k} (x - p_l)^m_l at p_k
+                                                          let mut b: Vec<Complex<T>> =
+                                                              vec![Complex::new(T::zero(), T::zero()); *m_k];
+                                                          b[0] = Complex::new(T::one(), T::zero());
+                                                          for (l, (p_l, m_l)) in poles.iter().enumerate()
+                                                          {
+                                                              if l == k
+                                                              {
+                                                                  continue;
+                                                              }
+                                                              for _ in 0..*m_l
+                                                              {
+                                                                  mul_linear(&mut b, *p_k - *p_l);
+                                                              }
+                                                          }
+
+                                                          let mut g: Vec<Complex<T>> = series_div(&a, &b);
+                                                          g.reverse();
+                                                          g
+                                                      })
+                                                      .collect();
+
+        PartialFraction { polynomial,
+                          poles: poles.into_iter().map(|(p, _)| p).collect(),
+                          coefficients }
+    }
+}
+
+/// Square-free factorization with Yun's algorithm
+///
+/// Returns the monic factors $a_i$ with degree > 0 and their multiplicities i
+/// of $p = c\prod_i a_i^i$.
+fn square_free<T>(p: &Polynomial<T>) -> Vec<(Polynomial<T>, usize)>
+    where T: Real
+{
+    let n: usize = p.degree();
+    let mut factors: Vec<(Polynomial<T>, usize)> = Vec::new();
+    if n == 0
+    {
+        return factors;
+    }
+
+    let dp: Polynomial<T> = p.differentiate();
+    let a_0: Polynomial<T> = p.gcd(&dp);
+    let mut b: Polynomial<T> = p.div_rem(&a_0).0.trimmed();
+    let mut c: Polynomial<T> = dp.div_rem(&a_0).0.trimmed();
+
+    for i in 1..(n + 1)
+    {
+        if b.degree() == 0
+        {
+            break;
+        }
+
+        let db: Polynomial<T> = b.differentiate();
+        let d: Polynomial<T> = cancelled(&c, &db);
+        let a: Polynomial<T> = b.gcd(&d);
+        if a.degree() > 0
+        {
+            factors.push((a.clone(), i));
+            b = b.div_rem(&a).0.trimmed();
+        }
+        c = d.div_rem(&a).0.trimmed();
+    }
+    factors
+}
+
+/// Difference c - d, which is zero, if it vanishes relative to c and d up to
+/// rounding errors
+fn cancelled<T>(c: &Polynomial<T>, d: &Polynomial<T>) -> Polynomial<T>
+    where T: Real
+{
+    let scale: T = c.coef.iter().chain(d.coef.iter()).fold(T::zero(), |m, a_i| m.max(a_i.abs()));
+    let difference: Polynomial<T> = (c - d).trimmed();
+    if difference.coef.iter().all(|a_i| a_i.abs() <= T::from_f64(1.0e3) * T::default_epsilon() * scale)
+    {
+        return Polynomial { coef: vec![T::zero()] };
+    }
+    difference
+}
+
+/// First m Taylor coefficients of p at z
+fn taylor<T>(p: &Polynomial<T>, z: Complex<T>, m: usize) -> Vec<Complex<T>>
+    where T: Real
+{
+    let mut a: Vec<Complex<T>> = p.coef.iter().map(|a_i| Complex::new(*a_i, T::zero())).collect();
+    let n: usize = a.len() - 1;
+    for i in 0..std::cmp::min(m, n)
+    {
+        for j in (i..n).rev()
+        {
+            let a_j: Complex<T> = a[j + 1] * z;
+            a[j] += a_j;
+        }
+    }
+    a.resize(m, Complex::new(T::zero(), T::zero()));
+    a
+}
+
+/// Multiplies the truncated power series b by h + w
+fn mul_linear<T>(b: &mut [Complex<T>], w: Complex<T>)
+    where T: Real
+{
+    for j in (0..b.len()).rev()
+    {
+        let shifted: Complex<T> = if j > 0 { b[j - 1] } else { Complex::new(T::zero(), T::zero()) };
+        b[j] = b[j] * w + shifted;
+    }
+}
+
+/// Quotient of the truncated power series a and b with $b_0 \neq 0$
+fn series_div<T>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>>
+    where T: Real
+{
+    let mut g: Vec<Complex<T>> = Vec::with_capacity(a.len());
+    for j in 0..a.len()
+    {
+        let mut s: Complex<T> = a[j];
+        for i in 1..(j + 1)
+        {
+            s -= b[i] * g[j - i];
+        }
+        g.push(s / b[0]);
+    }
+    g
+}
@@ -0,0 +1,324 @@
+//! Roots of polynomials
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::Matrix,
+};
+use std::cmp::Ordering;
+
+use super::Polynomial;
+
+/// Maximal number of Aberth-Ehrlich iterations
+const ABERTH_ITERS: usize = 500;
+
+/// Maximal number of Newton steps to polish a root
+const POLISH_ITERS: usize = 20;
+
+impl<T> Polynomial<T>
+    where T: Real
+{
+    /// Companion matrix
+    ///
+    /// The eigenvalues of the companion matrix of
+    /// $p(x) = a_0 + a_1x + \cdots + a_nx^n$
+    /// ```math
+    /// C = \begin{pmatrix} 0 & & & -a_0/a_n \\ 1 & 0 & & -a_1/a_n \\
+    /// & \ddots & \ddots & \vdots \\ & & 1 & -a_{n - 1}/a_n \end{pmatrix}
+    /// ```
+    /// are the roots of p.
+    ///
+    /// # Panics
+    ///
+    /// If the degree of the polynomial is zero or the leading coefficient is
+    /// zero
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = x^2 - 3x + 2
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::{abstr::Polynomial, linear::Matrix};
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -3.0, 2.0]);
+    ///
+    /// assert_eq!(Matrix::new(2, 2, vec![0.0, 1.0, -2.0, 3.0]), p.companion());
+    /// ```
+    pub fn companion(&self) -> Matrix<T>
+    {
+        let n: usize = self.degree();
+        let a_n: T = self.coef[n];
+        if n == 0 || a_n == T::zero()
+        {
+            panic!()
+        }
+
+        let mut c: Matrix<T> = Matrix::zero(n, n);
+        for i in 1..n
+        {
+            c[[i, i - 1]] = T::one();
+        }
+        for i in 0..n
+        {
+            c[[i, n - 1]] = -self.coef[i] / a_n;
+        }
+        c
+    }
+
+    /// Computes all complex roots of the polynomial
+    ///
+    /// The roots are computed simultaneously with the Aberth-Ehrlich
+    /// iteration
+    /// ```math
+    /// z_i \leftarrow z_i - \frac{p(z_i)/p'(z_i)}{1 - \frac{p(z_i)}{p'(z_i)}\sum_{j \neq i}\frac{1}{z_i - z_j}}
+    /// ```
+    /// which converges cubically to simple roots, and polished with Newton's
+    /// method. Multiple roots are returned according to their multiplicity,
+    /// but are only accurate to about $\epsilon^{1/m}$ for the multiplicity
+    /// m. The roots are sorted by their real and imaginary parts, roots with NaN
+    /// parts are sorted last.
+    ///
+    /// Bini, D. A.: Numerical Computation of Polynomial Zeros by Means of
+    /// Aberth's Method, Numerical Algorithms, 1996
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = x^3 - x^2 + x - 1 = (x - 1)(x - i)(x + i)
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Polynomial, Sign};
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -1.0, 1.0, -1.0]);
+    /// let roots: Vec<Complex<f64>> = p.roots();
+    ///
+    /// assert_eq!(3, roots.len());
+    /// assert!((roots[0] - Complex::new(0.0, -1.0)).abs().re < 1.0e-14);
+    /// assert!((roots[1] - Complex::new(0.0, 1.0)).abs().re < 1.0e-14);
+    /// assert!((roots[2] - Complex::new(1.0, 0.0)).abs().re < 1.0e-14);
+    /// ```
+    pub fn roots(&self) -> Vec<Complex<T>>
+    {
+        let mut coef: Vec<T> = self.trimmed().coef;
+
+        // roots at zero
+        let zeros: usize = std::cmp::min(coef.iter().take_while(|a_i| **a_i == T::zero()).count(), coef.len() - 1);
+        let mut roots: Vec<Complex<T>> = vec![Complex::new(T::zero(), T::zero()); zeros];
+        let deflated: Polynomial<T> = Polynomial { coef: coef.split_off(zeros) };
+
+        let n: usize = deflated.degree();
+        if n == 1
+        {
+            roots.push(Complex::new(-deflated.coef[0] / deflated.coef[1], T::zero()));
+        }
+        else if n > 1
+        {
+            roots.append(&mut deflated.aberth());
+        }
+
+        roots.sort_by(cmp_complex);
+        roots
+    }
+
+    /// Aberth-Ehrlich iteration for a polynomial with degree > 1 and
+    /// $a_0 \neq 0$
+    fn aberth(&self) -> Vec<Complex<T>>
+    {
+        let n: usize = self.degree();
+        let eps: T = T::default_epsilon();
+
+        // initial approximations on a circle with the geometric mean of the
+        // moduli of the roots as radius
+        let radius: T = (self.coef[0] / self.coef[n]).abs().pow(T::one() / T::from_f64(n as f64));
+        let mut z: Vec<Complex<T>> = (0..n).map(|k| {
+                                               let phi: T = T::from_f64(2.0) * T::pi() * T::from_f64(k as f64)
+                                                            / T::from_f64(n as f64)
+                                                            + T::from_f64(0.4);
+                                               Complex::new(radius * phi.cos(), radius * phi.sin())
+                                           })
+                                           .collect();
+
+        let mut converged: Vec<bool> = vec![false; n];
+        for _ in 0..ABERTH_ITERS
+        {
+            for i in 0..n
+            {
+                if converged[i]
+                {
+                    continue;
+                }
+
+                let (p, dp): (Complex<T>, Complex<T>) = self.eval_with_derivative(z[i]);
+                if modulus(p) == T::zero()
+                {
+                    converged[i] = true;
+                    continue;
+                }
+
+                let mut s: Complex<T> = Complex::new(T::zero(), T::zero());
+                for (j, z_j) in z.iter().enumerate()
+                {
+                    if j != i
+                    {
+                        s += Complex::new(T::one(), T::zero()) / (z[i] - *z_j);
+                    }
+                }
+
+                let denominator: Complex<T> = dp - p * s;
+                let w: Complex<T> = if modulus(denominator) == T::zero()
+                {
+                    // perturb the approximation
+                    Complex::new(eps.sqrt() * (T::one() + modulus(z[i])), T::zero())
+                }
+                else
+                {
+                    p / denominator
+                };
+                z[i] -= w;
+
+                if modulus(w) <= eps * modulus(z[i])
+                {
+                    converged[i] = true;
+                }
+            }
+
+            if converged.iter().all(|c| *c)
+            {
+                break;
+            }
+        }
+
+        let mut roots: Vec<Complex<T>> = Vec::with_capacity(n);
+        let mut upper: Vec<Complex<T>> = Vec::new();
+        let mut lower: Vec<Complex<T>> = Vec::new();
+        for z_i in z.into_iter().map(|z_i| self.polish_root(z_i))
+        {
+            if z_i.im.abs() <= T::from_f64(4.0) * eps * modulus(z_i)
+            {
+                roots.push(Complex::new(z_i.re, T::zero()));
+            }
+            else if z_i.im > T::zero()
+            {
+                upper.push(z_i);
+            }
+            else
+            {
+                lower.push(z_i);
+            }
+        }
+
+        // the non-real roots of a real polynomial are pairs of complex
+        // conjugates
+        if upper.len() == lower.len()
+        {
+            for u in upper.iter_mut()
+            {
+                let (k, _): (usize, T) = lower.iter()
+                                              .map(|l| modulus(*u - l.conj()))
+                                              .enumerate()
+                                              .fold((0, T::infinity()), |(k_min, d_min), (k, d)| {
+                                                  if d < d_min { (k, d) } else { (k_min, d_min) }
+                                              });
+                let l: Complex<T> = lower.swap_remove(k);
+                let half: T = T::from_f64(0.5);
+                *u = Complex::new(half * (u.re + l.re), half * (u.im - l.im));
+                roots.push(*u);
+                roots.push(u.conj());
+            }
+        }
+        else
+        {
+            roots.append(&mut upper);
+            roots.append(&mut lower);
+        }
+        roots
+    }
+
+    /// Polishes an approximation of a root with Newton's method
+    ///
+    /// Newton steps are taken as long as they reduce $\lvert p(z) \rvert$.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::{Complex, Polynomial};
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, -2.0]);
+    /// let root: Complex<f64> = p.polish_root(Complex::new(1.4, 0.1));
+    ///
+    /// assert!((root.re - 2.0f64.sqrt()).abs() < 1.0e-15);
+    /// assert!(root.im.abs() < 1.0e-15);
+    /// ```
+    pub fn polish_root(&self, z: Complex<T>) -> Complex<T>
+    {
+        let mut z: Complex<T> = z;
+        let (mut p, mut dp): (Complex<T>, Complex<T>) = self.eval_with_derivative(z);
+
+        for _ in 0..POLISH_ITERS
+        {
+            if modulus(p) == T::zero() || modulus(dp) == T::zero()
+            {
+                break;
+            }
+            let z_next: Complex<T> = z - p / dp;
+            let (p_next, dp_next): (Complex<T>, Complex<T>) = self.eval_with_derivative(z_next);
+            if modulus(p_next) >= modulus(p)
+            {
+                break;
+            }
+            z = z_next;
+            p = p_next;
+            dp = dp_next;
+        }
+        z
+    }
+
+    /// Evaluates p(z) and p'(z) with Horner's rule
+    fn eval_with_derivative(&self, z: Complex<T>) -> (Complex<T>, Complex<T>)
+    {
+        let mut p: Complex<T> = Complex::new(T::zero(), T::zero());
+        let mut dp: Complex<T> = Complex::new(T::zero(), T::zero());
+        for a_i in self.coef.iter().rev()
+        {
+            dp = dp * z + p;
+            p = p * z + Complex::new(*a_i, T::zero());
+        }
+        (p, dp)
+    }
+}
+
+/// Absolute value of a complex number
+fn modulus<T>(z: Complex<T>) -> T
+    where T: Real
+{
+    let scale: T = z.re.abs().max(z.im.abs());
+    if scale == T::zero()
+    {
+        return T::zero();
+    }
+    let re: T = z.re / scale;
+    let im: T = z.im / scale;
+    scale * (re * re + im * im).sqrt()
+}
+
+/// Orders complex numbers by their real and imaginary parts
+///
+/// NaN is greater than any other number, such that sorting does not panic for
+/// diverged roots.
+pub(super) fn cmp_complex<T>(a: &Complex<T>, b: &Complex<T>) -> Ordering
+    where T: Real
+{
+    cmp_nan_last(a.re, b.re).then(cmp_nan_last(a.im, b.im))
+}
+
+fn cmp_nan_last<T>(a: T, b: T) -> Ordering
+    where T: Real
+{
+    match a.partial_cmp(&b)
+    {
+        Some(ordering) => ordering,
+        // only NaN is unordered to itself
+        None => a.partial_cmp(&a).is_none().cmp(&b.partial_cmp(&b).is_none()),
+    }
+}
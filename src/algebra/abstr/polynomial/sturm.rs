@@ -0,0 +1,346 @@
+//! Sturm sequences and real root isolation
+use crate::algebra::abstr::{Complex, Real};
+
+use super::Polynomial;
+
+/// Maximal number of bisections of an isolating interval
+const BISECTION_ITERS: usize = 200;
+
+impl<T> Polynomial<T>
+    where T: Real
+{
+    /// Computes the Sturm sequence of the polynomial
+    ///
+    /// ```math
+    /// p_0 = p, \quad p_1 = p', \quad p_{k + 1} = -\operatorname{rem}(p_{k - 1}, p_k)
+    /// ```
+    /// The sequence ends with a multiple of $\gcd(p, p')$. Every element is
+    /// scaled to a maximal absolute coefficient of one, which does not change
+    /// the signs. Remainders, whose coefficients vanish up to their rounding
+    /// errors, terminate the sequence.
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = x^2 - 1, \quad p'(x) = 2x, \quad -\operatorname{rem}(p, p') = 1
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, -1.0]);
+    /// let sturm: Vec<Polynomial<f64>> = p.sturm_sequence();
+    ///
+    /// assert_eq!(3, sturm.len());
+    /// assert_eq!(Polynomial::from_coef(vec![1.0, 0.0]), sturm[1]);
+    /// assert_eq!(Polynomial::from_coef(vec![1.0]), sturm[2]);
+    /// ```
+    pub fn sturm_sequence(&self) -> Vec<Polynomial<T>>
+    {
+        let p_0: Polynomial<T> = self.trimmed().normalized();
+        if p_0.degree() == 0
+        {
+            return vec![p_0];
+        }
+
+        let p_1: Polynomial<T> = p_0.differentiate().normalized();
+        let mut sequence: Vec<Polynomial<T>> = vec![p_0, p_1];
+        loop
+        {
+            let k: usize = sequence.len() - 1;
+            if sequence[k].degree() == 0
+            {
+                break;
+            }
+            let r: Polynomial<T> = sequence[k - 1].sturm_remainder(&sequence[k]);
+            if r.coef.iter().all(|r_i| *r_i == T::zero())
+            {
+                break;
+            }
+            sequence.push((-r).normalized());
+        }
+        sequence
+    }
+
+    /// Counts the distinct real roots in the interval $(a, b]$
+    ///
+    /// By Sturm's theorem, the number of distinct real roots in $(a, b]$ is
+    /// the difference of the sign changes of the Sturm sequence at a and b.
+    /// The sequence is divided by $\gcd(p, p')$, so multiple roots are
+    /// counted once. If a or b is a root, rounding errors decide, whether it
+    /// is counted. For ill-conditioned polynomials, e.g. with more than about
+    /// 18 clustered roots in double precision, the rounding errors of the
+    /// sequence can change the count.
+    ///
+    /// # Panics
+    ///
+    /// If a > b
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = (x - 1)^2(x - 2)(x - 3)
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_root(vec![1.0, 1.0, 2.0, 3.0]);
+    ///
+    /// assert_eq!(3, p.count_real_roots(0.0, 4.0));
+    /// assert_eq!(1, p.count_real_roots(1.5, 2.5));
+    /// ```
+    pub fn count_real_roots(&self, a: T, b: T) -> usize
+    {
+        if a > b
+        {
+            panic!()
+        }
+        let sequence: Vec<Polynomial<T>> = self.reduced_sturm_sequence();
+        sign_changes(&sequence, a).saturating_sub(sign_changes(&sequence, b))
+    }
+
+    /// Isolates the distinct real roots
+    ///
+    /// Returns disjoint intervals $(a_k, b_k]$, which contain exactly one
+    /// distinct real root each, in ascending order. The intervals are found
+    /// by bisection of Cauchy's bound $\lvert x \rvert < 1 + \max_i
+    /// \lvert a_i / a_n \rvert$ with Sturm's theorem.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_root(vec![-1.0, 2.0, 2.5]);
+    /// let intervals: Vec<(f64, f64)> = p.isolate_real_roots();
+    ///
+    /// assert_eq!(3, intervals.len());
+    /// for ((a, b), root) in intervals.into_iter().zip(vec![-1.0, 2.0, 2.5])
+    /// {
+    ///     assert!(a < root && root <= b);
+    /// }
+    /// ```
+    pub fn isolate_real_roots(&self) -> Vec<(T, T)>
+    {
+        let p: Polynomial<T> = self.trimmed();
+        let n: usize = p.degree();
+        if n == 0
+        {
+            return Vec::new();
+        }
+
+        let bound: T = T::one()
+                       + p.coef[..n].iter().fold(T::zero(), |m, a_i| m.max((*a_i / p.coef[n]).abs()));
+        let sequence: Vec<Polynomial<T>> = p.reduced_sturm_sequence();
+
+        let mut intervals: Vec<(T, T)> = Vec::new();
+        let mut stack: Vec<(T, usize, T, usize)> =
+            vec![(-bound, sign_changes(&sequence, -bound), bound, sign_changes(&sequence, bound))];
+        while let Some((a, v_a, b, v_b)) = stack.pop()
+        {
+            let count: usize = v_a.saturating_sub(v_b);
+            if count == 0
+            {
+                continue;
+            }
+            let m: T = (a + b) / T::from_f64(2.0);
+            if count == 1 || m <= a || m >= b
+            {
+                intervals.push((a, b));
+                continue;
+            }
+            let v_m: usize = sign_changes(&sequence, m);
+            stack.push((m, v_m, b, v_b));
+            stack.push((a, v_a, m, v_m));
+        }
+        intervals
+    }
+
+    /// Computes the distinct real roots in ascending order
+    ///
+    /// The isolating intervals of [`isolate_real_roots`] are bisected with
+    /// Sturm's theorem and the roots are polished with Newton's method.
+    /// Unlike [`roots`], the method finds multiple roots to full accuracy,
+    /// but returns each of them only once.
+    ///
+    /// [`isolate_real_roots`]: Polynomial::isolate_real_roots
+    /// [`roots`]: Polynomial::roots
+    ///
+    /// # Example
+    ///
+    /// ```math
+    /// p(x) = (x - 1)^3(x^2 + 1)
+    /// ```
+    ///
+    /// ```
+    /// use mathru::algebra::abstr::Polynomial;
+    ///
+    /// let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -3.0, 4.0, -4.0, 3.0, -1.0]);
+    /// let roots: Vec<f64> = p.real_roots();
+    ///
+    /// assert_eq!(1, roots.len());
+    /// assert!((roots[0] - 1.0).abs() < 1.0e-12);
+    /// ```
+    pub fn real_roots(&self) -> Vec<T>
+    {
+        let sequence: Vec<Polynomial<T>> = self.reduced_sturm_sequence();
+
+        self.isolate_real_roots()
+            .into_iter()
+            .map(|(a, b)| {
+                let (mut a, mut b): (T, T) = (a, b);
+                let mut v_a: usize = sign_changes(&sequence, a);
+                for _ in 0..BISECTION_ITERS
+                {
+                    let m: T = (a + b) / T::from_f64(2.0);
+                    if m <= a || m >= b
+                    {
+                        break;
+                    }
+                    let v_m: usize = sign_changes(&sequence, m);
+                    if v_a > v_m
+                    {
+                        b = m;
+                    }
+                    else
+                    {
+                        a = m;
+                        v_a = v_m;
+                    }
+                }
+                self.polish_root(Complex::new(b, T::zero())).re
+            })
+            .collect()
+    }
+
+    /// Sturm sequence divided by $\gcd(p, p')$
+    ///
+    /// The elements of the reduced sequence do not vanish simultaneously at
+    /// multiple roots of p, so the sign changes are also defined there. The
+    /// last element is only used as $\gcd(p, p')$, if it divides p and p' up
+    /// to rounding errors. Otherwise, the unreduced sequence is returned.
+    fn reduced_sturm_sequence(&self) -> Vec<Polynomial<T>>
+    {
+        let sequence: Vec<Polynomial<T>> = self.sturm_sequence();
+        let gcd: &Polynomial<T> = &sequence[sequence.len() - 1];
+        if gcd.degree() == 0 || !sequence[..2].iter().all(|p_k| p_k.sturm_remainder(gcd).is_zero())
+        {
+            return sequence;
+        }
+        sequence.iter().map(|p_k| (p_k / gcd).0.normalized()).collect()
+    }
+
+    /// Removes leading zero coefficients
+    pub(super) fn trimmed(&self) -> Polynomial<T>
+    {
+        let mut coef: Vec<T> = self.coef.clone();
+        while coef.len() > 1 && coef[coef.len() - 1] == T::zero()
+        {
+            coef.pop();
+        }
+        Polynomial { coef }
+    }
+
+    /// Scales the polynomial to a maximal absolute coefficient of one
+    pub(super) fn normalized(self) -> Polynomial<T>
+    {
+        let scale: T = self.coef.iter().fold(T::zero(), |m, a_i| m.max(a_i.abs()));
+        if scale == T::zero()
+        {
+            return self;
+        }
+        Polynomial { coef: self.coef.into_iter().map(|a_i| a_i / scale).collect() }
+    }
+
+    /// Remainder of the division by divisor, leading coefficients below the
+    /// rounding errors are removed
+    pub(super) fn remainder(&self, divisor: &Polynomial<T>) -> Polynomial<T>
+    {
+        let n: usize = divisor.degree();
+        let scale: T = self.coef.iter().fold(T::zero(), |m, a_i| m.max(a_i.abs()));
+        let tol: T = T::from_f64(1.0e3) * T::default_epsilon() * scale;
+
+        let mut r: Vec<T> = self.coef.clone();
+        while r.len() > n
+        {
+            let q: T = r[r.len() - 1] / divisor.coef[n];
+            let shift: usize = r.len() - 1 - n;
+            for (k, d_k) in divisor.coef.iter().enumerate()
+            {
+                r[shift + k] -= q * *d_k;
+            }
+            r.pop();
+        }
+        while r.len() > 1 && r[r.len() - 1].abs() <= tol
+        {
+            r.pop();
+        }
+        if r.len() == 1 && r[0].abs() <= tol
+        {
+            r[0] = T::zero();
+        }
+        Polynomial { coef: r }
+    }
+
+    /// Remainder of the division by divisor, coefficients below their own
+    /// rounding errors are removed
+    ///
+    /// Unlike [`remainder`](Polynomial::remainder), the tolerance is not
+    /// relative to the largest coefficient, but to the sum of the absolute
+    /// values of the terms, which are subtracted from a coefficient during
+    /// the division. Small leading coefficients, e.g. of polynomials with
+    /// many roots, are therefore kept.
+    fn sturm_remainder(&self, divisor: &Polynomial<T>) -> Polynomial<T>
+    {
+        let n: usize = divisor.degree();
+
+        let mut r: Vec<T> = self.coef.clone();
+        let mut bound: Vec<T> = self.coef.iter().map(|a_i| a_i.abs()).collect();
+        while r.len() > n
+        {
+            let q: T = r[r.len() - 1] / divisor.coef[n];
+            let shift: usize = r.len() - 1 - n;
+            for (k, d_k) in divisor.coef.iter().enumerate()
+            {
+                r[shift + k] -= q * *d_k;
+                bound[shift + k] += (q * *d_k).abs();
+            }
+            r.pop();
+        }
+
+        let tol: T = T::from_f64(1.0e2) * T::default_epsilon();
+        while r.len() > 1 && r[r.len() - 1].abs() <= tol * bound[r.len() - 1]
+        {
+            r.pop();
+        }
+        if r.len() == 1 && r[0].abs() <= tol * bound[0]
+        {
+            r[0] = T::zero();
+        }
+        Polynomial { coef: r }
+    }
+}
+
+/// Number of sign changes of the Sturm sequence at x, zeros are skipped
+fn sign_changes<T>(sequence: &[Polynomial<T>], x: T) -> usize
+    where T: Real
+{
+    let mut changes: usize = 0;
+    let mut previous: Option<bool> = None;
+    for p in sequence.iter()
+    {
+        let p_x: T = p.eval(x);
+        if p_x == T::zero()
+        {
+            continue;
+        }
+        let positive: bool = p_x > T::zero();
+        if previous.is_some_and(|previous| previous != positive)
+        {
+            changes += 1;
+        }
+        previous = Some(positive);
+    }
+    changes
+}
@@ -0,0 +1,38 @@
+//! Two-point boundary value problem
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+
+/// Two-point boundary value problem
+///
+/// ```math
+/// x^{'}(t) = f(t, x(t)) \quad t \in \lbrack a, b \rbrack \\
+/// g(x(a), x(b)) = 0
+/// ```
+/// The number of boundary conditions has to be equal to the dimension of
+/// $x$.
+///
+/// This trait has to be implemented by every boundary value problem which
+/// shall be solved with a shooting or a collocation method.
+pub trait BoundaryValueProblem<T>
+    where T: Real
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>;
+
+    /// Jacobian $\frac{\partial f}{\partial x}$ of the right-hand side
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>;
+
+    /// Residual $g(x(a), x(b))$ of the boundary conditions
+    fn boundary_cond(&self, x_a: &Vector<T>, x_b: &Vector<T>) -> Vector<T>;
+
+    /// Jacobians $(\frac{\partial g}{\partial x(a)}, \frac{\partial
+    /// g}{\partial x(b)})$ of the boundary conditions
+    fn boundary_jacobian(&self, x_a: &Vector<T>, x_b: &Vector<T>) -> (Matrix<T>, Matrix<T>);
+
+    /// Interval $\lbrack a, b \rbrack$
+    fn time_span(&self) -> (T, T);
+
+    /// Initial guess of the solution at time t
+    fn init_guess(&self, t: &T) -> Vector<T>;
+}
@@ -0,0 +1,93 @@
+//! Boundary value problems
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Boundary_value_problem>
+//!
+//! A two-point boundary value problem consists of a system of first order
+//! ODEs and boundary conditions, which relate the values of the solution at
+//! both ends of the interval
+//! ```math
+//! x^{'}(t) = f(t, x(t)) \quad t \in \lbrack a, b \rbrack \\
+//! g(x(a), x(b)) = 0
+//! ```
+//!
+//! # Example
+//!
+//! The deflection $w(t)$ of a beam with length one, which is clamped at both
+//! ends and carries a uniform load $q$, fulfills
+//! ```math
+//! w^{''''}(t) = q \quad w(0) = w^{'}(0) = w(1) = w^{'}(1) = 0
+//! ```
+//! The closed solution is $w(t) = \frac{q}{24} t^2 (1 - t)^2$.
+//! ```
+//! # #[macro_use]
+//! # extern crate mathru;
+//! # fn main()
+//! # {
+//! use mathru::{
+//!     algebra::linear::{Matrix, Vector},
+//!     analysis::differential_equation::{
+//!         boundary_value::{BoundaryValueProblem, solver::MultipleShooting},
+//!         ordinary::solver::runge_kutta::RungeKutta4,
+//!     },
+//! };
+//!
+//! pub struct Beam
+//! {
+//!     q: f64,
+//! }
+//!
+//! impl BoundaryValueProblem<f64> for Beam
+//! {
+//!     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+//!     {
+//!         vector![x[1]; x[2]; x[3]; self.q]
+//!     }
+//!
+//!     fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+//!     {
+//!         matrix![0.0, 1.0, 0.0, 0.0;
+//!                 0.0, 0.0, 1.0, 0.0;
+//!                 0.0, 0.0, 0.0, 1.0;
+//!                 0.0, 0.0, 0.0, 0.0]
+//!     }
+//!
+//!     fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+//!     {
+//!         vector![x_a[0]; x_a[1]; x_b[0]; x_b[1]]
+//!     }
+//!
+//!     fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+//!     {
+//!         let b_a: Matrix<f64> = matrix![1.0, 0.0, 0.0, 0.0;
+//!                                        0.0, 1.0, 0.0, 0.0;
+//!                                        0.0, 0.0, 0.0, 0.0;
+//!                                        0.0, 0.0, 0.0, 0.0];
+//!         let b_b: Matrix<f64> = matrix![0.0, 0.0, 0.0, 0.0;
+//!                                        0.0, 0.0, 0.0, 0.0;
+//!                                        1.0, 0.0, 0.0, 0.0;
+//!                                        0.0, 1.0, 0.0, 0.0];
+//!         (b_a, b_b)
+//!     }
+//!
+//!     fn time_span(&self) -> (f64, f64)
+//!     {
+//!         (0.0, 1.0)
+//!     }
+//!
+//!     fn init_guess(&self, _t: &f64) -> Vector<f64>
+//!     {
+//!         vector![0.0; 0.0; 0.0; 0.0]
+//!     }
+//! }
+//!
+//! let solver: MultipleShooting<f64> = MultipleShooting::new(4, 0.01, 100, 10e-10);
+//!
+//! let (t, w): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Beam { q: 1.0 }, &RungeKutta4::default()).unwrap();
+//! # }
+//! ```
+
+mod boundary_value_problem;
+pub mod solver;
+
+pub use boundary_value_problem::BoundaryValueProblem;
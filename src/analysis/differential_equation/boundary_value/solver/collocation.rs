@@ -0,0 +1,409 @@
+//! Solves a boundary value problem with a collocation method.
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{differential_equation::boundary_value::BoundaryValueProblem, Function, Jacobian, NewtonRaphson},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Collocation method with mesh adaptation
+///
+/// The solution is approximated by a continuous, piecewise cubic polynomial
+/// $S(t)$, which fulfills the boundary conditions and the differential
+/// equation at the mesh points $t_i$ and the midpoints of the mesh intervals.
+/// This is equivalent to the three-stage Lobatto IIIA formula
+/// ```math
+/// x_{i + 1} - x_i - \frac{h_i}{6} \left( f_i + 4 f(t_i + \frac{h_i}{2},
+/// x_{i + \frac{1}{2}}) + f_{i + 1} \right) = 0 \\
+/// x_{i + \frac{1}{2}} = \frac{x_i + x_{i + 1}}{2} + \frac{h_i}{8} (f_i -
+/// f_{i + 1})
+/// ```
+/// with $f_i = f(t_i, x_i)$. The resulting nonlinear system for the values
+/// at all mesh points is solved with the Newton-Raphson method.
+///
+/// The accuracy of the solution is measured with the residual
+/// $r(t) = S^{'}(t) - f(t, S(t))$ on each mesh interval. The residual,
+/// scaled by $abs\_tol + rel\_tol |f(t, S(t))|$, has to be less than one.
+/// Mesh intervals with a larger residual are subdivided, and the problem is
+/// solved again on the refined mesh, taking the previous solution as initial
+/// guess.
+///
+/// J. Kierzenka, L. F. Shampine, A BVP Solver Based on Residual Control and
+/// the Matlab PSE, ACM Transactions on Mathematical Software 27 (2001)
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differential_equation::boundary_value::{BoundaryValueProblem, solver::Collocation},
+/// };
+///
+/// // x'' = -x, x(0) = 0, x(pi / 2) = 1
+/// pub struct Example;
+///
+/// impl BoundaryValueProblem<f64> for Example
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![x[1]; -x[0]]
+///     }
+///
+///     fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![0.0, 1.0; -1.0, 0.0]
+///     }
+///
+///     fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![x_a[0]; x_b[0] - 1.0]
+///     }
+///
+///     fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+///     {
+///         (matrix![1.0, 0.0; 0.0, 0.0], matrix![0.0, 0.0; 1.0, 0.0])
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, std::f64::consts::PI / 2.0)
+///     }
+///
+///     fn init_guess(&self, _t: &f64) -> Vector<f64>
+///     {
+///         vector![0.0; 0.0]
+///     }
+/// }
+///
+/// let solver: Collocation<f64> = Collocation::new(10, 1000, 10e-8, 10e-6);
+///
+/// let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Example).unwrap();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Collocation<T>
+{
+    /// Number of intervals of the initial mesh
+    intervals: usize,
+    /// Maximum number of mesh points
+    n_max: usize,
+    abs_tol: T,
+    rel_tol: T,
+    root_finder: NewtonRaphson<T>,
+}
+
+impl<T> Default for Collocation<T>
+    where T: Real
+{
+    fn default() -> Collocation<T>
+    {
+        Collocation::new(10, 1000, T::from_f64(10e-7), T::from_f64(10e-4))
+    }
+}
+
+impl<T> Collocation<T>
+    where T: Real
+{
+    /// Creates a collocation instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'intervals': number of intervals of the equidistant initial mesh
+    /// * 'n_max': maximum number of mesh points
+    /// * 'abs_tol': absolute tolerance of the residual
+    /// * 'rel_tol': relative tolerance of the residual
+    ///
+    /// # Panics
+    ///
+    /// if 'intervals' == 0 or 'n_max' <= 'intervals'
+    pub fn new(intervals: usize, n_max: usize, abs_tol: T, rel_tol: T) -> Collocation<T>
+    {
+        if intervals == 0
+        {
+            panic!("The number of intervals has to be positive");
+        }
+        if n_max <= intervals
+        {
+            panic!("The maximum number of mesh points is less than the number of initial mesh points");
+        }
+
+        Collocation { intervals,
+                      n_max,
+                      abs_tol,
+                      rel_tol,
+                      root_finder: NewtonRaphson::new(100, abs_tol / T::from_f64(10.0)) }
+    }
+
+    /// Solves `prob` with the collocation method.
+    ///
+    /// # Arguments
+    ///
+    /// * 'prob' is a two-point boundary value problem
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the mesh points
+    /// and the respectful values of the solution.
+    ///
+    /// # Panic
+    ///
+    /// if t_span.0 > t_span.1
+    pub fn solve<F>(&self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: BoundaryValueProblem<T>
+    {
+        let t_span: (T, T) = prob.time_span();
+
+        if t_span.0 > t_span.1
+        {
+            panic!();
+        }
+
+        let h: T = (t_span.1 - t_span.0) / T::from_u64(self.intervals as u64);
+        let mut mesh: Vec<T> = (0..self.intervals).map(|i| t_span.0 + T::from_u64(i as u64) * h).collect();
+        mesh.push(t_span.1);
+
+        let mut x: Vec<Vector<T>> = mesh.iter().map(|t_i| prob.init_guess(t_i)).collect();
+        let (m, _n): (usize, usize) = x[0].dim();
+
+        loop
+        {
+            let helper = CollocationHelper { prob,
+                                             mesh: &mesh,
+                                             m };
+
+            let mut z_0: Vector<T> = Vector::zero(mesh.len() * m);
+            for (i, x_i) in x.iter().enumerate()
+            {
+                z_0.set_slice(x_i, i * m);
+            }
+
+            let z: Vector<T> = self.root_finder.find_root(&helper, &z_0)?;
+            x = (0..mesh.len()).map(|i| z.get_slice(i * m, (i + 1) * m - 1)).collect();
+            let f: Vec<Vector<T>> = mesh.iter().zip(x.iter()).map(|(t_i, x_i)| prob.func(t_i, x_i)).collect();
+
+            let errors: Vec<T> = (0..mesh.len() - 1).map(|i| self.residual(prob, &mesh, &x, &f, i)).collect();
+
+            if errors.iter().all(|e| *e <= T::one())
+            {
+                return Ok((mesh, x));
+            }
+
+            // Subdivide the intervals with a too large residual
+            let mut mesh_new: Vec<T> = Vec::new();
+            let mut x_new: Vec<Vector<T>> = Vec::new();
+
+            for i in 0..mesh.len() - 1
+            {
+                mesh_new.push(mesh[i]);
+                x_new.push(x[i].clone());
+
+                let parts: usize = if errors[i] > T::from_f64(100.0)
+                {
+                    3
+                }
+                else if errors[i] > T::one()
+                {
+                    2
+                }
+                else
+                {
+                    1
+                };
+
+                for k in 1..parts
+                {
+                    let theta: T = T::from_u64(k as u64) / T::from_u64(parts as u64);
+                    let (s, _s_dot): (Vector<T>, Vector<T>) = Collocation::interpolate(&mesh, &x, &f, i, theta);
+                    mesh_new.push(mesh[i] + theta * (mesh[i + 1] - mesh[i]));
+                    x_new.push(s);
+                }
+            }
+            mesh_new.push(mesh[mesh.len() - 1]);
+            x_new.push(x[x.len() - 1].clone());
+
+            if mesh_new.len() > self.n_max
+            {
+                return Err("Maximum number of mesh points reached");
+            }
+
+            mesh = mesh_new;
+            x = x_new;
+        }
+    }
+}
+
+impl<T> Collocation<T>
+    where T: Real
+{
+    /// Evaluates the cubic Hermite polynomial $S$ and its derivative on the
+    /// i-th mesh interval at $t_i + \theta h_i$
+    fn interpolate(mesh: &[T], x: &[Vector<T>], f: &[Vector<T>], i: usize, theta: T) -> (Vector<T>, Vector<T>)
+    {
+        let h: T = mesh[i + 1] - mesh[i];
+        let theta_2: T = theta * theta;
+        let theta_3: T = theta_2 * theta;
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+        let four: T = T::from_f64(4.0);
+        let six: T = T::from_f64(6.0);
+
+        let h_00: T = two * theta_3 - three * theta_2 + T::one();
+        let h_10: T = theta_3 - two * theta_2 + theta;
+        let h_01: T = -two * theta_3 + three * theta_2;
+        let h_11: T = theta_3 - theta_2;
+
+        let s: Vector<T> = &x[i] * &h_00 + &f[i] * &(h_10 * h) + &x[i + 1] * &h_01 + &f[i + 1] * &(h_11 * h);
+
+        let d_00: T = six * theta_2 - six * theta;
+        let d_10: T = three * theta_2 - four * theta + T::one();
+        let d_11: T = three * theta_2 - two * theta;
+
+        let s_dot: Vector<T> = (&x[i + 1] - &x[i]) * (-d_00 / h) + &f[i] * &d_10 + &f[i + 1] * &d_11;
+
+        (s, s_dot)
+    }
+
+    /// Scaled residual on the i-th mesh interval, evaluated at the interior
+    /// nodes of the five-point Lobatto formula
+    fn residual<F>(&self, prob: &F, mesh: &[T], x: &[Vector<T>], f: &[Vector<T>], i: usize) -> T
+        where F: BoundaryValueProblem<T>
+    {
+        let h: T = mesh[i + 1] - mesh[i];
+        let offset: T = T::from_f64(21.0).sqrt() / T::from_f64(14.0);
+        let half: T = T::from_f64(0.5);
+
+        let mut error: T = T::zero();
+        for theta in [half - offset, half + offset].iter()
+        {
+            let (s, s_dot): (Vector<T>, Vector<T>) = Collocation::interpolate(mesh, x, f, i, *theta);
+            let f_s: Vector<T> = prob.func(&(mesh[i] + *theta * h), &s);
+            let (m, _n): (usize, usize) = s.dim();
+
+            for j in 0..m
+            {
+                let sc_j: T = self.abs_tol + self.rel_tol * f_s[j].abs();
+                error = error.max((s_dot[j] - f_s[j]).abs() / sc_j);
+            }
+        }
+
+        error
+    }
+}
+
+/// The unknowns are the values at all mesh points. The first m equations are
+/// the boundary conditions, followed by the collocation equations of each
+/// mesh interval.
+struct CollocationHelper<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    prob: &'a F,
+    mesh: &'a [T],
+    /// Dimension of the ODE
+    m: usize,
+}
+
+impl<'a, T, F> CollocationHelper<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    fn x_i(&self, z: &Vector<T>, i: usize) -> Vector<T>
+    {
+        z.get_slice(i * self.m, (i + 1) * self.m - 1)
+    }
+
+    /// Returns the midpoint value $x_{i + \frac{1}{2}}$
+    fn midpoint(&self, x_i: &Vector<T>, x_i1: &Vector<T>, f_i: &Vector<T>, f_i1: &Vector<T>, h: T) -> Vector<T>
+    {
+        (x_i + x_i1) * T::from_f64(0.5) + (f_i - f_i1) * (h / T::from_f64(8.0))
+    }
+}
+
+impl<'a, T, F> Function<Vector<T>> for CollocationHelper<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, z: &Vector<T>) -> Vector<T>
+    {
+        let n: usize = self.mesh.len();
+        let m: usize = self.m;
+        let mut residual: Vector<T> = Vector::zero(n * m);
+
+        residual.set_slice(&self.prob.boundary_cond(&self.x_i(z, 0), &self.x_i(z, n - 1)), 0);
+
+        for i in 0..n - 1
+        {
+            let h: T = self.mesh[i + 1] - self.mesh[i];
+            let x_i: Vector<T> = self.x_i(z, i);
+            let x_i1: Vector<T> = self.x_i(z, i + 1);
+            let f_i: Vector<T> = self.prob.func(&self.mesh[i], &x_i);
+            let f_i1: Vector<T> = self.prob.func(&self.mesh[i + 1], &x_i1);
+
+            let x_mid: Vector<T> = self.midpoint(&x_i, &x_i1, &f_i, &f_i1, h);
+            let f_mid: Vector<T> = self.prob.func(&(self.mesh[i] + h / T::from_f64(2.0)), &x_mid);
+
+            let r_i: Vector<T> = &x_i1 - &x_i - (f_i + f_mid * T::from_f64(4.0) + f_i1) * (h / T::from_f64(6.0));
+            residual.set_slice(&r_i, (i + 1) * m);
+        }
+
+        residual
+    }
+}
+
+impl<'a, T, F> Jacobian<T> for CollocationHelper<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    fn jacobian(&self, z: &Vector<T>) -> Matrix<T>
+    {
+        let n: usize = self.mesh.len();
+        let m: usize = self.m;
+        let mut jacobian: Matrix<T> = Matrix::zero(n * m, n * m);
+
+        let (b_a, b_b): (Matrix<T>, Matrix<T>) = self.prob.boundary_jacobian(&self.x_i(z, 0), &self.x_i(z, n - 1));
+        jacobian = jacobian.set_slice(&b_a, 0, 0);
+        jacobian = jacobian.set_slice(&b_b, 0, (n - 1) * m);
+
+        let identity: Matrix<T> = Matrix::one(m);
+        let half: T = T::from_f64(0.5);
+
+        for i in 0..n - 1
+        {
+            let h: T = self.mesh[i + 1] - self.mesh[i];
+            let x_i: Vector<T> = self.x_i(z, i);
+            let x_i1: Vector<T> = self.x_i(z, i + 1);
+            let f_i: Vector<T> = self.prob.func(&self.mesh[i], &x_i);
+            let f_i1: Vector<T> = self.prob.func(&self.mesh[i + 1], &x_i1);
+            let j_i: Matrix<T> = self.prob.jacobian(&self.mesh[i], &x_i);
+            let j_i1: Matrix<T> = self.prob.jacobian(&self.mesh[i + 1], &x_i1);
+
+            let x_mid: Vector<T> = self.midpoint(&x_i, &x_i1, &f_i, &f_i1, h);
+            let j_mid: Matrix<T> = self.prob.jacobian(&(self.mesh[i] + h / T::from_f64(2.0)), &x_mid);
+
+            // Derivatives of the midpoint value with respect to x_i and x_{i + 1}
+            let d_mid_i: Matrix<T> = &identity * &half + &j_i * &(h / T::from_f64(8.0));
+            let d_mid_i1: Matrix<T> = &identity * &half - &j_i1 * &(h / T::from_f64(8.0));
+
+            let c: T = -h / T::from_f64(6.0);
+            let four: T = T::from_f64(4.0);
+            let d_r_i: Matrix<T> = (j_i + &j_mid * &d_mid_i * four) * c - identity.clone();
+            let d_r_i1: Matrix<T> = (j_i1 + &j_mid * &d_mid_i1 * four) * c + identity.clone();
+
+            jacobian = jacobian.set_slice(&d_r_i, (i + 1) * m, i * m);
+            jacobian = jacobian.set_slice(&d_r_i1, (i + 1) * m, (i + 1) * m);
+        }
+
+        jacobian
+    }
+}
@@ -0,0 +1,9 @@
+//! This module provides algorithms to solve two-point boundary value problems.
+mod shooting_ode;
+mod singleshooting;
+mod multipleshooting;
+mod collocation;
+
+pub use singleshooting::SingleShooting;
+pub use multipleshooting::MultipleShooting;
+pub use collocation::Collocation;
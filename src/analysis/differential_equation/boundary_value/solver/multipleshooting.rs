@@ -0,0 +1,255 @@
+//! Solves a boundary value problem with the multiple shooting method.
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::{
+            boundary_value::{
+                solver::shooting_ode::{ShootingODE, VariationalODE},
+                BoundaryValueProblem,
+            },
+            ordinary::solver::runge_kutta::{ExplicitRKMethod, FixedStepper},
+        },
+        Function, Jacobian, NewtonRaphson,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Multiple shooting method
+///
+/// The interval $\lbrack a, b \rbrack$ is divided into $m$ subintervals of
+/// equal length with the nodes $a = \tau_0 < \tau_1 < \cdots < \tau_m = b$.
+/// The unknowns are the values $s_i = x(\tau_i)$ at the beginning of each
+/// subinterval. With $x(t; \tau_i, s_i)$ denoting the solution of the initial
+/// value problem starting at $\tau_i$ with the value $s_i$, the continuity
+/// and the boundary conditions
+/// ```math
+/// x(\tau_{i + 1}; \tau_i, s_i) - s_{i + 1} = 0 \quad i = 0, \dots, m - 2 \\
+/// g(s_0, x(b; \tau_{m - 1}, s_{m - 1})) = 0
+/// ```
+/// are solved with the Newton-Raphson method. The Jacobian is computed from
+/// the variational equation of the ODE on each subinterval.
+///
+/// Compared to [SingleShooting](super::SingleShooting), the initial value
+/// problems are integrated over shorter intervals, which reduces the
+/// sensitivity of the equations with respect to the unknowns and allows an
+/// initial guess of the whole solution.
+///
+/// Numerical Analysis
+/// J. Stoer, R. Bulirsch
+///
+/// <https://en.wikipedia.org/wiki/Direct_multiple_shooting_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct MultipleShooting<T>
+{
+    /// Number of subintervals
+    intervals: usize,
+    stepper: FixedStepper<T>,
+    root_finder: NewtonRaphson<T>,
+}
+
+impl<T> MultipleShooting<T>
+    where T: Real
+{
+    /// Creates a multiple shooting instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'intervals': number of subintervals
+    /// * 'step_size': step size of the initial value problem solver
+    /// * 'iters': maximum number of Newton iterations
+    /// * 'tolerance_abs': absolute tolerance of the Newton iteration
+    ///
+    /// # Panics
+    ///
+    /// if 'intervals' == 0 or 'step_size' <= 0.0
+    pub fn new(intervals: usize, step_size: T, iters: u64, tolerance_abs: T) -> MultipleShooting<T>
+    {
+        if intervals == 0
+        {
+            panic!("The number of subintervals has to be positive");
+        }
+
+        MultipleShooting { intervals,
+                           stepper: FixedStepper::new(step_size),
+                           root_finder: NewtonRaphson::new(iters, tolerance_abs) }
+    }
+
+    /// Solves `prob` with the multiple shooting method.
+    ///
+    /// # Arguments
+    ///
+    /// * 'prob' is a two-point boundary value problem
+    /// * 'method' is the explicit Runge-Kutta method the initial value
+    ///   problems are integrated with
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// # Panic
+    ///
+    /// if t_span.0 > t_span.1
+    pub fn solve<F, M>(&self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: BoundaryValueProblem<T>,
+              M: ExplicitRKMethod<T>
+    {
+        let t_span: (T, T) = prob.time_span();
+
+        if t_span.0 > t_span.1
+        {
+            panic!();
+        }
+
+        let h: T = (t_span.1 - t_span.0) / T::from_u64(self.intervals as u64);
+        let mut nodes: Vec<T> = (0..self.intervals).map(|i| t_span.0 + T::from_u64(i as u64) * h).collect();
+        nodes.push(t_span.1);
+
+        let (m, _n): (usize, usize) = prob.init_guess(&t_span.0).dim();
+
+        let mut s_0: Vector<T> = Vector::zero(self.intervals * m);
+        for (i, t_i) in nodes.iter().take(self.intervals).enumerate()
+        {
+            s_0.set_slice(&prob.init_guess(t_i), i * m);
+        }
+
+        let helper = MultipleShootingHelper { prob,
+                                              stepper: &self.stepper,
+                                              method,
+                                              nodes: &nodes,
+                                              m };
+
+        let s: Vector<T> = self.root_finder.find_root(&helper, &s_0)?;
+
+        let mut t_vec: Vec<T> = Vec::new();
+        let mut res_vec: Vec<Vector<T>> = Vec::new();
+
+        for i in 0..self.intervals
+        {
+            let (t, x): (Vec<T>, Vec<Vector<T>>) = helper.shoot(&s, i);
+
+            // The first value of each subinterval is the last value of the previous one
+            let skip: usize = if i == 0 { 0 } else { 1 };
+            t_vec.extend(t.into_iter().skip(skip));
+            res_vec.extend(x.into_iter().skip(skip));
+        }
+
+        Ok((t_vec, res_vec))
+    }
+}
+
+struct MultipleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    prob: &'a F,
+    stepper: &'a FixedStepper<T>,
+    method: &'a M,
+    nodes: &'a [T],
+    /// Dimension of the ODE
+    m: usize,
+}
+
+impl<'a, T, F, M> MultipleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    fn s_i(&self, s: &Vector<T>, i: usize) -> Vector<T>
+    {
+        s.get_slice(i * self.m, (i + 1) * self.m - 1)
+    }
+
+    fn shoot(&self, s: &Vector<T>, i: usize) -> (Vec<T>, Vec<Vector<T>>)
+    {
+        ShootingODE::new(self.prob, (self.nodes[i], self.nodes[i + 1]), self.s_i(s, i)).solve(self.stepper,
+                                                                                              self.method)
+    }
+}
+
+impl<'a, T, F, M> Function<Vector<T>> for MultipleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, s: &Vector<T>) -> Vector<T>
+    {
+        let intervals: usize = self.nodes.len() - 1;
+        let mut residual: Vector<T> = Vector::zero(intervals * self.m);
+
+        for i in 0..intervals
+        {
+            let (_t, x): (Vec<T>, Vec<Vector<T>>) = self.shoot(s, i);
+            let x_end: &Vector<T> = &x[x.len() - 1];
+
+            if i + 1 < intervals
+            {
+                residual.set_slice(&(x_end - &self.s_i(s, i + 1)), i * self.m);
+            }
+            else
+            {
+                residual.set_slice(&self.prob.boundary_cond(&self.s_i(s, 0), x_end), i * self.m);
+            }
+        }
+
+        residual
+    }
+}
+
+impl<'a, T, F, M> Jacobian<T> for MultipleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    fn jacobian(&self, s: &Vector<T>) -> Matrix<T>
+    {
+        let intervals: usize = self.nodes.len() - 1;
+        let m: usize = self.m;
+        let mut jacobian: Matrix<T> = Matrix::zero(intervals * m, intervals * m);
+
+        for i in 0..intervals
+        {
+            let (x_end, phi): (Vector<T>, Matrix<T>) =
+                VariationalODE::new(self.prob, (self.nodes[i], self.nodes[i + 1]), self.s_i(s, i)).solve(self.stepper,
+                                                                                                         self.method);
+
+            if i + 1 < intervals
+            {
+                for k in 0..m
+                {
+                    for l in 0..m
+                    {
+                        jacobian[[i * m + k, i * m + l]] = phi[[k, l]];
+                    }
+                    jacobian[[i * m + k, (i + 1) * m + k]] = -T::one();
+                }
+            }
+            else
+            {
+                let (b_a, b_b): (Matrix<T>, Matrix<T>) = self.prob.boundary_jacobian(&self.s_i(s, 0), &x_end);
+                let b_b_phi: Matrix<T> = b_b * phi;
+
+                // If there is only one subinterval, both blocks are in the same columns
+                for k in 0..m
+                {
+                    for l in 0..m
+                    {
+                        jacobian[[i * m + k, l]] += b_a[[k, l]];
+                        jacobian[[i * m + k, i * m + l]] += b_b_phi[[k, l]];
+                    }
+                }
+            }
+        }
+
+        jacobian
+    }
+}
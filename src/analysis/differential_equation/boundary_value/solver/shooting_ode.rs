@@ -0,0 +1,165 @@
+//! Initial value problems solved by the shooting methods
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differential_equation::{
+        boundary_value::BoundaryValueProblem,
+        ordinary::{
+            solver::runge_kutta::{ExplicitRKMethod, FixedStepper},
+            ExplicitODE,
+        },
+    },
+};
+
+/// Initial value problem $x^{'} = f(t, x)$, $x(t_0) = s$ on a subinterval of
+/// the boundary value problem
+pub struct ShootingODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    prob: &'a F,
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<'a, T, F> ShootingODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    pub fn new(prob: &'a F, time_span: (T, T), init_cond: Vector<T>) -> ShootingODE<'a, T, F>
+    {
+        ShootingODE { prob,
+                      time_span,
+                      init_cond }
+    }
+
+    /// Integrates the initial value problem
+    pub fn solve<M>(&self, stepper: &FixedStepper<T>, method: &M) -> (Vec<T>, Vec<Vector<T>>)
+        where M: ExplicitRKMethod<T>
+    {
+        // The fixed stepper does not fail
+        stepper.solve(self, method).unwrap()
+    }
+}
+
+impl<'a, T, F> ExplicitODE<T> for ShootingODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.prob.func(t, x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+/// Initial value problem together with its variational equation
+///
+/// ```math
+/// x^{'} = f(t, x) \quad x(t_0) = s \\
+/// \Phi^{'} = \frac{\partial f}{\partial x}(t, x) \Phi \quad \Phi(t_0) = I
+/// ```
+/// The solution $\Phi(t) = \frac{\partial x(t)}{\partial s}$ is the
+/// sensitivity of the solution with respect to the initial value. The state
+/// is the vector $x$, followed by the columns of $\Phi$.
+pub struct VariationalODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    prob: &'a F,
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<'a, T, F> VariationalODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    pub fn new(prob: &'a F, time_span: (T, T), init_cond: Vector<T>) -> VariationalODE<'a, T, F>
+    {
+        VariationalODE { prob,
+                         time_span,
+                         init_cond }
+    }
+
+    /// Returns the solution $x(t_1)$ and the sensitivity $\Phi(t_1)$ at the end
+    /// of the time span
+    pub fn solve<M>(&self, stepper: &FixedStepper<T>, method: &M) -> (Vector<T>, Matrix<T>)
+        where M: ExplicitRKMethod<T>
+    {
+        // The fixed stepper does not fail
+        let (_t, y): (Vec<T>, Vec<Vector<T>>) = stepper.solve(self, method).unwrap();
+        let (m, _n): (usize, usize) = self.init_cond.dim();
+
+        VariationalODE::<T, F>::split(&y[y.len() - 1], m)
+    }
+
+    fn split(y: &Vector<T>, m: usize) -> (Vector<T>, Matrix<T>)
+    {
+        let x: Vector<T> = y.get_slice(0, m - 1);
+        let mut phi: Matrix<T> = Matrix::zero(m, m);
+        for j in 0..m
+        {
+            for i in 0..m
+            {
+                phi[[i, j]] = y[m + j * m + i];
+            }
+        }
+
+        (x, phi)
+    }
+}
+
+impl<'a, T, F> ExplicitODE<T> for VariationalODE<'a, T, F>
+    where T: Real,
+          F: BoundaryValueProblem<T>
+{
+    fn func(&self, t: &T, y: &Vector<T>) -> Vector<T>
+    {
+        let (m, _n): (usize, usize) = self.init_cond.dim();
+        let (x, phi): (Vector<T>, Matrix<T>) = VariationalODE::<T, F>::split(y, m);
+
+        let phi_dot: Matrix<T> = &self.prob.jacobian(t, &x) * &phi;
+
+        let mut y_dot: Vector<T> = Vector::zero(m + m * m);
+        y_dot.set_slice(&self.prob.func(t, &x), 0);
+        for j in 0..m
+        {
+            for i in 0..m
+            {
+                y_dot[m + j * m + i] = phi_dot[[i, j]];
+            }
+        }
+
+        y_dot
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        let (m, _n): (usize, usize) = self.init_cond.dim();
+        let mut y: Vector<T> = Vector::zero(m + m * m);
+        y.set_slice(&self.init_cond, 0);
+        for i in 0..m
+        {
+            y[m + i * m + i] = T::one();
+        }
+
+        y
+    }
+}
@@ -0,0 +1,153 @@
+//! Solves a boundary value problem with the single shooting method.
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::{
+            boundary_value::{
+                solver::shooting_ode::{ShootingODE, VariationalODE},
+                BoundaryValueProblem,
+            },
+            ordinary::solver::runge_kutta::{ExplicitRKMethod, FixedStepper},
+        },
+        Function, Jacobian, NewtonRaphson,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Single shooting method
+///
+/// The boundary value problem is reduced to the search of the initial value
+/// $s = x(a)$, such that the solution $x(t; s)$ of the initial value problem
+/// fulfills the boundary conditions
+/// ```math
+/// G(s) = g(s, x(b; s)) = 0
+/// ```
+/// The initial value problem is integrated with an explicit Runge-Kutta
+/// method and a fixed step size, the nonlinear equation is solved with the
+/// Newton-Raphson method. The Jacobian
+/// ```math
+/// G^{'}(s) = \frac{\partial g}{\partial x(a)} + \frac{\partial g}{\partial
+/// x(b)} \frac{\partial x(b; s)}{\partial s}
+/// ```
+/// is computed from the variational equation of the ODE.
+///
+/// The single shooting method is limited to problems, where the solution of
+/// the initial value problem does not depend too sensitively on the initial
+/// value. Otherwise [MultipleShooting](super::MultipleShooting) shall be used.
+///
+/// Numerical Analysis
+/// J. Stoer, R. Bulirsch
+///
+/// <https://en.wikipedia.org/wiki/Shooting_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct SingleShooting<T>
+{
+    stepper: FixedStepper<T>,
+    root_finder: NewtonRaphson<T>,
+}
+
+impl<T> SingleShooting<T>
+    where T: Real
+{
+    /// Creates a single shooting instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'step_size': step size of the initial value problem solver
+    /// * 'iters': maximum number of Newton iterations
+    /// * 'tolerance_abs': absolute tolerance of the Newton iteration
+    ///
+    /// # Panics
+    ///
+    /// if 'step_size' <= 0.0
+    pub fn new(step_size: T, iters: u64, tolerance_abs: T) -> SingleShooting<T>
+    {
+        SingleShooting { stepper: FixedStepper::new(step_size),
+                         root_finder: NewtonRaphson::new(iters, tolerance_abs) }
+    }
+
+    /// Solves `prob` with the single shooting method.
+    ///
+    /// # Arguments
+    ///
+    /// * 'prob' is a two-point boundary value problem
+    /// * 'method' is the explicit Runge-Kutta method the initial value problem
+    ///   is integrated with
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// # Panic
+    ///
+    /// if t_span.0 > t_span.1
+    pub fn solve<F, M>(&self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: BoundaryValueProblem<T>,
+              M: ExplicitRKMethod<T>
+    {
+        let t_span: (T, T) = prob.time_span();
+
+        if t_span.0 > t_span.1
+        {
+            panic!();
+        }
+
+        let helper = SingleShootingHelper { prob,
+                                            stepper: &self.stepper,
+                                            method };
+
+        let s_0: Vector<T> = prob.init_guess(&t_span.0);
+        let s: Vector<T> = self.root_finder.find_root(&helper, &s_0)?;
+
+        Ok(ShootingODE::new(prob, t_span, s).solve(&self.stepper, method))
+    }
+}
+
+struct SingleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    prob: &'a F,
+    stepper: &'a FixedStepper<T>,
+    method: &'a M,
+}
+
+impl<'a, T, F, M> Function<Vector<T>> for SingleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, s: &Vector<T>) -> Vector<T>
+    {
+        let (_t, x): (Vec<T>, Vec<Vector<T>>) =
+            ShootingODE::new(self.prob, self.prob.time_span(), s.clone()).solve(self.stepper, self.method);
+
+        self.prob.boundary_cond(s, &x[x.len() - 1])
+    }
+}
+
+impl<'a, T, F, M> Jacobian<T> for SingleShootingHelper<'a, T, F, M>
+    where T: Real,
+          F: BoundaryValueProblem<T>,
+          M: ExplicitRKMethod<T>
+{
+    fn jacobian(&self, s: &Vector<T>) -> Matrix<T>
+    {
+        let (x_b, phi): (Vector<T>, Matrix<T>) =
+            VariationalODE::new(self.prob, self.prob.time_span(), s.clone()).solve(self.stepper, self.method);
+
+        let (b_a, b_b): (Matrix<T>, Matrix<T>) = self.prob.boundary_jacobian(s, &x_b);
+
+        b_a + b_b * phi
+    }
+}
@@ -0,0 +1,44 @@
+//! Delay differential equation
+use crate::algebra::{abstr::Real, linear::Vector};
+
+/// Delay differential equation with discrete delays
+///
+/// ```math
+/// x^{'}(t) = f(t, x(t), x(t - \tau_1), \dots, x(t - \tau_k)) \quad t_0 \leq
+/// t \leq t_1 \\
+/// x(t) = \phi(t) \quad t \leq t_0
+/// ```
+/// The delays $\tau_i = \tau_i(t, x(t)) \geq 0$ may be constant or depend on
+/// the time and the state.
+///
+/// This trait has to be implemented by every delay differential equation
+/// which shall be solved with the method of steps.
+///
+/// <https://en.wikipedia.org/wiki/Delay_differential_equation>
+pub trait DelayODE<T>
+    where T: Real
+{
+    /// Right-hand side $f$
+    ///
+    /// # Arguments
+    ///
+    /// * 't': time
+    /// * 'x': state $x(t)$
+    /// * 'x_delayed': delayed states $x(t - \tau_i)$ in the order of the
+    ///   delays returned by [delays](DelayODE::delays)
+    fn func(&self, t: &T, x: &Vector<T>, x_delayed: &[Vector<T>]) -> Vector<T>;
+
+    /// Delays $\tau_i(t, x)$, which have to be non-negative
+    fn delays(&self, t: &T, x: &Vector<T>) -> Vec<T>;
+
+    /// History $\phi(t)$ of the state for $t \leq t_0$
+    fn history(&self, t: &T) -> Vector<T>;
+
+    fn time_span(&self) -> (T, T);
+
+    /// Initial value $x(t_0)$, which may differ from $\phi(t_0)$
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.history(&self.time_span().0)
+    }
+}
@@ -0,0 +1,73 @@
+//! Delay differential equations
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Delay_differential_equation>
+//!
+//! The derivative of the state depends on the state at previous times
+//! ```math
+//! x^{'}(t) = f(t, x(t), x(t - \tau_1), \dots, x(t - \tau_k)) \\
+//! x(t) = \phi(t) \quad t \leq t_0
+//! ```
+//! Instead of an initial value, the history $\phi$ of the state has to be
+//! given. The delays may be constant or depend on the time and the state.
+//!
+//! # Example
+//!
+//! Hutchinson's equation, the logistic growth of a population with a
+//! maturation delay $\tau$
+//! ```math
+//! x^{'}(t) = r x(t) (1 - x(t - \tau))
+//! ```
+//! ```
+//! # #[macro_use]
+//! # extern crate mathru;
+//! # fn main()
+//! # {
+//! use mathru::{
+//!     algebra::linear::Vector,
+//!     analysis::differential_equation::{
+//!         delay_differential::{DelayODE, solver::MethodOfSteps},
+//!         ordinary::solver::runge_kutta::BogackiShampine32,
+//!     },
+//! };
+//!
+//! pub struct Hutchinson
+//! {
+//!     r: f64,
+//!     tau: f64,
+//! }
+//!
+//! impl DelayODE<f64> for Hutchinson
+//! {
+//!     fn func(&self, _t: &f64, x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+//!     {
+//!         vector![self.r * x[0] * (1.0 - x_delayed[0][0])]
+//!     }
+//!
+//!     fn delays(&self, _t: &f64, _x: &Vector<f64>) -> Vec<f64>
+//!     {
+//!         vec![self.tau]
+//!     }
+//!
+//!     fn history(&self, _t: &f64) -> Vector<f64>
+//!     {
+//!         vector![0.5]
+//!     }
+//!
+//!     fn time_span(&self) -> (f64, f64)
+//!     {
+//!         (0.0, 20.0)
+//!     }
+//! }
+//!
+//! let solver: MethodOfSteps<f64> = MethodOfSteps::default();
+//! let problem: Hutchinson = Hutchinson { r: 1.0, tau: 1.0 };
+//!
+//! let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &BogackiShampine32::default()).unwrap();
+//! # }
+//! ```
+
+mod delay_ode;
+pub mod solver;
+
+pub use delay_ode::DelayODE;
@@ -0,0 +1,142 @@
+//! Dense output of the solution of a delay differential equation
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    analysis::differential_equation::{delay_differential::DelayODE, ordinary::ExplicitODE},
+};
+
+/// Accepted steps of the solution together with the derivatives at the step
+/// points. Between two step points, the solution is approximated with the
+/// cubic Hermite polynomial.
+pub struct History<T>
+{
+    t: Vec<T>,
+    x: Vec<Vector<T>>,
+    f: Vec<Vector<T>>,
+}
+
+impl<T> History<T>
+    where T: Real
+{
+    pub fn new(t_0: T, x_0: Vector<T>, f_0: Vector<T>) -> History<T>
+    {
+        History { t: vec![t_0],
+                  x: vec![x_0],
+                  f: vec![f_0] }
+    }
+
+    pub fn push(&mut self, t: T, x: Vector<T>, f: Vector<T>)
+    {
+        self.t.push(t);
+        self.x.push(x);
+        self.f.push(f);
+    }
+
+    pub fn pop(&mut self)
+    {
+        self.t.pop();
+        self.x.pop();
+        self.f.pop();
+    }
+
+    pub fn last(&self) -> (T, &Vector<T>, &Vector<T>)
+    {
+        let n: usize = self.t.len() - 1;
+        (self.t[n], &self.x[n], &self.f[n])
+    }
+
+    pub fn into_solution(self) -> (Vec<T>, Vec<Vector<T>>)
+    {
+        (self.t, self.x)
+    }
+
+    /// Evaluates the history of `prob` before the initial time, and the
+    /// dense output after it. Times after the last accepted step are
+    /// extrapolated with the polynomial of the last step. A NaN time, e.g. of
+    /// an undefined state dependent delay, gives a NaN state.
+    pub fn eval<F>(&self, prob: &F, t: &T) -> Vector<T>
+        where F: DelayODE<T>
+    {
+        // only NaN is unordered to itself
+        if t.partial_cmp(t).is_none()
+        {
+            let (m, _n): (usize, usize) = self.x[0].dim();
+            return Vector::new_column(vec![*t; m]);
+        }
+
+        if *t < self.t[0]
+        {
+            return prob.history(t);
+        }
+
+        let n: usize = self.t.len();
+        if n == 1
+        {
+            return &self.x[0] + &(&self.f[0] * &(*t - self.t[0]));
+        }
+
+        // Index of the step interval containing t
+        let i: usize = match self.t.binary_search_by(|t_i| t_i.partial_cmp(t).unwrap())
+        {
+            Ok(i) => return self.x[i].clone(),
+            Err(i) => (i - 1).min(n - 2),
+        };
+
+        History::hermite(self.t[i], &self.x[i], &self.f[i], self.t[i + 1], &self.x[i + 1], &self.f[i + 1], t)
+    }
+
+    /// Cubic Hermite polynomial through $(t_0, x_0)$ and $(t_1, x_1)$ with the
+    /// derivatives $f_0$ and $f_1$
+    pub fn hermite(t_0: T, x_0: &Vector<T>, f_0: &Vector<T>, t_1: T, x_1: &Vector<T>, f_1: &Vector<T>, t: &T)
+                   -> Vector<T>
+    {
+        let h: T = t_1 - t_0;
+        let theta: T = (*t - t_0) / h;
+        let theta_2: T = theta * theta;
+        let theta_3: T = theta_2 * theta;
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+
+        let h_00: T = two * theta_3 - three * theta_2 + T::one();
+        let h_10: T = theta_3 - two * theta_2 + theta;
+        let h_01: T = -two * theta_3 + three * theta_2;
+        let h_11: T = theta_3 - theta_2;
+
+        x_0 * &h_00 + f_0 * &(h_10 * h) + x_1 * &h_01 + f_1 * &(h_11 * h)
+    }
+}
+
+/// The delay differential equation as an ODE, whose delayed states are taken
+/// from the history
+pub struct HistoryODE<'a, T, F>
+    where T: Real,
+          F: DelayODE<T>
+{
+    pub prob: &'a F,
+    pub history: &'a History<T>,
+}
+
+impl<'a, T, F> ExplicitODE<T> for HistoryODE<'a, T, F>
+    where T: Real,
+          F: DelayODE<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        let x_delayed: Vec<Vector<T>> = self.prob
+                                            .delays(t, x)
+                                            .iter()
+                                            .map(|tau| self.history.eval(self.prob, &(*t - *tau)))
+                                            .collect();
+
+        self.prob.func(t, x, &x_delayed)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.prob.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.prob.init_cond()
+    }
+}
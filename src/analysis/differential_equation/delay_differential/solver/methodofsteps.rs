@@ -0,0 +1,397 @@
+//! Solves a delay differential equation using the method of steps.
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    analysis::differential_equation::{
+        delay_differential::{
+            solver::history::{History, HistoryODE},
+            DelayODE,
+        },
+        ordinary::{solver::runge_kutta::ExplicitRKEmbeddedMethod, ExplicitODE},
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Method of steps with an embedded Runge-Kutta method and adaptive step
+/// size
+///
+/// The delay differential equation is integrated like an ODE with an
+/// embedded explicit Runge-Kutta method. The delayed states $x(t - \tau_i)$
+/// are taken from the history $\phi$ for $t - \tau_i < t_0$, and from a
+/// continuous extension of the already computed solution otherwise. The
+/// continuous extension is the piecewise cubic Hermite polynomial, which
+/// interpolates the solution and its derivative at the step points. If a
+/// delay is smaller than the current step size, the delayed state lies within
+/// the current step. The step is then repeated in a fixed-point iteration,
+/// taking the delayed states from the polynomial of the previous iterate.
+/// The continuous extension is of order three. The step size control does
+/// not take its error into account, hence the method of steps is best
+/// combined with a third order method like
+/// [BogackiShampine32](crate::analysis::differential_equation::ordinary::solver::runge_kutta::BogackiShampine32).
+///
+/// The solution of a delay differential equation is in general not smooth.
+/// The jump of the derivative at $t_0$ propagates to the points $\xi$ with
+/// ```math
+/// \xi - \tau_i(\xi, x(\xi)) = \xi_j
+/// ```
+/// where $\xi_j$ is a previous discontinuity. The discontinuities are located
+/// during the integration, for constant and for state dependent delays, and
+/// the steps are chosen such that they end at the discontinuities. As the
+/// solution gets smoother with every propagation, only discontinuities up to
+/// the order of the method are tracked.
+///
+/// Numerical Methods for Delay Differential Equations
+/// A. Bellen, M. Zennaro
+///
+/// L. F. Shampine, S. Thompson, Solving DDEs in Matlab, Applied Numerical
+/// Mathematics 37 (2001)
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::{
+///         delay_differential::{DelayODE, solver::MethodOfSteps},
+///         ordinary::solver::runge_kutta::BogackiShampine32,
+///     },
+/// };
+///
+/// // x'(t) = -x(t - 1), x(t) = 1 for t <= 0
+/// pub struct Example;
+///
+/// impl DelayODE<f64> for Example
+/// {
+///     fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+///     {
+///         -x_delayed[0].clone()
+///     }
+///
+///     fn delays(&self, _t: &f64, _x: &Vector<f64>) -> Vec<f64>
+///     {
+///         vec![1.0]
+///     }
+///
+///     fn history(&self, _t: &f64) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 3.0)
+///     }
+/// }
+///
+/// let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.01, 0.9, 0.2, 5.0, 10e-9, 10e-7);
+///
+/// let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Example, &BogackiShampine32::default()).unwrap();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct MethodOfSteps<T>
+{
+    /// Maximum number of accepted steps
+    n_max: u32,
+    /// Initial step size
+    h_0: T,
+    fac: T,
+    fac_min: T,
+    fac_max: T,
+    abs_tol: T,
+    rel_tol: T,
+}
+
+impl<T> Default for MethodOfSteps<T>
+    where T: Real
+{
+    fn default() -> MethodOfSteps<T>
+    {
+        MethodOfSteps::new(10000,
+                           T::from_f64(0.01),
+                           T::from_f64(0.9),
+                           T::from_f64(0.2),
+                           T::from_f64(5.0),
+                           T::from_f64(10e-7),
+                           T::from_f64(10e-5))
+    }
+}
+
+impl<T> MethodOfSteps<T>
+    where T: Real
+{
+    /// Creates a method of steps instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'n_max': maximum number of accepted steps
+    /// * 'h_0': initial step size
+    /// * 'fac': safety factor of the step size control
+    /// * 'fac_min': minimal factor the step size is decreased with
+    /// * 'fac_max': maximal factor the step size is increased with
+    /// * 'abs_tol': absolute tolerance of the local error estimate
+    /// * 'rel_tol': relative tolerance of the local error estimate
+    ///
+    /// # Panics
+    ///
+    /// if 'h_0' <= 0.0
+    pub fn new(n_max: u32, h_0: T, fac: T, fac_min: T, fac_max: T, abs_tol: T, rel_tol: T) -> MethodOfSteps<T>
+    {
+        if h_0 <= T::zero()
+        {
+            panic!("Initial step size has to be positive");
+        }
+
+        MethodOfSteps { n_max,
+                        h_0,
+                        fac,
+                        fac_min,
+                        fac_max,
+                        abs_tol,
+                        rel_tol }
+    }
+
+    /// Solves `prob` using the method of steps.
+    ///
+    /// # Arguments
+    ///
+    /// * 'prob' is a delay differential equation
+    /// * 'method' is the embedded explicit Runge-Kutta method
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// # Panics
+    ///
+    /// if t_span.0 > t_span.1. A DDE can not be integrated backward in time,
+    /// because the delayed states are only known in the past.
+    pub fn solve<F, M>(&self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: DelayODE<T>,
+              M: ExplicitRKEmbeddedMethod<T>
+    {
+        let t_span: (T, T) = prob.time_span();
+        let t_start: T = t_span.0;
+        let t_stop: T = t_span.1;
+
+        if t_start > t_stop
+        {
+            panic!("A DDE can not be integrated backward in time");
+        }
+
+        let tableau = method.tableau();
+        let (p, p_s): (u8, u8) = tableau.order();
+        let l: T = T::one() / (T::from_u8(p.min(p_s)) + T::one());
+
+        let x_0: Vector<T> = prob.init_cond();
+        let (m, _n): (usize, usize) = x_0.dim();
+        // The derivative at t_0 only depends on the delayed states with t - tau <= t_0
+        let f_0: Vector<T> = HistoryODE { prob,
+                                          history: &History::new(t_start, x_0.clone(), Vector::zero(m)) }.func(&t_start,
+                                                                                                               &x_0);
+        let mut history: History<T> = History::new(t_start, x_0, f_0);
+
+        // Discontinuities and their level, the derivative of order level + 1 jumps
+        let mut discontinuities: Vec<(T, u8)> = vec![(t_start, 0)];
+        // Level of the discontinuity the current step is shortened to
+        let mut pending: Option<u8> = None;
+
+        let mut h: T = self.h_0;
+        let mut n: u32 = 0;
+
+        while n < self.n_max && history.last().0 < t_stop
+        {
+            let (t_n, x_n, f_n): (T, Vector<T>, Vector<T>) = {
+                let (t_n, x_n, f_n) = history.last();
+                (t_n, x_n.clone(), f_n.clone())
+            };
+            h = h.min(t_stop - t_n);
+            let t_n1: T = t_n + h;
+
+            let (mut y_n, mut y_n_s): (Vector<T>, Vector<T>) =
+                tableau.do_step(&HistoryODE { prob,
+                                              history: &history },
+                                &t_n,
+                                &x_n,
+                                &h);
+            let mut f_y: Vector<T> = HistoryODE { prob,
+                                                  history: &history }.func(&t_n1, &y_n);
+
+            // The delayed states lie within the step
+            if prob.delays(&t_n1, &y_n).iter().any(|tau| t_n1 - *tau > t_n)
+            {
+                for _k in 0..MethodOfSteps::<T>::OVERLAP_ITERS
+                {
+                    history.push(t_n1, y_n.clone(), f_y);
+                    let ode: HistoryODE<T, F> = HistoryODE { prob,
+                                                             history: &history };
+                    let (y_k, y_k_s): (Vector<T>, Vector<T>) = tableau.do_step(&ode, &t_n, &x_n, &h);
+                    f_y = ode.func(&t_n1, &y_k);
+                    history.pop();
+
+                    let change: T = self.calc_error(&y_k, &y_n, &x_n);
+                    y_n = y_k;
+                    y_n_s = y_k_s;
+                    if change <= T::from_f64(0.01)
+                    {
+                        break;
+                    }
+                }
+            }
+
+            let err: T = self.calc_error(&y_n, &y_n_s, &x_n);
+
+            if err <= T::one()
+            {
+                let tol: T = T::from_f64(100.0 * f64::EPSILON) * (t_n.abs() + h.abs());
+
+                let mut level: Option<u8> = pending.take();
+                if let Some((xi, xi_level)) =
+                    MethodOfSteps::locate_discontinuity(prob, &discontinuities, p, tol, (t_n, &x_n, &f_n), (t_n1, &y_n, &f_y))
+                {
+                    if xi < t_n1 - tol
+                    {
+                        // Repeat the step, such that it ends at the discontinuity
+                        h = xi - t_n;
+                        pending = Some(xi_level);
+                        continue;
+                    }
+                    level = Some(level.map_or(xi_level, |l| l.min(xi_level)));
+                }
+
+                if let Some(level) = level
+                {
+                    if discontinuities.iter().all(|(xi, _)| (*xi - t_n1).abs() > tol)
+                    {
+                        discontinuities.push((t_n1, level + 1));
+                    }
+                }
+
+                history.push(t_n1, y_n, f_y);
+                n += 1;
+            }
+            else
+            {
+                pending = None;
+            }
+
+            if err != T::zero()
+            {
+                let s: T = (self.fac * (T::one() / err).pow(l)).max(self.fac_min).min(self.fac_max);
+                h = s * h;
+            }
+            else
+            {
+                h = self.fac_max * h;
+            }
+        }
+
+        if history.last().0 < t_stop
+        {
+            return Err("Maximum number of iterations reached");
+        }
+
+        Ok(history.into_solution())
+    }
+}
+
+impl<T> MethodOfSteps<T>
+    where T: Real
+{
+    /// Maximum number of fixed-point iterations, if the delayed states lie
+    /// within the step
+    const OVERLAP_ITERS: u32 = 10;
+
+    /// Returns the first point in the step from $t_n$ to $t_{n + 1}$, where
+    /// $t - \tau_i(t, x(t))$ crosses a discontinuity of level less than
+    /// `max_level`, together with the level of the crossed discontinuity.
+    fn locate_discontinuity<F>(prob: &F,
+                               discontinuities: &[(T, u8)],
+                               max_level: u8,
+                               tol: T,
+                               start: (T, &Vector<T>, &Vector<T>),
+                               end: (T, &Vector<T>, &Vector<T>))
+                               -> Option<(T, u8)>
+        where F: DelayODE<T>
+    {
+        let (t_n, x_n, f_n) = start;
+        let (t_n1, x_n1, f_n1) = end;
+
+        let delays_n: Vec<T> = prob.delays(&t_n, x_n);
+        let delays_n1: Vec<T> = prob.delays(&t_n1, x_n1);
+
+        let mut first: Option<(T, u8)> = None;
+
+        for (xi, level) in discontinuities.iter().filter(|(_, level)| *level < max_level)
+        {
+            for i in 0..delays_n.len()
+            {
+                if t_n - delays_n[i] >= *xi || t_n1 - delays_n1[i] <= *xi
+                {
+                    continue;
+                }
+
+                // Bisection on the Hermite polynomial of the step
+                let g = |t: &T| -> T {
+                    let x: Vector<T> = History::hermite(t_n, x_n, f_n, t_n1, x_n1, f_n1, t);
+                    *t - prob.delays(t, &x)[i] - *xi
+                };
+
+                let mut a: T = t_n;
+                let mut b: T = t_n1;
+                let mut k: u32 = 0;
+                while b - a > tol && k < 100
+                {
+                    let c: T = (a + b) / T::from_f64(2.0);
+                    if g(&c) < T::zero()
+                    {
+                        a = c;
+                    }
+                    else
+                    {
+                        b = c;
+                    }
+                    k += 1;
+                }
+
+                let earlier: bool = match first
+                {
+                    Some((root, _)) => b < root,
+                    None => true,
+                };
+
+                if b - t_n > tol && earlier
+                {
+                    first = Some((b, *level));
+                }
+            }
+        }
+
+        first
+    }
+
+    fn calc_error(&self, y: &Vector<T>, y_h: &Vector<T>, y_p: &Vector<T>) -> T
+    {
+        let (m, _n) = y.dim();
+
+        let mut sum: T = T::zero();
+
+        for i in 0..m
+        {
+            let y_max_i: T = y[i].abs().max(y_p[i].abs());
+            let sc_i: T = self.abs_tol + y_max_i * self.rel_tol;
+
+            let k: T = (y[i] - y_h[i]) / sc_i;
+            sum += k * k;
+        }
+
+        (sum / T::from_f64(m as f64)).sqrt()
+    }
+}
@@ -0,0 +1,5 @@
+//! This module provides algorithms to solve delay differential equations.
+mod history;
+mod methodofsteps;
+
+pub use methodofsteps::MethodOfSteps;
@@ -0,0 +1,151 @@
+//! Consistent initial values of differential-algebraic equations
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{differential_equation::differential_algebraic::ImplicitDAE, Function, Jacobian, NewtonRaphson},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Computes consistent initial values of an index-1 DAE
+///
+/// The differential variables of $x_0$ are kept fixed. The algebraic
+/// variables of $x_0$ and the derivatives of the differential variables
+/// $x_0^{'}$ are computed, such that
+/// ```math
+/// F(t_0, x_0, x_0^{'}) = 0
+/// ```
+/// The derivatives of the algebraic variables are set to zero.
+///
+/// P. N. Brown, A. C. Hindmarsh, L. R. Petzold, Consistent initial condition
+/// calculation for differential-algebraic systems, SIAM J. Sci. Comput. 19
+/// (1998)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct ConsistentInitialization<T>
+{
+    root_finder: NewtonRaphson<T>,
+}
+
+impl<T> Default for ConsistentInitialization<T>
+    where T: Real
+{
+    fn default() -> ConsistentInitialization<T>
+    {
+        ConsistentInitialization::new(100, T::from_f64(10e-12))
+    }
+}
+
+impl<T> ConsistentInitialization<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'iters': maximum number of Newton iterations
+    /// * 'tolerance_abs': absolute tolerance of the Newton iteration
+    pub fn new(iters: u64, tolerance_abs: T) -> ConsistentInitialization<T>
+    {
+        ConsistentInitialization { root_finder: NewtonRaphson::new(iters, tolerance_abs) }
+    }
+
+    /// Computes consistent initial values $(x_0, x_0^{'})$ of `prob`
+    pub fn compute<F>(&self, prob: &F) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where F: ImplicitDAE<T>
+    {
+        let (t_0, _t_1): (T, T) = prob.time_span();
+        let (x_0, x_dot_0): (Vector<T>, Vector<T>) = prob.init_cond();
+        let differential: Vec<bool> = prob.differential_variables();
+
+        let helper = ConsistentInitializationHelper { prob,
+                                                      t: &t_0,
+                                                      x: &x_0,
+                                                      differential: &differential };
+
+        let (m, _n): (usize, usize) = x_0.dim();
+        let mut z_0: Vector<T> = Vector::zero(m);
+        for i in 0..m
+        {
+            z_0[i] = if differential[i] { x_dot_0[i] } else { x_0[i] };
+        }
+
+        let z: Vector<T> = self.root_finder.find_root(&helper, &z_0)?;
+
+        Ok(helper.split(&z))
+    }
+}
+
+/// The unknowns $z$ are the derivatives of the differential variables and the
+/// values of the algebraic variables.
+struct ConsistentInitializationHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    prob: &'a F,
+    t: &'a T,
+    x: &'a Vector<T>,
+    differential: &'a [bool],
+}
+
+impl<'a, T, F> ConsistentInitializationHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    fn split(&self, z: &Vector<T>) -> (Vector<T>, Vector<T>)
+    {
+        let mut x: Vector<T> = self.x.clone();
+        let (m, _n): (usize, usize) = x.dim();
+        let mut x_dot: Vector<T> = Vector::zero(m);
+
+        for i in 0..m
+        {
+            if self.differential[i]
+            {
+                x_dot[i] = z[i];
+            }
+            else
+            {
+                x[i] = z[i];
+            }
+        }
+
+        (x, x_dot)
+    }
+}
+
+impl<'a, T, F> Function<Vector<T>> for ConsistentInitializationHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, z: &Vector<T>) -> Vector<T>
+    {
+        let (x, x_dot): (Vector<T>, Vector<T>) = self.split(z);
+        self.prob.residual(self.t, &x, &x_dot)
+    }
+}
+
+impl<'a, T, F> Jacobian<T> for ConsistentInitializationHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    fn jacobian(&self, z: &Vector<T>) -> Matrix<T>
+    {
+        let (x, x_dot): (Vector<T>, Vector<T>) = self.split(z);
+        let j_x: Matrix<T> = self.prob.jacobian_x(self.t, &x, &x_dot);
+        let j_x_dot: Matrix<T> = self.prob.jacobian_x_dot(self.t, &x, &x_dot);
+
+        let (m, _n): (usize, usize) = x.dim();
+        let mut jacobian: Matrix<T> = Matrix::zero(m, m);
+        for j in 0..m
+        {
+            let column: Vector<T> = if self.differential[j] { j_x_dot.get_column(j) } else { j_x.get_column(j) };
+            jacobian.set_column(&column, j);
+        }
+
+        jacobian
+    }
+}
@@ -0,0 +1,35 @@
+//! Implicit differential-algebraic equation
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+
+/// Fully implicit differential-algebraic equation
+///
+/// ```math
+/// 0 = F(t, x, x^{'})
+/// ```
+pub trait ImplicitDAE<T>
+    where T: Real
+{
+    /// Residual $F(t, x, x^{'})$
+    fn residual(&self, t: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Vector<T>;
+
+    /// Jacobian $\frac{\partial F}{\partial x}$
+    fn jacobian_x(&self, t: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Matrix<T>;
+
+    /// Jacobian $\frac{\partial F}{\partial x^{'}}$
+    fn jacobian_x_dot(&self, t: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Matrix<T>;
+
+    fn time_span(&self) -> (T, T);
+
+    /// Initial values $x(t_0)$ and an initial guess for $x^{'}(t_0)$
+    ///
+    /// The values of the algebraic variables and the derivatives of the
+    /// differential variables need not be consistent.
+    fn init_cond(&self) -> (Vector<T>, Vector<T>);
+
+    /// Flags the differential variables with `true` and the algebraic variables
+    /// with `false`.
+    fn differential_variables(&self) -> Vec<bool>;
+}
@@ -0,0 +1,73 @@
+//! Differential-algebraic equation in mass matrix form
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+use crate::analysis::differential_equation::differential_algebraic::ImplicitDAE;
+
+/// Differential-algebraic equation in mass matrix form
+///
+/// ```math
+/// M(t)x^{'} = f(t, x)
+/// ```
+///
+/// The mass matrix $M$ may be singular. A variable whose column in $M$ is
+/// zero is an algebraic variable, all the others are differential variables.
+pub trait MassMatrixDAE<T>
+    where T: Real
+{
+    /// Mass matrix $M(t)$
+    fn mass(&self, t: &T) -> Matrix<T>;
+
+    /// Right-hand side $f(t, x)$
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>;
+
+    /// Jacobian $\frac{\partial f}{\partial x}$
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>;
+
+    fn time_span(&self) -> (T, T);
+
+    fn init_cond(&self) -> Vector<T>;
+}
+
+impl<T, D> ImplicitDAE<T> for D
+    where T: Real,
+          D: MassMatrixDAE<T>
+{
+    /// $F(t, x, x^{'}) = M(t)x^{'} - f(t, x)$
+    fn residual(&self, t: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Vector<T>
+    {
+        &(&self.mass(t) * x_dot) - &self.func(t, x)
+    }
+
+    fn jacobian_x(&self, t: &T, x: &Vector<T>, _x_dot: &Vector<T>) -> Matrix<T>
+    {
+        self.jacobian(t, x) * -T::one()
+    }
+
+    fn jacobian_x_dot(&self, t: &T, _x: &Vector<T>, _x_dot: &Vector<T>) -> Matrix<T>
+    {
+        self.mass(t)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        MassMatrixDAE::time_span(self)
+    }
+
+    fn init_cond(&self) -> (Vector<T>, Vector<T>)
+    {
+        let x_0: Vector<T> = MassMatrixDAE::init_cond(self);
+        let (m, _n): (usize, usize) = x_0.dim();
+        (x_0, Vector::zero(m))
+    }
+
+    fn differential_variables(&self) -> Vec<bool>
+    {
+        let (t_0, _t_1): (T, T) = MassMatrixDAE::time_span(self);
+        let mass: Matrix<T> = self.mass(&t_0);
+        let (m, n): (usize, usize) = mass.dim();
+
+        (0..n).map(|j| (0..m).any(|i| mass[[i, j]] != T::zero())).collect()
+    }
+}
@@ -0,0 +1,85 @@
+//! Differential-algebraic equations
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Differential-algebraic_system_of_equations>
+//!
+//! A differential-algebraic equation (DAE) couples differential equations with
+//! algebraic constraints. Two forms are supported:
+//!
+//! The fully implicit form
+//! ```math
+//! 0 = F(t, x, x^{'})
+//! ```
+//! described by [`ImplicitDAE`] and the mass matrix form
+//! ```math
+//! M(t)x^{'} = f(t, x)
+//! ```
+//! described by [`MassMatrixDAE`], where $M$ may be singular. Every mass matrix
+//! DAE is also an implicit DAE with the residual $F(t, x, x^{'}) = M(t)x^{'} -
+//! f(t, x)$.
+//!
+//! The solvers support DAEs of index one. Before the integration starts, the
+//! initial values are made consistent with [`ConsistentInitialization`].
+//!
+//! # Example
+//!
+//! ```math
+//! x_{1}^{'} = -x_{1} + x_{2} \\
+//! 0 = x_{2} - 2x_{1}
+//! ```
+//!
+//! ```
+//! # #[macro_use]
+//! # extern crate mathru;
+//! # fn main()
+//! # {
+//! use mathru::{
+//!     algebra::linear::{Matrix, Vector},
+//!     analysis::differential_equation::differential_algebraic::{MassMatrixDAE, solver::RadauIIA},
+//! };
+//!
+//! pub struct Example;
+//!
+//! impl MassMatrixDAE<f64> for Example
+//! {
+//!     fn mass(&self, _t: &f64) -> Matrix<f64>
+//!     {
+//!         matrix![1.0, 0.0; 0.0, 0.0]
+//!     }
+//!
+//!     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+//!     {
+//!         vector![-x[0] + x[1]; x[1] - 2.0 * x[0]]
+//!     }
+//!
+//!     fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+//!     {
+//!         matrix![-1.0, 1.0; -2.0, 1.0]
+//!     }
+//!
+//!     fn time_span(&self) -> (f64, f64)
+//!     {
+//!         (0.0, 1.0)
+//!     }
+//!
+//!     fn init_cond(&self) -> Vector<f64>
+//!     {
+//!         // The algebraic variable is inconsistent, it is corrected by the solver
+//!         vector![1.0; 0.0]
+//!     }
+//! }
+//!
+//! let solver: RadauIIA<f64> = RadauIIA::new(0.01);
+//!
+//! let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Example).unwrap();
+//! # }
+//! ```
+
+mod implicit_dae;
+mod mass_matrix_dae;
+mod consistent_initialization;
+pub mod solver;
+
+pub use implicit_dae::ImplicitDAE;
+pub use mass_matrix_dae::MassMatrixDAE;
+pub use consistent_initialization::ConsistentInitialization;
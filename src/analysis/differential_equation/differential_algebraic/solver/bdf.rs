@@ -0,0 +1,189 @@
+//! Solves a DAE using backward differentiation formulas
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::differential_algebraic::{ConsistentInitialization, ImplicitDAE},
+        Function, Jacobian, NewtonRaphson,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+use super::directed::Directed;
+
+/// Backward differentiation formula for differential-algebraic equations
+///
+/// The derivative $x^{'}_{n+1}$ is replaced by the BDF approximation of order
+/// $k$
+/// ```math
+/// h x^{'}_{n+1} = \sum_{j=0}^{k} \alpha_{j} x_{n+1-j}
+/// ```
+/// and the resulting nonlinear equation $F(t_{n+1}, x_{n+1}, x^{'}_{n+1}) = 0$
+/// is solved with Newton's method. The first steps are done with lower
+/// orders, until enough previous values are available.
+///
+/// <https://en.wikipedia.org/wiki/Backward_differentiation_formula>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct BDF<T>
+{
+    k: u8,
+    step_size: T,
+    root_finder: NewtonRaphson<T>,
+    initialization: ConsistentInitialization<T>,
+}
+
+impl<T> BDF<T> where T: Real
+{
+    /// Creates a BDF instance of order `k`
+    ///
+    /// # Panics
+    ///
+    /// if k == 0 || k > 6 or step_size <= 0.0
+    pub fn new(k: u8, step_size: T) -> BDF<T>
+    {
+        if k == 0 || k > 6
+        {
+            panic!("Order k is not within the limits 1 <= k <= 6");
+        }
+        if step_size <= T::zero()
+        {
+            panic!("Step size has to be positive");
+        }
+
+        BDF { k,
+              step_size,
+              root_finder: NewtonRaphson::new(100, T::from_f64(10e-12)),
+              initialization: ConsistentInitialization::default() }
+    }
+
+    pub fn get_step_size(&self) -> &T
+    {
+        &self.step_size
+    }
+
+    pub fn set_step_size(&mut self, step_size: T)
+    {
+        self.step_size = step_size;
+    }
+
+    /// Solves `prob` with consistent initial values
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// The problem is integrated backward in time if the time span is
+    /// decreasing.
+    pub fn solve<F>(&self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: ImplicitDAE<T>
+    {
+        let (x_0, _x_dot_0): (Vector<T>, Vector<T>) = self.initialization.compute(prob)?;
+
+        let prob: &Directed<T, F> = &Directed::new(prob);
+        let (t_start, t_stop): (T, T) = prob.time_span();
+
+        let limit = ((t_stop - t_start) / self.step_size).ceil() + T::one();
+        let steps: usize = limit.to_u64() as usize;
+        let mut t_vec: Vec<T> = Vec::with_capacity(steps);
+        let mut res_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
+
+        let mut t_n: T = t_start;
+        t_vec.push(t_n);
+        res_vec.push(x_0);
+
+        while (t_n - t_stop).abs() > T::from_f64(0.0000000001)
+        {
+            let h: T = self.step_size.min(t_stop - t_n);
+            // A shortened last step invalidates the constant step size formula
+            let k: usize = if h < self.step_size { 1 } else { res_vec.len().min(self.k as usize) };
+            let alpha: Vec<T> = BDF::coefficients(k);
+
+            // Contribution of the previous values to the derivative
+            let x_prev: Vector<T> = alpha[1..].iter()
+                                              .zip(res_vec.iter().rev())
+                                              .fold(Vector::zero(res_vec[0].dim().0), |s, (a_j, x_j)| s + x_j * a_j);
+
+            t_n += h;
+            let helper = BDFHelper { prob,
+                                     t: &t_n,
+                                     x_prev: &x_prev,
+                                     alpha_0: alpha[0],
+                                     h };
+
+            let x_n: Vector<T> = self.root_finder.find_root(&helper, res_vec.last().unwrap())?;
+
+            t_vec.push(t_n);
+            res_vec.push(x_n);
+        }
+
+        Ok((prob.times(t_vec), res_vec))
+    }
+
+    /// Coefficients $\alpha_0, \dots, \alpha_k$ of the BDF of order k
+    fn coefficients(k: usize) -> Vec<T>
+    {
+        let alpha: Vec<f64> = match k
+        {
+            1 => vec![1.0, -1.0],
+            2 => vec![3.0 / 2.0, -2.0, 1.0 / 2.0],
+            3 => vec![11.0 / 6.0, -3.0, 3.0 / 2.0, -1.0 / 3.0],
+            4 => vec![25.0 / 12.0, -4.0, 3.0, -4.0 / 3.0, 1.0 / 4.0],
+            5 => vec![137.0 / 60.0, -5.0, 5.0, -10.0 / 3.0, 5.0 / 4.0, -1.0 / 5.0],
+            6 => vec![147.0 / 60.0, -6.0, 15.0 / 2.0, -20.0 / 3.0, 15.0 / 4.0, -6.0 / 5.0, 1.0 / 6.0],
+            _ => panic!(),
+        };
+
+        alpha.iter().map(|a| T::from_f64(*a)).collect()
+    }
+}
+
+struct BDFHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    prob: &'a Directed<'a, T, F>,
+    t: &'a T,
+    x_prev: &'a Vector<T>,
+    alpha_0: T,
+    h: T,
+}
+
+impl<'a, T, F> BDFHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    fn x_dot(&self, x: &Vector<T>) -> Vector<T>
+    {
+        (x * &self.alpha_0 + self.x_prev.clone()) / self.h
+    }
+}
+
+impl<'a, T, F> Function<Vector<T>> for BDFHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        self.prob.residual(self.t, x, &self.x_dot(x))
+    }
+}
+
+impl<'a, T, F> Jacobian<T> for BDFHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    /// $\frac{\partial F}{\partial x} + \frac{\alpha_0}{h}\frac{\partial F}{\partial x^{'}}$
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        let x_dot: Vector<T> = self.x_dot(x);
+        self.prob.jacobian_x(self.t, x, &x_dot) + self.prob.jacobian_x_dot(self.t, x, &x_dot) * (self.alpha_0 / self.h)
+    }
+}
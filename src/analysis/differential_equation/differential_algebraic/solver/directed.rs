@@ -0,0 +1,78 @@
+//! Integration of a DAE in the direction of the time span
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differential_equation::differential_algebraic::ImplicitDAE,
+};
+
+/// Wraps a DAE such that it is integrated forward in time
+///
+/// If the time span is decreasing, the DAE is transformed with $s = -t$
+/// ```math
+/// 0 = F(-s, x, -\frac{d x}{d s})
+/// ```
+/// and is integrated over the increasing span $\lbrack -t_0, -t_f \rbrack$.
+/// The wrapper does not implement [`ImplicitDAE`], because it would conflict
+/// with the implementation for mass matrix DAEs.
+pub(super) struct Directed<'a, T, F>
+{
+    problem: &'a F,
+    /// +1 for forward, -1 for backward integration
+    direction: T,
+}
+
+impl<'a, T, F> Directed<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    pub fn new(problem: &'a F) -> Directed<'a, T, F>
+    {
+        let (t_0, t_f): (T, T) = problem.time_span();
+        let direction: T = if t_f < t_0 { -T::one() } else { T::one() };
+
+        Directed { problem, direction }
+    }
+
+    /// Maps the internal time s to the time t and vice versa
+    fn time(&self, s: T) -> T
+    {
+        self.direction * s
+    }
+
+    /// Maps the internal time points back to the time
+    pub fn times(&self, s: Vec<T>) -> Vec<T>
+    {
+        s.into_iter().map(|s_i| self.time(s_i)).collect()
+    }
+
+    /// Maps the derivative with respect to t to the derivative with respect
+    /// to s and vice versa
+    pub fn derivative(&self, x_dot: Vector<T>) -> Vector<T>
+    {
+        x_dot * self.direction
+    }
+
+    /// Internal time span, which is increasing
+    pub fn time_span(&self) -> (T, T)
+    {
+        let (t_0, t_f): (T, T) = self.problem.time_span();
+        (self.time(t_0), self.time(t_f))
+    }
+
+    pub fn residual(&self, s: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Vector<T>
+    {
+        self.problem.residual(&self.time(*s), x, &(x_dot * &self.direction))
+    }
+
+    pub fn jacobian_x(&self, s: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Matrix<T>
+    {
+        self.problem.jacobian_x(&self.time(*s), x, &(x_dot * &self.direction))
+    }
+
+    pub fn jacobian_x_dot(&self, s: &T, x: &Vector<T>, x_dot: &Vector<T>) -> Matrix<T>
+    {
+        self.problem.jacobian_x_dot(&self.time(*s), x, &(x_dot * &self.direction)) * self.direction
+    }
+}
@@ -0,0 +1,7 @@
+//! This module provides algorithms to solve differential-algebraic equations.
+mod bdf;
+mod directed;
+mod radauiia;
+
+pub use bdf::BDF;
+pub use radauiia::RadauIIA;
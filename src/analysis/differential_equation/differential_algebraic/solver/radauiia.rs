@@ -0,0 +1,218 @@
+//! Solves a DAE using the Radau IIA method
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::differential_algebraic::{ConsistentInitialization, ImplicitDAE},
+        Function, Jacobian, NewtonRaphson,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+use super::directed::Directed;
+
+/// Three stage Radau IIA method of order five
+///
+/// The stage derivatives $K_i$ are the solution of
+/// ```math
+/// 0 = F(t_n + c_i h, x_n + h \sum_{j=1}^{3} a_{ij} K_j, K_i), \quad i = 1, 2, 3
+/// ```
+/// and the new value is $x_{n+1} = x_n + h \sum_{j=1}^{3} a_{3j} K_j$. The
+/// method is stiffly accurate and L-stable, hence well suited for stiff
+/// problems and DAEs of index one.
+///
+/// Solving Ordinary Differential Equations II
+/// Stiff and Differential-Algebraic Problems
+/// E. Hairer, G. Wanner
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct RadauIIA<T>
+{
+    step_size: T,
+    a: Vec<Vec<T>>,
+    c: Vec<T>,
+    root_finder: NewtonRaphson<T>,
+    initialization: ConsistentInitialization<T>,
+}
+
+impl<T> RadauIIA<T> where T: Real
+{
+    /// Creates a Radau IIA instance with the given step size
+    ///
+    /// # Panics
+    ///
+    /// if step_size <= 0.0
+    pub fn new(step_size: T) -> RadauIIA<T>
+    {
+        if step_size <= T::zero()
+        {
+            panic!("Step size has to be positive");
+        }
+
+        let sqrt_6: f64 = 6.0f64.sqrt();
+        let a: Vec<Vec<f64>> = vec![vec![(88.0 - 7.0 * sqrt_6) / 360.0,
+                                         (296.0 - 169.0 * sqrt_6) / 1800.0,
+                                         (-2.0 + 3.0 * sqrt_6) / 225.0],
+                                    vec![(296.0 + 169.0 * sqrt_6) / 1800.0,
+                                         (88.0 + 7.0 * sqrt_6) / 360.0,
+                                         (-2.0 - 3.0 * sqrt_6) / 225.0],
+                                    vec![(16.0 - sqrt_6) / 36.0, (16.0 + sqrt_6) / 36.0, 1.0 / 9.0]];
+        let c: Vec<f64> = vec![(4.0 - sqrt_6) / 10.0, (4.0 + sqrt_6) / 10.0, 1.0];
+
+        RadauIIA { step_size,
+                   a: a.iter().map(|a_i| a_i.iter().map(|a_ij| T::from_f64(*a_ij)).collect()).collect(),
+                   c: c.iter().map(|c_i| T::from_f64(*c_i)).collect(),
+                   root_finder: NewtonRaphson::new(100, T::from_f64(10e-12)),
+                   initialization: ConsistentInitialization::default() }
+    }
+
+    pub fn get_step_size(&self) -> &T
+    {
+        &self.step_size
+    }
+
+    pub fn set_step_size(&mut self, step_size: T)
+    {
+        self.step_size = step_size;
+    }
+
+    /// Solves `prob` with consistent initial values
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// The problem is integrated backward in time if the time span is
+    /// decreasing.
+    pub fn solve<F>(&self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: ImplicitDAE<T>
+    {
+        let (mut x_n, x_dot_0): (Vector<T>, Vector<T>) = self.initialization.compute(prob)?;
+
+        let prob: &Directed<T, F> = &Directed::new(prob);
+        let (t_start, t_stop): (T, T) = prob.time_span();
+        let mut x_dot_n: Vector<T> = prob.derivative(x_dot_0);
+        let (m, _n): (usize, usize) = x_n.dim();
+
+        let limit = ((t_stop - t_start) / self.step_size).ceil() + T::one();
+        let steps: usize = limit.to_u64() as usize;
+        let mut t_vec: Vec<T> = Vec::with_capacity(steps);
+        let mut res_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
+
+        let mut t_n: T = t_start;
+        t_vec.push(t_n);
+        res_vec.push(x_n.clone());
+
+        while (t_n - t_stop).abs() > T::from_f64(0.0000000001)
+        {
+            let h: T = self.step_size.min(t_stop - t_n);
+
+            let helper = RadauIIAHelper { prob,
+                                          method: self,
+                                          t: t_n,
+                                          x: &x_n,
+                                          h };
+
+            // The derivative of the previous step is the initial guess of all stages
+            let mut k_0: Vector<T> = Vector::zero(3 * m);
+            for i in 0..3
+            {
+                k_0.set_slice(&x_dot_n, i * m);
+            }
+
+            let k: Vector<T> = self.root_finder.find_root(&helper, &k_0)?;
+
+            x_n = helper.stage(&k, 2);
+            x_dot_n = k.get_slice(2 * m, 3 * m - 1);
+            t_n += h;
+
+            t_vec.push(t_n);
+            res_vec.push(x_n.clone());
+        }
+
+        Ok((prob.times(t_vec), res_vec))
+    }
+}
+
+struct RadauIIAHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    prob: &'a Directed<'a, T, F>,
+    method: &'a RadauIIA<T>,
+    t: T,
+    x: &'a Vector<T>,
+    h: T,
+}
+
+impl<'a, T, F> RadauIIAHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    /// $x_n + h \sum_{j} a_{ij} K_j$
+    fn stage(&self, k: &Vector<T>, i: usize) -> Vector<T>
+    {
+        let (m, _n): (usize, usize) = self.x.dim();
+        (0..3).fold(self.x.clone(), |x, j| x + k.get_slice(j * m, (j + 1) * m - 1) * (self.h * self.method.a[i][j]))
+    }
+}
+
+impl<'a, T, F> Function<Vector<T>> for RadauIIAHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, k: &Vector<T>) -> Vector<T>
+    {
+        let (m, _n): (usize, usize) = self.x.dim();
+        let mut res: Vector<T> = Vector::zero(3 * m);
+
+        for i in 0..3
+        {
+            let t_i: T = self.t + self.method.c[i] * self.h;
+            let k_i: Vector<T> = k.get_slice(i * m, (i + 1) * m - 1);
+            res.set_slice(&self.prob.residual(&t_i, &self.stage(k, i), &k_i), i * m);
+        }
+
+        res
+    }
+}
+
+impl<'a, T, F> Jacobian<T> for RadauIIAHelper<'a, T, F>
+    where T: Real,
+          F: ImplicitDAE<T>
+{
+    /// Block $(i, j)$ is $\delta_{ij}\frac{\partial F}{\partial x^{'}} + h a_{ij} \frac{\partial F}{\partial x}$
+    fn jacobian(&self, k: &Vector<T>) -> Matrix<T>
+    {
+        let (m, _n): (usize, usize) = self.x.dim();
+        let mut jacobian: Matrix<T> = Matrix::zero(3 * m, 3 * m);
+
+        for i in 0..3
+        {
+            let t_i: T = self.t + self.method.c[i] * self.h;
+            let k_i: Vector<T> = k.get_slice(i * m, (i + 1) * m - 1);
+            let x_i: Vector<T> = self.stage(k, i);
+            let j_x: Matrix<T> = self.prob.jacobian_x(&t_i, &x_i, &k_i);
+            let j_x_dot: Matrix<T> = self.prob.jacobian_x_dot(&t_i, &x_i, &k_i);
+
+            for j in 0..3
+            {
+                let mut block: Matrix<T> = &j_x * &(self.h * self.method.a[i][j]);
+                if i == j
+                {
+                    block += j_x_dot.clone();
+                }
+                jacobian = jacobian.set_slice(&block, i * m, j * m);
+            }
+        }
+
+        jacobian
+    }
+}
@@ -1 +1,6 @@
-pub mod ordinary;
\ No newline at end of file
+pub mod ordinary;
+pub mod differential_algebraic;
+pub mod boundary_value;
+pub mod delay_differential;
+pub mod sde;
+pub mod partial;
@@ -0,0 +1,180 @@
+//! Construction of ODEs from closures
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+use crate::analysis::{
+    differential_equation::ordinary::{ExplicitODE, ImplicitODE},
+    differentiation::{DifferenceScheme, FiniteDifference},
+};
+
+type Func<'a, T> = Box<dyn Fn(&T, &Vector<T>) -> Vector<T> + 'a>;
+type Jacobian<'a, T> = Box<dyn Fn(&T, &Vector<T>) -> Matrix<T> + 'a>;
+
+/// Builder for ODEs defined by closures
+///
+/// The right-hand side $f(t, x)$ is mandatory, the Jacobian
+/// $\frac{\partial f}{\partial x}$ is optional. If no Jacobian is given, it
+/// is approximated with forward differences.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differential_equation::ordinary::{
+///         solver::runge_kutta::{DormandPrince54, ImplicitEuler, ProportionalControl},
+///         ClosureODE, ODEBuilder,
+///     },
+/// };
+///
+/// let a: f64 = -2.0;
+///
+/// let problem: ClosureODE<f64> = ODEBuilder::new(move |_t: &f64, x: &Vector<f64>| x * &a)
+///     .jacobian(move |_t: &f64, _x: &Vector<f64>| matrix![a])
+///     .time_span(0.0, 1.0)
+///     .init_cond(vector![1.0])
+///     .build();
+///
+/// let solver: ProportionalControl<f64> = ProportionalControl::new(1000, 0.01, 0.9, 0.01, 2.0, 10e-10, 10e-8);
+/// let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &DormandPrince54::default()).unwrap();
+/// assert_relative_eq!((-2.0f64).exp(), x.last().unwrap()[0], epsilon = 1.0e-7);
+///
+/// let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.0001).solve(&problem).unwrap();
+/// assert_relative_eq!((-2.0f64).exp(), x.last().unwrap()[0], epsilon = 1.0e-4);
+/// # }
+/// ```
+pub struct ODEBuilder<'a, T>
+{
+    func: Func<'a, T>,
+    jacobian: Option<Jacobian<'a, T>>,
+    time_span: Option<(T, T)>,
+    init_cond: Option<Vector<T>>,
+}
+
+impl<'a, T> ODEBuilder<'a, T>
+    where T: Real
+{
+    /// Starts the construction of the ODE $x^{'}(t) = f(t, x)$
+    pub fn new<F>(func: F) -> ODEBuilder<'a, T>
+        where F: Fn(&T, &Vector<T>) -> Vector<T> + 'a
+    {
+        ODEBuilder { func: Box::new(func),
+                     jacobian: None,
+                     time_span: None,
+                     init_cond: None }
+    }
+
+    /// Sets the Jacobian of the right-hand side with respect to x
+    pub fn jacobian<J>(mut self, jacobian: J) -> ODEBuilder<'a, T>
+        where J: Fn(&T, &Vector<T>) -> Matrix<T> + 'a
+    {
+        self.jacobian = Some(Box::new(jacobian));
+        self
+    }
+
+    pub fn time_span(mut self, t_start: T, t_stop: T) -> ODEBuilder<'a, T>
+    {
+        self.time_span = Some((t_start, t_stop));
+        self
+    }
+
+    pub fn init_cond(mut self, init_cond: Vector<T>) -> ODEBuilder<'a, T>
+    {
+        self.init_cond = Some(init_cond);
+        self
+    }
+
+    /// # Panics
+    ///
+    /// if the time span or the initial condition has not been set
+    pub fn build(self) -> ClosureODE<'a, T>
+    {
+        let time_span: (T, T) = self.time_span.expect("The time span has not been set");
+        let init_cond: Vector<T> = self.init_cond.expect("The initial condition has not been set");
+
+        ClosureODE { func: self.func,
+                     jacobian: self.jacobian,
+                     time_span,
+                     init_cond }
+    }
+}
+
+/// ODE defined by closures
+///
+/// Is created with [`ODEBuilder`] and can be solved with the explicit and
+/// the implicit solvers.
+pub struct ClosureODE<'a, T>
+{
+    func: Func<'a, T>,
+    jacobian: Option<Jacobian<'a, T>>,
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<'a, T> ClosureODE<'a, T>
+    where T: Real
+{
+    /// Returns true if the Jacobian is given analytically
+    pub fn has_jacobian(&self) -> bool
+    {
+        self.jacobian.is_some()
+    }
+
+    /// Approximates the Jacobian with forward differences
+    fn jacobian_fd(&self, t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        FiniteDifference::new(DifferenceScheme::Forward).jacobian(|x: &Vector<T>| (self.func)(t, x), x)
+    }
+}
+
+impl<'a, T> ExplicitODE<T> for ClosureODE<'a, T>
+    where T: Real
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        (self.func)(t, x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<'a, T> ImplicitODE<T> for ClosureODE<'a, T>
+    where T: Real
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        (self.func)(t, x)
+    }
+
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        match &self.jacobian
+        {
+            Some(jacobian) => jacobian(t, x),
+            None => self.jacobian_fd(t, x),
+        }
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
@@ -96,10 +96,19 @@
 
 pub mod solver;
 pub mod problem;
+pub mod sensitivity;
 
 mod explicit_ode;
 mod implicit_ode;
+mod separable_hamiltonian;
+mod parameterised_ode;
+mod resume;
+mod builder;
 
 pub use implicit_ode::ImplicitODE;
 pub use explicit_ode::ExplicitODE;
+pub use separable_hamiltonian::SeparableHamiltonian;
+pub use parameterised_ode::ParameterisedODE;
+pub use resume::Resume;
+pub use builder::{ClosureODE, ODEBuilder};
 
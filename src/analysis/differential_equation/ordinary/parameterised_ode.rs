@@ -0,0 +1,39 @@
+//! Parameterised ordinary differential equation
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+
+/// Ordinary differential equation depending on parameters
+///
+/// ```math
+/// x^{'}(t) = f(t, x(t), p), \quad x(t_0) = x_0(p)
+/// ```
+///
+/// This trait has to be implemented by every ODE whose sensitivities with
+/// respect to the parameters $p \in \mathbb{R}^{n_p}$ shall be computed.
+pub trait ParameterisedODE<T>
+    where T: Real
+{
+    fn func(&self, t: &T, x: &Vector<T>, p: &Vector<T>) -> Vector<T>;
+
+    /// Jacobian $\frac{\partial f}{\partial x} \in \mathbb{R}^{n \times n}$
+    fn jacobian_x(&self, t: &T, x: &Vector<T>, p: &Vector<T>) -> Matrix<T>;
+
+    /// Jacobian $\frac{\partial f}{\partial p} \in \mathbb{R}^{n \times n_p}$
+    fn jacobian_p(&self, t: &T, x: &Vector<T>, p: &Vector<T>) -> Matrix<T>;
+
+    fn time_span(&self) -> (T, T);
+
+    fn init_cond(&self, p: &Vector<T>) -> Vector<T>;
+
+    /// Jacobian of the initial condition $\frac{\partial x_0}{\partial p}$
+    ///
+    /// The initial condition is independent of the parameters by default.
+    fn init_cond_jacobian(&self, p: &Vector<T>) -> Matrix<T>
+    {
+        let (n, _): (usize, usize) = self.init_cond(p).dim();
+        let (n_p, _): (usize, usize) = p.dim();
+        Matrix::zero(n, n_p)
+    }
+}
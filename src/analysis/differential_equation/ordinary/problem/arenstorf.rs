@@ -0,0 +1,133 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Arenstorf orbit
+///
+/// Periodic orbit of a satellite in the restricted three body problem
+/// earth-moon-satellite. The state is $(y_{1}, y_{2}, y_{1}^{'}, y_{2}^{'})$
+/// ```math
+/// y_{1}^{''} = y_{1} + 2 y_{2}^{'} - \mu^{'} \frac{y_{1} + \mu}{D_{1}} - \mu \frac{y_{1} - \mu^{'}}{D_{2}} \\
+/// y_{2}^{''} = y_{2} - 2 y_{1}^{'} - \mu^{'} \frac{y_{2}}{D_{1}} - \mu \frac{y_{2}}{D_{2}} \\
+/// D_{1} = ((y_{1} + \mu)^{2} + y_{2}^{2})^{\frac{3}{2}}, \quad D_{2} = ((y_{1} - \mu^{'})^{2} + y_{2}^{2})^{\frac{3}{2}} \\
+/// ```
+///
+/// ```math
+/// \mu = 0.012277471, \quad \mu^{'} = 1 - \mu, \quad y(0) = (0.994, 0, 0, -2.00158510637908252240537862224)^{T}
+/// ```
+///
+/// The time span is one period $\lbrack 0, 17.0652165601579625588917206249 \rbrack$,
+/// hence the solution at the end of the time span equals the initial
+/// condition.
+///
+/// Hairer, E., Nørsett, S. P., Wanner, G.: Solving Ordinary Differential
+/// Equations I, Springer, 1993, Section II.0
+pub struct Arenstorf<T>
+{
+    mu: T,
+
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Arenstorf<T>
+    where T: Real
+{
+    fn default() -> Arenstorf<T>
+    {
+        Arenstorf { mu: T::from_f64(0.012277471),
+                    time_span: (T::zero(), T::from_f64(17.06521656015796)),
+                    init_cond: vector![T::from_f64(0.994); T::zero(); T::zero(); T::from_f64(-2.001_585_106_379_082_5)] }
+    }
+}
+
+impl<T> Arenstorf<T>
+    where T: Real
+{
+    /// Solution after one period
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+
+    fn rhs(&self, y: &Vector<T>) -> Vector<T>
+    {
+        let mu: T = self.mu;
+        let mu_s: T = T::one() - mu;
+        let two: T = T::from_f64(2.0);
+        let exp: T = T::from_f64(1.5);
+
+        let d_1: T = ((y[0] + mu) * (y[0] + mu) + y[1] * y[1]).pow(exp);
+        let d_2: T = ((y[0] - mu_s) * (y[0] - mu_s) + y[1] * y[1]).pow(exp);
+
+        vector![y[2];
+                y[3];
+                y[0] + two * y[3] - mu_s * (y[0] + mu) / d_1 - mu * (y[0] - mu_s) / d_2;
+                y[1] - two * y[2] - mu_s * y[1] / d_1 - mu * y[1] / d_2]
+    }
+}
+
+impl<T> ExplicitODE<T> for Arenstorf<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Arenstorf<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, y: &Vector<T>) -> Matrix<T>
+    {
+        let mu: T = self.mu;
+        let mu_s: T = T::one() - mu;
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+
+        // derivatives of the gravitational terms -m (y - c) / r^3
+        let gravity = |m: T, dx: T, dy: T| -> (T, T, T) {
+            let r_2: T = dx * dx + dy * dy;
+            let r_3: T = r_2 * r_2.sqrt();
+            let r_5: T = r_3 * r_2;
+            (m * (three * dx * dx / r_5 - T::one() / r_3),
+             m * three * dx * dy / r_5,
+             m * (three * dy * dy / r_5 - T::one() / r_3))
+        };
+        let (a_11, a_12, a_22): (T, T, T) = gravity(mu_s, y[0] + mu, y[1]);
+        let (b_11, b_12, b_22): (T, T, T) = gravity(mu, y[0] - mu_s, y[1]);
+
+        let z: T = T::zero();
+        let o: T = T::one();
+        matrix![z, z, o, z;
+                z, z, z, o;
+                o + a_11 + b_11, a_12 + b_12, z, two;
+                a_12 + b_12, o + a_22 + b_22, -two, z]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
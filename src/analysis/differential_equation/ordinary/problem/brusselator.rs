@@ -0,0 +1,111 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Brusselator
+///
+/// Model of an autocatalytic oscillating chemical reaction
+/// ```math
+/// x_{1}^{'}(t) = A + x_{1}^{2} x_{2} - (B + 1) x_{1} \\
+/// x_{2}^{'}(t) = B x_{1} - x_{1}^{2} x_{2} \\
+/// ```
+///
+/// ```math
+/// A = 1, \quad B = 3, \quad x(0) = (1.5, 3)^{T}, \quad t \in \lbrack 0, 20 \rbrack
+/// ```
+///
+/// Hairer, E., Nørsett, S. P., Wanner, G.: Solving Ordinary Differential
+/// Equations I, Springer, 1993, Section II.10
+pub struct Brusselator<T>
+{
+    a: T,
+    b: T,
+
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Brusselator<T>
+    where T: Real
+{
+    fn default() -> Brusselator<T>
+    {
+        Brusselator { a: T::one(),
+                      b: T::from_f64(3.0),
+                      time_span: (T::zero(), T::from_f64(20.0)),
+                      init_cond: vector![T::from_f64(1.5); T::from_f64(3.0)] }
+    }
+}
+
+impl<T> Brusselator<T>
+    where T: Real
+{
+    /// Brusselator with the parameters A and B
+    pub fn new(a: T, b: T, time_span: (T, T), init_cond: Vector<T>) -> Brusselator<T>
+    {
+        Brusselator { a,
+                      b,
+                      time_span,
+                      init_cond }
+    }
+
+    /// Reference solution at t = 20 for the default parameters
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        vector![T::from_f64(0.4986370712683478); T::from_f64(4.596780349452039)]
+    }
+
+    fn rhs(&self, x: &Vector<T>) -> Vector<T>
+    {
+        let x_1_x_1_x_2: T = x[0] * x[0] * x[1];
+
+        vector![self.a + x_1_x_1_x_2 - (self.b + T::one()) * x[0];
+                self.b * x[0] - x_1_x_1_x_2]
+    }
+}
+
+impl<T> ExplicitODE<T> for Brusselator<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Brusselator<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        let two: T = T::from_f64(2.0);
+
+        matrix![two * x[0] * x[1] - (self.b + T::one()), x[0] * x[0];
+                self.b - two * x[0] * x[1], -x[0] * x[0]]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
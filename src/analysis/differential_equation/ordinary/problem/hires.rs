@@ -0,0 +1,130 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// High irradiance responses of photomorphogenesis (HIRES)
+///
+/// Stiff system of eight equations, which describes the reaction of plants
+/// to light
+/// ```math
+/// x_{1}^{'} = -1.71 x_{1} + 0.43 x_{2} + 8.32 x_{3} + 0.0007 \\
+/// x_{2}^{'} = 1.71 x_{1} - 8.75 x_{2} \\
+/// x_{3}^{'} = -10.03 x_{3} + 0.43 x_{4} + 0.035 x_{5} \\
+/// x_{4}^{'} = 8.32 x_{2} + 1.71 x_{3} - 1.12 x_{4} \\
+/// x_{5}^{'} = -1.745 x_{5} + 0.43 x_{6} + 0.43 x_{7} \\
+/// x_{6}^{'} = -280 x_{6} x_{8} + 0.69 x_{4} + 1.71 x_{5} - 0.43 x_{6} + 0.69 x_{7} \\
+/// x_{7}^{'} = 280 x_{6} x_{8} - 1.81 x_{7} \\
+/// x_{8}^{'} = -280 x_{6} x_{8} + 1.81 x_{7} \\
+/// ```
+///
+/// ```math
+/// x(0) = (1, 0, 0, 0, 0, 0, 0, 0.0057)^{T}, \quad t \in \lbrack 0, 321.8122 \rbrack
+/// ```
+///
+/// Hairer, E., Wanner, G.: Solving Ordinary Differential Equations II,
+/// Springer, 1996, Section IV.10
+pub struct Hires<T>
+{
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Hires<T>
+    where T: Real
+{
+    fn default() -> Hires<T>
+    {
+        Hires { time_span: (T::zero(), T::from_f64(321.8122)),
+                init_cond: vector![T::one(); T::zero(); T::zero(); T::zero(); T::zero(); T::zero(); T::zero(); T::from_f64(0.0057)] }
+    }
+}
+
+/// Solution at t = 321.8122
+const REFERENCE: [f64; 8] = [0.7371312573325668e-3,
+                             0.1442485726316185e-3,
+                             0.5888729740967575e-4,
+                             0.1175651343283149e-2,
+                             0.2386356198831331e-2,
+                             0.6238968252742796e-2,
+                             0.2849998395185769e-2,
+                             0.2850001604814231e-2];
+
+impl<T> Hires<T>
+    where T: Real
+{
+    /// Reference solution at t = 321.8122
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        Vector::new_column(REFERENCE.iter().map(|x_i| T::from_f64(*x_i)).collect())
+    }
+
+    fn rhs(&self, x: &Vector<T>) -> Vector<T>
+    {
+        let c = |c: f64| -> T { T::from_f64(c) };
+        let r: T = c(280.0) * x[5] * x[7];
+
+        vector![c(-1.71) * x[0] + c(0.43) * x[1] + c(8.32) * x[2] + c(0.0007);
+                c(1.71) * x[0] - c(8.75) * x[1];
+                c(-10.03) * x[2] + c(0.43) * x[3] + c(0.035) * x[4];
+                c(8.32) * x[1] + c(1.71) * x[2] - c(1.12) * x[3];
+                c(-1.745) * x[4] + c(0.43) * x[5] + c(0.43) * x[6];
+                -r + c(0.69) * x[3] + c(1.71) * x[4] - c(0.43) * x[5] + c(0.69) * x[6];
+                r - c(1.81) * x[6];
+                -r + c(1.81) * x[6]]
+    }
+}
+
+impl<T> ExplicitODE<T> for Hires<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Hires<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        let c = |c: f64| -> T { T::from_f64(c) };
+        let z: T = T::zero();
+        let r_6: T = c(280.0) * x[7];
+        let r_8: T = c(280.0) * x[5];
+
+        matrix![c(-1.71), c(0.43), c(8.32), z, z, z, z, z;
+                c(1.71), c(-8.75), z, z, z, z, z, z;
+                z, z, c(-10.03), c(0.43), c(0.035), z, z, z;
+                z, c(8.32), c(1.71), c(-1.12), z, z, z, z;
+                z, z, z, z, c(-1.745), c(0.43), c(0.43), z;
+                z, z, z, c(0.69), c(1.71), -r_6 - c(0.43), c(0.69), -r_8;
+                z, z, z, z, z, r_6, c(-1.81), r_8;
+                z, z, z, z, z, -r_6, c(1.81), -r_8]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
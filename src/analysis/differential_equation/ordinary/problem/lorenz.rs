@@ -0,0 +1,117 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Lorenz system
+///
+/// Chaotic model of atmospheric convection
+/// ```math
+/// x_{1}^{'}(t) = \sigma (x_{2} - x_{1}) \\
+/// x_{2}^{'}(t) = x_{1} (\rho - x_{3}) - x_{2} \\
+/// x_{3}^{'}(t) = x_{1} x_{2} - \beta x_{3} \\
+/// ```
+///
+/// ```math
+/// \sigma = 10, \quad \rho = 28, \quad \beta = \frac{8}{3}, \quad x(0) = (-8, 8, 27)^{T}, \quad t \in \lbrack 0, 10 \rbrack
+/// ```
+///
+/// Small errors grow exponentially, hence the problem is well suited to
+/// compare the accuracy of solvers at tight tolerances.
+///
+/// Hairer, E., Nørsett, S. P., Wanner, G.: Solving Ordinary Differential
+/// Equations I, Springer, 1993, Section I.16
+pub struct Lorenz<T>
+{
+    sigma: T,
+    rho: T,
+    beta: T,
+
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Lorenz<T>
+    where T: Real
+{
+    fn default() -> Lorenz<T>
+    {
+        Lorenz { sigma: T::from_f64(10.0),
+                 rho: T::from_f64(28.0),
+                 beta: T::from_f64(8.0 / 3.0),
+                 time_span: (T::zero(), T::from_f64(10.0)),
+                 init_cond: vector![T::from_f64(-8.0); T::from_f64(8.0); T::from_f64(27.0)] }
+    }
+}
+
+impl<T> Lorenz<T>
+    where T: Real
+{
+    /// Lorenz system with the parameters sigma, rho and beta
+    pub fn new(sigma: T, rho: T, beta: T, time_span: (T, T), init_cond: Vector<T>) -> Lorenz<T>
+    {
+        Lorenz { sigma,
+                 rho,
+                 beta,
+                 time_span,
+                 init_cond }
+    }
+
+    /// Reference solution at t = 10 for the default parameters with an
+    /// accuracy of about $10^{-8}$
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        vector![T::from_f64(8.176101755); T::from_f64(12.18221560); T::from_f64(19.89126163)]
+    }
+
+    fn rhs(&self, x: &Vector<T>) -> Vector<T>
+    {
+        vector![self.sigma * (x[1] - x[0]);
+                x[0] * (self.rho - x[2]) - x[1];
+                x[0] * x[1] - self.beta * x[2]]
+    }
+}
+
+impl<T> ExplicitODE<T> for Lorenz<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Lorenz<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        matrix![-self.sigma, self.sigma, T::zero();
+                self.rho - x[2], -T::one(), -x[0];
+                x[1], x[0], -self.beta]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
@@ -1,5 +1,37 @@
+//! Reference problems
+//!
+//! The problems are standard test cases for ODE solvers. Most of them provide
+//! a reference solution at the end of the time span, which allows to compare
+//! the work and the precision of solvers.
+//!
+//! | Problem       | Dimension | Stiff |
+//! |---------------|-----------|-------|
+//! | Arenstorf     | 4         | no    |
+//! | Brusselator   | 2         | no    |
+//! | Euler         | 3         | no    |
+//! | Hires         | 8         | yes   |
+//! | Lorenz        | 3         | no    |
+//! | Oregonator    | 3         | yes   |
+//! | Pleiades      | 28        | no    |
+//! | Robertson     | 3         | yes   |
+//! | VanDerPolOsc  | 2         | no    |
+
+mod arenstorf;
+mod brusselator;
 mod euler;
+mod hires;
+mod lorenz;
+mod oregonator;
+mod pleiades;
+mod robertson;
 mod van_der_pol_osc;
 
+pub use arenstorf::Arenstorf;
+pub use brusselator::Brusselator;
 pub use euler::Euler;
-pub use van_der_pol_osc::VanDerPolOsc;
\ No newline at end of file
+pub use hires::Hires;
+pub use lorenz::Lorenz;
+pub use oregonator::Oregonator;
+pub use pleiades::Pleiades;
+pub use robertson::Robertson;
+pub use van_der_pol_osc::VanDerPolOsc;
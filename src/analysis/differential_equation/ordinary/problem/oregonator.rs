@@ -0,0 +1,105 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Oregonator
+///
+/// Stiff model of the Belousov-Zhabotinsky reaction with a periodic solution
+/// ```math
+/// x_{1}^{'}(t) = 77.27 (x_{2} + x_{1} (1 - 8.375 \cdot 10^{-6} x_{1} - x_{2})) \\
+/// x_{2}^{'}(t) = \frac{1}{77.27} (x_{3} - (1 + x_{1}) x_{2}) \\
+/// x_{3}^{'}(t) = 0.161 (x_{1} - x_{3}) \\
+/// ```
+///
+/// ```math
+/// x(0) = (1, 2, 3)^{T}, \quad t \in \lbrack 0, 360 \rbrack
+/// ```
+///
+/// Hairer, E., Wanner, G.: Solving Ordinary Differential Equations II,
+/// Springer, 1996, Section IV.10
+pub struct Oregonator<T>
+{
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Oregonator<T>
+    where T: Real
+{
+    fn default() -> Oregonator<T>
+    {
+        Oregonator { time_span: (T::zero(), T::from_f64(360.0)),
+                     init_cond: vector![T::one(); T::from_f64(2.0); T::from_f64(3.0)] }
+    }
+}
+
+impl<T> Oregonator<T>
+    where T: Real
+{
+    /// Reference solution at t = 360
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        vector![T::from_f64(1.000814870318523); T::from_f64(1228.178521549917); T::from_f64(132.0554942846706)]
+    }
+
+    fn rhs(&self, x: &Vector<T>) -> Vector<T>
+    {
+        let s: T = T::from_f64(77.27);
+        let q: T = T::from_f64(8.375e-6);
+        let w: T = T::from_f64(0.161);
+
+        vector![s * (x[1] + x[0] * (T::one() - q * x[0] - x[1]));
+                (x[2] - (T::one() + x[0]) * x[1]) / s;
+                w * (x[0] - x[2])]
+    }
+}
+
+impl<T> ExplicitODE<T> for Oregonator<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Oregonator<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        let s: T = T::from_f64(77.27);
+        let q: T = T::from_f64(8.375e-6);
+        let w: T = T::from_f64(0.161);
+        let two: T = T::from_f64(2.0);
+
+        matrix![s * (T::one() - two * q * x[0] - x[1]), s * (T::one() - x[0]), T::zero();
+                -x[1] / s, -(T::one() + x[0]) / s, T::one() / s;
+                w, T::zero(), -w]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
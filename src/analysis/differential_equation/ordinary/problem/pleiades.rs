@@ -0,0 +1,178 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Pleiades problem
+///
+/// Celestial mechanics of seven stars in the plane with the masses
+/// $m_{i} = i$. The state is
+/// $(x_{1}, \dots, x_{7}, y_{1}, \dots, y_{7}, x_{1}^{'}, \dots, x_{7}^{'}, y_{1}^{'}, \dots, y_{7}^{'})$
+/// ```math
+/// x_{i}^{''} = \sum_{j \neq i} m_{j} \frac{x_{j} - x_{i}}{r_{ij}^{3}}, \quad
+/// y_{i}^{''} = \sum_{j \neq i} m_{j} \frac{y_{j} - y_{i}}{r_{ij}^{3}}, \quad
+/// r_{ij} = \sqrt{(x_{i} - x_{j})^{2} + (y_{i} - y_{j})^{2}}
+/// ```
+///
+/// The stars have several close encounters in the time span
+/// $\lbrack 0, 3 \rbrack$, which require a step size control.
+///
+/// Hairer, E., Nørsett, S. P., Wanner, G.: Solving Ordinary Differential
+/// Equations I, Springer, 1993, Section II.10
+pub struct Pleiades<T>
+{
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Pleiades<T>
+    where T: Real
+{
+    fn default() -> Pleiades<T>
+    {
+        let init_cond: [f64; 28] = [3.0, 3.0, -1.0, -3.0, 2.0, -2.0, 2.0,
+                                    3.0, -3.0, 2.0, 0.0, 0.0, -4.0, 4.0,
+                                    0.0, 0.0, 0.0, 0.0, 0.0, 1.75, -1.5,
+                                    0.0, 0.0, 0.0, -1.25, 1.0, 0.0, 0.0];
+
+        Pleiades { time_span: (T::zero(), T::from_f64(3.0)),
+                   init_cond: Vector::new_column(init_cond.iter().map(|x_i| T::from_f64(*x_i)).collect()) }
+    }
+}
+
+/// Number of stars
+const N: usize = 7;
+
+/// Solution at t = 3
+const REFERENCE: [f64; 28] = [0.3706139143970502, 3.237284092057233, -3.222559032418324, 0.659709145577531,
+                              0.3425581707156584, 1.562172101400631, -0.7003092922212495, -3.943437585517392,
+                              -3.27138097397255, 5.225081843456543, -2.59061243497747, 1.198213693392275,
+                              -0.2429682344935824, 1.09144924042898, 3.417003806314313, 1.354584501625501,
+                              -2.590065597810775, 2.025053734714242, -1.155815100160448, -0.8072988170223021,
+                              0.595239635420871, -3.74124496123401, 0.377345968575063, 0.9386858869551073,
+                              0.3667922227200571, -0.347404635380849, 2.344915448180937, -1.947020434263292];
+
+impl<T> Pleiades<T>
+    where T: Real
+{
+    /// Reference solution at t = 3
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        Vector::new_column(REFERENCE.iter().map(|x_i| T::from_f64(*x_i)).collect())
+    }
+
+    fn mass(i: usize) -> T
+    {
+        T::from_u64((i + 1) as u64)
+    }
+
+    fn rhs(&self, z: &Vector<T>) -> Vector<T>
+    {
+        let mut f: Vector<T> = Vector::zero(4 * N);
+        for i in 0..N
+        {
+            f[i] = z[2 * N + i];
+            f[N + i] = z[3 * N + i];
+        }
+
+        for i in 0..N
+        {
+            for j in (i + 1)..N
+            {
+                let dx: T = z[j] - z[i];
+                let dy: T = z[N + j] - z[N + i];
+                let r_2: T = dx * dx + dy * dy;
+                let r_3: T = r_2 * r_2.sqrt();
+
+                f[2 * N + i] += Self::mass(j) * dx / r_3;
+                f[3 * N + i] += Self::mass(j) * dy / r_3;
+                f[2 * N + j] -= Self::mass(i) * dx / r_3;
+                f[3 * N + j] -= Self::mass(i) * dy / r_3;
+            }
+        }
+
+        f
+    }
+}
+
+impl<T> ExplicitODE<T> for Pleiades<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Pleiades<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, z: &Vector<T>) -> Matrix<T>
+    {
+        let mut jacobian: Matrix<T> = Matrix::zero(4 * N, 4 * N);
+        for i in 0..(2 * N)
+        {
+            jacobian[[i, 2 * N + i]] = T::one();
+        }
+
+        let three: T = T::from_f64(3.0);
+        for i in 0..N
+        {
+            for j in 0..N
+            {
+                if i == j
+                {
+                    continue;
+                }
+                let dx: T = z[j] - z[i];
+                let dy: T = z[N + j] - z[N + i];
+                let r_2: T = dx * dx + dy * dy;
+                let r_3: T = r_2 * r_2.sqrt();
+                let r_5: T = r_3 * r_2;
+                let m_j: T = Self::mass(j);
+
+                // derivatives of m_j (x_j - x_i) / r^3 and m_j (y_j - y_i) / r^3 with respect
+                // to x_j and y_j
+                let d_xx: T = m_j * (T::one() / r_3 - three * dx * dx / r_5);
+                let d_xy: T = -m_j * three * dx * dy / r_5;
+                let d_yy: T = m_j * (T::one() / r_3 - three * dy * dy / r_5);
+
+                jacobian[[2 * N + i, j]] += d_xx;
+                jacobian[[2 * N + i, N + j]] += d_xy;
+                jacobian[[3 * N + i, j]] += d_xy;
+                jacobian[[3 * N + i, N + j]] += d_yy;
+
+                jacobian[[2 * N + i, i]] -= d_xx;
+                jacobian[[2 * N + i, N + i]] -= d_xy;
+                jacobian[[3 * N + i, i]] -= d_xy;
+                jacobian[[3 * N + i, N + i]] -= d_yy;
+            }
+        }
+
+        jacobian
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
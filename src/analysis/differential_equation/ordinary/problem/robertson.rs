@@ -0,0 +1,110 @@
+use crate::algebra::abstr::Real;
+use crate::algebra::linear::{Matrix, Vector};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Robertson's chemical reaction
+///
+/// Stiff system, which describes the kinetics of an autocatalytic reaction
+/// ```math
+/// x_{1}^{'}(t) = -0.04 x_{1} + 10^{4} x_{2} x_{3} \\
+/// x_{2}^{'}(t) = 0.04 x_{1} - 10^{4} x_{2} x_{3} - 3 \cdot 10^{7} x_{2}^{2} \\
+/// x_{3}^{'}(t) = 3 \cdot 10^{7} x_{2}^{2} \\
+/// ```
+///
+/// ```math
+/// x(0) = (1, 0, 0)^{T}, \quad t \in \lbrack 0, 40 \rbrack
+/// ```
+///
+/// Hairer, E., Wanner, G.: Solving Ordinary Differential Equations II,
+/// Springer, 1996, Section IV.10
+pub struct Robertson<T>
+{
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<T> Default for Robertson<T>
+    where T: Real
+{
+    fn default() -> Robertson<T>
+    {
+        Robertson { time_span: (T::zero(), T::from_f64(40.0)),
+                    init_cond: vector![T::one(); T::zero(); T::zero()] }
+    }
+}
+
+/// Solution at t = 40
+const REFERENCE: [f64; 3] = [0.7158270687193772, 9.185534764557954e-06, 0.2841637457458581];
+
+impl<T> Robertson<T>
+    where T: Real
+{
+    /// Reference solution at t = 40
+    pub fn reference_solution(&self) -> Vector<T>
+    {
+        vector![T::from_f64(REFERENCE[0]); T::from_f64(REFERENCE[1]); T::from_f64(REFERENCE[2])]
+    }
+
+    fn rhs(&self, x: &Vector<T>) -> Vector<T>
+    {
+        let k_1: T = T::from_f64(0.04);
+        let k_2: T = T::from_f64(3.0e7);
+        let k_3: T = T::from_f64(1.0e4);
+
+        let r_1: T = k_1 * x[0];
+        let r_2: T = k_2 * x[1] * x[1];
+        let r_3: T = k_3 * x[1] * x[2];
+
+        vector![-r_1 + r_3; r_1 - r_2 - r_3; r_2]
+    }
+}
+
+impl<T> ExplicitODE<T> for Robertson<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T> ImplicitODE<T> for Robertson<T>
+    where T: Real
+{
+    fn func(&self, _t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.rhs(x)
+    }
+
+    fn jacobian(&self, _t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        let k_1: T = T::from_f64(0.04);
+        let k_2: T = T::from_f64(3.0e7);
+        let k_3: T = T::from_f64(1.0e4);
+        let two: T = T::from_f64(2.0);
+
+        matrix![-k_1, k_3 * x[2], k_3 * x[1];
+                k_1, -two * k_2 * x[1] - k_3 * x[2], -k_3 * x[1];
+                T::zero(), two * k_2 * x[1], T::zero()]
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
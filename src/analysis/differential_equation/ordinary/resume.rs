@@ -0,0 +1,139 @@
+//! Continuation of an integration
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE};
+
+/// Problem which continues the integration of another problem from a given
+/// state
+///
+/// The right-hand side and the Jacobian are the ones of the wrapped problem,
+/// only the time span and the initial condition are replaced. Together with
+/// the backward integration of the solvers, a problem can be integrated over
+/// arbitrary spans, e.g. forward to $t_1$ and afterwards backward to $t_0$.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::ordinary::{
+///         solver::runge_kutta::{FixedStepper, RungeKutta4},
+///         ExplicitODE, Resume,
+///     },
+/// };
+///
+/// pub struct Decay;
+///
+/// impl ExplicitODE<f64> for Decay
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         -x.clone()
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+/// }
+///
+/// let solver: FixedStepper<f64> = FixedStepper::new(0.01);
+/// let method: RungeKutta4<f64> = RungeKutta4::default();
+/// let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Decay, &method).unwrap();
+///
+/// // integrate back to the initial time
+/// let backward: Resume<f64, Decay> = Resume::from_solution(&Decay, &t, &x, 0.0);
+/// let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&backward, &method).unwrap();
+///
+/// assert_relative_eq!(1.0, x.last().unwrap()[0], epsilon = 1.0e-8);
+/// # }
+/// ```
+pub struct Resume<'a, T, F>
+{
+    problem: &'a F,
+    time_span: (T, T),
+    init_cond: Vector<T>,
+}
+
+impl<'a, T, F> Resume<'a, T, F>
+    where T: Real
+{
+    /// Continues problem from the state x_0 at the time t_0 up to t_stop
+    pub fn new(problem: &'a F, t_0: T, x_0: Vector<T>, t_stop: T) -> Resume<'a, T, F>
+    {
+        Resume { problem,
+                 time_span: (t_0, t_stop),
+                 init_cond: x_0 }
+    }
+
+    /// Continues problem from the last point of a solution up to t_stop
+    ///
+    /// # Panics
+    ///
+    /// if the solution is empty or t and x do not have the same length
+    pub fn from_solution(problem: &'a F, t: &[T], x: &[Vector<T>], t_stop: T) -> Resume<'a, T, F>
+    {
+        if t.is_empty() || t.len() != x.len()
+        {
+            panic!("The solution is empty or the number of time points and states differ");
+        }
+
+        Resume::new(problem, t[t.len() - 1], x[x.len() - 1].clone(), t_stop)
+    }
+}
+
+impl<'a, T, F> ExplicitODE<T> for Resume<'a, T, F>
+    where T: Real,
+          F: ExplicitODE<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.problem.func(t, x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<'a, T, F> ImplicitODE<T> for Resume<'a, T, F>
+    where T: Real,
+          F: ImplicitODE<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.problem.func(t, x)
+    }
+
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        self.problem.jacobian(t, x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
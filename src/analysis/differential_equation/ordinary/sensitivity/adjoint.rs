@@ -0,0 +1,372 @@
+//! Adjoint sensitivity analysis
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Transpose, Matrix, Vector},
+    },
+    analysis::differential_equation::ordinary::{
+        solver::runge_kutta::{ExplicitRKMethod, FixedStepper},
+        ExplicitODE, ParameterisedODE,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Scalar objective of the solution of a parameterised ODE
+///
+/// ```math
+/// G(p) = g(x(t_f), p) + \int_{t_0}^{t_f} q(t, x(t), p) dt
+/// ```
+///
+/// The terminal and the running cost vanish by default.
+pub trait Objective<T>
+    where T: Real
+{
+    /// Terminal cost $g(x(t_f), p)$
+    fn terminal(&self, _x: &Vector<T>, _p: &Vector<T>) -> T
+    {
+        T::zero()
+    }
+
+    /// Gradient $\frac{\partial g}{\partial x}$
+    fn terminal_gradient_x(&self, x: &Vector<T>, _p: &Vector<T>) -> Vector<T>
+    {
+        Vector::zero(x.dim().0)
+    }
+
+    /// Gradient $\frac{\partial g}{\partial p}$
+    fn terminal_gradient_p(&self, _x: &Vector<T>, p: &Vector<T>) -> Vector<T>
+    {
+        Vector::zero(p.dim().0)
+    }
+
+    /// Running cost $q(t, x, p)$
+    fn running(&self, _t: &T, _x: &Vector<T>, _p: &Vector<T>) -> T
+    {
+        T::zero()
+    }
+
+    /// Gradient $\frac{\partial q}{\partial x}$
+    fn running_gradient_x(&self, _t: &T, x: &Vector<T>, _p: &Vector<T>) -> Vector<T>
+    {
+        Vector::zero(x.dim().0)
+    }
+
+    /// Gradient $\frac{\partial q}{\partial p}$
+    fn running_gradient_p(&self, _t: &T, _x: &Vector<T>, p: &Vector<T>) -> Vector<T>
+    {
+        Vector::zero(p.dim().0)
+    }
+}
+
+/// Adjoint sensitivity analysis
+///
+/// Computes the gradient of the objective $G(p)$ with the adjoint variables
+/// $\lambda$ and $\mu$, which are integrated backward in time
+/// ```math
+/// \lambda^{'} = -\left(\frac{\partial f}{\partial x}\right)^T \lambda - \frac{\partial q}{\partial x}, \quad \lambda(t_f) = \frac{\partial g}{\partial x}(x(t_f), p) \\
+/// \mu^{'} = -\left(\frac{\partial f}{\partial p}\right)^T \lambda - \frac{\partial q}{\partial p}, \quad \mu(t_f) = 0
+/// ```
+/// The gradient is
+/// ```math
+/// \frac{d G}{d p} = \mu(t_0) + \left(\frac{\partial x_0}{\partial p}\right)^T \lambda(t_0) + \frac{\partial g}{\partial p}(x(t_f), p)
+/// ```
+/// The costs are independent of the number of parameters. The state is
+/// computed in a forward integration with a fixed step size and interpolated
+/// with cubic Hermite polynomials during the backward integration.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differential_equation::ordinary::{
+///         sensitivity::{AdjointSensitivity, Objective},
+///         solver::runge_kutta::RungeKutta4,
+///         ParameterisedODE,
+///     },
+/// };
+///
+/// /// x' = -a x, x(0) = b
+/// struct Decay;
+///
+/// impl ParameterisedODE<f64> for Decay
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>, p: &Vector<f64>) -> Vector<f64>
+///     {
+///         x * &-p[0]
+///     }
+///
+///     fn jacobian_x(&self, _t: &f64, _x: &Vector<f64>, p: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![-p[0]]
+///     }
+///
+///     fn jacobian_p(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![-x[0], 0.0]
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self, p: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![p[1]]
+///     }
+///
+///     fn init_cond_jacobian(&self, _p: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![0.0, 1.0]
+///     }
+/// }
+///
+/// /// G(p) = x(1)
+/// struct Terminal;
+///
+/// impl Objective<f64> for Terminal
+/// {
+///     fn terminal(&self, x: &Vector<f64>, _p: &Vector<f64>) -> f64
+///     {
+///         x[0]
+///     }
+///
+///     fn terminal_gradient_x(&self, _x: &Vector<f64>, _p: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+/// }
+///
+/// let adjoint: AdjointSensitivity<f64> = AdjointSensitivity::new(0.01);
+/// let (g, dg): (f64, Vector<f64>) = adjoint.gradient(&Decay, &Terminal, &vector![2.0; 3.0], &RungeKutta4::default()).unwrap();
+///
+/// // x(1) = b exp(-a)
+/// assert_relative_eq!(3.0 * (-2.0f64).exp(), g, epsilon = 1.0e-8);
+/// assert_relative_eq!(-3.0 * (-2.0f64).exp(), dg[0], epsilon = 1.0e-6);
+/// assert_relative_eq!((-2.0f64).exp(), dg[1], epsilon = 1.0e-6);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct AdjointSensitivity<T>
+{
+    step_size: T,
+}
+
+impl<T> AdjointSensitivity<T>
+    where T: Real
+{
+    /// Creates an instance with the step size of the forward and backward
+    /// integration
+    ///
+    /// # Panics
+    ///
+    /// if step_size <= 0
+    pub fn new(step_size: T) -> AdjointSensitivity<T>
+    {
+        if step_size <= T::zero()
+        {
+            panic!("The step size has to be positive");
+        }
+
+        AdjointSensitivity { step_size }
+    }
+
+    /// Computes the objective and its gradient with respect to the parameters
+    ///
+    /// # Arguments
+    ///
+    /// * 'problem': parameterised ODE
+    /// * 'objective': scalar objective of the solution
+    /// * 'p': parameters
+    /// * 'method': Runge-Kutta method of the forward and backward integration
+    ///
+    /// # Return
+    ///
+    /// $(G(p), \frac{d G}{d p})$
+    pub fn gradient<P, O, M>(&self,
+                             problem: &P,
+                             objective: &O,
+                             p: &Vector<T>,
+                             method: &M)
+                             -> Result<(T, Vector<T>), &'static str>
+        where P: ParameterisedODE<T>,
+              O: Objective<T>,
+              M: ExplicitRKMethod<T>
+    {
+        let stepper: FixedStepper<T> = FixedStepper::new(self.step_size);
+
+        let forward: ForwardCost<T, P, O> = ForwardCost { problem,
+                                                          objective,
+                                                          p };
+        let (t, y): (Vec<T>, Vec<Vector<T>>) =
+            stepper.solve(&forward, method).map_err(|_| "Forward integration failed")?;
+
+        let (n, _): (usize, usize) = problem.init_cond(p).dim();
+        let (n_p, _): (usize, usize) = p.dim();
+
+        let x: Vec<Vector<T>> = y.iter().map(|y_i| y_i.get_slice(0, n - 1)).collect();
+        let f: Vec<Vector<T>> = t.iter().zip(x.iter()).map(|(t_i, x_i)| problem.func(t_i, x_i, p)).collect();
+
+        let x_f: &Vector<T> = x.last().unwrap();
+        let value: T = objective.terminal(x_f, p) + y.last().unwrap()[n];
+
+        let backward: Backward<T, P, O> = Backward { problem,
+                                                     objective,
+                                                     p,
+                                                     t: &t,
+                                                     x: &x,
+                                                     f: &f };
+        let (_tau, z): (Vec<T>, Vec<Vector<T>>) =
+            stepper.solve(&backward, method).map_err(|_| "Backward integration failed")?;
+
+        let z_0: &Vector<T> = z.last().unwrap();
+        let lambda_0: Vector<T> = z_0.get_slice(0, n - 1);
+        let mu_0: Vector<T> = z_0.get_slice(n, n + n_p - 1);
+
+        let s_0: Matrix<T> = problem.init_cond_jacobian(p);
+        let gradient: Vector<T> = &(&mu_0 + &(&s_0.transpose() * &lambda_0)) + &objective.terminal_gradient_p(x_f, p);
+
+        Ok((value, gradient))
+    }
+}
+
+/// State augmented with the integral of the running cost
+struct ForwardCost<'a, T, P, O>
+{
+    problem: &'a P,
+    objective: &'a O,
+    p: &'a Vector<T>,
+}
+
+impl<'a, T, P, O> ExplicitODE<T> for ForwardCost<'a, T, P, O>
+    where T: Real,
+          P: ParameterisedODE<T>,
+          O: Objective<T>
+{
+    fn func(&self, t: &T, y: &Vector<T>) -> Vector<T>
+    {
+        let (m, _): (usize, usize) = y.dim();
+        let x: Vector<T> = y.get_slice(0, m - 2);
+
+        let mut f: Vector<T> = Vector::zero(m);
+        f.set_slice(&self.problem.func(t, &x, self.p), 0);
+        f[m - 1] = self.objective.running(t, &x, self.p);
+
+        f
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.problem.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        let x_0: Vector<T> = self.problem.init_cond(self.p);
+        let (n, _): (usize, usize) = x_0.dim();
+
+        let mut y_0: Vector<T> = Vector::zero(n + 1);
+        y_0.set_slice(&x_0, 0);
+
+        y_0
+    }
+}
+
+/// Adjoint equations in the reversed time $\tau = t_0 + t_f - t$
+struct Backward<'a, T, P, O>
+{
+    problem: &'a P,
+    objective: &'a O,
+    p: &'a Vector<T>,
+    t: &'a Vec<T>,
+    x: &'a Vec<Vector<T>>,
+    f: &'a Vec<Vector<T>>,
+}
+
+impl<'a, T, P, O> Backward<'a, T, P, O>
+    where T: Real
+{
+    /// Cubic Hermite interpolation of the forward solution
+    fn state(&self, t: &T) -> Vector<T>
+    {
+        let len: usize = self.t.len();
+        if len == 1
+        {
+            return self.x[0].clone();
+        }
+
+        let i: usize = self.t.partition_point(|t_i| t_i <= t).clamp(1, len - 1) - 1;
+        let h: T = self.t[i + 1] - self.t[i];
+        if h <= T::zero()
+        {
+            return self.x[i + 1].clone();
+        }
+
+        let s: T = (*t - self.t[i]) / h;
+        let one: T = T::one();
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+
+        let h_00: T = (one + two * s) * (one - s) * (one - s);
+        let h_10: T = s * (one - s) * (one - s);
+        let h_01: T = s * s * (three - two * s);
+        let h_11: T = s * s * (s - one);
+
+        &(&(self.x[i].clone() * h_00) + &(self.f[i].clone() * (h_10 * h))) + &(&(self.x[i + 1].clone() * h_01)
+                                                                              + &(self.f[i + 1].clone() * (h_11 * h)))
+    }
+}
+
+impl<'a, T, P, O> ExplicitODE<T> for Backward<'a, T, P, O>
+    where T: Real,
+          P: ParameterisedODE<T>,
+          O: Objective<T>
+{
+    fn func(&self, tau: &T, z: &Vector<T>) -> Vector<T>
+    {
+        let (t_0, t_f): (T, T) = self.problem.time_span();
+        let t: T = t_0 + t_f - *tau;
+
+        let x: Vector<T> = self.state(&t);
+        let (n, _): (usize, usize) = x.dim();
+        let (m, _): (usize, usize) = z.dim();
+        let lambda: Vector<T> = z.get_slice(0, n - 1);
+
+        let j_x: Matrix<T> = self.problem.jacobian_x(&t, &x, self.p);
+        let j_p: Matrix<T> = self.problem.jacobian_p(&t, &x, self.p);
+
+        let d_lambda: Vector<T> = &(&j_x.transpose() * &lambda) + &self.objective.running_gradient_x(&t, &x, self.p);
+        let d_mu: Vector<T> = &(&j_p.transpose() * &lambda) + &self.objective.running_gradient_p(&t, &x, self.p);
+
+        let mut dz: Vector<T> = Vector::zero(m);
+        dz.set_slice(&d_lambda, 0);
+        dz.set_slice(&d_mu, n);
+
+        dz
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.problem.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        let x_f: &Vector<T> = self.x.last().unwrap();
+        let (n, _): (usize, usize) = x_f.dim();
+        let (n_p, _): (usize, usize) = self.p.dim();
+
+        let mut z: Vector<T> = Vector::zero(n + n_p);
+        z.set_slice(&self.objective.terminal_gradient_x(x_f, self.p), 0);
+
+        z
+    }
+}
@@ -0,0 +1,203 @@
+//! Forward sensitivity equations
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE, ParameterisedODE},
+};
+
+/// Forward sensitivity equations
+///
+/// The sensitivity matrix $S(t) = \frac{\partial x(t)}{\partial p}$ solves
+/// the variational equations
+/// ```math
+/// S^{'}(t) = \frac{\partial f}{\partial x}(t, x(t), p) S(t) + \frac{\partial f}{\partial p}(t, x(t), p), \quad S(t_0) = \frac{\partial x_0}{\partial p}
+/// ```
+/// They are integrated together with the state $x$ as an ODE with the
+/// augmented state $(x, s_1, \dots, s_{n_p})$, where $s_j$ is the j-th
+/// column of $S$. Hence every solver for explicit or implicit ODEs can be
+/// applied. The result is split with [`split`](ForwardSensitivity::split).
+///
+/// The Jacobian of the augmented system, which is used by implicit solvers,
+/// is approximated by the block diagonal matrix $I_{n_p + 1} \otimes
+/// \frac{\partial f}{\partial x}$, i.e. the second derivatives of f are
+/// neglected. As the sensitivity equations are linear in S, the Newton
+/// iteration still converges.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differential_equation::ordinary::{
+///         sensitivity::ForwardSensitivity,
+///         solver::runge_kutta::{FixedStepper, RungeKutta4},
+///         ParameterisedODE,
+///     },
+/// };
+///
+/// /// x' = -a x, x(0) = 1
+/// struct Decay;
+///
+/// impl ParameterisedODE<f64> for Decay
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>, p: &Vector<f64>) -> Vector<f64>
+///     {
+///         x * &-p[0]
+///     }
+///
+///     fn jacobian_x(&self, _t: &f64, _x: &Vector<f64>, p: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![-p[0]]
+///     }
+///
+///     fn jacobian_p(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![-x[0]]
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self, _p: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+/// }
+///
+/// let problem: ForwardSensitivity<f64, Decay> = ForwardSensitivity::new(&Decay, vector![2.0]);
+/// let (_t, y): (Vec<f64>, Vec<Vector<f64>>) =
+///     FixedStepper::new(0.01).solve(&problem, &RungeKutta4::default()).unwrap();
+///
+/// let (x, s): (Vector<f64>, Matrix<f64>) = problem.split(y.last().unwrap());
+///
+/// // x(t) = exp(-a t), dx/da = -t exp(-a t)
+/// assert_relative_eq!((-2.0f64).exp(), x[0], epsilon = 1.0e-8);
+/// assert_relative_eq!(-(-2.0f64).exp(), s[[0, 0]], epsilon = 1.0e-8);
+/// # }
+/// ```
+pub struct ForwardSensitivity<'a, T, P>
+{
+    problem: &'a P,
+    p: Vector<T>,
+}
+
+impl<'a, T, P> ForwardSensitivity<'a, T, P>
+    where T: Real,
+          P: ParameterisedODE<T>
+{
+    /// Creates the sensitivity equations of problem for the parameters p
+    pub fn new(problem: &'a P, p: Vector<T>) -> ForwardSensitivity<'a, T, P>
+    {
+        ForwardSensitivity { problem, p }
+    }
+
+    pub fn parameters(&self) -> &Vector<T>
+    {
+        &self.p
+    }
+
+    /// Splits the augmented state into the state $x$ and the sensitivity
+    /// matrix $S$
+    pub fn split(&self, y: &Vector<T>) -> (Vector<T>, Matrix<T>)
+    {
+        let (n_y, _): (usize, usize) = y.dim();
+        let (n_p, _): (usize, usize) = self.p.dim();
+        let n: usize = n_y / (n_p + 1);
+
+        let x: Vector<T> = y.get_slice(0, n - 1);
+        let mut s: Matrix<T> = Matrix::zero(n, n_p);
+        for j in 0..n_p
+        {
+            s.set_column(&y.get_slice((j + 1) * n, (j + 2) * n - 1), j);
+        }
+
+        (x, s)
+    }
+
+    /// Combines the state and the sensitivity matrix to the augmented state
+    fn join(x: &Vector<T>, s: &Matrix<T>) -> Vector<T>
+    {
+        let (n, n_p): (usize, usize) = s.dim();
+        let mut y: Vector<T> = Vector::zero(n * (n_p + 1));
+        y.set_slice(x, 0);
+        for j in 0..n_p
+        {
+            y.set_slice(&s.get_column(j), (j + 1) * n);
+        }
+
+        y
+    }
+}
+
+impl<'a, T, P> ExplicitODE<T> for ForwardSensitivity<'a, T, P>
+    where T: Real,
+          P: ParameterisedODE<T>
+{
+    fn func(&self, t: &T, y: &Vector<T>) -> Vector<T>
+    {
+        let (x, s): (Vector<T>, Matrix<T>) = self.split(y);
+
+        let f: Vector<T> = self.problem.func(t, &x, &self.p);
+        let j_x: Matrix<T> = self.problem.jacobian_x(t, &x, &self.p);
+        let j_p: Matrix<T> = self.problem.jacobian_p(t, &x, &self.p);
+
+        ForwardSensitivity::<T, P>::join(&f, &(&j_x * &s + j_p))
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.problem.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        let x_0: Vector<T> = self.problem.init_cond(&self.p);
+        let s_0: Matrix<T> = self.problem.init_cond_jacobian(&self.p);
+
+        ForwardSensitivity::<T, P>::join(&x_0, &s_0)
+    }
+}
+
+impl<'a, T, P> ImplicitODE<T> for ForwardSensitivity<'a, T, P>
+    where T: Real,
+          P: ParameterisedODE<T>
+{
+    fn func(&self, t: &T, y: &Vector<T>) -> Vector<T>
+    {
+        ExplicitODE::func(self, t, y)
+    }
+
+    fn jacobian(&self, t: &T, y: &Vector<T>) -> Matrix<T>
+    {
+        let (x, _s): (Vector<T>, Matrix<T>) = self.split(y);
+        let (n, _): (usize, usize) = x.dim();
+        let (n_y, _): (usize, usize) = y.dim();
+
+        let j_x: Matrix<T> = self.problem.jacobian_x(t, &x, &self.p);
+        let mut jacobian: Matrix<T> = Matrix::zero(n_y, n_y);
+        for k in 0..n_y / n
+        {
+            jacobian = jacobian.set_slice(&j_x, k * n, k * n);
+        }
+
+        jacobian
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        ExplicitODE::time_span(self)
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        ExplicitODE::init_cond(self)
+    }
+}
@@ -0,0 +1,23 @@
+//! Sensitivity analysis
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Sensitivity_analysis>
+//!
+//! The sensitivities of the solution of a [`ParameterisedODE`] with respect to
+//! its parameters are computed either with the forward sensitivity equations
+//! or the adjoint method. The forward method yields the full matrix
+//! $\frac{\partial x}{\partial p}$ and is suited for a small number of
+//! parameters, e.g. the Jacobian of a least squares problem. The adjoint
+//! method yields the gradient of a scalar objective at the cost of one forward
+//! and one backward integration independent of the number of parameters.
+//!
+//! Solving Ordinary Differential Equations I
+//! E. Hairer, S. P. Nørsett, G. Wanner
+//!
+//! [`ParameterisedODE`]: super::ParameterisedODE
+
+mod forward;
+mod adjoint;
+
+pub use forward::ForwardSensitivity;
+pub use adjoint::{AdjointSensitivity, Objective};
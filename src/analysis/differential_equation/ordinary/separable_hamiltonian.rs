@@ -0,0 +1,40 @@
+//! Separable Hamiltonian system
+use crate::algebra::{abstr::Real, linear::Vector};
+
+/// Separable Hamiltonian system
+///
+/// The Hamiltonian is the sum of the kinetic energy $T(p)$ and the potential
+/// energy $V(q)$
+/// ```math
+/// H(q, p) = T(p) + V(q)
+/// ```
+/// with the generalized coordinates $q$ and the conjugate momenta $p$. The
+/// equations of motion are
+/// ```math
+/// q^{'} = \frac{\partial H}{\partial p} = \nabla T(p) \\
+/// p^{'} = -\frac{\partial H}{\partial q} = -\nabla V(q)
+/// ```
+///
+/// This trait has to be implemented by every Hamiltonian system which shall be
+/// solved with a symplectic integrator.
+///
+/// <https://en.wikipedia.org/wiki/Hamiltonian_mechanics>
+pub trait SeparableHamiltonian<T>
+    where T: Real
+{
+    /// Partial derivative of the Hamiltonian with respect to the coordinates
+    /// $\frac{\partial H}{\partial q} = \nabla V(q)$
+    fn dh_dq(&self, q: &Vector<T>) -> Vector<T>;
+
+    /// Partial derivative of the Hamiltonian with respect to the momenta
+    /// $\frac{\partial H}{\partial p} = \nabla T(p)$
+    fn dh_dp(&self, p: &Vector<T>) -> Vector<T>;
+
+    /// Total energy $H(q, p)$ of the system
+    fn hamiltonian(&self, q: &Vector<T>, p: &Vector<T>) -> T;
+
+    fn time_span(&self) -> (T, T);
+
+    /// Initial coordinates and momenta $(q_0, p_0)$
+    fn init_cond(&self) -> (Vector<T>, Vector<T>);
+}
@@ -1,7 +1,7 @@
 //! Solves an ODE using Adam-Bashforth method.
 use crate::{
     algebra::{abstr::Real, linear::vector::vector::Vector},
-    analysis::differential_equation::ordinary::explicit_ode::ExplicitODE,
+    analysis::differential_equation::ordinary::{explicit_ode::ExplicitODE, solver::directed::Directed},
 };
 
 #[cfg(feature = "serde")]
@@ -124,21 +124,15 @@ impl<T> AdamsBashforth<T> where T: Real
     /// The solver returns a vector and a matrix, containing the times used in
     /// each step of the algorithm and the respectful values for that time.
     ///
-    /// # Panic
-    ///
-    /// if t_span.0 > t_span.1
+    /// The problem is integrated backward in time if t_span.0 > t_span.1.
     pub fn solve<F>(&self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
         where F: ExplicitODE<T>
     {
+        let prob: &Directed<T, F> = &Directed::new(prob, prob.time_span());
         let t_span: (T, T) = prob.time_span();
         let t_start: T = t_span.0;
         let t_stop: T = t_span.1;
 
-        if t_start > t_stop
-        {
-            panic!();
-        }
-
         let mut x_n: Vector<T> = prob.init_cond();
         let mut t_n: T = t_start;
 
@@ -213,7 +207,7 @@ impl<T> AdamsBashforth<T> where T: Real
             res_vec.push(x_n.clone());
         }
 
-        Ok((t_vec, res_vec))
+        Ok((prob.times(t_vec), res_vec))
     }
 }
 
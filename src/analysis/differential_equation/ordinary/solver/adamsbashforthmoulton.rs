@@ -0,0 +1,380 @@
+//! Solves an ODE using the Adams-Bashforth-Moulton predictor-corrector method.
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    analysis::{
+        differential_equation::ordinary::{solver::directed::Directed, ExplicitODE},
+        integral::gauss_legendre::GaussLegendre,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Adams-Bashforth-Moulton predictor-corrector method with adaptive step size
+///
+/// Each step is done in PECE mode: the explicit Adams-Bashforth formula
+/// predicts the new state (P), the right-hand side is evaluated (E), the
+/// implicit Adams-Moulton formula corrects the prediction (C) and the
+/// right-hand side is evaluated once more (E). A step therefore needs
+/// exactly two function evaluations, independent of the order.
+///
+/// The integration coefficients are computed for the actual, non-uniform
+/// step history, hence the step size can be changed after every step. The
+/// local error is estimated with Milne's device from the difference between
+/// predictor and corrector. The integration starts with order one and raises
+/// the order with every accepted step until the requested order is reached.
+///
+/// Solving Ordinary Differential Equations I
+/// Nonstiff Problems
+/// E. Hairer, S. P. Nørsett, G. Wanner
+///
+/// <https://en.wikipedia.org/wiki/Linear_multistep_method#Adams–Moulton_methods>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::ordinary::{ExplicitODE, solver::AdamsBashforthMoulton},
+/// };
+///
+/// pub struct ExplicitODE1
+/// {
+///     time_span: (f64, f64),
+///     init_cond: Vector<f64>,
+/// }
+///
+/// impl Default for ExplicitODE1
+/// {
+///     fn default() -> ExplicitODE1
+///     {
+///         ExplicitODE1 { time_span: (0.0, 2.0),
+///                        init_cond: vector![0.5] }
+///     }
+/// }
+///
+/// impl ExplicitODE<f64> for ExplicitODE1
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         return x * &2.0f64;
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         return self.time_span;
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         return self.init_cond.clone();
+///     }
+/// }
+///
+/// let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(4, 10000, 0.001, 0.9, 0.2, 5.0, 10e-9, 10e-7);
+/// let problem: ExplicitODE1 = ExplicitODE1::default();
+///
+/// // Solve the ODE
+/// let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct AdamsBashforthMoulton<T>
+{
+    /// Order of the method
+    k: u8,
+    /// Maximum number of accepted steps
+    n_max: u32,
+    /// Initial step size
+    h_0: T,
+    fac: T,
+    fac_min: T,
+    fac_max: T,
+    abs_tol: T,
+    rel_tol: T,
+}
+
+impl<T> Default for AdamsBashforthMoulton<T> where T: Real
+{
+    fn default() -> AdamsBashforthMoulton<T>
+    {
+        AdamsBashforthMoulton::new(4,
+                                   10000,
+                                   T::from_f64(0.001),
+                                   T::from_f64(0.9),
+                                   T::from_f64(0.2),
+                                   T::from_f64(5.0),
+                                   T::from_f64(10e-6),
+                                   T::from_f64(10e-3))
+    }
+}
+
+impl<T> AdamsBashforthMoulton<T> where T: Real
+{
+    /// Creates an Adams-Bashforth-Moulton instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'k': order of the predictor and the corrector, 1 <= k <= 5
+    /// * 'n_max': maximum number of accepted steps
+    /// * 'h_0': initial step size
+    /// * 'fac': safety factor of the step size control
+    /// * 'fac_min': minimal factor the step size is decreased with
+    /// * 'fac_max': maximal factor the step size is increased with
+    /// * 'abs_tol': absolute tolerance of the local error estimate
+    /// * 'rel_tol': relative tolerance of the local error estimate
+    ///
+    /// # Panics
+    ///
+    /// if k == 0 || k > 5 or h_0 <= 0.0
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(k: u8,
+               n_max: u32,
+               h_0: T,
+               fac: T,
+               fac_min: T,
+               fac_max: T,
+               abs_tol: T,
+               rel_tol: T)
+               -> AdamsBashforthMoulton<T>
+    {
+        if k == 0 || k > 5
+        {
+            panic!("Order k is not within the limits 1 <= k <= 5");
+        }
+        if h_0 <= T::zero()
+        {
+            panic!("Initial step size has to be positive");
+        }
+
+        AdamsBashforthMoulton { k,
+                                n_max,
+                                h_0,
+                                fac,
+                                fac_min,
+                                fac_max,
+                                abs_tol,
+                                rel_tol }
+    }
+
+    /// Returns the order of the method
+    pub fn order(&self) -> u8
+    {
+        self.k
+    }
+
+    /// Returns the absolute tolerance
+    pub fn get_abs_tol(&self) -> &T
+    {
+        &self.abs_tol
+    }
+
+    /// Returns the relative tolerance
+    pub fn get_rel_tol(&self) -> &T
+    {
+        &self.rel_tol
+    }
+
+    /// Sets the absolute tolerance
+    ///
+    /// # Panics
+    ///
+    /// if 'abs_tol' < 0.0
+    pub fn set_abs_tol(&mut self, abs_tol: T)
+    {
+        if abs_tol < T::zero()
+        {
+            panic!();
+        }
+        self.abs_tol = abs_tol;
+    }
+
+    /// Sets the relative tolerance
+    ///
+    /// # Panics
+    ///
+    /// if 'rel_tol' < 0.0
+    pub fn set_rel_tol(&mut self, rel_tol: T)
+    {
+        if rel_tol < T::zero()
+        {
+            panic!();
+        }
+        self.rel_tol = rel_tol;
+    }
+
+    /// Solves `prob` using the Adams-Bashforth-Moulton method in PECE mode.
+    ///
+    /// # Arguments
+    ///
+    /// * 'prob' is an explicit ordinary differential equation
+    ///
+    /// # Return
+    ///
+    /// The solver returns a vector and a matrix, containing the times used in
+    /// each step of the algorithm and the respectful values for that time.
+    ///
+    /// The problem is integrated backward in time if t_span.0 > t_span.1.
+    pub fn solve<F>(&self, prob: &F) -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: ExplicitODE<T>
+    {
+        let prob: &Directed<T, F> = &Directed::new(prob, prob.time_span());
+        let t_span: (T, T) = prob.time_span();
+        let t_start: T = t_span.0;
+        let t_stop: T = t_span.1;
+
+        let mut x_n: Vector<T> = prob.init_cond();
+        let mut t_n: T = t_start;
+        let mut h: T = self.h_0;
+
+        let mut t_vec: Vec<T> = vec![t_n];
+        let mut res_vec: Vec<Vector<T>> = vec![x_n.clone()];
+
+        // Time points and right-hand side evaluations of the last steps, the most
+        // recent one is stored at the end.
+        let mut t_hist: Vec<T> = vec![t_n];
+        let mut f_hist: Vec<Vector<T>> = vec![prob.func(&t_n, &x_n)];
+
+        let mut n: u32 = 0;
+
+        while n < self.n_max && t_n < t_stop
+        {
+            h = h.min(t_stop - t_n);
+            let t_n1: T = t_n + h;
+
+            // The order is limited by the number of available history values
+            let k: usize = f_hist.len().min(self.k as usize);
+
+            // Predict
+            let beta: Vec<T> = AdamsBashforthMoulton::coefficients(&t_hist[t_hist.len() - k..], t_n, h);
+            let x_p: Vector<T> = AdamsBashforthMoulton::combine(&x_n, &f_hist[f_hist.len() - k..], &beta, h);
+
+            // Evaluate
+            let f_p: Vector<T> = prob.func(&t_n1, &x_p);
+
+            // Correct
+            let mut t_corr: Vec<T> = t_hist[t_hist.len() + 1 - k..].to_vec();
+            t_corr.push(t_n1);
+            let mut f_corr: Vec<Vector<T>> = f_hist[f_hist.len() + 1 - k..].to_vec();
+            f_corr.push(f_p);
+            let gamma: Vec<T> = AdamsBashforthMoulton::coefficients(&t_corr, t_n, h);
+            let x_c: Vector<T> = AdamsBashforthMoulton::combine(&x_n, &f_corr, &gamma, h);
+
+            let err: T = self.calc_error(&x_c, &x_p, &x_n) * AdamsBashforthMoulton::milne_factor(k);
+
+            if err <= T::one()
+            {
+                // Evaluate
+                let f_c: Vector<T> = prob.func(&t_n1, &x_c);
+
+                t_n = t_n1;
+                x_n = x_c;
+
+                t_hist.push(t_n);
+                f_hist.push(f_c);
+                if t_hist.len() > self.k as usize
+                {
+                    t_hist.remove(0);
+                    f_hist.remove(0);
+                }
+
+                t_vec.push(t_n);
+                res_vec.push(x_n.clone());
+                n += 1;
+            }
+
+            if err != T::zero()
+            {
+                let l: T = T::one() / T::from_u8(k as u8 + 1);
+                let s: T = (self.fac * (T::one() / err).pow(l)).max(self.fac_min).min(self.fac_max);
+                h = s * h;
+            }
+            else
+            {
+                h = self.fac_max * h;
+            }
+        }
+
+        if t_n < t_stop
+        {
+            return Err("Maximum number of iterations reached");
+        }
+
+        Ok((prob.times(t_vec), res_vec))
+    }
+}
+
+impl<T> AdamsBashforthMoulton<T> where T: Real
+{
+    /// Computes the weights of the interpolating quadrature formula
+    ///
+    /// ```math
+    /// \int_{t_n}^{t_n + h} p(t) dt = h \sum_{j} w_j f_j
+    /// ```
+    /// where p is the polynomial interpolating the values $f_j$ at the nodes
+    /// `t`. The weights are the integrals of the Lagrange basis polynomials.
+    fn coefficients(t: &[T], t_n: T, h: T) -> Vec<T>
+    {
+        // Normalized nodes s_j = (t_j - t_n) / h
+        let s: Vec<T> = t.iter().map(|t_j| (*t_j - t_n) / h).collect();
+        // The Lagrange basis polynomials are of degree <= 4, three nodes integrate
+        // them exactly.
+        let gl: GaussLegendre<T> = GaussLegendre::new(3);
+
+        (0..s.len()).map(|j| {
+                        let l_j = |x: T| {
+                            s.iter()
+                             .enumerate()
+                             .filter(|(i, _)| *i != j)
+                             .fold(T::one(), |p, (_, s_i)| p * (x - *s_i) / (s[j] - *s_i))
+                        };
+                        gl.integrate(l_j, T::zero(), T::one())
+                    })
+                    .collect()
+    }
+
+    fn combine(x_n: &Vector<T>, f: &[Vector<T>], w: &[T], h: T) -> Vector<T>
+    {
+        f.iter().zip(w.iter()).fold(x_n.clone(), |s, (f_j, w_j)| s + f_j * &(*w_j * h))
+    }
+
+    /// Factor of Milne's device, computed from the error constants of the
+    /// Adams-Bashforth and Adams-Moulton methods of order k.
+    fn milne_factor(k: usize) -> T
+    {
+        let (c_ab, c_am): (f64, f64) = match k
+        {
+            1 => (1.0 / 2.0, -1.0 / 2.0),
+            2 => (5.0 / 12.0, -1.0 / 12.0),
+            3 => (3.0 / 8.0, -1.0 / 24.0),
+            4 => (251.0 / 720.0, -19.0 / 720.0),
+            5 => (95.0 / 288.0, -3.0 / 160.0),
+            _ => panic!(),
+        };
+
+        T::from_f64(c_am.abs() / (c_ab - c_am))
+    }
+
+    fn calc_error(&self, y: &Vector<T>, y_h: &Vector<T>, y_p: &Vector<T>) -> T
+    {
+        let (m, _n) = y.dim();
+
+        let mut sum: T = T::zero();
+
+        for i in 0..m
+        {
+            let y_max_i: T = y[i].abs().max(y_p[i].abs());
+            let sc_i: T = self.abs_tol + y_max_i * self.rel_tol;
+
+            let k: T = (y[i] - y_h[i]) / sc_i;
+            sum += k * k;
+        }
+
+        (sum / T::from_f64(m as f64)).sqrt()
+    }
+}
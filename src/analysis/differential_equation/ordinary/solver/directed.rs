@@ -0,0 +1,158 @@
+//! Integration in the direction of the time span
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE},
+};
+use std::cell::Cell;
+
+/// Wraps a problem such that it is integrated forward in time
+///
+/// If the time span is decreasing, the problem is transformed with $s = -t$
+/// ```math
+/// \frac{d x}{d s} = -f(-s, x)
+/// ```
+/// and is integrated over the increasing span $\lbrack -t_0, -t_f \rbrack$.
+/// In addition, the evaluations of the right-hand side and the Jacobian are
+/// counted.
+pub(crate) struct Directed<'a, T, F>
+{
+    problem: &'a F,
+    /// +1 for forward, -1 for backward integration
+    direction: T,
+    func: Cell<usize>,
+    jacobian: Cell<usize>,
+}
+
+impl<'a, T, F> Directed<'a, T, F>
+    where T: Real
+{
+    pub fn new(problem: &'a F, time_span: (T, T)) -> Directed<'a, T, F>
+    {
+        let direction: T = if time_span.1 < time_span.0 { -T::one() } else { T::one() };
+
+        Directed { problem,
+                   direction,
+                   func: Cell::new(0),
+                   jacobian: Cell::new(0) }
+    }
+
+    pub fn direction(&self) -> T
+    {
+        self.direction
+    }
+
+    pub fn is_backward(&self) -> bool
+    {
+        self.direction < T::zero()
+    }
+
+    /// Maps the internal time s back to the time t
+    pub fn time(&self, s: T) -> T
+    {
+        self.direction * s
+    }
+
+    /// Maps the internal time points back to the time
+    pub fn times(&self, s: Vec<T>) -> Vec<T>
+    {
+        if self.is_backward()
+        {
+            s.into_iter().map(|s_i| -s_i).collect()
+        }
+        else
+        {
+            s
+        }
+    }
+
+    /// Maps the internal time span
+    fn span(&self, time_span: (T, T)) -> (T, T)
+    {
+        (self.direction * time_span.0, self.direction * time_span.1)
+    }
+
+    pub fn func_evaluations(&self) -> usize
+    {
+        self.func.get()
+    }
+
+    pub fn jacobian_evaluations(&self) -> usize
+    {
+        self.jacobian.get()
+    }
+}
+
+impl<'a, T, F> ExplicitODE<T> for Directed<'a, T, F>
+    where T: Real,
+          F: ExplicitODE<T>
+{
+    fn func(&self, s: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.func.set(self.func.get() + 1);
+        let f: Vector<T> = self.problem.func(&self.time(*s), x);
+        if self.is_backward()
+        {
+            -f
+        }
+        else
+        {
+            f
+        }
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.span(self.problem.time_span())
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.problem.init_cond()
+    }
+}
+
+impl<'a, T, F> ImplicitODE<T> for Directed<'a, T, F>
+    where T: Real,
+          F: ImplicitODE<T>
+{
+    fn func(&self, s: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.func.set(self.func.get() + 1);
+        let f: Vector<T> = self.problem.func(&self.time(*s), x);
+        if self.is_backward()
+        {
+            -f
+        }
+        else
+        {
+            f
+        }
+    }
+
+    fn jacobian(&self, s: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        self.jacobian.set(self.jacobian.get() + 1);
+        let jacobian: Matrix<T> = self.problem.jacobian(&self.time(*s), x);
+        if self.is_backward()
+        {
+            jacobian * -T::one()
+        }
+        else
+        {
+            jacobian
+        }
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.span(self.problem.time_span())
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.problem.init_cond()
+    }
+}
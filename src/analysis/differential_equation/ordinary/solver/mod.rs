@@ -1,7 +1,13 @@
 //! This module provides different algorithms to solve initial value problems.
 mod adamsbashforth;
+mod adamsbashforthmoulton;
 mod bdf;
+mod solution;
+pub(crate) mod directed;
 
 pub mod runge_kutta;
+pub mod symplectic;
 pub use bdf::BDF;
-pub use adamsbashforth::AdamsBashforth;
\ No newline at end of file
+pub use adamsbashforth::AdamsBashforth;
+pub use adamsbashforthmoulton::AdamsBashforthMoulton;
+pub use solution::{Solution, Statistics, Status};
@@ -1,6 +1,6 @@
 //! Adaptive step size stepper
 use crate::algebra::{abstr::Real, linear::Vector};
-use crate::analysis::differential_equation::ordinary::{ExplicitODE, solver::runge_kutta::ExplicitRKEmbeddedMethod};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, solver::{directed::Directed, runge_kutta::ExplicitRKEmbeddedMethod}};
 use std::default::Default;
 use std::clone::Clone;
 #[cfg(feature = "serde")]
@@ -9,6 +9,10 @@ use serde::{Deserialize, Serialize};
 
 /// Proportional Control
 ///
+/// The solver fails if the maximum number of steps is reached. See
+/// [`AdaptiveStepper`](super::AdaptiveStepper) for a stepper which reports the
+/// reason of the termination together with statistics.
+///
 /// Solving Ordinary Differential Equations I
 /// Nonstiff Problems
 /// E. Hairer, S. P. Nørsett, G. Wanner
@@ -130,9 +134,7 @@ impl<T> ProportionalControl<T> where T: Real
     /// The solver returns a vector and a matrix, containing the times used in
     /// each step of the algorithm and the respectful values for that time.
     ///
-    /// # Panic
-    ///
-    /// if t_span.0 > t_span.1
+    /// The problem is integrated backward in time if t_span.0 > t_span.1.
     pub fn solve<F, M>(&self,
                        prob: &F,
                        method: &M)
@@ -140,13 +142,10 @@ impl<T> ProportionalControl<T> where T: Real
         where F: ExplicitODE<T>,
               M: ExplicitRKEmbeddedMethod<T>
     {
+        let prob: &Directed<T, F> = &Directed::new(prob, prob.time_span());
         let t_span: (T, T) = prob.time_span();
         let t_start: T = t_span.0;
         let t_stop: T = t_span.1;
-        if t_start > t_stop
-        {
-            panic!();
-        }
 
         let tableau = method.tableau();
 
@@ -206,7 +205,7 @@ impl<T> ProportionalControl<T> where T: Real
         {
             return Err("Maximum number of iterations reached");
         }
-        Ok((t_vec, res_vec))
+        Ok((prob.times(t_vec), res_vec))
     }
 
     fn calc_error(&self, y: &Vector<T>, y_h: &Vector<T>, y_p: &Vector<T>) -> T
@@ -0,0 +1,546 @@
+//! Adaptive step size stepper with statistics
+use super::{ExplicitRKEmbeddedMethod, StepSizeController};
+use crate::{
+    algebra::{abstr::Real, linear::Vector},
+    analysis::differential_equation::ordinary::{
+        solver::{directed::Directed, Solution, Statistics, Status},
+        ExplicitODE,
+    },
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{clone::Clone, default::Default};
+
+/// Adaptive step size control with configurable controller
+///
+/// In contrast to [`ProportionalControl`](super::ProportionalControl) the absolute tolerance can be set
+/// per component, the step size is bounded by a minimal and a maximal step
+/// size and the step size controller is selectable. The solver returns a
+/// [`Solution`], which contains the reason of the termination and statistics
+/// about the work done.
+///
+/// The error of a step is measured in the norm
+/// ```math
+/// \varepsilon = \sqrt{\frac{1}{n} \sum_{i=1}^{n} \left(\frac{x_i - \hat{x}_i}{atol_i + \max(\lvert x_{n, i} \rvert, \lvert x_{n+1, i}\rvert) rtol}\right)^2}
+/// ```
+/// and the step is accepted if $\varepsilon \leq 1$.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::ordinary::{
+///         solver::{
+///             runge_kutta::{AdaptiveStepper, DormandPrince54, StepSizeController},
+///             Solution, Status,
+///         },
+///         ExplicitODE,
+///     },
+/// };
+///
+/// pub struct Oscillator;
+///
+/// impl ExplicitODE<f64> for Oscillator
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![x[1]; -x[0]]
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 10.0)
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         vector![1.0; 0.0]
+///     }
+/// }
+///
+/// let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-8, 10e-6);
+/// solver.set_abs_tol_components(vector![10e-8; 10e-10]);
+/// solver.set_controller(StepSizeController::pi());
+///
+/// let solution: Solution<f64> = solver.solve(&Oscillator, &DormandPrince54::default());
+///
+/// assert_eq!(Status::Success, solution.status());
+/// assert_relative_eq!(10.0f64.cos(), solution.x().last().unwrap()[0], epsilon = 10e-6);
+/// assert_eq!(7 * (solution.statistics().accepted_steps() + solution.statistics().rejected_steps()),
+///            solution.statistics().rhs_evaluations());
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct AdaptiveStepper<T>
+{
+    n_max: u32,
+    h_0: T,
+    h_min: T,
+    h_max: T,
+    fac: T,
+    fac_min: T,
+    fac_max: T,
+    /// Absolute tolerance for each component or for all components if it
+    /// contains one entry only
+    abs_tol: Vec<T>,
+    rel_tol: T,
+    controller: StepSizeController<T>,
+}
+
+impl<T> Default for AdaptiveStepper<T>
+    where T: Real
+{
+    fn default() -> AdaptiveStepper<T>
+    {
+        AdaptiveStepper::new(1000, T::from_f64(0.02), T::from_f64(10e-6), T::from_f64(10e-3))
+    }
+}
+
+impl<T> AdaptiveStepper<T>
+    where T: Real
+{
+    /// Creates an instance with a proportional controller
+    ///
+    /// # Arguments
+    ///
+    /// * 'n_max': maximum number of accepted steps
+    /// * 'h_0': initial step size
+    /// * 'abs_tol': absolute tolerance of all components
+    /// * 'rel_tol': relative tolerance
+    ///
+    /// # Panics
+    ///
+    /// if h_0 <= 0, abs_tol < 0 or rel_tol < 0
+    pub fn new(n_max: u32, h_0: T, abs_tol: T, rel_tol: T) -> AdaptiveStepper<T>
+    {
+        if h_0 <= T::zero()
+        {
+            panic!("The initial step size has to be positive");
+        }
+        if abs_tol < T::zero() || rel_tol < T::zero()
+        {
+            panic!("The tolerances have to be non negative");
+        }
+
+        AdaptiveStepper { n_max,
+                          h_0,
+                          h_min: T::zero(),
+                          h_max: T::from_f64(f64::MAX),
+                          fac: T::from_f64(0.9),
+                          fac_min: T::from_f64(0.2),
+                          fac_max: T::from_f64(5.0),
+                          abs_tol: vec![abs_tol],
+                          rel_tol,
+                          controller: StepSizeController::Proportional }
+    }
+
+    /// Sets the absolute tolerance of all components
+    ///
+    /// # Panics
+    ///
+    /// if abs_tol < 0
+    pub fn set_abs_tol(&mut self, abs_tol: T)
+    {
+        if abs_tol < T::zero()
+        {
+            panic!("The tolerances have to be non negative");
+        }
+        self.abs_tol = vec![abs_tol];
+    }
+
+    /// Sets the absolute tolerance of each component
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative
+    pub fn set_abs_tol_components(&mut self, abs_tol: Vector<T>)
+    {
+        if abs_tol.iter().any(|a| *a < T::zero())
+        {
+            panic!("The tolerances have to be non negative");
+        }
+        self.abs_tol = abs_tol.iter().copied().collect();
+    }
+
+    /// Sets the relative tolerance
+    ///
+    /// # Panics
+    ///
+    /// if rel_tol < 0
+    pub fn set_rel_tol(&mut self, rel_tol: T)
+    {
+        if rel_tol < T::zero()
+        {
+            panic!("The tolerances have to be non negative");
+        }
+        self.rel_tol = rel_tol;
+    }
+
+    /// Sets the minimal and maximal step size
+    ///
+    /// # Panics
+    ///
+    /// if h_min < 0 or h_max < h_min
+    pub fn set_step_size_limits(&mut self, h_min: T, h_max: T)
+    {
+        if h_min < T::zero() || h_max < h_min
+        {
+            panic!("Invalid step size limits");
+        }
+        self.h_min = h_min;
+        self.h_max = h_max;
+    }
+
+    /// Sets the safety factor and the bounds of the step size ratio $h_{n+1}
+    /// / h_n$
+    ///
+    /// # Panics
+    ///
+    /// unless 0 < fac <= 1 and 0 < fac_min < 1 < fac_max
+    pub fn set_factors(&mut self, fac: T, fac_min: T, fac_max: T)
+    {
+        if fac <= T::zero() || fac > T::one() || fac_min <= T::zero() || fac_min >= T::one() || fac_max <= T::one()
+        {
+            panic!("Invalid factors");
+        }
+        self.fac = fac;
+        self.fac_min = fac_min;
+        self.fac_max = fac_max;
+    }
+
+    pub fn set_controller(&mut self, controller: StepSizeController<T>)
+    {
+        self.controller = controller;
+    }
+
+    pub fn get_controller(&self) -> &StepSizeController<T>
+    {
+        &self.controller
+    }
+
+    /// Solves prob with the embedded Runge-Kutta method
+    ///
+    /// The problem is integrated backward in time if the time span is
+    /// decreasing.
+    ///
+    /// # Panics
+    ///
+    /// if the number of components of the absolute tolerance does not match
+    /// the dimension of the problem
+    pub fn solve<F, M>(&self, prob: &F, method: &M) -> Solution<T>
+        where F: ExplicitODE<T>,
+              M: ExplicitRKEmbeddedMethod<T>
+    {
+        let mut integrator: AdaptiveIntegrator<T, F, M> = self.integrator(prob, method);
+
+        let mut t_vec: Vec<T> = vec![integrator.time()];
+        let mut res_vec: Vec<Vector<T>> = vec![integrator.state().clone()];
+
+        for (t_n, x_n) in &mut integrator
+        {
+            t_vec.push(t_n);
+            res_vec.push(x_n);
+        }
+
+        Solution::new(t_vec, res_vec, integrator.status().unwrap(), integrator.statistics())
+    }
+
+    /// Creates an integrator, which advances the solution of prob step by
+    /// step
+    ///
+    /// # Panics
+    ///
+    /// if the number of components of the absolute tolerance does not match
+    /// the dimension of the problem
+    pub fn integrator<'a, F, M>(&self, prob: &'a F, method: &'a M) -> AdaptiveIntegrator<'a, T, F, M>
+        where F: ExplicitODE<T>,
+              M: ExplicitRKEmbeddedMethod<T>
+    {
+        let prob: Directed<T, F> = Directed::new(prob, prob.time_span());
+        let (s, s_stop): (T, T) = prob.time_span();
+
+        let x: Vector<T> = prob.init_cond();
+        let (m, _n): (usize, usize) = x.dim();
+        if self.abs_tol.len() != 1 && self.abs_tol.len() != m
+        {
+            panic!("The number of absolute tolerances does not match the dimension of the problem");
+        }
+
+        let (p, p_s): (u8, u8) = method.tableau().order();
+
+        AdaptiveIntegrator { stepper: self.clone(),
+                             prob,
+                             method,
+                             k: T::from_u8(p.min(p_s)) + T::one(),
+                             s,
+                             s_stop,
+                             x,
+                             h: self.h_0.min(self.h_max),
+                             err_prev: (T::one(), T::one()),
+                             statistics: Statistics::default(),
+                             status: None }
+    }
+
+    fn calc_error(&self, y: &Vector<T>, y_h: &Vector<T>, y_p: &Vector<T>) -> T
+    {
+        let (m, _n): (usize, usize) = y.dim();
+
+        let mut sum: T = T::zero();
+        for i in 0..m
+        {
+            let abs_tol: T = if self.abs_tol.len() == 1 { self.abs_tol[0] } else { self.abs_tol[i] };
+            let sc_i: T = abs_tol + y[i].abs().max(y_p[i].abs()) * self.rel_tol;
+
+            let k: T = (y[i] - y_h[i]) / sc_i;
+            sum += k * k;
+        }
+
+        (sum / T::from_u64(m as u64)).sqrt()
+    }
+}
+
+/// Advances the solution of an explicit ODE with adaptive step size control
+///
+/// The integrator is created with [`AdaptiveStepper::integrator`]. Each call
+/// of [`step`](AdaptiveIntegrator::step) performs one accepted step. The
+/// integrator is an iterator over the time points and the states of the
+/// accepted steps.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::ordinary::{
+///         solver::runge_kutta::{AdaptiveIntegrator, AdaptiveStepper, DormandPrince54},
+///         ExplicitODE,
+///     },
+/// };
+///
+/// pub struct Decay;
+///
+/// impl ExplicitODE<f64> for Decay
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         -x.clone()
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+/// }
+///
+/// let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-10, 10e-8);
+/// let method: DormandPrince54<f64> = DormandPrince54::default();
+/// let mut integrator: AdaptiveIntegrator<f64, Decay, DormandPrince54<f64>> = solver.integrator(&Decay, &method);
+///
+/// // macro steps of a co-simulation
+/// for i in 1..=4
+/// {
+///     let x: &Vector<f64> = integrator.advance_to(0.25 * i as f64).unwrap();
+///     assert_relative_eq!((-0.25 * i as f64).exp(), x[0], epsilon = 1.0e-8);
+/// }
+/// # }
+/// ```
+pub struct AdaptiveIntegrator<'a, T, F, M>
+{
+    stepper: AdaptiveStepper<T>,
+    prob: Directed<'a, T, F>,
+    method: &'a M,
+    /// Order of the error estimate plus one
+    k: T,
+    /// Internal time, which increases also for backward integration
+    s: T,
+    s_stop: T,
+    x: Vector<T>,
+    /// Proposed step size
+    h: T,
+    /// Error estimates of the last accepted steps
+    err_prev: (T, T),
+    statistics: Statistics,
+    status: Option<Status>,
+}
+
+impl<'a, T, F, M> AdaptiveIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ExplicitODE<T>,
+          M: ExplicitRKEmbeddedMethod<T>
+{
+    /// Current time
+    pub fn time(&self) -> T
+    {
+        self.prob.time(self.s)
+    }
+
+    /// Current state
+    pub fn state(&self) -> &Vector<T>
+    {
+        &self.x
+    }
+
+    /// Replaces the current state, e.g. after an event of an external model
+    pub fn set_state(&mut self, x: Vector<T>)
+    {
+        self.x = x;
+    }
+
+    /// Proposed size of the next step
+    pub fn step_size(&self) -> T
+    {
+        self.h
+    }
+
+    /// Reason of the termination or None if the integration can be continued
+    pub fn status(&self) -> Option<Status>
+    {
+        self.status
+    }
+
+    pub fn statistics(&self) -> Statistics
+    {
+        let mut statistics: Statistics = self.statistics;
+        statistics.rhs_evaluations = self.prob.func_evaluations();
+        statistics
+    }
+
+    /// Performs one accepted step
+    ///
+    /// # Return
+    ///
+    /// The new time and state or None if the integration terminated. The
+    /// reason is returned by [`status`](AdaptiveIntegrator::status).
+    pub fn step(&mut self) -> Option<(T, Vector<T>)>
+    {
+        if self.step_until(self.s_stop)
+        {
+            Some((self.time(), self.x.clone()))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Advances the solution to the time t. The last step is shortened to
+    /// end at t.
+    ///
+    /// # Panics
+    ///
+    /// if t is not between the current time and the end of the time span
+    pub fn advance_to(&mut self, t: T) -> Result<&Vector<T>, Status>
+    {
+        let s_t: T = self.prob.direction() * t;
+        if s_t < self.s || s_t > self.s_stop
+        {
+            panic!("The time is not within the remaining time span");
+        }
+
+        while self.s < s_t
+        {
+            if !self.step_until(s_t)
+            {
+                return Err(self.status.unwrap());
+            }
+        }
+
+        Ok(&self.x)
+    }
+
+    /// Performs one accepted step, which does not go beyond limit
+    ///
+    /// Returns false if the integration terminated.
+    fn step_until(&mut self, limit: T) -> bool
+    {
+        if self.status.is_some()
+        {
+            return false;
+        }
+        if self.s >= self.s_stop
+        {
+            self.status = Some(Status::Success);
+            return false;
+        }
+        if self.statistics.accepted_steps >= self.stepper.n_max as usize
+        {
+            self.status = Some(Status::MaxStepsReached);
+            return false;
+        }
+
+        let stepper: &AdaptiveStepper<T> = &self.stepper;
+        let err_floor: T = T::from_f64(10e-10);
+        let eps: T = T::from_f64(f64::EPSILON);
+
+        loop
+        {
+            let remaining: T = limit - self.s;
+            let clamped: bool = self.h >= remaining;
+            let h: T = if clamped { remaining } else { self.h };
+
+            let (y_n, y_n_s): (Vector<T>, Vector<T>) = self.method.tableau().do_step(&self.prob, &self.s, &self.x, &h);
+            let err: T = stepper.calc_error(&y_n, &y_n_s, &self.x);
+
+            if err <= T::one()
+            {
+                self.s = if clamped { limit } else { self.s + h };
+                self.x = y_n;
+                self.statistics.accepted_steps += 1;
+
+                let err_n: T = err.max(err_floor);
+                let fac: T = stepper.fac * stepper.controller.factor((err_n, self.err_prev.0, self.err_prev.1), self.k);
+                self.err_prev = (err_n, self.err_prev.0);
+
+                let h_new: T = (h * fac.max(stepper.fac_min).min(stepper.fac_max)).max(stepper.h_min)
+                                                                                  .min(stepper.h_max);
+                // A step shortened to hit the limit does not decrease the proposed step size
+                self.h = if clamped && h_new >= h { self.h.max(h_new) } else { h_new };
+
+                return true;
+            }
+
+            self.statistics.rejected_steps += 1;
+
+            // The history of the controller is not used after a rejected step. A NaN
+            // error leads to the minimal factor.
+            let fac: T = stepper.fac * StepSizeController::Proportional.factor((err, T::one(), T::one()), self.k);
+            let h_new: T = h * fac.max(stepper.fac_min).min(T::one());
+
+            // A step with the minimal step size has been rejected
+            if h <= stepper.h_min || h_new <= eps * self.s.abs().max(T::one())
+            {
+                self.status = Some(Status::StepSizeTooSmall);
+                return false;
+            }
+            self.h = h_new.max(stepper.h_min);
+        }
+    }
+}
+
+impl<'a, T, F, M> Iterator for AdaptiveIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ExplicitODE<T>,
+          M: ExplicitRKEmbeddedMethod<T>
+{
+    type Item = (T, Vector<T>);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.step()
+    }
+}
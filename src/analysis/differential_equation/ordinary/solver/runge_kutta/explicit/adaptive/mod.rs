@@ -2,6 +2,8 @@
 
 mod explicit_rk_embedded;
 mod adaptive_stepper;
+mod controlled_stepper;
+mod step_size_controller;
 mod cashkarp54;
 mod dormandprince54;
 mod fehlberg21;
@@ -12,6 +14,8 @@ mod bogackishampine32;
 pub use explicit_rk_embedded::ExplicitRKEmbedded;
 pub use explicit_rk_embedded::ExplicitRKEmbeddedMethod;
 pub use adaptive_stepper::ProportionalControl;
+pub use controlled_stepper::{AdaptiveIntegrator, AdaptiveStepper};
+pub use step_size_controller::StepSizeController;
 // pub use tsitouras54::Tsitouras54;
 pub use dormandprince54::DormandPrince54;
 pub use fehlberg54::Fehlberg54;
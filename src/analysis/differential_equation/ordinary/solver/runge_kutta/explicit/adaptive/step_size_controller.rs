@@ -0,0 +1,85 @@
+//! Step size controller
+use crate::algebra::abstr::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Step size controller
+///
+/// The new step size is computed from the error estimates $\varepsilon_n,
+/// \varepsilon_{n-1}, \varepsilon_{n-2}$ of the last accepted steps
+/// ```math
+/// h_{n+1} = h_n \cdot fac \cdot \left(\frac{1}{\varepsilon_n}\right)^{\beta_1 / k} \left(\frac{1}{\varepsilon_{n-1}}\right)^{\beta_2 / k} \left(\frac{1}{\varepsilon_{n-2}}\right)^{\beta_3 / k}
+/// ```
+/// where $k = q + 1$ and q is the lower order of the embedded pair. The
+/// proportional controller uses $\beta = (1, 0, 0)$. The integrating and
+/// derivative parts damp oscillations of the step size.
+///
+/// Solving Ordinary Differential Equations II
+/// E. Hairer, G. Wanner
+///
+/// Digital filters in adaptive time-stepping
+/// G. Söderlind
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum StepSizeController<T>
+{
+    /// Proportional control, $\beta = (1, 0, 0)$
+    #[default]
+    Proportional,
+    /// Proportional-integral control
+    PI
+    {
+        beta_1: T, beta_2: T
+    },
+    /// Proportional-integral-derivative control
+    PID
+    {
+        beta_1: T, beta_2: T, beta_3: T
+    },
+}
+
+impl<T> StepSizeController<T>
+    where T: Real
+{
+    /// PI controller with $\beta = (0.7, -0.4)$ as proposed by Gustafsson
+    pub fn pi() -> StepSizeController<T>
+    {
+        StepSizeController::PI { beta_1: T::from_f64(0.7),
+                                 beta_2: T::from_f64(-0.4) }
+    }
+
+    /// PID controller with $\beta = (0.49, -0.34, 0.1)$ as proposed by
+    /// Söderlind
+    pub fn pid() -> StepSizeController<T>
+    {
+        StepSizeController::PID { beta_1: T::from_f64(0.49),
+                                  beta_2: T::from_f64(-0.34),
+                                  beta_3: T::from_f64(0.1) }
+    }
+
+    /// Exponents $(\beta_1, \beta_2, \beta_3)$
+    fn beta(&self) -> (T, T, T)
+    {
+        match *self
+        {
+            StepSizeController::Proportional => (T::one(), T::zero(), T::zero()),
+            StepSizeController::PI { beta_1, beta_2 } => (beta_1, beta_2, T::zero()),
+            StepSizeController::PID { beta_1, beta_2, beta_3 } => (beta_1, beta_2, beta_3),
+        }
+    }
+
+    /// Factor $h_{n+1} / h_n$ without safety factor and limits
+    ///
+    /// # Arguments
+    ///
+    /// * 'err': error estimates $(\varepsilon_n, \varepsilon_{n-1},
+    ///   \varepsilon_{n-2})$
+    /// * 'k': order of the error estimate plus one
+    pub fn factor(&self, err: (T, T, T), k: T) -> T
+    {
+        let (beta_1, beta_2, beta_3): (T, T, T) = self.beta();
+
+        (T::one() / err.0).pow(beta_1 / k) * (T::one() / err.1).pow(beta_2 / k) * (T::one() / err.2).pow(beta_3 / k)
+    }
+}
@@ -1,6 +1,6 @@
 //! Fixed step size Stepper
 use crate::algebra::{abstr::Real, linear::Vector};
-use crate::analysis::differential_equation::ordinary::{ExplicitODE, solver::runge_kutta::ExplicitRKMethod};
+use crate::analysis::differential_equation::ordinary::{ExplicitODE, solver::{directed::Directed, runge_kutta::ExplicitRKMethod}};
 use std::clone::Clone;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -36,20 +36,20 @@ impl<T> FixedStepper<T>
         FixedStepper { step_size }
     }
 
+    /// Solves prob with the given method
+    ///
+    /// The problem is integrated backward in time if the time span is
+    /// decreasing.
     pub fn solve<F, M>(&self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
         where F: ExplicitODE<T>,
               M: ExplicitRKMethod<T>
     {
+        let prob: &Directed<T, F> = &Directed::new(prob, prob.time_span());
         let t_span = prob.time_span();
         let init = prob.init_cond();
         let t_start = t_span.0;
         let t_stop = t_span.1;
 
-        if t_start > t_stop
-        {
-            panic!()
-        }
-
         let tableau = method.tableau();
 
         let mut x_n: Vector<T> = init;
@@ -73,7 +73,74 @@ impl<T> FixedStepper<T>
 
             t_n += h;
         }
-        Ok((t_vec, res_vec))
+        Ok((prob.times(t_vec), res_vec))
+    }
+
+    /// Creates an integrator, which advances the solution of prob step by
+    /// step
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
+    /// use mathru::{
+    ///     algebra::linear::Vector,
+    ///     analysis::differential_equation::ordinary::{
+    ///         solver::runge_kutta::{FixedStepIntegrator, FixedStepper, RungeKutta4},
+    ///         ExplicitODE,
+    ///     },
+    /// };
+    ///
+    /// pub struct Decay;
+    ///
+    /// impl ExplicitODE<f64> for Decay
+    /// {
+    ///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    ///     {
+    ///         -x.clone()
+    ///     }
+    ///
+    ///     fn time_span(&self) -> (f64, f64)
+    ///     {
+    ///         (0.0, 1.0)
+    ///     }
+    ///
+    ///     fn init_cond(&self) -> Vector<f64>
+    ///     {
+    ///         vector![1.0]
+    ///     }
+    /// }
+    ///
+    /// let solver: FixedStepper<f64> = FixedStepper::new(0.01);
+    /// let method: RungeKutta4<f64> = RungeKutta4::default();
+    /// let mut integrator: FixedStepIntegrator<f64, Decay, RungeKutta4<f64>> = solver.integrator(&Decay, &method);
+    ///
+    /// // macro steps of a co-simulation
+    /// for i in 1..=4
+    /// {
+    ///     let x: &Vector<f64> = integrator.advance_to(0.25 * i as f64);
+    ///     assert_relative_eq!((-0.25 * i as f64).exp(), x[0], epsilon = 1.0e-8);
+    /// }
+    /// assert!(integrator.is_finished());
+    /// # }
+    /// ```
+    pub fn integrator<'a, F, M>(&self, prob: &'a F, method: &'a M) -> FixedStepIntegrator<'a, T, F, M>
+        where F: ExplicitODE<T>,
+              M: ExplicitRKMethod<T>
+    {
+        let prob: Directed<T, F> = Directed::new(prob, prob.time_span());
+        let (s, s_stop): (T, T) = prob.time_span();
+        let x: Vector<T> = prob.init_cond();
+
+        FixedStepIntegrator { prob,
+                              method,
+                              step_size: self.step_size,
+                              s,
+                              s_stop,
+                              x }
     }
 
     pub fn get_step_size(&self) -> &T
@@ -87,3 +154,109 @@ impl<T> FixedStepper<T>
     }
 }
 
+/// Advances the solution of an explicit ODE with a fixed step size
+///
+/// The integrator is created with [`FixedStepper::integrator`]. Each call of
+/// [`step`](FixedStepIntegrator::step) performs one step, the last step is
+/// shortened to end at the end of the time span. The integrator is an
+/// iterator over the time points and the states.
+pub struct FixedStepIntegrator<'a, T, F, M>
+{
+    prob: Directed<'a, T, F>,
+    method: &'a M,
+    step_size: T,
+    /// Internal time, which increases also for backward integration
+    s: T,
+    s_stop: T,
+    x: Vector<T>,
+}
+
+impl<'a, T, F, M> FixedStepIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ExplicitODE<T>,
+          M: ExplicitRKMethod<T>
+{
+    /// Current time
+    pub fn time(&self) -> T
+    {
+        self.prob.time(self.s)
+    }
+
+    /// Current state
+    pub fn state(&self) -> &Vector<T>
+    {
+        &self.x
+    }
+
+    /// Replaces the current state, e.g. after an event of an external model
+    pub fn set_state(&mut self, x: Vector<T>)
+    {
+        self.x = x;
+    }
+
+    /// Returns true if the end of the time span has been reached
+    pub fn is_finished(&self) -> bool
+    {
+        self.s >= self.s_stop
+    }
+
+    /// Performs one step
+    ///
+    /// # Return
+    ///
+    /// The new time and state or None if the end of the time span has been
+    /// reached
+    pub fn step(&mut self) -> Option<(T, Vector<T>)>
+    {
+        if self.is_finished()
+        {
+            return None;
+        }
+
+        self.step_until(self.s_stop);
+        Some((self.time(), self.x.clone()))
+    }
+
+    /// Advances the solution to the time t
+    ///
+    /// # Panics
+    ///
+    /// if t is not between the current time and the end of the time span
+    pub fn advance_to(&mut self, t: T) -> &Vector<T>
+    {
+        let s_t: T = self.prob.direction() * t;
+        if s_t < self.s || s_t > self.s_stop
+        {
+            panic!("The time is not within the remaining time span");
+        }
+
+        while self.s < s_t
+        {
+            self.step_until(s_t);
+        }
+
+        &self.x
+    }
+
+    fn step_until(&mut self, limit: T)
+    {
+        let remaining: T = limit - self.s;
+        let h: T = self.step_size.min(remaining);
+
+        self.x = self.method.tableau().do_step(&self.prob, &self.s, &self.x, &h);
+        self.s = if h == remaining { limit } else { self.s + h };
+    }
+}
+
+impl<'a, T, F, M> Iterator for FixedStepIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ExplicitODE<T>,
+          M: ExplicitRKMethod<T>
+{
+    type Item = (T, Vector<T>);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.step()
+    }
+}
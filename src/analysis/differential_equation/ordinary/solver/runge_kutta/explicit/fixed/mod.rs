@@ -16,7 +16,7 @@ mod kutta38;
 
 pub use explicit_rk::ExplicitRK;
 pub use explicit_rk::ExplicitRKMethod;
-pub use fixed_stepper::FixedStepper;
+pub use fixed_stepper::{FixedStepIntegrator, FixedStepper};
 pub use explicit_euler::ExplicitEuler;
 pub use heun2::Heun2;
 pub use ralston2::Ralston2;
@@ -1,7 +1,7 @@
 /// Fixed step size Stepper
 use crate::algebra::{abstr::Real, linear::Vector};
 use crate::analysis::differential_equation::ordinary::ImplicitODE;
-use crate::analysis::differential_equation::ordinary::solver::runge_kutta::ImplicitFixedStepSizeMethod;
+use crate::analysis::differential_equation::ordinary::solver::{directed::Directed, runge_kutta::ImplicitFixedStepSizeMethod, Status};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -34,20 +34,24 @@ impl<T> ImplicitFixedStepper<T>
         ImplicitFixedStepper { step_size }
     }
 
+    /// Solves prob with the given method
+    ///
+    /// The problem is integrated backward in time if the time span is
+    /// decreasing.
+    ///
+    /// # Return
+    ///
+    /// The time points and the states or Err if a step fails
     pub fn solve<F, M>(&self, prob: &F, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>), ()>
         where F: ImplicitODE<T>,
               M: ImplicitFixedStepSizeMethod<T>
     {
+        let prob: &Directed<T, F> = &Directed::new(prob, prob.time_span());
         let t_span = prob.time_span();
         let init = prob.init_cond();
         let t_start = t_span.0;
         let t_stop = t_span.1;
 
-        if t_start > t_stop
-        {
-            panic!()
-        }
-
         let mut x_n: Vector<T> = init;
 
         let mut t_n: T = t_start;
@@ -59,19 +63,40 @@ impl<T> ImplicitFixedStepper<T>
         let mut t_vec: Vec<T> = Vec::with_capacity(steps);
         let mut res_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
 
-        for _i in 0..steps
+        t_vec.push(t_n);
+        res_vec.push(x_n.clone());
+
+        while t_n < t_stop
         {
-            let h: T = self.step_size.min(t_stop - t_n);
+            let (h, t_next): (T, T) = next_step(self.step_size, t_n, t_stop);
+
+            x_n = method.do_step(prob, &t_n, &x_n, &h).map_err(|_| ())?;
 
+            t_n = t_next;
             t_vec.push(t_n);
             res_vec.push(x_n.clone());
+        }
 
-            x_n = method.do_step(prob, &t_n, &x_n, &h);
+        Ok((prob.times(t_vec), res_vec))
+    }
 
-            t_n += h;
-        }
+    /// Creates an integrator, which advances the solution of prob step by
+    /// step
+    pub fn integrator<'a, F, M>(&self, prob: &'a F, method: &'a M) -> ImplicitFixedStepIntegrator<'a, T, F, M>
+        where F: ImplicitODE<T>,
+              M: ImplicitFixedStepSizeMethod<T>
+    {
+        let prob: Directed<T, F> = Directed::new(prob, prob.time_span());
+        let (s, s_stop): (T, T) = prob.time_span();
+        let x: Vector<T> = prob.init_cond();
 
-        Ok((t_vec, res_vec))
+        ImplicitFixedStepIntegrator { prob,
+                                      method,
+                                      step_size: self.step_size,
+                                      s,
+                                      s_stop,
+                                      x,
+                                      status: None }
     }
 
     pub fn get_step_size(&self) -> &T
@@ -84,3 +109,170 @@ impl<T> ImplicitFixedStepper<T>
         self.step_size = step_size;
     }
 }
+
+/// Advances the solution of an implicit ODE with a fixed step size
+///
+/// The integrator is created with [`ImplicitFixedStepper::integrator`]. Each
+/// call of [`step`](ImplicitFixedStepIntegrator::step) performs one step, the
+/// last step is shortened to end at the end of the time span. The integrator
+/// is an iterator over the time points and the states. If the Newton
+/// iteration of a step fails, the integration terminates with
+/// [`Status::ConvergenceFailure`].
+pub struct ImplicitFixedStepIntegrator<'a, T, F, M>
+{
+    prob: Directed<'a, T, F>,
+    method: &'a M,
+    step_size: T,
+    /// Internal time, which increases also for backward integration
+    s: T,
+    s_stop: T,
+    x: Vector<T>,
+    status: Option<Status>,
+}
+
+impl<'a, T, F, M> ImplicitFixedStepIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ImplicitODE<T>,
+          M: ImplicitFixedStepSizeMethod<T>
+{
+    /// Current time
+    pub fn time(&self) -> T
+    {
+        self.prob.time(self.s)
+    }
+
+    /// Current state
+    pub fn state(&self) -> &Vector<T>
+    {
+        &self.x
+    }
+
+    /// Replaces the current state, e.g. after an event of an external model
+    pub fn set_state(&mut self, x: Vector<T>)
+    {
+        self.x = x;
+    }
+
+    /// Returns true if the end of the time span has been reached
+    pub fn is_finished(&self) -> bool
+    {
+        self.s >= self.s_stop
+    }
+
+    /// Reason of the termination or None if the integration can be continued
+    pub fn status(&self) -> Option<Status>
+    {
+        self.status
+    }
+
+    /// Performs one step
+    ///
+    /// # Return
+    ///
+    /// The new time and state or None if the integration terminated. The
+    /// reason is returned by [`status`](ImplicitFixedStepIntegrator::status).
+    pub fn step(&mut self) -> Option<(T, Vector<T>)>
+    {
+        if self.step_until(self.s_stop)
+        {
+            Some((self.time(), self.x.clone()))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Advances the solution to the time t
+    ///
+    /// # Return
+    ///
+    /// The state at t or the status if a step fails
+    ///
+    /// # Panics
+    ///
+    /// if t is not between the current time and the end of the time span
+    pub fn advance_to(&mut self, t: T) -> Result<&Vector<T>, Status>
+    {
+        let s_t: T = self.prob.direction() * t;
+        if s_t < self.s || s_t > self.s_stop
+        {
+            panic!("The time is not within the remaining time span");
+        }
+
+        while self.s < s_t
+        {
+            if !self.step_until(s_t)
+            {
+                return Err(self.status.unwrap());
+            }
+        }
+
+        Ok(&self.x)
+    }
+
+    /// Performs one step, which does not go beyond limit
+    ///
+    /// Returns false if the integration terminated.
+    fn step_until(&mut self, limit: T) -> bool
+    {
+        if self.status.is_some()
+        {
+            return false;
+        }
+        if self.is_finished()
+        {
+            self.status = Some(Status::Success);
+            return false;
+        }
+
+        let (h, s_next): (T, T) = next_step(self.step_size, self.s, limit);
+
+        match self.method.do_step(&self.prob, &self.s, &self.x, &h)
+        {
+            Ok(x) =>
+            {
+                self.x = x;
+                self.s = s_next;
+                true
+            }
+            Err(_) =>
+            {
+                self.status = Some(Status::ConvergenceFailure);
+                false
+            }
+        }
+    }
+}
+
+/// Returns the step size and the time after a step from t towards limit
+///
+/// The step ends exactly at limit, if the remainder after a full step would
+/// be negligible compared to the step size. Otherwise, the rounding errors of
+/// the accumulated time lead to a tiny last step.
+fn next_step<T>(step_size: T, t: T, limit: T) -> (T, T)
+    where T: Real
+{
+    let remaining: T = limit - t;
+    if remaining <= step_size * (T::one() + T::default_epsilon().sqrt())
+    {
+        (remaining, limit)
+    }
+    else
+    {
+        (step_size, t + step_size)
+    }
+}
+
+impl<'a, T, F, M> Iterator for ImplicitFixedStepIntegrator<'a, T, F, M>
+    where T: Real,
+          F: ImplicitODE<T>,
+          M: ImplicitFixedStepSizeMethod<T>
+{
+    type Item = (T, Vector<T>);
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.step()
+    }
+}
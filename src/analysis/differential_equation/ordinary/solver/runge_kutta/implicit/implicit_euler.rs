@@ -8,8 +8,10 @@ use crate::{
         linear::{Matrix, Vector},
     },
     analysis::{
-        differential_equation::ordinary::solver::runge_kutta::implicit::ImplicitFixedStepper, Function, Jacobian,
-        NewtonRaphson,
+        differential_equation::ordinary::solver::{
+            directed::Directed, runge_kutta::implicit::{ImplicitFixedStepIntegrator, ImplicitFixedStepper}, Solution, Statistics, Status,
+        },
+        Function, Jacobian, NewtonRaphson,
     },
 };
 #[cfg(feature = "serde")]
@@ -119,6 +121,52 @@ impl<T> ImplicitEuler<T>
         self.stepper.solve(prob, self)
     }
 
+    /// Solves prob and counts the evaluations of the right-hand side and the
+    /// Jacobian
+    ///
+    /// The Newton iteration evaluates the Jacobian and factorises the
+    /// iteration matrix once in each iteration. Hence, the number of LU
+    /// decompositions is not counted separately, but is the number of
+    /// Jacobian evaluations. A fixed step size method does not reject steps.
+    ///
+    /// # Return
+    ///
+    /// The solution or Err if the Newton iteration of a step fails
+    pub fn solve_with_statistics<F>(&self, prob: &F) -> Result<Solution<T>, ()>
+        where F: ImplicitODE<T>
+    {
+        let counter: Directed<T, F> = Directed::new(prob, prob.time_span());
+        let (s, x): (Vec<T>, Vec<Vector<T>>) = self.stepper.solve(&counter, self)?;
+
+        let (_, s_stop): (T, T) = counter.time_span();
+        let status: Status = if s.last().is_some_and(|s_n| *s_n >= s_stop)
+        {
+            Status::Success
+        }
+        else
+        {
+            Status::MaxStepsReached
+        };
+        let t: Vec<T> = counter.times(s);
+
+        let statistics: Statistics = Statistics { rhs_evaluations: counter.func_evaluations(),
+                                                  jacobian_evaluations: counter.jacobian_evaluations(),
+                                                  // one factorisation per Jacobian evaluation
+                                                  lu_decompositions: counter.jacobian_evaluations(),
+                                                  accepted_steps: t.len() - 1,
+                                                  rejected_steps: 0 };
+
+        Ok(Solution::new(t, x, status, statistics))
+    }
+
+    /// Creates an integrator, which advances the solution of prob step by
+    /// step
+    pub fn integrator<'a, F>(&'a self, prob: &'a F) -> ImplicitFixedStepIntegrator<'a, T, F, ImplicitEuler<T>>
+        where F: ImplicitODE<T>
+    {
+        self.stepper.integrator(prob, self)
+    }
+
     pub fn get_step_size(&self) -> &T
     {
         self.stepper.get_step_size()
@@ -132,14 +180,13 @@ impl<T> ImplicitEuler<T>
 
 impl<T> ImplicitFixedStepSizeMethod<T> for ImplicitEuler<T> where T: Real
 {
-    fn do_step<F>(&self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Vector<T>
+    fn do_step<F>(&self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Result<Vector<T>, &'static str>
         where F: ImplicitODE<T>
     {
         let t: T = *t_n + *h;
         let ie_helper = ImplicitEulerHelper::new(prob, &t, x_n, h);
-        let x_n = self.root_finder.find_root(&ie_helper, x_n).unwrap();
 
-        x_n
+        self.root_finder.find_root(&ie_helper, x_n)
     }
 
     /// Euler's method is a first order method
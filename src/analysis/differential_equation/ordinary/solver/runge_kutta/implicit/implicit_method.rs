@@ -4,7 +4,13 @@ use crate::algebra::{abstr::Real, linear::Vector};
 pub trait ImplicitFixedStepSizeMethod<T>
     where T: Real
 {
-    fn do_step<F>(&self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Vector<T>
+    /// Performs one step of size h from (t_n, x_n)
+    ///
+    /// # Return
+    ///
+    /// The new state or Err with the reason, if the nonlinear system of the
+    /// step could not be solved
+    fn do_step<F>(&self, prob: &F, t_n: &T, x_n: &Vector<T>, h: &T) -> Result<Vector<T>, &'static str>
         where F: ImplicitODE<T>;
 
     fn order(&self) -> u8;
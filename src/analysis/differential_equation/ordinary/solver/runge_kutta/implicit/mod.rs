@@ -4,5 +4,5 @@ mod fixed_stepper;
 mod implicit_method;
 
 pub use implicit_euler::ImplicitEuler;
-pub use fixed_stepper::ImplicitFixedStepper;
+pub use fixed_stepper::{ImplicitFixedStepIntegrator, ImplicitFixedStepper};
 pub use implicit_method::ImplicitFixedStepSizeMethod;
\ No newline at end of file
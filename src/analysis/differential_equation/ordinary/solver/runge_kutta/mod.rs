@@ -2,6 +2,9 @@ pub mod explicit;
 
 pub use explicit::adaptive::ExplicitRKEmbeddedMethod;
 pub use explicit::adaptive::ProportionalControl;
+pub use explicit::adaptive::AdaptiveStepper;
+pub use explicit::adaptive::AdaptiveIntegrator;
+pub use explicit::adaptive::StepSizeController;
 // pub use explicit::adaptive::Tsitouras54;
 pub use explicit::adaptive::DormandPrince54;
 pub use explicit::adaptive::Fehlberg54;
@@ -11,6 +14,7 @@ pub use explicit::adaptive::CashKarp54;
 
 pub use explicit::fixed::ExplicitRKMethod;
 pub use explicit::fixed::FixedStepper;
+pub use explicit::fixed::FixedStepIntegrator;
 pub use explicit::fixed::ExplicitEuler;
 pub use explicit::fixed::Heun2;
 pub use explicit::fixed::Ralston2;
@@ -27,4 +31,5 @@ pub mod implicit;
 
 pub use implicit::ImplicitEuler;
 pub use implicit::ImplicitFixedStepper;
+pub use implicit::ImplicitFixedStepIntegrator;
 pub use implicit::ImplicitFixedStepSizeMethod;
\ No newline at end of file
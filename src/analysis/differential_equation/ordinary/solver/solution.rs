@@ -0,0 +1,125 @@
+//! Solution of an initial value problem
+use crate::algebra::linear::Vector;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Reason why a solver terminated
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status
+{
+    /// The end of the time span has been reached
+    Success,
+    /// The maximum number of steps has been taken before the end of the time
+    /// span has been reached
+    MaxStepsReached,
+    /// The step size fell below the minimal step size
+    StepSizeTooSmall,
+    /// The nonlinear equations of an implicit step could not be solved
+    ConvergenceFailure,
+}
+
+/// Counters of the work done by a solver
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Statistics
+{
+    pub(crate) rhs_evaluations: usize,
+    pub(crate) jacobian_evaluations: usize,
+    pub(crate) lu_decompositions: usize,
+    pub(crate) accepted_steps: usize,
+    pub(crate) rejected_steps: usize,
+}
+
+impl Statistics
+{
+    /// Number of evaluations of the right-hand side f(t, x)
+    pub fn rhs_evaluations(&self) -> usize
+    {
+        self.rhs_evaluations
+    }
+
+    /// Number of evaluations of the Jacobian
+    pub fn jacobian_evaluations(&self) -> usize
+    {
+        self.jacobian_evaluations
+    }
+
+    /// Number of LU decompositions of the iteration matrix
+    pub fn lu_decompositions(&self) -> usize
+    {
+        self.lu_decompositions
+    }
+
+    pub fn accepted_steps(&self) -> usize
+    {
+        self.accepted_steps
+    }
+
+    pub fn rejected_steps(&self) -> usize
+    {
+        self.rejected_steps
+    }
+}
+
+/// Solution of an initial value problem
+///
+/// Contains the accepted time points and states, the reason why the solver
+/// terminated and statistics about the work done. If the solver did not reach
+/// the end of the time span, the solution up to the last accepted step is
+/// kept.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Solution<T>
+{
+    t: Vec<T>,
+    x: Vec<Vector<T>>,
+    status: Status,
+    statistics: Statistics,
+}
+
+impl<T> Solution<T>
+{
+    pub fn new(t: Vec<T>, x: Vec<Vector<T>>, status: Status, statistics: Statistics) -> Solution<T>
+    {
+        Solution { t,
+                   x,
+                   status,
+                   statistics }
+    }
+
+    /// Time points of the accepted steps
+    pub fn t(&self) -> &Vec<T>
+    {
+        &self.t
+    }
+
+    /// States at the time points
+    pub fn x(&self) -> &Vec<Vector<T>>
+    {
+        &self.x
+    }
+
+    pub fn status(&self) -> Status
+    {
+        self.status
+    }
+
+    pub fn statistics(&self) -> &Statistics
+    {
+        &self.statistics
+    }
+
+    /// Returns true if the end of the time span has been reached
+    pub fn is_success(&self) -> bool
+    {
+        self.status == Status::Success
+    }
+
+    /// Returns the time points and the states
+    pub fn into_parts(self) -> (Vec<T>, Vec<Vector<T>>)
+    {
+        (self.t, self.x)
+    }
+}
@@ -0,0 +1,148 @@
+//! Solves a Hamiltonian system using Gauss-Legendre collocation methods.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::SymplecticMethod};
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gauss-Legendre collocation method
+///
+/// Implicit Runge-Kutta method with $s$ stages whose nodes are the roots of
+/// the shifted Legendre polynomial of degree $s$. The method is of order $2s$,
+/// symplectic and symmetric. With one stage it is the implicit midpoint
+/// rule.
+///
+/// The stage equations are solved with a fixed point iteration, which
+/// converges for non-stiff problems and sufficiently small step sizes.
+///
+/// <https://en.wikipedia.org/wiki/Gauss%E2%80%93Legendre_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussLegendreCollocation<T>
+{
+    a: Vec<Vec<T>>,
+    b: Vec<T>,
+    iters: u32,
+    tolerance_abs: T,
+}
+
+impl<T> GaussLegendreCollocation<T>
+    where T: Real
+{
+    /// Creates a Gauss-Legendre collocation method with `s` stages
+    ///
+    /// # Arguments
+    ///
+    /// * 's': number of stages, 1 <= s <= 3
+    /// * 'iters': maximum number of fixed point iterations per step
+    /// * 'tolerance_abs': absolute tolerance of the fixed point iteration
+    ///
+    /// # Panics
+    ///
+    /// if s < 1 || s > 3
+    pub fn new(s: u8, iters: u32, tolerance_abs: T) -> GaussLegendreCollocation<T>
+    {
+        let sqrt_3: f64 = 3.0f64.sqrt();
+        let sqrt_15: f64 = 15.0f64.sqrt();
+
+        let (a, b): (Vec<Vec<f64>>, Vec<f64>) = match s
+        {
+            1 => (vec![vec![0.5]], vec![1.0]),
+            2 => (vec![vec![0.25, 0.25 - sqrt_3 / 6.0],
+                       vec![0.25 + sqrt_3 / 6.0, 0.25]],
+                  vec![0.5, 0.5]),
+            3 => (vec![vec![5.0 / 36.0, 2.0 / 9.0 - sqrt_15 / 15.0, 5.0 / 36.0 - sqrt_15 / 30.0],
+                       vec![5.0 / 36.0 + sqrt_15 / 24.0, 2.0 / 9.0, 5.0 / 36.0 - sqrt_15 / 24.0],
+                       vec![5.0 / 36.0 + sqrt_15 / 30.0, 2.0 / 9.0 + sqrt_15 / 15.0, 5.0 / 36.0]],
+                  vec![5.0 / 18.0, 4.0 / 9.0, 5.0 / 18.0]),
+            _ => panic!("Number of stages s is not within the limits 1 <= s <= 3"),
+        };
+
+        GaussLegendreCollocation { a: a.iter().map(|a_i| a_i.iter().map(|a_ij| T::from_f64(*a_ij)).collect()).collect(),
+                                   b: b.iter().map(|b_i| T::from_f64(*b_i)).collect(),
+                                   iters,
+                                   tolerance_abs }
+    }
+
+    /// Returns the number of stages
+    pub fn stages(&self) -> usize
+    {
+        self.b.len()
+    }
+}
+
+impl<T> Default for GaussLegendreCollocation<T>
+    where T: Real
+{
+    /// Two stage method of order four, the tolerance is a small multiple of
+    /// the machine epsilon of T
+    fn default() -> GaussLegendreCollocation<T>
+    {
+        GaussLegendreCollocation::new(2, 100, T::from_f64(1.0e3) * T::default_epsilon())
+    }
+}
+
+impl<T> SymplecticMethod<T> for GaussLegendreCollocation<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        let s: usize = self.stages();
+
+        // Stage derivatives, initialized with the derivative at the start point
+        let mut k_q: Vec<Vector<T>> = vec![prob.dh_dp(p_n); s];
+        let mut k_p: Vec<Vector<T>> = vec![-prob.dh_dq(q_n); s];
+
+        let stage = |k: &[Vector<T>], x_n: &Vector<T>, i: usize| {
+            self.a[i].iter().zip(k.iter()).fold(x_n.clone(), |x, (a_ij, k_j)| x + k_j * &(*a_ij * *h))
+        };
+
+        let mut converged: bool = false;
+        for _it in 0..self.iters
+        {
+            let mut diff: T = T::zero();
+            let mut k_q_new: Vec<Vector<T>> = Vec::with_capacity(s);
+            let mut k_p_new: Vec<Vector<T>> = Vec::with_capacity(s);
+
+            for i in 0..s
+            {
+                let q_i: Vector<T> = stage(&k_q, q_n, i);
+                let p_i: Vector<T> = stage(&k_p, p_n, i);
+
+                let k_q_i: Vector<T> = prob.dh_dp(&p_i);
+                let k_p_i: Vector<T> = -prob.dh_dq(&q_i);
+
+                diff = diff.max((&k_q_i - &k_q[i]).p_norm(&T::from_f64(2.0)) * h.abs())
+                           .max((&k_p_i - &k_p[i]).p_norm(&T::from_f64(2.0)) * h.abs());
+
+                k_q_new.push(k_q_i);
+                k_p_new.push(k_p_i);
+            }
+
+            k_q = k_q_new;
+            k_p = k_p_new;
+
+            if diff <= self.tolerance_abs
+            {
+                converged = true;
+                break;
+            }
+        }
+
+        if !converged
+        {
+            return Err("Fixed point iteration did not converge");
+        }
+
+        let q: Vector<T> = self.b.iter().zip(k_q.iter()).fold(q_n.clone(), |q, (b_i, k_i)| q + k_i * &(*b_i * *h));
+        let p: Vector<T> = self.b.iter().zip(k_p.iter()).fold(p_n.clone(), |p, (b_i, k_i)| p + k_i * &(*b_i * *h));
+
+        Ok((q, p))
+    }
+
+    fn order(&self) -> u8
+    {
+        2 * self.stages() as u8
+    }
+}
@@ -0,0 +1,64 @@
+//! Solves a Hamiltonian system using the implicit midpoint rule.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::{GaussLegendreCollocation, SymplecticMethod}};
+use std::default::Default;
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Implicit midpoint rule
+///
+/// ```math
+/// y_{n+1} = y_n + h f\left(\frac{y_n + y_{n+1}}{2}\right)
+/// ```
+///
+/// It is the Gauss-Legendre collocation method with one stage.
+///
+/// <https://en.wikipedia.org/wiki/Midpoint_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct ImplicitMidpoint<T>
+{
+    collocation: GaussLegendreCollocation<T>,
+}
+
+impl<T> ImplicitMidpoint<T>
+    where T: Real
+{
+    /// Creates an implicit midpoint instance
+    ///
+    /// # Arguments
+    ///
+    /// * 'iters': maximum number of fixed point iterations per step
+    /// * 'tolerance_abs': absolute tolerance of the fixed point iteration
+    pub fn new(iters: u32, tolerance_abs: T) -> ImplicitMidpoint<T>
+    {
+        ImplicitMidpoint { collocation: GaussLegendreCollocation::new(1, iters, tolerance_abs) }
+    }
+}
+
+impl<T> Default for ImplicitMidpoint<T>
+    where T: Real
+{
+    /// The tolerance is a small multiple of the machine epsilon of T
+    fn default() -> ImplicitMidpoint<T>
+    {
+        ImplicitMidpoint::new(100, T::from_f64(1.0e3) * T::default_epsilon())
+    }
+}
+
+impl<T> SymplecticMethod<T> for ImplicitMidpoint<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        self.collocation.do_step(prob, q_n, p_n, h)
+    }
+
+    /// The implicit midpoint rule is a second order method
+    fn order(&self) -> u8
+    {
+        2
+    }
+}
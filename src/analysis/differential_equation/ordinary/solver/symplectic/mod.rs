@@ -0,0 +1,26 @@
+//! Symplectic integrators for separable Hamiltonian systems
+//!
+//! Symplectic integrators preserve the symplectic structure of the phase
+//! space. Thus the energy error of the numerical solution stays bounded over
+//! exponentially long time intervals, instead of drifting as it does with
+//! general purpose Runge-Kutta methods.
+//!
+//! Geometric Numerical Integration
+//! E. Hairer, C. Lubich, G. Wanner
+mod symplectic_method;
+mod symplectic_stepper;
+mod symplecticeuler;
+mod stormerverlet;
+mod yoshida4;
+mod yoshida6;
+mod gausslegendrecollocation;
+mod implicitmidpoint;
+
+pub use symplectic_method::SymplecticMethod;
+pub use symplectic_stepper::SymplecticStepper;
+pub use symplecticeuler::SymplecticEuler;
+pub use stormerverlet::StormerVerlet;
+pub use yoshida4::Yoshida4;
+pub use yoshida6::Yoshida6;
+pub use gausslegendrecollocation::GaussLegendreCollocation;
+pub use implicitmidpoint::ImplicitMidpoint;
@@ -0,0 +1,55 @@
+//! Solves a Hamiltonian system using the Störmer-Verlet method.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::SymplecticMethod};
+use std::default::Default;
+use std::clone::Clone;
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Störmer-Verlet method, also known as leapfrog or velocity Verlet
+///
+/// ```math
+/// p_{n+1/2} = p_n - \frac{h}{2} \nabla V(q_n) \\
+/// q_{n+1} = q_n + h \nabla T(p_{n+1/2}) \\
+/// p_{n+1} = p_{n+1/2} - \frac{h}{2} \nabla V(q_{n+1})
+/// ```
+///
+/// <https://en.wikipedia.org/wiki/Verlet_integration>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StormerVerlet<T>
+{
+    phantom: PhantomData<T>,
+}
+
+impl<T> StormerVerlet<T>
+    where T: Real
+{
+    pub(super) fn step<H>(prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> (Vector<T>, Vector<T>)
+        where H: SeparableHamiltonian<T>
+    {
+        let h_2: T = *h / T::from_f64(2.0);
+        let p_h: Vector<T> = p_n - &(&prob.dh_dq(q_n) * &h_2);
+        let q: Vector<T> = q_n + &(&prob.dh_dp(&p_h) * h);
+        let p: Vector<T> = &p_h - &(&prob.dh_dq(&q) * &h_2);
+
+        (q, p)
+    }
+}
+
+impl<T> SymplecticMethod<T> for StormerVerlet<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        Ok(StormerVerlet::step(prob, q_n, p_n, h))
+    }
+
+    /// Störmer-Verlet is a second order method
+    fn order(&self) -> u8
+    {
+        2
+    }
+}
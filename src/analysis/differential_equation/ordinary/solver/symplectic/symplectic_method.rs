@@ -0,0 +1,13 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::SeparableHamiltonian;
+
+/// Interface of a one-step symplectic integrator
+pub trait SymplecticMethod<T>
+    where T: Real
+{
+    /// Advances the coordinates `q_n` and the momenta `p_n` by the step size `h`
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>;
+
+    fn order(&self) -> u8;
+}
@@ -0,0 +1,147 @@
+//! Fixed step size stepper for symplectic methods
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::SymplecticMethod};
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fixed step size stepper for symplectic methods
+///
+/// Symplectic methods lose their favourable long-time behaviour if the step
+/// size is varied, therefore they are only used with a constant step size.
+///
+/// # Example
+///
+/// The harmonic oscillator $H(q, p) = \frac{1}{2}p^2 + \frac{1}{2}q^2$
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::{StormerVerlet, SymplecticStepper}},
+/// };
+///
+/// pub struct Oscillator;
+///
+/// impl SeparableHamiltonian<f64> for Oscillator
+/// {
+///     fn dh_dq(&self, q: &Vector<f64>) -> Vector<f64>
+///     {
+///         q.clone()
+///     }
+///
+///     fn dh_dp(&self, p: &Vector<f64>) -> Vector<f64>
+///     {
+///         p.clone()
+///     }
+///
+///     fn hamiltonian(&self, q: &Vector<f64>, p: &Vector<f64>) -> f64
+///     {
+///         0.5 * (p.dotp(p) + q.dotp(q))
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 100.0)
+///     }
+///
+///     fn init_cond(&self) -> (Vector<f64>, Vector<f64>)
+///     {
+///         (vector![1.0], vector![0.0])
+///     }
+/// }
+///
+/// let solver: SymplecticStepper<f64> = SymplecticStepper::new(0.01);
+///
+/// let (t, q, p): (Vec<f64>, Vec<Vector<f64>>, Vec<Vector<f64>>) = solver.solve(&Oscillator, &StormerVerlet::default()).unwrap();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct SymplecticStepper<T>
+{
+    /// Step size
+    step_size: T,
+}
+
+impl<T> SymplecticStepper<T>
+    where T: Real
+{
+    /// Creates an instance with the given step size
+    ///
+    /// # Arguments
+    ///
+    /// * 'step_size'
+    ///
+    /// # Panics
+    ///
+    /// if 'step_size' <= 0.0
+    pub fn new(step_size: T) -> SymplecticStepper<T>
+    {
+        if step_size <= T::zero()
+        {
+            panic!();
+        }
+        SymplecticStepper { step_size }
+    }
+
+    /// Solves the Hamiltonian system `prob` with `method`
+    ///
+    /// # Return
+    ///
+    /// The time points and the coordinates and momenta at these points.
+    ///
+    /// The system is integrated backward in time if t_span.0 > t_span.1. As
+    /// the Hamiltonian does not depend on the time, the steps are just taken
+    /// with a negative step size.
+    #[allow(clippy::type_complexity)]
+    pub fn solve<H, M>(&self, prob: &H, method: &M) -> Result<(Vec<T>, Vec<Vector<T>>, Vec<Vector<T>>), &'static str>
+        where H: SeparableHamiltonian<T>,
+              M: SymplecticMethod<T>
+    {
+        let (t_start, t_stop): (T, T) = prob.time_span();
+        let direction: T = if t_stop < t_start { -T::one() } else { T::one() };
+        let length: T = (t_stop - t_start).abs();
+
+        let (mut q_n, mut p_n): (Vector<T>, Vector<T>) = prob.init_cond();
+        // elapsed time in the direction of the integration
+        let mut s_n: T = T::zero();
+
+        let limit = (length / self.step_size).ceil() + T::one();
+        let steps: usize = limit.to_u64() as usize;
+
+        let mut t_vec: Vec<T> = Vec::with_capacity(steps);
+        let mut q_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
+        let mut p_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
+
+        for _i in 0..steps
+        {
+            let h: T = self.step_size.min(length - s_n);
+
+            t_vec.push(t_start + direction * s_n);
+            q_vec.push(q_n.clone());
+            p_vec.push(p_n.clone());
+
+            let (q, p) = method.do_step(prob, &q_n, &p_n, &(direction * h))?;
+            q_n = q;
+            p_n = p;
+
+            s_n += h;
+        }
+
+        Ok((t_vec, q_vec, p_vec))
+    }
+
+    pub fn get_step_size(&self) -> &T
+    {
+        &self.step_size
+    }
+
+    pub fn set_step_size(&mut self, step_size: T)
+    {
+        self.step_size = step_size;
+    }
+}
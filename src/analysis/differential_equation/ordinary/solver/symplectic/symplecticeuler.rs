@@ -0,0 +1,42 @@
+//! Solves a Hamiltonian system using the symplectic Euler method.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::SymplecticMethod};
+use std::default::Default;
+use std::clone::Clone;
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Symplectic Euler method
+///
+/// ```math
+/// p_{n+1} = p_n - h \nabla V(q_n) \\
+/// q_{n+1} = q_n + h \nabla T(p_{n+1})
+/// ```
+///
+/// <https://en.wikipedia.org/wiki/Semi-implicit_Euler_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SymplecticEuler<T>
+{
+    phantom: PhantomData<T>,
+}
+
+impl<T> SymplecticMethod<T> for SymplecticEuler<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        let p: Vector<T> = p_n - &(&prob.dh_dq(q_n) * h);
+        let q: Vector<T> = q_n + &(&prob.dh_dp(&p) * h);
+
+        Ok((q, p))
+    }
+
+    /// Symplectic Euler is a first order method
+    fn order(&self) -> u8
+    {
+        1
+    }
+}
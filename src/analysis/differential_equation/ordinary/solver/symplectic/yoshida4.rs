@@ -0,0 +1,52 @@
+//! Solves a Hamiltonian system using Yoshida's 4th order method.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::{StormerVerlet, SymplecticMethod}};
+use std::default::Default;
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Yoshida's 4th order method
+///
+/// Symmetric composition of three Störmer-Verlet steps with the step sizes
+/// $w_1 h$, $w_0 h$ and $w_1 h$, where
+/// ```math
+/// w_1 = \frac{1}{2 - 2^{1/3}}, \quad w_0 = -\frac{2^{1/3}}{2 - 2^{1/3}}
+/// ```
+///
+/// H. Yoshida, Construction of higher order symplectic integrators,
+/// Physics Letters A 150 (1990)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Yoshida4<T>
+{
+    w: Vec<T>,
+}
+
+impl<T> Default for Yoshida4<T>
+    where T: Real
+{
+    fn default() -> Yoshida4<T>
+    {
+        let cbrt_2: f64 = 2.0f64.powf(1.0 / 3.0);
+        let w_1: T = T::from_f64(1.0 / (2.0 - cbrt_2));
+        let w_0: T = T::from_f64(-cbrt_2 / (2.0 - cbrt_2));
+
+        Yoshida4 { w: vec![w_1, w_0, w_1] }
+    }
+}
+
+impl<T> SymplecticMethod<T> for Yoshida4<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        Ok(self.w.iter().fold((q_n.clone(), p_n.clone()), |(q, p), w_i| StormerVerlet::step(prob, &q, &p, &(*w_i * *h))))
+    }
+
+    fn order(&self) -> u8
+    {
+        4
+    }
+}
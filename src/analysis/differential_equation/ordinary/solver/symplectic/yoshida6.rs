@@ -0,0 +1,53 @@
+//! Solves a Hamiltonian system using Yoshida's 6th order method.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::ordinary::{SeparableHamiltonian, solver::symplectic::{StormerVerlet, SymplecticMethod}};
+use std::default::Default;
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Yoshida's 6th order method
+///
+/// Symmetric composition of seven Störmer-Verlet steps with the step sizes
+/// $w_3 h, w_2 h, w_1 h, w_0 h, w_1 h, w_2 h, w_3 h$, where the weights are
+/// Yoshida's solution A.
+///
+/// H. Yoshida, Construction of higher order symplectic integrators,
+/// Physics Letters A 150 (1990)
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Yoshida6<T>
+{
+    w: Vec<T>,
+}
+
+impl<T> Default for Yoshida6<T>
+    where T: Real
+{
+    fn default() -> Yoshida6<T>
+    {
+        let w_1: f64 = -1.177_679_984_178_87;
+        let w_2: f64 = 0.235_573_213_359_357;
+        let w_3: f64 = 0.784_513_610_477_560;
+        let w_0: f64 = 1.0 - 2.0 * (w_1 + w_2 + w_3);
+
+        let w: Vec<T> = [w_3, w_2, w_1, w_0, w_1, w_2, w_3].iter().map(|w_i| T::from_f64(*w_i)).collect();
+
+        Yoshida6 { w }
+    }
+}
+
+impl<T> SymplecticMethod<T> for Yoshida6<T>
+    where T: Real
+{
+    fn do_step<H>(&self, prob: &H, q_n: &Vector<T>, p_n: &Vector<T>, h: &T) -> Result<(Vector<T>, Vector<T>), &'static str>
+        where H: SeparableHamiltonian<T>
+    {
+        Ok(self.w.iter().fold((q_n.clone(), p_n.clone()), |(q, p), w_i| StormerVerlet::step(prob, &q, &p, &(*w_i * *h))))
+    }
+
+    fn order(&self) -> u8
+    {
+        6
+    }
+}
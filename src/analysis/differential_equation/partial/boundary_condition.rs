@@ -0,0 +1,19 @@
+//! Boundary conditions
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Boundary condition at one end of a grid
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BoundaryCondition<T>
+{
+    /// Prescribed value $u = g$
+    Dirichlet(T),
+    /// Prescribed derivative $\frac{\partial u}{\partial x} = g$ in the
+    /// direction of the coordinate axis
+    Neumann(T),
+    /// The last node of the grid is identified with the first one. Has to be
+    /// set at both ends of the grid.
+    Periodic,
+}
@@ -0,0 +1,230 @@
+//! Sparse affine difference operator
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{
+    clone::Clone,
+    ops::{Add, Mul},
+};
+
+/// Sparse affine operator
+///
+/// ```math
+/// L u = A u + b
+/// ```
+/// The matrix $A$ is stored row by row with its nonzero entries only. The
+/// offset $b$ contains the contributions of the boundary values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct DifferenceOperator<T>
+{
+    /// Column index and value of the nonzero entries of each row
+    rows: Vec<Vec<(usize, T)>>,
+    offset: Vector<T>,
+}
+
+impl<T> DifferenceOperator<T>
+    where T: Real
+{
+    /// Creates an operator from its rows and the offset. Entries of a row with
+    /// the same column index are summed up.
+    ///
+    /// # Panics
+    ///
+    /// if the number of rows and the dimension of the offset differ or a
+    /// column index is out of bounds
+    pub fn new(rows: Vec<Vec<(usize, T)>>, offset: Vector<T>) -> DifferenceOperator<T>
+    {
+        let (m, _n): (usize, usize) = offset.dim();
+        if rows.len() != m
+        {
+            panic!("The number of rows and the dimension of the offset differ");
+        }
+
+        let rows: Vec<Vec<(usize, T)>> = rows.into_iter()
+                                             .map(|row| {
+                                                 let mut merged: Vec<(usize, T)> = Vec::with_capacity(row.len());
+                                                 for (j, a_ij) in row
+                                                 {
+                                                     if j >= m
+                                                     {
+                                                         panic!("Column index out of bounds");
+                                                     }
+                                                     match merged.iter_mut().find(|(k, _)| *k == j)
+                                                     {
+                                                         Some((_, a)) => *a += a_ij,
+                                                         None => merged.push((j, a_ij)),
+                                                     }
+                                                 }
+                                                 merged.sort_by_key(|a| a.0);
+                                                 merged
+                                             })
+                                             .collect();
+
+        DifferenceOperator { rows, offset }
+    }
+
+    /// Diagonal operator without offset
+    pub fn diagonal(d: &Vector<T>) -> DifferenceOperator<T>
+    {
+        let (m, _n): (usize, usize) = d.dim();
+        DifferenceOperator::new((0..m).map(|i| vec![(i, d[i])]).collect(), Vector::zero(m))
+    }
+
+    /// Number of rows and columns
+    pub fn dim(&self) -> usize
+    {
+        self.rows.len()
+    }
+
+    /// Nonzero entries of the i-th row
+    pub fn row(&self, i: usize) -> &[(usize, T)]
+    {
+        &self.rows[i]
+    }
+
+    pub fn offset(&self) -> &Vector<T>
+    {
+        &self.offset
+    }
+
+    /// Linear part $A$ of the operator, i.e. the operator without offset
+    pub fn linear(&self) -> DifferenceOperator<T>
+    {
+        DifferenceOperator { rows: self.rows.clone(),
+                             offset: Vector::zero(self.dim()) }
+    }
+
+    /// Number of nonzero entries
+    pub fn nnz(&self) -> usize
+    {
+        self.rows.iter().map(|row| row.len()).sum()
+    }
+
+    /// Evaluates $A u + b$
+    pub fn apply(&self, u: &Vector<T>) -> Vector<T>
+    {
+        let mut v: Vector<T> = self.offset.clone();
+        for (i, row) in self.rows.iter().enumerate()
+        {
+            for (j, a_ij) in row.iter()
+            {
+                v[i] += *a_ij * u[*j];
+            }
+        }
+
+        v
+    }
+
+    /// Dense matrix $A$ of the linear part
+    pub fn matrix(&self) -> Matrix<T>
+    {
+        let m: usize = self.dim();
+        let mut a: Matrix<T> = Matrix::zero(m, m);
+        for (i, row) in self.rows.iter().enumerate()
+        {
+            for (j, a_ij) in row.iter()
+            {
+                a[[i, *j]] = *a_ij;
+            }
+        }
+
+        a
+    }
+
+    /// Operator acting on each block of a vector, which consists of n blocks
+    /// of the dimension of self
+    /// ```math
+    /// I_n \otimes A
+    /// ```
+    pub fn kron_left(&self, n: usize) -> DifferenceOperator<T>
+    {
+        let m: usize = self.dim();
+        let mut rows: Vec<Vec<(usize, T)>> = Vec::with_capacity(n * m);
+        let mut offset: Vector<T> = Vector::zero(n * m);
+
+        for k in 0..n
+        {
+            for i in 0..m
+            {
+                rows.push(self.rows[i].iter().map(|(j, a_ij)| (k * m + *j, *a_ij)).collect());
+                offset[k * m + i] = self.offset[i];
+            }
+        }
+
+        DifferenceOperator { rows, offset }
+    }
+
+    /// Operator acting on the same components of n blocks of a vector
+    /// ```math
+    /// A \otimes I_n
+    /// ```
+    pub fn kron_right(&self, n: usize) -> DifferenceOperator<T>
+    {
+        let m: usize = self.dim();
+        let mut rows: Vec<Vec<(usize, T)>> = Vec::with_capacity(n * m);
+        let mut offset: Vector<T> = Vector::zero(n * m);
+
+        for i in 0..m
+        {
+            for k in 0..n
+            {
+                rows.push(self.rows[i].iter().map(|(j, a_ij)| (*j * n + k, *a_ij)).collect());
+                offset[i * n + k] = self.offset[i];
+            }
+        }
+
+        DifferenceOperator { rows, offset }
+    }
+}
+
+impl<T> Add<DifferenceOperator<T>> for DifferenceOperator<T>
+    where T: Real
+{
+    type Output = DifferenceOperator<T>;
+
+    /// Sums up two operators
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions differ
+    fn add(self, rhs: DifferenceOperator<T>) -> Self::Output
+    {
+        if self.dim() != rhs.dim()
+        {
+            panic!("The dimensions of the operators differ");
+        }
+
+        let rows: Vec<Vec<(usize, T)>> = self.rows
+                                             .into_iter()
+                                             .zip(rhs.rows)
+                                             .map(|(mut a, b)| {
+                                                 a.extend(b);
+                                                 a
+                                             })
+                                             .collect();
+
+        DifferenceOperator::new(rows, self.offset + rhs.offset)
+    }
+}
+
+impl<T> Mul<T> for DifferenceOperator<T>
+    where T: Real
+{
+    type Output = DifferenceOperator<T>;
+
+    /// Multiplies the operator with a scalar
+    fn mul(self, c: T) -> Self::Output
+    {
+        let rows: Vec<Vec<(usize, T)>> = self.rows
+                                             .into_iter()
+                                             .map(|row| row.into_iter().map(|(j, a_ij)| (j, a_ij * c)).collect())
+                                             .collect();
+
+        DifferenceOperator { rows,
+                             offset: self.offset * c }
+    }
+}
@@ -0,0 +1,325 @@
+//! Finite differences in one dimension
+use super::{BoundaryCondition, DifferenceOperator, Grid};
+use crate::algebra::{abstr::Real, linear::Vector};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Finite difference discretisation on a one-dimensional grid
+///
+/// The unknowns are the values at the grid nodes without the nodes with a
+/// Dirichlet condition. For periodic boundary conditions the last node is
+/// identified with the first one. Neumann conditions are incorporated with a
+/// ghost node, which mirrors the first inner node at the boundary.
+///
+/// The derivatives are approximated with three point formulas, which are of
+/// second order on uniform grids. On a non-uniform grid with the step sizes
+/// $h_l = x_i - x_{i-1}$ and $h_r = x_{i+1} - x_i$
+/// ```math
+/// \frac{\partial u}{\partial x}(x_i) \approx -\frac{h_r}{h_l(h_l + h_r)} u_{i-1} + \frac{h_r - h_l}{h_l h_r} u_i + \frac{h_l}{h_r(h_l + h_r)} u_{i+1}
+/// ```
+/// ```math
+/// \frac{\partial^2 u}{\partial x^2}(x_i) \approx \frac{2}{h_l(h_l + h_r)} u_{i-1} - \frac{2}{h_l h_r} u_i + \frac{2}{h_r(h_l + h_r)} u_{i+1}
+/// ```
+///
+/// Both formulas are exact for quadratic polynomials respectively polynomials
+/// of degree one and two.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::partial::{BoundaryCondition, FiniteDifference, Grid},
+/// };
+///
+/// let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 11),
+///                                                       BoundaryCondition::Dirichlet(0.0),
+///                                                       BoundaryCondition::Dirichlet(1.0));
+///
+/// let u: Vector<f64> = fd.discretise(|x| x * x);
+/// let u_xx: Vector<f64> = fd.laplacian().apply(&u);
+///
+/// for i in 0..9
+/// {
+///     assert_relative_eq!(2.0, u_xx[i], epsilon = 1.0e-10);
+/// }
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct FiniteDifference<T>
+{
+    grid: Grid<T>,
+    left: BoundaryCondition<T>,
+    right: BoundaryCondition<T>,
+    /// Index of the unknown belonging to a grid node
+    map: Vec<Option<usize>>,
+    /// Grid nodes of the unknowns
+    unknowns: Vec<usize>,
+}
+
+/// Value at a neighbouring node, which is either a multiple of an unknown
+/// plus a constant or a constant only
+struct Neighbour<T>
+{
+    h: T,
+    node: Option<usize>,
+    constant: T,
+}
+
+impl<T> FiniteDifference<T>
+    where T: Real
+{
+    /// Creates a discretisation on grid with the boundary conditions left and
+    /// right
+    ///
+    /// # Panics
+    ///
+    /// if only one of the boundary conditions is periodic
+    pub fn new(grid: Grid<T>,
+               left: BoundaryCondition<T>,
+               right: BoundaryCondition<T>)
+               -> FiniteDifference<T>
+    {
+        let periodic: bool = match (left, right)
+        {
+            (BoundaryCondition::Periodic, BoundaryCondition::Periodic) => true,
+            (BoundaryCondition::Periodic, _) | (_, BoundaryCondition::Periodic) =>
+            {
+                panic!("Periodic boundary conditions have to be set at both ends")
+            }
+            _ => false,
+        };
+
+        let n: usize = grid.len();
+        let mut map: Vec<Option<usize>> = vec![None; n];
+        let mut unknowns: Vec<usize> = Vec::with_capacity(n);
+
+        for (i, m) in map.iter_mut().enumerate()
+        {
+            let known: bool = match (i, left, right)
+            {
+                (0, BoundaryCondition::Dirichlet(_), _) => true,
+                (i, _, BoundaryCondition::Dirichlet(_)) if i == n - 1 => true,
+                (i, _, _) => periodic && i == n - 1,
+            };
+
+            if !known
+            {
+                *m = Some(unknowns.len());
+                unknowns.push(i);
+            }
+        }
+
+        if periodic
+        {
+            map[n - 1] = map[0];
+        }
+
+        FiniteDifference { grid,
+                           left,
+                           right,
+                           map,
+                           unknowns }
+    }
+
+    pub fn grid(&self) -> &Grid<T>
+    {
+        &self.grid
+    }
+
+    /// Number of unknowns
+    pub fn dim(&self) -> usize
+    {
+        self.unknowns.len()
+    }
+
+    /// Coordinates of the unknowns
+    pub fn nodes(&self) -> Vec<T>
+    {
+        let x: &Vec<T> = self.grid.nodes();
+        self.unknowns.iter().map(|i| x[*i]).collect()
+    }
+
+    /// Coordinates of the unknowns as points
+    pub fn points(&self) -> Vec<Vec<T>>
+    {
+        self.nodes().into_iter().map(|x| vec![x]).collect()
+    }
+
+    /// Evaluates f at the unknowns
+    pub fn discretise<F>(&self, f: F) -> Vector<T>
+        where F: Fn(T) -> T
+    {
+        Vector::new_column(self.nodes().into_iter().map(f).collect())
+    }
+
+    /// Values at all grid nodes including the Dirichlet values and the
+    /// identified last node for periodic boundary conditions
+    pub fn expand(&self, u: &Vector<T>) -> Vector<T>
+    {
+        let n: usize = self.grid.len();
+        let values: Vec<T> = (0..n).map(|i| match self.map[i]
+                                    {
+                                        Some(k) => u[k],
+                                        None =>
+                                        {
+                                            let bc: BoundaryCondition<T> = if i == 0 { self.left } else { self.right };
+                                            match bc
+                                            {
+                                                BoundaryCondition::Dirichlet(g) => g,
+                                                _ => unreachable!(),
+                                            }
+                                        }
+                                    })
+                                    .collect();
+
+        Vector::new_column(values)
+    }
+
+    /// Approximation of $\frac{\partial u}{\partial x}$ with central
+    /// differences
+    pub fn gradient(&self) -> DifferenceOperator<T>
+    {
+        self.assemble(|h_l, h_r| {
+                let w_l: T = -h_r / (h_l * (h_l + h_r));
+                let w_c: T = (h_r - h_l) / (h_l * h_r);
+                let w_r: T = h_l / (h_r * (h_l + h_r));
+                (w_l, w_c, w_r)
+            })
+    }
+
+    /// Approximation of $\frac{\partial^2 u}{\partial x^2}$
+    pub fn laplacian(&self) -> DifferenceOperator<T>
+    {
+        let two: T = T::from_f64(2.0);
+        self.assemble(|h_l, h_r| {
+                let w_l: T = two / (h_l * (h_l + h_r));
+                let w_c: T = -two / (h_l * h_r);
+                let w_r: T = two / (h_r * (h_l + h_r));
+                (w_l, w_c, w_r)
+            })
+    }
+
+    /// Approximation of $v \frac{\partial u}{\partial x}$ with first order
+    /// upwind differences
+    ///
+    /// The transport equation $u_t + v u_x = 0$ is discretised by the
+    /// operator `advection(v) * -1.0`.
+    pub fn advection(&self, v: T) -> DifferenceOperator<T>
+    {
+        self.assemble(|h_l, h_r| {
+                if v >= T::zero()
+                {
+                    (-v / h_l, v / h_l, T::zero())
+                }
+                else
+                {
+                    (T::zero(), -v / h_r, v / h_r)
+                }
+            })
+    }
+
+    /// Assembles a three point operator with the weights of the left, center
+    /// and right node
+    fn assemble<W>(&self, weights: W) -> DifferenceOperator<T>
+        where W: Fn(T, T) -> (T, T, T)
+    {
+        let m: usize = self.dim();
+        let mut rows: Vec<Vec<(usize, T)>> = Vec::with_capacity(m);
+        let mut offset: Vector<T> = Vector::zero(m);
+
+        for (k, i) in self.unknowns.iter().enumerate()
+        {
+            let left: Neighbour<T> = self.neighbour_left(*i);
+            let right: Neighbour<T> = self.neighbour_right(*i);
+            let (w_l, w_c, w_r): (T, T, T) = weights(left.h, right.h);
+
+            let mut row: Vec<(usize, T)> = vec![(k, w_c)];
+            for (w, neighbour) in [(w_l, left), (w_r, right)]
+            {
+                if let Some(j) = neighbour.node
+                {
+                    row.push((self.map[j].unwrap(), w));
+                }
+                offset[k] += w * neighbour.constant;
+            }
+            rows.push(row);
+        }
+
+        DifferenceOperator::new(rows, offset)
+    }
+
+    fn neighbour_left(&self, i: usize) -> Neighbour<T>
+    {
+        let x: &Vec<T> = self.grid.nodes();
+        let n: usize = x.len();
+
+        if i > 0
+        {
+            return match (i - 1, self.left)
+            {
+                (0, BoundaryCondition::Dirichlet(g)) => Neighbour { h: x[i] - x[i - 1],
+                                                                    node: None,
+                                                                    constant: g },
+                (j, _) => Neighbour { h: x[i] - x[j],
+                                      node: Some(j),
+                                      constant: T::zero() },
+            };
+        }
+
+        match self.left
+        {
+            BoundaryCondition::Periodic => Neighbour { h: x[n - 1] - x[n - 2],
+                                                       node: Some(n - 2),
+                                                       constant: T::zero() },
+            // ghost node u_{-1} = u_1 - 2 h g
+            BoundaryCondition::Neumann(g) =>
+            {
+                let h: T = x[1] - x[0];
+                Neighbour { h,
+                            node: Some(1),
+                            constant: -T::from_f64(2.0) * h * g }
+            }
+            BoundaryCondition::Dirichlet(_) => unreachable!(),
+        }
+    }
+
+    fn neighbour_right(&self, i: usize) -> Neighbour<T>
+    {
+        let x: &Vec<T> = self.grid.nodes();
+        let n: usize = x.len();
+
+        if i < n - 1
+        {
+            return match (i + 1, self.right)
+            {
+                (j, BoundaryCondition::Dirichlet(g)) if j == n - 1 => Neighbour { h: x[j] - x[i],
+                                                                                  node: None,
+                                                                                  constant: g },
+                (j, _) => Neighbour { h: x[j] - x[i],
+                                      node: Some(j),
+                                      constant: T::zero() },
+            };
+        }
+
+        match self.right
+        {
+            // ghost node u_{n} = u_{n-2} + 2 h g
+            BoundaryCondition::Neumann(g) =>
+            {
+                let h: T = x[n - 1] - x[n - 2];
+                Neighbour { h,
+                            node: Some(n - 2),
+                            constant: T::from_f64(2.0) * h * g }
+            }
+            // The last node is no unknown for periodic conditions
+            BoundaryCondition::Periodic | BoundaryCondition::Dirichlet(_) => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Finite differences in two dimensions
+use super::{DifferenceOperator, FiniteDifference};
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Finite difference discretisation on a tensor product grid
+///
+/// The operators are composed of the one-dimensional operators in x and y
+/// direction with Kronecker products. The unknown $u_{ij}$ at $(x_i, y_j)$
+/// has the index $i + j m_x$, where $m_x$ is the number of unknowns in x
+/// direction.
+/// ```math
+/// \Delta_h = I_{m_y} \otimes D_{xx} + D_{yy} \otimes I_{m_x}
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct FiniteDifference2D<T>
+{
+    x: FiniteDifference<T>,
+    y: FiniteDifference<T>,
+}
+
+impl<T> FiniteDifference2D<T>
+    where T: Real
+{
+    /// Creates a discretisation from the discretisations in x and y direction
+    pub fn new(x: FiniteDifference<T>, y: FiniteDifference<T>) -> FiniteDifference2D<T>
+    {
+        FiniteDifference2D { x, y }
+    }
+
+    /// Number of unknowns
+    pub fn dim(&self) -> usize
+    {
+        self.x.dim() * self.y.dim()
+    }
+
+    /// Coordinates of the unknowns as points
+    pub fn points(&self) -> Vec<Vec<T>>
+    {
+        let x: Vec<T> = self.x.nodes();
+        let y: Vec<T> = self.y.nodes();
+
+        y.iter().flat_map(|y_j| x.iter().map(move |x_i| vec![*x_i, *y_j])).collect()
+    }
+
+    /// Evaluates f at the unknowns
+    pub fn discretise<F>(&self, f: F) -> Vector<T>
+        where F: Fn(T, T) -> T
+    {
+        Vector::new_column(self.points().into_iter().map(|p| f(p[0], p[1])).collect())
+    }
+
+    /// Values at all grid nodes as a matrix, whose entry $(i, j)$ is the value
+    /// at $(x_i, y_j)$. At corners the condition in y direction determines the
+    /// value.
+    pub fn expand(&self, u: &Vector<T>) -> Matrix<T>
+    {
+        let m_x: usize = self.x.dim();
+        let m_y: usize = self.y.dim();
+        let n_x: usize = self.x.grid().len();
+        let n_y: usize = self.y.grid().len();
+
+        let rows: Vec<Vector<T>> = (0..m_y).map(|j| self.x.expand(&u.get_slice(j * m_x, (j + 1) * m_x - 1)))
+                                           .collect();
+
+        let mut values: Matrix<T> = Matrix::zero(n_x, n_y);
+        for i in 0..n_x
+        {
+            let column: Vector<T> = Vector::new_column(rows.iter().map(|row| row[i]).collect());
+            let column: Vector<T> = self.y.expand(&column);
+            for j in 0..n_y
+            {
+                values[[i, j]] = column[j];
+            }
+        }
+
+        values
+    }
+
+    /// Approximation of $\frac{\partial u}{\partial x}$
+    pub fn gradient_x(&self) -> DifferenceOperator<T>
+    {
+        self.x.gradient().kron_left(self.y.dim())
+    }
+
+    /// Approximation of $\frac{\partial u}{\partial y}$
+    pub fn gradient_y(&self) -> DifferenceOperator<T>
+    {
+        self.y.gradient().kron_right(self.x.dim())
+    }
+
+    /// Approximation of $\Delta u = \frac{\partial^2 u}{\partial x^2} +
+    /// \frac{\partial^2 u}{\partial y^2}$
+    pub fn laplacian(&self) -> DifferenceOperator<T>
+    {
+        self.x.laplacian().kron_left(self.y.dim()) + self.y.laplacian().kron_right(self.x.dim())
+    }
+
+    /// Approximation of $v_x \frac{\partial u}{\partial x} + v_y
+    /// \frac{\partial u}{\partial y}$ with first order upwind differences
+    pub fn advection(&self, v_x: T, v_y: T) -> DifferenceOperator<T>
+    {
+        self.x.advection(v_x).kron_left(self.y.dim()) + self.y.advection(v_y).kron_right(self.x.dim())
+    }
+}
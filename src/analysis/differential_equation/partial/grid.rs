@@ -0,0 +1,73 @@
+//! Spatial grid
+use crate::algebra::abstr::Real;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// One-dimensional grid with strictly increasing nodes
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Grid<T>
+{
+    nodes: Vec<T>,
+}
+
+impl<T> Grid<T>
+    where T: Real
+{
+    /// Creates a grid with the given nodes
+    ///
+    /// # Panics
+    ///
+    /// if there are less than three nodes or the nodes are not strictly
+    /// increasing
+    pub fn new(nodes: Vec<T>) -> Grid<T>
+    {
+        if nodes.len() < 3
+        {
+            panic!("A grid needs at least three nodes");
+        }
+        if nodes.windows(2).any(|w| w[1] <= w[0])
+        {
+            panic!("The nodes are not strictly increasing");
+        }
+
+        Grid { nodes }
+    }
+
+    /// Creates a uniform grid on $\lbrack a, b \rbrack$ with n nodes
+    ///
+    /// # Panics
+    ///
+    /// if n < 3 or a >= b
+    pub fn uniform(a: T, b: T, n: usize) -> Grid<T>
+    {
+        if n < 3
+        {
+            panic!("A grid needs at least three nodes");
+        }
+
+        let h: T = (b - a) / T::from_u64((n - 1) as u64);
+        let mut nodes: Vec<T> = (0..n - 1).map(|i| a + T::from_u64(i as u64) * h).collect();
+        nodes.push(b);
+
+        Grid::new(nodes)
+    }
+
+    pub fn nodes(&self) -> &Vec<T>
+    {
+        &self.nodes
+    }
+
+    /// Number of nodes
+    pub fn len(&self) -> usize
+    {
+        self.nodes.len()
+    }
+
+    /// A grid has at least three nodes, hence it is never empty
+    pub fn is_empty(&self) -> bool
+    {
+        false
+    }
+}
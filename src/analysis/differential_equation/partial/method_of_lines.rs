@@ -0,0 +1,229 @@
+//! Semi-discretised partial differential equation
+use super::DifferenceOperator;
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Pointwise source term $R(t, x, u)$ of a partial differential equation
+pub trait Reaction<T>
+{
+    /// Value of the source term at time t and point x
+    fn eval(&self, t: &T, x: &[T], u: &T) -> T;
+
+    /// Partial derivative $\frac{\partial R}{\partial u}$
+    fn derivative(&self, t: &T, x: &[T], u: &T) -> T;
+}
+
+/// Vanishing source term
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoReaction;
+
+impl<T> Reaction<T> for NoReaction
+    where T: Real
+{
+    fn eval(&self, _t: &T, _x: &[T], _u: &T) -> T
+    {
+        T::zero()
+    }
+
+    fn derivative(&self, _t: &T, _x: &[T], _u: &T) -> T
+    {
+        T::zero()
+    }
+}
+
+/// Method of lines
+///
+/// The spatial discretisation $L_h$ of a partial differential equation
+/// ```math
+/// \frac{\partial u}{\partial t} = L u + R(t, x, u)
+/// ```
+/// turns it into the system of ordinary differential equations
+/// ```math
+/// \frac{d u_h}{d t} = L_h u_h + R(t, x_h, u_h)
+/// ```
+/// which is solved with the solvers for explicit or implicit ODEs. The
+/// Jacobian $L_h + \operatorname{diag}(\frac{\partial R}{\partial u})$ is
+/// sparse. It is available as a sparse operator and as a dense matrix for the
+/// implicit solvers.
+///
+/// The boundary values are constant in time.
+///
+/// # Example
+///
+/// Heat equation $u_t = u_{xx}$ with homogeneous Dirichlet conditions
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differential_equation::{
+///         ordinary::solver::runge_kutta::{FixedStepper, Ralston2},
+///         partial::{BoundaryCondition, FiniteDifference, Grid, MethodOfLines},
+///     },
+/// };
+/// use std::f64::consts::PI;
+///
+/// let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 21),
+///                                                       BoundaryCondition::Dirichlet(0.0),
+///                                                       BoundaryCondition::Dirichlet(0.0));
+///
+/// let problem = MethodOfLines::new(fd.laplacian(), (0.0, 0.1), fd.discretise(|x| (PI * x).sin()));
+///
+/// let solver: FixedStepper<f64> = FixedStepper::new(0.001);
+/// let (t, u): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &Ralston2::default()).unwrap();
+///
+/// let u_end: &Vector<f64> = u.last().unwrap();
+/// let exact: Vector<f64> = fd.discretise(|x| (PI * x).sin() * (-PI * PI * t.last().unwrap()).exp());
+///
+/// assert_relative_eq!(exact, *u_end, epsilon = 5.0e-3);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct MethodOfLines<T, R>
+{
+    operator: DifferenceOperator<T>,
+    time_span: (T, T),
+    init_cond: Vector<T>,
+    points: Vec<Vec<T>>,
+    reaction: R,
+}
+
+impl<T> MethodOfLines<T, NoReaction>
+    where T: Real
+{
+    /// Creates the semi-discretised equation $\frac{d u_h}{d t} = L_h u_h$
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the operator and the initial condition differ
+    pub fn new(operator: DifferenceOperator<T>,
+               time_span: (T, T),
+               init_cond: Vector<T>)
+               -> MethodOfLines<T, NoReaction>
+    {
+        let (m, _n): (usize, usize) = init_cond.dim();
+        if operator.dim() != m
+        {
+            panic!("The dimensions of the operator and the initial condition differ");
+        }
+
+        MethodOfLines { operator,
+                        time_span,
+                        init_cond,
+                        points: Vec::new(),
+                        reaction: NoReaction }
+    }
+}
+
+impl<T, R> MethodOfLines<T, R>
+    where T: Real,
+          R: Reaction<T>
+{
+    /// Adds the source term reaction evaluated at the coordinates of the
+    /// unknowns
+    ///
+    /// # Panics
+    ///
+    /// if the number of points and unknowns differ
+    pub fn with_reaction<S>(self, points: Vec<Vec<T>>, reaction: S) -> MethodOfLines<T, S>
+        where S: Reaction<T>
+    {
+        if points.len() != self.operator.dim()
+        {
+            panic!("The number of points and unknowns differ");
+        }
+
+        MethodOfLines { operator: self.operator,
+                        time_span: self.time_span,
+                        init_cond: self.init_cond,
+                        points,
+                        reaction }
+    }
+
+    pub fn operator(&self) -> &DifferenceOperator<T>
+    {
+        &self.operator
+    }
+
+    /// Jacobian as sparse operator without offset
+    pub fn sparse_jacobian(&self, t: &T, u: &Vector<T>) -> DifferenceOperator<T>
+    {
+        let jacobian: DifferenceOperator<T> = self.operator.linear();
+        if self.points.is_empty()
+        {
+            return jacobian;
+        }
+
+        let d: Vec<T> = self.points
+                            .iter()
+                            .enumerate()
+                            .map(|(i, x)| self.reaction.derivative(t, x, &u[i]))
+                            .collect();
+
+        jacobian + DifferenceOperator::diagonal(&Vector::new_column(d))
+    }
+}
+
+impl<T, R> ExplicitODE<T> for MethodOfLines<T, R>
+    where T: Real,
+          R: Reaction<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        let mut f: Vector<T> = self.operator.apply(x);
+        for (i, p) in self.points.iter().enumerate()
+        {
+            f[i] += self.reaction.eval(t, p, &x[i]);
+        }
+
+        f
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
+
+impl<T, R> ImplicitODE<T> for MethodOfLines<T, R>
+    where T: Real,
+          R: Reaction<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        ExplicitODE::func(self, t, x)
+    }
+
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        self.sparse_jacobian(t, x).matrix()
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.init_cond.clone()
+    }
+}
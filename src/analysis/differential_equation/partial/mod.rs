@@ -0,0 +1,26 @@
+//! Partial differential equations
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Method_of_lines>
+//!
+//! Time dependent partial differential equations in one and two space
+//! dimensions are discretised in space with finite differences. The resulting
+//! system of ordinary differential equations is solved with the solvers of
+//! the module ordinary.
+//!
+//! Numerical Solution of Time-Dependent Advection-Diffusion-Reaction Equations
+//! W. Hundsdorfer, J. Verwer
+
+mod grid;
+mod boundary_condition;
+mod difference_operator;
+mod finite_difference;
+mod finite_difference_2d;
+mod method_of_lines;
+
+pub use grid::Grid;
+pub use boundary_condition::BoundaryCondition;
+pub use difference_operator::DifferenceOperator;
+pub use finite_difference::FiniteDifference;
+pub use finite_difference_2d::FiniteDifference2D;
+pub use method_of_lines::{MethodOfLines, NoReaction, Reaction};
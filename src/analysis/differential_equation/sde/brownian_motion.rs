@@ -0,0 +1,129 @@
+//! Brownian motion
+use crate::algebra::{abstr::Real, linear::Vector};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::marker::PhantomData;
+
+/// Seedable generator of the increments of a Brownian motion (Wiener
+/// process)
+///
+/// The increments $\Delta W = W_{t + h} - W_t$ are independent and normally
+/// distributed with mean zero and variance h. Two generators created with the
+/// same seed produce the same sequence of increments.
+///
+/// <https://en.wikipedia.org/wiki/Wiener_process>
+///
+/// # Example
+///
+/// ```
+/// use mathru::{algebra::linear::Vector, analysis::differential_equation::sde::BrownianMotion};
+///
+/// let mut brownian: BrownianMotion<f64> = BrownianMotion::new(42);
+///
+/// // Path of a two-dimensional Brownian motion on [0, 1]
+/// let (t, w): (Vec<f64>, Vec<Vector<f64>>) = brownian.path(2, (0.0, 1.0), 0.01);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BrownianMotion<T>
+{
+    rng: StdRng,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for BrownianMotion<T>
+    where T: Real
+{
+    /// Creates a generator seeded from the operating system's entropy source
+    fn default() -> BrownianMotion<T>
+    {
+        BrownianMotion { rng: StdRng::from_entropy(),
+                         phantom: PhantomData }
+    }
+}
+
+impl<T> BrownianMotion<T>
+    where T: Real
+{
+    /// Creates a generator with the given seed
+    pub fn new(seed: u64) -> BrownianMotion<T>
+    {
+        BrownianMotion { rng: StdRng::seed_from_u64(seed),
+                         phantom: PhantomData }
+    }
+
+    /// Returns a sample of the standard normal distribution
+    pub fn standard_normal(&mut self) -> T
+    {
+        // Marsaglia's polar method
+        let mut s: T = T::one();
+        let mut v1: T = T::one();
+        let mut v2: T;
+
+        while s >= T::one() || s == T::zero()
+        {
+            let u1: T = T::from_f64(self.rng.gen::<f64>());
+            let u2: T = T::from_f64(self.rng.gen::<f64>());
+            v1 = T::from_f64(2.0) * u1 - T::one();
+            v2 = T::from_f64(2.0) * u2 - T::one();
+            s = v1 * v1 + v2 * v2
+        }
+
+        v1 * (-T::from_f64(2.0) * s.ln() / s).sqrt()
+    }
+
+    /// Returns the increment $\Delta W$ of a d-dimensional Brownian motion
+    /// over a time step h
+    pub fn increment(&mut self, d: usize, h: &T) -> Vector<T>
+    {
+        let sqrt_h: T = h.sqrt();
+        let mut dw: Vector<T> = Vector::zero(d);
+        for j in 0..d
+        {
+            dw[j] = self.standard_normal() * sqrt_h;
+        }
+
+        dw
+    }
+
+    /// Returns the increment $\Delta W$ of a one-dimensional Brownian motion
+    /// over a time step h together with the double integral
+    /// ```math
+    /// \Delta Z = \int_{t}^{t + h} \int_{t}^{s} dW_r ds
+    /// ```
+    /// $(\Delta W, \Delta Z)$ are jointly normally distributed with
+    /// $E(\Delta Z^2) = \frac{h^3}{3}$ and $E(\Delta W \Delta Z) =
+    /// \frac{h^2}{2}$.
+    pub fn increment_with_integral(&mut self, h: &T) -> (T, T)
+    {
+        let u_1: T = self.standard_normal();
+        let u_2: T = self.standard_normal();
+        let sqrt_h: T = h.sqrt();
+
+        let dw: T = u_1 * sqrt_h;
+        let dz: T = T::from_f64(0.5) * *h * sqrt_h * (u_1 + u_2 / T::from_f64(3.0).sqrt());
+
+        (dw, dz)
+    }
+
+    /// Returns a path of a d-dimensional Brownian motion starting with $W_{t_0}
+    /// = 0$, sampled with the step size h
+    pub fn path(&mut self, d: usize, t_span: (T, T), h: T) -> (Vec<T>, Vec<Vector<T>>)
+    {
+        let mut t_n: T = t_span.0;
+        let mut w_n: Vector<T> = Vector::zero(d);
+
+        let mut t_vec: Vec<T> = vec![t_n];
+        let mut w_vec: Vec<Vector<T>> = vec![w_n.clone()];
+
+        while t_n < t_span.1
+        {
+            let h_n: T = h.min(t_span.1 - t_n);
+            w_n += self.increment(d, &h_n);
+            t_n += h_n;
+
+            t_vec.push(t_n);
+            w_vec.push(w_n.clone());
+        }
+
+        (t_vec, w_vec)
+    }
+}
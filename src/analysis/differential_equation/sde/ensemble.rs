@@ -0,0 +1,101 @@
+//! Monte Carlo ensemble
+use crate::algebra::{abstr::Real, linear::Vector};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::clone::Clone;
+
+/// Independent paths of the solution of a stochastic differential equation,
+/// sampled at the same time points
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Ensemble<T>
+{
+    t: Vec<T>,
+    paths: Vec<Vec<Vector<T>>>,
+}
+
+impl<T> Ensemble<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 't': time points
+    /// * 'paths': values of the paths at the time points
+    ///
+    /// # Panics
+    ///
+    /// if there are no paths or the length of a path differs from the number
+    /// of time points
+    pub fn new(t: Vec<T>, paths: Vec<Vec<Vector<T>>>) -> Ensemble<T>
+    {
+        if paths.is_empty()
+        {
+            panic!("The ensemble has no paths");
+        }
+        if paths.iter().any(|path| path.len() != t.len())
+        {
+            panic!("The paths are not sampled at the time points");
+        }
+
+        Ensemble { t, paths }
+    }
+
+    pub fn time(&self) -> &Vec<T>
+    {
+        &self.t
+    }
+
+    pub fn paths(&self) -> &Vec<Vec<Vector<T>>>
+    {
+        &self.paths
+    }
+
+    /// Sample mean of the paths at each time point
+    pub fn mean(&self) -> Vec<Vector<T>>
+    {
+        let n: T = T::from_u64(self.paths.len() as u64);
+
+        (0..self.t.len()).map(|i| {
+                             self.paths
+                                 .iter()
+                                 .skip(1)
+                                 .fold(self.paths[0][i].clone(), |s, path| s + path[i].clone())
+                             / n
+                         })
+                         .collect()
+    }
+
+    /// Unbiased sample variance of each component of the paths at each time
+    /// point
+    ///
+    /// # Panics
+    ///
+    /// if the ensemble has less than two paths
+    pub fn variance(&self) -> Vec<Vector<T>>
+    {
+        if self.paths.len() < 2
+        {
+            panic!("The sample variance requires at least two paths");
+        }
+
+        let n: T = T::from_u64(self.paths.len() as u64);
+        let mean: Vec<Vector<T>> = self.mean();
+
+        mean.iter()
+            .enumerate()
+            .map(|(i, mean_i)| {
+                let (m, _n): (usize, usize) = mean_i.dim();
+                let mut var_i: Vector<T> = Vector::zero(m);
+                for path in self.paths.iter()
+                {
+                    for k in 0..m
+                    {
+                        let d: T = path[i][k] - mean_i[k];
+                        var_i[k] += d * d;
+                    }
+                }
+                var_i / (n - T::one())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,51 @@
+//! Explicit stochastic differential equation
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Interpretation of the stochastic integral
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Calculus
+{
+    /// The integrand is evaluated at the left end of each subinterval
+    Ito,
+    /// The integrand is evaluated at the midpoint of each subinterval
+    Stratonovich,
+}
+
+/// Explicit stochastic differential equation
+///
+/// ```math
+/// dX_t = a(t, X_t) dt + b(t, X_t) dW_t
+/// ```
+/// with the drift $a(t, x) \in \mathbb{R}^m$, the diffusion $b(t, x) \in
+/// \mathbb{R}^{m \times d}$ and a d-dimensional Wiener process $W_t$.
+///
+/// This trait has to be implemented by every SDE which shall be solved with
+/// a stochastic integration method.
+///
+/// <https://en.wikipedia.org/wiki/Stochastic_differential_equation>
+pub trait ExplicitSDE<T>
+    where T: Real
+{
+    /// Drift $a(t, x)$
+    fn drift(&self, t: &T, x: &Vector<T>) -> Vector<T>;
+
+    /// Diffusion $b(t, x)$, the j-th column belongs to the j-th component of
+    /// the Wiener process
+    fn diffusion(&self, t: &T, x: &Vector<T>) -> Matrix<T>;
+
+    fn time_span(&self) -> (T, T);
+
+    fn init_cond(&self) -> Vector<T>;
+
+    /// Interpretation of the stochastic integral, Itô by default
+    fn calculus(&self) -> Calculus
+    {
+        Calculus::Ito
+    }
+}
@@ -0,0 +1,24 @@
+//! Stochastic differential equations
+//!
+//! Fore more information:<br>
+//! <https://en.wikipedia.org/wiki/Stochastic_differential_equation>
+//!
+//! ```math
+//! dX_t = a(t, X_t) dt + b(t, X_t) dW_t
+//! ```
+//! The stochastic integral is either interpreted in the sense of Itô or
+//! Stratonovich. The SDE is solved path by path with a fixed step size,
+//! driven by a seedable generator of the increments of the Wiener process.
+//! Independent paths can be combined to a Monte Carlo ensemble.
+//!
+//! Numerical Solution of Stochastic Differential Equations
+//! P. E. Kloeden, E. Platen
+
+mod explicit_sde;
+mod brownian_motion;
+mod ensemble;
+pub mod solver;
+
+pub use explicit_sde::{Calculus, ExplicitSDE};
+pub use brownian_motion::BrownianMotion;
+pub use ensemble::Ensemble;
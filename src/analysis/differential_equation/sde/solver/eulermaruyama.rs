@@ -0,0 +1,75 @@
+//! Solves an SDE using the Euler-Maruyama method.
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+use crate::analysis::differential_equation::sde::{solver::SDEMethod, BrownianMotion, Calculus, ExplicitSDE};
+use std::default::Default;
+use std::clone::Clone;
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Euler-Maruyama method
+///
+/// Itô SDEs are solved with
+/// ```math
+/// X_{n+1} = X_n + a(t_n, X_n) h + b(t_n, X_n) \Delta W_n
+/// ```
+/// Stratonovich SDEs are solved with the Euler-Heun method, which
+/// approximates the stochastic integral with the trapezoidal rule
+/// ```math
+/// \bar{X}_{n + 1} = X_n + a(t_n, X_n) h + b(t_n, X_n) \Delta W_n \\
+/// X_{n+1} = X_n + a(t_n, X_n) h + \frac{1}{2} \left(b(t_n, X_n) +
+/// b(t_{n + 1}, \bar{X}_{n + 1})\right) \Delta W_n
+/// ```
+/// Both methods converge with strong order 0.5 and weak order 1.
+///
+/// Numerical Solution of Stochastic Differential Equations
+/// P. E. Kloeden, E. Platen
+///
+/// <https://en.wikipedia.org/wiki/Euler–Maruyama_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EulerMaruyama<T>
+{
+    phantom: PhantomData<T>,
+}
+
+impl<T> SDEMethod<T> for EulerMaruyama<T>
+    where T: Real
+{
+    fn do_step<F>(&self,
+                  prob: &F,
+                  t_n: &T,
+                  x_n: &Vector<T>,
+                  h: &T,
+                  brownian: &mut BrownianMotion<T>)
+                  -> Result<Vector<T>, &'static str>
+        where F: ExplicitSDE<T>
+    {
+        let a: Vector<T> = prob.drift(t_n, x_n);
+        let b: Matrix<T> = prob.diffusion(t_n, x_n);
+        let (_m, d): (usize, usize) = b.dim();
+
+        let dw: Vector<T> = brownian.increment(d, h);
+        let x_e: Vector<T> = x_n + &(&a * h);
+        let x_bar: Vector<T> = &x_e + &(&b * &dw);
+
+        match prob.calculus()
+        {
+            Calculus::Ito => Ok(x_bar),
+            Calculus::Stratonovich =>
+            {
+                let b_bar: Matrix<T> = prob.diffusion(&(*t_n + *h), &x_bar);
+                Ok(x_e + &(b + b_bar) * &dw * T::from_f64(0.5))
+            }
+        }
+    }
+
+    /// Euler-Maruyama converges with strong order 0.5
+    fn order(&self) -> T
+    {
+        T::from_f64(0.5)
+    }
+}
@@ -0,0 +1,92 @@
+//! Solves an SDE using the Milstein method.
+use crate::algebra::{
+    abstr::Real,
+    linear::{Matrix, Vector},
+};
+use crate::analysis::differential_equation::sde::{solver::SDEMethod, BrownianMotion, Calculus, ExplicitSDE};
+use std::default::Default;
+use std::clone::Clone;
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Milstein method
+///
+/// Derivative free Milstein method, which converges with strong order 1.
+/// The derivatives of the diffusion are approximated with central
+/// differences at the supporting values $\Upsilon_j^{\pm} = X_n \pm b_j(t_n,
+/// X_n) \sqrt{h}$, where $b_j$ denotes the j-th column of the diffusion
+/// ```math
+/// X_{n+1} = X_n + a h + b \Delta W_n + \frac{1}{2 \sqrt{h}}
+/// \sum_{j_1, j_2 = 1}^d \left( b_{j_2}(t_n, \Upsilon_{j_1}^{+}) -
+/// b_{j_2}(t_n, \Upsilon_{j_1}^{-}) \right) I_{(j_1, j_2)}
+/// ```
+/// The double stochastic integrals are approximated with
+/// ```math
+/// I_{(j_1, j_2)} = \frac{1}{2} (\Delta W_{j_1} \Delta W_{j_2} - \delta_{j_1
+/// j_2} h)
+/// ```
+/// for Itô SDEs and $J_{(j_1, j_2)} = \frac{1}{2} \Delta W_{j_1} \Delta
+/// W_{j_2}$ for Stratonovich SDEs. This is exact for scalar noise and
+/// diagonal noise. For general multi-dimensional noise, the strong order 1 is
+/// only achieved if the noise is commutative, otherwise the Lévy areas would
+/// have to be simulated.
+///
+/// Numerical Solution of Stochastic Differential Equations
+/// P. E. Kloeden, E. Platen
+///
+/// <https://en.wikipedia.org/wiki/Milstein_method>
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Milstein<T>
+{
+    phantom: PhantomData<T>,
+}
+
+impl<T> SDEMethod<T> for Milstein<T>
+    where T: Real
+{
+    fn do_step<F>(&self,
+                  prob: &F,
+                  t_n: &T,
+                  x_n: &Vector<T>,
+                  h: &T,
+                  brownian: &mut BrownianMotion<T>)
+                  -> Result<Vector<T>, &'static str>
+        where F: ExplicitSDE<T>
+    {
+        let a: Vector<T> = prob.drift(t_n, x_n);
+        let b: Matrix<T> = prob.diffusion(t_n, x_n);
+        let (_m, d): (usize, usize) = b.dim();
+
+        let dw: Vector<T> = brownian.increment(d, h);
+        let sqrt_h: T = h.sqrt();
+        let mut x: Vector<T> = x_n + &(&(&a * h) + &(&b * &dw));
+
+        for j_1 in 0..d
+        {
+            let b_j: Vector<T> = &b.get_column(j_1) * &sqrt_h;
+            let db: Matrix<T> = prob.diffusion(t_n, &(x_n + &b_j)) - prob.diffusion(t_n, &(x_n - &b_j));
+
+            let mut integral: Vector<T> = Vector::zero(d);
+            for j_2 in 0..d
+            {
+                integral[j_2] = dw[j_1] * dw[j_2];
+                if j_1 == j_2 && prob.calculus() == Calculus::Ito
+                {
+                    integral[j_2] -= *h;
+                }
+            }
+
+            x += &db * &integral * (T::from_f64(0.25) / sqrt_h);
+        }
+
+        Ok(x)
+    }
+
+    /// Milstein converges with strong order 1
+    fn order(&self) -> T
+    {
+        T::one()
+    }
+}
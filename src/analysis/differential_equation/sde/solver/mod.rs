@@ -0,0 +1,12 @@
+//! This module provides algorithms to solve stochastic differential equations.
+mod sde_method;
+mod sde_stepper;
+mod eulermaruyama;
+mod milstein;
+mod srk15;
+
+pub use sde_method::SDEMethod;
+pub use sde_stepper::SDEStepper;
+pub use eulermaruyama::EulerMaruyama;
+pub use milstein::Milstein;
+pub use srk15::Srk15;
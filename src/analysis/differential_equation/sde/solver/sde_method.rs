@@ -0,0 +1,21 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::sde::{BrownianMotion, ExplicitSDE};
+
+/// Interface of a one-step method for stochastic differential equations
+pub trait SDEMethod<T>
+    where T: Real
+{
+    /// Advances the state `x_n` at time `t_n` by the step size `h`, the
+    /// increments of the Wiener process are drawn from `brownian`
+    fn do_step<F>(&self,
+                  prob: &F,
+                  t_n: &T,
+                  x_n: &Vector<T>,
+                  h: &T,
+                  brownian: &mut BrownianMotion<T>)
+                  -> Result<Vector<T>, &'static str>
+        where F: ExplicitSDE<T>;
+
+    /// Strong order of convergence
+    fn order(&self) -> T;
+}
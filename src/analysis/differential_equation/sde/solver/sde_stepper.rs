@@ -0,0 +1,178 @@
+//! Fixed step size stepper for stochastic differential equations
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::sde::{solver::SDEMethod, BrownianMotion, Ensemble, ExplicitSDE};
+use std::clone::Clone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Fixed step size stepper for stochastic differential equations
+///
+/// # Example
+///
+/// Geometric Brownian motion $dX_t = \mu X_t dt + \sigma X_t dW_t$
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differential_equation::sde::{
+///         solver::{Milstein, SDEStepper},
+///         BrownianMotion, Ensemble, ExplicitSDE,
+///     },
+/// };
+///
+/// pub struct GeometricBrownianMotion;
+///
+/// impl ExplicitSDE<f64> for GeometricBrownianMotion
+/// {
+///     fn drift(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         x * &0.05
+///     }
+///
+///     fn diffusion(&self, _t: &f64, x: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![0.2 * x[0]]
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         vector![100.0]
+///     }
+/// }
+///
+/// let solver: SDEStepper<f64> = SDEStepper::new(0.01);
+/// let mut brownian: BrownianMotion<f64> = BrownianMotion::new(1);
+///
+/// // Single path
+/// let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&GeometricBrownianMotion, &Milstein::default(), &mut brownian).unwrap();
+///
+/// // Monte Carlo ensemble of 100 paths
+/// let ensemble: Ensemble<f64> = solver.solve_ensemble(&GeometricBrownianMotion, &Milstein::default(), &mut brownian, 100).unwrap();
+/// let mean: Vec<Vector<f64>> = ensemble.mean();
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct SDEStepper<T>
+{
+    /// Step size
+    step_size: T,
+}
+
+impl<T> SDEStepper<T>
+    where T: Real
+{
+    /// Creates an instance with the given step size
+    ///
+    /// # Arguments
+    ///
+    /// * 'step_size'
+    ///
+    /// # Panics
+    ///
+    /// if 'step_size' <= 0.0
+    pub fn new(step_size: T) -> SDEStepper<T>
+    {
+        if step_size <= T::zero()
+        {
+            panic!();
+        }
+        SDEStepper { step_size }
+    }
+
+    /// Computes one path of the solution of `prob` with `method`
+    ///
+    /// # Return
+    ///
+    /// The time points and the values of the path at these points.
+    ///
+    /// # Panics
+    ///
+    /// if t_span.0 > t_span.1. An SDE can not be integrated backward in time,
+    /// because the Itô and Stratonovich integrals are defined forward in time.
+    pub fn solve<F, M>(&self,
+                       prob: &F,
+                       method: &M,
+                       brownian: &mut BrownianMotion<T>)
+                       -> Result<(Vec<T>, Vec<Vector<T>>), &'static str>
+        where F: ExplicitSDE<T>,
+              M: SDEMethod<T>
+    {
+        let (t_start, t_stop): (T, T) = prob.time_span();
+
+        if t_start > t_stop
+        {
+            panic!("An SDE can not be integrated backward in time")
+        }
+
+        let mut x_n: Vector<T> = prob.init_cond();
+        let mut t_n: T = t_start;
+
+        let steps: usize = ((t_stop - t_start) / self.step_size).ceil().to_u64() as usize + 1;
+        let mut t_vec: Vec<T> = Vec::with_capacity(steps);
+        let mut res_vec: Vec<Vector<T>> = Vec::with_capacity(steps);
+
+        t_vec.push(t_n);
+        res_vec.push(x_n.clone());
+
+        while t_n < t_stop
+        {
+            let h: T = self.step_size.min(t_stop - t_n);
+
+            x_n = method.do_step(prob, &t_n, &x_n, &h, brownian)?;
+            t_n += h;
+
+            t_vec.push(t_n);
+            res_vec.push(x_n.clone());
+        }
+
+        Ok((t_vec, res_vec))
+    }
+
+    /// Computes `paths` independent paths of the solution of `prob` with
+    /// `method`
+    ///
+    /// # Panic
+    ///
+    /// if t_span.0 > t_span.1 or paths is zero
+    pub fn solve_ensemble<F, M>(&self,
+                                prob: &F,
+                                method: &M,
+                                brownian: &mut BrownianMotion<T>,
+                                paths: usize)
+                                -> Result<Ensemble<T>, &'static str>
+        where F: ExplicitSDE<T>,
+              M: SDEMethod<T>
+    {
+        let mut t: Vec<T> = Vec::new();
+        let mut x: Vec<Vec<Vector<T>>> = Vec::with_capacity(paths);
+
+        for _i in 0..paths
+        {
+            let (t_i, x_i): (Vec<T>, Vec<Vector<T>>) = self.solve(prob, method, brownian)?;
+            t = t_i;
+            x.push(x_i);
+        }
+
+        Ok(Ensemble::new(t, x))
+    }
+
+    pub fn get_step_size(&self) -> &T
+    {
+        &self.step_size
+    }
+
+    pub fn set_step_size(&mut self, step_size: T)
+    {
+        self.step_size = step_size;
+    }
+}
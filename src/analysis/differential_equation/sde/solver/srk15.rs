@@ -0,0 +1,106 @@
+//! Solves an SDE using a stochastic Runge-Kutta method of strong order 1.5.
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::differential_equation::sde::{solver::SDEMethod, BrownianMotion, Calculus, ExplicitSDE};
+use std::default::Default;
+use std::clone::Clone;
+use std::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Explicit stochastic Runge-Kutta method of strong order 1.5
+///
+/// The method is derivative free and requires an Itô SDE with scalar noise,
+/// i.e. the diffusion has exactly one column. With the supporting values
+/// ```math
+/// \Upsilon_{\pm} = X_n + a h \pm b \sqrt{h} \\
+/// \Phi_{\pm} = \Upsilon_{+} \pm b(\Upsilon_{+}) \sqrt{h}
+/// ```
+/// a step is
+/// ```math
+/// X_{n+1} = X_n + b \Delta W + \frac{1}{2 \sqrt{h}} \left( a(\Upsilon_{+}) -
+/// a(\Upsilon_{-}) \right) \Delta Z + \frac{h}{4} \left( a(\Upsilon_{+}) + 2a +
+/// a(\Upsilon_{-}) \right) \\
+/// + \frac{1}{4 \sqrt{h}} \left( b(\Upsilon_{+}) - b(\Upsilon_{-}) \right)
+/// \left( \Delta W^2 - h \right) + \frac{1}{2h} \left( b(\Upsilon_{+}) - 2b +
+/// b(\Upsilon_{-}) \right) \left( \Delta W h - \Delta Z \right) \\
+/// + \frac{1}{4h} \left( b(\Phi_{+}) - b(\Phi_{-}) - b(\Upsilon_{+}) +
+/// b(\Upsilon_{-}) \right) \left( \frac{1}{3} \Delta W^2 - h \right) \Delta W
+/// ```
+/// where $\Delta Z$ is the double integral $\int \int dW ds$ over the step.
+/// The supporting values are evaluated at $t_{n + 1}$.
+///
+/// Numerical Solution of Stochastic Differential Equations, chapter 11.2
+/// P. E. Kloeden, E. Platen
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Srk15<T>
+{
+    phantom: PhantomData<T>,
+}
+
+impl<T> SDEMethod<T> for Srk15<T>
+    where T: Real
+{
+    fn do_step<F>(&self,
+                  prob: &F,
+                  t_n: &T,
+                  x_n: &Vector<T>,
+                  h: &T,
+                  brownian: &mut BrownianMotion<T>)
+                  -> Result<Vector<T>, &'static str>
+        where F: ExplicitSDE<T>
+    {
+        if prob.calculus() != Calculus::Ito
+        {
+            return Err("The strong order 1.5 scheme requires an Itô SDE");
+        }
+
+        let (_m, d): (usize, usize) = prob.diffusion(t_n, x_n).dim();
+        if d != 1
+        {
+            return Err("The strong order 1.5 scheme requires scalar noise");
+        }
+
+        let diffusion = |t: &T, x: &Vector<T>| -> Vector<T> { prob.diffusion(t, x).get_column(0) };
+
+        let (dw, dz): (T, T) = brownian.increment_with_integral(h);
+        let sqrt_h: T = h.sqrt();
+        let two: T = T::from_f64(2.0);
+        let four: T = T::from_f64(4.0);
+        let t_n1: T = *t_n + *h;
+
+        let a: Vector<T> = prob.drift(t_n, x_n);
+        let b: Vector<T> = diffusion(t_n, x_n);
+
+        let x_e: Vector<T> = x_n + &(&a * h);
+        let upsilon_p: Vector<T> = &x_e + &(&b * &sqrt_h);
+        let upsilon_m: Vector<T> = &x_e - &(&b * &sqrt_h);
+
+        let a_p: Vector<T> = prob.drift(&t_n1, &upsilon_p);
+        let a_m: Vector<T> = prob.drift(&t_n1, &upsilon_m);
+        let b_p: Vector<T> = diffusion(&t_n1, &upsilon_p);
+        let b_m: Vector<T> = diffusion(&t_n1, &upsilon_m);
+
+        let phi_p: Vector<T> = &upsilon_p + &(&b_p * &sqrt_h);
+        let phi_m: Vector<T> = &upsilon_p - &(&b_p * &sqrt_h);
+        let b_phi_p: Vector<T> = diffusion(&t_n1, &phi_p);
+        let b_phi_m: Vector<T> = diffusion(&t_n1, &phi_m);
+
+        let dw_2: T = dw * dw;
+
+        let mut x: Vector<T> = x_n + &(&b * &dw);
+        x += (&a_p - &a_m) * (dz / (two * sqrt_h));
+        x += (&a_p + &a_m + &a * &two) * (*h / four);
+        x += (&b_p - &b_m) * ((dw_2 - *h) / (four * sqrt_h));
+        x += (&b_p + &b_m - &b * &two) * ((dw * *h - dz) / (two * *h));
+        x += ((&b_phi_p - &b_phi_m) - (&b_p - &b_m)) * ((dw_2 / T::from_f64(3.0) - *h) * dw / (four * *h));
+
+        Ok(x)
+    }
+
+    /// The method converges with strong order 1.5
+    fn order(&self) -> T
+    {
+        T::from_f64(1.5)
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differentiation::{Dual, HyperDual, Tape, Var},
+        Function, Hessian, Jacobian,
+    },
+    optimization::Optim,
+};
+
+/// Function $f: \mathbb{R}^n \rightarrow \mathbb{R}^m$, whose Jacobian is
+/// computed by forward mode automatic differentiation
+///
+/// The closure is evaluated with dual numbers, one evaluation per column of
+/// the Jacobian. The adapter implements [`Function`], [`Jacobian`] and the
+/// `eval` and `jacobian` methods of [`Optim`], so it can be used with
+/// [`NewtonRaphson`](crate::analysis::NewtonRaphson),
+/// [`GaussNewton`](crate::optimization::GaussNewton) and
+/// [`LevenbergMarquardt`](crate::optimization::LevenbergMarquardt).
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         differentiation::{Dual, ForwardJacobian},
+///         Jacobian,
+///     },
+///     elementary::Exponential,
+/// };
+///
+/// let f = ForwardJacobian::new(|x: &Vector<Dual<f64>>| {
+///     Vector::new_column(vec![x[0] * x[1], x[0].exp()])
+/// });
+///
+/// let jacobian: Matrix<f64> = f.jacobian(&vector![2.0; 3.0]);
+///
+/// assert_relative_eq!(jacobian, matrix![3.0, 2.0; 2.0f64.exp(), 0.0]);
+/// # }
+/// ```
+pub struct ForwardJacobian<F>
+{
+    function: F,
+}
+
+impl<F> ForwardJacobian<F>
+{
+    /// Wraps the closure f
+    pub fn new<T>(function: F) -> ForwardJacobian<F>
+        where T: Real,
+              F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>
+    {
+        ForwardJacobian { function }
+    }
+}
+
+impl<T, F> Function<Vector<T>> for ForwardJacobian<F>
+    where T: Real,
+          F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, input: &Vector<T>) -> Vector<T>
+    {
+        let x: Vector<Dual<T>> = seed(input, |i| Dual::constant(input[i]));
+        let y: Vector<Dual<T>> = (self.function)(&x);
+        Vector::new_column(y.iter().map(|y_i| y_i.re).collect())
+    }
+}
+
+impl<T, F> Jacobian<T> for ForwardJacobian<F>
+    where T: Real,
+          F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let (n, _): (usize, usize) = input.dim();
+        let mut columns: Vec<T> = Vec::new();
+        let mut m: usize = 0;
+        for j in 0..n
+        {
+            let x: Vector<Dual<T>> = seed(input, |i| Dual::new(input[i], if i == j { T::one() } else { T::zero() }));
+            let y: Vector<Dual<T>> = (self.function)(&x);
+            m = y.dim().0;
+            columns.extend(y.iter().map(|y_i| y_i.eps));
+        }
+        Matrix::new(m, n, columns)
+    }
+}
+
+impl<T, F> Optim<T> for ForwardJacobian<F>
+    where T: Real,
+          F: Fn(&Vector<Dual<T>>) -> Vector<Dual<T>>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Function::eval(self, x)
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+}
+
+/// Scalar function $f: \mathbb{R}^n \rightarrow \mathbb{R}$, whose gradient
+/// and Hessian are computed by forward mode automatic differentiation
+///
+/// The closure is evaluated with hyper-dual numbers, one evaluation per
+/// entry of the upper triangle of the Hessian. The adapter implements
+/// [`Function`], [`Jacobian`] (the gradient as a $1 \times n$ matrix),
+/// [`Hessian`] and [`Optim`], so it can be used with
+/// [`Newton`](crate::optimization::Newton) and the gradient based
+/// optimizers.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         differentiation::{ForwardHessian, HyperDual},
+///         Hessian,
+///     },
+/// };
+///
+/// let f = ForwardHessian::new(|x: &Vector<HyperDual<f64>>| x[0] * x[0] * x[1]);
+///
+/// let hessian: Matrix<f64> = f.hessian(&vector![2.0; 3.0]);
+///
+/// assert_relative_eq!(hessian, matrix![6.0, 4.0; 4.0, 0.0]);
+/// # }
+/// ```
+pub struct ForwardHessian<F>
+{
+    function: F,
+}
+
+impl<F> ForwardHessian<F>
+{
+    /// Wraps the closure f
+    pub fn new<T>(function: F) -> ForwardHessian<F>
+        where T: Real,
+              F: Fn(&Vector<HyperDual<T>>) -> HyperDual<T>
+    {
+        ForwardHessian { function }
+    }
+}
+
+impl<T, F> Function<Vector<T>> for ForwardHessian<F>
+    where T: Real,
+          F: Fn(&Vector<HyperDual<T>>) -> HyperDual<T>
+{
+    type Codomain = T;
+
+    fn eval(&self, input: &Vector<T>) -> T
+    {
+        let x: Vector<HyperDual<T>> = seed(input, |i| HyperDual::hyper(input[i], T::zero(), T::zero(), T::zero()));
+        (self.function)(&x).value()
+    }
+}
+
+impl<T, F> Jacobian<T> for ForwardHessian<F>
+    where T: Real,
+          F: Fn(&Vector<HyperDual<T>>) -> HyperDual<T>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let (n, _): (usize, usize) = input.dim();
+        let gradient: Vec<T> = (0..n).map(|j| {
+                                         let x: Vector<HyperDual<T>> = seed(input, |i| {
+                                             let e: T = if i == j { T::one() } else { T::zero() };
+                                             HyperDual::hyper(input[i], e, T::zero(), T::zero())
+                                         });
+                                         (self.function)(&x).e1()
+                                     })
+                                     .collect();
+        Matrix::new(1, n, gradient)
+    }
+}
+
+impl<T, F> Hessian<T> for ForwardHessian<F>
+    where T: Real,
+          F: Fn(&Vector<HyperDual<T>>) -> HyperDual<T>
+{
+    fn hessian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let (n, _): (usize, usize) = input.dim();
+        let mut hessian: Matrix<T> = Matrix::zero(n, n);
+        for j in 0..n
+        {
+            for k in j..n
+            {
+                let x: Vector<HyperDual<T>> = seed(input, |i| {
+                    let e1: T = if i == j { T::one() } else { T::zero() };
+                    let e2: T = if i == k { T::one() } else { T::zero() };
+                    HyperDual::hyper(input[i], e1, e2, T::zero())
+                });
+                let h: T = (self.function)(&x).e12();
+                hessian[[j, k]] = h;
+                hessian[[k, j]] = h;
+            }
+        }
+        hessian
+    }
+}
+
+impl<T, F> Optim<T> for ForwardHessian<F>
+    where T: Real,
+          F: Fn(&Vector<HyperDual<T>>) -> HyperDual<T>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Vector::new_column(vec![Function::eval(self, x)])
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+
+    fn hessian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Hessian::hessian(self, x)
+    }
+}
+
+/// Scalar function $f: \mathbb{R}^n \rightarrow \mathbb{R}$, whose gradient
+/// is computed by reverse mode automatic differentiation
+///
+/// One recorded evaluation of the closure yields the complete gradient,
+/// which makes the adapter preferable to [`ForwardHessian`] for many
+/// arguments. The adapter implements [`Function`], [`Jacobian`] (the
+/// gradient as a $1 \times n$ matrix) and the `eval` and `jacobian` methods
+/// of [`Optim`].
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         differentiation::{ReverseGradient, Var},
+///         Jacobian,
+///     },
+/// };
+///
+/// let f = ReverseGradient::new(|x: &Vector<Var<f64>>| x[0] * x[0] + x[0] * x[1]);
+///
+/// let gradient: Matrix<f64> = f.jacobian(&vector![2.0; 3.0]);
+///
+/// assert_relative_eq!(gradient, matrix![7.0, 2.0]);
+/// # }
+/// ```
+pub struct ReverseGradient<F>
+{
+    function: F,
+}
+
+impl<F> ReverseGradient<F>
+{
+    /// Wraps the closure f
+    pub fn new<T>(function: F) -> ReverseGradient<F>
+        where T: Real,
+              F: for<'t> Fn(&Vector<Var<'t, T>>) -> Var<'t, T>
+    {
+        ReverseGradient { function }
+    }
+}
+
+impl<T, F> Function<Vector<T>> for ReverseGradient<F>
+    where T: Real,
+          F: for<'t> Fn(&Vector<Var<'t, T>>) -> Var<'t, T>
+{
+    type Codomain = T;
+
+    fn eval(&self, input: &Vector<T>) -> T
+    {
+        let x: Vector<Var<T>> = seed(input, |i| Var::constant(input[i]));
+        (self.function)(&x).value()
+    }
+}
+
+impl<T, F> Jacobian<T> for ReverseGradient<F>
+    where T: Real,
+          F: for<'t> Fn(&Vector<Var<'t, T>>) -> Var<'t, T>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let (n, _): (usize, usize) = input.dim();
+        let tape: Tape<T> = Tape::new();
+        let x: Vector<Var<T>> = seed(input, |i| tape.var(input[i]));
+        let y: Var<T> = (self.function)(&x);
+        let gradient: Vec<T> = y.gradient(&x.convert_to_vec());
+        Matrix::new(1, n, gradient)
+    }
+}
+
+impl<T, F> Optim<T> for ReverseGradient<F>
+    where T: Real,
+          F: for<'t> Fn(&Vector<Var<'t, T>>) -> Var<'t, T>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Vector::new_column(vec![Function::eval(self, x)])
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+}
+
+/// Column vector with the entries init(i)
+fn seed<T, U, G>(input: &Vector<T>, init: G) -> Vector<U>
+    where T: Real,
+          U: Copy,
+          G: FnMut(usize) -> U
+{
+    Vector::new_column((0..input.dim().0).map(init).collect())
+}
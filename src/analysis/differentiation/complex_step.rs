@@ -0,0 +1,164 @@
+use crate::algebra::{
+    abstr::{Complex, Real},
+    linear::{Matrix, Vector},
+};
+
+/// Complex-step estimator of derivatives, gradients, Jacobians and Hessians
+///
+/// For a real analytic function, the complex-step approximation
+/// ```math
+/// f'(x) = \frac{\operatorname{Im}f(x + ih)}{h} + O(h^2)
+/// ```
+/// does not subtract function values, hence it is free of cancellation
+/// errors and the step can be chosen as small as $h = \epsilon\max(|x|, 1)$,
+/// which makes the derivative accurate to machine precision. The function
+/// has to be evaluated with [`Complex`] arguments. The Hessian is the
+/// central difference of the complex-step gradient.
+///
+/// Squire, W., Trapp, G.: Using Complex Variables to Estimate Derivatives of
+/// Real Functions, SIAM Review, 40(1), 1998 <br>
+/// Martins, J. R. R. A., Sturdza, P., Alonso, J. J.: The Complex-Step
+/// Derivative Approximation, ACM Transactions on Mathematical Software,
+/// 29(3), 2003
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::abstr::Complex,
+///     analysis::differentiation::ComplexStep,
+///     elementary::{Exponential, Trigonometry},
+/// };
+///
+/// let f = |x: Complex<f64>| x.exp() * x.sin();
+///
+/// let d: f64 = ComplexStep::new().derivative(f, 1.5);
+///
+/// assert_relative_eq!(d, (1.5f64.sin() + 1.5f64.cos()) * 1.5f64.exp(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ComplexStep<T>
+{
+    step: Option<T>,
+}
+
+impl<T> Default for ComplexStep<T>
+    where T: Real
+{
+    fn default() -> ComplexStep<T>
+    {
+        ComplexStep::new()
+    }
+}
+
+impl<T> ComplexStep<T>
+    where T: Real
+{
+    /// Estimator with automatic step selection
+    pub fn new() -> ComplexStep<T>
+    {
+        ComplexStep { step: None }
+    }
+
+    /// Sets the relative step h
+    ///
+    /// # Panics
+    ///
+    /// if the step is not positive
+    pub fn set_step(&mut self, step: T)
+    {
+        if step <= T::zero()
+        {
+            panic!("The step is not positive");
+        }
+        self.step = Some(step);
+    }
+
+    /// Derivative of the scalar function f at x
+    pub fn derivative<F>(&self, f: F, x: T) -> T
+        where F: Fn(Complex<T>) -> Complex<T>
+    {
+        let h: T = self.step(x);
+        f(Complex::new(x, h)).im / h
+    }
+
+    /// Gradient of the scalar function f at x
+    pub fn gradient<F>(&self, f: F, x: &Vector<T>) -> Vector<T>
+        where F: Fn(&Vector<Complex<T>>) -> Complex<T>
+    {
+        let (n, _): (usize, usize) = x.dim();
+        Vector::new_column((0..n).map(|i| {
+                                     let h: T = self.step(x[i]);
+                                     f(&perturb(x, i, h)).im / h
+                                 })
+                                 .collect())
+    }
+
+    /// Jacobian of the function f at x
+    pub fn jacobian<F>(&self, f: F, x: &Vector<T>) -> Matrix<T>
+        where F: Fn(&Vector<Complex<T>>) -> Vector<Complex<T>>
+    {
+        let (n, _): (usize, usize) = x.dim();
+        let mut m: usize = 0;
+        let mut columns: Vec<T> = Vec::new();
+        for i in 0..n
+        {
+            let h: T = self.step(x[i]);
+            let f_i: Vector<Complex<T>> = f(&perturb(x, i, h));
+            m = f_i.dim().0;
+            columns.extend(f_i.iter().map(|f_ij| f_ij.im / h));
+        }
+        Matrix::new(m, n, columns)
+    }
+
+    /// Hessian of the scalar function f at x
+    ///
+    /// The columns are central differences of the complex-step gradient with
+    /// the relative step $\epsilon^{1/3}$, the result is symmetrized.
+    pub fn hessian<F>(&self, f: F, x: &Vector<T>) -> Matrix<T>
+        where F: Fn(&Vector<Complex<T>>) -> Complex<T>
+    {
+        let (n, _): (usize, usize) = x.dim();
+        let h_rel: T = T::default_epsilon().pow(T::one() / T::from_f64(3.0));
+        let mut hessian: Matrix<T> = Matrix::zero(n, n);
+        for j in 0..n
+        {
+            let h_j: T = (x[j] + h_rel * x[j].abs().max(T::one())) - x[j];
+            let mut x_forward: Vector<T> = x.clone();
+            x_forward[j] += h_j;
+            let mut x_backward: Vector<T> = x.clone();
+            x_backward[j] -= h_j;
+            let column: Vector<T> = (self.gradient(&f, &x_forward) - self.gradient(&f, &x_backward)) / (h_j + h_j);
+            hessian.set_column(&column, j);
+        }
+        for j in 0..n
+        {
+            for i in 0..j
+            {
+                let h_ij: T = (hessian[[i, j]] + hessian[[j, i]]) / T::from_f64(2.0);
+                hessian[[i, j]] = h_ij;
+                hessian[[j, i]] = h_ij;
+            }
+        }
+        hessian
+    }
+
+    fn step(&self, x: T) -> T
+    {
+        self.step.unwrap_or_else(T::default_epsilon) * x.abs().max(T::one())
+    }
+}
+
+/// x as complex vector with the imaginary part h in the component i
+fn perturb<T>(x: &Vector<T>, i: usize, h: T) -> Vector<Complex<T>>
+    where T: Real
+{
+    let (n, _): (usize, usize) = x.dim();
+    Vector::new_column((0..n).map(|k| Complex::new(x[k], if k == i { h } else { T::zero() }))
+                             .collect())
+}
@@ -0,0 +1,223 @@
+use crate::{
+    algebra::abstr::{
+        cast::{FromPrimitive, NumCast, ToPrimitive},
+        AbelianGroup, AbelianGroupAdd, AbelianGroupMul, AbsDiffEq, Addition, CommutativeRing, Field,
+        Group, GroupAdd, GroupMul, Identity, Loop, Magma, MagmaAdd, MagmaMul, Monoid, MonoidAdd,
+        MonoidMul, Multiplication, One, Quasigroup, Real, RelativeEq, Ring, Scalar, Semigroup,
+        SemigroupAdd, SemigroupMul, Sign, Zero,
+    },
+    elementary::{Exponential, Hyperbolic, Power, Trigonometry},
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Dual number for forward mode automatic differentiation
+///
+/// A dual number $a + b\varepsilon$ with $\varepsilon^2 = 0$ carries the
+/// value a of a function together with its derivative b. Evaluating a
+/// function f with the argument $x + \varepsilon$ yields
+/// ```math
+/// f(x + \varepsilon) = f(x) + f'(x)\varepsilon
+/// ```
+/// `Dual<T>` implements [`Real`], hence every function written generically
+/// over `Real`, including the elementary functions and the special functions
+/// of [`special`](crate::special), can be differentiated. Comparisons only
+/// take the values into account.
+///
+/// Griewank, A., Walther, A.: Evaluating Derivatives, 2nd edition, SIAM,
+/// 2008
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::abstr::Real,
+///     analysis::differentiation::Dual,
+///     elementary::{Exponential, Trigonometry},
+/// };
+///
+/// fn f<T: Real>(x: T) -> T
+/// {
+///     x.sin() * x.exp()
+/// }
+///
+/// let x: f64 = 0.5;
+/// let y: Dual<f64> = f(Dual::variable(x));
+///
+/// assert_relative_eq!(y.re, f(x));
+/// assert_relative_eq!(y.eps, (x.cos() + x.sin()) * x.exp(), epsilon = 1.0e-12);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Dual<T>
+{
+    /// Value
+    pub re: T,
+    /// Derivative
+    pub eps: T,
+}
+
+impl<T> Dual<T>
+    where T: Real
+{
+    /// Creates a dual number $re + eps\,\varepsilon$
+    pub fn new(re: T, eps: T) -> Dual<T>
+    {
+        Dual { re, eps }
+    }
+
+    /// Creates a constant, whose derivative is zero
+    pub fn constant(re: T) -> Dual<T>
+    {
+        Dual { re,
+               eps: T::zero() }
+    }
+
+    /// Creates an independent variable, whose derivative is one
+    pub fn variable(re: T) -> Dual<T>
+    {
+        Dual { re,
+               eps: T::one() }
+    }
+
+    fn chain(self, f: T, df: T) -> Dual<T>
+    {
+        Dual { re: f,
+               eps: df * self.eps }
+    }
+}
+
+impl_real_number!([T] Dual<T>, re);
+
+impl<T> Display for Dual<T> where T: Real
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{} + {}ε", self.re, self.eps)
+    }
+}
+
+impl<T> Neg for Dual<T> where T: Real
+{
+    type Output = Dual<T>;
+
+    fn neg(self) -> Dual<T>
+    {
+        Dual { re: -self.re,
+               eps: -self.eps }
+    }
+}
+
+impl<T> Add for Dual<T> where T: Real
+{
+    type Output = Dual<T>;
+
+    fn add(self, rhs: Dual<T>) -> Dual<T>
+    {
+        Dual { re: self.re + rhs.re,
+               eps: self.eps + rhs.eps }
+    }
+}
+
+impl<T> AddAssign for Dual<T> where T: Real
+{
+    fn add_assign(&mut self, rhs: Dual<T>)
+    {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for Dual<T> where T: Real
+{
+    type Output = Dual<T>;
+
+    fn sub(self, rhs: Dual<T>) -> Dual<T>
+    {
+        Dual { re: self.re - rhs.re,
+               eps: self.eps - rhs.eps }
+    }
+}
+
+impl<T> SubAssign for Dual<T> where T: Real
+{
+    fn sub_assign(&mut self, rhs: Dual<T>)
+    {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Mul for Dual<T> where T: Real
+{
+    type Output = Dual<T>;
+
+    fn mul(self, rhs: Dual<T>) -> Dual<T>
+    {
+        Dual { re: self.re * rhs.re,
+               eps: self.eps * rhs.re + self.re * rhs.eps }
+    }
+}
+
+impl<T> MulAssign for Dual<T> where T: Real
+{
+    fn mul_assign(&mut self, rhs: Dual<T>)
+    {
+        *self = *self * rhs;
+    }
+}
+
+impl<T> Div for Dual<T> where T: Real
+{
+    type Output = Dual<T>;
+
+    fn div(self, rhs: Dual<T>) -> Dual<T>
+    {
+        let re: T = self.re / rhs.re;
+        Dual { re,
+               eps: (self.eps - re * rhs.eps) / rhs.re }
+    }
+}
+
+impl<T> DivAssign for Dual<T> where T: Real
+{
+    fn div_assign(&mut self, rhs: Dual<T>)
+    {
+        *self = *self / rhs;
+    }
+}
+
+impl<T> AbsDiffEq for Dual<T> where T: Real
+{
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self
+    {
+        Dual::new(T::default_epsilon(), T::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Dual<T>, epsilon: Self) -> bool
+    {
+        self.re.abs_diff_eq(&other.re, epsilon.re) && self.eps.abs_diff_eq(&other.eps, epsilon.eps)
+    }
+}
+
+impl<T> RelativeEq for Dual<T> where T: Real
+{
+    fn default_max_relative() -> Self
+    {
+        Dual::new(T::default_max_relative(), T::default_max_relative())
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool
+    {
+        self.re.relative_eq(&other.re, epsilon.re, max_relative.re)
+        && self.eps.relative_eq(&other.eps, epsilon.eps, max_relative.eps)
+    }
+}
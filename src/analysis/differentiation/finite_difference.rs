@@ -0,0 +1,286 @@
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::differentiation::Richardson,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Finite difference scheme
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DifferenceScheme
+{
+    /// $\frac{f(x + h) - f(x)}{h}$, first order, one additional function
+    /// evaluation per direction
+    Forward,
+    /// $\frac{f(x + h) - f(x - h)}{2h}$, second order, two additional
+    /// function evaluations per direction
+    #[default]
+    Central,
+}
+
+/// Finite difference estimator of derivatives, gradients, Jacobians and
+/// Hessians
+///
+/// The step in direction i is $h_i = h\max(|x_i|, 1)$. If no relative step
+/// h is given, it balances the truncation error against the rounding error
+/// $\epsilon$ of the function values: $h = \epsilon^{1/2}$ for forward and
+/// $h = \epsilon^{1/3}$ for central differences of first derivatives,
+/// $h = \epsilon^{1/3}$ and $h = \epsilon^{1/4}$ for second derivatives.
+/// With k levels of Richardson extrapolation, the optimal steps grow to
+/// $\epsilon^{1/(k+2)}$ and $\epsilon^{1/(2k+3)}$, respectively
+/// $\epsilon^{1/(k+3)}$ and $\epsilon^{1/(2k+4)}$.
+///
+/// Nocedal, J., Wright, S. J.: Numerical Optimization, 2nd edition,
+/// Springer, 2006, chapter 8 <br>
+/// Dennis, J. E., Schnabel, R. B.: Numerical Methods for Unconstrained
+/// Optimization and Nonlinear Equations, SIAM, 1996
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::differentiation::{DifferenceScheme, FiniteDifference},
+///     elementary::Exponential,
+/// };
+///
+/// let f = |x: &Vector<f64>| x[0] * x[1].exp();
+/// let x: Vector<f64> = vector![2.0; 0.5];
+///
+/// let fd: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Central);
+/// let gradient: Vector<f64> = fd.gradient(f, &x);
+/// let hessian: Matrix<f64> = fd.hessian(f, &x);
+///
+/// let e: f64 = 0.5f64.exp();
+/// assert_relative_eq!(gradient, vector![e; 2.0 * e], epsilon = 1.0e-9);
+/// assert_relative_eq!(hessian, matrix![0.0, e; e, 2.0 * e], epsilon = 1.0e-5);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct FiniteDifference<T>
+{
+    scheme: DifferenceScheme,
+    step: Option<T>,
+    extrapolation: usize,
+}
+
+impl<T> Default for FiniteDifference<T>
+    where T: Real
+{
+    fn default() -> FiniteDifference<T>
+    {
+        FiniteDifference::new(DifferenceScheme::default())
+    }
+}
+
+impl<T> FiniteDifference<T>
+    where T: Real
+{
+    /// Estimator with automatic step selection and without extrapolation
+    pub fn new(scheme: DifferenceScheme) -> FiniteDifference<T>
+    {
+        FiniteDifference { scheme,
+                           step: None,
+                           extrapolation: 0 }
+    }
+
+    /// Sets the relative step h
+    ///
+    /// # Panics
+    ///
+    /// if the step is not positive
+    pub fn set_step(&mut self, step: T)
+    {
+        if step <= T::zero()
+        {
+            panic!("The step is not positive");
+        }
+        self.step = Some(step);
+    }
+
+    /// Sets the number of Richardson extrapolation levels
+    ///
+    /// Each level halves the step and costs an additional difference
+    /// approximation.
+    pub fn set_extrapolation(&mut self, levels: usize)
+    {
+        self.extrapolation = levels;
+    }
+
+    /// Difference scheme
+    pub fn scheme(&self) -> DifferenceScheme
+    {
+        self.scheme
+    }
+
+    /// Derivative of the scalar function f at x
+    pub fn derivative<F>(&self, f: F, x: T) -> T
+        where F: Fn(T) -> T
+    {
+        self.derivative_with_error(f, x).0
+    }
+
+    /// Derivative of the scalar function f at x and an estimate of its error
+    ///
+    /// The error is estimated by Richardson extrapolation, it is infinite if
+    /// no extrapolation level is set.
+    pub fn derivative_with_error<F>(&self, f: F, x: T) -> (T, T)
+        where F: Fn(T) -> T
+    {
+        let f_x: T = f(x);
+        let (d, error): (Vec<T>, T) = self.estimate(false, |h: T| {
+                                              let h: T = step(x, h);
+                                              vec![self.difference(&f, x, h, f_x)]
+                                          });
+        (d[0], error)
+    }
+
+    /// Gradient of the scalar function f at x
+    pub fn gradient<F>(&self, f: F, x: &Vector<T>) -> Vector<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (n, _): (usize, usize) = x.dim();
+        let f_x: T = f(x);
+        let (gradient, _): (Vec<T>, T) = self.estimate(false, |h: T| {
+                                                     (0..n).map(|i| {
+                                                               let h_i: T = step(x[i], h);
+                                                               self.difference(|s: T| f(&shift(x, i, s)), T::zero(), h_i, f_x)
+                                                           })
+                                                           .collect()
+                                                 });
+        Vector::new_column(gradient)
+    }
+
+    /// Jacobian of the function f at x
+    pub fn jacobian<F>(&self, f: F, x: &Vector<T>) -> Matrix<T>
+        where F: Fn(&Vector<T>) -> Vector<T>
+    {
+        let (n, _): (usize, usize) = x.dim();
+        let f_x: Vector<T> = f(x);
+        let (m, _): (usize, usize) = f_x.dim();
+        let (jacobian, _): (Vec<T>, T) = self.estimate(false, |h: T| {
+                                                     let mut columns: Vec<T> = Vec::with_capacity(m * n);
+                                                     for i in 0..n
+                                                     {
+                                                         let h_i: T = step(x[i], h);
+                                                         let forward: Vector<T> = f(&shift(x, i, h_i));
+                                                         let column: Vector<T> = match self.scheme
+                                                         {
+                                                             DifferenceScheme::Forward => (forward - f_x.clone()) / h_i,
+                                                             DifferenceScheme::Central =>
+                                                             {
+                                                                 (forward - f(&shift(x, i, -h_i))) / (h_i + h_i)
+                                                             }
+                                                         };
+                                                         columns.extend(column.iter());
+                                                     }
+                                                     columns
+                                                 });
+        Matrix::new(m, n, jacobian)
+    }
+
+    /// Hessian of the scalar function f at x
+    pub fn hessian<F>(&self, f: F, x: &Vector<T>) -> Matrix<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (n, _): (usize, usize) = x.dim();
+        let f_x: T = f(x);
+        let (hessian, _): (Vec<T>, T) = self.estimate(true, |h: T| {
+            let steps: Vec<T> = (0..n).map(|i| step(x[i], h)).collect();
+            let f_at = |i: usize, s_i: T, j: usize, s_j: T| -> T { f(&shift(&shift(x, i, s_i), j, s_j)) };
+            let mut hessian: Vec<T> = vec![T::zero(); n * n];
+            for i in 0..n
+            {
+                for j in i..n
+                {
+                    let (h_i, h_j): (T, T) = (steps[i], steps[j]);
+                    let h_ij: T = match (self.scheme, i == j)
+                    {
+                        (DifferenceScheme::Forward, _) =>
+                        {
+                            (f_at(i, h_i, j, h_j) - f(&shift(x, i, h_i)) - f(&shift(x, j, h_j)) + f_x) / (h_i * h_j)
+                        }
+                        (DifferenceScheme::Central, true) =>
+                        {
+                            let two: T = T::from_f64(2.0);
+                            (f(&shift(x, i, h_i)) - two * f_x + f(&shift(x, i, -h_i))) / (h_i * h_i)
+                        }
+                        (DifferenceScheme::Central, false) =>
+                        {
+                            (f_at(i, h_i, j, h_j) - f_at(i, h_i, j, -h_j) - f_at(i, -h_i, j, h_j) + f_at(i, -h_i, j, -h_j))
+                            / (T::from_f64(4.0) * h_i * h_j)
+                        }
+                    };
+                    hessian[j * n + i] = h_ij;
+                    hessian[i * n + j] = h_ij;
+                }
+            }
+            hessian
+        });
+        Matrix::new(n, n, hessian)
+    }
+
+    /// Difference quotient of f at x with the step h, f_x = f(x)
+    fn difference<F>(&self, f: F, x: T, h: T, f_x: T) -> T
+        where F: Fn(T) -> T
+    {
+        match self.scheme
+        {
+            DifferenceScheme::Forward => (f(x + h) - f_x) / h,
+            DifferenceScheme::Central => (f(x + h) - f(x - h)) / (h + h),
+        }
+    }
+
+    /// Evaluates the approximation with the relative step and extrapolates
+    /// it, second is true for second derivatives
+    fn estimate<A>(&self, second: bool, approximation: A) -> (Vec<T>, T)
+        where A: Fn(T) -> Vec<T>
+    {
+        let k: f64 = self.extrapolation as f64;
+        let (order, exponent): (u32, f64) = match (self.scheme, second)
+        {
+            (DifferenceScheme::Forward, false) => (1, k + 2.0),
+            (DifferenceScheme::Forward, true) => (1, k + 3.0),
+            (DifferenceScheme::Central, false) => (2, 2.0 * k + 3.0),
+            (DifferenceScheme::Central, true) => (2, 2.0 * k + 4.0),
+        };
+        let h: T = match self.step
+        {
+            Some(h) => h,
+            None => T::default_epsilon().pow(T::one() / T::from_f64(exponent)),
+        };
+
+        if self.extrapolation == 0
+        {
+            return (approximation(h), T::infinity());
+        }
+        Richardson::new(order, order, self.extrapolation).extrapolate_vec(approximation, h)
+    }
+}
+
+/// Absolute step $h\max(|x|, 1)$, rounded such that x + h is representable
+fn step<T>(x: T, h: T) -> T
+    where T: Real
+{
+    let h: T = h * x.abs().max(T::one());
+    (x + h) - x
+}
+
+/// x with the component i increased by s
+fn shift<T>(x: &Vector<T>, i: usize, s: T) -> Vector<T>
+    where T: Real
+{
+    let mut x_s: Vector<T> = x.clone();
+    x_s[i] += s;
+    x_s
+}
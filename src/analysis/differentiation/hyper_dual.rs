@@ -0,0 +1,73 @@
+use crate::{algebra::abstr::Real, analysis::differentiation::Dual};
+
+/// Hyper-dual number for exact first and second derivatives
+///
+/// A hyper-dual number
+/// $a + b\varepsilon_1 + c\varepsilon_2 + d\varepsilon_1\varepsilon_2$ with
+/// $\varepsilon_1^2 = \varepsilon_2^2 = 0$ is a dual number, whose
+/// components are dual numbers. Evaluating a function with the argument
+/// $x + \varepsilon_1 + \varepsilon_2$ yields
+/// ```math
+/// f(x) + f'(x)\varepsilon_1 + f'(x)\varepsilon_2 + f''(x)\varepsilon_1\varepsilon_2
+/// ```
+/// Seeding $\varepsilon_1$ and $\varepsilon_2$ in different directions gives
+/// the mixed second partial derivatives. In contrast to finite differences
+/// the second derivatives are free of truncation and cancellation errors.
+///
+/// Fike, J. A., Alonso, J. J.: The Development of Hyper-Dual Numbers for
+/// Exact Second-Derivative Calculations, AIAA 2011-886, 2011
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{analysis::differentiation::HyperDual, elementary::Exponential};
+///
+/// let x: HyperDual<f64> = HyperDual::hyper(1.0, 1.0, 1.0, 0.0);
+/// let y: HyperDual<f64> = (x * x).exp();
+///
+/// assert_relative_eq!(y.value(), 1.0f64.exp());
+/// assert_relative_eq!(y.e1(), 2.0 * 1.0f64.exp());
+/// assert_relative_eq!(y.e12(), 6.0 * 1.0f64.exp(), epsilon = 1.0e-12);
+/// # }
+/// ```
+pub type HyperDual<T> = Dual<Dual<T>>;
+
+impl<T> Dual<Dual<T>>
+    where T: Real
+{
+    /// Creates the hyper-dual number
+    /// $re + e_1\varepsilon_1 + e_2\varepsilon_2 + e_{12}\varepsilon_1\varepsilon_2$
+    pub fn hyper(re: T, e1: T, e2: T, e12: T) -> HyperDual<T>
+    {
+        Dual::new(Dual::new(re, e1), Dual::new(e2, e12))
+    }
+
+    /// Value
+    pub fn value(&self) -> T
+    {
+        self.re.re
+    }
+
+    /// Derivative in the direction of $\varepsilon_1$
+    pub fn e1(&self) -> T
+    {
+        self.re.eps
+    }
+
+    /// Derivative in the direction of $\varepsilon_2$
+    pub fn e2(&self) -> T
+    {
+        self.eps.re
+    }
+
+    /// Second derivative in the directions of $\varepsilon_1$ and
+    /// $\varepsilon_2$
+    pub fn e12(&self) -> T
+    {
+        self.eps.eps
+    }
+}
@@ -0,0 +1,67 @@
+//! Differentiation
+//!
+//! Fore more information: <br>
+//! <https://en.wikipedia.org/wiki/Automatic_differentiation> <br>
+//! <https://en.wikipedia.org/wiki/Numerical_differentiation>
+//!
+//! Automatic differentiation evaluates derivatives of functions given as
+//! code exactly up to rounding errors.
+//!
+//! | Type | Mode | Derivatives |
+//! |------|------|-------------|
+//! | [`Dual`] | forward | first derivatives, one direction per evaluation |
+//! | [`HyperDual`] | forward | first and second derivatives, two directions per evaluation |
+//! | [`Var`] | reverse | gradient of a scalar function with one recorded evaluation |
+//!
+//! All number types implement [`Real`](crate::algebra::abstr::Real), functions
+//! written generically over `Real` can be differentiated without changes.
+//! The adapters [`ForwardJacobian`], [`ForwardHessian`] and
+//! [`ReverseGradient`] turn closures into implementations of
+//! [`Jacobian`](crate::analysis::Jacobian),
+//! [`Hessian`](crate::analysis::Hessian) and
+//! [`Optim`](crate::optimization::Optim).
+//!
+//! If a function can't be evaluated with these number types, its
+//! derivatives are approximated numerically:
+//!
+//! | Estimator | Accuracy | Derivatives |
+//! |-----------|----------|-------------|
+//! | [`FiniteDifference`] | $O(h)$ forward, $O(h^2)$ central | gradient, Jacobian and Hessian of real functions |
+//! | [`ComplexStep`] | machine precision | gradient and Jacobian of real analytic functions evaluated with [`Complex`](crate::algebra::abstr::Complex) |
+//! | [`Richardson`] | higher order | extrapolation of any approximation depending on a step |
+//!
+//! The adapters [`NumericalJacobian`], [`NumericalHessian`],
+//! [`ComplexStepJacobian`], [`ComplexStepHessian`] and [`NumericalODE`]
+//! provide the numerical derivatives to the solvers and optimizers.
+
+#[cfg(feature = "native")]
+#[macro_use]
+mod number;
+#[cfg(feature = "native")]
+mod adapter;
+mod complex_step;
+#[cfg(feature = "native")]
+mod dual;
+mod finite_difference;
+#[cfg(feature = "native")]
+mod hyper_dual;
+mod numerical;
+#[cfg(feature = "native")]
+mod reverse;
+mod richardson;
+#[cfg(feature = "native")]
+mod special;
+
+#[cfg(feature = "native")]
+pub use self::{
+    adapter::{ForwardHessian, ForwardJacobian, ReverseGradient},
+    dual::Dual,
+    hyper_dual::HyperDual,
+    reverse::{Tape, Var},
+};
+pub use self::{
+    complex_step::ComplexStep,
+    finite_difference::{DifferenceScheme, FiniteDifference},
+    numerical::{ComplexStepHessian, ComplexStepJacobian, NumericalHessian, NumericalJacobian, NumericalODE},
+    richardson::Richardson,
+};
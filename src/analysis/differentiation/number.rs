@@ -0,0 +1,446 @@
+//! Shared trait implementations of the automatic differentiation numbers
+//!
+//! A number type provides the private methods `constant(T) -> Self` and
+//! `chain(self, f: T, df: T) -> Self`. The latter returns the number with
+//! the value f, whose derivative is df times the derivative of self. All
+//! elementary functions are expressed with these two methods, the
+//! arithmetic operators are implemented by the number types themselves.
+
+macro_rules! impl_real_number
+{
+    ([$($g:tt)*] $ty:ty, $value:ident) =>
+    {
+        impl<$($g)*> Exponential for $ty
+            where T: Real
+        {
+            fn e() -> Self
+            {
+                Self::constant(T::e())
+            }
+
+            fn exp(self) -> Self
+            {
+                let e: T = self.$value.exp();
+                self.chain(e, e)
+            }
+
+            fn ln(self) -> Self
+            {
+                self.chain(self.$value.ln(), T::one() / self.$value)
+            }
+        }
+
+        impl<$($g)*> Trigonometry for $ty
+            where T: Real
+        {
+            fn pi() -> Self
+            {
+                Self::constant(T::pi())
+            }
+
+            fn sin(self) -> Self
+            {
+                self.chain(self.$value.sin(), self.$value.cos())
+            }
+
+            fn cos(self) -> Self
+            {
+                self.chain(self.$value.cos(), -self.$value.sin())
+            }
+
+            fn tan(self) -> Self
+            {
+                let t: T = self.$value.tan();
+                self.chain(t, T::one() + t * t)
+            }
+
+            fn cot(self) -> Self
+            {
+                let c: T = self.$value.cot();
+                self.chain(c, -(T::one() + c * c))
+            }
+
+            fn sec(self) -> Self
+            {
+                let s: T = self.$value.sec();
+                self.chain(s, s * self.$value.tan())
+            }
+
+            fn csc(self) -> Self
+            {
+                let c: T = self.$value.csc();
+                self.chain(c, -c * self.$value.cot())
+            }
+
+            fn arcsin(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arcsin(), T::one() / (T::one() - a * a).sqrt())
+            }
+
+            fn arccos(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arccos(), -T::one() / (T::one() - a * a).sqrt())
+            }
+
+            fn arctan(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arctan(), T::one() / (T::one() + a * a))
+            }
+
+            /// The derivatives are the ones of $\arctan(y/x)$, the value is
+            /// shifted to the quadrant of (x, y).
+            fn arctan2(self, other: Self) -> Self
+            {
+                let angle: T = self.$value.arctan2(other.$value);
+                let r: Self = if other.$value.abs() >= self.$value.abs()
+                {
+                    (self / other).arctan()
+                }
+                else
+                {
+                    -(other / self).arctan()
+                };
+                r + Self::constant(angle - r.$value)
+            }
+
+            fn arccot(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arccot(), -T::one() / (T::one() + a * a))
+            }
+
+            fn arcsec(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arcsec(), T::one() / (a.abs() * (a * a - T::one()).sqrt()))
+            }
+
+            fn arccsc(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arccsc(), -T::one() / (a.abs() * (a * a - T::one()).sqrt()))
+            }
+        }
+
+        impl<$($g)*> Power for $ty
+            where T: Real
+        {
+            /// For a positive base the function is evaluated as
+            /// $e^{\mathrm{exp} \ln(\mathrm{self})}$, otherwise the derivative
+            /// with respect to the exponent is not defined and only the
+            /// derivative with respect to the base is propagated.
+            fn pow(self, exp: Self) -> Self
+            {
+                let a: T = self.$value;
+                let b: T = exp.$value;
+                if a > T::zero()
+                {
+                    return (exp * self.ln()).exp();
+                }
+                if b == T::zero()
+                {
+                    return Self::constant(T::one());
+                }
+                self.chain(a.pow(b), b * a.pow(b - T::one()))
+            }
+
+            fn root(self, root: Self) -> Self
+            {
+                self.pow(Self::constant(T::one()) / root)
+            }
+
+            fn sqrt(self) -> Self
+            {
+                let s: T = self.$value.sqrt();
+                self.chain(s, T::one() / (s + s))
+            }
+        }
+
+        impl<$($g)*> Hyperbolic for $ty
+            where T: Real
+        {
+            fn sinh(self) -> Self
+            {
+                self.chain(self.$value.sinh(), self.$value.cosh())
+            }
+
+            fn cosh(self) -> Self
+            {
+                self.chain(self.$value.cosh(), self.$value.sinh())
+            }
+
+            fn tanh(self) -> Self
+            {
+                let t: T = self.$value.tanh();
+                self.chain(t, T::one() - t * t)
+            }
+
+            fn coth(self) -> Self
+            {
+                let c: T = self.$value.coth();
+                self.chain(c, T::one() - c * c)
+            }
+
+            fn sech(self) -> Self
+            {
+                let s: T = self.$value.sech();
+                self.chain(s, -s * self.$value.tanh())
+            }
+
+            fn csch(self) -> Self
+            {
+                let c: T = self.$value.csch();
+                self.chain(c, -c * self.$value.coth())
+            }
+
+            fn arsinh(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arsinh(), T::one() / (a * a + T::one()).sqrt())
+            }
+
+            fn arcosh(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arcosh(), T::one() / (a * a - T::one()).sqrt())
+            }
+
+            fn artanh(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.artanh(), T::one() / (T::one() - a * a))
+            }
+
+            fn arcoth(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arcoth(), T::one() / (T::one() - a * a))
+            }
+
+            fn arsech(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arsech(), -T::one() / (a * (T::one() - a * a).sqrt()))
+            }
+
+            fn arcsch(self) -> Self
+            {
+                let a: T = self.$value;
+                self.chain(a.arcsch(), -T::one() / (a.abs() * (T::one() + a * a).sqrt()))
+            }
+        }
+
+        impl<$($g)*> Real for $ty
+            where T: Real
+        {
+            fn ceil(&self) -> Self
+            {
+                Self::constant(self.$value.ceil())
+            }
+
+            fn floor(&self) -> Self
+            {
+                Self::constant(self.$value.floor())
+            }
+
+            fn euler_gamma() -> Self
+            {
+                Self::constant(T::euler_gamma())
+            }
+
+            fn infinity() -> Self
+            {
+                Self::constant(T::infinity())
+            }
+
+            fn neg_infinity() -> Self
+            {
+                Self::constant(T::neg_infinity())
+            }
+        }
+
+        impl<$($g)*> Sign for $ty
+            where T: Real
+        {
+            fn sign(&self) -> Self
+            {
+                Self::constant(self.$value.sign())
+            }
+
+            fn abs(&self) -> Self
+            {
+                self.chain(self.$value.abs(), self.$value.sign())
+            }
+
+            fn is_positive(&self) -> bool
+            {
+                self.$value.is_positive()
+            }
+
+            fn is_negative(&self) -> bool
+            {
+                self.$value.is_negative()
+            }
+        }
+
+        impl<$($g)*> PartialEq for $ty
+            where T: Real
+        {
+            fn eq(&self, other: &Self) -> bool
+            {
+                self.$value == other.$value
+            }
+        }
+
+        impl<$($g)*> PartialOrd for $ty
+            where T: Real
+        {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+            {
+                self.$value.partial_cmp(&other.$value)
+            }
+        }
+
+        impl<$($g)*> ToPrimitive for $ty
+            where T: Real
+        {
+            fn to_i64(&self) -> i64
+            {
+                self.$value.to_i64()
+            }
+
+            fn to_i128(&self) -> i128
+            {
+                self.$value.to_i128()
+            }
+
+            fn to_u64(&self) -> u64
+            {
+                self.$value.to_u64()
+            }
+
+            fn to_u128(&self) -> u128
+            {
+                self.$value.to_u128()
+            }
+
+            fn to_f64(&self) -> f64
+            {
+                self.$value.to_f64()
+            }
+        }
+
+        impl<$($g)*> FromPrimitive for $ty
+            where T: Real
+        {
+            fn from_i64(n: i64) -> Self
+            {
+                Self::constant(T::from_i64(n))
+            }
+
+            fn from_i128(n: i128) -> Self
+            {
+                Self::constant(T::from_i128(n))
+            }
+
+            fn from_u64(n: u64) -> Self
+            {
+                Self::constant(T::from_u64(n))
+            }
+
+            fn from_u128(n: u128) -> Self
+            {
+                Self::constant(T::from_u128(n))
+            }
+
+            fn from_f64(n: f64) -> Self
+            {
+                Self::constant(T::from_f64(n))
+            }
+        }
+
+        impl<$($g)*> NumCast for $ty
+            where T: Real
+        {
+            fn from<K: ToPrimitive>(n: K) -> Self
+            {
+                Self::constant(T::from_f64(n.to_f64()))
+            }
+        }
+
+        impl<$($g)*> Zero for $ty
+            where T: Real
+        {
+            fn zero() -> Self
+            {
+                Self::constant(T::zero())
+            }
+        }
+
+        impl<$($g)*> One for $ty
+            where T: Real
+        {
+            fn one() -> Self
+            {
+                Self::constant(T::one())
+            }
+        }
+
+        impl<$($g)*> Identity<Addition> for $ty
+            where T: Real
+        {
+            fn id() -> Self
+            {
+                Self::constant(T::zero())
+            }
+        }
+
+        impl<$($g)*> Identity<Multiplication> for $ty
+            where T: Real
+        {
+            fn id() -> Self
+            {
+                Self::constant(T::one())
+            }
+        }
+
+        impl<$($g)*> Magma<Addition> for $ty
+            where T: Real
+        {
+            fn operate(self, rhs: Self) -> Self
+            {
+                self + rhs
+            }
+        }
+
+        impl<$($g)*> Magma<Multiplication> for $ty
+            where T: Real
+        {
+            fn operate(self, rhs: Self) -> Self
+            {
+                self * rhs
+            }
+        }
+
+        impl_real_number!(@marker [$($g)*] $ty; MagmaAdd, MagmaMul, Semigroup<Addition>, SemigroupAdd,
+                          Semigroup<Multiplication>, SemigroupMul, Monoid<Addition>, MonoidAdd,
+                          Monoid<Multiplication>, MonoidMul, Quasigroup<Addition>,
+                          Quasigroup<Multiplication>, Loop<Addition>, Loop<Multiplication>,
+                          Group<Addition>, GroupAdd, Group<Multiplication>, GroupMul,
+                          AbelianGroup<Addition>, AbelianGroupAdd, AbelianGroup<Multiplication>,
+                          AbelianGroupMul, Ring, CommutativeRing, Field, Scalar);
+    };
+    (@marker [$($g:tt)*] $ty:ty;) => {};
+    (@marker [$($g:tt)*] $ty:ty; $tr:path $(, $rest:path)*) =>
+    {
+        impl<$($g)*> $tr for $ty
+            where T: Real
+        {
+        }
+
+        impl_real_number!(@marker [$($g)*] $ty; $($rest),*);
+    };
+}
@@ -0,0 +1,514 @@
+use crate::{
+    algebra::{
+        abstr::{Complex, Real},
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::ordinary::{ExplicitODE, ImplicitODE},
+        differentiation::{ComplexStep, FiniteDifference},
+        Function, Hessian, Jacobian,
+    },
+    optimization::Optim,
+};
+
+/// Function $f: \mathbb{R}^n \rightarrow \mathbb{R}^m$, whose Jacobian is
+/// approximated by finite differences
+///
+/// The adapter implements [`Function`], [`Jacobian`] and the `eval` and
+/// `jacobian` methods of [`Optim`], so it can be used with
+/// [`NewtonRaphson`](crate::analysis::NewtonRaphson),
+/// [`GaussNewton`](crate::optimization::GaussNewton) and
+/// [`LevenbergMarquardt`](crate::optimization::LevenbergMarquardt) if no
+/// analytic Jacobian is available.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::{
+///         differentiation::{FiniteDifference, NumericalJacobian},
+///         NewtonRaphson,
+///     },
+/// };
+///
+/// let f = NumericalJacobian::new(|x: &Vector<f64>| vector![x[0] * x[0] - 2.0; x[0] * x[1] - 1.0],
+///                                FiniteDifference::default());
+///
+/// let root: Vector<f64> = NewtonRaphson::new(100, 1.0e-12).find_root(&f, &vector![1.0; 1.0]).unwrap();
+///
+/// assert_relative_eq!(root, vector![2.0f64.sqrt(); 0.5f64.sqrt()], epsilon = 1.0e-10);
+/// # }
+/// ```
+pub struct NumericalJacobian<F, T>
+{
+    function: F,
+    estimator: FiniteDifference<T>,
+}
+
+impl<F, T> NumericalJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    /// Wraps the closure f, the Jacobian is approximated with the estimator
+    pub fn new(function: F, estimator: FiniteDifference<T>) -> NumericalJacobian<F, T>
+    {
+        NumericalJacobian { function,
+                            estimator }
+    }
+}
+
+impl<F, T> Function<Vector<T>> for NumericalJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, input: &Vector<T>) -> Vector<T>
+    {
+        (self.function)(input)
+    }
+}
+
+impl<F, T> Jacobian<T> for NumericalJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        self.estimator.jacobian(&self.function, input)
+    }
+}
+
+impl<F, T> Optim<T> for NumericalJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> Vector<T>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Function::eval(self, x)
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+}
+
+/// Scalar function $f: \mathbb{R}^n \rightarrow \mathbb{R}$, whose gradient
+/// and Hessian are approximated by finite differences
+///
+/// The adapter implements [`Function`], [`Jacobian`] (the gradient as a
+/// $1 \times n$ matrix), [`Hessian`] and [`Optim`], so it can be used with
+/// [`Newton`](crate::optimization::Newton) and the gradient based
+/// optimizers.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::differentiation::{FiniteDifference, NumericalHessian},
+///     optimization::Newton,
+/// };
+///
+/// let f = NumericalHessian::new(|x: &Vector<f64>| (x[0] - 1.0).powi(4) + (x[1] + 2.0) * (x[1] + 2.0),
+///                               FiniteDifference::default());
+///
+/// let x: Vector<f64> = Newton::new(100, 0.5, 0.5).minimize(&f, &vector![0.0; 0.0]).arg();
+///
+/// assert_relative_eq!(x, vector![1.0; -2.0], epsilon = 1.0e-2);
+/// # }
+/// ```
+pub struct NumericalHessian<F, T>
+{
+    function: F,
+    estimator: FiniteDifference<T>,
+}
+
+impl<F, T> NumericalHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> T
+{
+    /// Wraps the closure f, the derivatives are approximated with the
+    /// estimator
+    pub fn new(function: F, estimator: FiniteDifference<T>) -> NumericalHessian<F, T>
+    {
+        NumericalHessian { function,
+                           estimator }
+    }
+}
+
+impl<F, T> Function<Vector<T>> for NumericalHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> T
+{
+    type Codomain = T;
+
+    fn eval(&self, input: &Vector<T>) -> T
+    {
+        (self.function)(input)
+    }
+}
+
+impl<F, T> Jacobian<T> for NumericalHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> T
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let gradient: Vector<T> = self.estimator.gradient(&self.function, input);
+        Matrix::new(1, input.dim().0, gradient.convert_to_vec())
+    }
+}
+
+impl<F, T> Hessian<T> for NumericalHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> T
+{
+    fn hessian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        self.estimator.hessian(&self.function, input)
+    }
+}
+
+impl<F, T> Optim<T> for NumericalHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<T>) -> T
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Vector::new_column(vec![Function::eval(self, x)])
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+
+    fn hessian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Hessian::hessian(self, x)
+    }
+}
+
+/// Function $f: \mathbb{R}^n \rightarrow \mathbb{R}^m$, whose Jacobian is
+/// approximated by complex steps
+///
+/// The closure is evaluated with complex arguments, see [`ComplexStep`].
+/// The adapter implements [`Function`], [`Jacobian`] and the `eval` and
+/// `jacobian` methods of [`Optim`].
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::{
+///         abstr::Complex,
+///         linear::{Matrix, Vector},
+///     },
+///     analysis::{
+///         differentiation::{ComplexStep, ComplexStepJacobian},
+///         Jacobian,
+///     },
+///     elementary::Exponential,
+/// };
+///
+/// let f = ComplexStepJacobian::new(|x: &Vector<Complex<f64>>| {
+///                                      Vector::new_column(vec![x[0] * x[1], x[0].exp()])
+///                                  },
+///                                  ComplexStep::new());
+///
+/// let jacobian: Matrix<f64> = f.jacobian(&vector![2.0; 3.0]);
+///
+/// assert_relative_eq!(jacobian, matrix![3.0, 2.0; 2.0f64.exp(), 0.0], epsilon = 1.0e-14);
+/// # }
+/// ```
+pub struct ComplexStepJacobian<F, T>
+{
+    function: F,
+    estimator: ComplexStep<T>,
+}
+
+impl<F, T> ComplexStepJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Vector<Complex<T>>
+{
+    /// Wraps the closure f, the Jacobian is approximated with the estimator
+    pub fn new(function: F, estimator: ComplexStep<T>) -> ComplexStepJacobian<F, T>
+    {
+        ComplexStepJacobian { function,
+                              estimator }
+    }
+}
+
+impl<F, T> Function<Vector<T>> for ComplexStepJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Vector<Complex<T>>
+{
+    type Codomain = Vector<T>;
+
+    fn eval(&self, input: &Vector<T>) -> Vector<T>
+    {
+        let y: Vector<Complex<T>> = (self.function)(&complexify(input));
+        Vector::new_column(y.iter().map(|y_i| y_i.re).collect())
+    }
+}
+
+impl<F, T> Jacobian<T> for ComplexStepJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Vector<Complex<T>>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        self.estimator.jacobian(&self.function, input)
+    }
+}
+
+impl<F, T> Optim<T> for ComplexStepJacobian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Vector<Complex<T>>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Function::eval(self, x)
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+}
+
+/// Scalar function $f: \mathbb{R}^n \rightarrow \mathbb{R}$, whose gradient
+/// and Hessian are approximated by complex steps
+///
+/// The closure is evaluated with complex arguments, see [`ComplexStep`].
+/// The adapter implements [`Function`], [`Jacobian`] (the gradient as a
+/// $1 \times n$ matrix), [`Hessian`] and [`Optim`].
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::{
+///         abstr::Complex,
+///         linear::{Matrix, Vector},
+///     },
+///     analysis::{
+///         differentiation::{ComplexStep, ComplexStepHessian},
+///         Hessian,
+///     },
+/// };
+///
+/// let f = ComplexStepHessian::new(|x: &Vector<Complex<f64>>| x[0] * x[0] * x[1], ComplexStep::new());
+///
+/// let hessian: Matrix<f64> = f.hessian(&vector![2.0; 3.0]);
+///
+/// assert_relative_eq!(hessian, matrix![6.0, 4.0; 4.0, 0.0], epsilon = 1.0e-8);
+/// # }
+/// ```
+pub struct ComplexStepHessian<F, T>
+{
+    function: F,
+    estimator: ComplexStep<T>,
+}
+
+impl<F, T> ComplexStepHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Complex<T>
+{
+    /// Wraps the closure f, the derivatives are approximated with the
+    /// estimator
+    pub fn new(function: F, estimator: ComplexStep<T>) -> ComplexStepHessian<F, T>
+    {
+        ComplexStepHessian { function,
+                             estimator }
+    }
+}
+
+impl<F, T> Function<Vector<T>> for ComplexStepHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Complex<T>
+{
+    type Codomain = T;
+
+    fn eval(&self, input: &Vector<T>) -> T
+    {
+        (self.function)(&complexify(input)).re
+    }
+}
+
+impl<F, T> Jacobian<T> for ComplexStepHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Complex<T>
+{
+    fn jacobian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        let gradient: Vector<T> = self.estimator.gradient(&self.function, input);
+        Matrix::new(1, input.dim().0, gradient.convert_to_vec())
+    }
+}
+
+impl<F, T> Hessian<T> for ComplexStepHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Complex<T>
+{
+    fn hessian(&self, input: &Vector<T>) -> Matrix<T>
+    {
+        self.estimator.hessian(&self.function, input)
+    }
+}
+
+impl<F, T> Optim<T> for ComplexStepHessian<F, T>
+    where T: Real,
+          F: Fn(&Vector<Complex<T>>) -> Complex<T>
+{
+    fn eval(&self, x: &Vector<T>) -> Vector<T>
+    {
+        Vector::new_column(vec![Function::eval(self, x)])
+    }
+
+    fn jacobian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Jacobian::jacobian(self, x)
+    }
+
+    fn hessian(&self, x: &Vector<T>) -> Matrix<T>
+    {
+        Hessian::hessian(self, x)
+    }
+}
+
+/// Explicit ODE, whose Jacobian is approximated by finite differences
+///
+/// Wraps an [`ExplicitODE`] and implements [`ImplicitODE`], so that problems
+/// without an analytic Jacobian can be solved with the implicit solvers.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::{
+///         differential_equation::ordinary::{solver::runge_kutta::ImplicitEuler, ExplicitODE},
+///         differentiation::{FiniteDifference, NumericalODE},
+///     },
+/// };
+///
+/// // x' = -2x, x(0) = 1
+/// struct Decay;
+///
+/// impl ExplicitODE<f64> for Decay
+/// {
+///     fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         x * &-2.0
+///     }
+///
+///     fn time_span(&self) -> (f64, f64)
+///     {
+///         (0.0, 1.0)
+///     }
+///
+///     fn init_cond(&self) -> Vector<f64>
+///     {
+///         vector![1.0]
+///     }
+/// }
+///
+/// let problem: NumericalODE<Decay, f64> = NumericalODE::new(Decay, FiniteDifference::default());
+///
+/// let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.0001).solve(&problem).unwrap();
+///
+/// assert_relative_eq!((-2.0f64).exp(), x.last().unwrap()[0], epsilon = 1.0e-4);
+/// # }
+/// ```
+pub struct NumericalODE<O, T>
+{
+    ode: O,
+    estimator: FiniteDifference<T>,
+}
+
+impl<O, T> NumericalODE<O, T>
+    where T: Real,
+          O: ExplicitODE<T>
+{
+    /// Wraps the ODE, the Jacobian is approximated with the estimator
+    pub fn new(ode: O, estimator: FiniteDifference<T>) -> NumericalODE<O, T>
+    {
+        NumericalODE { ode, estimator }
+    }
+}
+
+impl<O, T> ExplicitODE<T> for NumericalODE<O, T>
+    where T: Real,
+          O: ExplicitODE<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.ode.func(t, x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.ode.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.ode.init_cond()
+    }
+}
+
+impl<O, T> ImplicitODE<T> for NumericalODE<O, T>
+    where T: Real,
+          O: ExplicitODE<T>
+{
+    fn func(&self, t: &T, x: &Vector<T>) -> Vector<T>
+    {
+        self.ode.func(t, x)
+    }
+
+    fn jacobian(&self, t: &T, x: &Vector<T>) -> Matrix<T>
+    {
+        self.estimator.jacobian(|x: &Vector<T>| self.ode.func(t, x), x)
+    }
+
+    fn time_span(&self) -> (T, T)
+    {
+        self.ode.time_span()
+    }
+
+    fn init_cond(&self) -> Vector<T>
+    {
+        self.ode.init_cond()
+    }
+}
+
+/// Complex vector with the real parts input
+fn complexify<T>(input: &Vector<T>) -> Vector<Complex<T>>
+    where T: Real
+{
+    Vector::new_column(input.iter().map(|x| Complex::new(*x, T::zero())).collect())
+}
@@ -0,0 +1,310 @@
+use crate::{
+    algebra::abstr::{
+        cast::{FromPrimitive, NumCast, ToPrimitive},
+        AbelianGroup, AbelianGroupAdd, AbelianGroupMul, AbsDiffEq, Addition, CommutativeRing, Field,
+        Group, GroupAdd, GroupMul, Identity, Loop, Magma, MagmaAdd, MagmaMul, Monoid, MonoidAdd,
+        MonoidMul, Multiplication, One, Quasigroup, Real, RelativeEq, Ring, Scalar, Semigroup,
+        SemigroupAdd, SemigroupMul, Sign, Zero,
+    },
+    elementary::{Exponential, Hyperbolic, Power, Trigonometry},
+};
+use std::{
+    cell::RefCell,
+    cmp::Ordering,
+    fmt,
+    fmt::Display,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// Recorded operation with the indices of its arguments and the partial
+/// derivatives with respect to them
+#[derive(Debug, Clone, Copy)]
+struct Node<T>
+{
+    parents: [(usize, T); 2],
+}
+
+/// Tape for reverse mode automatic differentiation
+///
+/// Every operation on the variables of a tape is recorded. Traversing the
+/// recorded operations backwards accumulates the adjoints
+/// $\bar{v}_i = \frac{\partial f}{\partial v_i}$, the gradient of a scalar
+/// function with respect to all its arguments costs a small multiple of one
+/// function evaluation, independent of the number of arguments.
+///
+/// Griewank, A., Walther, A.: Evaluating Derivatives, 2nd edition, SIAM,
+/// 2008
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     analysis::differentiation::{Tape, Var},
+///     elementary::Trigonometry,
+/// };
+///
+/// let tape: Tape<f64> = Tape::new();
+/// let x: Var<f64> = tape.var(2.0);
+/// let y: Var<f64> = tape.var(3.0);
+///
+/// let f: Var<f64> = x * y + x.sin();
+/// let grad: Vec<f64> = f.gradient(&[x, y]);
+///
+/// assert_relative_eq!(grad[0], 3.0 + 2.0f64.cos());
+/// assert_relative_eq!(grad[1], 2.0);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Tape<T>
+{
+    nodes: RefCell<Vec<Node<T>>>,
+}
+
+impl<T> Default for Tape<T>
+{
+    fn default() -> Tape<T>
+    {
+        Tape { nodes: RefCell::new(Vec::new()) }
+    }
+}
+
+impl<T> Tape<T>
+    where T: Real
+{
+    /// Creates an empty tape
+    pub fn new() -> Tape<T>
+    {
+        Tape::default()
+    }
+
+    /// Creates an independent variable on this tape
+    pub fn var(&self, value: T) -> Var<'_, T>
+    {
+        self.push([(0, T::zero()), (0, T::zero())], value)
+    }
+
+    fn push(&self, parents: [(usize, T); 2], value: T) -> Var<'_, T>
+    {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(Node { parents });
+        Var { tape: Some(self),
+              index: nodes.len() - 1,
+              value }
+    }
+}
+
+/// Variable of reverse mode automatic differentiation
+///
+/// A variable is either recorded on a [`Tape`] or a constant. `Var<T>`
+/// implements [`Real`], so functions written generically over `Real` can be
+/// recorded. Comparisons only take the values into account.
+#[derive(Debug, Clone, Copy)]
+pub struct Var<'t, T>
+{
+    tape: Option<&'t Tape<T>>,
+    index: usize,
+    value: T,
+}
+
+impl<'t, T> Var<'t, T>
+    where T: Real
+{
+    /// Creates a constant, which is not recorded
+    pub fn constant(value: T) -> Var<'t, T>
+    {
+        Var { tape: None,
+              index: 0,
+              value }
+    }
+
+    /// Value of the variable
+    pub fn value(&self) -> T
+    {
+        self.value
+    }
+
+    /// Partial derivatives of this variable with respect to the variables
+    /// wrt
+    ///
+    /// The derivatives with respect to constants and to variables which do
+    /// not influence self are zero.
+    pub fn gradient(&self, wrt: &[Var<'t, T>]) -> Vec<T>
+    {
+        let tape: &Tape<T> = match self.tape
+        {
+            Some(tape) => tape,
+            None => return vec![T::zero(); wrt.len()],
+        };
+        let nodes = tape.nodes.borrow();
+
+        let mut adjoint: Vec<T> = vec![T::zero(); self.index + 1];
+        adjoint[self.index] = T::one();
+        for i in (0..=self.index).rev()
+        {
+            let a: T = adjoint[i];
+            if a == T::zero()
+            {
+                continue;
+            }
+            for (parent, partial) in nodes[i].parents.iter()
+            {
+                adjoint[*parent] += *partial * a;
+            }
+        }
+
+        wrt.iter()
+           .map(|v| match v.tape
+           {
+               Some(_) if v.index <= self.index => adjoint[v.index],
+               _ => T::zero(),
+           })
+           .collect()
+    }
+
+    fn chain(self, f: T, df: T) -> Var<'t, T>
+    {
+        match self.tape
+        {
+            Some(tape) => tape.push([(self.index, df), (self.index, T::zero())], f),
+            None => Var::constant(f),
+        }
+    }
+
+    /// Records a binary operation with the value f and the partial
+    /// derivatives df_a and df_b with respect to a and b
+    fn binary(a: Var<'t, T>, b: Var<'t, T>, f: T, df_a: T, df_b: T) -> Var<'t, T>
+    {
+        match (a.tape, b.tape)
+        {
+            (Some(tape), Some(_)) => tape.push([(a.index, df_a), (b.index, df_b)], f),
+            (Some(tape), None) => tape.push([(a.index, df_a), (a.index, T::zero())], f),
+            (None, Some(tape)) => tape.push([(b.index, df_b), (b.index, T::zero())], f),
+            (None, None) => Var::constant(f),
+        }
+    }
+}
+
+impl_real_number!(['t, T] Var<'t, T>, value);
+
+impl<'t, T> Display for Var<'t, T> where T: Real
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<'t, T> Neg for Var<'t, T> where T: Real
+{
+    type Output = Var<'t, T>;
+
+    fn neg(self) -> Var<'t, T>
+    {
+        self.chain(-self.value, -T::one())
+    }
+}
+
+impl<'t, T> Add for Var<'t, T> where T: Real
+{
+    type Output = Var<'t, T>;
+
+    fn add(self, rhs: Var<'t, T>) -> Var<'t, T>
+    {
+        Var::binary(self, rhs, self.value + rhs.value, T::one(), T::one())
+    }
+}
+
+impl<'t, T> AddAssign for Var<'t, T> where T: Real
+{
+    fn add_assign(&mut self, rhs: Var<'t, T>)
+    {
+        *self = *self + rhs;
+    }
+}
+
+impl<'t, T> Sub for Var<'t, T> where T: Real
+{
+    type Output = Var<'t, T>;
+
+    fn sub(self, rhs: Var<'t, T>) -> Var<'t, T>
+    {
+        Var::binary(self, rhs, self.value - rhs.value, T::one(), -T::one())
+    }
+}
+
+impl<'t, T> SubAssign for Var<'t, T> where T: Real
+{
+    fn sub_assign(&mut self, rhs: Var<'t, T>)
+    {
+        *self = *self - rhs;
+    }
+}
+
+impl<'t, T> Mul for Var<'t, T> where T: Real
+{
+    type Output = Var<'t, T>;
+
+    fn mul(self, rhs: Var<'t, T>) -> Var<'t, T>
+    {
+        Var::binary(self, rhs, self.value * rhs.value, rhs.value, self.value)
+    }
+}
+
+impl<'t, T> MulAssign for Var<'t, T> where T: Real
+{
+    fn mul_assign(&mut self, rhs: Var<'t, T>)
+    {
+        *self = *self * rhs;
+    }
+}
+
+impl<'t, T> Div for Var<'t, T> where T: Real
+{
+    type Output = Var<'t, T>;
+
+    fn div(self, rhs: Var<'t, T>) -> Var<'t, T>
+    {
+        let f: T = self.value / rhs.value;
+        Var::binary(self, rhs, f, T::one() / rhs.value, -f / rhs.value)
+    }
+}
+
+impl<'t, T> DivAssign for Var<'t, T> where T: Real
+{
+    fn div_assign(&mut self, rhs: Var<'t, T>)
+    {
+        *self = *self / rhs;
+    }
+}
+
+impl<'t, T> AbsDiffEq for Var<'t, T> where T: Real
+{
+    type Epsilon = Self;
+
+    fn default_epsilon() -> Self
+    {
+        Var::constant(T::default_epsilon())
+    }
+
+    fn abs_diff_eq(&self, other: &Var<'t, T>, epsilon: Self) -> bool
+    {
+        self.value.abs_diff_eq(&other.value, epsilon.value)
+    }
+}
+
+impl<'t, T> RelativeEq for Var<'t, T> where T: Real
+{
+    fn default_max_relative() -> Self
+    {
+        Var::constant(T::default_max_relative())
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self, max_relative: Self) -> bool
+    {
+        self.value.relative_eq(&other.value, epsilon.value, max_relative.value)
+    }
+}
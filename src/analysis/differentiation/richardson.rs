@@ -0,0 +1,117 @@
+use crate::algebra::abstr::Real;
+
+/// Richardson extrapolation
+///
+/// An approximation A(h) with the error expansion
+/// ```math
+/// A(h) = A + c_1 h^{p} + c_2 h^{p + q} + c_3 h^{p + 2q} + \dots
+/// ```
+/// is evaluated for the steps $h, h/2, \dots, h/2^k$. The Neville-like
+/// tableau
+/// ```math
+/// A_{i,j} = A_{i,j-1} + \frac{A_{i,j-1} - A_{i-1,j-1}}{2^{p + (j-1)q} - 1}
+/// ```
+/// eliminates one term of the expansion per column. The difference of the
+/// last two diagonal entries serves as error estimate.
+///
+/// Richardson, L. F.: The approximate arithmetical solution by finite
+/// differences of physical problems, Philosophical Transactions of the Royal
+/// Society A, 210, 1911
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{analysis::differentiation::Richardson, elementary::Trigonometry};
+///
+/// // central difference of sin at 1, A(h) = cos(1) + O(h^2) + O(h^4) + ...
+/// let central = |h: f64| ((1.0 + h).sin() - (1.0 - h).sin()) / (2.0 * h);
+///
+/// let (d, error): (f64, f64) = Richardson::new(2, 2, 4).extrapolate(central, 0.1);
+///
+/// assert_relative_eq!(d, 1.0f64.cos(), epsilon = 1.0e-13);
+/// assert!(error < 1.0e-10);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Richardson
+{
+    order: u32,
+    increment: u32,
+    levels: usize,
+}
+
+impl Richardson
+{
+    /// Richardson extrapolation
+    ///
+    /// # Arguments
+    ///
+    /// * 'order': order p of the leading error term
+    /// * 'increment': increment q of the orders of the error terms
+    /// * 'levels': number of extrapolation steps k, the approximation is
+    ///   evaluated k + 1 times
+    ///
+    /// # Panics
+    ///
+    /// if order or increment is zero
+    pub fn new(order: u32, increment: u32, levels: usize) -> Richardson
+    {
+        if order == 0 || increment == 0
+        {
+            panic!("The order and the increment have to be positive");
+        }
+        Richardson { order,
+                     increment,
+                     levels }
+    }
+
+    /// Extrapolates the approximation to the step zero
+    ///
+    /// Returns the extrapolated value and an estimate of its error, which is
+    /// infinite if no extrapolation step is made.
+    pub fn extrapolate<T, F>(&self, approximation: F, h: T) -> (T, T)
+        where T: Real,
+              F: Fn(T) -> T
+    {
+        let (value, error): (Vec<T>, T) = self.extrapolate_vec(|h: T| vec![approximation(h)], h);
+        (value[0], error)
+    }
+
+    /// Extrapolates a vector of approximations component-wise
+    ///
+    /// The error estimate is the maximal error estimate of the components.
+    pub(crate) fn extrapolate_vec<T, F>(&self, approximation: F, h: T) -> (Vec<T>, T)
+        where T: Real,
+              F: Fn(T) -> Vec<T>
+    {
+        let two: T = T::from_f64(2.0);
+        let mut step: T = h;
+        let mut previous: Vec<Vec<T>> = vec![approximation(step)];
+        let mut error: T = T::infinity();
+
+        for i in 1..=self.levels
+        {
+            step /= two;
+            let mut row: Vec<Vec<T>> = vec![approximation(step)];
+            for j in 1..=i
+            {
+                let factor: T = two.pow(T::from_u32(self.order + (j as u32 - 1) * self.increment)) - T::one();
+                let entry: Vec<T> = row[j - 1].iter()
+                                              .zip(previous[j - 1].iter())
+                                              .map(|(a, b)| *a + (*a - *b) / factor)
+                                              .collect();
+                row.push(entry);
+            }
+            error = row[i].iter()
+                          .zip(previous[i - 1].iter())
+                          .fold(T::zero(), |e, (a, b)| e.max((*a - *b).abs()));
+            previous = row;
+        }
+
+        (previous.pop().unwrap(), error)
+    }
+}
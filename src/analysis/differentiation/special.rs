@@ -0,0 +1,196 @@
+//! Special functions of dual numbers
+//!
+//! Derivatives with respect to the arguments are exact. Derivatives of the
+//! incomplete gamma, incomplete beta and hypergeometric functions with
+//! respect to their parameters have no closed form, they are approximated by
+//! central differences.
+
+use crate::{
+    algebra::abstr::Real,
+    analysis::differentiation::Dual,
+    special::{beta::Beta, error::Error, gamma::Gamma, hypergeometric::Hypergeometric},
+};
+
+/// Central difference approximation of the derivative of f at a
+fn parameter_derivative<T, F>(f: F, a: T) -> T
+    where T: Real,
+          F: Fn(T) -> T
+{
+    // the step only depends on the value of a, so it does not carry derivatives
+    let h: T = T::from_f64(T::default_epsilon().to_f64().cbrt() * a.to_f64().abs().max(1.0));
+    (f(a + h) - f(a - h)) / (h + h)
+}
+
+/// Trigamma function $\psi_1(x) = \frac{d}{dx}\psi(x)$
+///
+/// The recurrence $\psi_1(x) = \psi_1(x + 1) + x^{-2}$ shifts the argument
+/// until the asymptotic expansion is accurate.
+fn trigamma<T>(x: T) -> T
+    where T: Real
+{
+    let mut x: T = x;
+    let mut sum: T = T::zero();
+    while x < T::from_f64(10.0)
+    {
+        sum += T::one() / (x * x);
+        x += T::one();
+    }
+    let z: T = T::one() / (x * x);
+    let series: T = z * (T::from_f64(1.0 / 6.0)
+                         - z * (T::from_f64(1.0 / 30.0) - z * (T::from_f64(1.0 / 42.0) - z * T::from_f64(1.0 / 30.0))));
+
+    sum + (T::one() + T::one() / (x + x) + series) / x
+}
+
+/// Density $x^{a - 1}e^{-x}$ of the incomplete gamma functions
+fn gamma_density<T>(a: T, x: T) -> T
+    where T: Real
+{
+    x.pow(a - T::one()) * (-x).exp()
+}
+
+/// Density $x^{a - 1}(1 - x)^{b - 1}$ of the incomplete beta functions
+fn beta_density<T>(x: T, a: T, b: T) -> T
+    where T: Real
+{
+    x.pow(a - T::one()) * (T::one() - x).pow(b - T::one())
+}
+
+impl<T> Gamma for Dual<T> where T: Real + Gamma
+{
+    fn gamma(self) -> Self
+    {
+        let g: T = self.re.gamma();
+        Dual::new(g, g * self.re.digamma() * self.eps)
+    }
+
+    fn ln_gamma(self) -> Self
+    {
+        Dual::new(self.re.ln_gamma(), self.re.digamma() * self.eps)
+    }
+
+    fn digamma(self) -> Self
+    {
+        Dual::new(self.re.digamma(), trigamma(self.re) * self.eps)
+    }
+
+    fn gamma_u(self, x: Self) -> Self
+    {
+        let d_a: T = parameter_derivative(|a: T| a.gamma_u(x.re), self.re);
+        let d_x: T = -gamma_density(self.re, x.re);
+        Dual::new(self.re.gamma_u(x.re), d_a * self.eps + d_x * x.eps)
+    }
+
+    fn gamma_ur(self, x: Self) -> Self
+    {
+        let d_a: T = parameter_derivative(|a: T| a.gamma_ur(x.re), self.re);
+        let d_x: T = -gamma_density(self.re, x.re) / self.re.gamma();
+        Dual::new(self.re.gamma_ur(x.re), d_a * self.eps + d_x * x.eps)
+    }
+
+    fn gamma_l(self, x: Self) -> Self
+    {
+        let d_a: T = parameter_derivative(|a: T| a.gamma_l(x.re), self.re);
+        let d_x: T = gamma_density(self.re, x.re);
+        Dual::new(self.re.gamma_l(x.re), d_a * self.eps + d_x * x.eps)
+    }
+
+    fn gamma_lr(self, x: Self) -> Self
+    {
+        let d_a: T = parameter_derivative(|a: T| a.gamma_lr(x.re), self.re);
+        let d_x: T = gamma_density(self.re, x.re) / self.re.gamma();
+        Dual::new(self.re.gamma_lr(x.re), d_a * self.eps + d_x * x.eps)
+    }
+
+    /// The derivatives follow from the implicit function theorem applied to
+    /// $Q(a, x) - q = 0$.
+    fn gamma_ur_inv(self, p: Self) -> Self
+    {
+        let x: T = self.re.gamma_ur_inv(p.re);
+        let d_x: T = -gamma_density(self.re, x) / self.re.gamma();
+        let d_a: T = parameter_derivative(|a: T| a.gamma_ur(x), self.re);
+        Dual::new(x, (p.eps - d_a * self.eps) / d_x)
+    }
+
+    /// The derivatives follow from the implicit function theorem applied to
+    /// $P(a, x) - p = 0$.
+    fn gamma_lr_inv(self, p: Self) -> Self
+    {
+        let x: T = self.re.gamma_lr_inv(p.re);
+        let d_x: T = gamma_density(self.re, x) / self.re.gamma();
+        let d_a: T = parameter_derivative(|a: T| a.gamma_lr(x), self.re);
+        Dual::new(x, (p.eps - d_a * self.eps) / d_x)
+    }
+}
+
+impl<T> Error for Dual<T> where T: Real + Error
+{
+    fn erf(self) -> Self
+    {
+        let d: T = T::from_f64(2.0) / T::pi().sqrt() * (-self.re * self.re).exp();
+        Dual::new(self.re.erf(), d * self.eps)
+    }
+
+    fn erfc(self) -> Self
+    {
+        let d: T = -T::from_f64(2.0) / T::pi().sqrt() * (-self.re * self.re).exp();
+        Dual::new(self.re.erfc(), d * self.eps)
+    }
+
+    fn erfinv(self) -> Self
+    {
+        let y: T = self.re.erfinv();
+        let d: T = T::pi().sqrt() / T::from_f64(2.0) * (y * y).exp();
+        Dual::new(y, d * self.eps)
+    }
+
+    fn erfcinv(self) -> Self
+    {
+        let y: T = self.re.erfcinv();
+        let d: T = -T::pi().sqrt() / T::from_f64(2.0) * (y * y).exp();
+        Dual::new(y, d * self.eps)
+    }
+}
+
+impl<T> Beta for Dual<T> where T: Real + Beta + Gamma
+{
+    fn beta(self, y: Self) -> Self
+    {
+        let b: T = self.re.beta(y.re);
+        let psi_xy: T = (self.re + y.re).digamma();
+        Dual::new(b,
+                  b * ((self.re.digamma() - psi_xy) * self.eps + (y.re.digamma() - psi_xy) * y.eps))
+    }
+
+    fn beta_inc(self, a: Self, b: Self) -> Self
+    {
+        let x: T = self.re;
+        let d_x: T = beta_density(x, a.re, b.re);
+        let d_a: T = parameter_derivative(|s: T| x.beta_inc(s, b.re), a.re);
+        let d_b: T = parameter_derivative(|s: T| x.beta_inc(a.re, s), b.re);
+        Dual::new(x.beta_inc(a.re, b.re), d_x * self.eps + d_a * a.eps + d_b * b.eps)
+    }
+
+    fn beta_inc_reg(self, a: Self, b: Self) -> Self
+    {
+        let x: T = self.re;
+        let d_x: T = beta_density(x, a.re, b.re) / a.re.beta(b.re);
+        let d_a: T = parameter_derivative(|s: T| x.beta_inc_reg(s, b.re), a.re);
+        let d_b: T = parameter_derivative(|s: T| x.beta_inc_reg(a.re, s), b.re);
+        Dual::new(x.beta_inc_reg(a.re, b.re), d_x * self.eps + d_a * a.eps + d_b * b.eps)
+    }
+}
+
+impl<T> Hypergeometric for Dual<T> where T: Real + Hypergeometric
+{
+    fn f21(self, b: Self, c: Self, z: Self) -> Self
+    {
+        let (a, b_re, c_re, z_re): (T, T, T, T) = (self.re, b.re, c.re, z.re);
+        let d_z: T = a * b_re / c_re * (a + T::one()).f21(b_re + T::one(), c_re + T::one(), z_re);
+        let d_a: T = parameter_derivative(|s: T| s.f21(b_re, c_re, z_re), a);
+        let d_b: T = parameter_derivative(|s: T| a.f21(s, c_re, z_re), b_re);
+        let d_c: T = parameter_derivative(|s: T| a.f21(b_re, s, z_re), c_re);
+        Dual::new(a.f21(b_re, c_re, z_re),
+                  d_a * self.eps + d_b * b.eps + d_c * c.eps + d_z * z.eps)
+    }
+}
@@ -0,0 +1,124 @@
+//! Gauss-Chebyshev quadrature
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Kind of the Chebyshev polynomials
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChebyshevKind
+{
+    /// Weight $\frac{1}{\sqrt{1 - x^2}}$
+    First,
+    /// Weight $\sqrt{1 - x^2}$
+    Second,
+}
+
+/// Gauss-Chebyshev quadrature
+///
+/// First kind:
+/// ```math
+/// \int_{-1}^{1}\frac{f(x)}{\sqrt{1 - x^2}}\,dx \approx \frac{\pi}{n}\sum_{i=1}^{n}f(x_i), \quad x_i = \cos\left(\frac{2i - 1}{2n}\pi\right)
+/// ```
+/// Second kind:
+/// ```math
+/// \int_{-1}^{1}\sqrt{1 - x^2}f(x)\,dx \approx \sum_{i=1}^{n}\frac{\pi}{n + 1}\sin^2\left(\frac{i}{n + 1}\pi\right)f(x_i), \quad x_i = \cos\left(\frac{i}{n + 1}\pi\right)
+/// ```
+/// The nodes and weights are known in closed form.
+///
+/// <https://en.wikipedia.org/wiki/Chebyshev%E2%80%93Gauss_quadrature>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::{ChebyshevKind, GaussChebyshev};
+///
+/// let gc: GaussChebyshev<f64> = GaussChebyshev::new(4, ChebyshevKind::First);
+/// let integral: f64 = gc.integrate(|x| x * x);
+///
+/// assert_relative_eq!(integral, std::f64::consts::PI / 2.0, epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussChebyshev<T>
+{
+    kind: ChebyshevKind,
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> GaussChebyshev<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes
+    /// * 'kind': kind of the weight function
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1
+    pub fn new(n: usize, kind: ChebyshevKind) -> GaussChebyshev<T>
+    {
+        if n < 1
+        {
+            panic!("n is not within the limits");
+        }
+
+        let (nodes, weights): (Vec<T>, Vec<T>) = match kind
+        {
+            ChebyshevKind::First =>
+            {
+                let w: T = T::pi() / T::from_f64(n as f64);
+                (1..=n).rev()
+                       .map(|i| {
+                           let theta: T = T::pi() * T::from_f64((2 * i - 1) as f64 / (2 * n) as f64);
+                           (theta.cos(), w)
+                       })
+                       .unzip()
+            }
+            ChebyshevKind::Second =>
+            {
+                let h: T = T::pi() / T::from_f64((n + 1) as f64);
+                (1..=n).rev()
+                       .map(|i| {
+                           let theta: T = h * T::from_f64(i as f64);
+                           let s: T = theta.sin();
+                           (theta.cos(), h * s * s)
+                       })
+                       .unzip()
+            }
+        };
+
+        GaussChebyshev { kind, nodes, weights }
+    }
+
+    pub fn kind(&self) -> ChebyshevKind
+    {
+        self.kind
+    }
+
+    pub fn nodes(&self) -> &[T]
+    {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T]
+    {
+        &self.weights
+    }
+
+    /// Approximates the integral of f against the Chebyshev weight over
+    /// $[-1, 1]$
+    pub fn integrate<F>(&self, f: F) -> T
+        where F: Fn(T) -> T
+    {
+        self.nodes.iter().zip(self.weights.iter()).fold(T::zero(), |s, (x_i, w_i)| s + *w_i * f(*x_i))
+    }
+}
@@ -0,0 +1,97 @@
+//! Gauss-Hermite quadrature
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::golub_welsch::golub_welsch;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gauss-Hermite quadrature
+///
+/// Integrates against the weight $e^{-x^2}$ over the real line
+/// ```math
+/// \int_{-\infty}^{\infty}e^{-x^2}f(x)\,dx \approx \sum_{i=1}^{n}w_i f(x_i)
+/// ```
+/// The rule is exact for polynomials of degree 2n - 1. The nodes and weights
+/// are computed with the Golub-Welsch algorithm.
+///
+/// <https://en.wikipedia.org/wiki/Gauss%E2%80%93Hermite_quadrature>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::GaussHermite;
+///
+/// let gh: GaussHermite<f64> = GaussHermite::new(10);
+///
+/// // E[X^2] with X ~ N(1, 2^2)
+/// let moment: f64 = gh.integrate_normal(|x| x * x, 1.0, 2.0);
+///
+/// assert_relative_eq!(moment, 5.0, epsilon = 1.0e-12);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussHermite<T>
+{
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> GaussHermite<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1
+    pub fn new(n: usize) -> GaussHermite<T>
+    {
+        if n < 1
+        {
+            panic!("n is not within the limits");
+        }
+
+        let diag: Vec<T> = vec![T::zero(); n];
+        let off_diag: Vec<T> = (1..n).map(|k| T::from_f64(k as f64 / 2.0).sqrt()).collect();
+
+        let (nodes, weights): (Vec<T>, Vec<T>) = golub_welsch(diag, off_diag, T::pi().sqrt());
+
+        GaussHermite { nodes, weights }
+    }
+
+    pub fn nodes(&self) -> &[T]
+    {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T]
+    {
+        &self.weights
+    }
+
+    /// Approximates $\int_{-\infty}^{\infty}e^{-x^2}f(x)\,dx$
+    pub fn integrate<F>(&self, f: F) -> T
+        where F: Fn(T) -> T
+    {
+        self.nodes.iter().zip(self.weights.iter()).fold(T::zero(), |s, (x_i, w_i)| s + *w_i * f(*x_i))
+    }
+
+    /// Approximates the expected value $E[f(X)]$ of a normally distributed
+    /// random variable $X \sim \mathcal{N}(\mu, \sigma^2)$
+    /// ```math
+    /// E[f(X)] = \frac{1}{\sqrt{\pi}}\int_{-\infty}^{\infty}e^{-x^2}f(\mu + \sqrt{2}\sigma x)\,dx
+    /// ```
+    pub fn integrate_normal<F>(&self, f: F, mean: T, std_dev: T) -> T
+        where F: Fn(T) -> T
+    {
+        let scale: T = T::from_f64(2.0).sqrt() * std_dev;
+        self.integrate(|x| f(mean + scale * x)) / T::pi().sqrt()
+    }
+}
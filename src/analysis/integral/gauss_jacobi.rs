@@ -0,0 +1,119 @@
+//! Gauss-Jacobi quadrature
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::golub_welsch::golub_welsch;
+use crate::special::gamma::Gamma;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gauss-Jacobi quadrature
+///
+/// Integrates against the weight $(1 - x)^{\alpha}(1 + x)^{\beta}$ over the
+/// interval $[-1, 1]$
+/// ```math
+/// \int_{-1}^{1}(1 - x)^{\alpha}(1 + x)^{\beta}f(x)\,dx \approx \sum_{i=1}^{n}w_i f(x_i)
+/// ```
+/// The rule is exact for polynomials of degree 2n - 1. Algebraic end point
+/// singularities of the integrand can be absorbed in the weight. The nodes
+/// and weights are computed with the Golub-Welsch algorithm.
+///
+/// <https://en.wikipedia.org/wiki/Gauss%E2%80%93Jacobi_quadrature>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::GaussJacobi;
+///
+/// // \int_{-1}^{1} (1 + x)^{-1/2} dx = 2 \sqrt{2}
+/// let gj: GaussJacobi<f64> = GaussJacobi::new(5, 0.0, -0.5);
+/// let integral: f64 = gj.integrate(|_x| 1.0);
+///
+/// assert_relative_eq!(integral, 2.0 * 2.0f64.sqrt(), epsilon = 1.0e-12);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussJacobi<T>
+{
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> GaussJacobi<T>
+    where T: Real + Gamma
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes
+    /// * 'alpha': exponent at the right end point, alpha > -1.0
+    /// * 'beta': exponent at the left end point, beta > -1.0
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1, alpha <= -1.0 or beta <= -1.0
+    pub fn new(n: usize, alpha: T, beta: T) -> GaussJacobi<T>
+    {
+        if n < 1 || alpha <= -T::one() || beta <= -T::one()
+        {
+            panic!("Parameters are not within the limits");
+        }
+
+        let one: T = T::one();
+        let two: T = T::from_f64(2.0);
+        let ab: T = alpha + beta;
+
+        let diag: Vec<T> = (0..n).map(|k| {
+                                     if k == 0
+                                     {
+                                         return (beta - alpha) / (ab + two);
+                                     }
+                                     let c: T = two * T::from_f64(k as f64) + ab;
+                                     (beta * beta - alpha * alpha) / (c * (c + two))
+                                 })
+                                 .collect();
+
+        let off_diag: Vec<T> = (1..n).map(|k| {
+                                         let k_f: T = T::from_f64(k as f64);
+                                         let c: T = two * k_f + ab;
+                                         let b_2: T = if k == 1
+                                         {
+                                             two * two * (alpha + one) * (beta + one) / (c * c * (c + one))
+                                         }
+                                         else
+                                         {
+                                             two * two * k_f * (k_f + alpha) * (k_f + beta) * (k_f + ab)
+                                             / (c * c * (c + one) * (c - one))
+                                         };
+                                         b_2.sqrt()
+                                     })
+                                     .collect();
+
+        let mu_0: T = ((ab + one) * two.ln() + (alpha + one).ln_gamma() + (beta + one).ln_gamma()
+                       - (ab + two).ln_gamma()).exp();
+
+        let (nodes, weights): (Vec<T>, Vec<T>) = golub_welsch(diag, off_diag, mu_0);
+
+        GaussJacobi { nodes, weights }
+    }
+
+    pub fn nodes(&self) -> &[T]
+    {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T]
+    {
+        &self.weights
+    }
+
+    /// Approximates $\int_{-1}^{1}(1 - x)^{\alpha}(1 + x)^{\beta}f(x)\,dx$
+    pub fn integrate<F>(&self, f: F) -> T
+        where F: Fn(T) -> T
+    {
+        self.nodes.iter().zip(self.weights.iter()).fold(T::zero(), |s, (x_i, w_i)| s + *w_i * f(*x_i))
+    }
+}
@@ -0,0 +1,382 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::{
+    gauss_kronrod::{epsilon::EpsilonTable, GaussKronrodRule},
+    QuadratureResult,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adaptive Gauss-Kronrod quadrature
+///
+/// ```math
+/// \int_{a}^{b}f(x)\,dx
+/// ```
+///
+/// The interval with the largest error estimate is bisected until the sum of
+/// the error estimates satisfies
+/// ```math
+/// \sum_{i} E_{i} \leq \max(\epsilon_{abs}, \epsilon_{rel} |I|)
+/// ```
+/// With extrapolation enabled (default), the algorithm corresponds to
+/// QUADPACK's QAGS: the sequence of approximations obtained by refining the
+/// intervals level by level is extrapolated with Wynn's epsilon algorithm,
+/// which accelerates the convergence for integrands with end point
+/// singularities. Without extrapolation, it corresponds to QAG.
+///
+/// Piessens, R., de Doncker-Kapenga, E., Überhuber, C. W., Kahaner, D. K.:
+/// QUADPACK, A Subroutine Package for Automatic Integration, Springer, 1983
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::{gauss_kronrod::GaussKronrod, QuadratureResult};
+///
+/// let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-10, 1.0e-10, 100);
+///
+/// // peaked density
+/// let f = |x: f64| (-(x - 0.3) * (x - 0.3) / 5.0e-3).exp() / (5.0e-3 * std::f64::consts::PI).sqrt();
+/// let result: QuadratureResult<f64> = gk.integrate(f, -1.0, 2.0);
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(1.0, result.value(), epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct GaussKronrod<T>
+{
+    rule: GaussKronrodRule,
+    abs_tol: T,
+    rel_tol: T,
+    max_subintervals: usize,
+    extrapolation: bool,
+}
+
+impl<T> Default for GaussKronrod<T>
+    where T: Real
+{
+    fn default() -> GaussKronrod<T>
+    {
+        GaussKronrod::new(T::from_f64(1.0e-10), T::from_f64(1.0e-10), 100)
+    }
+}
+
+/// Subinterval of the adaptive quadrature
+struct Interval<T>
+{
+    a: T,
+    b: T,
+    value: T,
+    error: T,
+    /// Number of bisections
+    level: u32,
+}
+
+impl<T> GaussKronrod<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'max_subintervals': maximal number of subintervals
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative, both tolerances are zero or
+    /// max_subintervals == 0
+    pub fn new(abs_tol: T, rel_tol: T, max_subintervals: usize) -> GaussKronrod<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+        if max_subintervals == 0
+        {
+            panic!("The number of subintervals has to be positive");
+        }
+
+        GaussKronrod { rule: GaussKronrodRule::default(),
+                       abs_tol,
+                       rel_tol,
+                       max_subintervals,
+                       extrapolation: true }
+    }
+
+    pub fn set_rule(&mut self, rule: GaussKronrodRule)
+    {
+        self.rule = rule;
+    }
+
+    pub fn get_rule(&self) -> GaussKronrodRule
+    {
+        self.rule
+    }
+
+    /// Enables or disables the extrapolation with the epsilon algorithm
+    pub fn set_extrapolation(&mut self, extrapolation: bool)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    /// Integrate function f from lower bound a to upper bound b
+    ///
+    /// Infinite bounds are mapped to the interval $(0, 1]$ as in QUADPACK's
+    /// QAGI
+    /// ```math
+    /// \int_{a}^{\infty}f(x)\,dx = \int_{0}^{1}f\left(a + \frac{1 - t}{t}\right)\frac{1}{t^2}\,dt
+    /// ```
+    /// The integrand is never evaluated at $t = 0$.
+    ///
+    /// # Arguments
+    /// * a: lower bound of the definite integral, may be $-\infty$
+    /// * b: upper bound of the definite integral, may be $\infty$
+    pub fn integrate<F>(&self, f: F, a: T, b: T) -> QuadratureResult<T>
+        where F: Fn(T) -> T
+    {
+        if a.abs() < T::infinity() && b.abs() < T::infinity()
+        {
+            return self.integrate_points(f, &[a, b]);
+        }
+        if a == b
+        {
+            return QuadratureResult::new(T::zero(), T::zero(), 0, true);
+        }
+        if a > b
+        {
+            let result: QuadratureResult<T> = self.integrate(f, b, a);
+            return QuadratureResult::new(-result.value(),
+                                         result.error(),
+                                         result.evaluations(),
+                                         result.is_converged());
+        }
+
+        let bounds: [T; 2] = [T::zero(), T::one()];
+        if a == T::neg_infinity() && b == T::infinity()
+        {
+            self.integrate_points(|t: T| {
+                                      let x: T = (T::one() - t) / t;
+                                      (f(x) + f(-x)) / (t * t)
+                                  },
+                                  &bounds)
+        }
+        else if b == T::infinity()
+        {
+            self.integrate_points(|t: T| f(a + (T::one() - t) / t) / (t * t), &bounds)
+        }
+        else
+        {
+            self.integrate_points(|t: T| f(b - (T::one() - t) / t) / (t * t), &bounds)
+        }
+    }
+
+    /// Cauchy principal value of the integral of $\frac{f(x)}{x - c}$
+    ///
+    /// ```math
+    /// \mathrm{PV}\int_{a}^{b}\frac{f(x)}{x - c}\,dx = \lim_{\varepsilon \rightarrow 0}\left(\int_{a}^{c - \varepsilon}\frac{f(x)}{x - c}\,dx + \int_{c + \varepsilon}^{b}\frac{f(x)}{x - c}\,dx\right)
+    /// ```
+    /// The singularity is subtracted
+    /// ```math
+    /// \mathrm{PV}\int_{a}^{b}\frac{f(x)}{x - c}\,dx = \int_{a}^{b}\frac{f(x) - f(c)}{x - c}\,dx + f(c)\ln\frac{b - c}{c - a}
+    /// ```
+    /// and the remaining integral, whose integrand has a removable
+    /// singularity, is computed with c as break point.
+    ///
+    /// # Arguments
+    /// * f: numerator of the integrand
+    /// * a: lower bound of the definite integral
+    /// * b: upper bound of the definite integral
+    /// * c: location of the pole
+    ///
+    /// # Panics
+    ///
+    /// if c does not lie strictly between a and b
+    pub fn integrate_cauchy<F>(&self, f: F, a: T, b: T, c: T) -> QuadratureResult<T>
+        where F: Fn(T) -> T
+    {
+        if !(a.min(b) < c && c < a.max(b))
+        {
+            panic!("The pole does not lie within the interval");
+        }
+
+        let f_c: T = f(c);
+        let result: QuadratureResult<T> = self.integrate_points(|x: T| (f(x) - f_c) / (x - c), &[a, c, b]);
+
+        QuadratureResult::new(result.value() + f_c * ((b - c) / (c - a)).ln(),
+                              result.error(),
+                              result.evaluations() + 1,
+                              result.is_converged())
+    }
+
+    /// Integrate function f over the interval, which is divided by the
+    /// given points
+    ///
+    /// Peaks, singularities and discontinuities of the integrand, whose
+    /// location is known, should be passed as break points. Otherwise, a
+    /// peak, which is narrow compared to the distance of the nodes of the
+    /// initial rule, is not detected.
+    ///
+    /// # Arguments
+    /// * points: increasing or decreasing sequence, the first and the last
+    ///   point are the bounds of the definite integral
+    ///
+    /// # Panics
+    ///
+    /// if less than two points are given
+    pub fn integrate_points<F>(&self, f: F, points: &[T]) -> QuadratureResult<T>
+        where F: Fn(T) -> T
+    {
+        if points.len() < 2
+        {
+            panic!("At least two points are required");
+        }
+
+        let evaluations_per_rule: usize = self.rule.nodes();
+        let tolerance = |value: T| -> T { self.abs_tol.max(self.rel_tol * value.abs()) };
+
+        let mut intervals: Vec<Interval<T>> = Vec::with_capacity(points.len() - 1);
+        let mut evaluations: usize = 0;
+        for p in points.windows(2).filter(|p| p[0] != p[1])
+        {
+            let (value, error): (T, T) = self.rule.apply(&f, p[0], p[1]);
+            evaluations += evaluations_per_rule;
+            intervals.push(Interval { a: p[0],
+                                      b: p[1],
+                                      value,
+                                      error,
+                                      level: 0 });
+        }
+
+        let value: T = intervals.iter().fold(T::zero(), |s, i| s + i.value);
+        let error: T = intervals.iter().fold(T::zero(), |s, i| s + i.error);
+        if error <= tolerance(value) || intervals.len() >= self.max_subintervals
+        {
+            return QuadratureResult::new(value, error, evaluations, error <= tolerance(value));
+        }
+
+        let mut area: T = value;
+        let mut error_sum: T = error;
+
+        // State of the extrapolation
+        let mut table: EpsilonTable<T> = EpsilonTable::new();
+        table.push(value);
+        let mut result_ext: T = value;
+        let mut error_ext: T = T::from_f64(f64::MAX);
+        let mut error_test: T = tolerance(value);
+        let mut no_improvement: u32 = 0;
+        // intervals with a level below are large, the other ones are small
+        let mut large_level: u32 = 2;
+        let mut extrapolate: bool = false;
+
+        let error_large = |intervals: &Vec<Interval<T>>, large_level: u32| -> T {
+            intervals.iter()
+                     .filter(|i| i.level < large_level)
+                     .fold(T::zero(), |s, i| s + i.error)
+        };
+
+        while intervals.len() < self.max_subintervals
+        {
+            // During the extrapolation, only the large intervals are bisected
+            let candidate: Option<usize> = intervals.iter()
+                                                    .enumerate()
+                                                    .filter(|(_, i)| !extrapolate || i.level < large_level)
+                                                    .fold(None, |m: Option<(usize, T)>, (k, i)| match m
+                                                    {
+                                                        Some((_, e)) if e >= i.error => m,
+                                                        _ => Some((k, i.error)),
+                                                    })
+                                                    .map(|(k, _)| k);
+
+            if let Some(k) = candidate
+            {
+                let interval: Interval<T> = intervals.swap_remove(k);
+                let center: T = (interval.a + interval.b) / T::from_f64(2.0);
+                let level: u32 = interval.level + 1;
+
+                let (value_1, error_1): (T, T) = self.rule.apply(&f, interval.a, center);
+                let (value_2, error_2): (T, T) = self.rule.apply(&f, center, interval.b);
+                evaluations += 2 * evaluations_per_rule;
+
+                area += value_1 + value_2 - interval.value;
+                error_sum += error_1 + error_2 - interval.error;
+
+                intervals.push(Interval { a: interval.a,
+                                          b: center,
+                                          value: value_1,
+                                          error: error_1,
+                                          level });
+                intervals.push(Interval { a: center,
+                                          b: interval.b,
+                                          value: value_2,
+                                          error: error_2,
+                                          level });
+
+                if error_sum <= tolerance(area)
+                {
+                    return QuadratureResult::new(area, error_sum, evaluations, true);
+                }
+
+                if !self.extrapolation
+                {
+                    continue;
+                }
+                if !extrapolate
+                {
+                    // refine the large intervals first
+                    if level < large_level
+                    {
+                        continue;
+                    }
+                    extrapolate = true;
+                }
+                if error_large(&intervals, large_level) > error_test
+                {
+                    continue;
+                }
+            }
+            else if !extrapolate
+            {
+                continue;
+            }
+
+            // All large intervals are resolved, extrapolate the sequence of areas
+            table.push(area);
+            let (result, error): (T, T) = table.extrapolate();
+            no_improvement += 1;
+            if no_improvement > 5 && error_ext < T::from_f64(1.0e-3) * error_sum
+            {
+                // the extrapolation does not improve the result anymore
+                break;
+            }
+            if error < error_ext
+            {
+                no_improvement = 0;
+                error_ext = error;
+                result_ext = result;
+                error_test = tolerance(result);
+                if error_ext <= error_test
+                {
+                    return QuadratureResult::new(result_ext, error_ext, evaluations, true);
+                }
+            }
+
+            // continue with the bisection of the small intervals
+            large_level += 1;
+            extrapolate = false;
+        }
+
+        if error_ext < error_sum
+        {
+            QuadratureResult::new(result_ext, error_ext, evaluations, error_ext <= tolerance(result_ext))
+        }
+        else
+        {
+            QuadratureResult::new(area, error_sum, evaluations, error_sum <= tolerance(area))
+        }
+    }
+}
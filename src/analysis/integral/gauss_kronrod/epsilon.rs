@@ -0,0 +1,100 @@
+use crate::algebra::abstr::Real;
+
+/// Maximal number of elements of the sequence, which are used
+const LIMIT: usize = 50;
+
+/// Wynn's epsilon algorithm
+///
+/// Accelerates the convergence of a sequence of approximations $S_{i}$
+/// ```math
+/// \varepsilon_{-1}^{(i)} = 0, \quad \varepsilon_{0}^{(i)} = S_{i}, \quad
+/// \varepsilon_{k+1}^{(i)} = \varepsilon_{k-1}^{(i+1)} + \frac{1}{\varepsilon_{k}^{(i+1)} - \varepsilon_{k}^{(i)}}
+/// ```
+/// The columns with even k contain the extrapolated values.
+///
+/// Wynn, P.: On a Device for Computing the e_m(S_n) Transformation,
+/// Mathematical Tables and Other Aids to Computation, 10, 1956
+pub(crate) struct EpsilonTable<T>
+{
+    sequence: Vec<T>,
+    /// Last three extrapolated values
+    results: Vec<T>,
+}
+
+impl<T> EpsilonTable<T>
+    where T: Real
+{
+    pub fn new() -> EpsilonTable<T>
+    {
+        EpsilonTable { sequence: Vec::new(),
+                       results: Vec::new() }
+    }
+
+    pub fn push(&mut self, s: T)
+    {
+        if self.sequence.len() == LIMIT
+        {
+            self.sequence.remove(0);
+        }
+        self.sequence.push(s);
+    }
+
+    /// Extrapolates the sequence
+    ///
+    /// # Return
+    ///
+    /// The extrapolated value and an estimate of its error, which is based on
+    /// the last three extrapolated values
+    pub fn extrapolate(&mut self) -> (T, T)
+    {
+        let n: usize = self.sequence.len();
+        let mut result: T = self.sequence[n - 1];
+
+        let mut previous: Vec<T> = vec![T::zero(); n + 1];
+        let mut current: Vec<T> = self.sequence.clone();
+
+        for k in 1..n
+        {
+            let mut next: Vec<T> = Vec::with_capacity(n - k);
+            for i in 0..(n - k)
+            {
+                let delta: T = current[i + 1] - current[i];
+                if delta == T::zero()
+                {
+                    // the sequence has converged
+                    return self.estimate(result);
+                }
+                next.push(previous[i + 1] + T::one() / delta);
+            }
+
+            if k % 2 == 0
+            {
+                result = next[n - k - 1];
+            }
+            previous = current;
+            current = next;
+        }
+
+        self.estimate(result)
+    }
+
+    fn estimate(&mut self, result: T) -> (T, T)
+    {
+        let error: T = if self.results.len() < 3
+        {
+            T::from_f64(f64::MAX)
+        }
+        else
+        {
+            self.results.iter().fold(T::zero(), |e, r_i| e + (result - *r_i).abs())
+        };
+
+        if self.results.len() == 3
+        {
+            self.results.remove(0);
+        }
+        self.results.push(result);
+
+        (result, error.max(T::from_f64(5.0 * f64::EPSILON) * result.abs()))
+    }
+}
@@ -0,0 +1,6 @@
+mod epsilon;
+mod adaptive;
+mod rule;
+
+pub use adaptive::GaussKronrod;
+pub use rule::GaussKronrodRule;
@@ -0,0 +1,164 @@
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gauss-Kronrod pair
+///
+/// The Kronrod rule with $2n + 1$ nodes extends the Gauss rule with $n$
+/// nodes. The difference of both approximations is used as error estimate.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GaussKronrodRule
+{
+    /// 7-point Gauss and 15-point Kronrod rule
+    #[default]
+    G7K15,
+    /// 10-point Gauss and 21-point Kronrod rule
+    G10K21,
+}
+
+impl GaussKronrodRule
+{
+    /// Number of nodes of the Kronrod rule
+    pub fn nodes(&self) -> usize
+    {
+        match self
+        {
+            GaussKronrodRule::G7K15 => 15,
+            GaussKronrodRule::G10K21 => 21,
+        }
+    }
+
+    /// Non-negative Kronrod nodes in decreasing order, the weights of the
+    /// Kronrod rule and the weights of the Gauss rule
+    ///
+    /// The Gauss nodes are the Kronrod nodes with odd index.
+    fn table(&self) -> (&'static [f64], &'static [f64], &'static [f64])
+    {
+        match self
+        {
+            GaussKronrodRule::G7K15 => (&XGK_15, &WGK_15, &WG_7),
+            GaussKronrodRule::G10K21 => (&XGK_21, &WGK_21, &WG_10),
+        }
+    }
+
+    /// Applies the rule to f on the interval [a, b]
+    ///
+    /// # Return
+    ///
+    /// The Kronrod approximation and the QUADPACK error estimate
+    pub(crate) fn apply<T, F>(&self, f: &F, a: T, b: T) -> (T, T)
+        where T: Real,
+              F: Fn(T) -> T
+    {
+        let (xgk, wgk, wg): (&[f64], &[f64], &[f64]) = self.table();
+        let n: usize = xgk.len() - 1;
+
+        let center: T = (a + b) / T::from_f64(2.0);
+        let half_length: T = (b - a) / T::from_f64(2.0);
+
+        let f_center: T = f(center);
+        // values at the nodes center -/+ half_length * x_i
+        let mut f_1: Vec<T> = Vec::with_capacity(n);
+        let mut f_2: Vec<T> = Vec::with_capacity(n);
+        for x_i in xgk[..n].iter()
+        {
+            let dx: T = half_length * T::from_f64(*x_i);
+            f_1.push(f(center - dx));
+            f_2.push(f(center + dx));
+        }
+
+        let mut res_k: T = f_center * T::from_f64(wgk[n]);
+        // the center is a node of the Gauss rule if the number of Gauss nodes is odd
+        let mut res_g: T = if n % 2 == 1 { f_center * T::from_f64(wg[wg.len() - 1]) } else { T::zero() };
+        let mut res_abs: T = res_k.abs();
+        for j in 0..n
+        {
+            let w_k: T = T::from_f64(wgk[j]);
+            res_k += w_k * (f_1[j] + f_2[j]);
+            res_abs += w_k * (f_1[j].abs() + f_2[j].abs());
+            if j % 2 == 1
+            {
+                res_g += T::from_f64(wg[j / 2]) * (f_1[j] + f_2[j]);
+            }
+        }
+
+        let mean: T = res_k / T::from_f64(2.0);
+        let mut res_asc: T = T::from_f64(wgk[n]) * (f_center - mean).abs();
+        for j in 0..n
+        {
+            res_asc += T::from_f64(wgk[j]) * ((f_1[j] - mean).abs() + (f_2[j] - mean).abs());
+        }
+
+        let result: T = res_k * half_length;
+        let res_abs: T = res_abs * half_length.abs();
+        let res_asc: T = res_asc * half_length.abs();
+
+        let mut error: T = ((res_k - res_g) * half_length).abs();
+        if res_asc != T::zero() && error != T::zero()
+        {
+            error = res_asc * (T::from_f64(200.0) * error / res_asc).pow(T::from_f64(1.5)).min(T::one());
+        }
+        let eps: T = T::from_f64(f64::EPSILON);
+        if res_abs > T::from_f64(f64::MIN_POSITIVE) / (T::from_f64(50.0) * eps)
+        {
+            error = error.max(T::from_f64(50.0) * eps * res_abs);
+        }
+
+        (result, error)
+    }
+}
+
+const XGK_15: [f64; 8] = [0.9914553711208126,
+                          0.9491079123427585,
+                          0.8648644233597691,
+                          0.7415311855993945,
+                          0.5860872354676911,
+                          0.4058451513773972,
+                          0.20778495500789848,
+                          0.0];
+
+const WGK_15: [f64; 8] = [0.022935322010529224,
+                          0.06309209262997856,
+                          0.10479001032225019,
+                          0.14065325971552592,
+                          0.1690047266392679,
+                          0.19035057806478542,
+                          0.20443294007529889,
+                          0.20948214108472782];
+
+const WG_7: [f64; 4] = [0.1294849661688697,
+                        0.27970539148927664,
+                        0.3818300505051189,
+                        0.4179591836734694];
+
+const XGK_21: [f64; 11] = [0.9956571630258081,
+                           0.9739065285171717,
+                           0.9301574913557082,
+                           0.8650633666889845,
+                           0.7808177265864169,
+                           0.6794095682990244,
+                           0.5627571346686047,
+                           0.4333953941292472,
+                           0.2943928627014602,
+                           0.14887433898163122,
+                           0.0];
+
+const WGK_21: [f64; 11] = [0.011694638867371874,
+                           0.032558162307964725,
+                           0.054755896574351995,
+                           0.07503967481091996,
+                           0.0931254545836976,
+                           0.10938715880229764,
+                           0.12349197626206584,
+                           0.13470921731147334,
+                           0.14277593857706009,
+                           0.14773910490133849,
+                           0.1494455540029169];
+
+const WG_10: [f64; 5] = [0.06667134430868814,
+                         0.1494513491505806,
+                         0.21908636251598204,
+                         0.26926671930999635,
+                         0.29552422471475287];
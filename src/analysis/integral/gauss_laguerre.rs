@@ -0,0 +1,115 @@
+//! Gauss-Laguerre quadrature
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::golub_welsch::golub_welsch;
+use crate::special::gamma::Gamma;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Generalized Gauss-Laguerre quadrature
+///
+/// Integrates against the weight $x^{\alpha}e^{-x}$ over the positive real
+/// axis
+/// ```math
+/// \int_{0}^{\infty}x^{\alpha}e^{-x}f(x)\,dx \approx \sum_{i=1}^{n}w_i f(x_i)
+/// ```
+/// The rule is exact for polynomials of degree 2n - 1. The nodes and weights
+/// are computed with the Golub-Welsch algorithm.
+///
+/// <https://en.wikipedia.org/wiki/Gauss%E2%80%93Laguerre_quadrature>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::GaussLaguerre;
+///
+/// let gl: GaussLaguerre<f64> = GaussLaguerre::new(10, 0.0);
+///
+/// // \int_0^\infty e^{-2x} x^3 dx = 3! / 2^4
+/// let integral: f64 = gl.integrate_exponential(|x| x * x * x, 2.0);
+///
+/// assert_relative_eq!(integral, 0.375, epsilon = 1.0e-12);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussLaguerre<T>
+{
+    alpha: T,
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> GaussLaguerre<T>
+    where T: Real + Gamma
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes
+    /// * 'alpha': exponent of the weight, alpha > -1.0. The classical
+    ///   Gauss-Laguerre quadrature is given by alpha = 0.0
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1 or alpha <= -1.0
+    pub fn new(n: usize, alpha: T) -> GaussLaguerre<T>
+    {
+        if n < 1 || alpha <= -T::one()
+        {
+            panic!("Parameters are not within the limits");
+        }
+
+        let diag: Vec<T> = (0..n).map(|k| T::from_f64(2.0 * k as f64 + 1.0) + alpha).collect();
+        let off_diag: Vec<T> = (1..n).map(|k| {
+                                         let k: T = T::from_f64(k as f64);
+                                         (k * (k + alpha)).sqrt()
+                                     })
+                                     .collect();
+
+        let mu_0: T = (alpha + T::one()).ln_gamma().exp();
+        let (nodes, weights): (Vec<T>, Vec<T>) = golub_welsch(diag, off_diag, mu_0);
+
+        GaussLaguerre { alpha, nodes, weights }
+    }
+
+    pub fn nodes(&self) -> &[T]
+    {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T]
+    {
+        &self.weights
+    }
+
+    /// Approximates $\int_{0}^{\infty}x^{\alpha}e^{-x}f(x)\,dx$
+    pub fn integrate<F>(&self, f: F) -> T
+        where F: Fn(T) -> T
+    {
+        self.nodes.iter().zip(self.weights.iter()).fold(T::zero(), |s, (x_i, w_i)| s + *w_i * f(*x_i))
+    }
+
+    /// Approximates the integral against an exponential weight with rate
+    /// $\lambda > 0$
+    /// ```math
+    /// \int_{0}^{\infty}x^{\alpha}e^{-\lambda x}f(x)\,dx = \lambda^{-(\alpha + 1)}\int_{0}^{\infty}x^{\alpha}e^{-x}f(\frac{x}{\lambda})\,dx
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if rate <= 0.0
+    pub fn integrate_exponential<F>(&self, f: F, rate: T) -> T
+        where F: Fn(T) -> T
+    {
+        if rate <= T::zero()
+        {
+            panic!("rate is not positive");
+        }
+
+        self.integrate(|x| f(x / rate)) / rate.pow(self.alpha + T::one())
+    }
+}
@@ -25,10 +25,12 @@ impl<T> GaussLegendre<T>
 
     /// # Arguments
     ///
+    /// * 'n': number of points, the rule is exact for polynomials of degree
+    ///   2n - 1
     ///
     /// # Panics
     ///
-    /// Panics if n < 1 || n > 9
+    /// Panics if n < 1
     ///
     /// # Examples
     /// ```
@@ -48,11 +50,6 @@ impl<T> GaussLegendre<T>
     /// ```
     pub fn new(n: u8) -> GaussLegendre<T>
     {
-        if !(1..=9).contains(&n)
-        {
-            panic!("n is not within the limits");
-        }
-
         GaussLegendre{
             root_weight: RootWeight::new(n)
         }
@@ -4,75 +4,122 @@ use std::vec::Vec;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// Iterator over the roots $x_i$ and weights $\alpha_i$ of the Gauss-Legendre
+/// quadrature with n points, in ascending order of the roots
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug)]
 pub struct RootWeight<T>
 {
     roots: Vec<T>,
     weights: Vec<T>,
-    n: i8,
-    i: i8,
-    k: i8,
+    n: i32,
+    i: i32,
+    k: i32,
 }
 
 impl<T> RootWeight<T>
     where T: Real
 {
-    
+    /// Computes the roots of the Legendre polynomial $P_n$ and the weights
+    /// ```math
+    /// \alpha_i = \frac{2}{(1 - x_i^2)P_n^{'}(x_i)^2} = \frac{2(1 - x_i^2)}{n^2 P_{n-1}(x_i)^2}
+    /// ```
+    /// The roots are found with Newton's method, where $P_n$ and $P_n^{'}$
+    /// are evaluated with the three-term recurrence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1
     pub fn new(n: u8) -> RootWeight<T>
     {
-        let roots = match n
+        if n < 1
         {
-            1 => vec![T::from_f64(0.0)],
-            2 => vec![T::from_f64(0.577350269189626)],
-            3 => vec![T::from_f64(0.0), T::from_f64(0.774596669241483)],
-            4 => vec![T::from_f64(0.339981043584856), T::from_f64(0.861136311594053)],
-            5 => vec![T::from_f64(0.0), T::from_f64(0.538469310105683), T::from_f64(0.906179845938664)],
-            6 => vec![T::from_f64(0.238619186083197), T::from_f64(0.661209386466265), T::from_f64(0.932469514203152)],
-            7 => vec![T::from_f64(0.0), T::from_f64(0.405845151377397), T::from_f64(0.741531185599394), T::from_f64(0.949107912342759)],
-            8 => vec![T::from_f64(0.183434642495650), T::from_f64(0.525532409916329), T::from_f64(0.796666477413627), T::from_f64(0.960289856497536)],
-            9 => vec![T::from_f64(0.0), T::from_f64(0.324253423403809), T::from_f64(0.613371432700590), T::from_f64(0.836031107326636), T::from_f64(0.968160239507626)],
-            _ => panic!("")
-        };
+            panic!("n is not within the limits");
+        }
 
-        let weights = match n
-        {
-            1 => vec![T::from_f64(2.0)],
-            2 => vec![T::from_f64(1.0)],
-            3 => vec![T::from_f64(0.888888888888889), T::from_f64(0.555555555555556)],
-            4 => vec![T::from_f64(0.652145154862546), T::from_f64(0.347854845137454)],
-            5 => vec![T::from_f64(0.568888888888889), T::from_f64(0.478628670499366), T::from_f64(0.236926885056189)],
-            6 => vec![T::from_f64(0.467913934572691), T::from_f64(0.360761573048139), T::from_f64(0.171324492379170)],
-            7 => vec![T::from_f64(0.417959183673469), T::from_f64(0.381830050505119), T::from_f64(0.279705391489277), T::from_f64(0.129484966168870)],
-            8 => vec![T::from_f64(0.362683783378362), T::from_f64(0.313706645877887), T::from_f64(0.222381034453374), T::from_f64(0.101228536290376)],
-            9 => vec![T::from_f64(0.330239355001260), T::from_f64(0.312347077040003), T::from_f64(0.260610696402935), T::from_f64(0.180648160694857), T::from_f64(0.081274388361574)],
-            _ => panic!("")
-        };
+        let k: i32 = (n as i32 + 1) / 2;
 
-        let k: i8 = if n % 2 == 0
+        let mut roots: Vec<T> = Vec::with_capacity(k as usize);
+        let mut weights: Vec<T> = Vec::with_capacity(k as usize);
+
+        for i in (1..=k).rev()
         {
-            n as i8 / 2
+            let (root, weight): (T, T) = if n % 2 == 1 && i == k
+            {
+                (T::zero(), RootWeight::weight(n, T::zero()))
+            }
+            else
+            {
+                RootWeight::newton(n, i)
+            };
+            roots.push(root);
+            weights.push(weight);
         }
-        else
-        {
-            n as i8 / 2 + 1
-        };
+
+        // the weights sum up to the length of the interval [-1, 1]
+        let total: T = weights.iter().fold(T::zero(), |s, w_i| s + *w_i) * T::from_f64(2.0)
+                       - if n % 2 == 1 { weights[0] } else { T::zero() };
+        let scale: T = T::from_f64(2.0) / total;
+        let weights: Vec<T> = weights.into_iter().map(|w_i| w_i * scale).collect();
 
         RootWeight
         {
             roots,
             weights,
-            n: n as i8,
+            n: n as i32,
             i: 1,
             k
         }
     }
 
+    /// Evaluates $P_n(x)$ and $P_{n-1}(x)$
+    fn legendre(n: u8, x: T) -> (T, T)
+    {
+        let mut p_0: T = T::one();
+        let mut p_1: T = x;
+        for j in 2..=n
+        {
+            let j: T = T::from_u8(j);
+            let p_2: T = ((T::from_f64(2.0) * j - T::one()) * x * p_1 - (j - T::one()) * p_0) / j;
+            p_0 = p_1;
+            p_1 = p_2;
+        }
+
+        (p_1, p_0)
+    }
+
+    fn weight(n: u8, x: T) -> T
+    {
+        let (_p_n, p_m): (T, T) = RootWeight::legendre(n, x);
+        let n_p_m: T = T::from_u8(n) * p_m;
+        T::from_f64(2.0) * (T::one() - x * x) / (n_p_m * n_p_m)
+    }
+
+    /// Computes the i-th largest root and its weight
+    fn newton(n: u8, i: i32) -> (T, T)
+    {
+        let mut x: T = (T::pi() * (T::from_f64(i as f64 - 0.25) / T::from_f64(n as f64 + 0.5))).cos();
+
+        for _ in 0..100
+        {
+            let (p_n, p_m): (T, T) = RootWeight::legendre(n, x);
+            let dp: T = T::from_u8(n) * (x * p_n - p_m) / (x * x - T::one());
+            let dx: T = p_n / dp;
+            x -= dx;
+            if dx.abs() <= T::from_f64(f64::EPSILON)
+            {
+                break;
+            }
+        }
+
+        (x, RootWeight::weight(n, x))
+    }
+
     fn idx_n_even(it: &Self) -> usize
     {
         if it.i <= it.k
         {
-            (it.i % it.k) as usize
+            (it.k - it.i) as usize
         }
         else
         {
@@ -82,7 +129,7 @@ impl<T> RootWeight<T>
 
     fn idx_n_odd(it: &Self) -> usize
     {
-        (it.i - it.k).abs() as usize
+        (it.i - it.k).unsigned_abs() as usize
     }
 }
 
@@ -0,0 +1,135 @@
+//! Gauss-Lobatto quadrature
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::golub_welsch::golub_welsch;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Gauss-Lobatto quadrature
+///
+/// The end points of the interval are nodes
+/// ```math
+/// \int_{-1}^{1}f(x)\,dx \approx \frac{2}{n(n - 1)}\left(f(-1) + f(1)\right) + \sum_{i=2}^{n-1}\frac{2}{n(n - 1)P_{n-1}(x_i)^2}f(x_i)
+/// ```
+/// where the interior nodes $x_i$ are the roots of $P_{n-1}^{'}$. The rule is
+/// exact for polynomials of degree 2n - 3.
+///
+/// <https://en.wikipedia.org/wiki/Gaussian_quadrature#Gauss%E2%80%93Lobatto_rules>
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::GaussLobatto;
+///
+/// let gl: GaussLobatto<f64> = GaussLobatto::new(4);
+/// let integral: f64 = gl.integrate(|x| x * x * x * x * x + x * x, 0.0, 1.0);
+///
+/// assert_relative_eq!(integral, 0.5, epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct GaussLobatto<T>
+{
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> GaussLobatto<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes including both end points
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 2
+    pub fn new(n: usize) -> GaussLobatto<T>
+    {
+        if n < 2
+        {
+            panic!("n is not within the limits");
+        }
+
+        // The roots of P'_{n-1} are the roots of the Jacobi polynomial P^{(1, 1)}_{n-2}
+        let m: usize = n - 2;
+        let interior: Vec<T> = if m == 0
+        {
+            Vec::new()
+        }
+        else
+        {
+            let diag: Vec<T> = vec![T::zero(); m];
+            let off_diag: Vec<T> = (1..m).map(|k| {
+                                             let k: f64 = k as f64;
+                                             T::from_f64(k * (k + 2.0) / ((2.0 * k + 1.0) * (2.0 * k + 3.0))).sqrt()
+                                         })
+                                         .collect();
+            golub_welsch(diag, off_diag, T::one()).0
+        };
+
+        let c: T = T::from_f64(2.0 / (n * (n - 1)) as f64);
+
+        let mut nodes: Vec<T> = Vec::with_capacity(n);
+        let mut weights: Vec<T> = Vec::with_capacity(n);
+
+        nodes.push(-T::one());
+        weights.push(c);
+        for x_i in interior
+        {
+            let p: T = GaussLobatto::legendre(n - 1, x_i);
+            nodes.push(x_i);
+            weights.push(c / (p * p));
+        }
+        nodes.push(T::one());
+        weights.push(c);
+
+        GaussLobatto { nodes, weights }
+    }
+
+    /// Evaluates $P_n(x)$ with the three-term recurrence
+    fn legendre(n: usize, x: T) -> T
+    {
+        let mut p_0: T = T::one();
+        let mut p_1: T = x;
+        for j in 2..=n
+        {
+            let j: T = T::from_f64(j as f64);
+            let p_2: T = ((T::from_f64(2.0) * j - T::one()) * x * p_1 - (j - T::one()) * p_0) / j;
+            p_0 = p_1;
+            p_1 = p_2;
+        }
+        p_1
+    }
+
+    pub fn nodes(&self) -> &[T]
+    {
+        &self.nodes
+    }
+
+    pub fn weights(&self) -> &[T]
+    {
+        &self.weights
+    }
+
+    /// Integrate function f from lower bound a to upper bound b
+    ///
+    /// # Arguments
+    /// * a: lower bound of the definite integral
+    /// * b: upper bound of the definite integral
+    pub fn integrate<F>(&self, f: F, a: T, b: T) -> T
+        where F: Fn(T) -> T
+    {
+        let two: T = T::from_f64(2.0);
+        let sum: T = self.nodes.iter().zip(self.weights.iter()).fold(T::zero(), |s, (x_i, w_i)| {
+                                                                  s + *w_i * f((b - a) / two * *x_i + (a + b) / two)
+                                                              });
+
+        (b - a) / two * sum
+    }
+}
@@ -0,0 +1,115 @@
+//! Nodes and weights of Gaussian quadrature rules from the recurrence
+//! coefficients of the orthogonal polynomials
+use crate::algebra::abstr::Real;
+
+/// Maximal number of QL iterations per eigenvalue
+const MAX_ITER: u32 = 60;
+
+/// Golub-Welsch algorithm
+///
+/// The nodes are the eigenvalues of the symmetric tridiagonal Jacobi matrix
+/// ```math
+/// J = \begin{pmatrix} a_0 & b_1 & & \\ b_1 & a_1 & \ddots & \\ & \ddots & \ddots & b_{n-1} \\ & & b_{n-1} & a_{n-1} \end{pmatrix}
+/// ```
+/// and the weights are $w_i = \mu_0 v_{i,0}^2$, where $v_i$ is the normalized
+/// eigenvector of $x_i$ and $\mu_0 = \int w(x)\,dx$.
+/// The eigenvalues are computed with the implicit QL algorithm, only the
+/// first components of the eigenvectors are accumulated.
+///
+/// Golub, G. H., Welsch, J. H.: Calculation of Gauss Quadrature Rules,
+/// Mathematics of Computation, 23, 1969
+///
+/// # Arguments
+///
+/// * 'diag': $a_0, \dots, a_{n-1}$
+/// * 'off_diag': $b_1, \dots, b_{n-1}$
+/// * 'mu_0': integral of the weight function
+///
+/// # Return
+///
+/// Nodes in ascending order and the corresponding weights
+pub(crate) fn golub_welsch<T>(mut diag: Vec<T>, off_diag: Vec<T>, mu_0: T) -> (Vec<T>, Vec<T>)
+    where T: Real
+{
+    let n: usize = diag.len();
+    let mut e: Vec<T> = off_diag;
+    e.push(T::zero());
+
+    let mut z: Vec<T> = vec![T::zero(); n];
+    z[0] = T::one();
+
+    let two: T = T::from_f64(2.0);
+    let eps: T = T::from_f64(f64::EPSILON);
+
+    for l in 0..n
+    {
+        let mut iter: u32 = 0;
+        loop
+        {
+            let mut m: usize = l;
+            while m < n - 1
+            {
+                let dd: T = diag[m].abs() + diag[m + 1].abs();
+                if e[m].abs() <= eps * dd
+                {
+                    break;
+                }
+                m += 1;
+            }
+
+            if m == l || iter == MAX_ITER
+            {
+                break;
+            }
+            iter += 1;
+
+            let mut g: T = (diag[l + 1] - diag[l]) / (two * e[l]);
+            let mut r: T = (g * g + T::one()).sqrt();
+            g = diag[m] - diag[l] + e[l] / if g >= T::zero() { g + r } else { g - r };
+            let mut s: T = T::one();
+            let mut c: T = T::one();
+            let mut p: T = T::zero();
+
+            let mut underflow: bool = false;
+            for i in (l..m).rev()
+            {
+                let f: T = s * e[i];
+                let b: T = c * e[i];
+                r = (f * f + g * g).sqrt();
+                e[i + 1] = r;
+                if r == T::zero()
+                {
+                    diag[i + 1] -= p;
+                    e[m] = T::zero();
+                    underflow = true;
+                    break;
+                }
+                s = f / r;
+                c = g / r;
+                g = diag[i + 1] - p;
+                r = (diag[i] - g) * s + two * c * b;
+                p = s * r;
+                diag[i + 1] = g + p;
+                g = c * r - b;
+
+                let z_i: T = z[i + 1];
+                z[i + 1] = s * z[i] + c * z_i;
+                z[i] = c * z[i] - s * z_i;
+            }
+
+            if underflow
+            {
+                continue;
+            }
+
+            diag[l] -= p;
+            e[l] = g;
+            e[m] = T::zero();
+        }
+    }
+
+    let mut rule: Vec<(T, T)> = diag.into_iter().zip(z).map(|(x_i, z_i)| (x_i, mu_0 * z_i * z_i)).collect();
+    rule.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    rule.into_iter().unzip()
+}
@@ -1,3 +1,24 @@
 
 pub mod newton_cotes;
-pub mod gauss_legendre;
\ No newline at end of file
+pub mod gauss_legendre;
+pub mod gauss_kronrod;
+pub mod simpson;
+pub mod multidimensional;
+
+mod golub_welsch;
+mod gauss_hermite;
+mod gauss_laguerre;
+mod gauss_jacobi;
+mod gauss_chebyshev;
+mod gauss_lobatto;
+mod tanh_sinh;
+
+mod quadrature_result;
+
+pub use quadrature_result::QuadratureResult;
+pub use gauss_hermite::GaussHermite;
+pub use gauss_laguerre::GaussLaguerre;
+pub use gauss_jacobi::GaussJacobi;
+pub use gauss_chebyshev::{ChebyshevKind, GaussChebyshev};
+pub use gauss_lobatto::GaussLobatto;
+pub use tanh_sinh::TanhSinh;
@@ -0,0 +1,262 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::integral::QuadratureResult;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adaptive cubature with the Genz-Malik rule
+///
+/// The degree 7 rule with the embedded degree 5 rule is applied on a
+/// hyper-rectangle with center c and half widths h
+/// ```math
+/// \begin{aligned}
+/// Q_7 &= V\left(w_1 f(c) + w_2\sum_{i}f(c \pm \lambda_2 h_i e_i) + w_3\sum_{i}f(c \pm \lambda_3 h_i e_i) + w_4\sum_{i<j}f(c \pm \lambda_4 h_i e_i \pm \lambda_4 h_j e_j) + w_5\sum f(c \pm \lambda_5 h)\right)\\
+/// E &= |Q_7 - Q_5|
+/// \end{aligned}
+/// ```
+/// where the last sum runs over all $2^d$ corners. The rule needs
+/// $2^d + 2d^2 + 2d + 1$ evaluations. The sub-region with the largest error
+/// estimate is bisected along the coordinate with the largest fourth
+/// difference until the sum of the error estimates satisfies
+/// ```math
+/// \sum_{i} E_{i} \leq \max(\epsilon_{abs}, \epsilon_{rel} |I|)
+/// ```
+/// or the maximal number of evaluations is exceeded.
+///
+/// Genz, A. C., Malik, A. A.: An adaptive algorithm for numerical
+/// integration over an n-dimensional rectangular region, Journal of
+/// Computational and Applied Mathematics, 6, 1980
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::integral::{multidimensional::GenzMalik, QuadratureResult},
+/// };
+///
+/// let gm: GenzMalik<f64> = GenzMalik::new(1.0e-8, 1.0e-8, 100000);
+/// let f = |x: &Vector<f64>| (x[0] + x[1] + x[2]).exp();
+///
+/// let result: QuadratureResult<f64> = gm.integrate(f, &vector![0.0; 0.0; 0.0], &vector![1.0; 1.0; 1.0]);
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(result.value(), (1.0f64.exp() - 1.0).powi(3), epsilon = 1.0e-8);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct GenzMalik<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    max_evaluations: usize,
+}
+
+impl<T> Default for GenzMalik<T>
+    where T: Real
+{
+    fn default() -> GenzMalik<T>
+    {
+        GenzMalik::new(T::from_f64(1.0e-8), T::from_f64(1.0e-8), 1_000_000)
+    }
+}
+
+/// Sub-region of the adaptive cubature
+struct Region<T>
+{
+    center: Vec<T>,
+    half: Vec<T>,
+    value: T,
+    error: T,
+    /// Coordinate along which the region is bisected
+    split: usize,
+}
+
+impl<T> GenzMalik<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'max_evaluations': maximal number of function evaluations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, max_evaluations: usize) -> GenzMalik<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        GenzMalik { abs_tol,
+                    rel_tol,
+                    max_evaluations }
+    }
+
+    /// Integrate function f over the hyper-rectangle $[a, b]$
+    ///
+    /// # Arguments
+    /// * lower: lower bounds a
+    /// * upper: upper bounds b
+    ///
+    /// # Panics
+    ///
+    /// if the dimension is less than two or the dimensions of the bounds
+    /// differ
+    pub fn integrate<F>(&self, f: F, lower: &Vector<T>, upper: &Vector<T>) -> QuadratureResult<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (d, _): (usize, usize) = lower.dim();
+        if d < 2 || upper.dim().0 != d
+        {
+            panic!("The dimension has to be at least two and the dimensions of the bounds have to agree");
+        }
+
+        let two: T = T::from_f64(2.0);
+        let evaluations_per_rule: usize = (1 << d) + 2 * d * d + 2 * d + 1;
+        let tolerance = |value: T| -> T { self.abs_tol.max(self.rel_tol * value.abs()) };
+
+        let center: Vec<T> = (0..d).map(|k| (upper[k] + lower[k]) / two).collect();
+        let half: Vec<T> = (0..d).map(|k| (upper[k] - lower[k]) / two).collect();
+
+        let mut regions: Vec<Region<T>> = vec![GenzMalik::rule(&f, center, half)];
+        let mut evaluations: usize = evaluations_per_rule;
+        let mut value: T = regions[0].value;
+        let mut error: T = regions[0].error;
+
+        while error > tolerance(value) && evaluations + 2 * evaluations_per_rule <= self.max_evaluations
+        {
+            let k: usize = regions.iter()
+                                  .enumerate()
+                                  .fold(0, |m, (k, r)| if r.error > regions[m].error { k } else { m });
+            let region: Region<T> = regions.swap_remove(k);
+
+            let s: usize = region.split;
+            let mut half: Vec<T> = region.half.clone();
+            half[s] /= two;
+            let mut center_1: Vec<T> = region.center.clone();
+            center_1[s] -= half[s];
+            let mut center_2: Vec<T> = region.center.clone();
+            center_2[s] += half[s];
+
+            let region_1: Region<T> = GenzMalik::rule(&f, center_1, half.clone());
+            let region_2: Region<T> = GenzMalik::rule(&f, center_2, half);
+            evaluations += 2 * evaluations_per_rule;
+
+            value += region_1.value + region_2.value - region.value;
+            error += region_1.error + region_2.error - region.error;
+
+            regions.push(region_1);
+            regions.push(region_2);
+        }
+
+        // the sums are recomputed to avoid the accumulation of rounding errors
+        let value: T = regions.iter().fold(T::zero(), |s, r| s + r.value);
+        let error: T = regions.iter().fold(T::zero(), |s, r| s + r.error);
+
+        QuadratureResult::new(value, error, evaluations, error <= tolerance(value))
+    }
+
+    /// Applies the degree 7 and the degree 5 rule on the region
+    fn rule<F>(f: &F, center: Vec<T>, half: Vec<T>) -> Region<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let d: usize = center.len();
+        let n: f64 = d as f64;
+
+        let lambda_2: T = T::from_f64(9.0 / 70.0).sqrt();
+        let lambda_3: T = T::from_f64(9.0 / 10.0).sqrt();
+        let lambda_4: T = T::from_f64(9.0 / 10.0).sqrt();
+        let lambda_5: T = T::from_f64(9.0 / 19.0).sqrt();
+
+        let w_1: T = T::from_f64((12824.0 - 9120.0 * n + 400.0 * n * n) / 19683.0);
+        let w_2: T = T::from_f64(980.0 / 6561.0);
+        let w_3: T = T::from_f64((1820.0 - 400.0 * n) / 19683.0);
+        let w_4: T = T::from_f64(200.0 / 19683.0);
+        let w_5: T = T::from_f64(6859.0 / 19683.0 / 2.0f64.powi(d as i32));
+
+        let v_1: T = T::from_f64((729.0 - 950.0 * n + 50.0 * n * n) / 729.0);
+        let v_2: T = T::from_f64(245.0 / 486.0);
+        let v_3: T = T::from_f64((265.0 - 100.0 * n) / 1458.0);
+        let v_4: T = T::from_f64(25.0 / 729.0);
+
+        let mut x: Vector<T> = Vector::new_column(center.clone());
+        let f_0: T = f(&x);
+
+        let mut sum_2: T = T::zero();
+        let mut sum_3: T = T::zero();
+        let mut split: usize = 0;
+        let mut difference_max: T = -T::one();
+        let ratio: T = lambda_2 * lambda_2 / (lambda_3 * lambda_3);
+
+        for i in 0..d
+        {
+            x[i] = center[i] - lambda_2 * half[i];
+            let f_2: T = f(&x);
+            x[i] = center[i] + lambda_2 * half[i];
+            let f_2: T = f_2 + f(&x);
+            x[i] = center[i] - lambda_3 * half[i];
+            let f_3: T = f(&x);
+            x[i] = center[i] + lambda_3 * half[i];
+            let f_3: T = f_3 + f(&x);
+            x[i] = center[i];
+
+            sum_2 += f_2;
+            sum_3 += f_3;
+
+            // fourth difference
+            let two_f_0: T = f_0 + f_0;
+            let difference: T = (f_2 - two_f_0 - ratio * (f_3 - two_f_0)).abs();
+            if difference > difference_max
+            {
+                difference_max = difference;
+                split = i;
+            }
+        }
+
+        let mut sum_4: T = T::zero();
+        for i in 0..d
+        {
+            for j in (i + 1)..d
+            {
+                for (s_i, s_j) in [(-T::one(), -T::one()), (-T::one(), T::one()), (T::one(), -T::one()), (T::one(), T::one())]
+                {
+                    x[i] = center[i] + s_i * lambda_4 * half[i];
+                    x[j] = center[j] + s_j * lambda_4 * half[j];
+                    sum_4 += f(&x);
+                }
+                x[j] = center[j];
+            }
+            x[i] = center[i];
+        }
+
+        let mut sum_5: T = T::zero();
+        for corner in 0..(1usize << d)
+        {
+            for (k, x_k) in center.iter().enumerate()
+            {
+                let sign: T = if corner & (1 << k) == 0 { -T::one() } else { T::one() };
+                x[k] = *x_k + sign * lambda_5 * half[k];
+            }
+            sum_5 += f(&x);
+        }
+
+        let volume: T = half.iter().fold(T::one(), |v, h_k| v * (*h_k + *h_k));
+        let value_7: T = volume * (w_1 * f_0 + w_2 * sum_2 + w_3 * sum_3 + w_4 * sum_4 + w_5 * sum_5);
+        let value_5: T = volume * (v_1 * f_0 + v_2 * sum_2 + v_3 * sum_3 + v_4 * sum_4);
+
+        Region { center,
+                 half,
+                 value: value_7,
+                 error: (value_7 - value_5).abs(),
+                 split }
+    }
+}
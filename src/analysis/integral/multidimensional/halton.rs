@@ -0,0 +1,99 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use std::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Halton sequence
+///
+/// The k-th coordinate of the i-th point is the radical inverse of i in the
+/// base of the k-th prime number
+/// ```math
+/// i = \sum_{j}d_j p_k^j \Rightarrow x_{i, k} = \sum_{j}d_j p_k^{-j - 1}
+/// ```
+/// The point with index 0 is skipped.
+///
+/// Halton, J. H.: On the efficiency of certain quasi-random sequences of
+/// points in evaluating multi-dimensional integrals, Numerische Mathematik,
+/// 2, 1960
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Vector, analysis::integral::multidimensional::Halton};
+///
+/// let mut halton: Halton<f64> = Halton::new(2);
+///
+/// assert_eq!(halton.next(), Some(vector![0.5; 1.0 / 3.0]));
+/// assert_eq!(halton.next(), Some(vector![0.25; 2.0 / 3.0]));
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Halton<T>
+{
+    bases: Vec<u64>,
+    index: u64,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Halton<T>
+    where T: Real
+{
+    /// # Panics
+    ///
+    /// if dim == 0
+    pub fn new(dim: usize) -> Halton<T>
+    {
+        if dim == 0
+        {
+            panic!("The dimension has to be positive");
+        }
+
+        let mut bases: Vec<u64> = Vec::with_capacity(dim);
+        let mut candidate: u64 = 2;
+        while bases.len() < dim
+        {
+            if bases.iter().take_while(|p| **p * **p <= candidate).all(|p| !candidate.is_multiple_of(*p))
+            {
+                bases.push(candidate);
+            }
+            candidate += 1;
+        }
+
+        Halton { bases,
+                 index: 0,
+                 phantom: PhantomData }
+    }
+
+    fn radical_inverse(mut i: u64, base: u64) -> T
+    {
+        let inv_base: f64 = 1.0 / base as f64;
+        let mut factor: f64 = inv_base;
+        let mut x: f64 = 0.0;
+        while i > 0
+        {
+            x += (i % base) as f64 * factor;
+            i /= base;
+            factor *= inv_base;
+        }
+        T::from_f64(x)
+    }
+}
+
+impl<T> Iterator for Halton<T>
+    where T: Real
+{
+    type Item = Vector<T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        self.index += 1;
+        let index: u64 = self.index;
+        Some(Vector::new_column(self.bases.iter().map(|b| Halton::radical_inverse(index, *b)).collect()))
+    }
+}
@@ -0,0 +1,24 @@
+//! Integration over hyper-rectangles
+//!
+//! | Method | Error estimate | Dimension |
+//! |--------|----------------|-----------|
+//! | [`TensorGauss`] | none | low |
+//! | [`GenzMalik`] | embedded rule | 2 - 10 |
+//! | [`MonteCarlo`] | sample variance | any |
+//! | [`QuasiMonteCarlo`] | randomly shifted replicates | any |
+//! | [`Vegas`] | variance of the iterations | any |
+mod tensor_gauss;
+mod genz_malik;
+mod monte_carlo;
+mod quasi_monte_carlo;
+mod halton;
+mod sobol;
+mod vegas;
+
+pub use tensor_gauss::TensorGauss;
+pub use genz_malik::GenzMalik;
+pub use monte_carlo::MonteCarlo;
+pub use quasi_monte_carlo::{LowDiscrepancySequence, QuasiMonteCarlo};
+pub use halton::Halton;
+pub use sobol::Sobol;
+pub use vegas::Vegas;
@@ -0,0 +1,159 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::integral::QuadratureResult;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of samples, which are drawn before the error estimate is checked
+const BATCH: usize = 1000;
+
+/// Plain Monte Carlo integration
+///
+/// ```math
+/// \int_{[a, b]}f(x)\,dx \approx \frac{V}{N}\sum_{i=1}^{N}f(x_i), \quad E = V\sqrt{\frac{\sigma^2}{N}}
+/// ```
+/// where $x_i$ are uniformly distributed in the hyper-rectangle with volume V
+/// and $\sigma^2$ is the sample variance of $f(x_i)$. Samples are drawn in
+/// batches until the error estimate, which is one standard deviation of the
+/// estimator, satisfies the tolerance or the maximal number of samples is
+/// reached.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::integral::{multidimensional::MonteCarlo, QuadratureResult},
+/// };
+///
+/// let mut mc: MonteCarlo<f64> = MonteCarlo::new(1.0e-3, 0.0, 1_000_000);
+/// mc.set_seed(2);
+/// let f = |x: &Vector<f64>| x[0] * x[1];
+///
+/// let result: QuadratureResult<f64> = mc.integrate(f, &vector![0.0; 0.0], &vector![1.0; 1.0]);
+///
+/// assert_relative_eq!(result.value(), 0.25, epsilon = 5.0 * result.error());
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct MonteCarlo<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    max_samples: usize,
+    seed: Option<u64>,
+}
+
+impl<T> Default for MonteCarlo<T>
+    where T: Real
+{
+    fn default() -> MonteCarlo<T>
+    {
+        MonteCarlo::new(T::from_f64(1.0e-3), T::from_f64(1.0e-3), 10_000_000)
+    }
+}
+
+impl<T> MonteCarlo<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'max_samples': maximal number of samples
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, max_samples: usize) -> MonteCarlo<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        MonteCarlo { abs_tol,
+                     rel_tol,
+                     max_samples,
+                     seed: None }
+    }
+
+    /// Sets the seed of the random number generator, which makes the
+    /// integration reproducible
+    pub fn set_seed(&mut self, seed: u64)
+    {
+        self.seed = Some(seed);
+    }
+
+    /// Integrate function f over the hyper-rectangle $[a, b]$
+    ///
+    /// # Arguments
+    /// * lower: lower bounds a
+    /// * upper: upper bounds b
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the bounds differ
+    pub fn integrate<F>(&self, f: F, lower: &Vector<T>, upper: &Vector<T>) -> QuadratureResult<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (d, _): (usize, usize) = lower.dim();
+        if upper.dim().0 != d
+        {
+            panic!("The dimensions of the bounds differ");
+        }
+
+        let mut rng: StdRng = match self.seed
+        {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let volume: T = (0..d).fold(T::one(), |v, k| v * (upper[k] - lower[k]));
+        let tolerance = |value: T| -> T { self.abs_tol.max(self.rel_tol * value.abs()) };
+
+        let mut x: Vector<T> = Vector::zero(d);
+        // Welford's algorithm
+        let mut mean: T = T::zero();
+        let mut m_2: T = T::zero();
+        let mut n: usize = 0;
+
+        loop
+        {
+            for _ in 0..BATCH.min(self.max_samples - n)
+            {
+                for k in 0..d
+                {
+                    x[k] = lower[k] + (upper[k] - lower[k]) * T::from_f64(rng.gen::<f64>());
+                }
+                let f_x: T = f(&x);
+
+                n += 1;
+                let delta: T = f_x - mean;
+                mean += delta / T::from_f64(n as f64);
+                m_2 += delta * (f_x - mean);
+            }
+
+            let value: T = volume * mean;
+            let error: T = if n > 1
+            {
+                volume.abs() * (m_2 / T::from_f64(((n - 1) * n) as f64)).sqrt()
+            }
+            else
+            {
+                T::infinity()
+            };
+
+            if error <= tolerance(value) || n >= self.max_samples
+            {
+                return QuadratureResult::new(value, error, n, error <= tolerance(value));
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::integral::{
+    multidimensional::{Halton, Sobol},
+    QuadratureResult,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of points per replicate in the first round
+const INITIAL_POINTS: usize = 128;
+
+/// Low discrepancy sequence of the quasi-Monte Carlo integration
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LowDiscrepancySequence
+{
+    Halton,
+    /// Supports up to `Sobol::MAX_DIM` dimensions
+    #[default]
+    Sobol,
+}
+
+/// Randomized quasi-Monte Carlo integration
+///
+/// The integrand is evaluated at the points $u_i$ of a low discrepancy
+/// sequence, which are randomly shifted modulo one (Cranley-Patterson
+/// rotation)
+/// ```math
+/// Q_r = \frac{V}{N}\sum_{i=1}^{N}f(a + (b - a) \circ \{u_i + \Delta_r\}), \quad r = 1, \dots, R
+/// ```
+/// The integral is approximated by the mean of the R replicates $Q_r$, its
+/// error is estimated by the standard deviation of the mean. The number of
+/// points N is doubled until the error estimate satisfies the tolerance or
+/// the maximal number of evaluations $R N$ is reached. For smooth
+/// integrands, the error decreases almost like $N^{-1}$ instead of the
+/// $N^{-1/2}$ of plain Monte Carlo.
+///
+/// Owen, A. B.: Monte Carlo theory, methods and examples, Chapter 17, 2013
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::integral::{multidimensional::QuasiMonteCarlo, QuadratureResult},
+/// };
+///
+/// let mut qmc: QuasiMonteCarlo<f64> = QuasiMonteCarlo::new(1.0e-5, 0.0, 1_000_000);
+/// qmc.set_seed(1);
+/// let f = |x: &Vector<f64>| (x[0] + x[1] + x[2] + x[3]).cos();
+///
+/// let result: QuadratureResult<f64> = qmc.integrate(f, &Vector::zero(4), &vector![1.0; 1.0; 1.0; 1.0]);
+///
+/// assert!(result.is_converged());
+/// // \int_{[0, 1]^4} cos(x_1 + x_2 + x_3 + x_4) dx = 16 sin^4(1/2) cos(2)
+/// assert_relative_eq!(result.value(), 16.0 * 0.5f64.sin().powi(4) * 2.0f64.cos(), epsilon = 1.0e-4);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct QuasiMonteCarlo<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    max_evaluations: usize,
+    sequence: LowDiscrepancySequence,
+    replicates: usize,
+    seed: Option<u64>,
+}
+
+impl<T> Default for QuasiMonteCarlo<T>
+    where T: Real
+{
+    fn default() -> QuasiMonteCarlo<T>
+    {
+        QuasiMonteCarlo::new(T::from_f64(1.0e-6), T::from_f64(1.0e-6), 10_000_000)
+    }
+}
+
+impl<T> QuasiMonteCarlo<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'max_evaluations': maximal number of function evaluations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, max_evaluations: usize) -> QuasiMonteCarlo<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        QuasiMonteCarlo { abs_tol,
+                          rel_tol,
+                          max_evaluations,
+                          sequence: LowDiscrepancySequence::default(),
+                          replicates: 8,
+                          seed: None }
+    }
+
+    pub fn set_sequence(&mut self, sequence: LowDiscrepancySequence)
+    {
+        self.sequence = sequence;
+    }
+
+    pub fn get_sequence(&self) -> LowDiscrepancySequence
+    {
+        self.sequence
+    }
+
+    /// Sets the number of randomly shifted replicates, defaults to 8
+    ///
+    /// # Panics
+    ///
+    /// if replicates < 2
+    pub fn set_replicates(&mut self, replicates: usize)
+    {
+        if replicates < 2
+        {
+            panic!("At least two replicates are required");
+        }
+        self.replicates = replicates;
+    }
+
+    /// Sets the seed of the random shifts, which makes the integration
+    /// reproducible
+    pub fn set_seed(&mut self, seed: u64)
+    {
+        self.seed = Some(seed);
+    }
+
+    /// Integrate function f over the hyper-rectangle $[a, b]$
+    ///
+    /// # Arguments
+    /// * lower: lower bounds a
+    /// * upper: upper bounds b
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the bounds differ or the dimension is not
+    /// supported by the sequence
+    pub fn integrate<F>(&self, f: F, lower: &Vector<T>, upper: &Vector<T>) -> QuadratureResult<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (d, _): (usize, usize) = lower.dim();
+        if upper.dim().0 != d
+        {
+            panic!("The dimensions of the bounds differ");
+        }
+
+        let mut points: Box<dyn Iterator<Item = Vector<T>>> = match self.sequence
+        {
+            LowDiscrepancySequence::Halton => Box::new(Halton::new(d)),
+            LowDiscrepancySequence::Sobol => Box::new(Sobol::new(d)),
+        };
+
+        let mut rng: StdRng = match self.seed
+        {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let shifts: Vec<Vec<T>> = (0..self.replicates).map(|_| (0..d).map(|_| T::from_f64(rng.gen::<f64>())).collect())
+                                                      .collect();
+
+        let volume: T = (0..d).fold(T::one(), |v, k| v * (upper[k] - lower[k]));
+        let tolerance = |value: T| -> T { self.abs_tol.max(self.rel_tol * value.abs()) };
+        let r: T = T::from_f64(self.replicates as f64);
+
+        let mut sums: Vec<T> = vec![T::zero(); self.replicates];
+        let mut n: usize = 0;
+        let mut n_next: usize = INITIAL_POINTS;
+        let mut x: Vector<T> = Vector::zero(d);
+
+        loop
+        {
+            for u in points.by_ref().take(n_next - n)
+            {
+                for (sum, shift) in sums.iter_mut().zip(shifts.iter())
+                {
+                    for k in 0..d
+                    {
+                        let mut u_k: T = u[k] + shift[k];
+                        if u_k >= T::one()
+                        {
+                            u_k -= T::one();
+                        }
+                        x[k] = lower[k] + (upper[k] - lower[k]) * u_k;
+                    }
+                    *sum += f(&x);
+                }
+            }
+            n = n_next;
+
+            let estimates: Vec<T> = sums.iter().map(|s| volume * *s / T::from_f64(n as f64)).collect();
+            let value: T = estimates.iter().fold(T::zero(), |s, q| s + *q) / r;
+            let variance: T = estimates.iter().fold(T::zero(), |s, q| s + (*q - value) * (*q - value)) / (r - T::one());
+            let error: T = (variance / r).sqrt();
+
+            let evaluations: usize = n * self.replicates;
+            if error <= tolerance(value) || 2 * evaluations > self.max_evaluations
+            {
+                return QuadratureResult::new(value, error, evaluations, error <= tolerance(value));
+            }
+            n_next = 2 * n;
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use std::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of bits of the direction numbers
+const BITS: usize = 32;
+
+/// Degree s, coefficients a and initial direction numbers m of the primitive
+/// polynomials for the dimensions 2 to 16
+const DIRECTIONS: [(usize, u32, [u32; 6]); 15] = [(1, 0, [1, 0, 0, 0, 0, 0]),
+                                                  (2, 1, [1, 3, 0, 0, 0, 0]),
+                                                  (3, 1, [1, 3, 1, 0, 0, 0]),
+                                                  (3, 2, [1, 1, 1, 0, 0, 0]),
+                                                  (4, 1, [1, 1, 3, 3, 0, 0]),
+                                                  (4, 4, [1, 3, 5, 13, 0, 0]),
+                                                  (5, 2, [1, 1, 5, 5, 17, 0]),
+                                                  (5, 4, [1, 1, 5, 5, 5, 0]),
+                                                  (5, 7, [1, 1, 7, 11, 19, 0]),
+                                                  (5, 11, [1, 1, 5, 1, 1, 0]),
+                                                  (5, 13, [1, 1, 1, 3, 11, 0]),
+                                                  (5, 14, [1, 3, 5, 5, 31, 0]),
+                                                  (6, 1, [1, 3, 3, 9, 7, 49]),
+                                                  (6, 13, [1, 1, 1, 15, 21, 21]),
+                                                  (6, 16, [1, 3, 1, 13, 27, 49])];
+
+/// Sobol sequence
+///
+/// The points are generated in Gray code order with the direction numbers of
+/// Joe and Kuo. The point with index 0 is skipped. The first $2^m$ points
+/// of the sequence are well distributed in the unit cube, the number of
+/// points should therefore be a power of two.
+///
+/// Joe, S., Kuo, F. Y.: Constructing Sobol sequences with better
+/// two-dimensional projections, SIAM Journal on Scientific Computing, 30,
+/// 2008
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Vector, analysis::integral::multidimensional::Sobol};
+///
+/// let mut sobol: Sobol<f64> = Sobol::new(2);
+///
+/// assert_eq!(sobol.next(), Some(vector![0.5; 0.5]));
+/// assert_eq!(sobol.next(), Some(vector![0.75; 0.25]));
+/// assert_eq!(sobol.next(), Some(vector![0.25; 0.75]));
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Sobol<T>
+{
+    /// Direction numbers v_{k, j}
+    directions: Vec<Vec<u32>>,
+    state: Vec<u32>,
+    index: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Sobol<T>
+    where T: Real
+{
+    /// Maximal dimension
+    pub const MAX_DIM: usize = DIRECTIONS.len() + 1;
+
+    /// # Panics
+    ///
+    /// if dim == 0 or dim > Sobol::MAX_DIM
+    pub fn new(dim: usize) -> Sobol<T>
+    {
+        if dim == 0 || dim > Self::MAX_DIM
+        {
+            panic!("The dimension is not within the limits");
+        }
+
+        let mut directions: Vec<Vec<u32>> = Vec::with_capacity(dim);
+        directions.push((1..=BITS).map(|j| 1u32 << (BITS - j)).collect());
+
+        for (s, a, m) in DIRECTIONS.iter().take(dim - 1)
+        {
+            let s: usize = *s;
+            let mut v: Vec<u32> = Vec::with_capacity(BITS);
+            for (j, m_j) in m.iter().take(s).enumerate()
+            {
+                v.push(*m_j << (BITS - 1 - j));
+            }
+            for j in s..BITS
+            {
+                let mut v_j: u32 = v[j - s] ^ (v[j - s] >> s);
+                for k in 1..s
+                {
+                    if (a >> (s - 1 - k)) & 1 == 1
+                    {
+                        v_j ^= v[j - k];
+                    }
+                }
+                v.push(v_j);
+            }
+            directions.push(v);
+        }
+
+        Sobol { directions,
+                state: vec![0; dim],
+                index: 0,
+                phantom: PhantomData }
+    }
+}
+
+impl<T> Iterator for Sobol<T>
+    where T: Real
+{
+    type Item = Vector<T>;
+
+    /// Returns None after $2^{32} - 1$ points
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.index == u32::MAX
+        {
+            return None;
+        }
+
+        // index of the rightmost zero bit
+        let c: usize = self.index.trailing_ones() as usize;
+        self.index += 1;
+
+        let scale: f64 = 2.0f64.powi(-(BITS as i32));
+        Some(Vector::new_column(self.state
+                                    .iter_mut()
+                                    .zip(self.directions.iter())
+                                    .map(|(x_k, v_k)| {
+                                        *x_k ^= v_k[c];
+                                        T::from_f64(*x_k as f64 * scale)
+                                    })
+                                    .collect()))
+    }
+}
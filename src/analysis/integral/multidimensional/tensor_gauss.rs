@@ -0,0 +1,114 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::integral::gauss_legendre::RootWeight;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Tensor product Gauss-Legendre rule
+///
+/// ```math
+/// \int_{a_1}^{b_1}\cdots\int_{a_d}^{b_d}f(x)\,dx \approx \prod_{k=1}^{d}\frac{b_k - a_k}{2}\sum_{i_1=1}^{n}\cdots\sum_{i_d=1}^{n}\alpha_{i_1}\cdots\alpha_{i_d}f(x_{i_1}, \dots, x_{i_d})
+/// ```
+/// The rule is exact for polynomials of degree 2n - 1 in each coordinate.
+/// The number of evaluations $n^d$ grows exponentially with the dimension.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Vector, analysis::integral::multidimensional::TensorGauss};
+///
+/// let tg: TensorGauss<f64> = TensorGauss::new(3);
+/// let f = |x: &Vector<f64>| x[0] * x[0] * x[1];
+///
+/// let integral: f64 = tg.integrate(f, &vector![0.0; 0.0], &vector![1.0; 2.0]);
+///
+/// assert_relative_eq!(integral, 2.0 / 3.0, epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct TensorGauss<T>
+{
+    nodes: Vec<T>,
+    weights: Vec<T>,
+}
+
+impl<T> TensorGauss<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'n': number of nodes per coordinate
+    ///
+    /// # Panics
+    ///
+    /// Panics if n < 1
+    pub fn new(n: u8) -> TensorGauss<T>
+    {
+        let (nodes, weights): (Vec<T>, Vec<T>) = RootWeight::<T>::new(n).unzip();
+
+        TensorGauss { nodes, weights }
+    }
+
+    /// Integrate function f over the hyper-rectangle $[a, b]$
+    ///
+    /// # Arguments
+    /// * lower: lower bounds a
+    /// * upper: upper bounds b
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the bounds differ
+    pub fn integrate<F>(&self, f: F, lower: &Vector<T>, upper: &Vector<T>) -> T
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (d, _): (usize, usize) = lower.dim();
+        if upper.dim().0 != d
+        {
+            panic!("The dimensions of the bounds differ");
+        }
+
+        let two: T = T::from_f64(2.0);
+        let half: Vec<T> = (0..d).map(|k| (upper[k] - lower[k]) / two).collect();
+        let center: Vec<T> = (0..d).map(|k| (upper[k] + lower[k]) / two).collect();
+
+        let n: usize = self.nodes.len();
+        let mut index: Vec<usize> = vec![0; d];
+        let mut x: Vector<T> = Vector::zero(d);
+        let mut sum: T = T::zero();
+
+        loop
+        {
+            let mut weight: T = T::one();
+            for k in 0..d
+            {
+                x[k] = center[k] + half[k] * self.nodes[index[k]];
+                weight *= self.weights[index[k]];
+            }
+            sum += weight * f(&x);
+
+            // next multi index
+            let mut k: usize = 0;
+            while k < d
+            {
+                index[k] += 1;
+                if index[k] < n
+                {
+                    break;
+                }
+                index[k] = 0;
+                k += 1;
+            }
+            if k == d
+            {
+                break;
+            }
+        }
+
+        half.iter().fold(sum, |s, h_k| s * *h_k)
+    }
+}
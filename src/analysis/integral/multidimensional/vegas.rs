@@ -0,0 +1,277 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::integral::QuadratureResult;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Number of bins of the grid in each coordinate
+const BINS: usize = 50;
+/// Damping of the grid refinement
+const ALPHA: f64 = 1.5;
+
+/// VEGAS adaptive Monte Carlo integration
+///
+/// The samples are drawn from a separable density, which is represented by
+/// a grid with equally probable bins of different widths in each
+/// coordinate. After every iteration, the bins are refined such that they
+/// contain equal parts of $\sum f^2$, i.e. the density approaches
+/// $|f|$ and the variance of the estimator is reduced. The estimates $I_k$
+/// of the iterations after the warm up are combined with weights
+/// $\sigma_k^{-2}$
+/// ```math
+/// I = \sigma^2\sum_{k}\frac{I_k}{\sigma_k^2}, \quad \sigma^{-2} = \sum_{k}\sigma_k^{-2}
+/// ```
+/// The iteration stops if $\sigma$ satisfies the tolerance or the maximal
+/// number of iterations is reached.
+///
+/// Lepage, G. P.: A new algorithm for adaptive multidimensional
+/// integration, Journal of Computational Physics, 27, 1978
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::integral::{multidimensional::Vegas, QuadratureResult},
+/// };
+///
+/// let mut vegas: Vegas<f64> = Vegas::new(1.0e-3, 1.0e-3, 10000, 20);
+/// vegas.set_seed(3);
+///
+/// // Gaussian peak in the corner of the unit cube
+/// let f = |x: &Vector<f64>| {
+///     let r_2: f64 = (0..3).map(|k| x[k] * x[k]).sum();
+///     (-r_2 / 0.02).exp() / (0.02 * std::f64::consts::PI).powf(1.5) * 8.0
+/// };
+///
+/// let result: QuadratureResult<f64> = vegas.integrate(f, &Vector::zero(3), &vector![1.0; 1.0; 1.0]);
+///
+/// assert_relative_eq!(result.value(), 1.0, epsilon = 5.0 * result.error());
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Vegas<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    samples: usize,
+    max_iterations: usize,
+    warm_up: usize,
+    seed: Option<u64>,
+}
+
+impl<T> Default for Vegas<T>
+    where T: Real
+{
+    fn default() -> Vegas<T>
+    {
+        Vegas::new(T::from_f64(1.0e-3), T::from_f64(1.0e-3), 10000, 50)
+    }
+}
+
+impl<T> Vegas<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'samples': number of samples per iteration
+    /// * 'max_iterations': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative, both tolerances are zero or samples < 2
+    pub fn new(abs_tol: T, rel_tol: T, samples: usize, max_iterations: usize) -> Vegas<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+        if samples < 2
+        {
+            panic!("At least two samples per iteration are required");
+        }
+
+        Vegas { abs_tol,
+                rel_tol,
+                samples,
+                max_iterations,
+                warm_up: 5,
+                seed: None }
+    }
+
+    /// Sets the number of iterations, which only adapt the grid and are
+    /// not used for the estimate, defaults to 5
+    pub fn set_warm_up(&mut self, warm_up: usize)
+    {
+        self.warm_up = warm_up;
+    }
+
+    /// Sets the seed of the random number generator, which makes the
+    /// integration reproducible
+    pub fn set_seed(&mut self, seed: u64)
+    {
+        self.seed = Some(seed);
+    }
+
+    /// Integrate function f over the hyper-rectangle $[a, b]$
+    ///
+    /// # Arguments
+    /// * lower: lower bounds a
+    /// * upper: upper bounds b
+    ///
+    /// # Panics
+    ///
+    /// if the dimensions of the bounds differ
+    pub fn integrate<F>(&self, f: F, lower: &Vector<T>, upper: &Vector<T>) -> QuadratureResult<T>
+        where F: Fn(&Vector<T>) -> T
+    {
+        let (d, _): (usize, usize) = lower.dim();
+        if upper.dim().0 != d
+        {
+            panic!("The dimensions of the bounds differ");
+        }
+
+        let mut rng: StdRng = match self.seed
+        {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let volume: T = (0..d).fold(T::one(), |v, k| v * (upper[k] - lower[k]));
+        let tolerance = |value: T| -> T { self.abs_tol.max(self.rel_tol * value.abs()) };
+        let n: T = T::from_f64(self.samples as f64);
+        let bins: T = T::from_f64(BINS as f64);
+
+        // edges of the bins in the unit cube
+        let mut grid: Vec<Vec<T>> = vec![(0..=BINS).map(|i| T::from_f64(i as f64 / BINS as f64)).collect(); d];
+
+        let mut x: Vector<T> = Vector::zero(d);
+        let mut bin: Vec<usize> = vec![0; d];
+        let mut weight_sum: T = T::zero();
+        let mut weighted_value_sum: T = T::zero();
+        let mut value: T = T::zero();
+        let mut error: T = T::infinity();
+        let mut evaluations: usize = 0;
+
+        for iteration in 0..self.max_iterations
+        {
+            let mut sum: T = T::zero();
+            let mut sum_2: T = T::zero();
+            let mut distribution: Vec<Vec<T>> = vec![vec![T::zero(); BINS]; d];
+
+            for _ in 0..self.samples
+            {
+                let mut jacobian: T = volume;
+                for k in 0..d
+                {
+                    let y: f64 = rng.gen::<f64>() * BINS as f64;
+                    let i: usize = (y as usize).min(BINS - 1);
+                    let width: T = grid[k][i + 1] - grid[k][i];
+                    let u: T = grid[k][i] + T::from_f64(y - i as f64) * width;
+
+                    x[k] = lower[k] + (upper[k] - lower[k]) * u;
+                    jacobian *= bins * width;
+                    bin[k] = i;
+                }
+
+                let f_x: T = jacobian * f(&x);
+                sum += f_x;
+                sum_2 += f_x * f_x;
+                for k in 0..d
+                {
+                    distribution[k][bin[k]] += f_x * f_x;
+                }
+            }
+            evaluations += self.samples;
+
+            let mean: T = sum / n;
+            // the variance is bounded by the rounding error of the mean
+            let eps: T = T::from_f64(f64::EPSILON);
+            let variance: T = ((sum_2 / n - mean * mean) / (n - T::one())).max(eps * eps * mean * mean)
+                                                                          .max(T::from_f64(f64::MIN_POSITIVE));
+
+            for (edges, d_k) in grid.iter_mut().zip(distribution.iter())
+            {
+                Vegas::refine(edges, d_k);
+            }
+
+            if iteration < self.warm_up && iteration + 1 < self.max_iterations
+            {
+                continue;
+            }
+
+            weight_sum += T::one() / variance;
+            weighted_value_sum += mean / variance;
+            value = weighted_value_sum / weight_sum;
+            error = (T::one() / weight_sum).sqrt();
+
+            if error <= tolerance(value)
+            {
+                return QuadratureResult::new(value, error, evaluations, true);
+            }
+        }
+
+        QuadratureResult::new(value, error, evaluations, false)
+    }
+
+    /// Moves the edges such that each bin contains the same part of the
+    /// damped distribution
+    fn refine(edges: &mut [T], distribution: &[T])
+    {
+        // smoothing
+        let m: usize = distribution.len();
+        let smoothed: Vec<T> = (0..m).map(|i| {
+                                         let lo: usize = i.saturating_sub(1);
+                                         let hi: usize = (i + 1).min(m - 1);
+                                         distribution[lo..=hi].iter().fold(T::zero(), |s, d_i| s + *d_i)
+                                         / T::from_f64((hi - lo + 1) as f64)
+                                     })
+                                     .collect();
+
+        let total: T = smoothed.iter().fold(T::zero(), |s, d_i| s + *d_i);
+        if total <= T::zero()
+        {
+            return;
+        }
+
+        let alpha: T = T::from_f64(ALPHA);
+        let r: Vec<T> = smoothed.iter()
+                                .map(|d_i| {
+                                    let x: T = *d_i / total;
+                                    if x <= T::zero() || x >= T::one()
+                                    {
+                                        x
+                                    }
+                                    else
+                                    {
+                                        ((T::one() - x) / -x.ln()).pow(alpha)
+                                    }
+                                })
+                                .collect();
+
+        let delta: T = r.iter().fold(T::zero(), |s, r_i| s + *r_i) / T::from_f64(m as f64);
+        let old: Vec<T> = edges.to_vec();
+
+        let mut j: usize = 0;
+        let mut accumulated: T = T::zero();
+        for (k, edge) in edges.iter_mut().enumerate().take(m).skip(1)
+        {
+            let target: T = delta * T::from_f64(k as f64);
+            while j < m - 1 && accumulated + r[j] < target
+            {
+                accumulated += r[j];
+                j += 1;
+            }
+            let fraction: T = if r[j] > T::zero() { ((target - accumulated) / r[j]).min(T::one()) } else { T::zero() };
+            *edge = old[j] + fraction * (old[j + 1] - old[j]);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Result of an adaptive quadrature
+///
+/// Contains the approximation of the integral, an estimate of the absolute
+/// error, the number of function evaluations and whether the requested
+/// tolerance has been reached.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct QuadratureResult<T>
+{
+    value: T,
+    error: T,
+    evaluations: usize,
+    converged: bool,
+}
+
+impl<T> QuadratureResult<T>
+    where T: Real
+{
+    pub fn new(value: T, error: T, evaluations: usize, converged: bool) -> QuadratureResult<T>
+    {
+        QuadratureResult { value,
+                           error,
+                           evaluations,
+                           converged }
+    }
+
+    /// Approximation of the integral
+    pub fn value(&self) -> T
+    {
+        self.value
+    }
+
+    /// Estimate of the absolute error
+    pub fn error(&self) -> T
+    {
+        self.error
+    }
+
+    /// Number of evaluations of the integrand
+    pub fn evaluations(&self) -> usize
+    {
+        self.evaluations
+    }
+
+    /// Returns true if the error estimate satisfies the requested tolerance
+    pub fn is_converged(&self) -> bool
+    {
+        self.converged
+    }
+}
@@ -0,0 +1,139 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::QuadratureResult;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Adaptive Simpson quadrature
+///
+/// Simpson's rule $S(a, b)$ is compared with the composite rule
+/// $S(a, m) + S(m, b)$ on the two halves of the interval. The interval is
+/// bisected recursively until
+/// ```math
+/// |S(a, m) + S(m, b) - S(a, b)| \leq 15 \epsilon
+/// ```
+/// where the tolerance $\epsilon$ is halved with each bisection. The result
+/// is improved by Richardson extrapolation.
+///
+/// Lyness, J. N.: Notes on the Adaptive Simpson Quadrature Routine, Journal
+/// of the ACM, 16, 1969
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::{simpson::AdaptiveSimpson, QuadratureResult};
+///
+/// let simpson: AdaptiveSimpson<f64> = AdaptiveSimpson::new(1.0e-10, 50);
+/// let result: QuadratureResult<f64> = simpson.integrate(|x: f64| x.sin(), 0.0, std::f64::consts::PI);
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(2.0, result.value(), epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct AdaptiveSimpson<T>
+{
+    tol: T,
+    max_depth: u32,
+}
+
+/// Evaluations of the integrand at the end points and the center of an
+/// interval
+struct Panel<T>
+{
+    a: T,
+    b: T,
+    f_a: T,
+    f_m: T,
+    f_b: T,
+    simpson: T,
+}
+
+impl<T> AdaptiveSimpson<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'tol': absolute tolerance
+    /// * 'max_depth': maximal number of bisections of an interval
+    ///
+    /// # Panics
+    ///
+    /// if tol <= 0
+    pub fn new(tol: T, max_depth: u32) -> AdaptiveSimpson<T>
+    {
+        if tol <= T::zero()
+        {
+            panic!("The tolerance has to be positive");
+        }
+
+        AdaptiveSimpson { tol, max_depth }
+    }
+
+    /// Integrate function f from lower bound a to upper bound b
+    ///
+    /// # Arguments
+    /// * a: lower bound of the definite integral
+    /// * b: upper bound of the definite integral
+    pub fn integrate<F>(&self, f: F, a: T, b: T) -> QuadratureResult<T>
+        where F: Fn(T) -> T
+    {
+        let f_a: T = f(a);
+        let f_b: T = f(b);
+        let panel: Panel<T> = Self::panel(&f, a, b, f_a, f_b);
+
+        let mut evaluations: usize = 3;
+        let mut converged: bool = true;
+        let (value, error): (T, T) = self.refine(&f, panel, self.tol, self.max_depth, &mut evaluations, &mut converged);
+
+        QuadratureResult::new(value, error, evaluations, converged)
+    }
+
+    fn panel<F>(f: &F, a: T, b: T, f_a: T, f_b: T) -> Panel<T>
+        where F: Fn(T) -> T
+    {
+        let m: T = (a + b) / T::from_f64(2.0);
+        let f_m: T = f(m);
+        let simpson: T = (b - a) / T::from_f64(6.0) * (f_a + T::from_f64(4.0) * f_m + f_b);
+
+        Panel { a,
+                b,
+                f_a,
+                f_m,
+                f_b,
+                simpson }
+    }
+
+    fn refine<F>(&self, f: &F, panel: Panel<T>, tol: T, depth: u32, evaluations: &mut usize, converged: &mut bool)
+                 -> (T, T)
+        where F: Fn(T) -> T
+    {
+        let m: T = (panel.a + panel.b) / T::from_f64(2.0);
+        let left: Panel<T> = Self::panel(f, panel.a, m, panel.f_a, panel.f_m);
+        let right: Panel<T> = Self::panel(f, m, panel.b, panel.f_m, panel.f_b);
+        *evaluations += 2;
+
+        let delta: T = left.simpson + right.simpson - panel.simpson;
+        let fifteen: T = T::from_f64(15.0);
+
+        if delta.abs() <= fifteen * tol || depth == 0 || m == panel.a || m == panel.b
+        {
+            if delta.abs() > fifteen * tol
+            {
+                *converged = false;
+            }
+            return (left.simpson + right.simpson + delta / fifteen, delta.abs() / fifteen);
+        }
+
+        let tol_half: T = tol / T::from_f64(2.0);
+        let (value_l, error_l): (T, T) = self.refine(f, left, tol_half, depth - 1, evaluations, converged);
+        let (value_r, error_r): (T, T) = self.refine(f, right, tol_half, depth - 1, evaluations, converged);
+
+        (value_l + value_r, error_l + error_r)
+    }
+}
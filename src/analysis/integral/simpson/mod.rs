@@ -0,0 +1,3 @@
+mod adaptive_simpson;
+
+pub use adaptive_simpson::AdaptiveSimpson;
@@ -0,0 +1,231 @@
+//! Double exponential quadrature
+use crate::algebra::abstr::Real;
+use crate::analysis::integral::QuadratureResult;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bound of the truncated transformed interval $[-t_{max}, t_{max}]$
+const T_MAX: f64 = 4.0;
+
+/// Double exponential (tanh-sinh) quadrature
+///
+/// The integral is transformed with a substitution $x = \phi(t)$, whose
+/// derivative decays double exponentially for $t \rightarrow \pm \infty$,
+/// and the transformed integral is approximated with the trapezoidal rule
+/// ```math
+/// \int_{a}^{b}f(x)\,dx = \int_{-\infty}^{\infty}f(\phi(t))\phi^{'}(t)\,dt \approx h\sum_{k}f(\phi(kh))\phi^{'}(kh)
+/// ```
+/// The substitution depends on the interval:
+///
+/// | Interval | $\phi(t)$ |
+/// |----------|-----------|
+/// | $[a, b]$ | $\frac{a + b}{2} + \frac{b - a}{2}\tanh(\frac{\pi}{2}\sinh t)$ |
+/// | $[a, \infty)$ | $a + \exp(\frac{\pi}{2}\sinh t)$ |
+/// | $(-\infty, \infty)$ | $\sinh(\frac{\pi}{2}\sinh t)$ |
+///
+/// The step size h is halved until two successive approximations agree
+/// within the tolerance. The integrand is never evaluated at a finite bound,
+/// such that integrable end point singularities like $x^{-1/2}$ or
+/// $\ln x$ at $x = 0$ are handled without special treatment. Evaluations
+/// which are not finite, because the node is too close to a singularity,
+/// are ignored. Nodes, whose distance to a bound $b \neq 0$ is below the
+/// machine precision, coincide with the bound and are skipped, which limits
+/// the accuracy for singularities like $(b - x)^{-1/2}$ to about
+/// $\sqrt{\epsilon}$.
+///
+/// Takahasi, H., Mori, M.: Double Exponential Formulas for Numerical
+/// Integration, Publications of the Research Institute for Mathematical
+/// Sciences, 9, 1974
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::integral::{QuadratureResult, TanhSinh};
+///
+/// let ts: TanhSinh<f64> = TanhSinh::default();
+///
+/// // \int_0^1 ln(x) dx
+/// let result: QuadratureResult<f64> = ts.integrate(|x: f64| x.ln(), 0.0, 1.0);
+/// assert_relative_eq!(result.value(), -1.0, epsilon = 1.0e-10);
+///
+/// // \int_{-\infty}^{\infty} e^{-x^2} dx
+/// let result: QuadratureResult<f64> = ts.integrate(|x: f64| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY);
+/// assert_relative_eq!(result.value(), std::f64::consts::PI.sqrt(), epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct TanhSinh<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    max_level: u32,
+}
+
+impl<T> Default for TanhSinh<T>
+    where T: Real
+{
+    fn default() -> TanhSinh<T>
+    {
+        TanhSinh::new(T::from_f64(1.0e-10), T::from_f64(1.0e-10), 8)
+    }
+}
+
+impl<T> TanhSinh<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'max_level': maximal number of step size halvings, the smallest
+    ///   step size is $2^{-max\_level}$
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, max_level: u32) -> TanhSinh<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        TanhSinh { abs_tol,
+                   rel_tol,
+                   max_level }
+    }
+
+    /// Integrate function f from lower bound a to upper bound b
+    ///
+    /// # Arguments
+    /// * a: lower bound of the definite integral, may be $-\infty$
+    /// * b: upper bound of the definite integral, may be $\infty$
+    pub fn integrate<F>(&self, f: F, a: T, b: T) -> QuadratureResult<T>
+        where F: Fn(T) -> T
+    {
+        if a == b
+        {
+            return QuadratureResult::new(T::zero(), T::zero(), 0, true);
+        }
+        if a > b
+        {
+            let result: QuadratureResult<T> = self.integrate(f, b, a);
+            return QuadratureResult::new(-result.value(),
+                                         result.error(),
+                                         result.evaluations(),
+                                         result.is_converged());
+        }
+
+        let half_pi: T = T::pi() / T::from_f64(2.0);
+
+        if a == T::neg_infinity() && b == T::infinity()
+        {
+            // sinh-sinh
+            self.trapezoidal(|t: T| {
+                                 let s: T = half_pi * t.sinh();
+                                 let w: T = half_pi * t.cosh() * s.cosh();
+                                 w * f(s.sinh())
+                             })
+        }
+        else if b == T::infinity()
+        {
+            // exp-sinh
+            self.trapezoidal(|t: T| {
+                                 let e: T = (half_pi * t.sinh()).exp();
+                                 let x: T = a + e;
+                                 if x == a
+                                 {
+                                     return T::zero();
+                                 }
+                                 half_pi * t.cosh() * e * f(x)
+                             })
+        }
+        else if a == T::neg_infinity()
+        {
+            // exp-sinh on the reflected interval
+            self.trapezoidal(|t: T| {
+                                 let e: T = (half_pi * t.sinh()).exp();
+                                 let x: T = b - e;
+                                 if x == b
+                                 {
+                                     return T::zero();
+                                 }
+                                 half_pi * t.cosh() * e * f(x)
+                             })
+        }
+        else
+        {
+            // tanh-sinh, the distance to the nearest bound is computed without cancellation
+            let half: T = (b - a) / T::from_f64(2.0);
+            self.trapezoidal(|t: T| {
+                                 let s: T = half_pi * t.abs().sinh();
+                                 let cosh_s: T = s.cosh();
+                                 let delta: T = half / (s.exp() * cosh_s);
+                                 let x: T = if t < T::zero() { a + delta } else { b - delta };
+                                 if x == a || x == b
+                                 {
+                                     return T::zero();
+                                 }
+                                 half * half_pi * t.cosh() / (cosh_s * cosh_s) * f(x)
+                             })
+        }
+    }
+
+    /// Trapezoidal rule on $[-t_{max}, t_{max}]$ with successively halved step
+    /// sizes
+    fn trapezoidal<G>(&self, term: G) -> QuadratureResult<T>
+        where G: Fn(T) -> T
+    {
+        let t_max: T = T::from_f64(T_MAX);
+        let eval = |t: T| -> T {
+            let v: T = term(t);
+            // ignore evaluations at, or too close to a singularity
+            if v.abs() < T::infinity()
+            {
+                v
+            }
+            else
+            {
+                T::zero()
+            }
+        };
+
+        let k_max: i32 = T_MAX as i32;
+        let mut h: T = T::one();
+        let mut sum: T = (-k_max..=k_max).fold(T::zero(), |s, k| s + eval(T::from_f64(k as f64)));
+        let mut evaluations: usize = 2 * k_max as usize + 1;
+        let mut value: T = h * sum;
+        let mut error: T = T::from_f64(f64::MAX);
+
+        for level in 1..=self.max_level
+        {
+            h /= T::from_f64(2.0);
+
+            // only the new nodes (2j + 1)h have to be evaluated
+            let mut t: T = h;
+            while t <= t_max
+            {
+                sum += eval(t) + eval(-t);
+                evaluations += 2;
+                t += h + h;
+            }
+
+            let value_new: T = h * sum;
+            error = (value_new - value).abs();
+            value = value_new;
+
+            if level >= 2 && error <= self.abs_tol.max(self.rel_tol * value.abs())
+            {
+                return QuadratureResult::new(value, error, evaluations, true);
+            }
+        }
+
+        QuadratureResult::new(value, error, evaluations, false)
+    }
+}
@@ -0,0 +1,88 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::piecewise_cubic::{check_nodes, PiecewiseCubic};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Akima interpolation
+///
+/// The slope at a node is a weighted mean of the adjacent secants $m_i$
+/// ```math
+/// s_i = \frac{|m_{i+1} - m_i|m_{i-1} + |m_{i-1} - m_{i-2}|m_i}{|m_{i+1} - m_i| + |m_{i-1} - m_{i-2}|}
+/// ```
+/// which only depends on the neighbouring points. Outliers therefore cause
+/// less overshoot than with cubic splines. The secants are extended by
+/// linear extrapolation at both ends.
+///
+/// Akima, H.: A New Method of Interpolation and Smooth Curve Fitting Based
+/// on Local Procedures, Journal of the ACM, 17, 1970
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{Akima, Interpolant};
+///
+/// let akima: Akima<f64> = Akima::new(vec![0.0, 1.0, 2.0, 3.0, 4.0], vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+///
+/// assert_relative_eq!(akima.eval(2.5), 2.5);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Akima<T>
+{
+    pp: PiecewiseCubic<T>,
+}
+
+impl<T> Akima<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    ///
+    /// # Panics
+    ///
+    /// if less than three nodes are given, the number of nodes and values
+    /// differ or the nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Akima<T>
+    {
+        check_nodes(&x, &y, 3);
+
+        let n: usize = x.len();
+        let two: T = T::from_f64(2.0);
+
+        // m[k + 2] is the secant of the interval k
+        let mut m: Vec<T> = Vec::with_capacity(n + 3);
+        m.push(T::zero());
+        m.push(T::zero());
+        m.extend((0..(n - 1)).map(|i| (y[i + 1] - y[i]) / (x[i + 1] - x[i])));
+        m[1] = two * m[2] - m[3];
+        m[0] = two * m[1] - m[2];
+        m.push(two * m[n] - m[n - 1]);
+        m.push(two * m[n + 1] - m[n]);
+
+        let dydx: Vec<T> = (0..n).map(|i| {
+                                     let w_1: T = (m[i + 3] - m[i + 2]).abs();
+                                     let w_2: T = (m[i + 1] - m[i]).abs();
+                                     if w_1 + w_2 == T::zero()
+                                     {
+                                         (m[i + 1] + m[i + 2]) / two
+                                     }
+                                     else
+                                     {
+                                         (w_1 * m[i + 1] + w_2 * m[i + 2]) / (w_1 + w_2)
+                                     }
+                                 })
+                                 .collect();
+
+        Akima { pp: PiecewiseCubic::hermite(x, &y, &dydx) }
+    }
+}
+
+impl_piecewise_cubic!(Akima);
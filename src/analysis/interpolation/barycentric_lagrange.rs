@@ -0,0 +1,257 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::{
+    integral::gauss_legendre::GaussLegendre,
+    interpolation::{piecewise_cubic::check_nodes, Extrapolation, Interpolant},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Barycentric Lagrange interpolation
+///
+/// The interpolation polynomial of degree n through the points
+/// $(x_j, y_j)$ is evaluated with the barycentric formula
+/// ```math
+/// p(x) = \frac{\sum_{j=0}^{n}\frac{w_j}{x - x_j}y_j}{\sum_{j=0}^{n}\frac{w_j}{x - x_j}}, \quad w_j = \frac{1}{\prod_{k \neq j}(x_j - x_k)}
+/// ```
+/// in O(n) operations. The evaluation is numerically stable, but the
+/// polynomial itself is only well conditioned for suitable nodes. For
+/// Chebyshev points of the second kind, the weights are known explicitly
+/// and the interpolant converges rapidly for smooth functions.
+///
+/// Berrut, J.-P., Trefethen, L. N.: Barycentric Lagrange Interpolation, SIAM
+/// Review, 46, 2004
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{BarycentricLagrange, Interpolant};
+///
+/// let x: Vec<f64> = BarycentricLagrange::chebyshev_points(20, 0.0, 2.0);
+/// let y: Vec<f64> = x.iter().map(|x_j| x_j.sin()).collect();
+/// let p: BarycentricLagrange<f64> = BarycentricLagrange::from_chebyshev(y, 0.0, 2.0);
+///
+/// assert_relative_eq!(p.eval(1.3), 1.3f64.sin(), epsilon = 1.0e-14);
+/// assert_relative_eq!(p.derivative(1.3), 1.3f64.cos(), epsilon = 1.0e-12);
+/// assert_relative_eq!(p.integrate(0.0, 2.0), 1.0 - 2.0f64.cos(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct BarycentricLagrange<T>
+{
+    x: Vec<T>,
+    y: Vec<T>,
+    w: Vec<T>,
+    extrapolation: Extrapolation,
+}
+
+impl<T> BarycentricLagrange<T>
+    where T: Real
+{
+    /// Interpolation polynomial through arbitrary nodes, the weights are
+    /// computed in $O(n^2)$ operations
+    ///
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    ///
+    /// # Panics
+    ///
+    /// if less than two nodes are given, the number of nodes and values
+    /// differ or the nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>) -> BarycentricLagrange<T>
+    {
+        check_nodes(&x, &y, 2);
+
+        // the differences are scaled with the inverse of the capacity of the interval to avoid
+        // overflow and underflow of the weights
+        let scale: T = T::from_f64(4.0) / (x[x.len() - 1] - x[0]);
+        let w: Vec<T> = x.iter()
+                         .enumerate()
+                         .map(|(j, x_j)| {
+                             let product: T = x.iter()
+                                               .enumerate()
+                                               .filter(|(k, _)| *k != j)
+                                               .fold(T::one(), |p, (_, x_k)| p * scale * (*x_j - *x_k));
+                             T::one() / product
+                         })
+                         .collect();
+
+        BarycentricLagrange { x,
+                              y,
+                              w,
+                              extrapolation: Extrapolation::default() }
+    }
+
+    /// Interpolation polynomial through the values y at the Chebyshev points
+    /// of the second kind in $[a, b]$, see
+    /// [`chebyshev_points`](BarycentricLagrange::chebyshev_points)
+    ///
+    /// The weights are $w_j = (-1)^j\delta_j$ with $\delta_0 = \delta_n = \frac{1}{2}$
+    /// and $\delta_j = 1$ otherwise.
+    ///
+    /// # Panics
+    ///
+    /// if less than two values are given or a >= b
+    pub fn from_chebyshev(y: Vec<T>, a: T, b: T) -> BarycentricLagrange<T>
+    {
+        let x: Vec<T> = BarycentricLagrange::chebyshev_points(y.len(), a, b);
+        let n: usize = x.len() - 1;
+        let w: Vec<T> = (0..=n).map(|j| {
+                                    let sign: T = if j % 2 == 0 { T::one() } else { -T::one() };
+                                    if j == 0 || j == n
+                                    {
+                                        sign / T::from_f64(2.0)
+                                    }
+                                    else
+                                    {
+                                        sign
+                                    }
+                                })
+                                .collect();
+
+        BarycentricLagrange { x,
+                              y,
+                              w,
+                              extrapolation: Extrapolation::default() }
+    }
+
+    /// Chebyshev points of the second kind in ascending order
+    /// ```math
+    /// x_j = \frac{a + b}{2} - \frac{b - a}{2}\cos\left(\frac{j\pi}{n - 1}\right), \quad j = 0, \dots, n - 1
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// if n < 2 or a >= b
+    pub fn chebyshev_points(n: usize, a: T, b: T) -> Vec<T>
+    {
+        if n < 2
+        {
+            panic!("At least 2 nodes are required");
+        }
+        if a >= b
+        {
+            panic!("The interval is empty");
+        }
+
+        let center: T = (a + b) / T::from_f64(2.0);
+        let half: T = (b - a) / T::from_f64(2.0);
+        let m: usize = n - 1;
+        (0..n).map(|j| {
+                  // symmetric evaluation, such that the points are symmetric about the center
+                  if 2 * j == m
+                  {
+                      center
+                  }
+                  else if 2 * j < m
+                  {
+                      center - half * (T::pi() * T::from_f64(j as f64) / T::from_f64(m as f64)).cos()
+                  }
+                  else
+                  {
+                      center + half * (T::pi() * T::from_f64((m - j) as f64) / T::from_f64(m as f64)).cos()
+                  }
+              })
+              .collect()
+    }
+
+    /// Evaluates the polynomial without extrapolation policy
+    fn eval_polynomial(&self, x: T) -> T
+    {
+        let mut numerator: T = T::zero();
+        let mut denominator: T = T::zero();
+        for ((x_j, y_j), w_j) in self.x.iter().zip(self.y.iter()).zip(self.w.iter())
+        {
+            if x == *x_j
+            {
+                return *y_j;
+            }
+            let c: T = *w_j / (x - *x_j);
+            numerator += c * *y_j;
+            denominator += c;
+        }
+
+        numerator / denominator
+    }
+
+    /// Evaluates the derivative of the polynomial without extrapolation
+    /// policy
+    /// ```math
+    /// p^{'}(x) = \frac{\sum_{j}\frac{w_j}{x - x_j}\frac{p(x) - y_j}{x - x_j}}{\sum_{j}\frac{w_j}{x - x_j}}, \quad p^{'}(x_i) = \sum_{j \neq i}\frac{w_j}{w_i}\frac{y_j - y_i}{x_i - x_j}
+    /// ```
+    fn derivative_polynomial(&self, x: T) -> T
+    {
+        if let Some(i) = self.x.iter().position(|x_i| *x_i == x)
+        {
+            return (0..self.x.len()).filter(|j| *j != i)
+                                    .fold(T::zero(), |s, j| {
+                                        s + self.w[j] / self.w[i] * (self.y[j] - self.y[i]) / (self.x[i] - self.x[j])
+                                    });
+        }
+
+        let p: T = self.eval_polynomial(x);
+        let mut numerator: T = T::zero();
+        let mut denominator: T = T::zero();
+        for ((x_j, y_j), w_j) in self.x.iter().zip(self.y.iter()).zip(self.w.iter())
+        {
+            let c: T = *w_j / (x - *x_j);
+            numerator += c * (p - *y_j) / (x - *x_j);
+            denominator += c;
+        }
+
+        numerator / denominator
+    }
+
+    /// Integral of the polynomial from $x_0$ to x with a Gauss-Legendre
+    /// rule, which is exact for the degree of the polynomial
+    fn antiderivative(&self, x: T) -> T
+    {
+        let n: usize = (self.x.len() / 2 + 1).min(u8::MAX as usize);
+        GaussLegendre::new(n as u8).integrate(|t: T| self.eval_polynomial(t), self.x[0], x)
+    }
+}
+
+impl<T> Interpolant<T> for BarycentricLagrange<T>
+    where T: Real
+{
+    fn eval(&self, x: T) -> T
+    {
+        self.extrapolation.eval(|x: T| self.eval_polynomial(x), x, self.domain())
+    }
+
+    fn derivative(&self, x: T) -> T
+    {
+        self.extrapolation.derivative(|x: T| self.derivative_polynomial(x), x, self.domain())
+    }
+
+    fn integrate(&self, a: T, b: T) -> T
+    {
+        self.extrapolation.integrate(|x: T| self.antiderivative(x),
+                                     |x: T| self.eval_polynomial(x),
+                                     a,
+                                     b,
+                                     self.domain())
+    }
+
+    fn domain(&self) -> (T, T)
+    {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.extrapolation
+    }
+}
@@ -0,0 +1,228 @@
+use crate::algebra::{abstr::Real, linear::Matrix};
+use crate::analysis::interpolation::{
+    grid::{check_axis, locate, AxisPosition},
+    CubicSpline, Extrapolation, Interpolant, SplineBoundary,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bicubic interpolation on a rectilinear grid
+///
+/// In every cell, the interpolant is the bicubic Hermite polynomial, which
+/// matches $f$, $\frac{\partial f}{\partial x}$,
+/// $\frac{\partial f}{\partial y}$ and
+/// $\frac{\partial^2 f}{\partial x \partial y}$ at the four corners
+/// ```math
+/// p(x, y) = \sum_{a, b \in \{0, 1\}}\left(f_{ab}H_a(t)H_b(u) + h_x f^x_{ab}K_a(t)H_b(u) + h_y f^y_{ab}H_a(t)K_b(u) + h_x h_y f^{xy}_{ab}K_a(t)K_b(u)\right)
+/// ```
+/// where $H_a$ and $K_a$ are the cubic Hermite basis functions for the
+/// value and the derivative at the corner a. The derivatives at the nodes
+/// are taken from cubic splines along the grid lines, with not-a-knot end
+/// conditions if the axis has at least four nodes and natural end
+/// conditions otherwise. The interpolant is continuously differentiable.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, analysis::interpolation::Bicubic};
+///
+/// let x: Vec<f64> = (0..=10).map(|i| i as f64 / 10.0).collect();
+/// let y: Vec<f64> = (0..=20).map(|j| j as f64 / 10.0).collect();
+/// let f = |x: f64, y: f64| x.sin() * y.exp();
+/// let z: Matrix<f64> = Matrix::new(11, 21, y.iter().flat_map(|y_j| x.iter().map(move |x_i| f(*x_i, *y_j))).collect());
+///
+/// let bicubic: Bicubic<f64> = Bicubic::new(x, y, z);
+///
+/// assert_relative_eq!(bicubic.eval(0.33, 1.57), f(0.33, 1.57), epsilon = 1.0e-5);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Bicubic<T>
+{
+    x: Vec<T>,
+    y: Vec<T>,
+    z: Matrix<T>,
+    dz_dx: Matrix<T>,
+    dz_dy: Matrix<T>,
+    dz_dxdy: Matrix<T>,
+    extrapolation: Extrapolation,
+}
+
+impl<T> Bicubic<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes of the first axis
+    /// * 'y': strictly increasing nodes of the second axis
+    /// * 'z': values $z_{i, j} = f(x_i, y_j)$ with the dimension
+    ///   $|x| \times |y|$
+    ///
+    /// # Panics
+    ///
+    /// if an axis has less than two nodes, the nodes are not strictly
+    /// increasing or the dimension of z does not match the axes
+    pub fn new(x: Vec<T>, y: Vec<T>, z: Matrix<T>) -> Bicubic<T>
+    {
+        check_axis(&x);
+        check_axis(&y);
+        if z.dim() != (x.len(), y.len())
+        {
+            panic!("The dimension of the values does not match the axes");
+        }
+
+        let (m, n): (usize, usize) = z.dim();
+        let mut dz_dx: Matrix<T> = Matrix::zero(m, n);
+        let mut dz_dy: Matrix<T> = Matrix::zero(m, n);
+        let mut dz_dxdy: Matrix<T> = Matrix::zero(m, n);
+
+        for j in 0..n
+        {
+            let slopes: Vec<T> = Bicubic::slopes(&x, (0..m).map(|i| z[[i, j]]).collect());
+            for (i, s) in slopes.into_iter().enumerate()
+            {
+                dz_dx[[i, j]] = s;
+            }
+        }
+        for i in 0..m
+        {
+            let slopes: Vec<T> = Bicubic::slopes(&y, (0..n).map(|j| z[[i, j]]).collect());
+            for (j, s) in slopes.into_iter().enumerate()
+            {
+                dz_dy[[i, j]] = s;
+            }
+            let slopes: Vec<T> = Bicubic::slopes(&y, (0..n).map(|j| dz_dx[[i, j]]).collect());
+            for (j, s) in slopes.into_iter().enumerate()
+            {
+                dz_dxdy[[i, j]] = s;
+            }
+        }
+
+        Bicubic { x,
+                  y,
+                  z,
+                  dz_dx,
+                  dz_dy,
+                  dz_dxdy,
+                  extrapolation: Extrapolation::default() }
+    }
+
+    /// Derivatives of the cubic spline through the values at the nodes
+    fn slopes(nodes: &[T], values: Vec<T>) -> Vec<T>
+    {
+        let boundary: SplineBoundary<T> = if nodes.len() >= 4
+        {
+            SplineBoundary::NotAKnot
+        }
+        else
+        {
+            SplineBoundary::Natural
+        };
+        let spline: CubicSpline<T> = CubicSpline::new(nodes.to_vec(), values, boundary);
+
+        nodes.iter().map(|x_i| spline.derivative(*x_i)).collect()
+    }
+
+    /// Cubic Hermite basis functions $(H_0, H_1, K_0, K_1)$ and their
+    /// derivatives
+    fn basis(t: T) -> ([T; 4], [T; 4])
+    {
+        let one: T = T::one();
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+        let six: T = T::from_f64(6.0);
+        let t_2: T = t * t;
+        let t_3: T = t_2 * t;
+
+        ([one - three * t_2 + two * t_3, three * t_2 - two * t_3, t - two * t_2 + t_3, t_3 - t_2],
+         [six * (t_2 - t), six * (t - t_2), one - T::from_f64(4.0) * t + three * t_2, three * t_2 - two * t])
+    }
+
+    fn locate(&self, x: T, y: T) -> Option<(AxisPosition<T>, AxisPosition<T>)>
+    {
+        Some((locate(&self.x, x, self.extrapolation)?, locate(&self.y, y, self.extrapolation)?))
+    }
+
+    /// Evaluates the Hermite polynomial of the cell with the basis functions
+    /// in the x and the y direction
+    fn hermite(&self, i: usize, j: usize, h_x: T, h_y: T, b_x: &[T; 4], b_y: &[T; 4]) -> T
+    {
+        let mut sum: T = T::zero();
+        for a in 0..2
+        {
+            for b in 0..2
+            {
+                let (k, l): (usize, usize) = (i + a, j + b);
+                sum += self.z[[k, l]] * b_x[a] * b_y[b]
+                       + h_x * self.dz_dx[[k, l]] * b_x[a + 2] * b_y[b]
+                       + h_y * self.dz_dy[[k, l]] * b_x[a] * b_y[b + 2]
+                       + h_x * h_y * self.dz_dxdy[[k, l]] * b_x[a + 2] * b_y[b + 2];
+            }
+        }
+
+        sum
+    }
+
+    /// Evaluates the interpolant at (x, y)
+    pub fn eval(&self, x: T, y: T) -> T
+    {
+        let (p_x, p_y) = match self.locate(x, y)
+        {
+            Some(p) => p,
+            None => return T::from_f64(f64::NAN),
+        };
+
+        let (b_x, _) = Bicubic::basis(p_x.t);
+        let (b_y, _) = Bicubic::basis(p_y.t);
+
+        self.hermite(p_x.i, p_y.i, p_x.h, p_y.h, &b_x, &b_y)
+    }
+
+    /// Evaluates the gradient $(\frac{\partial p}{\partial x}, \frac{\partial p}{\partial y})$ at (x, y)
+    pub fn gradient(&self, x: T, y: T) -> (T, T)
+    {
+        let (p_x, p_y) = match self.locate(x, y)
+        {
+            Some(p) => p,
+            None => return (T::from_f64(f64::NAN), T::from_f64(f64::NAN)),
+        };
+
+        let (b_x, db_x) = Bicubic::basis(p_x.t);
+        let (b_y, db_y) = Bicubic::basis(p_y.t);
+
+        let dz_dx: T = if p_x.constant
+        {
+            T::zero()
+        }
+        else
+        {
+            self.hermite(p_x.i, p_y.i, p_x.h, p_y.h, &db_x, &b_y) / p_x.h
+        };
+        let dz_dy: T = if p_y.constant
+        {
+            T::zero()
+        }
+        else
+        {
+            self.hermite(p_x.i, p_y.i, p_x.h, p_y.h, &b_x, &db_y) / p_y.h
+        };
+
+        (dz_dx, dz_dy)
+    }
+
+    pub fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    pub fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.extrapolation
+    }
+}
@@ -0,0 +1,133 @@
+use crate::algebra::{abstr::Real, linear::Matrix};
+use crate::analysis::interpolation::{
+    grid::{check_axis, locate, AxisPosition},
+    Extrapolation,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bilinear interpolation on a rectilinear grid
+///
+/// In the cell $[x_i, x_{i+1}] \times [y_j, y_{j+1}]$ with the local
+/// coordinates $t, u \in [0, 1]$
+/// ```math
+/// p(x, y) = (1 - t)(1 - u)z_{i, j} + t(1 - u)z_{i+1, j} + (1 - t)u z_{i, j+1} + t u z_{i+1, j+1}
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Matrix, analysis::interpolation::Bilinear};
+///
+/// let z: Matrix<f64> = matrix![0.0, 1.0;
+///                              2.0, 4.0];
+/// let bilinear: Bilinear<f64> = Bilinear::new(vec![0.0, 1.0], vec![0.0, 2.0], z);
+///
+/// assert_relative_eq!(bilinear.eval(0.5, 1.0), 1.75);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Bilinear<T>
+{
+    x: Vec<T>,
+    y: Vec<T>,
+    z: Matrix<T>,
+    extrapolation: Extrapolation,
+}
+
+impl<T> Bilinear<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes of the first axis
+    /// * 'y': strictly increasing nodes of the second axis
+    /// * 'z': values $z_{i, j} = f(x_i, y_j)$ with the dimension
+    ///   $|x| \times |y|$
+    ///
+    /// # Panics
+    ///
+    /// if an axis has less than two nodes, the nodes are not strictly
+    /// increasing or the dimension of z does not match the axes
+    pub fn new(x: Vec<T>, y: Vec<T>, z: Matrix<T>) -> Bilinear<T>
+    {
+        check_axis(&x);
+        check_axis(&y);
+        if z.dim() != (x.len(), y.len())
+        {
+            panic!("The dimension of the values does not match the axes");
+        }
+
+        Bilinear { x,
+                   y,
+                   z,
+                   extrapolation: Extrapolation::default() }
+    }
+
+    fn locate(&self, x: T, y: T) -> Option<(AxisPosition<T>, AxisPosition<T>)>
+    {
+        Some((locate(&self.x, x, self.extrapolation)?, locate(&self.y, y, self.extrapolation)?))
+    }
+
+    /// Evaluates the interpolant at (x, y)
+    pub fn eval(&self, x: T, y: T) -> T
+    {
+        let (p_x, p_y) = match self.locate(x, y)
+        {
+            Some(p) => p,
+            None => return T::from_f64(f64::NAN),
+        };
+        let (i, j, t, u): (usize, usize, T, T) = (p_x.i, p_y.i, p_x.t, p_y.t);
+
+        (T::one() - t) * (T::one() - u) * self.z[[i, j]]
+        + t * (T::one() - u) * self.z[[i + 1, j]]
+        + (T::one() - t) * u * self.z[[i, j + 1]]
+        + t * u * self.z[[i + 1, j + 1]]
+    }
+
+    /// Evaluates the gradient $(\frac{\partial p}{\partial x}, \frac{\partial p}{\partial y})$ at (x, y)
+    pub fn gradient(&self, x: T, y: T) -> (T, T)
+    {
+        let (p_x, p_y) = match self.locate(x, y)
+        {
+            Some(p) => p,
+            None => return (T::from_f64(f64::NAN), T::from_f64(f64::NAN)),
+        };
+        let (i, j, t, u): (usize, usize, T, T) = (p_x.i, p_y.i, p_x.t, p_y.t);
+
+        let dz_dx: T = if p_x.constant
+        {
+            T::zero()
+        }
+        else
+        {
+            ((T::one() - u) * (self.z[[i + 1, j]] - self.z[[i, j]]) + u * (self.z[[i + 1, j + 1]] - self.z[[i, j + 1]])) / p_x.h
+        };
+        let dz_dy: T = if p_y.constant
+        {
+            T::zero()
+        }
+        else
+        {
+            ((T::one() - t) * (self.z[[i, j + 1]] - self.z[[i, j]]) + t * (self.z[[i + 1, j + 1]] - self.z[[i + 1, j]])) / p_y.h
+        };
+
+        (dz_dx, dz_dy)
+    }
+
+    pub fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    pub fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.extrapolation
+    }
+}
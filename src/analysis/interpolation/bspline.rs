@@ -0,0 +1,519 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{matrix::Solve, Matrix, Vector},
+};
+use crate::analysis::interpolation::{Extrapolation, Interpolant};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// B-spline function
+///
+/// ```math
+/// s(x) = \sum_{i=0}^{n-1}c_i N_{i,p}(x)
+/// ```
+/// The B-spline basis functions of degree p are defined on the
+/// non-decreasing knots $t_0, \dots, t_{n+p}$ by the Cox-de Boor recursion
+/// ```math
+/// N_{i,0}(x) = \begin{cases} 1 & t_i \leq x < t_{i+1} \\ 0 & \text{otherwise} \end{cases}, \quad N_{i,p}(x) = \frac{x - t_i}{t_{i+p} - t_i}N_{i,p-1}(x) + \frac{t_{i+p+1} - x}{t_{i+p+1} - t_{i+1}}N_{i+1,p-1}(x)
+/// ```
+/// The spline is evaluated with de Boor's algorithm on its domain
+/// $[t_p, t_n]$. Outside of the domain, the polynomial of the first,
+/// respectively the last knot span is continued, or the
+/// [`Extrapolation`] policy is applied.
+///
+/// de Boor, C.: A Practical Guide to Splines, Springer, 1978 <br>
+/// Piegl, L., Tiller, W.: The NURBS Book, Springer, 1997
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{BSpline, Interpolant};
+///
+/// // quadratic B-spline with the knots 0, 0, 0, 1, 2, 2, 2
+/// let knots: Vec<f64> = BSpline::clamped_knots(&[0.0, 1.0, 2.0], 2);
+/// let spline: BSpline<f64> = BSpline::new(2, knots, vec![0.0, 1.0, 1.0, 0.0]);
+///
+/// assert_relative_eq!(spline.eval(1.0), 1.0);
+/// assert_relative_eq!(spline.derivative(0.0), 2.0);
+/// assert_relative_eq!(spline.integrate(0.0, 2.0), 4.0 / 3.0);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct BSpline<T>
+{
+    degree: usize,
+    knots: Vec<T>,
+    coef: Vec<T>,
+    extrapolation: Extrapolation,
+}
+
+impl<T> BSpline<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'degree': degree p
+    /// * 'knots': non-decreasing knots $t_0, \dots, t_{n+p}$
+    /// * 'coef': coefficients $c_0, \dots, c_{n-1}$
+    ///
+    /// # Panics
+    ///
+    /// if less than p + 1 coefficients are given, the number of knots is not
+    /// n + p + 1, the knots are decreasing, a knot has a multiplicity
+    /// greater than p + 1 or the domain is empty
+    pub fn new(degree: usize, knots: Vec<T>, coef: Vec<T>) -> BSpline<T>
+    {
+        check_knots(degree, &knots, coef.len());
+
+        BSpline { degree,
+                  knots,
+                  coef,
+                  extrapolation: Extrapolation::default() }
+    }
+
+    /// Clamped knot vector of degree p, whose first and last breakpoint are
+    /// repeated p + 1 times, such that the spline interpolates the first and
+    /// the last coefficient
+    ///
+    /// # Panics
+    ///
+    /// if less than two breakpoints are given or the breakpoints are not
+    /// strictly increasing
+    pub fn clamped_knots(breakpoints: &[T], degree: usize) -> Vec<T>
+    {
+        if breakpoints.len() < 2 || breakpoints.windows(2).any(|w| w[0] >= w[1])
+        {
+            panic!("At least two strictly increasing breakpoints are required");
+        }
+
+        let first: T = breakpoints[0];
+        let last: T = breakpoints[breakpoints.len() - 1];
+        let mut knots: Vec<T> = vec![first; degree];
+        knots.extend_from_slice(breakpoints);
+        knots.extend(std::iter::repeat_n(last, degree));
+
+        knots
+    }
+
+    /// Least squares fit of a B-spline with the given degree and knots to the
+    /// data $(x_i, y_i)$
+    /// ```math
+    /// \min_{c}\sum_{i}\left(y_i - s(x_i)\right)^2
+    /// ```
+    /// The normal equations $B^T B c = B^T y$, with the collocation matrix
+    /// $B_{ij} = N_{j,p}(x_i)$, are assembled from the p + 1 nonzero basis
+    /// functions at each point.
+    ///
+    /// # Panics
+    ///
+    /// if the knots are not valid, the number of points and values differ, a
+    /// point is not finite or the points do not satisfy the Schoenberg-Whitney
+    /// conditions, i.e. there are no distinct points
+    /// $x_{j_0} < \dots < x_{j_{n-1}}$ with $N_{i,p}(x_{j_i}) > 0$, in which
+    /// case the normal equations are singular
+    pub fn fit(x: &[T], y: &[T], degree: usize, knots: Vec<T>) -> BSpline<T>
+    {
+        if x.len() != y.len()
+        {
+            panic!("The number of points and values differ");
+        }
+        if !x.iter().all(|x_i| x_i.abs() < T::infinity())
+        {
+            panic!("The points are not finite");
+        }
+        let n: usize = knots.len().saturating_sub(degree + 1);
+        check_knots(degree, &knots, n);
+
+        // the supports of the basis functions are ordered, the greedy assignment succeeds if any does
+        let mut sorted: Vec<T> = x.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut j: usize = 0;
+        for i in 0..n
+        {
+            while j < sorted.len() && !BSpline::is_nonzero(degree, &knots, i, sorted[j])
+            {
+                j += 1;
+            }
+            if j == sorted.len()
+            {
+                panic!("The points do not satisfy the Schoenberg-Whitney conditions");
+            }
+            j += 1;
+        }
+
+        let mut normal: Matrix<T> = Matrix::zero(n, n);
+        let mut rhs: Vector<T> = Vector::zero(n);
+        for (x_i, y_i) in x.iter().zip(y.iter())
+        {
+            let span: usize = span(degree, &knots, *x_i);
+            let basis: Vec<T> = basis_derivatives(degree, &knots, span, *x_i, 0).swap_remove(0);
+            let first: usize = span - degree;
+            for (k, b_k) in basis.iter().enumerate()
+            {
+                rhs[first + k] += *b_k * *y_i;
+                for (l, b_l) in basis.iter().enumerate()
+                {
+                    normal[[first + k, first + l]] += *b_k * *b_l;
+                }
+            }
+        }
+
+        let coef: Vector<T> = match normal.solve(&rhs)
+        {
+            Ok(c) => c,
+            Err(_) => panic!("The normal equations are singular"),
+        };
+
+        BSpline::new(degree, knots, (0..n).map(|i| coef[i]).collect())
+    }
+
+    /// Checks, if the basis function i is nonzero at x
+    fn is_nonzero(degree: usize, knots: &[T], i: usize, x: T) -> bool
+    {
+        if x < knots[i] || x > knots[i + degree + 1]
+        {
+            return false;
+        }
+        let span: usize = span(degree, knots, x);
+        if i + degree < span || i > span
+        {
+            return false;
+        }
+        basis_derivatives(degree, knots, span, x, 0)[0][i + degree - span] > T::zero()
+    }
+
+    pub fn degree(&self) -> usize
+    {
+        self.degree
+    }
+
+    pub fn knots(&self) -> &[T]
+    {
+        &self.knots
+    }
+
+    pub fn coefficients(&self) -> &[T]
+    {
+        &self.coef
+    }
+
+    /// Values of the p + 1 basis functions, which are nonzero at x, and the
+    /// index of the first of them
+    pub fn basis(&self, x: T) -> (usize, Vec<T>)
+    {
+        let span: usize = span(self.degree, &self.knots, x);
+        (span - self.degree, basis_derivatives(self.degree, &self.knots, span, x, 0).swap_remove(0))
+    }
+
+    /// Derivative of the spline, a B-spline of degree p - 1 with the knots
+    /// $t_1, \dots, t_{n+p-1}$ and the coefficients
+    /// ```math
+    /// c^{'}_i = p\frac{c_{i+1} - c_i}{t_{i+p+1} - t_{i+1}}
+    /// ```
+    /// The derivative of a spline of degree zero is zero.
+    pub fn differentiate(&self) -> BSpline<T>
+    {
+        let p: usize = self.degree;
+        if p == 0
+        {
+            return BSpline { degree: 0,
+                             knots: self.knots.clone(),
+                             coef: vec![T::zero(); self.coef.len()],
+                             extrapolation: self.extrapolation };
+        }
+
+        let coef: Vec<T> = (0..(self.coef.len() - 1)).map(|i| {
+                                                         let h: T = self.knots[i + p + 1] - self.knots[i + 1];
+                                                         if h > T::zero()
+                                                         {
+                                                             T::from_f64(p as f64) * (self.coef[i + 1] - self.coef[i]) / h
+                                                         }
+                                                         else
+                                                         {
+                                                             T::zero()
+                                                         }
+                                                     })
+                                                     .collect();
+
+        BSpline { degree: p - 1,
+                  knots: self.knots[1..(self.knots.len() - 1)].to_vec(),
+                  coef,
+                  extrapolation: self.extrapolation }
+    }
+
+    /// Inserts the knot x with Boehm's algorithm, the spline function does
+    /// not change
+    ///
+    /// # Panics
+    ///
+    /// if x is not inside of the domain or the multiplicity of x would
+    /// exceed p + 1
+    pub fn insert_knot(&mut self, x: T)
+    {
+        let p: usize = self.degree;
+        let (a, b): (T, T) = self.domain();
+        if x <= a || x >= b
+        {
+            panic!("The knot is not inside of the domain");
+        }
+        if self.knots.iter().filter(|t| **t == x).count() > p
+        {
+            panic!("The multiplicity of the knot would exceed the degree plus one");
+        }
+
+        let k: usize = span(p, &self.knots, x);
+        let mut coef: Vec<T> = Vec::with_capacity(self.coef.len() + 1);
+        coef.extend_from_slice(&self.coef[..=(k - p)]);
+        for i in (k - p + 1)..=k
+        {
+            let alpha: T = (x - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+            coef.push((T::one() - alpha) * self.coef[i - 1] + alpha * self.coef[i]);
+        }
+        coef.extend_from_slice(&self.coef[k..]);
+
+        self.knots.insert(k + 1, x);
+        self.coef = coef;
+    }
+
+    /// de Boor's algorithm
+    fn de_boor(&self, x: T) -> T
+    {
+        let p: usize = self.degree;
+        let k: usize = span(p, &self.knots, x);
+        let mut d: Vec<T> = self.coef[(k - p)..=k].to_vec();
+
+        for r in 1..=p
+        {
+            for j in (r..=p).rev()
+            {
+                let left: T = self.knots[j + k - p];
+                let right: T = self.knots[j + 1 + k - r];
+                let alpha: T = (x - left) / (right - left);
+                d[j] = (T::one() - alpha) * d[j - 1] + alpha * d[j];
+            }
+        }
+
+        d[p]
+    }
+
+    /// Derivative at x from the p derivative coefficients of the knot span
+    fn derivative_value(&self, x: T) -> T
+    {
+        let p: usize = self.degree;
+        if p == 0
+        {
+            return T::zero();
+        }
+
+        let k: usize = span(p, &self.knots, x);
+        let ders: Vec<Vec<T>> = basis_derivatives(p, &self.knots, k, x, 1);
+        (0..=p).fold(T::zero(), |s, j| s + ders[1][j] * self.coef[k - p + j])
+    }
+
+    /// Integral from the lower bound of the domain to x
+    fn antiderivative(&self, x: T) -> T
+    {
+        // the antiderivative is a spline of degree p + 1 with the knots t_0, t_0, ..., t_{n+p}, t_{n+p}
+        let p: usize = self.degree;
+        let n: usize = self.coef.len();
+        let mut knots: Vec<T> = Vec::with_capacity(n + p + 3);
+        knots.push(self.knots[0]);
+        knots.extend_from_slice(&self.knots);
+        knots.push(self.knots[n + p]);
+
+        let mut coef: Vec<T> = Vec::with_capacity(n + 1);
+        coef.push(T::zero());
+        for j in 0..n
+        {
+            let c: T = coef[j] + self.coef[j] * (self.knots[j + p + 1] - self.knots[j]) / T::from_f64((p + 1) as f64);
+            coef.push(c);
+        }
+
+        let antiderivative: BSpline<T> = BSpline { degree: p + 1,
+                                                   knots,
+                                                   coef,
+                                                   extrapolation: Extrapolation::default() };
+
+        antiderivative.de_boor(x) - antiderivative.de_boor(self.domain().0)
+    }
+}
+
+impl<T> Interpolant<T> for BSpline<T>
+    where T: Real
+{
+    fn eval(&self, x: T) -> T
+    {
+        self.extrapolation.eval(|x: T| self.de_boor(x), x, self.domain())
+    }
+
+    fn derivative(&self, x: T) -> T
+    {
+        self.extrapolation.derivative(|x: T| self.derivative_value(x), x, self.domain())
+    }
+
+    fn integrate(&self, a: T, b: T) -> T
+    {
+        self.extrapolation.integrate(|x: T| self.antiderivative(x),
+                                     |x: T| self.de_boor(x),
+                                     a,
+                                     b,
+                                     self.domain())
+    }
+
+    /// Interval $[t_p, t_n]$
+    fn domain(&self) -> (T, T)
+    {
+        (self.knots[self.degree], self.knots[self.coef.len()])
+    }
+
+    fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.extrapolation
+    }
+}
+
+/// Checks a knot vector of degree p for n coefficients
+///
+/// # Panics
+///
+/// if n < p + 1, the number of knots is not n + p + 1, the knots are
+/// decreasing, the multiplicity of a knot exceeds p + 1 or the domain is
+/// empty
+pub(crate) fn check_knots<T>(degree: usize, knots: &[T], n: usize)
+    where T: Real
+{
+    if n < degree + 1
+    {
+        panic!("At least degree + 1 coefficients are required");
+    }
+    if knots.len() != n + degree + 1
+    {
+        panic!("The number of knots has to be the number of coefficients plus the degree plus one");
+    }
+    if knots.windows(2).any(|w| w[0] > w[1])
+    {
+        panic!("The knots are decreasing");
+    }
+    if knots.windows(degree + 2).any(|w| w[0] == w[degree + 1])
+    {
+        panic!("The multiplicity of a knot exceeds the degree plus one");
+    }
+    if knots[degree] == knots[n]
+    {
+        panic!("The domain is empty");
+    }
+}
+
+/// Index k of the knot span $[t_k, t_{k+1})$ with $p \leq k < n$, which
+/// contains x. Points outside of the domain are assigned to the first,
+/// respectively the last span.
+pub(crate) fn span<T>(degree: usize, knots: &[T], x: T) -> usize
+    where T: Real
+{
+    let n: usize = knots.len() - degree - 1;
+    // number of knots t_k <= x
+    let k: usize = knots.partition_point(|t_k| *t_k <= x);
+    let mut k: usize = k.saturating_sub(1).clamp(degree, n - 1);
+    // the last span is closed, skip empty spans at the end of the domain
+    while k > degree && knots[k] == knots[k + 1]
+    {
+        k -= 1;
+    }
+
+    k
+}
+
+/// Values and derivatives up to the given order of the p + 1 basis functions
+/// $N_{k-p,p}, \dots, N_{k,p}$, which are nonzero in the knot span k
+///
+/// Piegl, L., Tiller, W.: The NURBS Book, Algorithm A2.3, Springer, 1997
+pub(crate) fn basis_derivatives<T>(degree: usize, knots: &[T], span: usize, x: T, order: usize) -> Vec<Vec<T>>
+    where T: Real
+{
+    let p: usize = degree;
+    let mut ndu: Vec<Vec<T>> = vec![vec![T::zero(); p + 1]; p + 1];
+    let mut left: Vec<T> = vec![T::zero(); p + 1];
+    let mut right: Vec<T> = vec![T::zero(); p + 1];
+
+    ndu[0][0] = T::one();
+    for j in 1..=p
+    {
+        left[j] = x - knots[span + 1 - j];
+        right[j] = knots[span + j] - x;
+        let mut saved: T = T::zero();
+        for r in 0..j
+        {
+            // lower triangle
+            ndu[j][r] = right[r + 1] + left[j - r];
+            let temp: T = ndu[r][j - 1] / ndu[j][r];
+            // upper triangle
+            ndu[r][j] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        ndu[j][j] = saved;
+    }
+
+    let mut ders: Vec<Vec<T>> = vec![vec![T::zero(); p + 1]; order + 1];
+    for j in 0..=p
+    {
+        ders[0][j] = ndu[j][p];
+    }
+
+    let n: usize = order.min(p);
+    let p_i: isize = p as isize;
+    let mut a: [Vec<T>; 2] = [vec![T::zero(); p + 1], vec![T::zero(); p + 1]];
+    for r in 0..=p_i
+    {
+        let (mut s_1, mut s_2): (usize, usize) = (0, 1);
+        a[0][0] = T::one();
+        for k in 1..=(n as isize)
+        {
+            let mut d: T = T::zero();
+            let r_k: isize = r - k;
+            let p_k: isize = p_i - k;
+            if r >= k
+            {
+                a[s_2][0] = a[s_1][0] / ndu[(p_k + 1) as usize][r_k as usize];
+                d = a[s_2][0] * ndu[r_k as usize][p_k as usize];
+            }
+            let j_1: isize = if r_k >= -1 { 1 } else { -r_k };
+            let j_2: isize = if r - 1 <= p_k { k - 1 } else { p_i - r };
+            for j in j_1..=j_2
+            {
+                let j_u: usize = j as usize;
+                a[s_2][j_u] = (a[s_1][j_u] - a[s_1][j_u - 1]) / ndu[(p_k + 1) as usize][(r_k + j) as usize];
+                d += a[s_2][j_u] * ndu[(r_k + j) as usize][p_k as usize];
+            }
+            if r <= p_k
+            {
+                a[s_2][k as usize] = -a[s_1][(k - 1) as usize] / ndu[(p_k + 1) as usize][r as usize];
+                d += a[s_2][k as usize] * ndu[r as usize][p_k as usize];
+            }
+            ders[k as usize][r as usize] = d;
+            std::mem::swap(&mut s_1, &mut s_2);
+        }
+    }
+
+    let mut factor: T = T::from_f64(p as f64);
+    for (k, ders_k) in ders.iter_mut().enumerate().take(n + 1).skip(1)
+    {
+        for d in ders_k.iter_mut()
+        {
+            *d *= factor;
+        }
+        factor *= T::from_f64((p - k) as f64);
+    }
+
+    ders
+}
@@ -0,0 +1,60 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::piecewise_cubic::{check_nodes, PiecewiseCubic};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Piecewise cubic Hermite interpolation
+///
+/// Interpolates the values $y_i$ and the derivatives $y^{'}_i$ at the nodes
+/// $x_i$. With the right-hand side of an ODE as derivatives, the
+/// interpolant is a dense output of the solution, whose error is of
+/// order four.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{CubicHermite, Interpolant};
+///
+/// let hermite: CubicHermite<f64> = CubicHermite::new(vec![0.0, 1.0], vec![0.0, 1.0], vec![0.0, 3.0]);
+///
+/// assert_relative_eq!(hermite.eval(0.5), 0.125);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct CubicHermite<T>
+{
+    pp: PiecewiseCubic<T>,
+}
+
+impl<T> CubicHermite<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    /// * 'dydx': derivatives at the nodes
+    ///
+    /// # Panics
+    ///
+    /// if less than two nodes are given, the number of nodes, values and
+    /// derivatives differ or the nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>, dydx: Vec<T>) -> CubicHermite<T>
+    {
+        check_nodes(&x, &y, 2);
+        if dydx.len() != x.len()
+        {
+            panic!("The number of nodes and derivatives differ");
+        }
+
+        CubicHermite { pp: PiecewiseCubic::hermite(x, &y, &dydx) }
+    }
+}
+
+impl_piecewise_cubic!(CubicHermite);
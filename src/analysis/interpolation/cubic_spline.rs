@@ -0,0 +1,153 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::piecewise_cubic::{check_nodes, PiecewiseCubic};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// End conditions of a cubic spline
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum SplineBoundary<T>
+{
+    /// $s^{''}(x_0) = s^{''}(x_n) = 0$
+    #[default]
+    Natural,
+    /// Prescribed first derivatives $s^{'}(x_0)$ and $s^{'}(x_n)$
+    Clamped(T, T),
+    /// The third derivative is continuous at $x_1$ and $x_{n-1}$
+    NotAKnot,
+}
+
+/// Cubic spline interpolation
+///
+/// Twice continuously differentiable piecewise cubic interpolant. The slopes
+/// $s_i$ at the nodes are the solution of the tridiagonal system
+/// ```math
+/// h_i s_{i-1} + 2(h_{i-1} + h_i)s_i + h_{i-1}s_{i+1} = 3(h_i\delta_{i-1} + h_{i-1}\delta_i), \quad i = 1, \dots, n - 1
+/// ```
+/// with $h_i = x_{i+1} - x_i$ and $\delta_i = \frac{y_{i+1} - y_i}{h_i}$,
+/// completed by the end conditions.
+///
+/// de Boor, C.: A Practical Guide to Splines, Springer, 1978
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{CubicSpline, Interpolant, SplineBoundary};
+///
+/// let x: Vec<f64> = (0..=10).map(|i| i as f64 / 10.0).collect();
+/// let y: Vec<f64> = x.iter().map(|x_i| x_i.exp()).collect();
+///
+/// let spline: CubicSpline<f64> = CubicSpline::new(x, y, SplineBoundary::Clamped(1.0, 1.0f64.exp()));
+///
+/// assert_relative_eq!(spline.eval(0.55), 0.55f64.exp(), epsilon = 1.0e-6);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct CubicSpline<T>
+{
+    pp: PiecewiseCubic<T>,
+}
+
+impl<T> CubicSpline<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    /// * 'boundary': end conditions
+    ///
+    /// # Panics
+    ///
+    /// if less than two nodes, or less than four nodes for the not-a-knot
+    /// condition, are given, the number of nodes and values differ or the
+    /// nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>, boundary: SplineBoundary<T>) -> CubicSpline<T>
+    {
+        check_nodes(&x, &y, if boundary == SplineBoundary::NotAKnot { 4 } else { 2 });
+
+        let n: usize = x.len();
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+        let h: Vec<T> = x.windows(2).map(|w| w[1] - w[0]).collect();
+        let delta: Vec<T> = (0..(n - 1)).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+        // tridiagonal system, sub diagonal l, diagonal d, super diagonal u
+        let mut l: Vec<T> = vec![T::zero(); n];
+        let mut d: Vec<T> = vec![T::zero(); n];
+        let mut u: Vec<T> = vec![T::zero(); n];
+        let mut r: Vec<T> = vec![T::zero(); n];
+
+        for i in 1..(n - 1)
+        {
+            l[i] = h[i];
+            d[i] = two * (h[i - 1] + h[i]);
+            u[i] = h[i - 1];
+            r[i] = three * (h[i] * delta[i - 1] + h[i - 1] * delta[i]);
+        }
+
+        match boundary
+        {
+            SplineBoundary::Natural =>
+            {
+                d[0] = two;
+                u[0] = T::one();
+                r[0] = three * delta[0];
+                l[n - 1] = T::one();
+                d[n - 1] = two;
+                r[n - 1] = three * delta[n - 2];
+            }
+            SplineBoundary::Clamped(dydx_0, dydx_n) =>
+            {
+                d[0] = T::one();
+                r[0] = dydx_0;
+                d[n - 1] = T::one();
+                r[n - 1] = dydx_n;
+            }
+            SplineBoundary::NotAKnot =>
+            {
+                let s: T = h[0] + h[1];
+                d[0] = h[1];
+                u[0] = s;
+                r[0] = ((h[0] + two * s) * h[1] * delta[0] + h[0] * h[0] * delta[1]) / s;
+
+                let s: T = h[n - 3] + h[n - 2];
+                l[n - 1] = s;
+                d[n - 1] = h[n - 3];
+                r[n - 1] = (h[n - 2] * h[n - 2] * delta[n - 3] + (two * s + h[n - 2]) * h[n - 3] * delta[n - 2]) / s;
+            }
+        }
+
+        let dydx: Vec<T> = CubicSpline::solve_tridiagonal(l, d, u, r);
+
+        CubicSpline { pp: PiecewiseCubic::hermite(x, &y, &dydx) }
+    }
+
+    /// Solves the tridiagonal system with the Thomas algorithm
+    fn solve_tridiagonal(l: Vec<T>, mut d: Vec<T>, u: Vec<T>, mut r: Vec<T>) -> Vec<T>
+    {
+        let n: usize = d.len();
+        for i in 1..n
+        {
+            let m: T = l[i] / d[i - 1];
+            d[i] -= m * u[i - 1];
+            r[i] = r[i] - m * r[i - 1];
+        }
+
+        r[n - 1] /= d[n - 1];
+        for i in (0..(n - 1)).rev()
+        {
+            r[i] = (r[i] - u[i] * r[i + 1]) / d[i];
+        }
+
+        r
+    }
+}
+
+impl_piecewise_cubic!(CubicSpline);
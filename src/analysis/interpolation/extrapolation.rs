@@ -0,0 +1,117 @@
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Policy, which defines an interpolant outside of its domain $[x_0, x_n]$
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Extrapolation
+{
+    /// Continues the polynomial of the first, respectively the last interval
+    #[default]
+    Extrapolate,
+    /// Continues with the value at the nearest bound
+    Constant,
+    /// Repeats the interpolant with the period $x_n - x_0$
+    Periodic,
+    /// Returns NaN
+    Nan,
+}
+
+impl Extrapolation
+{
+    /// Maps x to the point, at which the interpolant is evaluated, None if
+    /// the result is NaN
+    pub(crate) fn map<T>(&self, x: T, (x_0, x_n): (T, T)) -> Option<T>
+        where T: Real
+    {
+        if x_0 <= x && x <= x_n
+        {
+            return Some(x);
+        }
+
+        match self
+        {
+            Extrapolation::Extrapolate => Some(x),
+            Extrapolation::Constant => Some(if x < x_0 { x_0 } else { x_n }),
+            Extrapolation::Periodic =>
+            {
+                let period: T = x_n - x_0;
+                Some(x - ((x - x_0) / period).floor() * period)
+            }
+            Extrapolation::Nan => None,
+        }
+    }
+
+    /// Evaluates the interpolant with the policy
+    pub(crate) fn eval<T, F>(&self, f: F, x: T, domain: (T, T)) -> T
+        where T: Real,
+              F: Fn(T) -> T
+    {
+        match self.map(x, domain)
+        {
+            Some(x) => f(x),
+            None => T::from_f64(f64::NAN),
+        }
+    }
+
+    /// Evaluates the derivative of the interpolant with the policy
+    pub(crate) fn derivative<T, F>(&self, df: F, x: T, domain: (T, T)) -> T
+        where T: Real,
+              F: Fn(T) -> T
+    {
+        if *self == Extrapolation::Constant && (x < domain.0 || x > domain.1)
+        {
+            return T::zero();
+        }
+        self.eval(df, x, domain)
+    }
+
+    /// Integrates the interpolant with the policy
+    ///
+    /// # Arguments
+    ///
+    /// * 'antiderivative': antiderivative G with $G(x_0) = 0$ of the
+    ///   polynomial pieces, evaluated outside of the domain with the
+    ///   polynomials of the first and the last interval
+    /// * 'f': interpolant
+    pub(crate) fn integrate<T, G, F>(&self, antiderivative: G, f: F, a: T, b: T, domain: (T, T)) -> T
+        where T: Real,
+              G: Fn(T) -> T,
+              F: Fn(T) -> T
+    {
+        let (x_0, x_n): (T, T) = domain;
+        let g = |x: T| -> T {
+            if x_0 <= x && x <= x_n
+            {
+                return antiderivative(x);
+            }
+
+            match self
+            {
+                Extrapolation::Extrapolate => antiderivative(x),
+                Extrapolation::Constant =>
+                {
+                    if x < x_0
+                    {
+                        f(x_0) * (x - x_0)
+                    }
+                    else
+                    {
+                        antiderivative(x_n) + f(x_n) * (x - x_n)
+                    }
+                }
+                Extrapolation::Periodic =>
+                {
+                    let period: T = x_n - x_0;
+                    let k: T = ((x - x_0) / period).floor();
+                    k * antiderivative(x_n) + antiderivative(x - k * period)
+                }
+                Extrapolation::Nan => T::from_f64(f64::NAN),
+            }
+        };
+
+        g(b) - g(a)
+    }
+}
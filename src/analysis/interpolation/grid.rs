@@ -0,0 +1,53 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::Extrapolation;
+
+/// Position of a coordinate relative to the nodes of a grid axis
+pub(crate) struct AxisPosition<T>
+{
+    /// Index of the cell $[x_i, x_{i+1}]$
+    pub i: usize,
+    /// Local coordinate $t = \frac{x - x_i}{x_{i+1} - x_i}$
+    pub t: T,
+    /// Width of the cell
+    pub h: T,
+    /// The interpolant is constant along the axis at the coordinate
+    pub constant: bool,
+}
+
+/// Checks the nodes of a grid axis
+///
+/// # Panics
+///
+/// if less than two nodes are given or the nodes are not strictly increasing
+pub(crate) fn check_axis<T>(axis: &[T])
+    where T: Real
+{
+    if axis.len() < 2
+    {
+        panic!("At least 2 nodes per axis are required");
+    }
+    if axis.windows(2).any(|w| w[0] >= w[1])
+    {
+        panic!("The nodes are not strictly increasing");
+    }
+}
+
+/// Locates x on the axis after applying the extrapolation policy, None if
+/// the interpolant is NaN at x
+pub(crate) fn locate<T>(axis: &[T], x: T, extrapolation: Extrapolation) -> Option<AxisPosition<T>>
+    where T: Real
+{
+    let n: usize = axis.len();
+    let domain: (T, T) = (axis[0], axis[n - 1]);
+    let x_mapped: T = extrapolation.map(x, domain)?;
+
+    // number of nodes x_k <= x
+    let k: usize = axis.partition_point(|x_k| *x_k <= x_mapped);
+    let i: usize = k.saturating_sub(1).min(n - 2);
+    let h: T = axis[i + 1] - axis[i];
+
+    Some(AxisPosition { i,
+                        t: (x_mapped - axis[i]) / h,
+                        h,
+                        constant: extrapolation == Extrapolation::Constant && (x < domain.0 || x > domain.1) })
+}
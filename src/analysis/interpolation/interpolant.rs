@@ -0,0 +1,26 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::Extrapolation;
+
+/// Function, which interpolates tabulated data
+///
+/// Outside of the interpolation domain, the values are defined by the
+/// [`Extrapolation`] policy of the interpolant.
+pub trait Interpolant<T>
+    where T: Real
+{
+    /// Evaluates the interpolant at x
+    fn eval(&self, x: T) -> T;
+
+    /// Evaluates the first derivative of the interpolant at x
+    fn derivative(&self, x: T) -> T;
+
+    /// Integrates the interpolant from the lower bound a to the upper bound b
+    fn integrate(&self, a: T, b: T) -> T;
+
+    /// Interval $[x_0, x_n]$ spanned by the nodes
+    fn domain(&self) -> (T, T);
+
+    fn set_extrapolation(&mut self, extrapolation: Extrapolation);
+
+    fn get_extrapolation(&self) -> Extrapolation;
+}
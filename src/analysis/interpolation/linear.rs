@@ -0,0 +1,54 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::piecewise_cubic::{check_nodes, PiecewiseCubic};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Piecewise linear interpolation
+///
+/// ```math
+/// p(x) = y_i + \frac{y_{i+1} - y_i}{x_{i+1} - x_i}(x - x_i), \quad x_i \leq x \leq x_{i+1}
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use mathru::analysis::interpolation::{Interpolant, Linear};
+///
+/// let linear: Linear<f64> = Linear::new(vec![0.0, 1.0, 3.0], vec![0.0, 2.0, 3.0]);
+///
+/// assert_eq!(linear.eval(2.0), 2.5);
+/// assert_eq!(linear.derivative(0.5), 2.0);
+/// assert_eq!(linear.integrate(0.0, 3.0), 6.0);
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Linear<T>
+{
+    pp: PiecewiseCubic<T>,
+}
+
+impl<T> Linear<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    ///
+    /// # Panics
+    ///
+    /// if less than two nodes are given, the number of nodes and values
+    /// differ or the nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Linear<T>
+    {
+        check_nodes(&x, &y, 2);
+
+        let coef: Vec<[T; 4]> = (0..(x.len() - 1)).map(|i| [y[i], (y[i + 1] - y[i]) / (x[i + 1] - x[i]), T::zero(), T::zero()])
+                                                  .collect();
+
+        Linear { pp: PiecewiseCubic::new(x, coef) }
+    }
+}
+
+impl_piecewise_cubic!(Linear);
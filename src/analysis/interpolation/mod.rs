@@ -0,0 +1,55 @@
+//! Interpolation
+//!
+//! Fore more information: <br>
+//! <https://en.wikipedia.org/wiki/Interpolation>
+//!
+//! | Interpolant | Continuity | Properties |
+//! |-------------|------------|------------|
+//! | [`Linear`] | $C^0$ | monotone |
+//! | [`CubicSpline`] | $C^2$ | natural, clamped or not-a-knot end conditions |
+//! | [`CubicHermite`] | $C^1$ | given derivatives, e.g. dense output of ODE solvers |
+//! | [`Pchip`] | $C^1$ | monotone, no overshoot |
+//! | [`Akima`] | $C^1$ | little overshoot near outliers |
+//! | [`BarycentricLagrange`] | $C^\infty$ | polynomial, Chebyshev points |
+//!
+//! Data on rectilinear grids in two dimensions is interpolated with
+//! [`Bilinear`] and [`Bicubic`], in N dimensions with [`RegularGrid`].
+//! Scattered data is interpolated with radial basis functions [`Rbf`].
+//!
+//! [`BSpline`] represents splines in the B-spline basis and fits them by
+//! least squares, [`SmoothingSpline`] smooths noisy data and [`Nurbs`]
+//! evaluates rational B-spline curves.
+#[macro_use]
+mod piecewise_cubic;
+mod interpolant;
+mod extrapolation;
+mod linear;
+mod cubic_spline;
+mod cubic_hermite;
+mod pchip;
+mod akima;
+mod barycentric_lagrange;
+mod grid;
+mod bilinear;
+mod bicubic;
+mod regular_grid;
+mod rbf;
+mod bspline;
+mod smoothing_spline;
+mod nurbs;
+
+pub use interpolant::Interpolant;
+pub use extrapolation::Extrapolation;
+pub use linear::Linear;
+pub use cubic_spline::{CubicSpline, SplineBoundary};
+pub use cubic_hermite::CubicHermite;
+pub use pchip::Pchip;
+pub use akima::Akima;
+pub use barycentric_lagrange::BarycentricLagrange;
+pub use bilinear::Bilinear;
+pub use bicubic::Bicubic;
+pub use regular_grid::{GridMethod, RegularGrid};
+pub use rbf::{RadialBasisFunction, Rbf};
+pub use bspline::BSpline;
+pub use smoothing_spline::SmoothingSpline;
+pub use nurbs::Nurbs;
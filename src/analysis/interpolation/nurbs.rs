@@ -0,0 +1,143 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::interpolation::bspline::{basis_derivatives, check_knots, span};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Non-uniform rational B-spline (NURBS) curve
+///
+/// ```math
+/// C(u) = \frac{\sum_{i=0}^{n-1}N_{i,p}(u)w_i P_i}{\sum_{i=0}^{n-1}N_{i,p}(u)w_i}
+/// ```
+/// with the control points $P_i$, the positive weights $w_i$ and the
+/// B-spline basis functions $N_{i,p}$ of degree p on the knots
+/// $t_0, \dots, t_{n+p}$. Unlike polynomial B-splines, NURBS represent
+/// conic sections like circles exactly.
+///
+/// Piegl, L., Tiller, W.: The NURBS Book, Springer, 1997
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Vector, analysis::interpolation::Nurbs};
+///
+/// // quarter of the unit circle
+/// let nurbs: Nurbs<f64> = Nurbs::new(2,
+///                                    vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0],
+///                                    vec![vector![1.0; 0.0], vector![1.0; 1.0], vector![0.0; 1.0]],
+///                                    vec![1.0, 0.5f64.sqrt(), 1.0]);
+///
+/// let c: Vector<f64> = nurbs.eval(0.3);
+///
+/// assert_relative_eq!(c[0] * c[0] + c[1] * c[1], 1.0, epsilon = 1.0e-15);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Nurbs<T>
+{
+    degree: usize,
+    knots: Vec<T>,
+    control_points: Vec<Vector<T>>,
+    weights: Vec<T>,
+}
+
+impl<T> Nurbs<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'degree': degree p
+    /// * 'knots': non-decreasing knots $t_0, \dots, t_{n+p}$
+    /// * 'control_points': control points $P_0, \dots, P_{n-1}$ of the same
+    ///   dimension
+    /// * 'weights': positive weights $w_0, \dots, w_{n-1}$
+    ///
+    /// # Panics
+    ///
+    /// if the knots are not valid for the number of control points, the
+    /// number of control points and weights differ, the dimensions of the
+    /// control points differ or a weight is not positive
+    pub fn new(degree: usize, knots: Vec<T>, control_points: Vec<Vector<T>>, weights: Vec<T>) -> Nurbs<T>
+    {
+        check_knots(degree, &knots, control_points.len());
+        if weights.len() != control_points.len()
+        {
+            panic!("The number of control points and weights differ");
+        }
+        let d: usize = control_points[0].dim().0;
+        if control_points.iter().any(|p| p.dim().0 != d)
+        {
+            panic!("The dimensions of the control points differ");
+        }
+        if weights.iter().any(|w| *w <= T::zero())
+        {
+            panic!("The weights have to be positive");
+        }
+
+        Nurbs { degree,
+                knots,
+                control_points,
+                weights }
+    }
+
+    /// Interval $[t_p, t_n]$ of the curve parameter
+    pub fn domain(&self) -> (T, T)
+    {
+        (self.knots[self.degree], self.knots[self.control_points.len()])
+    }
+
+    /// Weighted sums $A^{(k)}(u) = \sum_i N_{i,p}^{(k)}(u)w_i P_i$ and
+    /// $w^{(k)}(u) = \sum_i N_{i,p}^{(k)}(u)w_i$ for k = 0, ..., order
+    fn homogeneous(&self, u: T, order: usize) -> Vec<(Vector<T>, T)>
+    {
+        let p: usize = self.degree;
+        let d: usize = self.control_points[0].dim().0;
+        let k: usize = span(p, &self.knots, u);
+        let ders: Vec<Vec<T>> = basis_derivatives(p, &self.knots, k, u, order);
+
+        ders.iter()
+            .map(|n| {
+                let mut a: Vector<T> = Vector::zero(d);
+                let mut w: T = T::zero();
+                for (j, n_j) in n.iter().enumerate()
+                {
+                    let i: usize = k - p + j;
+                    let c: T = *n_j * self.weights[i];
+                    for l in 0..d
+                    {
+                        a[l] += c * self.control_points[i][l];
+                    }
+                    w += c;
+                }
+                (a, w)
+            })
+            .collect()
+    }
+
+    /// Evaluates the curve at the parameter u
+    pub fn eval(&self, u: T) -> Vector<T>
+    {
+        let (a, w): (Vector<T>, T) = self.homogeneous(u, 0).swap_remove(0);
+        a * (T::one() / w)
+    }
+
+    /// Evaluates the first derivative of the curve with respect to the
+    /// parameter u
+    /// ```math
+    /// C^{'}(u) = \frac{A^{'}(u) - w^{'}(u)C(u)}{w(u)}
+    /// ```
+    pub fn derivative(&self, u: T) -> Vector<T>
+    {
+        let h: Vec<(Vector<T>, T)> = self.homogeneous(u, 1);
+        let (a, w): (Vector<T>, T) = h[0].clone();
+        let (da, dw): (Vector<T>, T) = h[1].clone();
+        let c: Vector<T> = a * (T::one() / w);
+
+        (da - c * dw) * (T::one() / w)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::piecewise_cubic::{check_nodes, PiecewiseCubic};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Piecewise cubic Hermite interpolating polynomial (PCHIP)
+///
+/// Shape preserving interpolation: the interpolant is monotone on every
+/// interval, on which the data is monotone, and has no overshoot at local
+/// extrema. The slopes are the weighted harmonic means of the adjacent
+/// secants
+/// ```math
+/// \frac{w_1 + w_2}{s_i} = \frac{w_1}{\delta_{i-1}} + \frac{w_2}{\delta_i}, \quad w_1 = 2h_i + h_{i-1}, \quad w_2 = h_i + 2h_{i-1}
+/// ```
+/// if the secants have the same sign, otherwise zero.
+///
+/// Fritsch, F. N., Carlson, R. E.: Monotone Piecewise Cubic Interpolation,
+/// SIAM Journal on Numerical Analysis, 17, 1980
+///
+/// # Example
+///
+/// ```
+/// use mathru::analysis::interpolation::{Interpolant, Pchip};
+///
+/// let pchip: Pchip<f64> = Pchip::new(vec![0.0, 1.0, 2.0, 3.0], vec![0.0, 0.0, 1.0, 1.0]);
+///
+/// assert!((0..=30).all(|i| {
+///     let y: f64 = pchip.eval(i as f64 / 10.0);
+///     0.0 <= y && y <= 1.0
+/// }));
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Pchip<T>
+{
+    pp: PiecewiseCubic<T>,
+}
+
+impl<T> Pchip<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'y': values at the nodes
+    ///
+    /// # Panics
+    ///
+    /// if less than two nodes are given, the number of nodes and values
+    /// differ or the nodes are not strictly increasing
+    pub fn new(x: Vec<T>, y: Vec<T>) -> Pchip<T>
+    {
+        check_nodes(&x, &y, 2);
+
+        let n: usize = x.len();
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+        let h: Vec<T> = x.windows(2).map(|w| w[1] - w[0]).collect();
+        let delta: Vec<T> = (0..(n - 1)).map(|i| (y[i + 1] - y[i]) / h[i]).collect();
+
+        let mut dydx: Vec<T> = vec![T::zero(); n];
+        if n == 2
+        {
+            dydx[0] = delta[0];
+            dydx[1] = delta[0];
+            return Pchip { pp: PiecewiseCubic::hermite(x, &y, &dydx) };
+        }
+
+        for i in 1..(n - 1)
+        {
+            if delta[i - 1] * delta[i] > T::zero()
+            {
+                let w_1: T = two * h[i] + h[i - 1];
+                let w_2: T = h[i] + two * h[i - 1];
+                dydx[i] = (w_1 + w_2) / (w_1 / delta[i - 1] + w_2 / delta[i]);
+            }
+        }
+
+        // one-sided three-point formulas, which preserve the shape
+        let end_slope = |h_0: T, h_1: T, delta_0: T, delta_1: T| -> T {
+            let s: T = ((two * h_0 + h_1) * delta_0 - h_0 * delta_1) / (h_0 + h_1);
+            if s * delta_0 <= T::zero()
+            {
+                T::zero()
+            }
+            else if delta_0 * delta_1 < T::zero() && s.abs() > (three * delta_0).abs()
+            {
+                three * delta_0
+            }
+            else
+            {
+                s
+            }
+        };
+        dydx[0] = end_slope(h[0], h[1], delta[0], delta[1]);
+        dydx[n - 1] = end_slope(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+
+        Pchip { pp: PiecewiseCubic::hermite(x, &y, &dydx) }
+    }
+}
+
+impl_piecewise_cubic!(Pchip);
@@ -0,0 +1,188 @@
+use crate::algebra::abstr::Real;
+use crate::analysis::interpolation::Extrapolation;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Piecewise cubic polynomial
+///
+/// ```math
+/// p(x) = a_i + b_i(x - x_i) + c_i(x - x_i)^2 + d_i(x - x_i)^3, \quad x_i \leq x \leq x_{i+1}
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub(crate) struct PiecewiseCubic<T>
+{
+    x: Vec<T>,
+    /// Coefficients (a_i, b_i, c_i, d_i) of the intervals
+    coef: Vec<[T; 4]>,
+    /// Integrals from x_0 to x_i
+    cumulative: Vec<T>,
+    pub(crate) extrapolation: Extrapolation,
+}
+
+impl<T> PiecewiseCubic<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing nodes
+    /// * 'coef': coefficients of the intervals between the nodes
+    pub fn new(x: Vec<T>, coef: Vec<[T; 4]>) -> PiecewiseCubic<T>
+    {
+        let mut cumulative: Vec<T> = Vec::with_capacity(x.len());
+        cumulative.push(T::zero());
+        for (i, coef_i) in coef.iter().enumerate()
+        {
+            let integral: T = PiecewiseCubic::antiderivative_local(coef_i, x[i + 1] - x[i]);
+            cumulative.push(cumulative[i] + integral);
+        }
+
+        PiecewiseCubic { x,
+                         coef,
+                         cumulative,
+                         extrapolation: Extrapolation::default() }
+    }
+
+    /// Cubic Hermite interpolant with the values y and the derivatives dydx
+    /// at the nodes x
+    pub fn hermite(x: Vec<T>, y: &[T], dydx: &[T]) -> PiecewiseCubic<T>
+    {
+        let coef: Vec<[T; 4]> = (0..(x.len() - 1)).map(|i| {
+                                                      let h: T = x[i + 1] - x[i];
+                                                      let delta: T = (y[i + 1] - y[i]) / h;
+                                                      let c: T = (T::from_f64(3.0) * delta - T::from_f64(2.0) * dydx[i] - dydx[i + 1]) / h;
+                                                      let d: T = (dydx[i] + dydx[i + 1] - T::from_f64(2.0) * delta) / (h * h);
+                                                      [y[i], dydx[i], c, d]
+                                                  })
+                                                  .collect();
+
+        PiecewiseCubic::new(x, coef)
+    }
+
+    pub fn domain(&self) -> (T, T)
+    {
+        (self.x[0], self.x[self.x.len() - 1])
+    }
+
+    /// Index of the interval, which contains x. Points outside of the
+    /// domain are assigned to the first, respectively the last interval.
+    fn interval(&self, x: T) -> usize
+    {
+        let n: usize = self.coef.len();
+        // number of nodes x_k <= x
+        let k: usize = self.x.partition_point(|x_k| *x_k <= x);
+        k.saturating_sub(1).min(n - 1)
+    }
+
+    fn antiderivative_local(coef: &[T; 4], s: T) -> T
+    {
+        let [a, b, c, d] = *coef;
+        s * (a + s * (b / T::from_f64(2.0) + s * (c / T::from_f64(3.0) + s * d / T::from_f64(4.0))))
+    }
+
+    fn eval_polynomial(&self, x: T) -> T
+    {
+        let i: usize = self.interval(x);
+        let s: T = x - self.x[i];
+        let [a, b, c, d] = self.coef[i];
+        a + s * (b + s * (c + s * d))
+    }
+
+    fn derivative_polynomial(&self, x: T) -> T
+    {
+        let i: usize = self.interval(x);
+        let s: T = x - self.x[i];
+        let [_a, b, c, d] = self.coef[i];
+        b + s * (T::from_f64(2.0) * c + s * T::from_f64(3.0) * d)
+    }
+
+    fn antiderivative(&self, x: T) -> T
+    {
+        let i: usize = self.interval(x);
+        self.cumulative[i] + PiecewiseCubic::antiderivative_local(&self.coef[i], x - self.x[i])
+    }
+
+    pub fn eval(&self, x: T) -> T
+    {
+        self.extrapolation.eval(|x| self.eval_polynomial(x), x, self.domain())
+    }
+
+    pub fn derivative(&self, x: T) -> T
+    {
+        self.extrapolation.derivative(|x| self.derivative_polynomial(x), x, self.domain())
+    }
+
+    pub fn integrate(&self, a: T, b: T) -> T
+    {
+        self.extrapolation.integrate(|x| self.antiderivative(x),
+                                     |x| self.eval_polynomial(x),
+                                     a,
+                                     b,
+                                     self.domain())
+    }
+}
+
+/// Checks the nodes and the values of an interpolant
+///
+/// # Panics
+///
+/// if less than min nodes are given, the number of nodes and values differ
+/// or the nodes are not strictly increasing
+pub(crate) fn check_nodes<T>(x: &[T], y: &[T], min: usize)
+    where T: Real
+{
+    if x.len() < min
+    {
+        panic!("At least {} nodes are required", min);
+    }
+    if x.len() != y.len()
+    {
+        panic!("The number of nodes and values differ");
+    }
+    if x.windows(2).any(|w| w[0] >= w[1])
+    {
+        panic!("The nodes are not strictly increasing");
+    }
+}
+
+/// Implements [`Interpolant`](crate::analysis::interpolation::Interpolant)
+/// for an interpolant, which stores its piecewise cubic polynomial in the
+/// field `pp`
+macro_rules! impl_piecewise_cubic {
+    ($name:ident) => {
+        impl<T> crate::analysis::interpolation::Interpolant<T> for $name<T>
+            where T: crate::algebra::abstr::Real
+        {
+            fn eval(&self, x: T) -> T
+            {
+                self.pp.eval(x)
+            }
+
+            fn derivative(&self, x: T) -> T
+            {
+                self.pp.derivative(x)
+            }
+
+            fn integrate(&self, a: T, b: T) -> T
+            {
+                self.pp.integrate(a, b)
+            }
+
+            fn domain(&self) -> (T, T)
+            {
+                self.pp.domain()
+            }
+
+            fn set_extrapolation(&mut self, extrapolation: crate::analysis::interpolation::Extrapolation)
+            {
+                self.pp.extrapolation = extrapolation;
+            }
+
+            fn get_extrapolation(&self) -> crate::analysis::interpolation::Extrapolation
+            {
+                self.pp.extrapolation
+            }
+        }
+    };
+}
@@ -0,0 +1,219 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{matrix::Solve, Matrix, Vector},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Radial basis function $\phi(r)$ with the shape parameter $\epsilon$
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RadialBasisFunction<T>
+{
+    /// $\phi(r) = r^2 \ln r$
+    ThinPlate,
+    /// $\phi(r) = e^{-(\epsilon r)^2}$
+    Gaussian(T),
+    /// $\phi(r) = \sqrt{1 + (\epsilon r)^2}$
+    Multiquadric(T),
+    /// $\phi(r) = \frac{1}{\sqrt{1 + (\epsilon r)^2}}$
+    InverseMultiquadric(T),
+}
+
+impl<T> RadialBasisFunction<T>
+    where T: Real
+{
+    fn eval(&self, r: T) -> T
+    {
+        match *self
+        {
+            RadialBasisFunction::ThinPlate =>
+            {
+                if r == T::zero()
+                {
+                    T::zero()
+                }
+                else
+                {
+                    r * r * r.ln()
+                }
+            }
+            RadialBasisFunction::Gaussian(epsilon) => (-(epsilon * r) * (epsilon * r)).exp(),
+            RadialBasisFunction::Multiquadric(epsilon) => (T::one() + (epsilon * r) * (epsilon * r)).sqrt(),
+            RadialBasisFunction::InverseMultiquadric(epsilon) => T::one() / (T::one() + (epsilon * r) * (epsilon * r)).sqrt(),
+        }
+    }
+}
+
+/// Radial basis function interpolation of scattered data
+///
+/// The interpolant of the values $f_i$ at the points $x_i \in \mathbb{R}^d$
+/// is a linear combination of radial basis functions centered at the
+/// points, augmented with a linear polynomial
+/// ```math
+/// s(x) = \sum_{i=1}^{n}w_i\phi(\lVert x - x_i \rVert) + c_0 + \sum_{k=1}^{d}c_k x_k
+/// ```
+/// The coefficients are the solution of the dense linear system
+/// ```math
+/// \begin{pmatrix} \Phi + \lambda I & P \\ P^T & 0 \end{pmatrix}\begin{pmatrix} w \\ c \end{pmatrix} = \begin{pmatrix} f \\ 0 \end{pmatrix}
+/// ```
+/// with $\Phi_{ij} = \phi(\lVert x_i - x_j \rVert)$ and the rows
+/// $P_i = (1, x_i^T)$. The polynomial part makes the system uniquely
+/// solvable for the conditionally positive definite thin plate spline and
+/// multiquadric, and reproduces linear functions exactly. With a smoothing
+/// parameter $\lambda > 0$ the data is approximated instead of
+/// interpolated.
+///
+/// Fasshauer, G. E.: Meshfree Approximation Methods with Matlab, World
+/// Scientific, 2007
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::interpolation::{RadialBasisFunction, Rbf},
+/// };
+///
+/// let points: Vec<Vector<f64>> = vec![vector![0.0; 0.0], vector![1.0; 0.0], vector![0.0; 1.0], vector![1.0; 1.0], vector![0.4; 0.6]];
+/// let values: Vec<f64> = points.iter().map(|p| p[0] * p[1]).collect();
+///
+/// let rbf: Rbf<f64> = Rbf::new(points, values, RadialBasisFunction::ThinPlate);
+///
+/// assert_relative_eq!(rbf.eval(&vector![0.4; 0.6]), 0.24, epsilon = 1.0e-12);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Rbf<T>
+{
+    points: Vec<Vector<T>>,
+    kernel: RadialBasisFunction<T>,
+    weights: Vec<T>,
+    /// Coefficients $c_0, \dots, c_d$ of the linear polynomial
+    polynomial: Vec<T>,
+}
+
+impl<T> Rbf<T>
+    where T: Real
+{
+    /// Interpolant of the values at the points
+    ///
+    /// # Arguments
+    ///
+    /// * 'points': pairwise distinct points of the same dimension d
+    /// * 'values': values at the points
+    /// * 'kernel': radial basis function
+    ///
+    /// # Panics
+    ///
+    /// if less than d + 1 points are given, the number of points and values
+    /// differ, the dimensions of the points differ or the linear system is
+    /// singular, e.g. because points coincide or all points lie on a
+    /// hyperplane
+    pub fn new(points: Vec<Vector<T>>, values: Vec<T>, kernel: RadialBasisFunction<T>) -> Rbf<T>
+    {
+        Rbf::with_smoothing(points, values, kernel, T::zero())
+    }
+
+    /// Smoothing approximation of the values at the points
+    ///
+    /// # Arguments
+    ///
+    /// * 'points': points of the same dimension d
+    /// * 'values': values at the points
+    /// * 'kernel': radial basis function
+    /// * 'smoothing': smoothing parameter $\lambda \geq 0$
+    ///
+    /// # Panics
+    ///
+    /// if less than d + 1 points are given, the number of points and values
+    /// differ, the dimensions of the points differ, the smoothing parameter
+    /// is negative or the linear system is singular
+    pub fn with_smoothing(points: Vec<Vector<T>>, values: Vec<T>, kernel: RadialBasisFunction<T>, smoothing: T) -> Rbf<T>
+    {
+        let n: usize = points.len();
+        if n == 0 || n != values.len()
+        {
+            panic!("The number of points and values differ");
+        }
+        let d: usize = points[0].dim().0;
+        if points.iter().any(|p| p.dim().0 != d)
+        {
+            panic!("The dimensions of the points differ");
+        }
+        if n < d + 1
+        {
+            panic!("At least {} points are required", d + 1);
+        }
+        if smoothing < T::zero()
+        {
+            panic!("The smoothing parameter is negative");
+        }
+
+        let m: usize = n + d + 1;
+        let mut a: Matrix<T> = Matrix::zero(m, m);
+        let mut rhs: Vector<T> = Vector::zero(m);
+
+        for i in 0..n
+        {
+            for j in 0..n
+            {
+                a[[i, j]] = kernel.eval(Rbf::distance(&points[i], &points[j]));
+            }
+            a[[i, i]] += smoothing;
+
+            a[[i, n]] = T::one();
+            a[[n, i]] = T::one();
+            for k in 0..d
+            {
+                a[[i, n + 1 + k]] = points[i][k];
+                a[[n + 1 + k, i]] = points[i][k];
+            }
+            rhs[i] = values[i];
+        }
+
+        let solution: Vector<T> = match a.solve(&rhs)
+        {
+            Ok(s) => s,
+            Err(_) => panic!("The interpolation matrix is singular"),
+        };
+
+        Rbf { points,
+              kernel,
+              weights: (0..n).map(|i| solution[i]).collect(),
+              polynomial: (n..m).map(|i| solution[i]).collect() }
+    }
+
+    fn distance(x: &Vector<T>, y: &Vector<T>) -> T
+    {
+        (0..x.dim().0).fold(T::zero(), |s, k| s + (x[k] - y[k]) * (x[k] - y[k]))
+                      .sqrt()
+    }
+
+    /// Evaluates the interpolant at x
+    ///
+    /// # Panics
+    ///
+    /// if the dimension of x does not match the dimension of the points
+    pub fn eval(&self, x: &Vector<T>) -> T
+    {
+        let d: usize = self.polynomial.len() - 1;
+        if x.dim().0 != d
+        {
+            panic!("The dimension of the point does not match");
+        }
+
+        let radial: T = self.points
+                            .iter()
+                            .zip(self.weights.iter())
+                            .fold(T::zero(), |s, (p, w)| s + *w * self.kernel.eval(Rbf::distance(x, p)));
+
+        (0..d).fold(radial + self.polynomial[0], |s, k| s + self.polynomial[k + 1] * x[k])
+    }
+}
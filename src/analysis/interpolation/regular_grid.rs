@@ -0,0 +1,166 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+use crate::analysis::interpolation::{
+    grid::{check_axis, locate, AxisPosition},
+    Extrapolation,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Interpolation method on a regular grid
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridMethod
+{
+    /// Multilinear interpolation
+    #[default]
+    Linear,
+    /// Value at the nearest node
+    Nearest,
+}
+
+/// Interpolation on a rectilinear grid in N dimensions
+///
+/// The multilinear interpolant is, in the cell with the local coordinates
+/// $t_k \in [0, 1]$, the weighted sum over the $2^N$ corners c
+/// ```math
+/// p(x) = \sum_{c \in \{0, 1\}^N}f_{i + c}\prod_{k=1}^{N}\left(c_k t_k + (1 - c_k)(1 - t_k)\right)
+/// ```
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{algebra::linear::Vector, analysis::interpolation::RegularGrid};
+///
+/// let axes: Vec<Vec<f64>> = vec![vec![0.0, 1.0], vec![0.0, 1.0], vec![0.0, 2.0]];
+/// // f(x, y, z) = x + 2y + z, the last axis varies fastest
+/// let values: Vec<f64> = vec![0.0, 2.0, 2.0, 4.0, 1.0, 3.0, 3.0, 5.0];
+/// let grid: RegularGrid<f64> = RegularGrid::new(axes, values);
+///
+/// assert_relative_eq!(grid.eval(&vector![0.5; 0.25; 1.0]), 2.0);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct RegularGrid<T>
+{
+    axes: Vec<Vec<T>>,
+    values: Vec<T>,
+    method: GridMethod,
+    extrapolation: Extrapolation,
+}
+
+impl<T> RegularGrid<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'axes': strictly increasing nodes of every axis
+    /// * 'values': values at the grid points in row-major order, i.e. the
+    ///   index of the last axis varies fastest
+    ///
+    /// # Panics
+    ///
+    /// if no axis is given, an axis has less than two nodes, the nodes are
+    /// not strictly increasing or the number of values does not match the
+    /// number of grid points
+    pub fn new(axes: Vec<Vec<T>>, values: Vec<T>) -> RegularGrid<T>
+    {
+        if axes.is_empty()
+        {
+            panic!("At least one axis is required");
+        }
+        axes.iter().for_each(|axis| check_axis(axis));
+        if values.len() != axes.iter().map(|axis| axis.len()).product::<usize>()
+        {
+            panic!("The number of values does not match the grid");
+        }
+
+        RegularGrid { axes,
+                      values,
+                      method: GridMethod::default(),
+                      extrapolation: Extrapolation::default() }
+    }
+
+    pub fn set_method(&mut self, method: GridMethod)
+    {
+        self.method = method;
+    }
+
+    pub fn get_method(&self) -> GridMethod
+    {
+        self.method
+    }
+
+    pub fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.extrapolation = extrapolation;
+    }
+
+    pub fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.extrapolation
+    }
+
+    /// Evaluates the interpolant at x
+    ///
+    /// # Panics
+    ///
+    /// if the dimension of x does not match the number of axes
+    pub fn eval(&self, x: &Vector<T>) -> T
+    {
+        let d: usize = self.axes.len();
+        if x.dim().0 != d
+        {
+            panic!("The dimension of the point does not match the grid");
+        }
+
+        let mut positions: Vec<AxisPosition<T>> = Vec::with_capacity(d);
+        for (k, axis) in self.axes.iter().enumerate()
+        {
+            match locate(axis, x[k], self.extrapolation)
+            {
+                Some(p) => positions.push(p),
+                None => return T::from_f64(f64::NAN),
+            }
+        }
+
+        // offset of the grid point with the multi-index
+        let offset = |index: &dyn Fn(usize) -> usize| -> usize {
+            self.axes.iter().enumerate().fold(0, |o, (k, axis)| o * axis.len() + index(k))
+        };
+
+        match self.method
+        {
+            GridMethod::Nearest =>
+            {
+                let half: T = T::from_f64(0.5);
+                self.values[offset(&|k| if positions[k].t < half { positions[k].i } else { positions[k].i + 1 })]
+            }
+            GridMethod::Linear =>
+            {
+                let mut sum: T = T::zero();
+                for corner in 0..(1usize << d)
+                {
+                    let weight: T = positions.iter().enumerate().fold(T::one(), |w, (k, p)| {
+                                                                    if corner & (1 << k) == 0
+                                                                    {
+                                                                        w * (T::one() - p.t)
+                                                                    }
+                                                                    else
+                                                                    {
+                                                                        w * p.t
+                                                                    }
+                                                                });
+                    sum += weight * self.values[offset(&|k| positions[k].i + ((corner >> k) & 1))];
+                }
+
+                sum
+            }
+        }
+    }
+}
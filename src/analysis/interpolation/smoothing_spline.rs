@@ -0,0 +1,337 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{
+        matrix::{Inverse, Solve},
+        Matrix, Vector,
+    },
+};
+use crate::analysis::interpolation::{
+    bspline::{basis_derivatives, span},
+    BSpline, Extrapolation, Interpolant,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Search interval of $\log_{10}$ of the relative smoothing parameter
+const LOG_LAMBDA_MIN: f64 = -4.0;
+const LOG_LAMBDA_MAX: f64 = 8.0;
+/// Step of the coarse search
+const LOG_LAMBDA_STEP: f64 = 0.5;
+/// Number of golden section steps of the refinement
+const GOLDEN_SECTION_STEPS: usize = 30;
+
+/// Cubic smoothing spline
+///
+/// The smoothing spline is the minimizer of the penalized sum of squares
+/// ```math
+/// \sum_{i=1}^{m}\left(y_i - s(x_i)\right)^2 + \lambda\int_{x_1}^{x_m}s^{''}(x)^2\,dx
+/// ```
+/// which is a natural cubic spline with knots at the data points. In the
+/// cubic B-spline basis with the collocation matrix B and the penalty
+/// matrix $\Omega_{ij} = \int N_{i}^{''}N_{j}^{''}\,dx$, the coefficients are
+/// the solution of
+/// ```math
+/// (B^T B + \lambda\Omega)c = B^T y
+/// ```
+/// The smoothing parameter can be chosen by generalized cross-validation,
+/// which minimizes
+/// ```math
+/// GCV(\lambda) = \frac{m\sum_{i}\left(y_i - s(x_i)\right)^2}{\left(m - \operatorname{tr}H(\lambda)\right)^2}, \quad H(\lambda) = B(B^T B + \lambda\Omega)^{-1}B^T
+/// ```
+/// where $\operatorname{tr}H(\lambda)$ is the effective number of degrees
+/// of freedom. The linear systems are dense, the effort grows with the cube
+/// of the number of points.
+///
+/// Green, P. J., Silverman, B. W.: Nonparametric Regression and Generalized
+/// Linear Models, Chapman & Hall, 1994 <br>
+/// Craven, P., Wahba, G.: Smoothing noisy data with spline functions,
+/// Numerische Mathematik, 31, 1979
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::interpolation::{Interpolant, SmoothingSpline};
+///
+/// let x: Vec<f64> = (0..50).map(|i| i as f64 / 49.0).collect();
+/// // deterministic noise
+/// let y: Vec<f64> = x.iter()
+///                    .enumerate()
+///                    .map(|(i, x_i)| (3.0 * x_i).sin() + 0.05 * ((i * 7919 % 13) as f64 / 6.0 - 1.0))
+///                    .collect();
+///
+/// let spline: SmoothingSpline<f64> = SmoothingSpline::gcv(x, y);
+///
+/// assert!(spline.effective_degrees_of_freedom() < 30.0);
+/// assert_relative_eq!(spline.eval(0.5), 1.5f64.sin(), epsilon = 0.05);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct SmoothingSpline<T>
+{
+    spline: BSpline<T>,
+    lambda: T,
+    edf: T,
+    gcv: T,
+}
+
+/// Matrices of the penalized least squares problem
+struct System<T>
+{
+    gram: Matrix<T>,
+    penalty: Matrix<T>,
+    rhs: Vector<T>,
+    knots: Vec<T>,
+}
+
+impl<T> SmoothingSpline<T>
+    where T: Real
+{
+    /// Smoothing spline with the given smoothing parameter
+    ///
+    /// # Arguments
+    ///
+    /// * 'x': strictly increasing points
+    /// * 'y': values at the points
+    /// * 'lambda': smoothing parameter $\lambda > 0$, for
+    ///   $\lambda \rightarrow 0$ the spline approaches the interpolating
+    ///   natural cubic spline, for $\lambda \rightarrow \infty$ the least
+    ///   squares line
+    ///
+    /// # Panics
+    ///
+    /// if less than three points are given, the number of points and values
+    /// differ, the points are not strictly increasing or lambda is not
+    /// positive
+    pub fn new(x: Vec<T>, y: Vec<T>, lambda: T) -> SmoothingSpline<T>
+    {
+        if lambda <= T::zero()
+        {
+            panic!("The smoothing parameter is not positive");
+        }
+        let system: System<T> = SmoothingSpline::system(&x, &y);
+
+        SmoothingSpline::solve(&system, &x, &y, lambda)
+    }
+
+    /// Smoothing spline, whose smoothing parameter minimizes the generalized
+    /// cross-validation score
+    ///
+    /// The score is evaluated on a logarithmic grid of smoothing parameters
+    /// and the best one is refined with a golden section search.
+    ///
+    /// # Panics
+    ///
+    /// if less than three points are given, the number of points and values
+    /// differ or the points are not strictly increasing
+    pub fn gcv(x: Vec<T>, y: Vec<T>) -> SmoothingSpline<T>
+    {
+        let system: System<T> = SmoothingSpline::system(&x, &y);
+
+        // the smoothing parameter is relative to the scale of the matrices
+        let scale: T = system.gram.trace() / system.penalty.trace();
+        let fit = |log_lambda: T| -> SmoothingSpline<T> {
+            SmoothingSpline::solve(&system, &x, &y, scale * T::from_f64(10.0).pow(log_lambda))
+        };
+
+        let steps: usize = ((LOG_LAMBDA_MAX - LOG_LAMBDA_MIN) / LOG_LAMBDA_STEP) as usize;
+        let mut best: SmoothingSpline<T> = fit(T::from_f64(LOG_LAMBDA_MIN));
+        let mut best_log_lambda: T = T::from_f64(LOG_LAMBDA_MIN);
+        for i in 1..=steps
+        {
+            let log_lambda: T = T::from_f64(LOG_LAMBDA_MIN + i as f64 * LOG_LAMBDA_STEP);
+            let candidate: SmoothingSpline<T> = fit(log_lambda);
+            if candidate.gcv < best.gcv
+            {
+                best = candidate;
+                best_log_lambda = log_lambda;
+            }
+        }
+
+        // golden section search in the neighbourhood of the best grid point
+        let ratio: T = (T::from_f64(5.0).sqrt() - T::one()) / T::from_f64(2.0);
+        let step: T = T::from_f64(LOG_LAMBDA_STEP);
+        let (mut a, mut b): (T, T) = (best_log_lambda - step, best_log_lambda + step);
+        let mut c: T = b - ratio * (b - a);
+        let mut d: T = a + ratio * (b - a);
+        let mut fit_c: SmoothingSpline<T> = fit(c);
+        let mut fit_d: SmoothingSpline<T> = fit(d);
+        for _ in 0..GOLDEN_SECTION_STEPS
+        {
+            if fit_c.gcv < fit_d.gcv
+            {
+                b = d;
+                d = c;
+                fit_d = fit_c;
+                c = b - ratio * (b - a);
+                fit_c = fit(c);
+            }
+            else
+            {
+                a = c;
+                c = d;
+                fit_c = fit_d;
+                d = a + ratio * (b - a);
+                fit_d = fit(d);
+            }
+        }
+
+        [fit_c, fit_d].into_iter().fold(best, |best, s| if s.gcv < best.gcv { s } else { best })
+    }
+
+    /// Assembles the Gram matrix $B^T B$, the penalty matrix and $B^T y$
+    fn system(x: &[T], y: &[T]) -> System<T>
+    {
+        if x.len() < 3
+        {
+            panic!("At least 3 points are required");
+        }
+        if x.len() != y.len()
+        {
+            panic!("The number of points and values differ");
+        }
+
+        let knots: Vec<T> = BSpline::clamped_knots(x, 3);
+        let n: usize = x.len() + 2;
+        let mut gram: Matrix<T> = Matrix::zero(n, n);
+        let mut penalty: Matrix<T> = Matrix::zero(n, n);
+        let mut rhs: Vector<T> = Vector::zero(n);
+
+        for (x_i, y_i) in x.iter().zip(y.iter())
+        {
+            let k: usize = span(3, &knots, *x_i);
+            let basis: Vec<T> = basis_derivatives(3, &knots, k, *x_i, 0).swap_remove(0);
+            for (a, b_a) in basis.iter().enumerate()
+            {
+                rhs[k - 3 + a] += *b_a * *y_i;
+                for (b, b_b) in basis.iter().enumerate()
+                {
+                    gram[[k - 3 + a, k - 3 + b]] += *b_a * *b_b;
+                }
+            }
+        }
+
+        // the second derivatives are linear in each interval, the two point Gauss-Legendre rule is exact
+        let node: T = T::one() / T::from_f64(3.0).sqrt();
+        for w in x.windows(2)
+        {
+            let half: T = (w[1] - w[0]) / T::from_f64(2.0);
+            let center: T = (w[0] + w[1]) / T::from_f64(2.0);
+            for s in [-node, node]
+            {
+                let t: T = center + s * half;
+                let k: usize = span(3, &knots, t);
+                let second: Vec<T> = basis_derivatives(3, &knots, k, t, 2).swap_remove(2);
+                for (a, d_a) in second.iter().enumerate()
+                {
+                    for (b, d_b) in second.iter().enumerate()
+                    {
+                        penalty[[k - 3 + a, k - 3 + b]] += half * *d_a * *d_b;
+                    }
+                }
+            }
+        }
+
+        System { gram,
+                 penalty,
+                 rhs,
+                 knots }
+    }
+
+    fn solve(system: &System<T>, x: &[T], y: &[T], lambda: T) -> SmoothingSpline<T>
+    {
+        let a: Matrix<T> = &system.gram + &(system.penalty.clone() * lambda);
+        let coef: Vector<T> = match a.solve(&system.rhs)
+        {
+            Ok(c) => c,
+            Err(_) => panic!("The penalized normal equations are singular"),
+        };
+        let n: usize = system.rhs.dim().0;
+        let spline: BSpline<T> = BSpline::new(3, system.knots.clone(), (0..n).map(|i| coef[i]).collect());
+
+        // tr H = tr((B^T B + lambda Omega)^{-1} B^T B)
+        let edf: T = match a.inv()
+        {
+            Ok(a_inv) => (0..n).fold(T::zero(), |s, i| {
+                                   (0..n).fold(s, |s, j| s + a_inv[[i, j]] * system.gram[[j, i]])
+                               }),
+            Err(_) => panic!("The penalized normal equations are singular"),
+        };
+
+        let m: T = T::from_f64(x.len() as f64);
+        let rss: T = x.iter()
+                      .zip(y.iter())
+                      .fold(T::zero(), |s, (x_i, y_i)| {
+                          let r: T = *y_i - spline.eval(*x_i);
+                          s + r * r
+                      });
+
+        SmoothingSpline { spline,
+                          lambda,
+                          edf,
+                          gcv: m * rss / ((m - edf) * (m - edf)) }
+    }
+
+    /// Smoothing parameter $\lambda$
+    pub fn lambda(&self) -> T
+    {
+        self.lambda
+    }
+
+    /// Effective number of degrees of freedom $\operatorname{tr}H(\lambda)$
+    pub fn effective_degrees_of_freedom(&self) -> T
+    {
+        self.edf
+    }
+
+    /// Generalized cross-validation score
+    pub fn gcv_score(&self) -> T
+    {
+        self.gcv
+    }
+
+    /// Cubic B-spline representation
+    pub fn spline(&self) -> &BSpline<T>
+    {
+        &self.spline
+    }
+}
+
+impl<T> Interpolant<T> for SmoothingSpline<T>
+    where T: Real
+{
+    fn eval(&self, x: T) -> T
+    {
+        self.spline.eval(x)
+    }
+
+    fn derivative(&self, x: T) -> T
+    {
+        self.spline.derivative(x)
+    }
+
+    fn integrate(&self, a: T, b: T) -> T
+    {
+        self.spline.integrate(a, b)
+    }
+
+    fn domain(&self) -> (T, T)
+    {
+        self.spline.domain()
+    }
+
+    fn set_extrapolation(&mut self, extrapolation: Extrapolation)
+    {
+        self.spline.set_extrapolation(extrapolation);
+    }
+
+    fn get_extrapolation(&self) -> Extrapolation
+    {
+        self.spline.get_extrapolation()
+    }
+}
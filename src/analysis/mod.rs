@@ -3,7 +3,10 @@
 //! Fore more information: <br>
 //! <https://en.wikipedia.org/wiki/Analysis>
 
-//pub mod interpolation;
+pub mod differentiation;
+pub mod interpolation;
+pub mod nonlinear;
+pub mod root;
 #[macro_use]
 mod function;
 mod hessian;
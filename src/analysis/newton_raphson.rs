@@ -54,7 +54,11 @@ impl<T> NewtonRaphson<T> where T: Real
 
             let jacobian_x: Matrix<T> = func.jacobian(&x);
 
-            let b: Vector<T> = jacobian_x.solve(&func_x).unwrap();
+            let b: Vector<T> = match jacobian_x.solve(&func_x)
+            {
+                Ok(b) => b,
+                Err(_) => return Err("The Jacobian is singular"),
+            };
 
             let x_current: Vector<T> = &x - &b;
 
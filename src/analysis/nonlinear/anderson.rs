@@ -0,0 +1,217 @@
+//! Anderson acceleration
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Solve, Matrix, Vector},
+    },
+    analysis::{
+        nonlinear::{
+            criteria::{is_finite, norm, Criteria},
+            NonlinearResult, Termination,
+        },
+        Function,
+    },
+};
+use std::collections::VecDeque;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Anderson acceleration
+///
+/// Accelerates the fixed point iteration $x_{k + 1} = G(x_k)$. With the
+/// residuals $f_k = G(x_k) - x_k$ and the differences $\Delta x_i$ and
+/// $\Delta f_i$ of the last m iterates and residuals, the coefficients
+/// $\gamma$ minimize $\lVert f_k - \sum_i \gamma_i\Delta f_i \rVert_2$ and
+/// ```math
+/// x_{k + 1} = x_k + \beta f_k - \sum_i \gamma_i(\Delta x_i + \beta\Delta f_i)
+/// ```
+/// with the mixing parameter $\beta$. For m = 0 the method reduces to the
+/// damped fixed point iteration. No derivatives are required.
+///
+/// Walker, H. F., Ni, P.: Anderson Acceleration for Fixed-Point
+/// Iterations, SIAM Journal on Numerical Analysis, 2011
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::Vector,
+///     analysis::{
+///         nonlinear::{Anderson, NonlinearResult},
+///         Function,
+///     },
+/// };
+///
+/// // G(x) = cos(x)
+/// struct Cosine;
+///
+/// impl Function<Vector<f64>> for Cosine
+/// {
+///     type Codomain = Vector<f64>;
+///
+///     fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         x.clone().apply(&|x_i: &f64| x_i.cos())
+///     }
+/// }
+///
+/// let result: NonlinearResult<f64> = Anderson::default().find_fixed_point(&Cosine, &vector![1.0; 0.0]);
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(result.arg(), vector![0.7390851332151607; 0.7390851332151607], epsilon = 1.0e-9);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Anderson<T>
+{
+    criteria: Criteria<T>,
+    memory: usize,
+    mixing: T,
+}
+
+impl<T> Default for Anderson<T>
+    where T: Real
+{
+    fn default() -> Anderson<T>
+    {
+        Anderson { criteria: Criteria::default_with(200),
+                   memory: 5,
+                   mixing: T::one() }
+    }
+}
+
+impl<T> Anderson<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'iters': maximal number of iterations
+    /// * 'memory': number m of previous iterates
+    /// * 'mixing': mixing parameter $\beta$
+    /// * 'residual_tol': tolerance of the Euclidean norm of $G(x) - x$
+    ///
+    /// # Panics
+    ///
+    /// if the mixing parameter is not positive or the tolerance is negative
+    pub fn new(iters: usize, memory: usize, mixing: T, residual_tol: T) -> Anderson<T>
+    {
+        if mixing <= T::zero()
+        {
+            panic!("The mixing parameter is not positive");
+        }
+
+        Anderson { criteria: Criteria::new(iters, residual_tol, T::zero()),
+                   memory,
+                   mixing }
+    }
+
+    /// Finds a fixed point $x = G(x)$ of func starting at x_0
+    ///
+    /// The residual of the result is $\lVert G(x) - x \rVert_2$.
+    pub fn find_fixed_point<G>(&self, func: &G, x_0: &Vector<T>) -> NonlinearResult<T>
+        where G: Function<Vector<T>, Codomain = Vector<T>>
+    {
+        let mut x: Vector<T> = x_0.clone();
+        let mut f_x: Vector<T> = &func.eval(&x) - &x;
+        let mut evaluations: usize = 1;
+
+        let mut delta_x: VecDeque<Vector<T>> = VecDeque::with_capacity(self.memory);
+        let mut delta_f: VecDeque<Vector<T>> = VecDeque::with_capacity(self.memory);
+
+        for k in 0..self.criteria.iters()
+        {
+            let f_norm: T = norm(&f_x);
+            if self.criteria.is_residual_small(f_norm)
+            {
+                return NonlinearResult::new(x, f_norm, k, evaluations, 0, Termination::Residual);
+            }
+
+            let mut x_next: Vector<T> = &x + &(&f_x * &self.mixing);
+            match self.coefficients(&delta_f, &f_x)
+            {
+                Some(gamma) =>
+                {
+                    for (i, gamma_i) in gamma.iter().enumerate()
+                    {
+                        let correction: Vector<T> = &delta_x[i] + &(&delta_f[i] * &self.mixing);
+                        x_next = &x_next - &(&correction * gamma_i);
+                    }
+                }
+                None =>
+                {
+                    delta_x.clear();
+                    delta_f.clear();
+                }
+            }
+
+            let f_next: Vector<T> = &func.eval(&x_next) - &x_next;
+            evaluations += 1;
+            if !is_finite(&f_next)
+            {
+                return NonlinearResult::new(x, f_norm, k + 1, evaluations, 0, Termination::NoProgress);
+            }
+
+            if self.memory > 0
+            {
+                if delta_x.len() == self.memory
+                {
+                    delta_x.pop_front();
+                    delta_f.pop_front();
+                }
+                delta_x.push_back(&x_next - &x);
+                delta_f.push_back(&f_next - &f_x);
+            }
+            x = x_next;
+            f_x = f_next;
+        }
+
+        let residual: T = norm(&f_x);
+        let termination: Termination = if self.criteria.is_residual_small(residual)
+        {
+            Termination::Residual
+        }
+        else
+        {
+            Termination::MaxIterations
+        };
+        NonlinearResult::new(x, residual, self.criteria.iters(), evaluations, 0, termination)
+    }
+
+    /// Solves the least squares problem $\min_\gamma \lVert f - \sum_i
+    /// \gamma_i \Delta f_i \rVert_2$ with the regularized normal equations,
+    /// None if the history is empty or the normal equations are singular
+    fn coefficients(&self, delta_f: &VecDeque<Vector<T>>, f: &Vector<T>) -> Option<Vector<T>>
+    {
+        let m: usize = delta_f.len();
+        if m == 0
+        {
+            return None;
+        }
+
+        let mut normal: Matrix<T> = Matrix::zero(m, m);
+        let mut rhs: Vector<T> = Vector::zero(m);
+        let mut trace: T = T::zero();
+        for i in 0..m
+        {
+            for j in 0..m
+            {
+                normal[[i, j]] = delta_f[i].dotp(&delta_f[j]);
+            }
+            trace += normal[[i, i]];
+            rhs[i] = delta_f[i].dotp(f);
+        }
+        let regularization: T = T::default_epsilon() * trace;
+        for i in 0..m
+        {
+            normal[[i, i]] += regularization;
+        }
+
+        normal.solve(&rhs).ok().filter(is_finite)
+    }
+}
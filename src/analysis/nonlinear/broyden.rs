@@ -0,0 +1,252 @@
+//! Broyden's method
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{matrix::Inverse, Matrix, Vector},
+    },
+    analysis::{
+        nonlinear::{
+            criteria::{is_finite, is_newton_step, norm, rank_one_update, transpose_mul, Criteria},
+            NonlinearResult, Termination,
+        },
+        Function, Jacobian,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Broyden's method
+///
+/// Quasi-Newton method for systems, whose Jacobian is expensive to evaluate.
+/// The Jacobian is evaluated and inverted only at the starting value. The
+/// approximation $H_k \approx J(x_k)^{-1}$ is updated after every step
+/// $s_k = x_{k + 1} - x_k$ with $y_k = F(x_{k + 1}) - F(x_k)$ by the
+/// Sherman-Morrison formula of Broyden's good update:
+/// ```math
+/// H_{k + 1} = H_k + \frac{(s_k - H_ky_k)s_k^TH_k}{s_k^TH_ky_k}
+/// ```
+/// The steps $p_k = -H_kF(x_k)$ are halved until the residual decreases.
+/// If that fails, the Jacobian is evaluated again. The convergence is
+/// superlinear close to a solution.
+///
+/// Broyden, C. G.: A Class of Methods for Solving Nonlinear Simultaneous
+/// Equations, Mathematics of Computation, 1965 <br>
+/// Kelley, C. T.: Iterative Methods for Linear and Nonlinear Equations,
+/// SIAM, 1995, chapter 7
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         nonlinear::{Broyden, NonlinearResult},
+///         Function, Jacobian,
+///     },
+/// };
+///
+/// // F(x) = (x_0^2 + x_1^2 - 4, x_0 - x_1)
+/// struct Intersection;
+///
+/// impl Function<Vector<f64>> for Intersection
+/// {
+///     type Codomain = Vector<f64>;
+///
+///     fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![x[0] * x[0] + x[1] * x[1] - 4.0; x[0] - x[1]]
+///     }
+/// }
+///
+/// impl Jacobian<f64> for Intersection
+/// {
+///     fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![2.0 * x[0], 2.0 * x[1]; 1.0, -1.0]
+///     }
+/// }
+///
+/// let result: NonlinearResult<f64> = Broyden::default().find_root(&Intersection, &vector![1.0; 2.0]);
+///
+/// assert!(result.is_converged());
+/// assert!(result.jacobian_evaluations() < result.evaluations());
+/// assert_relative_eq!(result.arg(), vector![2.0f64.sqrt(); 2.0f64.sqrt()], epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Broyden<T>
+{
+    criteria: Criteria<T>,
+}
+
+impl<T> Default for Broyden<T>
+    where T: Real
+{
+    fn default() -> Broyden<T>
+    {
+        Broyden { criteria: Criteria::default_with(200) }
+    }
+}
+
+impl<T> Broyden<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'iters': maximal number of iterations
+    /// * 'residual_tol': tolerance of the Euclidean norm of the residual
+    /// * 'step_tol': relative tolerance of the quasi-Newton step
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative
+    pub fn new(iters: usize, residual_tol: T, step_tol: T) -> Broyden<T>
+    {
+        Broyden { criteria: Criteria::new(iters, residual_tol, step_tol) }
+    }
+
+    /// Finds a root of func starting at x_0
+    pub fn find_root<F>(&self, func: &F, x_0: &Vector<T>) -> NonlinearResult<T>
+        where F: Function<Vector<T>, Codomain = Vector<T>> + Jacobian<T>
+    {
+        let mut x: Vector<T> = x_0.clone();
+        let mut f_x: Vector<T> = func.eval(&x);
+        let mut evaluations: usize = 1;
+        let mut jacobian_evaluations: usize = 0;
+
+        let mut inverse: Option<Matrix<T>> = None;
+
+        for k in 0..self.criteria.iters()
+        {
+            let f_norm: T = norm(&f_x);
+            if self.criteria.is_residual_small(f_norm)
+            {
+                return NonlinearResult::new(x, f_norm, k, evaluations, jacobian_evaluations, Termination::Residual);
+            }
+
+            let fresh: bool = inverse.is_none();
+            let (h, p): (Matrix<T>, Vector<T>) = match inverse.take()
+            {
+                Some(h) =>
+                {
+                    let p: Vector<T> = -(&h * &f_x);
+                    (h, p)
+                }
+                None =>
+                {
+                    let jacobian: Matrix<T> = func.jacobian(&x);
+                    jacobian_evaluations += 1;
+                    let step: Option<(Matrix<T>, Vector<T>)> =
+                        jacobian.inv()
+                                .ok()
+                                .map(|h| {
+                                    let p: Vector<T> = -(&h * &f_x);
+                                    (h, p)
+                                })
+                                .filter(|(_, p)| is_newton_step(&jacobian, &f_x, p));
+                    match step
+                    {
+                        Some(step) => step,
+                        None =>
+                        {
+                            // singular Jacobian
+                            return NonlinearResult::new(x,
+                                                        f_norm,
+                                                        k,
+                                                        evaluations,
+                                                        jacobian_evaluations,
+                                                        Termination::NoProgress);
+                        }
+                    }
+                }
+            };
+            if !is_finite(&p)
+            {
+                return NonlinearResult::new(x, f_norm, k, evaluations, jacobian_evaluations, Termination::NoProgress);
+            }
+            if self.criteria.is_step_small(&p, &x)
+            {
+                let x_next: Vector<T> = &x + &p;
+                let f_next: Vector<T> = func.eval(&x_next);
+                return NonlinearResult::new(x_next,
+                                            norm(&f_next),
+                                            k + 1,
+                                            evaluations + 1,
+                                            jacobian_evaluations,
+                                            Termination::Step);
+            }
+
+            // backtracking
+            let mut lambda: T = T::one();
+            let mut accepted: Option<(Vector<T>, Vector<T>)> = None;
+            while lambda >= T::from_f64(1.0 / 1024.0)
+            {
+                let x_next: Vector<T> = &x + &(&p * &lambda);
+                let f_next: Vector<T> = func.eval(&x_next);
+                evaluations += 1;
+                if is_finite(&f_next) && norm(&f_next) < f_norm
+                {
+                    accepted = Some((x_next, f_next));
+                    break;
+                }
+                lambda /= T::from_f64(2.0);
+            }
+
+            match accepted
+            {
+                Some((x_next, f_next)) =>
+                {
+                    let s: Vector<T> = &x_next - &x;
+                    let y: Vector<T> = &f_next - &f_x;
+                    let h_y: Vector<T> = &h * &y;
+                    let denominator: T = s.dotp(&h_y);
+                    x = x_next;
+                    f_x = f_next;
+
+                    // a vanishing denominator requires a new Jacobian
+                    if denominator != T::zero()
+                    {
+                        let mut h: Matrix<T> = h;
+                        let s_h: Vector<T> = transpose_mul(&h, &s);
+                        rank_one_update(&mut h, &((&s - &h_y) / denominator), &s_h);
+                        inverse = Some(h);
+                    }
+                }
+                None =>
+                {
+                    if fresh
+                    {
+                        return NonlinearResult::new(x,
+                                                    f_norm,
+                                                    k + 1,
+                                                    evaluations,
+                                                    jacobian_evaluations,
+                                                    Termination::NoProgress);
+                    }
+                }
+            }
+        }
+
+        let residual: T = norm(&f_x);
+        let termination: Termination = if self.criteria.is_residual_small(residual)
+        {
+            Termination::Residual
+        }
+        else
+        {
+            Termination::MaxIterations
+        };
+        NonlinearResult::new(x,
+                             residual,
+                             self.criteria.iters(),
+                             evaluations,
+                             jacobian_evaluations,
+                             termination)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::algebra::{
+    abstr::Real,
+    linear::{matrix::Solve, Matrix, Vector},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Termination criteria of the nonlinear solvers
+///
+/// The iteration terminates if $\lVert F(x) \rVert_2 \leq \tau_F$ or the
+/// full step p satisfies $\lVert p \rVert_2 \leq \tau_x(1 + \lVert x
+/// \rVert_2)$.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Criteria<T>
+{
+    iters: usize,
+    residual_tol: T,
+    step_tol: T,
+}
+
+impl<T> Criteria<T>
+    where T: Real
+{
+    /// # Panics
+    ///
+    /// if a tolerance is negative
+    pub fn new(iters: usize, residual_tol: T, step_tol: T) -> Criteria<T>
+    {
+        if residual_tol < T::zero() || step_tol < T::zero()
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        Criteria { iters,
+                   residual_tol,
+                   step_tol }
+    }
+
+    /// Both tolerances are $\epsilon^{2/3}$
+    pub fn default_with(iters: usize) -> Criteria<T>
+    {
+        let tol: T = T::default_epsilon().pow(T::from_f64(2.0 / 3.0));
+        Criteria::new(iters, tol, tol)
+    }
+
+    /// Maximal number of iterations
+    pub fn iters(&self) -> usize
+    {
+        self.iters
+    }
+
+    /// Returns true if the residual with the norm f_norm is small enough
+    pub fn is_residual_small(&self, f_norm: T) -> bool
+    {
+        f_norm <= self.residual_tol
+    }
+
+    /// Returns true if the step p at x is small enough
+    pub fn is_step_small(&self, p: &Vector<T>, x: &Vector<T>) -> bool
+    {
+        norm(p) <= self.step_tol * (T::one() + norm(x))
+    }
+}
+
+/// Euclidean norm
+pub(crate) fn norm<T>(v: &Vector<T>) -> T
+    where T: Real
+{
+    v.dotp(v).sqrt()
+}
+
+/// Returns true if all entries are neither infinite nor NaN
+pub(crate) fn is_finite<T>(v: &Vector<T>) -> bool
+    where T: Real
+{
+    v.iter().all(|v_i| v_i.abs() < T::infinity())
+}
+
+/// Newton step $p = -J^{-1}f$, None if J is singular
+pub(crate) fn newton_step<T>(j: &Matrix<T>, f: &Vector<T>) -> Option<Vector<T>>
+    where T: Real
+{
+    j.solve(&-f.clone()).ok().filter(|p| is_newton_step(j, f, p))
+}
+
+/// Returns true if p solves $Jp = -f$
+///
+/// The LU decomposition does not report singular matrices, so the residual
+/// of the linear system is checked.
+pub(crate) fn is_newton_step<T>(j: &Matrix<T>, f: &Vector<T>, p: &Vector<T>) -> bool
+    where T: Real
+{
+    if !is_finite(p)
+    {
+        return false;
+    }
+    let defect: Vector<T> = &(j * p) + f;
+    let j_norm: T = j.iter().fold(T::zero(), |s, j_ik| s + *j_ik * *j_ik).sqrt();
+    norm(&defect) <= T::default_epsilon().sqrt() * (norm(f) + j_norm * norm(p))
+}
+
+/// $J^T v$
+pub(crate) fn transpose_mul<T>(j: &Matrix<T>, v: &Vector<T>) -> Vector<T>
+    where T: Real
+{
+    let (m, n): (usize, usize) = j.dim();
+    Vector::new_column((0..n).map(|k| (0..m).fold(T::zero(), |s, i| s + j[[i, k]] * v[i]))
+                             .collect())
+}
+
+/// $A \leftarrow A + u v^T$
+pub(crate) fn rank_one_update<T>(a: &mut Matrix<T>, u: &Vector<T>, v: &Vector<T>)
+    where T: Real
+{
+    let (m, n): (usize, usize) = a.dim();
+    for k in 0..n
+    {
+        for i in 0..m
+        {
+            a[[i, k]] += u[i] * v[k];
+        }
+    }
+}
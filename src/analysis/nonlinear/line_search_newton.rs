@@ -0,0 +1,236 @@
+//! Newton's method with line search
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        nonlinear::{
+            criteria::{newton_step, norm, transpose_mul, Criteria},
+            NonlinearResult, Termination,
+        },
+        Function, Jacobian,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Sufficient decrease parameter of the Armijo condition
+const ALPHA: f64 = 1.0e-4;
+
+/// Newton's method globalised by a backtracking line search
+///
+/// The Newton direction $p_k = -J(x_k)^{-1}F(x_k)$ is a descent direction
+/// of the merit function $\phi(x) = \frac{1}{2}\lVert F(x) \rVert_2^2$. The
+/// step length $\lambda_k \in (0, 1]$ is reduced by quadratic interpolation
+/// until the Armijo condition
+/// ```math
+/// \phi(x_k + \lambda_k p_k) \leq \phi(x_k) + \alpha\lambda_k\nabla\phi(x_k)^Tp_k
+/// ```
+/// holds. Close to a solution the full step is accepted and the convergence
+/// is quadratic. If the Jacobian is singular, the steepest descent direction
+/// $-J(x_k)^TF(x_k)$ of the merit function is used instead.
+///
+/// Dennis, J. E., Schnabel, R. B.: Numerical Methods for Unconstrained
+/// Optimization and Nonlinear Equations, SIAM, 1996, section 6.3
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         nonlinear::{LineSearchNewton, NonlinearResult},
+///         Function, Jacobian,
+///     },
+/// };
+///
+/// // F(x) = (x_0^2 + x_1^2 - 4, x_0 - x_1)
+/// struct Intersection;
+///
+/// impl Function<Vector<f64>> for Intersection
+/// {
+///     type Codomain = Vector<f64>;
+///
+///     fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![x[0] * x[0] + x[1] * x[1] - 4.0; x[0] - x[1]]
+///     }
+/// }
+///
+/// impl Jacobian<f64> for Intersection
+/// {
+///     fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![2.0 * x[0], 2.0 * x[1]; 1.0, -1.0]
+///     }
+/// }
+///
+/// let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&Intersection, &vector![100.0; 1.0]);
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(result.arg(), vector![2.0f64.sqrt(); 2.0f64.sqrt()], epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct LineSearchNewton<T>
+{
+    criteria: Criteria<T>,
+}
+
+impl<T> Default for LineSearchNewton<T>
+    where T: Real
+{
+    fn default() -> LineSearchNewton<T>
+    {
+        LineSearchNewton { criteria: Criteria::default_with(100) }
+    }
+}
+
+impl<T> LineSearchNewton<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'iters': maximal number of iterations
+    /// * 'residual_tol': tolerance of the Euclidean norm of the residual
+    /// * 'step_tol': relative tolerance of the Newton step
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative
+    pub fn new(iters: usize, residual_tol: T, step_tol: T) -> LineSearchNewton<T>
+    {
+        LineSearchNewton { criteria: Criteria::new(iters, residual_tol, step_tol) }
+    }
+
+    /// Finds a root of func starting at x_0
+    pub fn find_root<F>(&self, func: &F, x_0: &Vector<T>) -> NonlinearResult<T>
+        where F: Function<Vector<T>, Codomain = Vector<T>> + Jacobian<T>
+    {
+        let half: T = T::from_f64(0.5);
+        let alpha: T = T::from_f64(ALPHA);
+
+        let mut x: Vector<T> = x_0.clone();
+        let mut f_x: Vector<T> = func.eval(&x);
+        let mut phi: T = half * f_x.dotp(&f_x);
+        let mut evaluations: usize = 1;
+        let mut jacobian_evaluations: usize = 0;
+
+        for k in 0..self.criteria.iters()
+        {
+            if self.criteria.is_residual_small(norm(&f_x))
+            {
+                return NonlinearResult::new(x, norm(&f_x), k, evaluations, jacobian_evaluations, Termination::Residual);
+            }
+
+            let jacobian: Matrix<T> = func.jacobian(&x);
+            jacobian_evaluations += 1;
+            let gradient: Vector<T> = transpose_mul(&jacobian, &f_x);
+
+            let newton: Option<Vector<T>> = newton_step(&jacobian, &f_x);
+            if let Some(p) = &newton
+            {
+                if self.criteria.is_step_small(p, &x)
+                {
+                    let x_next: Vector<T> = &x + p;
+                    let f_next: Vector<T> = func.eval(&x_next);
+                    return NonlinearResult::new(x_next,
+                                                norm(&f_next),
+                                                k + 1,
+                                                evaluations + 1,
+                                                jacobian_evaluations,
+                                                Termination::Step);
+                }
+            }
+
+            let mut p: Vector<T> = newton.unwrap_or_else(|| -gradient.clone());
+            let mut slope: T = gradient.dotp(&p);
+            if slope >= T::zero()
+            {
+                p = -gradient.clone();
+                slope = -gradient.dotp(&gradient);
+            }
+            if slope == T::zero()
+            {
+                // stationary point of the merit function
+                return NonlinearResult::new(x,
+                                            norm(&f_x),
+                                            k,
+                                            evaluations,
+                                            jacobian_evaluations,
+                                            Termination::NoProgress);
+            }
+
+            // backtracking
+            let mut lambda: T = T::one();
+            loop
+            {
+                let x_next: Vector<T> = &x + &(&p * &lambda);
+                let f_next: Vector<T> = func.eval(&x_next);
+                evaluations += 1;
+                let phi_next: T = half * f_next.dotp(&f_next);
+
+                if phi_next <= phi + alpha * lambda * slope
+                {
+                    if self.criteria.is_step_small(&(&p * &lambda), &x)
+                    {
+                        // the merit function decreases only by rounding
+                        return NonlinearResult::new(x_next,
+                                                    norm(&f_next),
+                                                    k + 1,
+                                                    evaluations,
+                                                    jacobian_evaluations,
+                                                    Termination::NoProgress);
+                    }
+                    x = x_next;
+                    f_x = f_next;
+                    phi = phi_next;
+                    break;
+                }
+
+                let lambda_next: T = if phi_next.abs() < T::infinity()
+                {
+                    -slope * lambda * lambda / (T::from_f64(2.0) * (phi_next - phi - slope * lambda))
+                }
+                else
+                {
+                    T::zero()
+                };
+                lambda = lambda_next.max(T::from_f64(0.1) * lambda).min(half * lambda);
+
+                if lambda < T::default_epsilon() || self.criteria.is_step_small(&(&p * &lambda), &x)
+                {
+                    return NonlinearResult::new(x,
+                                                norm(&f_x),
+                                                k + 1,
+                                                evaluations,
+                                                jacobian_evaluations,
+                                                Termination::NoProgress);
+                }
+            }
+        }
+
+        let residual: T = norm(&f_x);
+        let termination: Termination = if self.criteria.is_residual_small(residual)
+        {
+            Termination::Residual
+        }
+        else
+        {
+            Termination::MaxIterations
+        };
+        NonlinearResult::new(x,
+                             residual,
+                             self.criteria.iters(),
+                             evaluations,
+                             jacobian_evaluations,
+                             termination)
+    }
+}
@@ -0,0 +1,34 @@
+//! Nonlinear systems of equations
+//!
+//! Fore more information: <br>
+//! <https://en.wikipedia.org/wiki/Nonlinear_system>
+//!
+//! Globally convergent solvers for systems $F(x) = 0$ with $F: \mathbb{R}^n
+//! \rightarrow \mathbb{R}^n$ and accelerated fixed point iterations
+//! $x = G(x)$. Unlike [`NewtonRaphson`](crate::analysis::NewtonRaphson), the
+//! solvers converge from poor starting values and do not fail on singular
+//! Jacobians. All solvers return a [`NonlinearResult`] with the
+//! approximation, the norm of the residual, the number of iterations,
+//! function and Jacobian evaluations and the [`Termination`] reason.
+//!
+//! | Solver | Requires | Globalisation |
+//! |--------|----------|---------------|
+//! | [`LineSearchNewton`] | Jacobian in every iteration | backtracking line search |
+//! | [`PowellHybrid`] | Jacobian, updated by Broyden's formula | dogleg trust region |
+//! | [`Broyden`] | Jacobian at the starting value | step halving |
+//! | [`Anderson`] | fixed point map G | none, extrapolation of the last iterates |
+
+mod anderson;
+mod broyden;
+mod criteria;
+mod line_search_newton;
+mod nonlinear_result;
+mod powell_hybrid;
+
+pub use self::{
+    anderson::Anderson,
+    broyden::Broyden,
+    line_search_newton::LineSearchNewton,
+    nonlinear_result::{NonlinearResult, Termination},
+    powell_hybrid::PowellHybrid,
+};
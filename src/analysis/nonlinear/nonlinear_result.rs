@@ -0,0 +1,100 @@
+use crate::algebra::{abstr::Real, linear::Vector};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Reason for the termination of a nonlinear solver
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination
+{
+    /// The norm of the residual is below the residual tolerance
+    Residual,
+    /// The length of the full step is below the step tolerance
+    Step,
+    /// The maximal number of iterations has been reached
+    MaxIterations,
+    /// Neither the line search nor the trust region reduce the residual,
+    /// the iteration is trapped in a local minimum of the residual norm or
+    /// the Jacobian is singular
+    NoProgress,
+}
+
+/// Result of a solver for nonlinear systems of equations
+///
+/// Contains the approximation of the solution and the diagnostics of the
+/// iteration: the norm of the residual, the number of iterations, function
+/// and Jacobian evaluations and the reason for the termination.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug)]
+pub struct NonlinearResult<T>
+{
+    arg: Vector<T>,
+    residual: T,
+    iterations: usize,
+    evaluations: usize,
+    jacobian_evaluations: usize,
+    termination: Termination,
+}
+
+impl<T> NonlinearResult<T>
+    where T: Real
+{
+    pub fn new(arg: Vector<T>,
+               residual: T,
+               iterations: usize,
+               evaluations: usize,
+               jacobian_evaluations: usize,
+               termination: Termination)
+               -> NonlinearResult<T>
+    {
+        NonlinearResult { arg,
+                          residual,
+                          iterations,
+                          evaluations,
+                          jacobian_evaluations,
+                          termination }
+    }
+
+    /// Approximation of the solution
+    pub fn arg(self) -> Vector<T>
+    {
+        self.arg
+    }
+
+    /// Euclidean norm of the residual at the approximation
+    pub fn residual(&self) -> T
+    {
+        self.residual
+    }
+
+    /// Number of iterations
+    pub fn iterations(&self) -> usize
+    {
+        self.iterations
+    }
+
+    /// Number of evaluations of the function
+    pub fn evaluations(&self) -> usize
+    {
+        self.evaluations
+    }
+
+    /// Number of evaluations of the Jacobian
+    pub fn jacobian_evaluations(&self) -> usize
+    {
+        self.jacobian_evaluations
+    }
+
+    /// Reason for the termination
+    pub fn termination(&self) -> Termination
+    {
+        self.termination
+    }
+
+    /// Returns true if the residual or the step tolerance has been reached
+    pub fn is_converged(&self) -> bool
+    {
+        self.termination == Termination::Residual || self.termination == Termination::Step
+    }
+}
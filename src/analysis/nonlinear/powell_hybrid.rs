@@ -0,0 +1,329 @@
+//! Powell's hybrid method
+use crate::{
+    algebra::{
+        abstr::Real,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        nonlinear::{
+            criteria::{is_finite, newton_step, norm, rank_one_update, transpose_mul, Criteria},
+            NonlinearResult, Termination,
+        },
+        Function, Jacobian,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Powell's hybrid method
+///
+/// Trust region method, whose step is the dogleg combination of the Newton
+/// step $p^N = -J^{-1}F$ and the Cauchy step $p^C$, which minimizes the
+/// linear model $\lVert F + Jp \rVert_2$ along the steepest descent
+/// direction $-J^TF$:
+/// ```math
+/// p = \begin{cases} p^N & \lVert p^N \rVert_2 \leq \Delta \\
+/// -\frac{\Delta}{\lVert J^TF \rVert_2}J^TF & \lVert p^C \rVert_2 \geq \Delta \\
+/// p^C + \tau(p^N - p^C) & \text{otherwise, with } \lVert p \rVert_2 = \Delta \end{cases}
+/// ```
+/// The trust region radius $\Delta$ is adapted to the ratio of the actual
+/// and the predicted reduction of $\lVert F \rVert_2^2$. As in MINPACK's
+/// `hybrj`, the Jacobian is updated by Broyden's rank one formula after
+/// every step and only evaluated again if two consecutive steps fail.
+///
+/// Powell, M. J. D.: A Hybrid Method for Nonlinear Equations, in Numerical
+/// Methods for Nonlinear Algebraic Equations, Gordon and Breach, 1970 <br>
+/// Moré, J. J., Garbow, B. S., Hillstrom, K. E.: User Guide for MINPACK-1,
+/// Argonne National Laboratory, 1980
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     algebra::linear::{Matrix, Vector},
+///     analysis::{
+///         nonlinear::{NonlinearResult, PowellHybrid},
+///         Function, Jacobian,
+///     },
+/// };
+///
+/// // Powell's badly scaled function
+/// struct Powell;
+///
+/// impl Function<Vector<f64>> for Powell
+/// {
+///     type Codomain = Vector<f64>;
+///
+///     fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+///     {
+///         vector![1.0e4 * x[0] * x[1] - 1.0; (-x[0]).exp() + (-x[1]).exp() - 1.0001]
+///     }
+/// }
+///
+/// impl Jacobian<f64> for Powell
+/// {
+///     fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+///     {
+///         matrix![1.0e4 * x[1], 1.0e4 * x[0]; -(-x[0]).exp(), -(-x[1]).exp()]
+///     }
+/// }
+///
+/// let result: NonlinearResult<f64> = PowellHybrid::default().find_root(&Powell, &vector![0.0; 1.0]);
+///
+/// assert!(result.is_converged());
+/// assert!(result.residual() < 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct PowellHybrid<T>
+{
+    criteria: Criteria<T>,
+    factor: T,
+}
+
+impl<T> Default for PowellHybrid<T>
+    where T: Real
+{
+    fn default() -> PowellHybrid<T>
+    {
+        PowellHybrid { criteria: Criteria::default_with(200),
+                       factor: T::from_f64(100.0) }
+    }
+}
+
+impl<T> PowellHybrid<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'iters': maximal number of iterations
+    /// * 'residual_tol': tolerance of the Euclidean norm of the residual
+    /// * 'step_tol': relative tolerance of the Newton step
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative
+    pub fn new(iters: usize, residual_tol: T, step_tol: T) -> PowellHybrid<T>
+    {
+        PowellHybrid { criteria: Criteria::new(iters, residual_tol, step_tol),
+                       factor: T::from_f64(100.0) }
+    }
+
+    /// Sets the factor of the initial trust region radius
+    /// $\Delta_0 = factor \cdot \max(\lVert x_0 \rVert_2, 1)$
+    ///
+    /// # Panics
+    ///
+    /// if the factor is not positive
+    pub fn set_factor(&mut self, factor: T)
+    {
+        if factor <= T::zero()
+        {
+            panic!("The factor is not positive");
+        }
+        self.factor = factor;
+    }
+
+    /// Finds a root of func starting at x_0
+    pub fn find_root<F>(&self, func: &F, x_0: &Vector<T>) -> NonlinearResult<T>
+        where F: Function<Vector<T>, Codomain = Vector<T>> + Jacobian<T>
+    {
+        let mut x: Vector<T> = x_0.clone();
+        let mut f_x: Vector<T> = func.eval(&x);
+        let mut jacobian: Matrix<T> = func.jacobian(&x);
+        let mut evaluations: usize = 1;
+        let mut jacobian_evaluations: usize = 1;
+
+        let mut delta: T = self.factor * norm(&x).max(T::one());
+        let mut failures: usize = 0;
+        let mut fresh: bool = true;
+
+        for k in 0..self.criteria.iters()
+        {
+            let f_norm: T = norm(&f_x);
+            if self.criteria.is_residual_small(f_norm)
+            {
+                return NonlinearResult::new(x, f_norm, k, evaluations, jacobian_evaluations, Termination::Residual);
+            }
+
+            let newton: Option<Vector<T>> = newton_step(&jacobian, &f_x);
+            if let Some(p) = &newton
+            {
+                if self.criteria.is_step_small(p, &x)
+                {
+                    let x_next: Vector<T> = &x + p;
+                    let f_next: Vector<T> = func.eval(&x_next);
+                    return NonlinearResult::new(x_next,
+                                                norm(&f_next),
+                                                k + 1,
+                                                evaluations + 1,
+                                                jacobian_evaluations,
+                                                Termination::Step);
+                }
+            }
+
+            let p: Vector<T> = match self.dogleg(&jacobian, &f_x, newton, delta)
+            {
+                Some(p) => p,
+                None =>
+                {
+                    // J^T F = 0
+                    if fresh
+                    {
+                        return NonlinearResult::new(x,
+                                                    f_norm,
+                                                    k,
+                                                    evaluations,
+                                                    jacobian_evaluations,
+                                                    Termination::NoProgress);
+                    }
+                    jacobian = func.jacobian(&x);
+                    jacobian_evaluations += 1;
+                    failures = 0;
+                    fresh = true;
+                    continue;
+                }
+            };
+            let p_norm: T = norm(&p);
+
+            let x_next: Vector<T> = &x + &p;
+            let f_next: Vector<T> = func.eval(&x_next);
+            evaluations += 1;
+
+            let linear: Vector<T> = &f_x + &(&jacobian * &p);
+            let actual: T = f_norm * f_norm - f_next.dotp(&f_next);
+            let predicted: T = f_norm * f_norm - linear.dotp(&linear);
+            let ratio: T = if predicted > T::zero() && is_finite(&f_next)
+            {
+                actual / predicted
+            }
+            else
+            {
+                -T::one()
+            };
+
+            if ratio < T::from_f64(0.1)
+            {
+                delta = T::from_f64(0.5) * delta.min(p_norm);
+                failures += 1;
+            }
+            else
+            {
+                if ratio >= T::from_f64(0.5)
+                {
+                    delta = delta.max(T::from_f64(2.0) * p_norm);
+                }
+                failures = 0;
+            }
+
+            // Broyden update of the Jacobian
+            if is_finite(&f_next)
+            {
+                let defect: Vector<T> = &(&f_next - &f_x) - &(&jacobian * &p);
+                rank_one_update(&mut jacobian, &(defect / p.dotp(&p)), &p);
+                fresh = false;
+            }
+
+            if ratio >= T::from_f64(1.0e-4)
+            {
+                x = x_next;
+                f_x = f_next;
+            }
+
+            if failures == 2
+            {
+                jacobian = func.jacobian(&x);
+                jacobian_evaluations += 1;
+                failures = 0;
+                fresh = true;
+            }
+
+            if delta <= T::default_epsilon() * (T::one() + norm(&x))
+            {
+                return NonlinearResult::new(x,
+                                            norm(&f_x),
+                                            k + 1,
+                                            evaluations,
+                                            jacobian_evaluations,
+                                            Termination::NoProgress);
+            }
+        }
+
+        let residual: T = norm(&f_x);
+        let termination: Termination = if self.criteria.is_residual_small(residual)
+        {
+            Termination::Residual
+        }
+        else
+        {
+            Termination::MaxIterations
+        };
+        NonlinearResult::new(x,
+                             residual,
+                             self.criteria.iters(),
+                             evaluations,
+                             jacobian_evaluations,
+                             termination)
+    }
+
+    /// Dogleg step in the trust region with the radius delta, None if the
+    /// gradient $J^TF$ vanishes
+    fn dogleg(&self, jacobian: &Matrix<T>, f_x: &Vector<T>, newton: Option<Vector<T>>, delta: T) -> Option<Vector<T>>
+    {
+        if let Some(p) = &newton
+        {
+            if norm(p) <= delta
+            {
+                return newton;
+            }
+        }
+
+        let gradient: Vector<T> = transpose_mul(jacobian, f_x);
+        let g_norm: T = norm(&gradient);
+        if g_norm == T::zero()
+        {
+            return None;
+        }
+        let j_g: Vector<T> = jacobian * &gradient;
+        let j_g_norm: T = norm(&j_g);
+        if j_g_norm == T::zero()
+        {
+            return Some(&gradient * &(-delta / g_norm));
+        }
+
+        let cauchy: Vector<T> = &gradient * &(-(g_norm * g_norm) / (j_g_norm * j_g_norm));
+        let c_norm: T = norm(&cauchy);
+        if c_norm >= delta
+        {
+            return Some(&gradient * &(-delta / g_norm));
+        }
+
+        let p_n: Vector<T> = match newton
+        {
+            Some(p_n) => p_n,
+            None => return Some(cauchy),
+        };
+
+        // ||cauchy + tau (p_n - cauchy)|| = delta, 0 <= tau <= 1
+        let d: Vector<T> = &p_n - &cauchy;
+        let a: T = d.dotp(&d);
+        let b: T = cauchy.dotp(&d);
+        let c: T = c_norm * c_norm - delta * delta;
+        let discriminant: T = (b * b - a * c).sqrt();
+        let tau: T = if b > T::zero()
+        {
+            -c / (b + discriminant)
+        }
+        else
+        {
+            (discriminant - b) / a
+        };
+        Some(&cauchy + &(&d * &tau))
+    }
+}
@@ -0,0 +1,125 @@
+//! Bisection method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{
+        tolerance::{brackets, Tolerance},
+        RootResult,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Bisection method
+///
+/// The bracket $[a, b]$ with $f(a)f(b) \leq 0$ is halved in every
+/// iteration, keeping the half on which f changes its sign. The method
+/// converges linearly, but is guaranteed to converge for every continuous
+/// function.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::root::{Bisection, RootResult};
+///
+/// let result: RootResult<f64> = Bisection::default().find_root(|x: f64| x * x - 2.0, 0.0, 2.0).unwrap();
+///
+/// assert!(result.is_converged());
+/// assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Bisection<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Bisection<T>
+    where T: Real
+{
+    fn default() -> Bisection<T>
+    {
+        Bisection { tolerance: Tolerance::machine(200) }
+    }
+}
+
+impl<T> Bisection<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Bisection<T>
+    {
+        Bisection { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f in the bracket $[a, b]$
+    ///
+    /// # Errors
+    ///
+    /// if f(a) and f(b) have the same sign
+    pub fn find_root<F>(&self, f: F, a: T, b: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T
+    {
+        let (mut a, mut b): (T, T) = (a, b);
+        let mut fa: T = f(a);
+        let fb: T = f(b);
+        let mut evaluations: usize = 2;
+
+        if fa == T::zero()
+        {
+            return Ok(RootResult::new(a, T::zero(), 0, evaluations, true));
+        }
+        if fb == T::zero()
+        {
+            return Ok(RootResult::new(b, T::zero(), 0, evaluations, true));
+        }
+        if !brackets(fa, fb)
+        {
+            return Err("The interval does not bracket a root");
+        }
+
+        let two: T = T::from_f64(2.0);
+        for i in 0..self.tolerance.iters()
+        {
+            let m: T = a + (b - a) / two;
+            let error: T = (b - a).abs() / two;
+            // m coincides with a bound if a and b are neighbouring numbers
+            if error <= self.tolerance.at(m) || m == a || m == b
+            {
+                return Ok(RootResult::new(m, error, i, evaluations, true));
+            }
+
+            let fm: T = f(m);
+            evaluations += 1;
+            if fm == T::zero()
+            {
+                return Ok(RootResult::new(m, T::zero(), i + 1, evaluations, true));
+            }
+            if brackets(fa, fm)
+            {
+                b = m;
+            }
+            else
+            {
+                a = m;
+                fa = fm;
+            }
+        }
+
+        let m: T = a + (b - a) / two;
+        Ok(RootResult::new(m, (b - a).abs() / two, self.tolerance.iters(), evaluations, false))
+    }
+}
@@ -0,0 +1,160 @@
+//! Bracket expansion
+use crate::{algebra::abstr::Real, analysis::root::tolerance::brackets};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Automatic expansion of an interval to a bracket of a root
+///
+/// The interval $[a, b]$ is enlarged geometrically on the side with the
+/// smaller absolute function value, until f changes its sign. Optional
+/// bounds restrict the expansion to the domain of f, e.g. to positive
+/// volatilities or to the support of a distribution.
+///
+/// Press, W. H. et al.: Numerical Recipes, 3rd edition, Cambridge
+/// University Press, 2007, section 9.1
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     analysis::root::{BracketExpansion, Brent, RootResult},
+///     special::gamma,
+/// };
+///
+/// // 0.999 quantile of the gamma distribution with shape 3 and rate 1
+/// let f = |x: f64| gamma::gamma_lr(3.0, x) - 0.999;
+///
+/// let mut expansion: BracketExpansion<f64> = BracketExpansion::default();
+/// expansion.set_bounds(0.0, f64::INFINITY);
+///
+/// let (a, b): (f64, f64) = expansion.expand(f, 1.0, 2.0).unwrap();
+/// let result: RootResult<f64> = Brent::default().find_root(f, a, b).unwrap();
+///
+/// assert_relative_eq!(gamma::gamma_lr(3.0, result.root()), 0.999, epsilon = 1.0e-12);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct BracketExpansion<T>
+{
+    factor: T,
+    iters: usize,
+    lower: T,
+    upper: T,
+}
+
+impl<T> Default for BracketExpansion<T>
+    where T: Real
+{
+    fn default() -> BracketExpansion<T>
+    {
+        BracketExpansion::new(T::from_f64(1.6), 50)
+    }
+}
+
+impl<T> BracketExpansion<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'factor': growth factor, the interval $[a, b]$ is enlarged by
+    ///   factor * (b - a) per expansion
+    /// * 'iters': maximal number of expansions
+    ///
+    /// # Panics
+    ///
+    /// if the factor is not positive
+    pub fn new(factor: T, iters: usize) -> BracketExpansion<T>
+    {
+        if factor <= T::zero()
+        {
+            panic!("The factor is not positive");
+        }
+
+        BracketExpansion { factor,
+                           iters,
+                           lower: T::neg_infinity(),
+                           upper: T::infinity() }
+    }
+
+    /// Restricts the expansion to the interval $[lower, upper]$
+    ///
+    /// # Panics
+    ///
+    /// if lower > upper
+    pub fn set_bounds(&mut self, lower: T, upper: T)
+    {
+        if lower > upper
+        {
+            panic!("The lower bound is greater than the upper bound");
+        }
+        self.lower = lower;
+        self.upper = upper;
+    }
+
+    /// Expands the interval $[a, b]$ until f changes its sign
+    ///
+    /// Returns the bracket $[a', b'] \supseteq [a, b]$ with
+    /// $f(a')f(b') \leq 0$.
+    ///
+    /// # Errors
+    ///
+    /// if no sign change is found within the maximal number of expansions or
+    /// the bounds
+    ///
+    /// # Panics
+    ///
+    /// if a = b or the interval is not inside the bounds
+    pub fn expand<F>(&self, f: F, a: T, b: T) -> Result<(T, T), &'static str>
+        where F: Fn(T) -> T
+    {
+        if a == b
+        {
+            panic!("The interval is empty");
+        }
+        let (mut a, mut b): (T, T) = (a.min(b), a.max(b));
+        if a < self.lower || b > self.upper
+        {
+            panic!("The interval is not inside the bounds");
+        }
+
+        let mut fa: T = f(a);
+        let mut fb: T = f(b);
+        for _ in 0..self.iters
+        {
+            if brackets(fa, fb)
+            {
+                return Ok((a, b));
+            }
+
+            let width: T = self.factor * (b - a);
+            let a_expanded: T = (a - width).max(self.lower);
+            let b_expanded: T = (b + width).min(self.upper);
+            if (fa.abs() < fb.abs() && a_expanded < a) || b_expanded == b
+            {
+                if a_expanded == a
+                {
+                    return Err("No bracket found within the bounds");
+                }
+                a = a_expanded;
+                fa = f(a);
+            }
+            else
+            {
+                b = b_expanded;
+                fb = f(b);
+            }
+        }
+
+        if brackets(fa, fb)
+        {
+            return Ok((a, b));
+        }
+        Err("No bracket found")
+    }
+}
@@ -0,0 +1,188 @@
+//! Brent-Dekker method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{
+        tolerance::{brackets, Tolerance},
+        RootResult,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Brent-Dekker method
+///
+/// Combines inverse quadratic interpolation and the secant method with
+/// bisection. Interpolation steps are only accepted if they fall well inside
+/// the current bracket and reduce the step length fast enough, otherwise the
+/// bracket is bisected. The method converges superlinearly for smooth
+/// functions and never needs more than about the square of the number of
+/// bisection steps.
+///
+/// Brent, R. P.: Algorithms for Minimization without Derivatives,
+/// Prentice-Hall, 1973, chapter 4
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     analysis::root::{Brent, RootResult},
+///     special::gamma,
+/// };
+///
+/// // median of the gamma distribution with shape 3 and rate 1
+/// let cdf = |x: f64| gamma::gamma_lr(3.0, x) - 0.5;
+///
+/// let result: RootResult<f64> = Brent::default().find_root(cdf, 0.1, 10.0).unwrap();
+///
+/// assert_relative_eq!(result.root(), 2.674060313723561, epsilon = 1.0e-10);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Brent<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Brent<T>
+    where T: Real
+{
+    fn default() -> Brent<T>
+    {
+        Brent { tolerance: Tolerance::machine(200) }
+    }
+}
+
+impl<T> Brent<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Brent<T>
+    {
+        Brent { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f in the bracket $[a, b]$
+    ///
+    /// # Errors
+    ///
+    /// if f(a) and f(b) have the same sign
+    pub fn find_root<F>(&self, f: F, a: T, b: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T
+    {
+        let (mut a, mut b): (T, T) = (a, b);
+        let mut fa: T = f(a);
+        let mut fb: T = f(b);
+        let mut evaluations: usize = 2;
+
+        if fa == T::zero()
+        {
+            return Ok(RootResult::new(a, T::zero(), 0, evaluations, true));
+        }
+        if fb == T::zero()
+        {
+            return Ok(RootResult::new(b, T::zero(), 0, evaluations, true));
+        }
+        if !brackets(fa, fb)
+        {
+            return Err("The interval does not bracket a root");
+        }
+
+        let two: T = T::from_f64(2.0);
+        let three: T = T::from_f64(3.0);
+
+        // b is the best approximation, c the opposite end of the bracket, a
+        // the previous value of b
+        let (mut c, mut fc): (T, T) = (b, fb);
+        let mut d: T = b - a;
+        let mut e: T = d;
+
+        for i in 0..self.tolerance.iters()
+        {
+            if !brackets(fb, fc)
+            {
+                c = a;
+                fc = fa;
+                d = b - a;
+                e = d;
+            }
+            if fc.abs() < fb.abs()
+            {
+                a = b;
+                b = c;
+                c = a;
+                fa = fb;
+                fb = fc;
+                fc = fa;
+            }
+
+            let tol: T = self.tolerance.at(b) / two;
+            let m: T = (c - b) / two;
+            if m.abs() <= tol || fb == T::zero()
+            {
+                let error: T = if fb == T::zero() { T::zero() } else { (c - b).abs() };
+                return Ok(RootResult::new(b, error, i, evaluations, true));
+            }
+
+            if e.abs() >= tol && fa.abs() > fb.abs()
+            {
+                let s: T = fb / fa;
+                let (mut p, mut q): (T, T) = if a == c
+                {
+                    // secant
+                    (two * m * s, T::one() - s)
+                }
+                else
+                {
+                    // inverse quadratic interpolation
+                    let q: T = fa / fc;
+                    let r: T = fb / fc;
+                    (s * (two * m * q * (q - r) - (b - a) * (r - T::one())),
+                     (q - T::one()) * (r - T::one()) * (s - T::one()))
+                };
+                if p > T::zero()
+                {
+                    q = -q;
+                }
+                p = p.abs();
+
+                if two * p < (three * m * q - (tol * q).abs()).min((e * q).abs())
+                {
+                    e = d;
+                    d = p / q;
+                }
+                else
+                {
+                    d = m;
+                    e = d;
+                }
+            }
+            else
+            {
+                d = m;
+                e = d;
+            }
+
+            a = b;
+            fa = fb;
+            b += if d.abs() > tol { d } else { tol * m.sign() };
+            fb = f(b);
+            evaluations += 1;
+        }
+
+        Ok(RootResult::new(b, (c - b).abs(), self.tolerance.iters(), evaluations, false))
+    }
+}
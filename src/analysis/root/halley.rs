@@ -0,0 +1,122 @@
+//! Halley's method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{householder, tolerance::Tolerance, RootResult},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Halley's method
+///
+/// ```math
+/// x_{n + 1} = x_n - \frac{2f(x_n)f^{'}(x_n)}{2f^{'}(x_n)^2 - f(x_n)f^{''}(x_n)}
+/// ```
+/// Converges cubically close to a simple root and is preferable to Newton's
+/// method if the second derivative is cheap. Given a bracket, steps which
+/// leave the bracket are replaced with bisection steps.
+///
+/// Scavo, T. R., Thoo, J. B.: On the Geometry of Halley's Method, The
+/// American Mathematical Monthly, 102(5), 1995
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::root::{Halley, RootResult};
+///
+/// let f = |x: f64| x * x * x - 2.0;
+/// let df = |x: f64| 3.0 * x * x;
+/// let d2f = |x: f64| 6.0 * x;
+///
+/// let result: RootResult<f64> = Halley::default().find_root(f, df, d2f, 1.0).unwrap();
+///
+/// assert_relative_eq!(result.root(), 2.0f64.cbrt(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Halley<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Halley<T>
+    where T: Real
+{
+    fn default() -> Halley<T>
+    {
+        Halley { tolerance: Tolerance::machine(100) }
+    }
+}
+
+impl<T> Halley<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Halley<T>
+    {
+        Halley { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f with the derivatives df and d2f starting at x_0
+    ///
+    /// # Errors
+    ///
+    /// if the first derivative vanishes at an iterate or an iterate is not
+    /// finite
+    pub fn find_root<F, D, D2>(&self, f: F, df: D, d2f: D2, x_0: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T,
+              D2: Fn(T) -> T
+    {
+        householder::iterate(&self.tolerance, |x: T| Halley::step(&f, &df, &d2f, x), x_0, None)
+    }
+
+    /// Finds a root of f with the derivatives df and d2f in the bracket
+    /// $[a, b]$ starting at x_0
+    ///
+    /// # Errors
+    ///
+    /// if f(a) and f(b) have the same sign or x_0 is not in $[a, b]$
+    pub fn find_root_bracketed<F, D, D2>(&self, f: F, df: D, d2f: D2, x_0: T, a: T, b: T)
+                                         -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T,
+              D2: Fn(T) -> T
+    {
+        householder::iterate(&self.tolerance, |x: T| Halley::step(&f, &df, &d2f, x), x_0, Some((a, b)))
+    }
+
+    fn step<F, D, D2>(f: &F, df: &D, d2f: &D2, x: T) -> (T, Option<T>)
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T,
+              D2: Fn(T) -> T
+    {
+        let f_x: T = f(x);
+        let df_x: T = df(x);
+        if df_x == T::zero()
+        {
+            return (f_x, None);
+        }
+        let two: T = T::from_f64(2.0);
+        let denominator: T = two * df_x * df_x - f_x * d2f(x);
+        if denominator == T::zero()
+        {
+            // Newton step
+            return (f_x, Some(f_x / df_x));
+        }
+        (f_x, Some(two * f_x * df_x / denominator))
+    }
+}
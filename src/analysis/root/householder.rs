@@ -0,0 +1,106 @@
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{
+        tolerance::{brackets, is_finite, Tolerance},
+        RootResult,
+    },
+};
+
+/// Iterates $x_{n + 1} = x_n - s(x_n)$ for the Householder methods
+///
+/// step returns $f(x)$ and the step $s(x)$, which is None if it is not
+/// defined. With a bracket, which has to contain x_0, steps which leave the
+/// bracket or are not defined are replaced with bisection steps.
+pub(crate) fn iterate<T, S>(tolerance: &Tolerance<T>, step: S, x_0: T, bracket: Option<(T, T)>)
+                            -> Result<RootResult<T>, &'static str>
+    where T: Real,
+          S: Fn(T) -> (T, Option<T>)
+{
+    let mut evaluations: usize = 0;
+    let mut bracket: Option<(T, T, T)> = match bracket
+    {
+        Some((a, b)) =>
+        {
+            if x_0 < a.min(b) || x_0 > a.max(b)
+            {
+                return Err("The starting value is not in the interval");
+            }
+            let (fa, _): (T, Option<T>) = step(a);
+            let (fb, _): (T, Option<T>) = step(b);
+            evaluations += 2;
+            if fa == T::zero()
+            {
+                return Ok(RootResult::new(a, T::zero(), 0, evaluations, true));
+            }
+            if fb == T::zero()
+            {
+                return Ok(RootResult::new(b, T::zero(), 0, evaluations, true));
+            }
+            if !brackets(fa, fb)
+            {
+                return Err("The interval does not bracket a root");
+            }
+            Some((a.min(b), a.max(b), if a < b { fa } else { fb }))
+        }
+        None => None,
+    };
+
+    let mut x: T = x_0;
+    let mut delta: T = T::infinity();
+    for i in 0..tolerance.iters()
+    {
+        let (f_x, s): (T, Option<T>) = step(x);
+        evaluations += 1;
+        if f_x == T::zero()
+        {
+            return Ok(RootResult::new(x, T::zero(), i, evaluations, true));
+        }
+
+        let x_next: T = match bracket.as_mut()
+        {
+            None =>
+            {
+                let s: T = s.ok_or("The derivative is zero")?;
+                let x_next: T = x - s;
+                if !is_finite(x_next)
+                {
+                    return Err("The iteration diverges");
+                }
+                x_next
+            }
+            Some((lower, upper, f_lower)) =>
+            {
+                if brackets(*f_lower, f_x)
+                {
+                    *upper = x;
+                }
+                else
+                {
+                    *lower = x;
+                    *f_lower = f_x;
+                }
+                match s
+                {
+                    Some(s) if x - s > *lower && x - s < *upper => x - s,
+                    _ => *lower + (*upper - *lower) / T::from_f64(2.0),
+                }
+            }
+        };
+
+        delta = (x_next - x).abs();
+        x = x_next;
+        if delta <= tolerance.at(x)
+        {
+            return Ok(RootResult::new(x, delta, i + 1, evaluations, true));
+        }
+        if let Some((lower, upper, _)) = bracket
+        {
+            if upper - lower <= tolerance.at(x)
+            {
+                return Ok(RootResult::new(x, upper - lower, i + 1, evaluations, true));
+            }
+        }
+    }
+
+    Ok(RootResult::new(x, delta, tolerance.iters(), evaluations, false))
+}
@@ -0,0 +1,140 @@
+//! Illinois method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{
+        tolerance::{brackets, Tolerance},
+        RootResult,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Illinois method
+///
+/// Regula falsi, which replaces the bracket end with the same sign as the
+/// root of the secant through $(a, f(a))$ and $(b, f(b))$. If the new
+/// function value has the same sign as the previous one, the function value
+/// at the retained end is halved. This modification prevents the
+/// stagnation of the plain regula falsi and results in superlinear
+/// convergence of order $3^{1/3} \approx 1.44$.
+///
+/// Dowell, M., Jarratt, P.: A modified regula falsi method for computing the
+/// root of an equation, BIT, 11, 1971
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     analysis::root::{Illinois, RootResult},
+///     elementary::Trigonometry,
+/// };
+///
+/// let result: RootResult<f64> = Illinois::default().find_root(|x: f64| x.cos() - x, 0.0, 1.0).unwrap();
+///
+/// assert_relative_eq!(result.root(), 0.7390851332151607, epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Illinois<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Illinois<T>
+    where T: Real
+{
+    fn default() -> Illinois<T>
+    {
+        Illinois { tolerance: Tolerance::machine(100) }
+    }
+}
+
+impl<T> Illinois<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Illinois<T>
+    {
+        Illinois { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f in the bracket $[a, b]$
+    ///
+    /// # Errors
+    ///
+    /// if f(a) and f(b) have the same sign
+    pub fn find_root<F>(&self, f: F, a: T, b: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T
+    {
+        let (mut a, mut b): (T, T) = (a, b);
+        let mut fa: T = f(a);
+        let mut fb: T = f(b);
+        let mut evaluations: usize = 2;
+
+        if fa == T::zero()
+        {
+            return Ok(RootResult::new(a, T::zero(), 0, evaluations, true));
+        }
+        if fb == T::zero()
+        {
+            return Ok(RootResult::new(b, T::zero(), 0, evaluations, true));
+        }
+        if !brackets(fa, fb)
+        {
+            return Err("The interval does not bracket a root");
+        }
+
+        // b is the latest approximation, a the opposite end of the bracket
+        for i in 0..self.tolerance.iters()
+        {
+            let error: T = (b - a).abs();
+            if error <= self.tolerance.at(b)
+            {
+                return Ok(RootResult::new(b, error, i, evaluations, true));
+            }
+
+            let c: T = b - fb * (b - a) / (fb - fa);
+            // the secant root coincides with a bound if a and b are
+            // neighbouring numbers
+            if c == a || c == b
+            {
+                return Ok(RootResult::new(b, error, i, evaluations, true));
+            }
+
+            let fc: T = f(c);
+            evaluations += 1;
+            if fc == T::zero()
+            {
+                return Ok(RootResult::new(c, T::zero(), i + 1, evaluations, true));
+            }
+
+            if brackets(fb, fc)
+            {
+                a = b;
+                fa = fb;
+            }
+            else
+            {
+                fa /= T::from_f64(2.0);
+            }
+            b = c;
+            fb = fc;
+        }
+
+        Ok(RootResult::new(b, (b - a).abs(), self.tolerance.iters(), evaluations, false))
+    }
+}
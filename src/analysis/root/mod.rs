@@ -0,0 +1,35 @@
+//! Scalar root finding
+//!
+//! Fore more information: <br>
+//! <https://en.wikipedia.org/wiki/Root-finding_algorithms>
+//!
+//! Solvers for equations $f(x) = 0$ with $f: \mathbb{R} \rightarrow
+//! \mathbb{R}$. All solvers return a [`RootResult`] with the approximation,
+//! an error estimate and the number of iterations and function evaluations.
+//!
+//! | Solver | Requires | Convergence |
+//! |--------|----------|-------------|
+//! | [`Bisection`] | bracket | linear, guaranteed |
+//! | [`Brent`] | bracket | superlinear, guaranteed |
+//! | [`Illinois`] | bracket | order 1.44, guaranteed |
+//! | [`Secant`] | two starting values | order 1.62, local |
+//! | [`Newton`] | f' and a starting value | quadratic, local or safeguarded by a bracket |
+//! | [`Halley`] | f', f'' and a starting value | cubic, local or safeguarded by a bracket |
+//!
+//! [`BracketExpansion`] enlarges an interval until it brackets a root.
+
+mod bisection;
+mod bracket;
+mod brent;
+mod halley;
+mod householder;
+mod illinois;
+mod newton;
+mod root_result;
+mod secant;
+mod tolerance;
+
+pub use self::{
+    bisection::Bisection, bracket::BracketExpansion, brent::Brent, halley::Halley, illinois::Illinois, newton::Newton,
+    root_result::RootResult, secant::Secant,
+};
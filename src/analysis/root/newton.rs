@@ -0,0 +1,109 @@
+//! Newton's method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{householder, tolerance::Tolerance, RootResult},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Newton's method for scalar equations
+///
+/// ```math
+/// x_{n + 1} = x_n - \frac{f(x_n)}{f^{'}(x_n)}
+/// ```
+/// Converges quadratically close to a simple root. Given a bracket, steps
+/// which leave the bracket are replaced with bisection steps, which makes
+/// the iteration globally convergent.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::{
+///     analysis::root::{Newton, RootResult},
+///     elementary::Exponential,
+/// };
+///
+/// let f = |x: f64| x.exp() - 2.0;
+/// let df = |x: f64| x.exp();
+///
+/// let result: RootResult<f64> = Newton::default().find_root(f, df, 0.0).unwrap();
+///
+/// assert_relative_eq!(result.root(), 2.0f64.ln(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Newton<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Newton<T>
+    where T: Real
+{
+    fn default() -> Newton<T>
+    {
+        Newton { tolerance: Tolerance::machine(100) }
+    }
+}
+
+impl<T> Newton<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Newton<T>
+    {
+        Newton { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f with the derivative df starting at x_0
+    ///
+    /// # Errors
+    ///
+    /// if the derivative vanishes at an iterate or an iterate is not finite
+    pub fn find_root<F, D>(&self, f: F, df: D, x_0: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T
+    {
+        householder::iterate(&self.tolerance, |x: T| Newton::step(&f, &df, x), x_0, None)
+    }
+
+    /// Finds a root of f with the derivative df in the bracket $[a, b]$
+    /// starting at x_0
+    ///
+    /// # Errors
+    ///
+    /// if f(a) and f(b) have the same sign or x_0 is not in $[a, b]$
+    pub fn find_root_bracketed<F, D>(&self, f: F, df: D, x_0: T, a: T, b: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T
+    {
+        householder::iterate(&self.tolerance, |x: T| Newton::step(&f, &df, x), x_0, Some((a, b)))
+    }
+
+    fn step<F, D>(f: &F, df: &D, x: T) -> (T, Option<T>)
+        where F: Fn(T) -> T,
+              D: Fn(T) -> T
+    {
+        let f_x: T = f(x);
+        let df_x: T = df(x);
+        if df_x == T::zero()
+        {
+            return (f_x, None);
+        }
+        (f_x, Some(f_x / df_x))
+    }
+}
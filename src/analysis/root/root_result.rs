@@ -0,0 +1,66 @@
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Result of a scalar root finder
+///
+/// Contains the approximation of the root, an estimate of its absolute
+/// error, the number of iterations and function evaluations and whether the
+/// requested tolerance has been reached.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct RootResult<T>
+{
+    root: T,
+    error: T,
+    iterations: usize,
+    evaluations: usize,
+    converged: bool,
+}
+
+impl<T> RootResult<T>
+    where T: Real
+{
+    pub fn new(root: T, error: T, iterations: usize, evaluations: usize, converged: bool) -> RootResult<T>
+    {
+        RootResult { root,
+                     error,
+                     iterations,
+                     evaluations,
+                     converged }
+    }
+
+    /// Approximation of the root
+    pub fn root(&self) -> T
+    {
+        self.root
+    }
+
+    /// Estimate of the absolute error
+    ///
+    /// For bracketing methods, the error is bounded by the width of the
+    /// final bracket, otherwise it is the length of the last step.
+    pub fn error(&self) -> T
+    {
+        self.error
+    }
+
+    /// Number of iterations
+    pub fn iterations(&self) -> usize
+    {
+        self.iterations
+    }
+
+    /// Number of evaluations of the function
+    pub fn evaluations(&self) -> usize
+    {
+        self.evaluations
+    }
+
+    /// Returns true if the error estimate satisfies the requested tolerance
+    pub fn is_converged(&self) -> bool
+    {
+        self.converged
+    }
+}
@@ -0,0 +1,115 @@
+//! Secant method
+use crate::{
+    algebra::abstr::Real,
+    analysis::root::{
+        tolerance::{is_finite, Tolerance},
+        RootResult,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Secant method
+///
+/// ```math
+/// x_{n + 1} = x_n - f(x_n)\frac{x_n - x_{n - 1}}{f(x_n) - f(x_{n - 1})}
+/// ```
+/// Replaces the derivative of Newton's method with a difference quotient of
+/// the last two iterates. Converges with order $\frac{1 + \sqrt{5}}{2}$
+/// close to a simple root, but does not require a bracket and may diverge.
+///
+/// # Example
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate mathru;
+/// # fn main()
+/// # {
+/// use mathru::analysis::root::{RootResult, Secant};
+///
+/// let result: RootResult<f64> = Secant::default().find_root(|x: f64| x * x * x - 2.0, 1.0, 2.0).unwrap();
+///
+/// assert_relative_eq!(result.root(), 2.0f64.cbrt(), epsilon = 1.0e-14);
+/// # }
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub struct Secant<T>
+{
+    tolerance: Tolerance<T>,
+}
+
+impl<T> Default for Secant<T>
+    where T: Real
+{
+    fn default() -> Secant<T>
+    {
+        Secant { tolerance: Tolerance::machine(100) }
+    }
+}
+
+impl<T> Secant<T>
+    where T: Real
+{
+    /// # Arguments
+    ///
+    /// * 'abs_tol': absolute tolerance
+    /// * 'rel_tol': relative tolerance
+    /// * 'iters': maximal number of iterations
+    ///
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Secant<T>
+    {
+        Secant { tolerance: Tolerance::new(abs_tol, rel_tol, iters) }
+    }
+
+    /// Finds a root of f starting with the approximations x_0 and x_1
+    ///
+    /// # Errors
+    ///
+    /// if the secant through two iterates is horizontal or an iterate is not
+    /// finite
+    pub fn find_root<F>(&self, f: F, x_0: T, x_1: T) -> Result<RootResult<T>, &'static str>
+        where F: Fn(T) -> T
+    {
+        let (mut x_prev, mut x): (T, T) = (x_0, x_1);
+        let mut f_prev: T = f(x_prev);
+        let mut f_x: T = f(x);
+        let mut evaluations: usize = 2;
+        let mut step: T = (x - x_prev).abs();
+
+        for i in 0..self.tolerance.iters()
+        {
+            if f_x == T::zero()
+            {
+                return Ok(RootResult::new(x, T::zero(), i, evaluations, true));
+            }
+            if f_x == f_prev
+            {
+                return Err("The secant is horizontal");
+            }
+
+            let x_next: T = x - f_x * (x - x_prev) / (f_x - f_prev);
+            if !is_finite(x_next)
+            {
+                return Err("The iteration diverges");
+            }
+            step = (x_next - x).abs();
+            x_prev = x;
+            f_prev = f_x;
+            x = x_next;
+            f_x = f(x);
+            evaluations += 1;
+
+            if step <= self.tolerance.at(x)
+            {
+                return Ok(RootResult::new(x, step, i + 1, evaluations, true));
+            }
+        }
+
+        Ok(RootResult::new(x, step, self.tolerance.iters(), evaluations, f_x == T::zero()))
+    }
+}
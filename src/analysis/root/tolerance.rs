@@ -0,0 +1,69 @@
+use crate::algebra::abstr::Real;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Termination criterion of the scalar root finders
+///
+/// An approximation x is accepted, if its error is below
+/// $\tau_{abs} + \tau_{rel}|x|$ or the function vanishes at x.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Tolerance<T>
+{
+    abs_tol: T,
+    rel_tol: T,
+    iters: usize,
+}
+
+impl<T> Tolerance<T>
+    where T: Real
+{
+    /// # Panics
+    ///
+    /// if a tolerance is negative or both tolerances are zero
+    pub fn new(abs_tol: T, rel_tol: T, iters: usize) -> Tolerance<T>
+    {
+        if abs_tol < T::zero() || rel_tol < T::zero() || (abs_tol == T::zero() && rel_tol == T::zero())
+        {
+            panic!("The tolerances are not valid");
+        }
+
+        Tolerance { abs_tol,
+                    rel_tol,
+                    iters }
+    }
+
+    /// Tolerances of a few units in the last place
+    pub fn machine(iters: usize) -> Tolerance<T>
+    {
+        let eps: T = T::default_epsilon();
+        Tolerance::new(eps, T::from_f64(2.0) * eps, iters)
+    }
+
+    /// Maximal number of iterations
+    pub fn iters(&self) -> usize
+    {
+        self.iters
+    }
+
+    /// Accepted absolute error at x
+    pub fn at(&self, x: T) -> T
+    {
+        self.abs_tol + self.rel_tol * x.abs()
+    }
+}
+
+/// Returns true if the function values fa and fb do not have the same sign
+pub(crate) fn brackets<T>(fa: T, fb: T) -> bool
+    where T: Real
+{
+    (fa <= T::zero() && fb >= T::zero()) || (fa >= T::zero() && fb <= T::zero())
+}
+
+/// Returns true if x is neither infinite nor NaN
+pub(crate) fn is_finite<T>(x: T) -> bool
+    where T: Real
+{
+    x.abs() < T::infinity()
+}
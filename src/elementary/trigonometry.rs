@@ -198,6 +198,10 @@ impl<T> Trigonometry for Complex<T>
     /// # Example
     ///
     /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
     /// use mathru::{elementary::Trigonometry};
     /// use mathru::algebra::abstr::Complex;
     ///
@@ -210,15 +214,12 @@ impl<T> Trigonometry for Complex<T>
     /// let uut: Complex<f64> = z.sin();
     /// let refer: Complex<f64> = Complex::new(re, im);
     ///
-    /// assert_eq!(refer, uut);
+    /// assert_relative_eq!(refer, uut);
+    /// # }
     /// ```
     fn sin(self) -> Self
     {
-        let a: Complex<T> = Complex::new(-self.im, self.re);
-        let b: Complex<T> = Complex::new(self.im, -self.re);
-        let c: Complex<T> = Complex::new(T::zero(), T::one() + T::one());
-
-        (a.exp() - b.exp()) / c
+        Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
     }
 
     /// Cosine function
@@ -226,6 +227,10 @@ impl<T> Trigonometry for Complex<T>
     /// # Example
     ///
     /// ```
+    /// # #[macro_use]
+    /// # extern crate mathru;
+    /// # fn main()
+    /// # {
     /// use mathru::{elementary::Trigonometry};
     /// use mathru::algebra::abstr::Complex;
     ///
@@ -238,15 +243,12 @@ impl<T> Trigonometry for Complex<T>
     ///
     /// let uut: Complex<f64> = z.cos();
     ///
-    /// assert_eq!(refer, uut);
+    /// assert_relative_eq!(refer, uut);
+    /// # }
     /// ```
     fn cos(self) -> Self
     {
-        let a: Complex<T> = Complex::new(-self.im, self.re);
-        let b: Complex<T> = Complex::new(self.im, -self.re);
-        let c: Complex<T> = Complex::new(T::one() + T::one(), T::zero());
-
-        (a.exp() + b.exp()) / c
+        Complex::new(self.re.cos() * self.im.cosh(), -self.re.sin() * self.im.sinh())
     }
 
     /// tangents function
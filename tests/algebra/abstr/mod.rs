@@ -30,6 +30,7 @@ mod complex;
 //pub use self::complex::Complex;
 
 mod polynomial;
+mod rational;
 // #[macro_use]
 //pub mod real;
 //pub mod integer;
@@ -1,4 +1,5 @@
-use mathru::algebra::abstr::Polynomial;
+use mathru::algebra::abstr::{Complex, Polynomial};
+use mathru::algebra::linear::Matrix;
 use crate::mathru::algebra::abstr::Zero;
 
 #[test]
@@ -369,4 +370,397 @@ fn from_chebyshev_u_2()
     let p_ref = Polynomial::from_coef(vec![4.0, 0.0, -1.0]);
 
     assert_eq!(p_ref, p);
-}
\ No newline at end of file
+}
+#[test]
+fn companion()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![2.0, 0.0, -4.0, 6.0]);
+    let companion_ref: Matrix<f64> = Matrix::new(3, 3, vec![0.0, 1.0, 0.0, 0.0, 0.0, 1.0, -3.0, 2.0, 0.0]);
+
+    assert_eq!(companion_ref, poly.companion());
+}
+
+#[test]
+#[should_panic]
+fn companion_constant()
+{
+    Polynomial::from_coef(vec![2.0]).companion();
+}
+
+#[test]
+fn roots_real()
+{
+    let poly: Polynomial<f64> = Polynomial::from_root(vec![3.0, -1.0, 0.5, 2.0]);
+    let roots: Vec<Complex<f64>> = poly.roots();
+
+    assert_eq!(4, roots.len());
+    for (root, root_ref) in roots.iter().zip(vec![-1.0, 0.5, 2.0, 3.0])
+    {
+        assert_relative_eq!(root_ref, root.re, epsilon = 1.0e-13);
+        assert_eq!(0.0, root.im);
+    }
+}
+
+#[test]
+fn roots_complex()
+{
+    // (x - 2)(x^2 + 2x + 5) = x^3 + x - 10
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0, -10.0]);
+    let roots: Vec<Complex<f64>> = poly.roots();
+    let roots_ref: Vec<Complex<f64>> = vec![Complex::new(-1.0, -2.0), Complex::new(-1.0, 2.0), Complex::new(2.0, 0.0)];
+
+    assert_eq!(3, roots.len());
+    for (root, root_ref) in roots.iter().zip(roots_ref.iter())
+    {
+        assert_relative_eq!(root_ref.re, root.re, epsilon = 1.0e-13);
+        assert_relative_eq!(root_ref.im, root.im, epsilon = 1.0e-13);
+    }
+    assert_eq!(roots[0].re, roots[1].re);
+    assert_eq!(roots[0].im, -roots[1].im);
+}
+
+#[test]
+fn roots_zero()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![0.0, 1.0, -1.0, 0.0, 0.0]);
+    let roots: Vec<Complex<f64>> = poly.roots();
+
+    assert_eq!(vec![Complex::new(0.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)], roots);
+}
+
+#[test]
+fn roots_nan()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, f64::NAN, 1.0, 0.0]);
+    let roots: Vec<Complex<f64>> = poly.roots();
+
+    assert_eq!(3, roots.len());
+    assert_eq!(Complex::new(0.0, 0.0), roots[0]);
+    assert!(roots[1].re.is_nan());
+    assert!(roots[2].re.is_nan());
+}
+
+#[test]
+fn roots_constant()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![0.0, 0.0, 3.0]);
+
+    assert!(poly.roots().is_empty());
+}
+
+#[test]
+fn roots_wilkinson()
+{
+    let poly: Polynomial<f64> = Polynomial::from_root((1..=10).map(|k| k as f64).collect());
+    let roots: Vec<Complex<f64>> = poly.roots();
+
+    assert_eq!(10, roots.len());
+    for (k, root) in roots.iter().enumerate()
+    {
+        assert_relative_eq!((k + 1) as f64, root.re, epsilon = 1.0e-8);
+        assert_eq!(0.0, root.im);
+    }
+}
+
+#[test]
+fn roots_unity()
+{
+    // x^8 - 1
+    let mut coef: Vec<f64> = vec![0.0; 9];
+    coef[0] = 1.0;
+    coef[8] = -1.0;
+    let poly: Polynomial<f64> = Polynomial::from_coef(coef);
+
+    for root in poly.roots()
+    {
+        assert_relative_eq!(1.0, (root.re * root.re + root.im * root.im).sqrt(), epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+fn polish_root()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+    let root: Complex<f64> = poly.polish_root(Complex::new(0.1, 0.9));
+
+    assert_relative_eq!(0.0, root.re, epsilon = 1.0e-15);
+    assert_relative_eq!(1.0, root.im, epsilon = 1.0e-15);
+}
+
+#[test]
+fn sturm_sequence()
+{
+    // x^3 - 3x + 1
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, -3.0, 1.0]);
+    let sequence: Vec<Polynomial<f64>> = poly.sturm_sequence();
+
+    assert_eq!(4, sequence.len());
+    assert_eq!(0, sequence[3].degree());
+}
+
+#[test]
+fn count_real_roots()
+{
+    // (x + 2)(x - 1)^2(x^2 + 1)
+    let poly: Polynomial<f64> = Polynomial::from_root(vec![-2.0, 1.0, 1.0]) * Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+
+    assert_eq!(2, poly.count_real_roots(-10.0, 10.0));
+    assert_eq!(1, poly.count_real_roots(-10.0, 0.0));
+    assert_eq!(1, poly.count_real_roots(0.0, 1.5));
+    assert_eq!(0, poly.count_real_roots(1.5, 10.0));
+}
+
+#[test]
+#[should_panic]
+fn count_real_roots_interval()
+{
+    Polynomial::from_coef(vec![1.0, 0.0, -1.0]).count_real_roots(1.0, -1.0);
+}
+
+#[test]
+fn isolate_real_roots()
+{
+    let roots_ref: Vec<f64> = vec![-3.0, 0.1, 0.2, 7.0];
+    let poly: Polynomial<f64> = Polynomial::from_root(roots_ref.clone());
+    let intervals: Vec<(f64, f64)> = poly.isolate_real_roots();
+
+    assert_eq!(4, intervals.len());
+    for ((a, b), root) in intervals.into_iter().zip(roots_ref)
+    {
+        assert!(a < root && root <= b);
+    }
+}
+
+#[test]
+fn real_roots()
+{
+    // (x - 1)^2(x + 0.5)(x^2 + 4)
+    let poly: Polynomial<f64> = Polynomial::from_root(vec![1.0, 1.0, -0.5]) * Polynomial::from_coef(vec![1.0, 0.0, 4.0]);
+    let roots: Vec<f64> = poly.real_roots();
+
+    assert_eq!(2, roots.len());
+    assert_relative_eq!(-0.5, roots[0], epsilon = 1.0e-14);
+    assert_relative_eq!(1.0, roots[1], epsilon = 1.0e-10);
+}
+
+#[test]
+fn real_roots_wilkinson()
+{
+    let poly: Polynomial<f64> = Polynomial::from_root((1..=16).map(|k| k as f64).collect());
+    let roots: Vec<f64> = poly.real_roots();
+
+    assert_eq!(16, poly.count_real_roots(0.0, 17.0));
+    assert_eq!(16, roots.len());
+    for (k, root) in roots.iter().enumerate()
+    {
+        assert_relative_eq!((k + 1) as f64, *root, epsilon = 1.0e-4);
+    }
+}
+
+#[test]
+fn real_roots_none()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+
+    assert!(poly.real_roots().is_empty());
+}
+
+#[test]
+fn div_rem()
+{
+    // (2x^4 - 3x^2 + x - 5) = (x^2 + 1)(2x^2 - 5) + (x)
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![2.0, 0.0, -3.0, 1.0, -5.0]);
+    let divisor: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+
+    let (quotient, remainder): (Polynomial<f64>, Polynomial<f64>) = poly.div_rem(&divisor);
+
+    assert_eq!(Polynomial::from_coef(vec![2.0, 0.0, -5.0]), quotient);
+    assert_eq!(Polynomial::from_coef(vec![1.0, 0.0]), remainder);
+}
+
+#[test]
+fn div_rem_degree()
+{
+    let poly: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 2.0]);
+    let divisor: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+
+    let (quotient, remainder): (Polynomial<f64>, Polynomial<f64>) = poly.div_rem(&divisor);
+
+    assert_eq!(Polynomial::zero(), quotient);
+    assert_eq!(poly, remainder);
+}
+
+#[test]
+#[should_panic]
+fn div_rem_zero()
+{
+    Polynomial::from_coef(vec![1.0, 2.0]).div_rem(&Polynomial::from_coef(vec![0.0, 0.0]));
+}
+
+#[test]
+fn gcd()
+{
+    let a: Polynomial<f64> = Polynomial::from_root(vec![0.5, 2.0, 2.0, -1.0]);
+    let b: Polynomial<f64> = Polynomial::from_root(vec![2.0, 2.0, 3.0]);
+
+    let gcd: Polynomial<f64> = a.gcd(&b);
+
+    assert_eq!(2, gcd.degree());
+    assert_relative_eq!(Polynomial::from_root(vec![2.0, 2.0]), gcd, epsilon = 1.0e-12);
+}
+
+#[test]
+fn gcd_coprime()
+{
+    let a: Polynomial<f64> = Polynomial::from_root(vec![1.0, 2.0]);
+    let b: Polynomial<f64> = Polynomial::from_root(vec![3.0]);
+
+    assert_eq!(Polynomial::from_coef(vec![1.0]), a.gcd(&b));
+}
+
+#[test]
+fn compose()
+{
+    let p: Polynomial<f64> = Polynomial::from_chebyshev_t(3);
+    let q: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -1.0, 2.0]);
+    let composition: Polynomial<f64> = p.compose(&q);
+
+    assert_eq!(6, composition.degree());
+    for x in [-1.5, -0.3, 0.0, 0.8, 2.0]
+    {
+        assert_relative_eq!(p.eval(q.eval(x)), composition.eval(x), epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn eval_complex()
+{
+    let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+
+    assert_eq!(Complex::new(0.0, 0.0), p.eval_complex(Complex::new(0.0, 1.0)));
+    assert_eq!(Complex::new(-2.0, 4.0), p.eval_complex(Complex::new(1.0, 2.0)));
+}
+
+#[test]
+fn eval_matrix()
+{
+    // p(x) = x^2 - 2x + 3
+    let p: Polynomial<f64> = Polynomial::from_coef(vec![1.0, -2.0, 3.0]);
+    let a: Matrix<f64> = Matrix::new(2, 2, vec![1.0, 0.0, 2.0, -1.0]);
+
+    assert_eq!(Matrix::new(2, 2, vec![2.0, 0.0, -4.0, 6.0]), p.eval_matrix(&a));
+}
+
+#[test]
+#[should_panic]
+fn eval_matrix_not_square()
+{
+    Polynomial::from_coef(vec![1.0, 0.0]).eval_matrix(&Matrix::<f64>::zero(2, 3));
+}
+
+#[test]
+fn fit()
+{
+    let x: Vec<f64> = (0..20).map(|i| 100.0 + 0.5 * i as f64).collect();
+    let y: Vec<f64> = x.iter().map(|x_i| 0.5 * x_i * x_i * x_i - 2.0 * x_i + 1.0).collect();
+
+    let p: Polynomial<f64> = Polynomial::fit(&x, &y, 3);
+
+    for (x_i, y_i) in x.iter().zip(y.iter())
+    {
+        assert_relative_eq!(*y_i, p.eval(*x_i), max_relative = 1.0e-10);
+    }
+}
+
+#[test]
+fn fit_least_squares()
+{
+    // the least squares line through (0, 0), (1, 1), (2, 1), (3, 2) is 0.6x + 0.1
+    let p: Polynomial<f64> = Polynomial::fit(&[0.0, 1.0, 2.0, 3.0], &[0.0, 1.0, 1.0, 2.0], 1);
+
+    assert_relative_eq!(Polynomial::from_coef(vec![0.6, 0.1]), p, epsilon = 1.0e-14);
+}
+
+#[test]
+#[should_panic]
+fn fit_distinct_points()
+{
+    Polynomial::fit(&[1.0, 1.0, 2.0, 2.0], &[0.0, 1.0, 2.0, 3.0], 2);
+}
+
+#[test]
+#[should_panic]
+fn fit_dimension()
+{
+    Polynomial::fit(&[0.0, 1.0, 2.0], &[0.0, 1.0], 1);
+}
+
+#[test]
+fn hermite_h()
+{
+    let p: Polynomial<f64> = Polynomial::from_hermite_h(4);
+
+    assert_eq!(Polynomial::from_coef(vec![16.0, 0.0, -48.0, 0.0, 12.0]), p);
+}
+
+#[test]
+fn hermite_he()
+{
+    let p: Polynomial<f64> = Polynomial::from_hermite_he(4);
+
+    assert_eq!(Polynomial::from_coef(vec![1.0, 0.0, -6.0, 0.0, 3.0]), p);
+}
+
+#[test]
+fn laguerre()
+{
+    let p: Polynomial<f64> = Polynomial::from_laguerre(3);
+
+    assert_relative_eq!(Polynomial::from_coef(vec![-1.0 / 6.0, 1.5, -3.0, 1.0]), p, epsilon = 1.0e-14);
+}
+
+#[test]
+fn generalized_laguerre()
+{
+    // L_n^(alpha)(0) = binom(n + alpha, n)
+    let p: Polynomial<f64> = Polynomial::from_generalized_laguerre(4, 2.5);
+
+    assert_eq!(4, p.degree());
+    assert_relative_eq!(6.5 * 5.5 * 4.5 * 3.5 / 24.0, p.eval(0.0), epsilon = 1.0e-12);
+}
+
+#[test]
+#[should_panic]
+fn generalized_laguerre_alpha()
+{
+    Polynomial::from_generalized_laguerre(2, -1.0);
+}
+
+#[test]
+fn gegenbauer()
+{
+    // C_n^(1) is the Chebyshev polynomial of second kind
+    let p: Polynomial<f64> = Polynomial::from_gegenbauer(5, 1.0);
+
+    assert_relative_eq!(Polynomial::from_chebyshev_u(5), p, epsilon = 1.0e-12);
+}
+
+#[test]
+fn jacobi()
+{
+    // P_n^(alpha, beta)(1) = binom(n + alpha, n)
+    let p: Polynomial<f64> = Polynomial::from_jacobi(3, 1.5, -0.5);
+
+    assert_eq!(3, p.degree());
+    assert_relative_eq!(4.5 * 3.5 * 2.5 / 6.0, p.eval(1.0), epsilon = 1.0e-12);
+}
+
+#[test]
+fn jacobi_gegenbauer()
+{
+    // P_n^(a, a) = (a + 1)_n / (2a + 1)_n C_n^(a + 1/2)
+    let p: Polynomial<f64> = Polynomial::from_jacobi(2, 1.0, 1.0);
+    let c: Polynomial<f64> = Polynomial::from_gegenbauer(2, 1.5);
+
+    assert_relative_eq!(&c * &Polynomial::from_coef(vec![2.0 * 3.0 / (3.0 * 4.0)]), p, epsilon = 1.0e-12);
+}
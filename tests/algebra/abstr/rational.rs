@@ -0,0 +1,257 @@
+use mathru::algebra::abstr::{Complex, PartialFraction, Polynomial, Rational};
+
+#[test]
+#[should_panic]
+fn new_zero_denominator()
+{
+    Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![0.0, 0.0]));
+}
+
+#[test]
+fn eval()
+{
+    // r(x) = (x^2 + 1) / (x - 2)
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0, 1.0]), Polynomial::from_coef(vec![1.0, -2.0]));
+
+    assert_eq!(-6.5, r.eval(1.5));
+    assert_eq!(10.0, r.eval(3.0));
+    assert!(r.eval(2.0).is_infinite());
+}
+
+#[test]
+fn fmt()
+{
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, 2.0]));
+
+    assert_eq!("(1) / (2 + 1x)", format!("{}", r));
+}
+
+#[test]
+fn add()
+{
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+    let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![2.0, 0.0]), Polynomial::from_coef(vec![1.0, 3.0]));
+    let c: Rational<f64> = &a + &b;
+
+    for x in [-2.0, 0.5, 4.0]
+    {
+        assert_relative_eq!(a.eval(x) + b.eval(x), c.eval(x), epsilon = 1.0e-14);
+    }
+    assert_eq!(c, a + b);
+}
+
+#[test]
+fn add_same_denominator()
+{
+    let den: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), den.clone());
+    let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0]), den.clone());
+
+    let c: Rational<f64> = a + b;
+
+    assert_eq!(Polynomial::from_coef(vec![1.0, 1.0]), *c.numerator());
+    assert_eq!(den, *c.denominator());
+}
+
+#[test]
+fn sub()
+{
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 2.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+    let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![3.0]), Polynomial::from_coef(vec![1.0, 0.0, 2.0]));
+    let c: Rational<f64> = &a - &b;
+
+    for x in [-2.0, 0.5, 4.0]
+    {
+        assert_relative_eq!(a.eval(x) - b.eval(x), c.eval(x), epsilon = 1.0e-14);
+    }
+    assert_eq!(c, a - b);
+}
+
+#[test]
+fn mul()
+{
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 2.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+    let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![3.0, 0.0]), Polynomial::from_coef(vec![1.0, 0.0, 2.0]));
+    let c: Rational<f64> = &a * &b;
+
+    for x in [-2.0, 0.5, 4.0]
+    {
+        assert_relative_eq!(a.eval(x) * b.eval(x), c.eval(x), epsilon = 1.0e-14);
+    }
+    assert_eq!(c, a * b);
+}
+
+#[test]
+fn div()
+{
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 2.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+    let b: Rational<f64> = Rational::new(Polynomial::from_coef(vec![3.0, 1.0]), Polynomial::from_coef(vec![1.0, 0.0, 2.0]));
+    let c: Rational<f64> = &a / &b;
+
+    for x in [-2.0, 0.5, 4.0]
+    {
+        assert_relative_eq!(a.eval(x) / b.eval(x), c.eval(x), epsilon = 1.0e-14);
+    }
+    assert_eq!(c, a / b);
+}
+
+#[test]
+#[should_panic]
+fn div_zero()
+{
+    let a: Rational<f64> = Rational::from_polynomial(Polynomial::from_coef(vec![1.0, 2.0]));
+    let _ = &a / &Rational::from_polynomial(Polynomial::from_coef(vec![0.0]));
+}
+
+#[test]
+fn neg()
+{
+    let a: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 2.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+
+    assert_eq!(-a.eval(3.0), (-&a).eval(3.0));
+    assert_eq!(-&a, -a);
+}
+
+#[test]
+fn differentiate()
+{
+    // r(x) = (x^2 + 1) / (x - 2), r'(x) = (x^2 - 4x - 1) / (x - 2)^2
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0, 1.0]), Polynomial::from_coef(vec![1.0, -2.0]));
+    let dr: Rational<f64> = r.differentiate();
+
+    for x in [-1.0, 0.0, 3.0]
+    {
+        assert_relative_eq!((x * x - 4.0 * x - 1.0) / ((x - 2.0) * (x - 2.0)), dr.eval(x), epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+fn reduce()
+{
+    // r(x) = (x - 1)(x + 2) / (3(x - 1)^2(x - 4))
+    let num: Polynomial<f64> = Polynomial::from_root(vec![1.0, -2.0]);
+    let den: Polynomial<f64> = &Polynomial::from_root(vec![1.0, 1.0, 4.0]) * &Polynomial::from_coef(vec![3.0]);
+    let r: Rational<f64> = Rational::new(num, den).reduce();
+
+    assert_eq!(1, r.numerator().degree());
+    assert_eq!(2, r.denominator().degree());
+    assert_relative_eq!(Polynomial::from_coef(vec![1.0 / 3.0, 2.0 / 3.0]), *r.numerator(), epsilon = 1.0e-12);
+    assert_relative_eq!(Polynomial::from_root(vec![1.0, 4.0]), *r.denominator(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn reduce_zero()
+{
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![0.0]), Polynomial::from_coef(vec![2.0, 1.0])).reduce();
+
+    assert_eq!(Polynomial::from_coef(vec![0.0]), *r.numerator());
+    assert_eq!(Polynomial::from_coef(vec![1.0]), *r.denominator());
+}
+
+#[test]
+fn partial_fraction_simple()
+{
+    // 1 / (x^2 - 1) = -1/2 / (x + 1) + 1/2 / (x - 1)
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0]), Polynomial::from_coef(vec![1.0, 0.0, -1.0]));
+    let decomposition: PartialFraction<f64> = r.partial_fraction();
+
+    assert_eq!(Polynomial::from_coef(vec![0.0]), *decomposition.polynomial());
+    assert_eq!(vec![Complex::new(-1.0, 0.0), Complex::new(1.0, 0.0)], *decomposition.poles());
+    let residues: Vec<Complex<f64>> = decomposition.residues();
+    assert_relative_eq!(-0.5, residues[0].re, epsilon = 1.0e-14);
+    assert_relative_eq!(0.5, residues[1].re, epsilon = 1.0e-14);
+}
+
+#[test]
+fn partial_fraction_complex()
+{
+    // (x + 2) / (x^2 + 1) = (1/2 - i) / (x - i) + (1/2 + i) / (x + i)
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 2.0]), Polynomial::from_coef(vec![1.0, 0.0, 1.0]));
+    let decomposition: PartialFraction<f64> = r.partial_fraction();
+
+    let poles: &Vec<Complex<f64>> = decomposition.poles();
+    let residues: Vec<Complex<f64>> = decomposition.residues();
+    assert_eq!(2, poles.len());
+    assert_relative_eq!(-1.0, poles[0].im, epsilon = 1.0e-14);
+    assert_relative_eq!(0.5, residues[0].re, epsilon = 1.0e-14);
+    assert_relative_eq!(1.0, residues[0].im, epsilon = 1.0e-14);
+    assert_relative_eq!(1.0, poles[1].im, epsilon = 1.0e-14);
+    assert_relative_eq!(0.5, residues[1].re, epsilon = 1.0e-14);
+    assert_relative_eq!(-1.0, residues[1].im, epsilon = 1.0e-14);
+}
+
+#[test]
+fn partial_fraction_multiple()
+{
+    // (x^4 + 1) / ((x + 1)^3 (x - 2)(x^2 + 1))
+    let num: Polynomial<f64> = Polynomial::from_coef(vec![1.0, 0.0, 0.0, 0.0, 1.0]);
+    let den: Polynomial<f64> = &Polynomial::from_root(vec![-1.0, -1.0, -1.0, 2.0]) * &Polynomial::from_coef(vec![1.0, 0.0, 1.0]);
+    let r: Rational<f64> = Rational::new(num, den);
+    let decomposition: PartialFraction<f64> = r.partial_fraction();
+
+    let multiplicities: Vec<usize> = decomposition.coefficients().iter().map(|c_k| c_k.len()).collect();
+    assert_eq!(vec![3, 1, 1, 1], multiplicities);
+
+    // coefficient of 1/(x + 1)^3 is 2 / ((-3) 2)
+    assert_relative_eq!(-1.0 / 3.0, decomposition.coefficients()[0][2].re, epsilon = 1.0e-10);
+
+    for z in [Complex::new(0.5, 0.0), Complex::new(-3.0, 1.0), Complex::new(1.0, 2.0)]
+    {
+        let difference: Complex<f64> = decomposition.eval_complex(z) - r.eval_complex(z);
+        assert!(difference.re.abs() < 1.0e-10 && difference.im.abs() < 1.0e-10);
+    }
+}
+
+#[test]
+fn partial_fraction_polynomial_part()
+{
+    // (x^3 + 2) / (x - 1) = x^2 + x + 1 + 3 / (x - 1)
+    let r: Rational<f64> = Rational::new(Polynomial::from_coef(vec![1.0, 0.0, 0.0, 2.0]), Polynomial::from_coef(vec![1.0, -1.0]));
+    let decomposition: PartialFraction<f64> = r.partial_fraction();
+
+    assert_relative_eq!(Polynomial::from_coef(vec![1.0, 1.0, 1.0]), *decomposition.polynomial(), epsilon = 1.0e-14);
+    assert_relative_eq!(3.0, decomposition.residues()[0].re, epsilon = 1.0e-14);
+}
+
+#[test]
+fn pade_exp()
+{
+    let taylor: Vec<f64> = (0..7).scan(1.0, |f, k| {
+                                     let c: f64 = 1.0 / *f;
+                                     *f *= (k + 1) as f64;
+                                     Some(c)
+                                 })
+                                 .collect();
+    let r: Rational<f64> = Rational::from_pade(&taylor, 3, 3);
+
+    assert_relative_eq!(Polynomial::from_coef(vec![1.0 / 120.0, 1.0 / 10.0, 0.5, 1.0]), *r.numerator(), epsilon = 1.0e-14);
+    assert_relative_eq!(Polynomial::from_coef(vec![-1.0 / 120.0, 1.0 / 10.0, -0.5, 1.0]), *r.denominator(), epsilon = 1.0e-14);
+    assert!((r.eval(1.0) - 1.0f64.exp()).abs() < 1.0e-4);
+}
+
+#[test]
+fn pade_polynomial()
+{
+    let taylor: Vec<f64> = vec![1.0, 2.0, 3.0];
+    let r: Rational<f64> = Rational::from_pade(&taylor, 2, 0);
+
+    assert_eq!(Rational::from_polynomial(Polynomial::from_coef(vec![3.0, 2.0, 1.0])), r);
+}
+
+#[test]
+fn pade_log()
+{
+    // log(1 + x) = x - x^2/2 + x^3/3 - ..., [1/1] = x / (1 + x/2)
+    let taylor: Vec<f64> = vec![0.0, 1.0, -0.5, 1.0 / 3.0];
+    let r: Rational<f64> = Rational::from_pade(&taylor, 1, 1);
+
+    assert_relative_eq!(Polynomial::from_coef(vec![1.0, 0.0]), *r.numerator(), epsilon = 1.0e-14);
+    assert_relative_eq!(Polynomial::from_coef(vec![0.5, 1.0]), *r.denominator(), epsilon = 1.0e-14);
+}
+
+#[test]
+#[should_panic]
+fn pade_coefficients()
+{
+    Rational::from_pade(&[1.0, 1.0, 0.5], 2, 1);
+}
@@ -0,0 +1,81 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::boundary_value::solver::Collocation,
+    elementary::Trigonometry,
+};
+
+use super::problem::{Beam, BoundaryLayer, Bratu, Sine};
+
+#[test]
+fn sine()
+{
+    let solver: Collocation<f64> = Collocation::new(10, 1000, 10e-8, 10e-6);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Sine).unwrap();
+
+    let len: usize = t.len();
+    assert_relative_eq!(0.0, t[0], epsilon=0.000000001);
+    assert_relative_eq!(std::f64::consts::PI / 2.0, t[len - 1], epsilon=0.000000001);
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(t_i.sin(), x_i[0], epsilon=0.000001);
+        assert_relative_eq!(t_i.cos(), x_i[1], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn bratu()
+{
+    let problem: Bratu = Bratu;
+    let solver: Collocation<f64> = Collocation::new(10, 1000, 10e-8, 10e-6);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn beam()
+{
+    let problem: Beam = Beam { q: 24.0 };
+    let solver: Collocation<f64> = Collocation::default();
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn mesh_refined_at_boundary_layers()
+{
+    let problem: BoundaryLayer = BoundaryLayer { lambda: 30.0 };
+    let solver: Collocation<f64> = Collocation::new(10, 1000, 10e-6, 10e-4);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.0001);
+    }
+
+    // The mesh is finer close to the boundaries than in the middle of the interval
+    let len: usize = t.len();
+    let mid: usize = t.iter().position(|t_i| *t_i >= 0.5).unwrap();
+    assert!(t[1] - t[0] < t[mid + 1] - t[mid]);
+    assert!(t[len - 1] - t[len - 2] < t[mid + 1] - t[mid]);
+}
+
+#[test]
+fn max_mesh_points_reached()
+{
+    let problem: BoundaryLayer = BoundaryLayer { lambda: 30.0 };
+    let solver: Collocation<f64> = Collocation::new(10, 20, 10e-8, 10e-6);
+
+    assert!(solver.solve(&problem).is_err());
+}
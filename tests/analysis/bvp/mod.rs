@@ -0,0 +1,5 @@
+mod problem;
+
+mod singleshooting;
+mod multipleshooting;
+mod collocation;
@@ -0,0 +1,80 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::{
+        boundary_value::solver::MultipleShooting,
+        ordinary::solver::runge_kutta::RungeKutta4,
+    },
+    elementary::Trigonometry,
+};
+
+use super::problem::{Beam, BoundaryLayer, Bratu, Sine};
+
+#[test]
+fn sine()
+{
+    let solver: MultipleShooting<f64> = MultipleShooting::new(5, 0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Sine, &RungeKutta4::default()).unwrap();
+
+    let len: usize = t.len();
+    assert_relative_eq!(std::f64::consts::PI / 2.0, t[len - 1], epsilon=0.000000001);
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(t_i.sin(), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn one_interval()
+{
+    let solver: MultipleShooting<f64> = MultipleShooting::new(1, 0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Sine, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(t_i.sin(), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn bratu()
+{
+    let problem: Bratu = Bratu;
+    let solver: MultipleShooting<f64> = MultipleShooting::new(4, 0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn beam()
+{
+    let problem: Beam = Beam { q: 24.0 };
+    let solver: MultipleShooting<f64> = MultipleShooting::new(4, 0.01, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn boundary_layer()
+{
+    let problem: BoundaryLayer = BoundaryLayer { lambda: 30.0 };
+    let solver: MultipleShooting<f64> = MultipleShooting::new(10, 0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.00001);
+    }
+}
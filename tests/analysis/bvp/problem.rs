@@ -0,0 +1,205 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::boundary_value::BoundaryValueProblem,
+    elementary::{Exponential, Hyperbolic},
+};
+
+/// x'' = -x, x(0) = 0, x(pi / 2) = 1
+///
+/// The closed solution is x(t) = sin(t)
+pub struct Sine;
+
+impl BoundaryValueProblem<f64> for Sine
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[1]; -x[0]]
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![0.0, 1.0; -1.0, 0.0]
+    }
+
+    fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x_a[0]; x_b[0] - 1.0]
+    }
+
+    fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+    {
+        (matrix![1.0, 0.0; 0.0, 0.0], matrix![0.0, 0.0; 1.0, 0.0])
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, std::f64::consts::PI / 2.0)
+    }
+
+    fn init_guess(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![0.0; 0.0]
+    }
+}
+
+/// Bratu's problem x'' + exp(x) = 0, x(0) = x(1) = 0
+///
+/// The closed solution of the lower branch is
+/// x(t) = -2 ln(cosh((t - 0.5) theta / 2) / cosh(theta / 4)), where theta
+/// solves theta = sqrt(2) cosh(theta / 4)
+pub struct Bratu;
+
+impl Bratu
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        let theta: f64 = 1.5171645990507543;
+        -2.0 * (((t - 0.5) * theta / 2.0).cosh() / (theta / 4.0).cosh()).ln()
+    }
+}
+
+impl BoundaryValueProblem<f64> for Bratu
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[1]; -x[0].exp()]
+    }
+
+    fn jacobian(&self, _t: &f64, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![0.0, 1.0; -x[0].exp(), 0.0]
+    }
+
+    fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x_a[0]; x_b[0]]
+    }
+
+    fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+    {
+        (matrix![1.0, 0.0; 0.0, 0.0], matrix![0.0, 0.0; 1.0, 0.0])
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_guess(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![0.0; 0.0]
+    }
+}
+
+/// Beam clamped at both ends with a uniform load q
+///
+/// w'''' = q, w(0) = w'(0) = w(1) = w'(1) = 0
+///
+/// The closed solution is w(t) = q / 24 t^2 (1 - t)^2
+pub struct Beam
+{
+    pub q: f64,
+}
+
+impl Beam
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        self.q / 24.0 * t * t * (1.0 - t) * (1.0 - t)
+    }
+}
+
+impl BoundaryValueProblem<f64> for Beam
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[1]; x[2]; x[3]; self.q]
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![0.0, 1.0, 0.0, 0.0;
+                0.0, 0.0, 1.0, 0.0;
+                0.0, 0.0, 0.0, 1.0;
+                0.0, 0.0, 0.0, 0.0]
+    }
+
+    fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x_a[0]; x_a[1]; x_b[0]; x_b[1]]
+    }
+
+    fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+    {
+        let b_a: Matrix<f64> = matrix![1.0, 0.0, 0.0, 0.0;
+                                       0.0, 1.0, 0.0, 0.0;
+                                       0.0, 0.0, 0.0, 0.0;
+                                       0.0, 0.0, 0.0, 0.0];
+        let b_b: Matrix<f64> = matrix![0.0, 0.0, 0.0, 0.0;
+                                       0.0, 0.0, 0.0, 0.0;
+                                       1.0, 0.0, 0.0, 0.0;
+                                       0.0, 1.0, 0.0, 0.0];
+        (b_a, b_b)
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_guess(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![0.0; 0.0; 0.0; 0.0]
+    }
+}
+
+/// x'' = lambda^2 x, x(0) = 1, x(1) = 1
+///
+/// The solution of the initial value problem grows with exp(lambda t), which
+/// makes the problem hard for single shooting. The closed solution is
+/// x(t) = (sinh(lambda (1 - t)) + sinh(lambda t)) / sinh(lambda)
+pub struct BoundaryLayer
+{
+    pub lambda: f64,
+}
+
+impl BoundaryLayer
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        ((self.lambda * (1.0 - t)).sinh() + (self.lambda * t).sinh()) / self.lambda.sinh()
+    }
+}
+
+impl BoundaryValueProblem<f64> for BoundaryLayer
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[1]; self.lambda * self.lambda * x[0]]
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![0.0, 1.0; self.lambda * self.lambda, 0.0]
+    }
+
+    fn boundary_cond(&self, x_a: &Vector<f64>, x_b: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x_a[0] - 1.0; x_b[0] - 1.0]
+    }
+
+    fn boundary_jacobian(&self, _x_a: &Vector<f64>, _x_b: &Vector<f64>) -> (Matrix<f64>, Matrix<f64>)
+    {
+        (matrix![1.0, 0.0; 0.0, 0.0], matrix![0.0, 0.0; 1.0, 0.0])
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_guess(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0; 0.0]
+    }
+}
@@ -0,0 +1,51 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::{boundary_value::solver::SingleShooting, ordinary::solver::runge_kutta::RungeKutta4},
+    elementary::Trigonometry,
+};
+
+use super::problem::{Beam, Bratu, Sine};
+
+#[test]
+fn sine()
+{
+    let solver: SingleShooting<f64> = SingleShooting::new(0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Sine, &RungeKutta4::default()).unwrap();
+
+    let len: usize = t.len();
+    assert_relative_eq!(std::f64::consts::PI / 2.0, t[len - 1], epsilon=0.000000001);
+    assert_relative_eq!(1.0, x[0][1], epsilon=0.000001);
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(t_i.sin(), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn bratu()
+{
+    let problem: Bratu = Bratu;
+    let solver: SingleShooting<f64> = SingleShooting::new(0.001, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn beam()
+{
+    let problem: Beam = Beam { q: 24.0 };
+    let solver: SingleShooting<f64> = SingleShooting::new(0.01, 100, 10e-10);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
@@ -0,0 +1,35 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::differential_algebraic::{ConsistentInitialization, ImplicitDAE},
+};
+
+use super::problem::{ImplicitDAE1, LinearDAE, Robertson};
+
+#[test]
+fn mass_matrix_differential_variables()
+{
+    assert_eq!(vec![true, false], LinearDAE::default().differential_variables());
+    assert_eq!(vec![true, true, false], Robertson::default().differential_variables());
+}
+
+#[test]
+fn mass_matrix()
+{
+    let init: ConsistentInitialization<f64> = ConsistentInitialization::default();
+
+    let (x_0, x_dot_0): (Vector<f64>, Vector<f64>) = init.compute(&LinearDAE::default()).unwrap();
+
+    assert_relative_eq!(vector![1.0; 2.0], x_0, epsilon=0.00000001);
+    assert_relative_eq!(1.0, x_dot_0[0], epsilon=0.00000001);
+}
+
+#[test]
+fn implicit()
+{
+    let init: ConsistentInitialization<f64> = ConsistentInitialization::default();
+
+    let (x_0, x_dot_0): (Vector<f64>, Vector<f64>) = init.compute(&ImplicitDAE1::default()).unwrap();
+
+    assert_relative_eq!(vector![0.0; 1.0], x_0, epsilon=0.00000001);
+    assert_relative_eq!(1.0, x_dot_0[0], epsilon=0.00000001);
+}
@@ -0,0 +1,5 @@
+mod problem;
+
+mod consistent_initialization;
+mod bdf;
+mod radauiia;
@@ -0,0 +1,169 @@
+//! Often used DAEs
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::differential_algebraic::{ImplicitDAE, MassMatrixDAE},
+    elementary::Trigonometry,
+};
+use std::default::Default;
+
+/// Semi-explicit DAE in mass matrix form
+/// $x^{'} = -x + z$
+/// $0 = z - 2x$
+/// $x(t) = e^t, z(t) = 2e^t$
+pub struct LinearDAE
+{
+    time_span: (f64, f64),
+    init_cond: Vector<f64>,
+}
+
+impl Default for LinearDAE
+{
+    fn default() -> LinearDAE
+    {
+        LinearDAE { time_span: (0.0, 1.0),
+                    init_cond: vector![1.0; 0.0] }
+    }
+}
+
+impl LinearDAE
+{
+    pub fn new(time_span: (f64, f64), init_cond: Vector<f64>) -> LinearDAE
+    {
+        LinearDAE { time_span, init_cond }
+    }
+}
+
+impl MassMatrixDAE<f64> for LinearDAE
+{
+    fn mass(&self, _t: &f64) -> Matrix<f64>
+    {
+        return matrix![1.0, 0.0; 0.0, 0.0];
+    }
+
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![-x[0] + x[1]; x[1] - 2.0 * x[0]];
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![-1.0, 1.0; -2.0, 1.0];
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        return self.init_cond.clone();
+    }
+}
+
+/// Robertson's chemical reaction as DAE
+/// $y_1^{'} = -0.04y_1 + 10^4 y_2 y_3$
+/// $y_2^{'} = 0.04y_1 - 10^4 y_2 y_3 - 3 \cdot 10^7 y_2^2$
+/// $0 = y_1 + y_2 + y_3 - 1$
+pub struct Robertson
+{
+    time_span: (f64, f64),
+}
+
+impl Default for Robertson
+{
+    fn default() -> Robertson
+    {
+        Robertson { time_span: (0.0, 40.0) }
+    }
+}
+
+impl MassMatrixDAE<f64> for Robertson
+{
+    fn mass(&self, _t: &f64) -> Matrix<f64>
+    {
+        return matrix![1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 0.0];
+    }
+
+    fn func(&self, _t: &f64, y: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![-0.04 * y[0] + 1.0e4 * y[1] * y[2];
+                       0.04 * y[0] - 1.0e4 * y[1] * y[2] - 3.0e7 * y[1] * y[1];
+                       y[0] + y[1] + y[2] - 1.0];
+    }
+
+    fn jacobian(&self, _t: &f64, y: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![-0.04, 1.0e4 * y[2], 1.0e4 * y[1];
+                       0.04, -1.0e4 * y[2] - 6.0e7 * y[1], -1.0e4 * y[1];
+                       1.0, 1.0, 1.0];
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        return vector![1.0; 0.0; 0.0];
+    }
+}
+
+/// Fully implicit DAE
+/// $0 = x_1^{'} - x_2$
+/// $0 = x_2 - cos(t)$
+/// $x_1(t) = sin(t), x_2(t) = cos(t)$
+pub struct ImplicitDAE1
+{
+    time_span: (f64, f64),
+}
+
+impl Default for ImplicitDAE1
+{
+    fn default() -> ImplicitDAE1
+    {
+        ImplicitDAE1 { time_span: (0.0, 2.0) }
+    }
+}
+
+impl ImplicitDAE1
+{
+    pub fn new(time_span: (f64, f64)) -> ImplicitDAE1
+    {
+        ImplicitDAE1 { time_span }
+    }
+}
+
+impl ImplicitDAE<f64> for ImplicitDAE1
+{
+    fn residual(&self, t: &f64, x: &Vector<f64>, x_dot: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![x_dot[0] - x[1]; x[1] - t.cos()];
+    }
+
+    fn jacobian_x(&self, _t: &f64, _x: &Vector<f64>, _x_dot: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![0.0, -1.0; 0.0, 1.0];
+    }
+
+    fn jacobian_x_dot(&self, _t: &f64, _x: &Vector<f64>, _x_dot: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![1.0, 0.0; 0.0, 0.0];
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self) -> (Vector<f64>, Vector<f64>)
+    {
+        return (vector![self.time_span.0.sin(); 0.0], vector![0.0; 0.0]);
+    }
+
+    fn differential_variables(&self) -> Vec<bool>
+    {
+        return vec![true, false];
+    }
+}
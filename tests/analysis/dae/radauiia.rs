@@ -0,0 +1,69 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::differential_algebraic::solver::RadauIIA,
+    elementary::Trigonometry,
+};
+
+use super::problem::{ImplicitDAE1, LinearDAE, Robertson};
+
+#[test]
+fn linear()
+{
+    let solver: RadauIIA<f64> = RadauIIA::new(0.05);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&LinearDAE::default()).unwrap();
+
+    assert_relative_eq!(1.0, *t.last().unwrap(), epsilon=0.000000001);
+    assert_relative_eq!(1.0f64.exp(), x.last().unwrap()[0], epsilon=0.00000001);
+    assert_relative_eq!(2.0 * 1.0f64.exp(), x.last().unwrap()[1], epsilon=0.00000002);
+}
+
+#[test]
+fn implicit()
+{
+    let solver: RadauIIA<f64> = RadauIIA::new(0.1);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&ImplicitDAE1::default()).unwrap();
+
+    let t_n: f64 = *t.last().unwrap();
+    assert_relative_eq!(2.0, t_n, epsilon=0.000000001);
+    assert_relative_eq!(vector![t_n.sin(); t_n.cos()], *x.last().unwrap(), epsilon=0.00000001);
+}
+
+#[test]
+fn robertson()
+{
+    let solver: RadauIIA<f64> = RadauIIA::new(0.01);
+
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Robertson::default()).unwrap();
+    let x_n: &Vector<f64> = x.last().unwrap();
+
+    assert_relative_eq!(0.7158270687, x_n[0], epsilon=0.000001);
+    assert_relative_eq!(9.185534764e-6, x_n[1], epsilon=0.000000001);
+    assert_relative_eq!(0.2841637457, x_n[2], epsilon=0.000001);
+}
+
+#[test]
+fn linear_backward()
+{
+    let solver: RadauIIA<f64> = RadauIIA::new(0.05);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&LinearDAE::new((1.0, 0.0), vector![1.0f64.exp(); 0.0])).unwrap();
+
+    assert_eq!(1.0, t[0]);
+    assert!(t.windows(2).all(|t_i| t_i[1] < t_i[0]));
+    assert_relative_eq!(0.0, *t.last().unwrap(), epsilon=0.000000001);
+    assert_relative_eq!(1.0, x.last().unwrap()[0], epsilon=0.00000001);
+    assert_relative_eq!(2.0, x.last().unwrap()[1], epsilon=0.00000001);
+}
+
+#[test]
+fn implicit_backward()
+{
+    let solver: RadauIIA<f64> = RadauIIA::new(0.05);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&ImplicitDAE1::new((2.0, 0.0))).unwrap();
+
+    assert_relative_eq!(0.0, *t.last().unwrap(), epsilon=0.000000001);
+    assert_relative_eq!(vector![0.0; 1.0], *x.last().unwrap(), epsilon=0.00000001);
+}
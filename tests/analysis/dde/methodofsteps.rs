@@ -0,0 +1,105 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::{
+        delay_differential::{solver::MethodOfSteps, DelayODE},
+        ordinary::solver::runge_kutta::{BogackiShampine32, DormandPrince54},
+    },
+};
+
+use super::problem::{ConstantDelay, Pantograph, StateDependentDelay, TwoDelays, UndefinedDelay};
+
+fn contains(t: &[f64], t_i: f64) -> bool
+{
+    t.iter().any(|t_j| (t_j - t_i).abs() < 10e-10)
+}
+
+#[test]
+fn constant_delay()
+{
+    let problem: ConstantDelay = ConstantDelay;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.01, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &DormandPrince54::default()).unwrap();
+
+    let len: usize = t.len();
+    assert_relative_eq!(problem.time_span().1, t[len - 1], epsilon=0.000000001);
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.0000001);
+    }
+}
+
+#[test]
+fn constant_delay_discontinuities()
+{
+    let problem: ConstantDelay = ConstantDelay;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.3, 0.9, 0.2, 5.0, 10e-6, 10e-4);
+
+    let (t, _x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &BogackiShampine32::default()).unwrap();
+
+    assert!(contains(&t, 1.0));
+    assert!(contains(&t, 2.0));
+}
+
+#[test]
+fn two_delays_discontinuities()
+{
+    let problem: TwoDelays = TwoDelays;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.3, 0.9, 0.2, 5.0, 10e-8, 10e-6);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &DormandPrince54::default()).unwrap();
+
+    assert!(contains(&t, 0.5));
+    assert!(contains(&t, 1.0));
+    assert!(contains(&t, 1.5));
+
+    // x(t) = 1 - 2t on [0, 0.5]
+    let i: usize = t.iter().position(|t_i| (t_i - 0.5).abs() < 10e-10).unwrap();
+    assert_relative_eq!(0.0, x[i][0], epsilon=0.0000001);
+}
+
+#[test]
+fn vanishing_delay()
+{
+    let problem: Pantograph = Pantograph;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.01, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &BogackiShampine32::default()).unwrap();
+
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.000001);
+    }
+}
+
+#[test]
+fn state_dependent_delay()
+{
+    let problem: StateDependentDelay = StateDependentDelay;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(1000, 0.3, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &DormandPrince54::default()).unwrap();
+
+    assert!(contains(&t, 2.0 / 3.0));
+    for (t_i, x_i) in t.iter().zip(x.iter())
+    {
+        assert_relative_eq!(problem.solution(*t_i), x_i[0], epsilon=0.0000001);
+    }
+}
+
+#[test]
+fn max_steps_reached()
+{
+    let problem: ConstantDelay = ConstantDelay;
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(10, 0.01, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    assert!(solver.solve(&problem, &DormandPrince54::default()).is_err());
+}
+
+#[test]
+fn undefined_delay()
+{
+    let solver: MethodOfSteps<f64> = MethodOfSteps::new(10000, 0.01, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    assert!(solver.solve(&UndefinedDelay, &DormandPrince54::default()).is_err());
+}
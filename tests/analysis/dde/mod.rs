@@ -0,0 +1,3 @@
+mod problem;
+
+mod methodofsteps;
@@ -0,0 +1,193 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::delay_differential::DelayODE,
+    elementary::{Exponential, Power},
+};
+
+/// x'(t) = -x(t - 1), x(t) = 1 for t <= 0
+///
+/// The closed solution is a polynomial of degree n on [n - 1, n], whose n-th
+/// derivative jumps at t = n.
+pub struct ConstantDelay;
+
+impl ConstantDelay
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        let mut x: f64 = 1.0 - t;
+        if t > 1.0
+        {
+            x += (t - 1.0).pow(2.0) / 2.0;
+        }
+        if t > 2.0
+        {
+            x -= (t - 2.0).pow(3.0) / 6.0;
+        }
+        x
+    }
+}
+
+impl DelayODE<f64> for ConstantDelay
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+    {
+        -x_delayed[0].clone()
+    }
+
+    fn delays(&self, _t: &f64, _x: &Vector<f64>) -> Vec<f64>
+    {
+        vec![1.0]
+    }
+
+    fn history(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 3.0)
+    }
+}
+
+/// x'(t) = -x(t - 1) - x(t - 0.5), x(t) = 1 for t <= 0
+pub struct TwoDelays;
+
+impl DelayODE<f64> for TwoDelays
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+    {
+        -(&x_delayed[0] + &x_delayed[1])
+    }
+
+    fn delays(&self, _t: &f64, _x: &Vector<f64>) -> Vec<f64>
+    {
+        vec![1.0, 0.5]
+    }
+
+    fn history(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 2.0)
+    }
+}
+
+/// Pantograph equation x'(t) = -x(t / 2), x(0) = 1
+///
+/// The delay t / 2 vanishes at t = 0. The closed solution is
+/// x(t) = sum_n (-t)^n / (n! 2^(n (n - 1) / 2))
+pub struct Pantograph;
+
+impl Pantograph
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        let mut sum: f64 = 0.0;
+        let mut term: f64 = 1.0;
+        for n in 0..30
+        {
+            sum += term;
+            term *= -t / ((n + 1) as f64 * 2.0f64.pow(n as f64));
+        }
+        sum
+    }
+}
+
+impl DelayODE<f64> for Pantograph
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+    {
+        -x_delayed[0].clone()
+    }
+
+    fn delays(&self, t: &f64, _x: &Vector<f64>) -> Vec<f64>
+    {
+        vec![t / 2.0]
+    }
+
+    fn history(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 3.0)
+    }
+}
+
+/// x'(t) = -x(t - 2 x(t)), x(t) = 1 for t <= 0
+///
+/// The closed solution is x(t) = 1 - t on [0, 2/3], where t - 2 x(t) crosses
+/// the initial point. Afterwards x(t) = t / 2 - 3 / 4 + 3 / 4 exp(4 / 3 - 2t)
+/// as long as t - 2 x(t) <= 2 / 3.
+pub struct StateDependentDelay;
+
+impl StateDependentDelay
+{
+    pub fn solution(&self, t: f64) -> f64
+    {
+        if t <= 2.0 / 3.0
+        {
+            1.0 - t
+        }
+        else
+        {
+            t / 2.0 - 0.75 + 0.75 * (4.0 / 3.0 - 2.0 * t).exp()
+        }
+    }
+}
+
+impl DelayODE<f64> for StateDependentDelay
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+    {
+        -x_delayed[0].clone()
+    }
+
+    fn delays(&self, _t: &f64, x: &Vector<f64>) -> Vec<f64>
+    {
+        vec![2.0 * x[0]]
+    }
+
+    fn history(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 0.95)
+    }
+}
+
+/// $x^{'}(t) = -x(t - \sqrt{x(t) - 1/2})$, whose delay is not defined for
+/// $x(t) < 1/2$
+pub struct UndefinedDelay;
+
+impl DelayODE<f64> for UndefinedDelay
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>, x_delayed: &[Vector<f64>]) -> Vector<f64>
+    {
+        -x_delayed[0].clone()
+    }
+
+    fn delays(&self, _t: &f64, x: &Vector<f64>) -> Vec<f64>
+    {
+        vec![(x[0] - 0.5).sqrt()]
+    }
+
+    fn history(&self, _t: &f64) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 2.0)
+    }
+}
@@ -0,0 +1,92 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::{
+        differentiation::{Dual, ForwardHessian, ForwardJacobian, HyperDual, ReverseGradient, Var},
+        Function, Hessian, Jacobian, NewtonRaphson,
+    },
+    elementary::{Exponential, Trigonometry},
+    optimization::{GaussNewton, Newton},
+};
+
+#[test]
+fn forward_jacobian()
+{
+    let f = ForwardJacobian::new(|x: &Vector<Dual<f64>>| {
+        Vector::new_column(vec![x[0] * x[1].sin(), x[0].exp() + x[2], x[1] * x[2]])
+    });
+    let x: Vector<f64> = vector![1.0; 0.5; -2.0];
+
+    let jacobian_ref: Matrix<f64> = matrix![0.5f64.sin(), 0.5f64.cos(), 0.0;
+                                            1.0f64.exp(), 0.0, 1.0;
+                                            0.0, -2.0, 0.5];
+
+    assert_relative_eq!(f.eval(&x), vector![0.5f64.sin(); 1.0f64.exp() - 2.0; -1.0]);
+    assert_relative_eq!(f.jacobian(&x), jacobian_ref, epsilon = 1.0e-14);
+}
+
+#[test]
+fn forward_jacobian_newton_raphson()
+{
+    // intersection of the circle x^2 + y^2 = 4 and the line y = x
+    let f = ForwardJacobian::new(|x: &Vector<Dual<f64>>| {
+        Vector::new_column(vec![x[0] * x[0] + x[1] * x[1] - Dual::constant(4.0), x[1] - x[0]])
+    });
+
+    let root: Vector<f64> = NewtonRaphson::new(50, 1.0e-12).find_root(&f, &vector![1.0; 2.0]).unwrap();
+
+    assert_relative_eq!(root, vector![2.0f64.sqrt(); 2.0f64.sqrt()], epsilon = 1.0e-10);
+}
+
+#[test]
+fn forward_jacobian_gauss_newton()
+{
+    let f = ForwardJacobian::new(|x: &Vector<Dual<f64>>| {
+        Vector::new_column(vec![x[0] - Dual::constant(1.0), x[0] * x[1] - Dual::constant(3.0)])
+    });
+
+    let x_opt: Vector<f64> = GaussNewton::new(50).minimize(&f, &vector![2.0; 2.0]).unwrap().arg();
+
+    assert_relative_eq!(x_opt, vector![1.0; 3.0], epsilon = 1.0e-8);
+}
+
+#[test]
+fn forward_hessian()
+{
+    let f = ForwardHessian::new(|x: &Vector<HyperDual<f64>>| x[0].sin() * x[1].exp() + x[0] * x[0] * x[2]);
+    let (a, b, c): (f64, f64, f64) = (0.3, -0.2, 1.5);
+    let x: Vector<f64> = vector![a; b; c];
+
+    let gradient_ref: Matrix<f64> = matrix![a.cos() * b.exp() + 2.0 * a * c, a.sin() * b.exp(), a * a];
+    let hessian_ref: Matrix<f64> = matrix![-a.sin() * b.exp() + 2.0 * c, a.cos() * b.exp(), 2.0 * a;
+                                           a.cos() * b.exp(), a.sin() * b.exp(), 0.0;
+                                           2.0 * a, 0.0, 0.0];
+
+    assert_relative_eq!(f.eval(&x), a.sin() * b.exp() + a * a * c);
+    assert_relative_eq!(f.jacobian(&x), gradient_ref, epsilon = 1.0e-14);
+    assert_relative_eq!(f.hessian(&x), hessian_ref, epsilon = 1.0e-14);
+}
+
+#[test]
+fn forward_hessian_newton()
+{
+    let rosenbrock = ForwardHessian::new(|x: &Vector<HyperDual<f64>>| {
+        let a: HyperDual<f64> = HyperDual::hyper(1.0, 0.0, 0.0, 0.0) - x[0];
+        let b: HyperDual<f64> = x[1] - x[0] * x[0];
+        a * a + HyperDual::hyper(100.0, 0.0, 0.0, 0.0) * b * b
+    });
+
+    let x_opt: Vector<f64> = Newton::new(30, 0.1, 0.00001).minimize(&rosenbrock, &vector![0.0; -0.1]).arg();
+
+    assert_relative_eq!(x_opt, vector![1.0; 1.0], epsilon = 1.0e-8);
+}
+
+#[test]
+fn reverse_gradient()
+{
+    let f = ReverseGradient::new(|x: &Vector<Var<f64>>| x[0] * x[1].cos() + x[2] * x[2] * x[0]);
+    let (a, b, c): (f64, f64, f64) = (1.5, 0.4, -0.5);
+    let x: Vector<f64> = vector![a; b; c];
+
+    assert_relative_eq!(f.eval(&x), a * b.cos() + c * c * a);
+    assert_relative_eq!(f.jacobian(&x), matrix![b.cos() + c * c, -a * b.sin(), 2.0 * a * c], epsilon = 1.0e-14);
+}
@@ -0,0 +1,87 @@
+use mathru::{
+    algebra::{
+        abstr::Complex,
+        linear::{Matrix, Vector},
+    },
+    analysis::differentiation::ComplexStep,
+    elementary::{Exponential, Power, Trigonometry},
+};
+
+#[test]
+fn derivative()
+{
+    // f(x) = e^x / sqrt(sin(x)^3 + cos(x)^3), Squire and Trapp
+    let f = |x: Complex<f64>| {
+        let s: Complex<f64> = x.sin();
+        let c: Complex<f64> = x.cos();
+        x.exp() / (s * s * s + c * c * c).sqrt()
+    };
+    let x: f64 = 1.5;
+    let (s, c): (f64, f64) = (x.sin(), x.cos());
+    let g: f64 = s * s * s + c * c * c;
+    let d_ref: f64 = x.exp() / g.sqrt() - x.exp() * (3.0 * s * s * c - 3.0 * c * c * s) / (2.0 * g.powf(1.5));
+
+    let d: f64 = ComplexStep::new().derivative(f, x);
+
+    assert_relative_eq!(d, d_ref, epsilon = 1.0e-13);
+}
+
+#[test]
+fn derivative_tiny_step()
+{
+    let mut cs: ComplexStep<f64> = ComplexStep::default();
+    cs.set_step(1.0e-100);
+
+    assert_relative_eq!(cs.derivative(|x: Complex<f64>| x.sin(), 1.0), 1.0f64.cos(), epsilon = 1.0e-15);
+}
+
+#[test]
+#[should_panic]
+fn set_step_zero()
+{
+    let mut cs: ComplexStep<f64> = ComplexStep::new();
+    cs.set_step(0.0);
+}
+
+#[test]
+fn gradient()
+{
+    let f = |x: &Vector<Complex<f64>>| x[0] * x[1].exp() + x[2].sin();
+    let x: Vector<f64> = vector![2.0; 0.5; 1.0];
+    let gradient_ref: Vector<f64> = vector![0.5f64.exp(); 2.0 * 0.5f64.exp(); 1.0f64.cos()];
+
+    assert_relative_eq!(ComplexStep::new().gradient(f, &x), gradient_ref, epsilon = 1.0e-14);
+}
+
+#[test]
+fn jacobian()
+{
+    let f = |x: &Vector<Complex<f64>>| Vector::new_column(vec![x[0] * x[1], x[0].exp(), x[1].sin()]);
+    let x: Vector<f64> = vector![2.0; 3.0];
+    let jacobian_ref: Matrix<f64> = matrix![3.0, 2.0;
+                                            2.0f64.exp(), 0.0;
+                                            0.0, 3.0f64.cos()];
+
+    assert_relative_eq!(ComplexStep::new().jacobian(f, &x), jacobian_ref, epsilon = 1.0e-14);
+}
+
+#[test]
+fn hessian()
+{
+    let f = |x: &Vector<Complex<f64>>| x[0] * x[0] * x[1] + x[1].exp();
+    let x: Vector<f64> = vector![2.0; 0.5];
+    let hessian_ref: Matrix<f64> = matrix![1.0, 4.0; 4.0, 0.5f64.exp()];
+
+    let hessian: Matrix<f64> = ComplexStep::new().hessian(f, &x);
+
+    assert_relative_eq!(hessian, hessian_ref, epsilon = 1.0e-9);
+    assert_eq!(hessian[[0, 1]], hessian[[1, 0]]);
+}
+
+#[test]
+fn derivative_f32()
+{
+    let d: f32 = ComplexStep::new().derivative(|x: Complex<f32>| x * x.exp(), 1.0);
+
+    assert_relative_eq!(d, 2.0 * 1.0f32.exp(), epsilon = 1.0e-6);
+}
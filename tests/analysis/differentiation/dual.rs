@@ -0,0 +1,137 @@
+use mathru::{
+    algebra::abstr::Real,
+    analysis::differentiation::Dual,
+    elementary::{Exponential, Hyperbolic, Power, Trigonometry},
+    special::{error::Error, gamma::Gamma},
+};
+
+fn derivative<F>(f: F, x: f64) -> f64
+    where F: Fn(Dual<f64>) -> Dual<f64>
+{
+    f(Dual::variable(x)).eps
+}
+
+#[test]
+fn arithmetic()
+{
+    let y: Dual<f64> = derivative_of(|x| (x * x + Dual::constant(3.0)) / (x - Dual::constant(1.0)), 2.0);
+
+    // f(x) = (x^2 + 3) / (x - 1), f'(x) = (x^2 - 2x - 3) / (x - 1)^2
+    assert_relative_eq!(y.re, 7.0);
+    assert_relative_eq!(y.eps, -3.0);
+}
+
+fn derivative_of<F>(f: F, x: f64) -> Dual<f64>
+    where F: Fn(Dual<f64>) -> Dual<f64>
+{
+    f(Dual::variable(x))
+}
+
+#[test]
+fn elementary()
+{
+    let x: f64 = 0.4;
+
+    assert_relative_eq!(derivative(|x| x.exp(), x), x.exp(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.ln(), x), 1.0 / x, epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.sin(), x), x.cos(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.tan(), x), 1.0 / (x.cos() * x.cos()), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.arcsin(), x), 1.0 / (1.0 - x * x).sqrt(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.arctan(), x), 1.0 / (1.0 + x * x), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.sqrt(), x), 0.5 / x.sqrt(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.tanh(), x), 1.0 - x.tanh() * x.tanh(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.arsinh(), x), 1.0 / (x * x + 1.0).sqrt(), epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.pow(Dual::constant(3.0)), x), 3.0 * x * x, epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| Dual::constant(2.0).pow(x), x), 2.0f64.powf(x) * 2.0f64.ln(), epsilon = 1.0e-14);
+}
+
+#[test]
+fn pow_negative_base()
+{
+    assert_relative_eq!(derivative(|x| x.pow(Dual::constant(3.0)), -2.0), 12.0, epsilon = 1.0e-12);
+    assert_relative_eq!(derivative(|x| x.pow(Dual::constant(2.0)), 0.0), 0.0);
+}
+
+#[test]
+fn arctan2()
+{
+    // d/dt atan2(sin t, cos t) = 1 in every quadrant
+    for t in [0.3, 1.5, 2.5, -2.0, -0.7]
+    {
+        let y: Dual<f64> = derivative_of(|t| t.sin().arctan2(t.cos()), t);
+
+        assert_relative_eq!(y.re, t, epsilon = 1.0e-14);
+        assert_relative_eq!(y.eps, 1.0, epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+fn generic_function()
+{
+    fn f<T: Real>(x: T) -> T
+    {
+        let mut s: T = T::zero();
+        for k in 1..4
+        {
+            s += (x * T::from_f64(k as f64)).cos() / T::from_f64(k as f64);
+        }
+        s.abs()
+    }
+
+    let x: f64 = 0.2;
+    let df: f64 = -(x.sin() + (2.0 * x).sin() + (3.0 * x).sin());
+
+    assert_relative_eq!(derivative(f, x), df, epsilon = 1.0e-14);
+}
+
+#[test]
+fn comparison_uses_value()
+{
+    assert!(Dual::new(1.0, 5.0) < Dual::new(2.0, -1.0));
+    assert_eq!(Dual::new(1.0, 5.0), Dual::new(1.0, 0.0));
+}
+
+#[test]
+fn gamma()
+{
+    let x: f64 = 2.5;
+
+    assert_relative_eq!(derivative(|x| x.gamma(), x), x.gamma() * x.digamma(), epsilon = 1.0e-12);
+    assert_relative_eq!(derivative(|x| x.ln_gamma(), x), x.digamma(), epsilon = 1.0e-12);
+    // psi_1(1) = pi^2 / 6
+    assert_relative_eq!(derivative(|x| x.digamma(), 1.0), std::f64::consts::PI.powi(2) / 6.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn incomplete_gamma()
+{
+    let a: Dual<f64> = Dual::constant(1.5);
+    let x: f64 = 0.8;
+
+    let y: Dual<f64> = a.gamma_lr(Dual::variable(x));
+    assert_relative_eq!(y.eps, x.powf(0.5) * (-x).exp() / 1.5f64.gamma(), epsilon = 1.0e-12);
+
+    // the inverse undoes the derivative
+    let q: Dual<f64> = a.gamma_ur(Dual::variable(x));
+    let x_inv: Dual<f64> = a.gamma_ur_inv(q);
+    assert_relative_eq!(x_inv.re, x, epsilon = 1.0e-8);
+    assert_relative_eq!(x_inv.eps, 1.0, epsilon = 1.0e-6);
+
+    // derivative with respect to the parameter, Gamma(a, x) = e^{-x} for a = 1
+    let y: Dual<f64> = Dual::variable(1.0).gamma_u(Dual::constant(x));
+    let h: f64 = 1.0e-5;
+    let d_a: f64 = ((1.0 + h).gamma_u(x) - (1.0 - h).gamma_u(x)) / (2.0 * h);
+    assert_relative_eq!(y.re, (-x).exp(), epsilon = 1.0e-10);
+    assert_relative_eq!(y.eps, d_a, epsilon = 1.0e-6);
+}
+
+#[test]
+fn error_function()
+{
+    let x: f64 = 0.3;
+
+    assert_relative_eq!(derivative(|x| x.erf(), x),
+                        2.0 / std::f64::consts::PI.sqrt() * (-x * x).exp(),
+                        epsilon = 1.0e-14);
+    assert_relative_eq!(derivative(|x| x.erf().erfinv(), x), 1.0, epsilon = 1.0e-8);
+}
@@ -0,0 +1,139 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differentiation::{DifferenceScheme, FiniteDifference},
+    elementary::{Exponential, Trigonometry},
+};
+
+#[test]
+fn derivative_forward()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+
+    assert_relative_eq!(fd.derivative(|x: f64| x.sin(), 1.0), 1.0f64.cos(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn derivative_central()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::default();
+
+    assert_eq!(fd.scheme(), DifferenceScheme::Central);
+    assert_relative_eq!(fd.derivative(|x: f64| x.sin(), 1.0), 1.0f64.cos(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn derivative_large_argument()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::default();
+
+    assert_relative_eq!(fd.derivative(|x: f64| x * x, 1.0e6), 2.0e6, max_relative = 1.0e-10);
+}
+
+#[test]
+fn derivative_extrapolation()
+{
+    let mut fd: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Central);
+    fd.set_extrapolation(3);
+
+    let (d, error): (f64, f64) = fd.derivative_with_error(|x: f64| x.exp(), 1.0);
+
+    assert_relative_eq!(d, 1.0f64.exp(), epsilon = 1.0e-12);
+    assert!(error < 1.0e-9);
+    assert!((d - 1.0f64.exp()).abs() <= 10.0 * error);
+}
+
+#[test]
+fn derivative_forward_extrapolation()
+{
+    let mut fd: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+    fd.set_extrapolation(3);
+
+    assert_relative_eq!(fd.derivative(|x: f64| x.exp(), 1.0), 1.0f64.exp(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn derivative_without_extrapolation_error()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::default();
+
+    let (_d, error): (f64, f64) = fd.derivative_with_error(|x: f64| x.exp(), 1.0);
+
+    assert!(error.is_infinite());
+}
+
+#[test]
+fn derivative_step()
+{
+    let mut fd: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+    fd.set_step(0.5);
+
+    // (f(1.5) - f(1)) / 0.5 for f(x) = x^2
+    assert_relative_eq!(fd.derivative(|x: f64| x * x, 1.0), 2.5);
+}
+
+#[test]
+#[should_panic]
+fn set_step_negative()
+{
+    let mut fd: FiniteDifference<f64> = FiniteDifference::default();
+    fd.set_step(-1.0);
+}
+
+#[test]
+fn gradient()
+{
+    let f = |x: &Vector<f64>| x[0] * x[1].exp() + x[2].sin();
+    let x: Vector<f64> = vector![2.0; 0.5; 1.0];
+    let gradient_ref: Vector<f64> = vector![0.5f64.exp(); 2.0 * 0.5f64.exp(); 1.0f64.cos()];
+
+    let forward: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+    let central: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Central);
+
+    assert_relative_eq!(forward.gradient(f, &x), gradient_ref, epsilon = 1.0e-6);
+    assert_relative_eq!(central.gradient(f, &x), gradient_ref, epsilon = 1.0e-9);
+}
+
+#[test]
+fn jacobian()
+{
+    let f = |x: &Vector<f64>| vector![x[0] * x[1]; x[0].exp(); x[1].sin()];
+    let x: Vector<f64> = vector![2.0; 3.0];
+    let jacobian_ref: Matrix<f64> = matrix![3.0, 2.0;
+                                            2.0f64.exp(), 0.0;
+                                            0.0, 3.0f64.cos()];
+
+    let forward: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+    let mut central: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Central);
+
+    assert_relative_eq!(forward.jacobian(f, &x), jacobian_ref, epsilon = 1.0e-6);
+    assert_relative_eq!(central.jacobian(f, &x), jacobian_ref, epsilon = 1.0e-9);
+
+    central.set_extrapolation(2);
+    assert_relative_eq!(central.jacobian(f, &x), jacobian_ref, epsilon = 1.0e-12);
+}
+
+#[test]
+fn hessian()
+{
+    let f = |x: &Vector<f64>| x[0] * x[0] * x[1] + x[1].exp();
+    let x: Vector<f64> = vector![2.0; 0.5];
+    let hessian_ref: Matrix<f64> = matrix![1.0, 4.0; 4.0, 0.5f64.exp()];
+
+    let forward: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Forward);
+    let mut central: FiniteDifference<f64> = FiniteDifference::new(DifferenceScheme::Central);
+
+    assert_relative_eq!(forward.hessian(f, &x), hessian_ref, epsilon = 1.0e-3);
+    assert_relative_eq!(central.hessian(f, &x), hessian_ref, epsilon = 1.0e-6);
+
+    central.set_extrapolation(2);
+    assert_relative_eq!(central.hessian(f, &x), hessian_ref, epsilon = 1.0e-8);
+}
+
+#[test]
+fn gradient_f32()
+{
+    let f = |x: &Vector<f32>| x[0] * x[0] + x[0] * x[1];
+    let fd: FiniteDifference<f32> = FiniteDifference::default();
+
+    assert_relative_eq!(fd.gradient(f, &vector![2.0; 3.0]), vector![7.0; 2.0], epsilon = 1.0e-3);
+}
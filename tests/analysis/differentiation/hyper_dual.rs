@@ -0,0 +1,65 @@
+use mathru::{
+    algebra::abstr::Real,
+    analysis::differentiation::HyperDual,
+    elementary::{Exponential, Power, Trigonometry},
+    special::gamma::Gamma,
+};
+
+fn second_derivative<F>(f: F, x: f64) -> HyperDual<f64>
+    where F: Fn(HyperDual<f64>) -> HyperDual<f64>
+{
+    f(HyperDual::hyper(x, 1.0, 1.0, 0.0))
+}
+
+#[test]
+fn polynomial()
+{
+    let y: HyperDual<f64> = second_derivative(|x| x * x * x, 2.0);
+
+    assert_relative_eq!(y.value(), 8.0);
+    assert_relative_eq!(y.e1(), 12.0);
+    assert_relative_eq!(y.e2(), 12.0);
+    assert_relative_eq!(y.e12(), 12.0);
+}
+
+#[test]
+fn elementary()
+{
+    let x: f64 = 0.7;
+
+    assert_relative_eq!(second_derivative(|x| x.sin(), x).e12(), -x.sin(), epsilon = 1.0e-14);
+    assert_relative_eq!(second_derivative(|x| x.ln(), x).e12(), -1.0 / (x * x), epsilon = 1.0e-14);
+    assert_relative_eq!(second_derivative(|x| x.sqrt(), x).e12(), -0.25 * x.powf(-1.5), epsilon = 1.0e-14);
+    assert_relative_eq!(second_derivative(|x| (x * x).exp(), x).e12(),
+                        (2.0 + 4.0 * x * x) * (x * x).exp(),
+                        epsilon = 1.0e-13);
+    assert_relative_eq!(second_derivative(|x| x.arctan(), x).e12(),
+                        -2.0 * x / ((1.0 + x * x) * (1.0 + x * x)),
+                        epsilon = 1.0e-14);
+}
+
+#[test]
+fn mixed_partial()
+{
+    fn f<T: Real>(x: T, y: T) -> T
+    {
+        x.sin() * y.exp() + x * y * y
+    }
+
+    let (x, y): (f64, f64) = (0.5, 0.3);
+    let h: HyperDual<f64> = f(HyperDual::hyper(x, 1.0, 0.0, 0.0), HyperDual::hyper(y, 0.0, 1.0, 0.0));
+
+    assert_relative_eq!(h.e1(), x.cos() * y.exp() + y * y, epsilon = 1.0e-14);
+    assert_relative_eq!(h.e2(), x.sin() * y.exp() + 2.0 * x * y, epsilon = 1.0e-14);
+    assert_relative_eq!(h.e12(), x.cos() * y.exp() + 2.0 * y, epsilon = 1.0e-14);
+}
+
+#[test]
+fn ln_gamma()
+{
+    // psi_1(1) = pi^2 / 6
+    let y: HyperDual<f64> = second_derivative(|x| x.ln_gamma(), 1.0);
+
+    assert_relative_eq!(y.e1(), -f64::euler_gamma(), epsilon = 1.0e-10);
+    assert_relative_eq!(y.e12(), std::f64::consts::PI.powi(2) / 6.0, epsilon = 1.0e-10);
+}
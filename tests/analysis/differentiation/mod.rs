@@ -0,0 +1,8 @@
+mod dual;
+mod hyper_dual;
+mod reverse;
+mod adapter;
+mod finite_difference;
+mod complex_step;
+mod richardson;
+mod numerical;
@@ -0,0 +1,151 @@
+use mathru::{
+    algebra::{
+        abstr::Complex,
+        linear::{Matrix, Vector},
+    },
+    analysis::{
+        differential_equation::ordinary::{
+            problem::Robertson, solver::runge_kutta::ImplicitEuler, ExplicitODE, ImplicitODE,
+        },
+        differentiation::{
+            ComplexStep, ComplexStepHessian, ComplexStepJacobian, DifferenceScheme, FiniteDifference,
+            NumericalHessian, NumericalJacobian, NumericalODE,
+        },
+        Function, Hessian, Jacobian, NewtonRaphson,
+    },
+    elementary::{Exponential, Trigonometry},
+    optimization::{GaussNewton, Newton},
+};
+
+#[test]
+fn numerical_jacobian()
+{
+    let f = NumericalJacobian::new(|x: &Vector<f64>| vector![x[0] * x[1].sin(); x[0].exp() + x[1]],
+                                   FiniteDifference::default());
+    let x: Vector<f64> = vector![1.0; 0.5];
+
+    assert_relative_eq!(f.eval(&x), vector![0.5f64.sin(); 1.0f64.exp() + 0.5]);
+    assert_relative_eq!(f.jacobian(&x),
+                        matrix![0.5f64.sin(), 0.5f64.cos(); 1.0f64.exp(), 1.0],
+                        epsilon = 1.0e-9);
+}
+
+#[test]
+fn numerical_jacobian_newton_raphson()
+{
+    // intersection of the circle x^2 + y^2 = 4 and the line y = x
+    let f = NumericalJacobian::new(|x: &Vector<f64>| vector![x[0] * x[0] + x[1] * x[1] - 4.0; x[1] - x[0]],
+                                   FiniteDifference::new(DifferenceScheme::Forward));
+
+    let root: Vector<f64> = NewtonRaphson::new(50, 1.0e-12).find_root(&f, &vector![1.0; 2.0]).unwrap();
+
+    assert_relative_eq!(root, vector![2.0f64.sqrt(); 2.0f64.sqrt()], epsilon = 1.0e-10);
+}
+
+#[test]
+fn numerical_jacobian_gauss_newton()
+{
+    // residuals of the Rosenbrock function
+    let f = NumericalJacobian::new(|x: &Vector<f64>| vector![10.0 * (x[1] - x[0] * x[0]); 1.0 - x[0]],
+                                   FiniteDifference::default());
+
+    let x: Vector<f64> = GaussNewton::new(50).minimize(&f, &vector![-1.2; 1.0]).unwrap().arg();
+
+    assert_relative_eq!(x, vector![1.0; 1.0], epsilon = 1.0e-6);
+}
+
+#[test]
+fn numerical_hessian()
+{
+    let f = NumericalHessian::new(|x: &Vector<f64>| x[0] * x[0] * x[1] + x[1].exp(), FiniteDifference::default());
+    let x: Vector<f64> = vector![2.0; 0.5];
+
+    assert_relative_eq!(f.eval(&x), 2.0 + 0.5f64.exp());
+    assert_relative_eq!(f.jacobian(&x), matrix![2.0, 4.0 + 0.5f64.exp()], epsilon = 1.0e-9);
+    assert_relative_eq!(f.hessian(&x), matrix![1.0, 4.0; 4.0, 0.5f64.exp()], epsilon = 1.0e-6);
+}
+
+#[test]
+fn numerical_hessian_newton()
+{
+    let f = NumericalHessian::new(|x: &Vector<f64>| {
+                                      (x[0] - 1.0) * (x[0] - 1.0) + 2.0 * (x[1] + 0.5) * (x[1] + 0.5) + x[0] * x[1]
+                                  },
+                                  FiniteDifference::default());
+
+    let x: Vector<f64> = Newton::new(50, 0.5, 0.5).minimize(&f, &vector![3.0; 3.0]).arg();
+
+    // 2(x0 - 1) + x1 = 0, 4(x1 + 0.5) + x0 = 0
+    assert_relative_eq!(x, vector![10.0 / 7.0; -6.0 / 7.0], epsilon = 1.0e-6);
+}
+
+#[test]
+fn complex_step_jacobian()
+{
+    let f = ComplexStepJacobian::new(|x: &Vector<Complex<f64>>| Vector::new_column(vec![x[0] * x[1].sin(), x[0].exp()]),
+                                     ComplexStep::new());
+    let x: Vector<f64> = vector![1.0; 0.5];
+
+    assert_relative_eq!(f.eval(&x), vector![0.5f64.sin(); 1.0f64.exp()], epsilon = 1.0e-15);
+    assert_relative_eq!(f.jacobian(&x),
+                        matrix![0.5f64.sin(), 0.5f64.cos(); 1.0f64.exp(), 0.0],
+                        epsilon = 1.0e-15);
+}
+
+#[test]
+fn complex_step_jacobian_newton_raphson()
+{
+    let f = ComplexStepJacobian::new(|x: &Vector<Complex<f64>>| {
+                                         Vector::new_column(vec![x[0] * x[0] + x[1] * x[1] - Complex::new(4.0, 0.0),
+                                                                 x[1] - x[0]])
+                                     },
+                                     ComplexStep::new());
+
+    let root: Vector<f64> = NewtonRaphson::new(50, 1.0e-12).find_root(&f, &vector![1.0; 2.0]).unwrap();
+
+    assert_relative_eq!(root, vector![2.0f64.sqrt(); 2.0f64.sqrt()], epsilon = 1.0e-12);
+}
+
+#[test]
+fn complex_step_hessian_newton()
+{
+    let f = ComplexStepHessian::new(|x: &Vector<Complex<f64>>| {
+                                        let a: Complex<f64> = x[0] - Complex::new(1.0, 0.0);
+                                        let b: Complex<f64> = x[1] + Complex::new(0.5, 0.0);
+                                        a * a + Complex::new(2.0, 0.0) * b * b + x[0] * x[1]
+                                    },
+                                    ComplexStep::new());
+
+    assert_relative_eq!(f.hessian(&vector![0.0; 0.0]), matrix![2.0, 1.0; 1.0, 4.0], epsilon = 1.0e-8);
+
+    let x: Vector<f64> = Newton::new(50, 0.5, 0.5).minimize(&f, &vector![3.0; 3.0]).arg();
+
+    assert_relative_eq!(x, vector![10.0 / 7.0; -6.0 / 7.0], epsilon = 1.0e-6);
+}
+
+#[test]
+fn numerical_ode_jacobian()
+{
+    let problem: Robertson<f64> = Robertson::default();
+    let numerical: NumericalODE<Robertson<f64>, f64> = NumericalODE::new(Robertson::default(), FiniteDifference::default());
+    let x: Vector<f64> = vector![0.9; 1.0e-5; 0.1];
+
+    assert_eq!(ExplicitODE::time_span(&numerical), ExplicitODE::time_span(&problem));
+    assert_relative_eq!(ImplicitODE::func(&numerical, &0.0, &x), ImplicitODE::func(&problem, &0.0, &x));
+    assert_relative_eq!(ImplicitODE::jacobian(&numerical, &0.0, &x),
+                        ImplicitODE::jacobian(&problem, &0.0, &x),
+                        epsilon = 1.0e-4);
+}
+
+#[test]
+fn numerical_ode_implicit_euler()
+{
+    let problem: Robertson<f64> = Robertson::default();
+    let numerical: NumericalODE<Robertson<f64>, f64> = NumericalODE::new(Robertson::default(), FiniteDifference::default());
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.01);
+
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+    let (_t_num, x_num): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&numerical).unwrap();
+
+    assert_relative_eq!(*x.last().unwrap(), *x_num.last().unwrap(), epsilon = 1.0e-8);
+}
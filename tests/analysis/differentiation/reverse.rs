@@ -0,0 +1,87 @@
+use mathru::{
+    algebra::abstr::Real,
+    analysis::differentiation::{Tape, Var},
+    elementary::{Exponential, Power, Trigonometry},
+};
+
+#[test]
+fn arithmetic()
+{
+    let tape: Tape<f64> = Tape::new();
+    let x: Var<f64> = tape.var(3.0);
+    let y: Var<f64> = tape.var(2.0);
+
+    let f: Var<f64> = (x * y - y) / (x + y);
+    let grad: Vec<f64> = f.gradient(&[x, y]);
+
+    // f = (xy - y) / (x + y)
+    assert_relative_eq!(f.value(), 0.8);
+    assert_relative_eq!(grad[0], (y.value() * (x.value() + y.value()) - (x.value() * y.value() - y.value())) / 25.0);
+    assert_relative_eq!(grad[1], ((x.value() - 1.0) * (x.value() + y.value()) - (x.value() * y.value() - y.value())) / 25.0);
+}
+
+#[test]
+fn reused_variable()
+{
+    let tape: Tape<f64> = Tape::new();
+    let x: Var<f64> = tape.var(0.5);
+
+    let f: Var<f64> = x.sin() * x.exp() + x * x;
+
+    assert_relative_eq!(f.gradient(&[x])[0],
+                        (0.5f64.cos() + 0.5f64.sin()) * 0.5f64.exp() + 1.0,
+                        epsilon = 1.0e-14);
+}
+
+#[test]
+fn constants()
+{
+    let tape: Tape<f64> = Tape::new();
+    let x: Var<f64> = tape.var(2.0);
+    let c: Var<f64> = Var::constant(4.0);
+    let unused: Var<f64> = tape.var(1.0);
+
+    let f: Var<f64> = c * x.pow(Var::constant(3.0));
+    let grad: Vec<f64> = f.gradient(&[x, c, unused]);
+
+    assert_relative_eq!(grad[0], 48.0, epsilon = 1.0e-12);
+    assert_relative_eq!(grad[1], 0.0);
+    assert_relative_eq!(grad[2], 0.0);
+}
+
+#[test]
+fn generic_function()
+{
+    fn rosenbrock<T: Real>(x: &[T]) -> T
+    {
+        let one: T = T::one();
+        let hundred: T = T::from_f64(100.0);
+        (0..x.len() - 1).fold(T::zero(), |s, i| {
+                            let a: T = x[i + 1] - x[i] * x[i];
+                            let b: T = one - x[i];
+                            s + hundred * a * a + b * b
+                        })
+    }
+
+    let values: Vec<f64> = vec![0.5, -0.3, 1.2, 0.8];
+    let tape: Tape<f64> = Tape::new();
+    let x: Vec<Var<f64>> = values.iter().map(|v| tape.var(*v)).collect();
+
+    let f: Var<f64> = rosenbrock(&x);
+    let grad: Vec<f64> = f.gradient(&x);
+
+    assert_relative_eq!(f.value(), rosenbrock(&values));
+    for i in 0..values.len()
+    {
+        let mut g: f64 = 0.0;
+        if i + 1 < values.len()
+        {
+            g += -400.0 * values[i] * (values[i + 1] - values[i] * values[i]) - 2.0 * (1.0 - values[i]);
+        }
+        if i > 0
+        {
+            g += 200.0 * (values[i] - values[i - 1] * values[i - 1]);
+        }
+        assert_relative_eq!(grad[i], g, epsilon = 1.0e-12);
+    }
+}
@@ -0,0 +1,40 @@
+use mathru::{analysis::differentiation::Richardson, elementary::Exponential};
+
+#[test]
+fn extrapolate_forward_difference()
+{
+    // A(h) = e + O(h) + O(h^2) + ...
+    let forward = |h: f64| ((1.0 + h).exp() - 1.0f64.exp()) / h;
+
+    let (d, error): (f64, f64) = Richardson::new(1, 1, 5).extrapolate(forward, 0.1);
+
+    assert_relative_eq!(d, 1.0f64.exp(), epsilon = 1.0e-10);
+    assert!(error < 1.0e-7);
+}
+
+#[test]
+fn extrapolate_polynomial_exact()
+{
+    // A(h) = 1 + h^2 + h^4 is exact after two levels
+    let approximation = |h: f64| 1.0 + h * h + h * h * h * h;
+
+    let (value, _error): (f64, f64) = Richardson::new(2, 2, 2).extrapolate(approximation, 1.0);
+
+    assert_relative_eq!(value, 1.0, epsilon = 1.0e-14);
+}
+
+#[test]
+fn extrapolate_without_levels()
+{
+    let (value, error): (f64, f64) = Richardson::new(2, 2, 0).extrapolate(|h: f64| 1.0 + h, 0.5);
+
+    assert_relative_eq!(value, 1.5);
+    assert!(error.is_infinite());
+}
+
+#[test]
+#[should_panic]
+fn new_order_zero()
+{
+    let _ = Richardson::new(0, 1, 2);
+}
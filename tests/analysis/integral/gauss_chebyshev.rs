@@ -0,0 +1,23 @@
+use mathru::analysis::integral::{ChebyshevKind, GaussChebyshev};
+use std::f64::consts::PI;
+
+#[test]
+fn first_kind()
+{
+    let gc: GaussChebyshev<f64> = GaussChebyshev::new(3, ChebyshevKind::First);
+
+    assert_eq!(gc.kind(), ChebyshevKind::First);
+    assert_relative_eq!(gc.nodes()[0], -0.75f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(gc.nodes()[2], 0.75f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(gc.integrate(|x| x.powi(4)), 3.0 * PI / 8.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn second_kind()
+{
+    let gc: GaussChebyshev<f64> = GaussChebyshev::new(3, ChebyshevKind::Second);
+
+    assert_relative_eq!(gc.integrate(|_x| 1.0), PI / 2.0, epsilon=1.0e-14);
+    assert_relative_eq!(gc.integrate(|x| x * x), PI / 8.0, epsilon=1.0e-14);
+    assert_relative_eq!(gc.integrate(|x| x.powi(4)), PI / 16.0, epsilon=1.0e-14);
+}
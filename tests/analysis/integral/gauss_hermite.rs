@@ -0,0 +1,52 @@
+use mathru::analysis::integral::GaussHermite;
+use std::f64::consts::PI;
+
+#[test]
+fn nodes_weights_3()
+{
+    let gh: GaussHermite<f64> = GaussHermite::new(3);
+
+    let x: &[f64] = gh.nodes();
+    let w: &[f64] = gh.weights();
+
+    assert_relative_eq!(x[0], -1.5f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(x[1], 0.0, epsilon=1.0e-14);
+    assert_relative_eq!(x[2], 1.5f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(w[0], PI.sqrt() / 6.0, epsilon=1.0e-14);
+    assert_relative_eq!(w[1], 2.0 * PI.sqrt() / 3.0, epsilon=1.0e-14);
+    assert_relative_eq!(w[2], PI.sqrt() / 6.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn polynomial_exact()
+{
+    let gh: GaussHermite<f64> = GaussHermite::new(4);
+
+    let integral: f64 = gh.integrate(|x| x.powi(6) + x.powi(3));
+
+    assert_relative_eq!(integral, 15.0 * PI.sqrt() / 8.0, epsilon=1.0e-13);
+}
+
+#[test]
+fn cos_high_order()
+{
+    let gh: GaussHermite<f64> = GaussHermite::new(60);
+
+    let integral: f64 = gh.integrate(|x| x.cos());
+
+    assert_relative_eq!(integral, PI.sqrt() * (-0.25f64).exp(), epsilon=1.0e-12);
+}
+
+#[test]
+fn normal_moments()
+{
+    let gh: GaussHermite<f64> = GaussHermite::new(5);
+    let mean: f64 = 1.5;
+    let std_dev: f64 = 0.5;
+
+    assert_relative_eq!(gh.integrate_normal(|_x| 1.0, mean, std_dev), 1.0, epsilon=1.0e-14);
+    assert_relative_eq!(gh.integrate_normal(|x| x, mean, std_dev), mean, epsilon=1.0e-14);
+    assert_relative_eq!(gh.integrate_normal(|x| x.powi(4), mean, std_dev),
+                        mean.powi(4) + 6.0 * mean * mean * std_dev * std_dev + 3.0 * std_dev.powi(4),
+                        epsilon=1.0e-12);
+}
@@ -0,0 +1,53 @@
+use mathru::analysis::integral::{ChebyshevKind, GaussChebyshev, GaussJacobi, gauss_legendre::RootWeight};
+
+#[test]
+fn legendre()
+{
+    let gj: GaussJacobi<f64> = GaussJacobi::new(5, 0.0, 0.0);
+
+    for (i, (x_i, a_i)) in RootWeight::<f64>::new(5).enumerate()
+    {
+        assert_relative_eq!(gj.nodes()[i], x_i, epsilon=1.0e-14);
+        assert_relative_eq!(gj.weights()[i], a_i, epsilon=1.0e-13);
+    }
+}
+
+#[test]
+fn chebyshev()
+{
+    let gj: GaussJacobi<f64> = GaussJacobi::new(7, -0.5, -0.5);
+    let gc: GaussChebyshev<f64> = GaussChebyshev::new(7, ChebyshevKind::First);
+
+    for i in 0..7
+    {
+        assert_relative_eq!(gj.nodes()[i], gc.nodes()[i], epsilon=1.0e-14);
+        assert_relative_eq!(gj.weights()[i], gc.weights()[i], epsilon=1.0e-13);
+    }
+}
+
+#[test]
+fn moments()
+{
+    let gj: GaussJacobi<f64> = GaussJacobi::new(3, 1.0, 2.0);
+
+    assert_relative_eq!(gj.integrate(|_x| 1.0), 4.0 / 3.0, epsilon=1.0e-14);
+    assert_relative_eq!(gj.integrate(|x| x), 4.0 / 15.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn end_point_singularity()
+{
+    let gj: GaussJacobi<f64> = GaussJacobi::new(10, -0.5, 0.0);
+
+    // \int_{-1}^{1} e^x / \sqrt{1 - x} dx
+    let integral: f64 = gj.integrate(|x| x.exp());
+
+    assert_relative_eq!(integral, 4.598807499429596, epsilon=1.0e-12);
+}
+
+#[test]
+#[should_panic]
+fn beta_out_of_bounds()
+{
+    let _gj: GaussJacobi<f64> = GaussJacobi::new(3, 0.0, -1.5);
+}
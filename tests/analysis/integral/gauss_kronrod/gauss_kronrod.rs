@@ -0,0 +1,169 @@
+use mathru::analysis::integral::{
+    gauss_kronrod::{GaussKronrod, GaussKronrodRule},
+    QuadratureResult,
+};
+use std::f64::consts::PI;
+
+#[test]
+fn polynomial_exact_g7k15()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-12, 1.0e-12, 100);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| 14.0 * x.powi(13), 0.0, 1.0);
+
+    assert!(result.is_converged());
+    assert_eq!(15, result.evaluations());
+    assert_relative_eq!(1.0, result.value(), epsilon = 1.0e-14);
+}
+
+#[test]
+fn polynomial_exact_g10k21()
+{
+    let mut gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-12, 1.0e-12, 100);
+    gk.set_rule(GaussKronrodRule::G10K21);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| 20.0 * x.powi(19), 0.0, 1.0);
+
+    assert!(result.is_converged());
+    assert_eq!(21, result.evaluations());
+    assert_relative_eq!(1.0, result.value(), epsilon = 1.0e-14);
+}
+
+#[test]
+fn peaked_density()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-10, 1.0e-10, 200);
+    let sigma: f64 = 1.0e-3;
+    let f = |x: f64| (-(x - 0.7) * (x - 0.7) / (2.0 * sigma * sigma)).exp() / (2.0 * PI * sigma * sigma).sqrt();
+
+    // the peak is not detected by the initial rule
+    assert_eq!(0.0, gk.integrate(f, -2.0, 3.0).value());
+
+    let result: QuadratureResult<f64> = gk.integrate_points(f, &[-2.0, 0.69, 0.71, 3.0]);
+
+    assert!(result.is_converged());
+    assert!(result.error() <= 1.0e-10);
+    assert_relative_eq!(1.0, result.value(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn oscillating()
+{
+    let mut gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-12, 1.0e-12, 200);
+    gk.set_rule(GaussKronrodRule::G10K21);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| (50.0 * x).cos(), 0.0, PI / 4.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!((12.5 * PI).sin() / 50.0, result.value(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn end_point_singularity()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-12, 1.0e-10, 100);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| x.ln() / x.sqrt(), 0.0, 1.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(-4.0, result.value(), epsilon = 1.0e-10);
+    assert!((result.value() + 4.0).abs() <= result.error());
+}
+
+#[test]
+fn extrapolation_saves_evaluations()
+{
+    let f = |x: f64| 1.0 / x.sqrt();
+    let qags: GaussKronrod<f64> = GaussKronrod::new(1.0e-10, 1.0e-10, 200);
+    let mut qag: GaussKronrod<f64> = qags;
+    qag.set_extrapolation(false);
+
+    let extrapolated: QuadratureResult<f64> = qags.integrate(f, 0.0, 1.0);
+    let bisected: QuadratureResult<f64> = qag.integrate(f, 0.0, 1.0);
+
+    assert!(extrapolated.is_converged());
+    assert!(bisected.is_converged());
+    assert_relative_eq!(2.0, extrapolated.value(), epsilon = 1.0e-10);
+    assert_relative_eq!(2.0, bisected.value(), epsilon = 1.0e-10);
+    assert!(extrapolated.evaluations() < bisected.evaluations());
+}
+
+#[test]
+fn not_converged()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-14, 1.0e-14, 3);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| (1.0 / x).sin(), 0.01, 1.0);
+
+    assert!(!result.is_converged());
+    assert!(result.error() > 1.0e-14);
+    assert_eq!(5 * 15, result.evaluations());
+}
+
+#[test]
+fn reversed_bounds()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::default();
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| x.exp(), 1.0, 0.0);
+
+    assert_relative_eq!(1.0 - 1.0f64.exp(), result.value(), epsilon = 1.0e-12);
+    assert_eq!(0.0, gk.integrate(|x: f64| x.exp(), 1.0, 1.0).value());
+}
+
+#[test]
+fn break_points_at_discontinuities()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::new(1.0e-12, 1.0e-12, 50);
+    let f = |x: f64| if x < 0.5 { 1.0 } else if x < 1.5 { x } else { 0.0 };
+
+    let result: QuadratureResult<f64> = gk.integrate_points(f, &[0.0, 0.5, 1.5, 2.0]);
+
+    assert!(result.is_converged());
+    assert_eq!(3 * 15, result.evaluations());
+    assert_relative_eq!(1.5, result.value(), epsilon = 1.0e-14);
+}
+
+#[test]
+#[should_panic]
+fn zero_tolerances()
+{
+    let _gk: GaussKronrod<f64> = GaussKronrod::new(0.0, 0.0, 100);
+}
+
+#[test]
+fn infinite_interval()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::default();
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| (-x * x).exp(), f64::NEG_INFINITY, f64::INFINITY);
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), PI.sqrt(), epsilon=1.0e-10);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| (-x).exp() / x.sqrt(), 0.0, f64::INFINITY);
+    assert_relative_eq!(result.value(), PI.sqrt(), epsilon=1.0e-8);
+
+    let result: QuadratureResult<f64> = gk.integrate(|x: f64| x.exp(), 0.0, f64::NEG_INFINITY);
+    assert_relative_eq!(result.value(), -1.0, epsilon=1.0e-10);
+}
+
+#[test]
+fn cauchy_principal_value()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::default();
+
+    // PV \int_{-1}^{5} 1 / ((1 + x^2) (x - 2)) dx
+    let result: QuadratureResult<f64> = gk.integrate_cauchy(|x: f64| 1.0 / (1.0 + x * x), -1.0, 5.0, 2.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), -13.0f64.ln() / 10.0 - 0.4 * (5.0f64.atan() + PI / 4.0), epsilon=1.0e-10);
+}
+
+#[test]
+#[should_panic]
+fn cauchy_pole_outside()
+{
+    let gk: GaussKronrod<f64> = GaussKronrod::default();
+
+    let _result: QuadratureResult<f64> = gk.integrate_cauchy(|x: f64| x, 0.0, 1.0, 2.0);
+}
@@ -0,0 +1 @@
+mod gauss_kronrod;
@@ -0,0 +1,50 @@
+use mathru::analysis::integral::GaussLaguerre;
+
+#[test]
+fn nodes_weights_2()
+{
+    let gl: GaussLaguerre<f64> = GaussLaguerre::new(2, 0.0);
+
+    let x: &[f64] = gl.nodes();
+    let w: &[f64] = gl.weights();
+
+    assert_relative_eq!(x[0], 2.0 - 2.0f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(x[1], 2.0 + 2.0f64.sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(w[0], (2.0 + 2.0f64.sqrt()) / 4.0, epsilon=1.0e-14);
+    assert_relative_eq!(w[1], (2.0 - 2.0f64.sqrt()) / 4.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn factorial_moments()
+{
+    let gl: GaussLaguerre<f64> = GaussLaguerre::new(6, 0.0);
+
+    assert_relative_eq!(gl.integrate(|x| x.powi(11)), 39916800.0, max_relative=1.0e-12);
+}
+
+#[test]
+fn generalized()
+{
+    let gl: GaussLaguerre<f64> = GaussLaguerre::new(3, 0.5);
+
+    // Gamma(3.5)
+    assert_relative_eq!(gl.integrate(|x| x * x), 3.323350970447842, epsilon=1.0e-12);
+}
+
+#[test]
+fn exponential()
+{
+    let gl: GaussLaguerre<f64> = GaussLaguerre::new(20, 0.0);
+
+    let integral: f64 = gl.integrate_exponential(|x| 1.0 / (1.0 + x).powi(2), 5.0);
+
+    // \int_0^\infty e^{-5x} / (1 + x)^2 dx = 1 - 5 e^5 E_1(5)
+    assert_relative_eq!(integral, 0.14788911857612563, epsilon=1.0e-8);
+}
+
+#[test]
+#[should_panic]
+fn alpha_out_of_bounds()
+{
+    let _gl: GaussLaguerre<f64> = GaussLaguerre::new(3, -1.0);
+}
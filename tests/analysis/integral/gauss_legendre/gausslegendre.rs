@@ -89,3 +89,30 @@ fn gauss_legendre_8()
     assert_relative_eq!(integral, 6.0, epsilon=0.000000001);
 }
 
+
+#[test]
+fn gauss_legendre_high_order()
+{
+    let gl: GaussLegendre<f64> = GaussLegendre::new(40);
+
+    let integral: f64 = gl.integrate(|x: f64| x.exp(), 0.0, 3.0);
+
+    assert_relative_eq!(integral, 3.0f64.exp() - 1.0, epsilon=1.0e-12);
+}
+
+#[test]
+fn gauss_legendre_polynomial_exact()
+{
+    let gl: GaussLegendre<f64> = GaussLegendre::new(12);
+
+    let integral: f64 = gl.integrate(|x: f64| x.powi(23) + x.powi(22), -1.0, 1.0);
+
+    assert_relative_eq!(integral, 2.0 / 23.0, epsilon=1.0e-14);
+}
+
+#[test]
+#[should_panic]
+fn gauss_legendre_0()
+{
+    let _gl: GaussLegendre<f64> = GaussLegendre::new(0);
+}
@@ -5,7 +5,9 @@ fn root_weight_1()
 {
     let mut roots = RootWeight::<f64>::new(1);
 
-    assert_eq!(roots.next(), Some((0.0, 2.0)));
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.0, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 2.0, epsilon=1.0e-14);
     assert_eq!(roots.next(), None);
 }
 
@@ -14,8 +16,12 @@ fn root_weight_2()
 {
     let mut roots = RootWeight::<f64>::new(2);
 
-    assert_eq!(roots.next(), Some((-0.577350269189626, 1.0)));
-    assert_eq!(roots.next(), Some((0.577350269189626, 1.0)));
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, -0.577350269189626, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 1.0, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.577350269189626, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 1.0, epsilon=1.0e-14);
     assert_eq!(roots.next(), None);
 }
 
@@ -24,9 +30,15 @@ fn root_weight_3()
 {
     let mut roots = RootWeight::<f64>::new(3);
 
-    assert_eq!(roots.next(), Some((-0.774596669241483, 0.555555555555556)));
-    assert_eq!(roots.next(), Some((0.0, 0.888888888888889)));
-    assert_eq!(roots.next(), Some((0.774596669241483, 0.555555555555556)));
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, -0.774596669241483, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.555555555555556, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.0, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.888888888888889, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.774596669241483, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.555555555555556, epsilon=1.0e-14);
     assert_eq!(roots.next(), None);
 }
 
@@ -35,10 +47,32 @@ fn root_weight_4()
 {
     let mut roots = RootWeight::<f64>::new(4);
 
-    assert_eq!(roots.next(), Some((-0.861136311594053, 0.347854845137454)));
-    assert_eq!(roots.next(), Some((-0.339981043584856, 0.652145154862546)));
-    assert_eq!(roots.next(), Some((0.339981043584856, 0.652145154862546)));
-    assert_eq!(roots.next(), Some((0.861136311594053, 0.347854845137454)));
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, -0.861136311594053, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.347854845137454, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, -0.339981043584856, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.652145154862546, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.339981043584856, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.652145154862546, epsilon=1.0e-14);
+    let (x_i, a_i): (f64, f64) = roots.next().unwrap();
+    assert_relative_eq!(x_i, 0.861136311594053, epsilon=1.0e-14);
+    assert_relative_eq!(a_i, 0.347854845137454, epsilon=1.0e-14);
     assert_eq!(roots.next(), None);
 }
 
+
+#[test]
+fn root_weight_20()
+{
+    let roots = RootWeight::<f64>::new(20);
+
+    let (x, a): (Vec<f64>, Vec<f64>) = roots.unzip();
+
+    assert_eq!(x.len(), 20);
+    assert_relative_eq!(x[19], 0.9931285991850949, epsilon=1.0e-14);
+    assert_relative_eq!(a[19], 0.017614007139152118, epsilon=1.0e-14);
+    assert_relative_eq!(x[0], -x[19]);
+    assert_relative_eq!(a.iter().sum::<f64>(), 2.0, epsilon=1.0e-14);
+}
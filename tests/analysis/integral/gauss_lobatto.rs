@@ -0,0 +1,48 @@
+use mathru::analysis::integral::GaussLobatto;
+
+#[test]
+fn trapezoidal()
+{
+    let gl: GaussLobatto<f64> = GaussLobatto::new(2);
+
+    assert_eq!(gl.nodes(), &[-1.0, 1.0]);
+    assert_eq!(gl.weights(), &[1.0, 1.0]);
+}
+
+#[test]
+fn simpson()
+{
+    let gl: GaussLobatto<f64> = GaussLobatto::new(3);
+
+    assert_relative_eq!(gl.nodes()[1], 0.0, epsilon=1.0e-15);
+    assert_relative_eq!(gl.weights()[0], 1.0 / 3.0, epsilon=1.0e-15);
+    assert_relative_eq!(gl.weights()[1], 4.0 / 3.0, epsilon=1.0e-15);
+    assert_relative_eq!(gl.weights()[2], 1.0 / 3.0, epsilon=1.0e-15);
+}
+
+#[test]
+fn nodes_weights_5()
+{
+    let gl: GaussLobatto<f64> = GaussLobatto::new(5);
+
+    assert_relative_eq!(gl.nodes()[3], (3.0f64 / 7.0).sqrt(), epsilon=1.0e-14);
+    assert_relative_eq!(gl.weights()[2], 32.0 / 45.0, epsilon=1.0e-14);
+    assert_relative_eq!(gl.weights()[3], 49.0 / 90.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn polynomial_exact()
+{
+    let gl: GaussLobatto<f64> = GaussLobatto::new(6);
+
+    let integral: f64 = gl.integrate(|x| x.powi(9) + x.powi(8), 0.0, 2.0);
+
+    assert_relative_eq!(integral, 102.4 + 512.0 / 9.0, epsilon=1.0e-11);
+}
+
+#[test]
+#[should_panic]
+fn n_out_of_bounds()
+{
+    let _gl: GaussLobatto<f64> = GaussLobatto::new(1);
+}
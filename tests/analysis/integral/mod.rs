@@ -1,3 +1,11 @@
-
 mod newton_cotes;
-mod gauss_legendre;
\ No newline at end of file
+mod gauss_legendre;
+mod gauss_kronrod;
+mod simpson;
+mod gauss_hermite;
+mod gauss_laguerre;
+mod gauss_jacobi;
+mod gauss_chebyshev;
+mod gauss_lobatto;
+mod tanh_sinh;
+mod multidimensional;
@@ -0,0 +1,51 @@
+use super::normal_density;
+use mathru::{
+    algebra::linear::Vector,
+    analysis::integral::{multidimensional::GenzMalik, QuadratureResult},
+};
+
+#[test]
+fn polynomial_exact()
+{
+    let gm: GenzMalik<f64> = GenzMalik::default();
+    let f = |x: &Vector<f64>| x[0].powi(3) * x[1].powi(2) * x[2].powi(2) + x[3];
+
+    let result: QuadratureResult<f64> = gm.integrate(f, &Vector::zero(4), &vector![1.0; 1.0; 1.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 1.0 / 36.0 + 0.5, epsilon=1.0e-12);
+}
+
+#[test]
+fn product_peak()
+{
+    let gm: GenzMalik<f64> = GenzMalik::new(1.0e-6, 1.0e-8, 2_000_000);
+    let f = |x: &Vector<f64>| (0..3).fold(1.0f64, |p, k| p / (0.04 + (x[k] - 0.5) * (x[k] - 0.5)));
+
+    let result: QuadratureResult<f64> = gm.integrate(f, &Vector::zero(3), &vector![1.0; 1.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 1686.3910933934972, max_relative=1.0e-8);
+}
+
+#[test]
+fn normal_probability()
+{
+    let gm: GenzMalik<f64> = GenzMalik::new(1.0e-6, 0.0, 2_000_000);
+
+    let result: QuadratureResult<f64> = gm.integrate(normal_density,
+                                                     &vector![-1.0; -1.0; -1.0; -1.0; -1.0],
+                                                     &vector![2.0; 2.0; 2.0; 2.0; 2.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 0.3675736869862552, epsilon=1.0e-6);
+}
+
+#[test]
+#[should_panic]
+fn one_dimensional()
+{
+    let gm: GenzMalik<f64> = GenzMalik::default();
+
+    let _result: QuadratureResult<f64> = gm.integrate(|x: &Vector<f64>| x[0], &vector![0.0], &vector![1.0]);
+}
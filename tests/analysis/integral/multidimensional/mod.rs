@@ -0,0 +1,16 @@
+mod tensor_gauss;
+mod genz_malik;
+mod monte_carlo;
+mod quasi_monte_carlo;
+mod vegas;
+
+use mathru::algebra::linear::Vector;
+use std::f64::consts::PI;
+
+/// Density of the standard normal distribution in d dimensions
+fn normal_density(x: &Vector<f64>) -> f64
+{
+    let (d, _): (usize, usize) = x.dim();
+    let r_2: f64 = (0..d).fold(0.0f64, |s, k| s + x[k] * x[k]);
+    (-r_2 / 2.0).exp() / (2.0 * PI).powf(d as f64 / 2.0)
+}
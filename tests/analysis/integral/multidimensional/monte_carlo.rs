@@ -0,0 +1,45 @@
+use super::normal_density;
+use mathru::{
+    algebra::linear::Vector,
+    analysis::integral::{multidimensional::MonteCarlo, QuadratureResult},
+};
+
+#[test]
+fn normal_probability()
+{
+    let mut mc: MonteCarlo<f64> = MonteCarlo::new(1.0e-3, 0.0, 10_000_000);
+    mc.set_seed(5);
+
+    let result: QuadratureResult<f64> = mc.integrate(normal_density,
+                                                     &vector![-1.0; -1.0; -1.0; -1.0; -1.0],
+                                                     &vector![2.0; 2.0; 2.0; 2.0; 2.0]);
+
+    assert!(result.is_converged());
+    assert!(result.error() <= 1.0e-3);
+    assert_relative_eq!(result.value(), 0.3675736869862552, epsilon=5.0 * result.error());
+}
+
+#[test]
+fn reproducible()
+{
+    let mut mc: MonteCarlo<f64> = MonteCarlo::new(1.0e-2, 0.0, 100_000);
+    mc.set_seed(7);
+    let f = |x: &Vector<f64>| x[0] * x[1];
+
+    let result_1: QuadratureResult<f64> = mc.integrate(f, &Vector::zero(2), &vector![1.0; 1.0]);
+    let result_2: QuadratureResult<f64> = mc.integrate(f, &Vector::zero(2), &vector![1.0; 1.0]);
+
+    assert_eq!(result_1.value(), result_2.value());
+}
+
+#[test]
+fn max_samples()
+{
+    let mut mc: MonteCarlo<f64> = MonteCarlo::new(1.0e-12, 0.0, 5000);
+    mc.set_seed(7);
+
+    let result: QuadratureResult<f64> = mc.integrate(|x: &Vector<f64>| x[0], &vector![0.0], &vector![1.0]);
+
+    assert!(!result.is_converged());
+    assert_eq!(result.evaluations(), 5000);
+}
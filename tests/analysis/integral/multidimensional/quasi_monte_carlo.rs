@@ -0,0 +1,79 @@
+use super::normal_density;
+use mathru::{
+    algebra::linear::Vector,
+    analysis::integral::{
+        multidimensional::{Halton, LowDiscrepancySequence, QuasiMonteCarlo, Sobol},
+        QuadratureResult,
+    },
+};
+
+#[test]
+fn sobol_points()
+{
+    let points: Vec<Vector<f64>> = Sobol::new(3).take(4).collect();
+
+    assert_eq!(points[0], vector![0.5; 0.5; 0.5]);
+    assert_eq!(points[1], vector![0.75; 0.25; 0.25]);
+    assert_eq!(points[2], vector![0.25; 0.75; 0.75]);
+    assert_eq!(points[3], vector![0.375; 0.375; 0.625]);
+}
+
+#[test]
+fn sobol_stratification()
+{
+    // each of the 2^4 elementary intervals of a coordinate contains exactly one of the first 16 points
+    for k in 0..Sobol::<f64>::MAX_DIM
+    {
+        let mut count: Vec<usize> = vec![0; 16];
+        for x in Sobol::<f64>::new(Sobol::<f64>::MAX_DIM).take(15)
+        {
+            count[(x[k] * 16.0) as usize] += 1;
+        }
+        assert_eq!(count.iter().filter(|c| **c == 1).count(), 15);
+    }
+}
+
+#[test]
+fn halton_points()
+{
+    let points: Vec<Vector<f64>> = Halton::new(3).take(3).collect();
+
+    assert_relative_eq!(points[2], vector![0.75; 1.0 / 9.0; 0.6]);
+}
+
+#[test]
+fn normal_probability()
+{
+    let mut qmc: QuasiMonteCarlo<f64> = QuasiMonteCarlo::new(1.0e-5, 0.0, 10_000_000);
+    qmc.set_seed(3);
+
+    let lower: Vector<f64> = Vector::new_column(vec![-1.0; 10]);
+    let upper: Vector<f64> = Vector::new_column(vec![2.0; 10]);
+    let result: QuadratureResult<f64> = qmc.integrate(normal_density, &lower, &upper);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 0.13511041536466953, epsilon=5.0e-5);
+}
+
+#[test]
+fn halton()
+{
+    let mut qmc: QuasiMonteCarlo<f64> = QuasiMonteCarlo::new(1.0e-5, 0.0, 10_000_000);
+    qmc.set_sequence(LowDiscrepancySequence::Halton);
+    qmc.set_seed(3);
+
+    let result: QuadratureResult<f64> = qmc.integrate(normal_density,
+                                                      &vector![-1.0; -1.0; -1.0],
+                                                      &vector![2.0; 2.0; 2.0]);
+
+    assert!(result.is_converged());
+    assert_eq!(qmc.get_sequence(), LowDiscrepancySequence::Halton);
+    assert_relative_eq!(result.value(), 0.5485379115971046, epsilon=1.0e-4);
+}
+
+#[test]
+#[should_panic]
+fn sobol_dimension()
+{
+    let _sobol: Sobol<f64> = Sobol::new(Sobol::<f64>::MAX_DIM + 1);
+}
@@ -0,0 +1,23 @@
+use super::normal_density;
+use mathru::{algebra::linear::Vector, analysis::integral::multidimensional::TensorGauss};
+
+#[test]
+fn polynomial_exact()
+{
+    let tg: TensorGauss<f64> = TensorGauss::new(3);
+    let f = |x: &Vector<f64>| x[0].powi(5) * x[1].powi(4) * x[2];
+
+    let integral: f64 = tg.integrate(f, &vector![0.0; -1.0; 0.0], &vector![1.0; 1.0; 2.0]);
+
+    assert_relative_eq!(integral, 1.0 / 6.0 * 2.0 / 5.0 * 2.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn normal_probability()
+{
+    let tg: TensorGauss<f64> = TensorGauss::new(12);
+
+    let integral: f64 = tg.integrate(normal_density, &vector![-1.0; -1.0; -1.0], &vector![2.0; 2.0; 2.0]);
+
+    assert_relative_eq!(integral, 0.5485379115971046, epsilon=1.0e-10);
+}
@@ -0,0 +1,36 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::integral::{multidimensional::Vegas, QuadratureResult},
+};
+use std::f64::consts::PI;
+
+#[test]
+fn gaussian_peak()
+{
+    let mut vegas: Vegas<f64> = Vegas::new(1.0e-3, 1.0e-3, 20000, 30);
+    vegas.set_seed(11);
+
+    // normalized peak in the center of [0, 1]^4
+    let f = |x: &Vector<f64>| {
+        let r_2: f64 = (0..4).fold(0.0f64, |s, k| s + (x[k] - 0.5) * (x[k] - 0.5));
+        (-r_2 / 0.005).exp() / (0.005 * PI).powi(2)
+    };
+
+    let result: QuadratureResult<f64> = vegas.integrate(f, &Vector::zero(4), &vector![1.0; 1.0; 1.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 1.0, epsilon=5.0 * result.error());
+}
+
+#[test]
+fn constant()
+{
+    let mut vegas: Vegas<f64> = Vegas::default();
+    vegas.set_seed(1);
+    vegas.set_warm_up(0);
+
+    let result: QuadratureResult<f64> = vegas.integrate(|_x: &Vector<f64>| 2.0, &vector![0.0; 1.0], &vector![2.0; 4.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 12.0, epsilon=1.0e-10);
+}
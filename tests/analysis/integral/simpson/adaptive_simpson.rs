@@ -0,0 +1,47 @@
+use mathru::analysis::integral::{simpson::AdaptiveSimpson, QuadratureResult};
+
+#[test]
+fn polynomial()
+{
+    let simpson: AdaptiveSimpson<f64> = AdaptiveSimpson::new(1.0e-10, 50);
+
+    // Simpson's rule is exact for cubic polynomials
+    let result: QuadratureResult<f64> = simpson.integrate(|x: f64| x * x * x - 2.0 * x, 1.0, 3.0);
+
+    assert!(result.is_converged());
+    assert_eq!(5, result.evaluations());
+    assert_relative_eq!(12.0, result.value(), epsilon = 1.0e-12);
+}
+
+#[test]
+fn peaked()
+{
+    let simpson: AdaptiveSimpson<f64> = AdaptiveSimpson::new(1.0e-9, 50);
+
+    let result: QuadratureResult<f64> = simpson.integrate(|x: f64| 1.0 / (1.0e-4 + x * x), -1.0, 1.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(200.0 * 100.0f64.atan(), result.value(), epsilon = 1.0e-7);
+}
+
+#[test]
+fn singularity()
+{
+    let simpson: AdaptiveSimpson<f64> = AdaptiveSimpson::new(1.0e-8, 60);
+
+    let result: QuadratureResult<f64> = simpson.integrate(|x: f64| x.sqrt(), 0.0, 1.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(2.0 / 3.0, result.value(), epsilon = 1.0e-8);
+}
+
+#[test]
+fn max_depth_reached()
+{
+    let simpson: AdaptiveSimpson<f64> = AdaptiveSimpson::new(1.0e-12, 3);
+
+    let result: QuadratureResult<f64> = simpson.integrate(|x: f64| x.sqrt(), 0.0, 1.0);
+
+    assert!(!result.is_converged());
+    assert!(result.error() > 0.0);
+}
@@ -0,0 +1 @@
+mod adaptive_simpson;
@@ -0,0 +1,88 @@
+use mathru::analysis::integral::{QuadratureResult, TanhSinh};
+use mathru::statistics::distrib::{Continuous, Gamma, LogNormal, Normal};
+use std::f64::consts::PI;
+
+#[test]
+fn smooth()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| x.exp(), 0.0, 2.0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 2.0f64.exp() - 1.0, epsilon=1.0e-12);
+}
+
+#[test]
+fn reversed_bounds()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| x.cos(), PI / 2.0, 0.0);
+
+    assert_relative_eq!(result.value(), -1.0, epsilon=1.0e-12);
+}
+
+#[test]
+fn end_point_singularities()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| 1.0 / x.sqrt(), 0.0, 1.0);
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), 2.0, epsilon=1.0e-10);
+
+    // \int_{-1}^{1} 1 / \sqrt{1 - x^2} dx, the nodes closer to the bounds than the machine precision are lost
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| 1.0 / (1.0 - x * x).sqrt(), -1.0, 1.0);
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), PI, epsilon=1.0e-7);
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| x.ln() * (1.0 - x).ln(), 0.0, 1.0);
+    assert_relative_eq!(result.value(), 2.0 - PI * PI / 6.0, epsilon=1.0e-10);
+}
+
+#[test]
+fn semi_infinite()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| (-x).exp(), 1.0, f64::INFINITY);
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), (-1.0f64).exp(), epsilon=1.0e-10);
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| 1.0 / (1.0 + x * x), f64::NEG_INFINITY, 0.0);
+    assert_relative_eq!(result.value(), PI / 2.0, epsilon=1.0e-9);
+
+    // \int_0^\infty e^{-x} / \sqrt{x} dx = \sqrt{\pi}
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| (-x).exp() / x.sqrt(), 0.0, f64::INFINITY);
+    assert_relative_eq!(result.value(), PI.sqrt(), epsilon=1.0e-9);
+}
+
+#[test]
+fn infinite()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let result: QuadratureResult<f64> = ts.integrate(|x: f64| 1.0 / (1.0 + x * x), f64::NEG_INFINITY, f64::INFINITY);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.value(), PI, epsilon=1.0e-9);
+}
+
+#[test]
+fn expectations()
+{
+    let ts: TanhSinh<f64> = TanhSinh::default();
+
+    let normal: Normal<f64> = Normal::new(1.5, 1.0);
+    let mean: f64 = ts.integrate(|x: f64| x * normal.pdf(x), f64::NEG_INFINITY, f64::INFINITY).value();
+    assert_relative_eq!(mean, normal.mean(), epsilon=1.0e-9);
+
+    let gamma: Gamma<f64> = Gamma::new(2.5, 1.5);
+    let mean: f64 = ts.integrate(|x: f64| x * gamma.pdf(x), 0.0, f64::INFINITY).value();
+    assert_relative_eq!(mean, gamma.mean(), epsilon=1.0e-9);
+
+    let log_normal: LogNormal<f64> = LogNormal::new(0.3, 0.2);
+    let mean: f64 = ts.integrate(|x: f64| x * log_normal.pdf(x), 0.0, f64::INFINITY).value();
+    assert_relative_eq!(mean, log_normal.mean(), epsilon=1.0e-9);
+}
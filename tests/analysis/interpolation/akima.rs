@@ -0,0 +1,33 @@
+use mathru::analysis::interpolation::{Akima, Interpolant};
+
+#[test]
+fn linear_data()
+{
+    let akima: Akima<f64> = Akima::new(vec![0.0, 1.0, 1.5, 4.0], vec![1.0, 3.0, 4.0, 9.0]);
+
+    assert_relative_eq!(akima.eval(2.5), 6.0, epsilon=1.0e-14);
+    assert_relative_eq!(akima.eval(-1.0), -1.0, epsilon=1.0e-14);
+    assert_relative_eq!(akima.integrate(0.0, 4.0), 20.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn step()
+{
+    let akima: Akima<f64> = Akima::new(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+    assert_eq!(akima.eval(0.5), 0.0);
+    assert_eq!(akima.eval(1.5), 0.0);
+    assert_relative_eq!(akima.eval(2.5), 0.5, epsilon=1.0e-15);
+    assert_eq!(akima.eval(3.5), 1.0);
+    assert_eq!(akima.eval(4.5), 1.0);
+}
+
+#[test]
+fn smooth()
+{
+    let x: Vec<f64> = (0..=50).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|x_i| x_i.cos()).collect();
+    let akima: Akima<f64> = Akima::new(x, y);
+
+    assert_relative_eq!(akima.eval(2.33), 2.33f64.cos(), epsilon=1.0e-4);
+}
@@ -0,0 +1,54 @@
+use mathru::analysis::interpolation::{BarycentricLagrange, Extrapolation, Interpolant};
+
+#[test]
+fn polynomial()
+{
+    let f = |x: f64| x * x * x - 2.0 * x + 1.0;
+    let x: Vec<f64> = vec![-1.0, 0.0, 1.0, 2.0];
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+    let p: BarycentricLagrange<f64> = BarycentricLagrange::new(x, y);
+
+    assert_relative_eq!(p.eval(0.5), f(0.5), epsilon=1.0e-14);
+    assert_relative_eq!(p.eval(3.0), f(3.0), epsilon=1.0e-13);
+    assert_relative_eq!(p.derivative(0.5), 3.0 * 0.25 - 2.0, epsilon=1.0e-14);
+    assert_relative_eq!(p.derivative(1.0), 1.0, epsilon=1.0e-14);
+    assert_relative_eq!(p.integrate(-1.0, 2.0), 15.0 / 4.0 - 3.0 + 3.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn chebyshev_points()
+{
+    let x: Vec<f64> = BarycentricLagrange::chebyshev_points(5, -1.0, 3.0);
+
+    assert_eq!(x[0], -1.0);
+    assert_relative_eq!(x[1], 1.0 - 2.0f64.sqrt(), epsilon=1.0e-15);
+    assert_eq!(x[2], 1.0);
+    assert_eq!(x[4], 3.0);
+}
+
+#[test]
+fn runge()
+{
+    let f = |x: f64| 1.0 / (1.0 + 25.0 * x * x);
+    let x: Vec<f64> = BarycentricLagrange::chebyshev_points(201, -1.0, 1.0);
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+    let p: BarycentricLagrange<f64> = BarycentricLagrange::from_chebyshev(y, -1.0, 1.0);
+
+    for x in [-0.95, -0.3, 0.01, 0.77]
+    {
+        assert_relative_eq!(p.eval(x), f(x), epsilon=1.0e-13);
+    }
+    assert_relative_eq!(p.derivative(0.2), -50.0 * 0.2 / (2.0f64 * 2.0), epsilon=1.0e-10);
+    assert_relative_eq!(p.integrate(-1.0, 1.0), 0.4 * 5.0f64.atan(), epsilon=1.0e-13);
+}
+
+#[test]
+fn constant_extrapolation()
+{
+    let mut p: BarycentricLagrange<f64> = BarycentricLagrange::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 4.0]);
+    p.set_extrapolation(Extrapolation::Constant);
+
+    assert_relative_eq!(p.eval(3.0), 4.0);
+    assert_relative_eq!(p.derivative(3.0), 0.0);
+    assert_relative_eq!(p.integrate(0.0, 3.0), 8.0 / 3.0 + 4.0, epsilon=1.0e-14);
+}
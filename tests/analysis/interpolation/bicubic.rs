@@ -0,0 +1,44 @@
+use mathru::{algebra::linear::Matrix, analysis::interpolation::Bicubic};
+
+fn grid(f: &dyn Fn(f64, f64) -> f64, x: &[f64], y: &[f64]) -> Matrix<f64>
+{
+    Matrix::new(x.len(), y.len(), y.iter().flat_map(|y_j| x.iter().map(move |x_i| f(*x_i, *y_j))).collect())
+}
+
+#[test]
+fn bicubic_polynomial()
+{
+    let f = |x: f64, y: f64| x * x * x * y * y + x * y * y * y - y + 2.0;
+    let x: Vec<f64> = vec![-1.0, 0.0, 0.5, 1.5, 2.0];
+    let y: Vec<f64> = vec![0.0, 0.3, 1.0, 2.0];
+    let bicubic: Bicubic<f64> = Bicubic::new(x.clone(), y.clone(), grid(&f, &x, &y));
+
+    assert_relative_eq!(bicubic.eval(0.7, 0.45), f(0.7, 0.45), epsilon=1.0e-12);
+    assert_relative_eq!(bicubic.eval(-0.3, 1.8), f(-0.3, 1.8), epsilon=1.0e-12);
+
+    let (dz_dx, dz_dy): (f64, f64) = bicubic.gradient(0.7, 0.45);
+    assert_relative_eq!(dz_dx, 3.0 * 0.49 * 0.2025 + 0.45f64.powi(3), epsilon=1.0e-12);
+    assert_relative_eq!(dz_dy, 2.0 * 0.343 * 0.45 + 3.0 * 0.7 * 0.2025 - 1.0, epsilon=1.0e-12);
+}
+
+#[test]
+fn convergence()
+{
+    let f = |x: f64, y: f64| (x * y).cos();
+    let x: Vec<f64> = (0..=20).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = (0..=20).map(|j| j as f64 / 10.0).collect();
+    let bicubic: Bicubic<f64> = Bicubic::new(x.clone(), y.clone(), grid(&f, &x, &y));
+
+    assert_relative_eq!(bicubic.eval(1.23, 1.77), f(1.23, 1.77), epsilon=1.0e-5);
+}
+
+#[test]
+fn small_grid()
+{
+    let f = |x: f64, y: f64| x - 3.0 * y;
+    let x: Vec<f64> = vec![0.0, 1.0];
+    let y: Vec<f64> = vec![0.0, 1.0, 3.0];
+    let bicubic: Bicubic<f64> = Bicubic::new(x.clone(), y.clone(), grid(&f, &x, &y));
+
+    assert_relative_eq!(bicubic.eval(0.25, 2.0), f(0.25, 2.0), epsilon=1.0e-14);
+}
@@ -0,0 +1,49 @@
+use mathru::{
+    algebra::linear::Matrix,
+    analysis::interpolation::{Bilinear, Extrapolation},
+};
+
+fn grid(f: &dyn Fn(f64, f64) -> f64, x: &[f64], y: &[f64]) -> Matrix<f64>
+{
+    Matrix::new(x.len(), y.len(), y.iter().flat_map(|y_j| x.iter().map(move |x_i| f(*x_i, *y_j))).collect())
+}
+
+#[test]
+fn bilinear_function()
+{
+    let f = |x: f64, y: f64| 1.0 + 2.0 * x - y + 0.5 * x * y;
+    let x: Vec<f64> = vec![0.0, 0.5, 2.0, 3.0];
+    let y: Vec<f64> = vec![-1.0, 1.0, 1.5];
+    let bilinear: Bilinear<f64> = Bilinear::new(x.clone(), y.clone(), grid(&f, &x, &y));
+
+    assert_relative_eq!(bilinear.eval(1.3, 0.2), f(1.3, 0.2), epsilon=1.0e-14);
+    assert_relative_eq!(bilinear.eval(3.0, 1.5), f(3.0, 1.5), epsilon=1.0e-14);
+    assert_relative_eq!(bilinear.eval(4.0, -2.0), f(4.0, -2.0), epsilon=1.0e-14);
+
+    let (dz_dx, dz_dy): (f64, f64) = bilinear.gradient(1.3, 0.2);
+    assert_relative_eq!(dz_dx, 2.0 + 0.5 * 0.2, epsilon=1.0e-14);
+    assert_relative_eq!(dz_dy, -1.0 + 0.5 * 1.3, epsilon=1.0e-14);
+}
+
+#[test]
+fn constant_extrapolation()
+{
+    let f = |x: f64, y: f64| x + 2.0 * y;
+    let x: Vec<f64> = vec![0.0, 1.0];
+    let y: Vec<f64> = vec![0.0, 1.0];
+    let mut bilinear: Bilinear<f64> = Bilinear::new(x.clone(), y.clone(), grid(&f, &x, &y));
+    bilinear.set_extrapolation(Extrapolation::Constant);
+
+    assert_relative_eq!(bilinear.eval(2.0, 0.5), 2.0);
+    assert_eq!(bilinear.gradient(2.0, 0.5), (0.0, 2.0));
+
+    bilinear.set_extrapolation(Extrapolation::Nan);
+    assert!(bilinear.eval(0.5, -0.1).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn dimension_mismatch()
+{
+    let _ = Bilinear::new(vec![0.0, 1.0], vec![0.0, 1.0, 2.0], Matrix::zero(2, 2));
+}
@@ -0,0 +1,191 @@
+use mathru::analysis::interpolation::{BSpline, Extrapolation, Interpolant};
+
+fn cubic() -> BSpline<f64>
+{
+    let knots: Vec<f64> = BSpline::clamped_knots(&[0.0, 1.0, 1.5, 3.0, 4.0], 3);
+    BSpline::new(3, knots, vec![1.0, -1.0, 2.0, 0.5, 3.0, -2.0, 1.0])
+}
+
+#[test]
+fn clamped_knots()
+{
+    assert_eq!(BSpline::clamped_knots(&[0.0, 1.0, 2.0], 2), vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn partition_of_unity()
+{
+    let knots: Vec<f64> = vec![0.0, 0.5, 1.0, 1.0, 2.5, 3.0, 4.0, 4.5, 6.0];
+    let spline: BSpline<f64> = BSpline::new(3, knots, vec![1.0; 5]);
+
+    assert_eq!(spline.domain(), (1.0, 3.0));
+    for x in [1.0, 1.7, 2.5, 2.9, 3.0]
+    {
+        assert_relative_eq!(spline.eval(x), 1.0, epsilon=1.0e-15);
+        let (_, basis): (usize, Vec<f64>) = spline.basis(x);
+        assert_relative_eq!(basis.iter().sum::<f64>(), 1.0, epsilon=1.0e-15);
+    }
+}
+
+#[test]
+fn basis()
+{
+    let knots: Vec<f64> = BSpline::clamped_knots(&[0.0, 1.0, 2.0], 2);
+    let spline: BSpline<f64> = BSpline::new(2, knots, vec![0.0; 4]);
+
+    let (first, basis): (usize, Vec<f64>) = spline.basis(0.5);
+    assert_eq!(first, 0);
+    assert_relative_eq!(basis[0], 0.25, epsilon=1.0e-15);
+    assert_relative_eq!(basis[1], 0.625, epsilon=1.0e-15);
+    assert_relative_eq!(basis[2], 0.125, epsilon=1.0e-15);
+}
+
+#[test]
+fn eval_matches_basis()
+{
+    let spline: BSpline<f64> = cubic();
+
+    for x in [0.0, 0.3, 1.0, 1.2, 2.2, 3.7, 4.0]
+    {
+        let (first, basis): (usize, Vec<f64>) = spline.basis(x);
+        let value: f64 = basis.iter().enumerate().map(|(j, b)| b * spline.coefficients()[first + j]).sum();
+        assert_relative_eq!(spline.eval(x), value, epsilon=1.0e-14);
+    }
+    assert_relative_eq!(spline.eval(0.0), 1.0);
+    assert_relative_eq!(spline.eval(4.0), 1.0);
+}
+
+#[test]
+fn differentiate()
+{
+    // x^2 on [0, 1] in the Bernstein basis
+    let spline: BSpline<f64> = BSpline::new(2, vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0], vec![0.0, 0.0, 1.0]);
+    let derivative: BSpline<f64> = spline.differentiate();
+
+    assert_eq!(derivative.degree(), 1);
+    assert_eq!(derivative.coefficients(), &[0.0, 2.0]);
+    assert_relative_eq!(derivative.eval(0.3), 0.6, epsilon=1.0e-15);
+    assert_relative_eq!(spline.derivative(0.3), 0.6, epsilon=1.0e-15);
+}
+
+#[test]
+fn derivative_matches_differentiate()
+{
+    let spline: BSpline<f64> = cubic();
+    let first: BSpline<f64> = spline.differentiate();
+    let second: BSpline<f64> = first.differentiate();
+
+    for x in [0.1, 1.0, 1.3, 2.0, 3.5]
+    {
+        assert_relative_eq!(spline.derivative(x), first.eval(x), epsilon=1.0e-13);
+        assert_relative_eq!(first.derivative(x), second.eval(x), epsilon=1.0e-13);
+        let h: f64 = 1.0e-6;
+        assert_relative_eq!(spline.derivative(x), (spline.eval(x + h) - spline.eval(x - h)) / (2.0 * h), epsilon=1.0e-7);
+    }
+}
+
+#[test]
+fn integrate()
+{
+    let spline: BSpline<f64> = cubic();
+
+    // the integral of a clamped spline is the sum of c_i (t_{i+p+1} - t_i) / (p + 1)
+    let knots: &[f64] = spline.knots();
+    let total: f64 = spline.coefficients().iter().enumerate().map(|(i, c)| c * (knots[i + 4] - knots[i]) / 4.0).sum();
+    assert_relative_eq!(spline.integrate(0.0, 4.0), total, epsilon=1.0e-14);
+
+    let simpson: f64 = (0..1000).map(|k| {
+                                    let (a, b): (f64, f64) = (1.2 + k as f64 * 0.002, 1.2 + (k + 1) as f64 * 0.002);
+                                    (b - a) / 6.0 * (spline.eval(a) + 4.0 * spline.eval((a + b) / 2.0) + spline.eval(b))
+                                })
+                                .sum();
+    assert_relative_eq!(spline.integrate(1.2, 3.2), simpson, epsilon=1.0e-12);
+}
+
+#[test]
+fn insert_knot()
+{
+    let mut spline: BSpline<f64> = cubic();
+    let original: BSpline<f64> = spline.clone();
+
+    spline.insert_knot(2.0);
+    spline.insert_knot(1.5);
+    spline.insert_knot(0.25);
+
+    assert_eq!(spline.coefficients().len(), original.coefficients().len() + 3);
+    assert_eq!(spline.knots().len(), original.knots().len() + 3);
+    for k in 0..=40
+    {
+        let x: f64 = k as f64 / 10.0;
+        assert_relative_eq!(spline.eval(x), original.eval(x), epsilon=1.0e-14);
+    }
+}
+
+#[test]
+#[should_panic]
+fn insert_knot_outside()
+{
+    cubic().insert_knot(4.0);
+}
+
+#[test]
+fn extrapolation()
+{
+    let mut spline: BSpline<f64> = BSpline::new(1, vec![0.0, 0.0, 1.0, 2.0, 2.0], vec![0.0, 2.0, 1.0]);
+
+    assert_relative_eq!(spline.eval(-1.0), -2.0);
+    spline.set_extrapolation(Extrapolation::Constant);
+    assert_relative_eq!(spline.eval(-1.0), 0.0);
+    assert_relative_eq!(spline.integrate(0.0, 3.0), 2.5 + 1.0);
+}
+
+#[test]
+fn fit_polynomial()
+{
+    let f = |x: f64| x * x * x - 2.0 * x * x + 0.5;
+    let x: Vec<f64> = (0..=30).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+
+    let knots: Vec<f64> = BSpline::clamped_knots(&[0.0, 0.7, 1.9, 3.0], 3);
+    let spline: BSpline<f64> = BSpline::fit(&x, &y, 3, knots);
+
+    assert_relative_eq!(spline.eval(1.23), f(1.23), epsilon=1.0e-12);
+    assert_relative_eq!(spline.eval(2.87), f(2.87), epsilon=1.0e-12);
+}
+
+#[test]
+fn fit_noisy()
+{
+    let x: Vec<f64> = (0..200).map(|i| i as f64 / 199.0 * std::f64::consts::PI).collect();
+    let y: Vec<f64> = x.iter().enumerate().map(|(i, x_i)| x_i.sin() + if i % 2 == 0 { 1.0e-3 } else { -1.0e-3 }).collect();
+
+    let breakpoints: Vec<f64> = (0..=8).map(|i| i as f64 / 8.0 * std::f64::consts::PI).collect();
+    let spline: BSpline<f64> = BSpline::fit(&x, &y, 3, BSpline::clamped_knots(&breakpoints, 3));
+
+    assert_relative_eq!(spline.eval(1.0), 1.0f64.sin(), epsilon=1.0e-4);
+}
+
+#[test]
+#[should_panic]
+fn fit_empty_span()
+{
+    let x: Vec<f64> = vec![0.0, 0.1, 0.2, 0.3, 0.4, 2.0];
+    let y: Vec<f64> = vec![0.0; 6];
+    let _ = BSpline::fit(&x, &y, 3, BSpline::clamped_knots(&[0.0, 0.5, 1.0, 1.5, 2.0], 3));
+}
+
+#[test]
+#[should_panic(expected = "The points are not finite")]
+fn fit_nan()
+{
+    let x: Vec<f64> = vec![0.0, 0.1, f64::NAN, 0.3, 0.4, 0.5];
+    let y: Vec<f64> = vec![0.0; 6];
+    let _ = BSpline::fit(&x, &y, 1, BSpline::clamped_knots(&[0.0, 0.25, 0.5], 1));
+}
+
+#[test]
+#[should_panic]
+fn knots_length()
+{
+    let _ = BSpline::new(2, vec![0.0, 0.0, 0.0, 1.0, 1.0], vec![0.0, 1.0, 2.0]);
+}
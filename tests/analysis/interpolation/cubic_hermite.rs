@@ -0,0 +1,35 @@
+use mathru::analysis::interpolation::{CubicHermite, Interpolant};
+
+#[test]
+fn cubic()
+{
+    let f = |x: f64| x * x * x - x;
+    let df = |x: f64| 3.0 * x * x - 1.0;
+    let x: Vec<f64> = vec![-1.0, 0.0, 0.5, 2.0];
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+    let dydx: Vec<f64> = x.iter().map(|x_i| df(*x_i)).collect();
+    let hermite: CubicHermite<f64> = CubicHermite::new(x, y, dydx);
+
+    assert_relative_eq!(hermite.eval(-0.3), f(-0.3), epsilon=1.0e-15);
+    assert_relative_eq!(hermite.eval(1.2), f(1.2), epsilon=1.0e-14);
+    assert_relative_eq!(hermite.derivative(1.2), df(1.2), epsilon=1.0e-14);
+    assert_relative_eq!(hermite.integrate(-1.0, 2.0), 2.25, epsilon=1.0e-14);
+}
+
+#[test]
+fn dense_output()
+{
+    let x: Vec<f64> = (0..=20).map(|i| i as f64 / 10.0).collect();
+    let y: Vec<f64> = x.iter().map(|t| (-t).exp()).collect();
+    let dydx: Vec<f64> = y.iter().map(|y_i| -y_i).collect();
+    let hermite: CubicHermite<f64> = CubicHermite::new(x, y, dydx);
+
+    assert_relative_eq!(hermite.eval(1.05), (-1.05f64).exp(), epsilon=1.0e-6);
+}
+
+#[test]
+#[should_panic]
+fn derivatives_missing()
+{
+    let _ = CubicHermite::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0], vec![0.0, 1.0]);
+}
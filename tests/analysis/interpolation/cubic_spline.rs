@@ -0,0 +1,68 @@
+use mathru::analysis::interpolation::{CubicSpline, Interpolant, SplineBoundary};
+
+#[test]
+fn natural()
+{
+    let spline: CubicSpline<f64> = CubicSpline::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0], SplineBoundary::Natural);
+
+    assert_relative_eq!(spline.eval(0.5), 0.6875, epsilon=1.0e-15);
+    assert_relative_eq!(spline.eval(1.5), 0.6875, epsilon=1.0e-15);
+    assert_relative_eq!(spline.derivative(0.0), 1.5, epsilon=1.0e-15);
+    assert_relative_eq!(spline.derivative(1.0), 0.0, epsilon=1.0e-15);
+    assert_relative_eq!(spline.integrate(0.0, 2.0), 1.25, epsilon=1.0e-15);
+}
+
+#[test]
+fn natural_linear_data()
+{
+    let spline: CubicSpline<f64> = CubicSpline::new(vec![0.0, 0.5, 2.0, 3.0], vec![1.0, 2.0, 5.0, 7.0], SplineBoundary::default());
+
+    assert_relative_eq!(spline.eval(1.2), 3.4, epsilon=1.0e-14);
+    assert_relative_eq!(spline.derivative(2.7), 2.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn clamped_cubic()
+{
+    let f = |x: f64| x * x * x - 2.0 * x;
+    let x: Vec<f64> = vec![-1.0, -0.2, 0.5, 1.0, 2.0];
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+    let spline: CubicSpline<f64> = CubicSpline::new(x, y, SplineBoundary::Clamped(1.0, 10.0));
+
+    assert_relative_eq!(spline.eval(0.1), f(0.1), epsilon=1.0e-14);
+    assert_relative_eq!(spline.eval(1.7), f(1.7), epsilon=1.0e-14);
+    assert_relative_eq!(spline.derivative(0.8), 3.0 * 0.64 - 2.0, epsilon=1.0e-14);
+    assert_relative_eq!(spline.integrate(-1.0, 2.0), 0.75, epsilon=1.0e-14);
+}
+
+#[test]
+fn not_a_knot_cubic()
+{
+    let f = |x: f64| 2.0 * x * x * x - x * x + 1.0;
+    let x: Vec<f64> = vec![0.0, 0.3, 1.0, 1.5, 2.5, 3.0];
+    let y: Vec<f64> = x.iter().map(|x_i| f(*x_i)).collect();
+    let spline: CubicSpline<f64> = CubicSpline::new(x, y, SplineBoundary::NotAKnot);
+
+    assert_relative_eq!(spline.eval(0.1), f(0.1), epsilon=1.0e-13);
+    assert_relative_eq!(spline.eval(2.8), f(2.8), epsilon=1.0e-13);
+    assert_relative_eq!(spline.derivative(0.0), 0.0, epsilon=1.0e-13);
+}
+
+#[test]
+fn convergence()
+{
+    let x: Vec<f64> = (0..=40).map(|i| i as f64 * std::f64::consts::PI / 40.0).collect();
+    let y: Vec<f64> = x.iter().map(|x_i| x_i.sin()).collect();
+    let spline: CubicSpline<f64> = CubicSpline::new(x, y, SplineBoundary::NotAKnot);
+
+    assert_relative_eq!(spline.eval(1.0), 1.0f64.sin(), epsilon=1.0e-7);
+    assert_relative_eq!(spline.derivative(1.0), 1.0f64.cos(), epsilon=1.0e-5);
+    assert_relative_eq!(spline.integrate(0.0, std::f64::consts::PI), 2.0, epsilon=1.0e-6);
+}
+
+#[test]
+#[should_panic]
+fn not_a_knot_too_few_nodes()
+{
+    let _ = CubicSpline::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0], SplineBoundary::NotAKnot);
+}
@@ -0,0 +1,73 @@
+use mathru::analysis::interpolation::{Extrapolation, Interpolant, Linear};
+
+fn interpolant(extrapolation: Extrapolation) -> Linear<f64>
+{
+    let mut linear: Linear<f64> = Linear::new(vec![0.0, 1.0, 3.0], vec![0.0, 2.0, 3.0]);
+    linear.set_extrapolation(extrapolation);
+    linear
+}
+
+#[test]
+fn eval()
+{
+    let linear: Linear<f64> = interpolant(Extrapolation::default());
+
+    assert_eq!(linear.eval(0.0), 0.0);
+    assert_eq!(linear.eval(0.25), 0.5);
+    assert_eq!(linear.eval(1.0), 2.0);
+    assert_eq!(linear.eval(3.0), 3.0);
+    assert_eq!(linear.domain(), (0.0, 3.0));
+}
+
+#[test]
+fn extrapolate()
+{
+    let linear: Linear<f64> = interpolant(Extrapolation::Extrapolate);
+
+    assert_relative_eq!(linear.eval(-1.0), -2.0);
+    assert_relative_eq!(linear.eval(5.0), 4.0);
+    assert_relative_eq!(linear.derivative(5.0), 0.5);
+    assert_relative_eq!(linear.integrate(-1.0, 0.0), -1.0);
+}
+
+#[test]
+fn constant()
+{
+    let linear: Linear<f64> = interpolant(Extrapolation::Constant);
+
+    assert_eq!(linear.get_extrapolation(), Extrapolation::Constant);
+    assert_relative_eq!(linear.eval(-1.0), 0.0);
+    assert_relative_eq!(linear.eval(5.0), 3.0);
+    assert_relative_eq!(linear.derivative(5.0), 0.0);
+    assert_relative_eq!(linear.integrate(0.0, 5.0), 6.0 + 6.0);
+}
+
+#[test]
+fn periodic()
+{
+    let linear: Linear<f64> = interpolant(Extrapolation::Periodic);
+
+    assert_relative_eq!(linear.eval(4.0), 2.0);
+    assert_relative_eq!(linear.eval(-2.0), 2.0);
+    assert_relative_eq!(linear.derivative(3.5), 2.0);
+    assert_relative_eq!(linear.integrate(0.0, 6.0), 12.0);
+    assert_relative_eq!(linear.integrate(-3.0, 1.0), 7.0);
+}
+
+#[test]
+fn nan()
+{
+    let linear: Linear<f64> = interpolant(Extrapolation::Nan);
+
+    assert!(linear.eval(-0.1).is_nan());
+    assert!(linear.derivative(3.1).is_nan());
+    assert!(linear.integrate(0.0, 4.0).is_nan());
+    assert_relative_eq!(linear.integrate(0.0, 3.0), 6.0);
+}
+
+#[test]
+#[should_panic]
+fn nodes_not_increasing()
+{
+    let _ = Linear::new(vec![0.0, 1.0, 1.0], vec![0.0, 2.0, 3.0]);
+}
@@ -0,0 +1,13 @@
+mod linear;
+mod cubic_spline;
+mod cubic_hermite;
+mod pchip;
+mod akima;
+mod barycentric_lagrange;
+mod bilinear;
+mod bicubic;
+mod regular_grid;
+mod rbf;
+mod bspline;
+mod smoothing_spline;
+mod nurbs;
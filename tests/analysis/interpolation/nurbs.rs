@@ -0,0 +1,76 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::interpolation::{BSpline, Interpolant, Nurbs},
+};
+
+fn circle() -> Nurbs<f64>
+{
+    // full unit circle from nine control points
+    let w: f64 = 0.5f64.sqrt();
+    let points: Vec<Vector<f64>> = vec![vector![1.0; 0.0],
+                                        vector![1.0; 1.0],
+                                        vector![0.0; 1.0],
+                                        vector![-1.0; 1.0],
+                                        vector![-1.0; 0.0],
+                                        vector![-1.0; -1.0],
+                                        vector![0.0; -1.0],
+                                        vector![1.0; -1.0],
+                                        vector![1.0; 0.0]];
+    let knots: Vec<f64> = vec![0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0];
+    Nurbs::new(2, knots, points, vec![1.0, w, 1.0, w, 1.0, w, 1.0, w, 1.0])
+}
+
+#[test]
+fn circle_eval()
+{
+    let nurbs: Nurbs<f64> = circle();
+
+    assert_eq!(nurbs.domain(), (0.0, 1.0));
+    for k in 0..=20
+    {
+        let c: Vector<f64> = nurbs.eval(k as f64 / 20.0);
+        assert_relative_eq!(c[0] * c[0] + c[1] * c[1], 1.0, epsilon=1.0e-14);
+    }
+
+    let c: Vector<f64> = nurbs.eval(0.25);
+    assert_relative_eq!(c[0], 0.0, epsilon=1.0e-15);
+    assert_relative_eq!(c[1], 1.0, epsilon=1.0e-15);
+}
+
+#[test]
+fn circle_derivative()
+{
+    let nurbs: Nurbs<f64> = circle();
+
+    for u in [0.1, 0.3, 0.62, 0.9]
+    {
+        let c: Vector<f64> = nurbs.eval(u);
+        let dc: Vector<f64> = nurbs.derivative(u);
+        // the tangent is orthogonal to the radius
+        assert_relative_eq!(c[0] * dc[0] + c[1] * dc[1], 0.0, epsilon=1.0e-12);
+
+        let h: f64 = 1.0e-6;
+        let difference: Vector<f64> = (nurbs.eval(u + h) - nurbs.eval(u - h)) * (0.5 / h);
+        assert_relative_eq!(dc[0], difference[0], epsilon=1.0e-6);
+        assert_relative_eq!(dc[1], difference[1], epsilon=1.0e-6);
+    }
+}
+
+#[test]
+fn unit_weights()
+{
+    let knots: Vec<f64> = BSpline::clamped_knots(&[0.0, 1.0, 2.0], 3);
+    let coef: Vec<f64> = vec![0.0, 2.0, -1.0, 3.0, 1.0];
+    let spline: BSpline<f64> = BSpline::new(3, knots.clone(), coef.clone());
+    let nurbs: Nurbs<f64> = Nurbs::new(3, knots, coef.iter().map(|c| vector![*c]).collect(), vec![1.0; 5]);
+
+    assert_relative_eq!(nurbs.eval(1.3)[0], spline.eval(1.3), epsilon=1.0e-14);
+    assert_relative_eq!(nurbs.derivative(1.3)[0], spline.derivative(1.3), epsilon=1.0e-13);
+}
+
+#[test]
+#[should_panic]
+fn negative_weight()
+{
+    let _ = Nurbs::new(1, vec![0.0, 0.0, 1.0, 1.0], vec![vector![0.0], vector![1.0]], vec![1.0, -1.0]);
+}
@@ -0,0 +1,39 @@
+use mathru::analysis::interpolation::{Interpolant, Pchip};
+
+#[test]
+fn two_nodes()
+{
+    let pchip: Pchip<f64> = Pchip::new(vec![1.0, 3.0], vec![1.0, 2.0]);
+
+    assert_relative_eq!(pchip.eval(2.0), 1.5, epsilon=1.0e-15);
+    assert_relative_eq!(pchip.derivative(2.0), 0.5, epsilon=1.0e-15);
+}
+
+#[test]
+fn linear_data()
+{
+    let pchip: Pchip<f64> = Pchip::new(vec![0.0, 1.0, 1.5, 4.0], vec![1.0, 3.0, 4.0, 9.0]);
+
+    assert_relative_eq!(pchip.eval(2.5), 6.0, epsilon=1.0e-14);
+    assert_relative_eq!(pchip.derivative(0.2), 2.0, epsilon=1.0e-14);
+}
+
+#[test]
+fn monotone()
+{
+    let x: Vec<f64> = vec![0.0, 1.0, 2.0, 2.5, 4.0, 5.0];
+    let y: Vec<f64> = vec![0.0, 0.1, 0.1, 2.0, 2.1, 5.0];
+    let pchip: Pchip<f64> = Pchip::new(x, y);
+
+    let values: Vec<f64> = (0..=500).map(|i| pchip.eval(i as f64 / 100.0)).collect();
+    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn local_extremum()
+{
+    let pchip: Pchip<f64> = Pchip::new(vec![0.0, 1.0, 3.0, 4.0], vec![0.0, 1.0, 0.5, 0.0]);
+
+    assert_eq!(pchip.derivative(1.0), 0.0);
+    assert!((0..=300).all(|i| pchip.eval(i as f64 / 100.0) <= 1.0));
+}
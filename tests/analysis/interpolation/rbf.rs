@@ -0,0 +1,88 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::interpolation::{RadialBasisFunction, Rbf},
+};
+
+fn scattered_points() -> Vec<Vector<f64>>
+{
+    // Halton points in the unit square
+    (1..=60).map(|i| {
+                let radical_inverse = |mut n: usize, b: usize| -> f64 {
+                    let (mut x, mut f): (f64, f64) = (0.0, 1.0 / b as f64);
+                    while n > 0
+                    {
+                        x += f * (n % b) as f64;
+                        n /= b;
+                        f /= b as f64;
+                    }
+                    x
+                };
+                vector![radical_inverse(i, 2); radical_inverse(i, 3)]
+            })
+            .collect()
+}
+
+fn check_interpolation(kernel: RadialBasisFunction<f64>, epsilon: f64)
+{
+    let f = |p: &Vector<f64>| (2.0 * p[0]).sin() * (p[1] + 0.5).exp();
+    let points: Vec<Vector<f64>> = scattered_points();
+    let values: Vec<f64> = points.iter().map(|p| f(p)).collect();
+    let rbf: Rbf<f64> = Rbf::new(points.clone(), values.clone(), kernel);
+
+    for (p, v) in points.iter().zip(values.iter())
+    {
+        assert_relative_eq!(rbf.eval(p), *v, epsilon=1.0e-8);
+    }
+    let x: Vector<f64> = vector![0.42; 0.37];
+    assert_relative_eq!(rbf.eval(&x), f(&x), epsilon=epsilon);
+}
+
+#[test]
+fn thin_plate()
+{
+    check_interpolation(RadialBasisFunction::ThinPlate, 1.0e-2);
+}
+
+#[test]
+fn gaussian()
+{
+    check_interpolation(RadialBasisFunction::Gaussian(3.0), 1.0e-3);
+}
+
+#[test]
+fn multiquadric()
+{
+    check_interpolation(RadialBasisFunction::Multiquadric(2.0), 1.0e-3);
+}
+
+#[test]
+fn inverse_multiquadric()
+{
+    check_interpolation(RadialBasisFunction::InverseMultiquadric(2.0), 1.0e-3);
+}
+
+#[test]
+fn linear_reproduction()
+{
+    let points: Vec<Vector<f64>> = vec![vector![0.0; 0.0; 0.0],
+                                        vector![1.0; 0.0; 0.0],
+                                        vector![0.0; 1.0; 0.0],
+                                        vector![0.0; 0.0; 1.0],
+                                        vector![1.0; 1.0; 1.0]];
+    let f = |p: &Vector<f64>| 2.0 - p[0] + 3.0 * p[1] + 0.5 * p[2];
+    let values: Vec<f64> = points.iter().map(|p| f(p)).collect();
+    let rbf: Rbf<f64> = Rbf::new(points, values, RadialBasisFunction::ThinPlate);
+
+    let x: Vector<f64> = vector![0.3; -0.2; 2.0];
+    assert_relative_eq!(rbf.eval(&x), f(&x), epsilon=1.0e-12);
+}
+
+#[test]
+fn smoothing()
+{
+    let points: Vec<Vector<f64>> = scattered_points();
+    let values: Vec<f64> = points.iter().enumerate().map(|(i, p)| p[0] + if i % 2 == 0 { 0.01 } else { -0.01 }).collect();
+    let rbf: Rbf<f64> = Rbf::with_smoothing(points, values, RadialBasisFunction::ThinPlate, 10.0);
+
+    assert_relative_eq!(rbf.eval(&vector![0.5; 0.5]), 0.5, epsilon=1.0e-2);
+}
@@ -0,0 +1,69 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::interpolation::{Extrapolation, GridMethod, RegularGrid},
+};
+
+fn grid_3d(f: &dyn Fn(f64, f64, f64) -> f64, axes: &[Vec<f64>]) -> Vec<f64>
+{
+    let mut values: Vec<f64> = Vec::new();
+    for x in axes[0].iter()
+    {
+        for y in axes[1].iter()
+        {
+            for z in axes[2].iter()
+            {
+                values.push(f(*x, *y, *z));
+            }
+        }
+    }
+    values
+}
+
+#[test]
+fn trilinear()
+{
+    let f = |x: f64, y: f64, z: f64| 1.0 + x - 2.0 * y + 3.0 * z + x * y * z;
+    let axes: Vec<Vec<f64>> = vec![vec![0.0, 1.0, 2.5], vec![-1.0, 0.0, 1.0, 2.0], vec![0.0, 3.0]];
+    let grid: RegularGrid<f64> = RegularGrid::new(axes.clone(), grid_3d(&f, &axes));
+
+    assert_relative_eq!(grid.eval(&vector![1.7; 0.4; 2.2]), f(1.7, 0.4, 2.2), epsilon=1.0e-13);
+    assert_relative_eq!(grid.eval(&vector![2.5; 2.0; 3.0]), f(2.5, 2.0, 3.0), epsilon=1.0e-13);
+}
+
+#[test]
+fn one_dimensional()
+{
+    let grid: RegularGrid<f64> = RegularGrid::new(vec![vec![0.0, 1.0, 3.0]], vec![0.0, 2.0, 3.0]);
+
+    assert_relative_eq!(grid.eval(&vector![2.0]), 2.5);
+}
+
+#[test]
+fn nearest()
+{
+    let axes: Vec<Vec<f64>> = vec![vec![0.0, 1.0], vec![0.0, 1.0, 2.0]];
+    let mut grid: RegularGrid<f64> = RegularGrid::new(axes, vec![0.0, 1.0, 2.0, 10.0, 11.0, 12.0]);
+    grid.set_method(GridMethod::Nearest);
+
+    assert_eq!(grid.get_method(), GridMethod::Nearest);
+    assert_eq!(grid.eval(&vector![0.2; 1.6]), 2.0);
+    assert_eq!(grid.eval(&vector![0.7; 0.4]), 10.0);
+    assert_eq!(grid.eval(&vector![5.0; -3.0]), 10.0);
+}
+
+#[test]
+fn periodic()
+{
+    let axes: Vec<Vec<f64>> = vec![vec![0.0, 1.0], vec![0.0, 1.0]];
+    let mut grid: RegularGrid<f64> = RegularGrid::new(axes, vec![0.0, 1.0, 2.0, 3.0]);
+    grid.set_extrapolation(Extrapolation::Periodic);
+
+    assert_relative_eq!(grid.eval(&vector![1.5; -0.75]), grid.eval(&vector![0.5; 0.25]));
+}
+
+#[test]
+#[should_panic]
+fn values_mismatch()
+{
+    let _ = RegularGrid::new(vec![vec![0.0, 1.0], vec![0.0, 1.0]], vec![0.0, 1.0, 2.0]);
+}
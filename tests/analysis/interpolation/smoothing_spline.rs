@@ -0,0 +1,67 @@
+use mathru::analysis::interpolation::{Interpolant, SmoothingSpline};
+
+fn noisy_data() -> (Vec<f64>, Vec<f64>)
+{
+    let x: Vec<f64> = (0..60).map(|i| i as f64 / 59.0 * 4.0).collect();
+    let y: Vec<f64> = x.iter()
+                       .enumerate()
+                       .map(|(i, x_i)| (-x_i).exp() * (2.0 * x_i).cos() + 0.02 * ((i * 37 % 11) as f64 / 5.0 - 1.0))
+                       .collect();
+    (x, y)
+}
+
+#[test]
+fn linear_data()
+{
+    let x: Vec<f64> = vec![0.0, 0.5, 1.5, 2.0, 3.0];
+    let y: Vec<f64> = x.iter().map(|x_i| 1.0 - 2.0 * x_i).collect();
+    let spline: SmoothingSpline<f64> = SmoothingSpline::new(x, y, 10.0);
+
+    assert_relative_eq!(spline.eval(1.1), -1.2, epsilon=1.0e-12);
+    assert_relative_eq!(spline.derivative(2.5), -2.0, epsilon=1.0e-12);
+}
+
+#[test]
+fn limits()
+{
+    let (x, y): (Vec<f64>, Vec<f64>) = noisy_data();
+
+    // small smoothing parameter, close to interpolation
+    let spline: SmoothingSpline<f64> = SmoothingSpline::new(x.clone(), y.clone(), 1.0e-10);
+    assert_relative_eq!(spline.eval(x[17]), y[17], epsilon=1.0e-6);
+    assert_relative_eq!(spline.effective_degrees_of_freedom(), 60.0, epsilon=1.0e-2);
+
+    // large smoothing parameter, close to the least squares line
+    let spline: SmoothingSpline<f64> = SmoothingSpline::new(x.clone(), y.clone(), 1.0e6);
+    assert_relative_eq!(spline.effective_degrees_of_freedom(), 2.0, epsilon=1.0e-3);
+    assert_relative_eq!(spline.derivative(0.5), spline.derivative(3.5), epsilon=1.0e-4);
+}
+
+#[test]
+fn gcv()
+{
+    let (x, y): (Vec<f64>, Vec<f64>) = noisy_data();
+    let spline: SmoothingSpline<f64> = SmoothingSpline::gcv(x.clone(), y.clone());
+
+    let edf: f64 = spline.effective_degrees_of_freedom();
+    assert!(edf > 2.0 && edf < 30.0);
+    assert!(spline.lambda() > 0.0);
+
+    // the score is minimal
+    let lower: SmoothingSpline<f64> = SmoothingSpline::new(x.clone(), y.clone(), spline.lambda() * 0.5);
+    let upper: SmoothingSpline<f64> = SmoothingSpline::new(x, y, spline.lambda() * 2.0);
+    assert!(spline.gcv_score() <= lower.gcv_score());
+    assert!(spline.gcv_score() <= upper.gcv_score());
+
+    for t in [0.3, 1.1, 2.4, 3.6]
+    {
+        assert_relative_eq!(spline.eval(t), (-t as f64).exp() * (2.0 * t as f64).cos(), epsilon=2.0e-2);
+    }
+}
+
+#[test]
+#[should_panic]
+fn lambda_zero()
+{
+    let _ = SmoothingSpline::new(vec![0.0, 1.0, 2.0], vec![0.0, 1.0, 0.0], 0.0);
+}
@@ -1,3 +1,12 @@
 mod ode;
+mod dae;
+mod bvp;
+mod dde;
+mod sde;
+mod pde;
 mod newton_raphson;
 mod integral;
+mod differentiation;
+mod interpolation;
+mod root;
+mod nonlinear;
@@ -0,0 +1,73 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::{
+        nonlinear::{Anderson, NonlinearResult, Termination},
+        Function,
+    },
+};
+
+/// $G(x) = \cos(x)$
+struct Cosine;
+
+impl Function<Vector<f64>> for Cosine
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        x.clone().apply(&|x_i: &f64| x_i.cos())
+    }
+}
+
+/// Linear contraction $G(x) = Ax + b$ with the fixed point (1, 2)
+struct Contraction;
+
+impl Function<Vector<f64>> for Contraction
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![0.8 * x[0] + 0.1 * x[1]; 0.1 * x[0] + 0.85 * x[1] + 0.2]
+    }
+}
+
+#[test]
+fn find_fixed_point_cosine()
+{
+    let result: NonlinearResult<f64> = Anderson::default().find_fixed_point(&Cosine, &vector![1.0; 0.0]);
+
+    assert!(result.is_converged());
+    assert_eq!(result.jacobian_evaluations(), 0);
+    assert_relative_eq!(result.arg(), vector![0.7390851332151607; 0.7390851332151607], epsilon = 1.0e-9);
+}
+
+#[test]
+fn find_fixed_point_acceleration()
+{
+    let x_0: Vector<f64> = vector![0.0; 0.0];
+
+    let plain: NonlinearResult<f64> = Anderson::new(1000, 0, 1.0, 1.0e-10).find_fixed_point(&Contraction, &x_0);
+    let accelerated: NonlinearResult<f64> = Anderson::new(1000, 2, 1.0, 1.0e-10).find_fixed_point(&Contraction, &x_0);
+
+    assert!(plain.is_converged());
+    assert!(accelerated.is_converged());
+    assert!(accelerated.iterations() * 10 < plain.iterations());
+    assert_relative_eq!(accelerated.arg(), vector![1.0; 2.0], epsilon = 1.0e-8);
+}
+
+#[test]
+fn find_fixed_point_max_iterations()
+{
+    let result: NonlinearResult<f64> = Anderson::new(3, 0, 1.0, 1.0e-10).find_fixed_point(&Contraction, &vector![0.0; 0.0]);
+
+    assert_eq!(result.termination(), Termination::MaxIterations);
+    assert_eq!(result.evaluations(), 4);
+}
+
+#[test]
+#[should_panic]
+fn new_mixing_zero()
+{
+    Anderson::<f64>::new(10, 2, 0.0, 1.0e-10);
+}
@@ -0,0 +1,42 @@
+use super::problems::{Arctan, NoRoot, Powell, Rosenbrock};
+use mathru::{
+    algebra::linear::Vector,
+    analysis::nonlinear::{Broyden, NonlinearResult, Termination},
+};
+
+#[test]
+fn find_root_rosenbrock()
+{
+    let result: NonlinearResult<f64> = Broyden::default().find_root(&Rosenbrock, &vector![-1.2; 1.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.arg(), vector![1.0; 1.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_powell()
+{
+    let result: NonlinearResult<f64> = Broyden::default().find_root(&Powell, &vector![0.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert!(result.residual() < 1.0e-10);
+}
+
+#[test]
+fn find_root_few_jacobians()
+{
+    let result: NonlinearResult<f64> = Broyden::default().find_root(&Arctan, &vector![0.5; -0.3]);
+
+    assert!(result.is_converged());
+    assert_eq!(result.jacobian_evaluations(), 1);
+    assert_relative_eq!(result.arg(), vector![0.0; 0.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_singular_jacobian()
+{
+    let result: NonlinearResult<f64> = Broyden::default().find_root(&NoRoot, &vector![0.0; 1.0]);
+
+    assert_eq!(result.termination(), Termination::NoProgress);
+    assert_eq!(result.iterations(), 0);
+}
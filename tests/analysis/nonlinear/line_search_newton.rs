@@ -0,0 +1,70 @@
+use super::problems::{Arctan, NoRoot, Powell, Rosenbrock};
+use mathru::{
+    algebra::linear::Vector,
+    analysis::{
+        nonlinear::{LineSearchNewton, NonlinearResult, Termination},
+        NewtonRaphson,
+    },
+};
+
+#[test]
+fn find_root_rosenbrock()
+{
+    let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&Rosenbrock, &vector![-1.2; 1.0]);
+
+    assert!(result.is_converged());
+    assert!(result.residual() < 1.0e-10);
+    assert_relative_eq!(result.arg(), vector![1.0; 1.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_powell()
+{
+    let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&Powell, &vector![0.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert!(result.residual() < 1.0e-10);
+}
+
+#[test]
+fn find_root_poor_starting_value()
+{
+    let x_0: Vector<f64> = vector![10.0; -10.0];
+    assert!(NewtonRaphson::new(100, 1.0e-10).find_root(&Arctan, &x_0).is_err());
+
+    let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&Arctan, &x_0);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.arg(), vector![0.0; 0.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_stagnation()
+{
+    // the line search reduces the residual of the second equation, while the
+    // first component diverges
+    let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&Arctan, &vector![10.0; -5.0]);
+
+    assert!(!result.is_converged());
+    assert_eq!(result.termination(), Termination::NoProgress);
+}
+
+#[test]
+fn find_root_no_root()
+{
+    let result: NonlinearResult<f64> = LineSearchNewton::default().find_root(&NoRoot, &vector![2.0; 1.0]);
+
+    assert!(!result.is_converged());
+    assert_eq!(result.termination(), Termination::NoProgress);
+    assert_relative_eq!(result.residual(), 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+fn find_root_max_iterations()
+{
+    let result: NonlinearResult<f64> = LineSearchNewton::new(2, 1.0e-12, 1.0e-12).find_root(&Rosenbrock, &vector![-1.2; 1.0]);
+
+    assert_eq!(result.termination(), Termination::MaxIterations);
+    assert_eq!(result.iterations(), 2);
+    assert_eq!(result.jacobian_evaluations(), 2);
+}
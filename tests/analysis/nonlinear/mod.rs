@@ -0,0 +1,6 @@
+mod problems;
+
+mod anderson;
+mod broyden;
+mod line_search_newton;
+mod powell_hybrid;
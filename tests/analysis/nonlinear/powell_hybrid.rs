@@ -0,0 +1,55 @@
+use super::problems::{Arctan, NoRoot, Powell, Rosenbrock};
+use mathru::{
+    algebra::linear::Vector,
+    analysis::nonlinear::{NonlinearResult, PowellHybrid, Termination},
+};
+
+#[test]
+fn find_root_rosenbrock()
+{
+    let result: NonlinearResult<f64> = PowellHybrid::default().find_root(&Rosenbrock, &vector![-1.2; 1.0]);
+
+    assert!(result.is_converged());
+    assert!(result.residual() < 1.0e-10);
+    assert_relative_eq!(result.arg(), vector![1.0; 1.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_powell()
+{
+    let result: NonlinearResult<f64> = PowellHybrid::default().find_root(&Powell, &vector![0.0; 1.0]);
+
+    assert!(result.is_converged());
+    assert!(result.residual() < 1.0e-10);
+    assert!(result.jacobian_evaluations() < result.evaluations());
+}
+
+#[test]
+fn find_root_poor_starting_value()
+{
+    let mut solver: PowellHybrid<f64> = PowellHybrid::default();
+    solver.set_factor(1.0);
+
+    // line search Newton stagnates from this starting value
+    let result: NonlinearResult<f64> = solver.find_root(&Arctan, &vector![10.0; -5.0]);
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.arg(), vector![0.0; 0.0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_no_root()
+{
+    let result: NonlinearResult<f64> = PowellHybrid::default().find_root(&NoRoot, &vector![2.0; 1.0]);
+
+    assert!(!result.is_converged());
+    assert_eq!(result.termination(), Termination::NoProgress);
+    assert_relative_eq!(result.residual(), 1.0, epsilon = 1.0e-6);
+}
+
+#[test]
+#[should_panic]
+fn set_factor_negative()
+{
+    PowellHybrid::<f64>::default().set_factor(-1.0);
+}
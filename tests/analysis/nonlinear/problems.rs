@@ -0,0 +1,91 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::{Function, Jacobian},
+};
+
+/// Rosenbrock's system $F(x) = (10(x_1 - x_0^2), 1 - x_0)$ with the root
+/// (1, 1)
+pub struct Rosenbrock;
+
+impl Function<Vector<f64>> for Rosenbrock
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![10.0 * (x[1] - x[0] * x[0]); 1.0 - x[0]]
+    }
+}
+
+impl Jacobian<f64> for Rosenbrock
+{
+    fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![-20.0 * x[0], 10.0; -1.0, 0.0]
+    }
+}
+
+/// Powell's badly scaled function with the root (1.098159e-5, 9.106146)
+pub struct Powell;
+
+impl Function<Vector<f64>> for Powell
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![1.0e4 * x[0] * x[1] - 1.0; (-x[0]).exp() + (-x[1]).exp() - 1.0001]
+    }
+}
+
+impl Jacobian<f64> for Powell
+{
+    fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![1.0e4 * x[1], 1.0e4 * x[0]; -(-x[0]).exp(), -(-x[1]).exp()]
+    }
+}
+
+/// $F(x) = (\arctan(x_0), \arctan(x_1))$, the undamped Newton method
+/// diverges for $\lvert x_i \rvert > 1.3917$
+pub struct Arctan;
+
+impl Function<Vector<f64>> for Arctan
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[0].atan(); x[1].atan()]
+    }
+}
+
+impl Jacobian<f64> for Arctan
+{
+    fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![1.0 / (1.0 + x[0] * x[0]), 0.0; 0.0, 1.0 / (1.0 + x[1] * x[1])]
+    }
+}
+
+/// $F(x) = (x_0^2 + 1, x_1)$ has no root, the residual norm has a local
+/// minimum at the origin, where the Jacobian is singular
+pub struct NoRoot;
+
+impl Function<Vector<f64>> for NoRoot
+{
+    type Codomain = Vector<f64>;
+
+    fn eval(&self, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[0] * x[0] + 1.0; x[1]]
+    }
+}
+
+impl Jacobian<f64> for NoRoot
+{
+    fn jacobian(&self, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![2.0 * x[0], 0.0; 0.0, 1.0]
+    }
+}
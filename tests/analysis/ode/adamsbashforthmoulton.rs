@@ -0,0 +1,118 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::ordinary::{ExplicitODE, solver::AdamsBashforthMoulton},
+    elementary::Trigonometry,
+};
+
+use super::problem::{ExplicitODE1, ExplicitODE2, ExplicitODE3};
+
+/// Returns the number of points and the relative error at the end of the time
+/// span of $x^{'} = 2x$
+fn final_error(k: u8, tol: f64) -> (usize, f64)
+{
+    let problem: ExplicitODE1 = ExplicitODE1::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(k, 1000000, 0.001, 0.9, 0.2, 5.0, tol, tol);
+
+    let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    let t_n: f64 = *t.last().unwrap();
+    assert_relative_eq!(problem.time_span().1, t_n, epsilon=0.000000001);
+
+    let exact: f64 = problem.init_cond()[0] * (2.0 * t_n).exp();
+    (t.len(), (y.last().unwrap()[0] - exact).abs() / exact)
+}
+
+#[test]
+fn fn1_order_1()
+{
+    let problem: ExplicitODE1 = ExplicitODE1::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(1, 1000000, 0.0001, 0.9, 0.2, 5.0, 10e-10, 10e-10);
+
+    let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    let len: usize = y.len();
+    let time_span: (f64, f64) = problem.time_span();
+    let init_cond: Vector<f64> = problem.init_cond();
+
+    assert_relative_eq!(time_span.1, t[len - 1], epsilon=0.000000001);
+    assert_relative_eq!(init_cond[0] * (2.0 * time_span.1).exp(), y[len - 1][0], epsilon=0.01);
+}
+
+#[test]
+fn fn1_order_4()
+{
+    let problem: ExplicitODE1 = ExplicitODE1::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(4, 10000, 0.001, 0.9, 0.2, 5.0, 10e-12, 10e-10);
+
+    let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    let len: usize = y.len();
+    let time_span: (f64, f64) = problem.time_span();
+    let init_cond: Vector<f64> = problem.init_cond();
+
+    assert_relative_eq!(time_span.1, t[len - 1], epsilon=0.000000001);
+    assert_relative_eq!(init_cond[0] * (2.0 * time_span.1).exp(), y[len - 1][0], epsilon=0.00001);
+    assert_relative_eq!(init_cond[1] * (2.0 * time_span.1).exp(), y[len - 1][1], epsilon=0.00004);
+}
+
+#[test]
+fn fn2_order_5()
+{
+    let problem: ExplicitODE2 = ExplicitODE2::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(5, 10000, 0.001, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    let len: usize = y.len();
+    let time_span: (f64, f64) = problem.time_span();
+
+    assert_relative_eq!(time_span.1, t[len - 1], epsilon=0.000000001);
+    assert_relative_eq!(time_span.1.tan(), y[len - 1][0], epsilon=0.0001);
+}
+
+#[test]
+fn fn3_order_3()
+{
+    let problem: ExplicitODE3 = ExplicitODE3::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(3, 10000, 0.001, 0.9, 0.2, 5.0, 10e-12, 10e-10);
+
+    let (t, y): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+
+    let len: usize = y.len();
+    let time_span: (f64, f64) = problem.time_span();
+
+    assert_relative_eq!(time_span.1, t[len - 1], epsilon=0.000000001);
+    assert_relative_eq!(1.0 / (2.0 - time_span.1), y[len - 1][0], epsilon=0.0001);
+}
+
+#[test]
+fn error_decreases_with_tolerance()
+{
+    for k in 1..=5
+    {
+        let (_, error_coarse): (usize, f64) = final_error(k, 10e-7);
+        let (_, error_medium): (usize, f64) = final_error(k, 10e-9);
+        let (_, error_fine): (usize, f64) = final_error(k, 10e-11);
+
+        assert!(error_medium < error_coarse);
+        assert!(error_fine < error_medium);
+    }
+}
+
+#[test]
+fn fewer_evaluations_than_fixed_steps()
+{
+    let (n, error): (usize, f64) = final_error(5, 10e-9);
+
+    assert!(n < 100);
+    assert!(error < 0.000001);
+}
+
+#[test]
+fn max_steps_reached()
+{
+    let problem: ExplicitODE1 = ExplicitODE1::default();
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(2, 10, 0.001, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+
+    assert!(solver.solve(&problem).is_err());
+}
@@ -0,0 +1,202 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::ordinary::{
+        solver::{
+            runge_kutta::{
+                AdaptiveIntegrator, AdaptiveStepper, DormandPrince54, FixedStepIntegrator, FixedStepper, ImplicitEuler,
+                ProportionalControl, RungeKutta4,
+            },
+            symplectic::{StormerVerlet, SymplecticStepper},
+            AdamsBashforth, AdamsBashforthMoulton, Status, BDF,
+        },
+        Resume,
+    },
+};
+
+use super::problem::{Gaussian, HarmonicOscillator};
+
+#[test]
+fn fixed_stepper_backward()
+{
+    let problem: Gaussian = Gaussian::new((2.0, 0.0), 0.5);
+    let solver: FixedStepper<f64> = FixedStepper::new(0.01);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &RungeKutta4::default()).unwrap();
+
+    assert_relative_eq!(2.0, t[0]);
+    assert_relative_eq!(1.99, t[1], epsilon = 1.0e-12);
+    assert_relative_eq!(0.0, *t.last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!(problem.solution(0.0), x.last().unwrap()[0], epsilon = 1.0e-8);
+}
+
+#[test]
+fn proportional_control_backward()
+{
+    let problem: Gaussian = Gaussian::new((1.5, -0.5), 1.0);
+    let solver: ProportionalControl<f64> = ProportionalControl::new(1000, 0.01, 0.9, 0.01, 2.0, 10e-10, 10e-8);
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &DormandPrince54::default()).unwrap();
+
+    assert!(t.windows(2).all(|w| w[1] < w[0]));
+    assert_relative_eq!(-0.5, *t.last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!(problem.solution(-0.5), x.last().unwrap()[0], epsilon = 1.0e-6);
+}
+
+#[test]
+fn adaptive_stepper_backward()
+{
+    let problem: Gaussian = Gaussian::new((2.0, 0.0), 0.5);
+    let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-10, 10e-8);
+
+    let solution = solver.solve(&problem, &DormandPrince54::default());
+
+    assert_eq!(Status::Success, solution.status());
+    assert_relative_eq!(0.0, *solution.t().last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!(problem.solution(0.0), solution.x().last().unwrap()[0], epsilon = 1.0e-6);
+}
+
+#[test]
+fn implicit_euler_backward()
+{
+    let problem: Gaussian = Gaussian::new((1.0, 0.0), 1.0);
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.0001);
+
+    let solution = solver.solve_with_statistics(&problem).unwrap();
+
+    assert_relative_eq!(0.0, *solution.t().last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!(problem.solution(0.0), solution.x().last().unwrap()[0], epsilon = 1.0e-4);
+}
+
+#[test]
+fn multistep_backward()
+{
+    let problem: Gaussian = Gaussian::new((1.0, 0.0), 1.0);
+    let expected: f64 = problem.solution(0.0);
+
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = AdamsBashforth::new(3, 0.001).solve(&problem).unwrap();
+    assert_relative_eq!(expected, x.last().unwrap()[0], epsilon = 1.0e-5);
+
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = BDF::new(3, 0.001).solve(&problem).unwrap();
+    assert_relative_eq!(expected, x.last().unwrap()[0], epsilon = 1.0e-5);
+
+    let solver: AdamsBashforthMoulton<f64> = AdamsBashforthMoulton::new(4, 10000, 0.001, 0.9, 0.2, 5.0, 10e-10, 10e-8);
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem).unwrap();
+    assert_relative_eq!(0.0, *t.last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!(expected, x.last().unwrap()[0], epsilon = 1.0e-5);
+}
+
+#[test]
+fn symplectic_backward()
+{
+    let problem: HarmonicOscillator = HarmonicOscillator::new((0.0, -3.0));
+    let solver: SymplecticStepper<f64> = SymplecticStepper::new(0.001);
+
+    let (t, q, p): (Vec<f64>, Vec<Vector<f64>>, Vec<Vector<f64>>) =
+        solver.solve(&problem, &StormerVerlet::default()).unwrap();
+
+    assert_relative_eq!(-3.0, *t.last().unwrap(), epsilon = 1.0e-12);
+    assert_relative_eq!((-3.0f64).cos(), q.last().unwrap()[0], epsilon = 1.0e-5);
+    assert_relative_eq!(-(-3.0f64).sin(), p.last().unwrap()[0], epsilon = 1.0e-5);
+}
+
+#[test]
+fn resume_forward_and_back()
+{
+    let problem: Gaussian = Gaussian::new((0.0, 1.0), 1.0);
+    let solver: FixedStepper<f64> = FixedStepper::new(0.001);
+    let method: RungeKutta4<f64> = RungeKutta4::default();
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &method).unwrap();
+
+    // continue to t = 2 and compare with the integration in one piece
+    let forward: Resume<f64, Gaussian> = Resume::from_solution(&problem, &t, &x, 2.0);
+    let (t_2, x_2): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&forward, &method).unwrap();
+    let (_t, x_whole): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Gaussian::new((0.0, 2.0), 1.0), &method).unwrap();
+
+    assert_relative_eq!(1.0, t_2[0]);
+    assert_relative_eq!(x_whole.last().unwrap()[0], x_2.last().unwrap()[0], epsilon = 1.0e-10);
+
+    // and back to the initial time
+    let backward: Resume<f64, Gaussian> = Resume::from_solution(&problem, &t_2, &x_2, 0.0);
+    let (_t, x_0): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&backward, &method).unwrap();
+
+    assert_relative_eq!(1.0, x_0.last().unwrap()[0], epsilon = 1.0e-10);
+}
+
+#[test]
+fn fixed_step_integrator()
+{
+    let problem: Gaussian = Gaussian::new((0.0, 1.0), 1.0);
+    let solver: FixedStepper<f64> = FixedStepper::new(0.01);
+    let method: RungeKutta4<f64> = RungeKutta4::default();
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &method).unwrap();
+
+    // the iterator reproduces the solution of solve
+    let integrator: FixedStepIntegrator<f64, Gaussian, RungeKutta4<f64>> = solver.integrator(&problem, &method);
+    let steps: Vec<(f64, Vector<f64>)> = integrator.collect();
+
+    assert_eq!(t.len() - 1, steps.len());
+    for ((t_i, x_i), (t_s, x_s)) in t.iter().zip(x.iter()).skip(1).zip(steps.iter())
+    {
+        assert_relative_eq!(*t_i, *t_s, epsilon = 1.0e-12);
+        assert_relative_eq!(x_i[0], x_s[0], epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+fn fixed_step_integrator_macro_steps()
+{
+    let problem: Gaussian = Gaussian::new((0.0, -1.0), 1.0);
+    let solver: FixedStepper<f64> = FixedStepper::new(0.03);
+    let method: RungeKutta4<f64> = RungeKutta4::default();
+    let mut integrator: FixedStepIntegrator<f64, Gaussian, RungeKutta4<f64>> = solver.integrator(&problem, &method);
+
+    for i in 1..=10
+    {
+        let t_i: f64 = -0.1 * i as f64;
+        let x_i: f64 = integrator.advance_to(t_i)[0];
+
+        assert_relative_eq!(t_i, integrator.time(), epsilon = 1.0e-12);
+        assert_relative_eq!(problem.solution(t_i), x_i, epsilon = 1.0e-7);
+    }
+    assert!(integrator.is_finished());
+    assert!(integrator.step().is_none());
+}
+
+#[test]
+fn adaptive_integrator_set_state()
+{
+    let problem: Gaussian = Gaussian::new((0.0, 2.0), 1.0);
+    let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-10, 10e-8);
+    let method: DormandPrince54<f64> = DormandPrince54::default();
+    let mut integrator: AdaptiveIntegrator<f64, Gaussian, DormandPrince54<f64>> = solver.integrator(&problem, &method);
+
+    let x_1: f64 = integrator.advance_to(1.0).unwrap()[0];
+    assert_relative_eq!(problem.solution(1.0), x_1, epsilon = 1.0e-7);
+
+    // an external model doubles the state at t = 1
+    integrator.set_state(vector![2.0 * x_1]);
+    let x_2: f64 = integrator.advance_to(2.0).unwrap()[0];
+
+    assert_relative_eq!(2.0 * problem.solution(2.0), x_2, epsilon = 1.0e-7);
+    assert_eq!(None, integrator.status());
+    assert!(integrator.step().is_none());
+    assert_eq!(Some(Status::Success), integrator.status());
+}
+
+#[test]
+fn adaptive_integrator_matches_solve()
+{
+    let problem: Gaussian = Gaussian::new((1.0, -1.0), 1.0);
+    let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-10, 10e-8);
+    let method: DormandPrince54<f64> = DormandPrince54::default();
+
+    let solution = solver.solve(&problem, &method);
+    let mut integrator: AdaptiveIntegrator<f64, Gaussian, DormandPrince54<f64>> = solver.integrator(&problem, &method);
+    let steps: Vec<(f64, Vector<f64>)> = (&mut integrator).collect();
+
+    assert_eq!(solution.t().len() - 1, steps.len());
+    assert_relative_eq!(solution.t()[1], steps[0].0);
+    assert_eq!(solution.statistics(), &integrator.statistics());
+}
@@ -0,0 +1,268 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::ordinary::{
+        problem::Euler,
+        solver::{
+            runge_kutta::{
+                AdaptiveStepper, BogackiShampine32, DormandPrince54, ImplicitEuler, ImplicitFixedStepIntegrator,
+                StepSizeController,
+            },
+            Solution, Status,
+        },
+        ExplicitODE, ImplicitODE,
+    },
+};
+
+use super::problem::{ExplicitODE1, ExplicitODE2};
+
+/// $x^{'} = x^2, x(0) = 1$ with the solution $x(t) = \frac{1}{1 - t}$, which
+/// blows up at t = 1
+struct BlowUp;
+
+impl ExplicitODE<f64> for BlowUp
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![x[0] * x[0]]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 2.0)
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+}
+
+fn solve(controller: StepSizeController<f64>) -> Solution<f64>
+{
+    let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.1, 10e-10, 10e-8);
+    solver.set_controller(controller);
+
+    solver.solve(&ExplicitODE2::default(), &DormandPrince54::default())
+}
+
+#[test]
+fn controllers()
+{
+    for controller in [StepSizeController::Proportional,
+                       StepSizeController::pi(),
+                       StepSizeController::pid()]
+    {
+        let solution: Solution<f64> = solve(controller);
+
+        assert!(solution.is_success());
+        assert_relative_eq!(1.4, *solution.t().last().unwrap(), epsilon=10e-12);
+        assert_relative_eq!(1.4f64.tan(), solution.x().last().unwrap()[0], epsilon=10e-6);
+    }
+}
+
+#[test]
+fn statistics()
+{
+    let solution: Solution<f64> = solve(StepSizeController::pi());
+    let statistics = solution.statistics();
+
+    assert_eq!(solution.t().len() - 1, statistics.accepted_steps());
+    assert_eq!(7 * (statistics.accepted_steps() + statistics.rejected_steps()),
+               statistics.rhs_evaluations());
+    assert_eq!(0, statistics.jacobian_evaluations());
+    assert_eq!(0, statistics.lu_decompositions());
+}
+
+#[test]
+fn controller_factor()
+{
+    let proportional: StepSizeController<f64> = StepSizeController::Proportional;
+    assert_relative_eq!(2.0, proportional.factor((1.0 / 32.0, 1.0, 1.0), 5.0), epsilon=10e-12);
+    assert_relative_eq!(2.0, proportional.factor((1.0 / 32.0, 7.0, 0.1), 5.0), epsilon=10e-12);
+
+    let pi: StepSizeController<f64> = StepSizeController::PI { beta_1: 0.5, beta_2: -0.5 };
+    assert_relative_eq!(0.5, pi.factor((1.0 / 4.0, 1.0 / 16.0, 3.0), 1.0), epsilon=10e-12);
+}
+
+#[test]
+fn max_steps_reached()
+{
+    let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(5, 0.01, 10e-8, 10e-6);
+    let solution: Solution<f64> = solver.solve(&ExplicitODE1::default(), &DormandPrince54::default());
+
+    assert_eq!(Status::MaxStepsReached, solution.status());
+    assert_eq!(6, solution.t().len());
+    assert!(*solution.t().last().unwrap() < 2.0);
+}
+
+#[test]
+fn step_size_too_small()
+{
+    let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::new(10000, 0.01, 10e-8, 10e-6);
+    solver.set_step_size_limits(10e-5, 1.0);
+    let solution: Solution<f64> = solver.solve(&BlowUp, &BogackiShampine32::default());
+
+    assert_eq!(Status::StepSizeTooSmall, solution.status());
+    let t_end: f64 = *solution.t().last().unwrap();
+    assert!(t_end < 1.0 && t_end > 0.99);
+
+    // The solution up to the last accepted step is kept
+    for (t_i, x_i) in solution.t().iter().zip(solution.x().iter()).filter(|(t_i, _x_i)| **t_i < 0.9)
+    {
+        assert_relative_eq!(1.0 / (1.0 - t_i), x_i[0], max_relative=10e-4);
+    }
+}
+
+#[test]
+fn max_step_size()
+{
+    let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.01, 10e-6, 10e-4);
+    solver.set_step_size_limits(0.0, 0.05);
+    let solution: Solution<f64> = solver.solve(&ExplicitODE2::default(), &DormandPrince54::default());
+
+    assert!(solution.is_success());
+    assert!(solution.t().windows(2).all(|t| t[1] - t[0] <= 0.05 + 10e-12));
+}
+
+#[test]
+fn abs_tol_components()
+{
+    let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::new(1000, 0.1, 10e-3, 0.0);
+    let loose: Solution<f64> = solver.solve(&ExplicitODE1::default(), &DormandPrince54::default());
+
+    solver.set_abs_tol_components(vector![10e-3; 10e-9]);
+    let tight: Solution<f64> = solver.solve(&ExplicitODE1::default(), &DormandPrince54::default());
+
+    assert!(tight.statistics().accepted_steps() > loose.statistics().accepted_steps());
+    let exact: f64 = 2.0 * 4.0f64.exp();
+    assert_relative_eq!(exact, tight.x().last().unwrap()[1], epsilon=10e-6);
+}
+
+#[test]
+#[should_panic]
+fn abs_tol_components_dimension()
+{
+    let mut solver: AdaptiveStepper<f64> = AdaptiveStepper::default();
+    solver.set_abs_tol_components(vector![10e-3; 10e-9; 10e-9]);
+    let _ = solver.solve(&ExplicitODE1::default(), &DormandPrince54::default());
+}
+
+#[test]
+fn implicit_euler_statistics()
+{
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.1);
+    let solution: Solution<f64> = solver.solve_with_statistics(&Euler::default()).unwrap();
+    let statistics = solution.statistics();
+
+    assert!(solution.is_success());
+    assert_eq!(solution.t().len() - 1, statistics.accepted_steps());
+    assert!(statistics.jacobian_evaluations() >= statistics.accepted_steps());
+    assert_eq!(statistics.jacobian_evaluations(), statistics.lu_decompositions());
+    assert_eq!(statistics.jacobian_evaluations(), statistics.rhs_evaluations());
+
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&Euler::default()).unwrap();
+    assert_relative_eq!(*x.last().unwrap(), *solution.x().last().unwrap());
+}
+
+/// $x^{'} = -x, x(0) = 1$ on $\lbrack 0, 1 \rbrack$
+struct Decay;
+
+impl ImplicitODE<f64> for Decay
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        -x.clone()
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![-1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+}
+
+#[test]
+fn implicit_euler_statistics_span()
+{
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.1);
+    let solution: Solution<f64> = solver.solve_with_statistics(&Decay).unwrap();
+    let statistics = solution.statistics();
+
+    assert_eq!(Status::Success, solution.status());
+    assert_eq!(11, solution.t().len());
+    assert_eq!(1.0, *solution.t().last().unwrap());
+    assert_eq!(10, statistics.accepted_steps());
+    // the Newton iteration of a linear problem converges in the second
+    // iteration
+    assert_eq!(20, statistics.rhs_evaluations());
+    assert_eq!(20, statistics.jacobian_evaluations());
+    assert_relative_eq!(1.0 / 1.1f64.powi(10), solution.x().last().unwrap()[0], epsilon = 1.0e-14);
+}
+
+/// $x^{'} = f(t, x)$, whose right-hand side is not defined
+struct Undefined;
+
+impl ImplicitODE<f64> for Undefined
+{
+    fn func(&self, _t: &f64, _x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![f64::NAN]
+    }
+
+    fn jacobian(&self, _t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![1.0]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+}
+
+#[test]
+fn implicit_euler_statistics_failure()
+{
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.1);
+
+    assert!(solver.solve_with_statistics(&Undefined).is_err());
+    assert!(solver.solve(&Undefined).is_err());
+}
+
+#[test]
+fn implicit_euler_integrator()
+{
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.1);
+    let mut integrator: ImplicitFixedStepIntegrator<f64, Decay, ImplicitEuler<f64>> = solver.integrator(&Decay);
+
+    assert_relative_eq!(1.0 / 1.1f64.powi(5), integrator.advance_to(0.5).unwrap()[0], epsilon = 1.0e-14);
+    assert_eq!(5, integrator.count());
+}
+
+#[test]
+fn implicit_euler_integrator_failure()
+{
+    let solver: ImplicitEuler<f64> = ImplicitEuler::new(0.1);
+    let mut integrator: ImplicitFixedStepIntegrator<f64, Undefined, ImplicitEuler<f64>> = solver.integrator(&Undefined);
+
+    assert!(integrator.step().is_none());
+    assert_eq!(Some(Status::ConvergenceFailure), integrator.status());
+    assert_eq!(0.0, integrator.time());
+
+    let mut integrator: ImplicitFixedStepIntegrator<f64, Undefined, ImplicitEuler<f64>> = solver.integrator(&Undefined);
+    assert_eq!(Err(Status::ConvergenceFailure), integrator.advance_to(0.5).map(|x| x.clone()));
+}
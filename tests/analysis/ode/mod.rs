@@ -1,9 +1,11 @@
 
 mod bdf;
 mod adamsbashforth;
+mod adamsbashforthmoulton;
 mod problem;
 
 mod adaptive_stepper;
+mod controlled_stepper;
 mod bogackishampine32;
 mod cashkarp54;
 mod dormandprince54;
@@ -24,3 +26,10 @@ mod rungekutta4;
 mod ralston4;
 
 mod implicit_euler;
+
+mod symplectic;
+
+mod sensitivity;
+
+mod backward;
+mod reference_problem;
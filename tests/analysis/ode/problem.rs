@@ -1,5 +1,8 @@
 //! Often used ODEs
-use mathru::{algebra::linear::Vector, analysis::differential_equation::ordinary::ExplicitODE};
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::ordinary::{ExplicitODE, ImplicitODE, ParameterisedODE, SeparableHamiltonian},
+};
 use std::{default::Default, f64};
 
 /// Define ODE
@@ -113,3 +116,261 @@ impl ExplicitODE<f64> for ExplicitODE3
         return self.init_cond.clone();
     }
 }
+
+/// Harmonic oscillator
+/// $H(q, p) = \frac{1}{2}p^2 + \frac{1}{2}q^2$
+/// $q(t) = cos(t), p(t) = -sin(t)$
+pub struct HarmonicOscillator
+{
+    time_span: (f64, f64),
+}
+
+impl Default for HarmonicOscillator
+{
+    fn default() -> HarmonicOscillator
+    {
+        HarmonicOscillator { time_span: (0.0, 10.0) }
+    }
+}
+
+impl HarmonicOscillator
+{
+    pub fn new(time_span: (f64, f64)) -> HarmonicOscillator
+    {
+        HarmonicOscillator { time_span }
+    }
+}
+
+impl SeparableHamiltonian<f64> for HarmonicOscillator
+{
+    fn dh_dq(&self, q: &Vector<f64>) -> Vector<f64>
+    {
+        return q.clone();
+    }
+
+    fn dh_dp(&self, p: &Vector<f64>) -> Vector<f64>
+    {
+        return p.clone();
+    }
+
+    fn hamiltonian(&self, q: &Vector<f64>, p: &Vector<f64>) -> f64
+    {
+        return 0.5 * (p.dotp(p) + q.dotp(q));
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self) -> (Vector<f64>, Vector<f64>)
+    {
+        return (vector![1.0], vector![0.0]);
+    }
+}
+
+/// Kepler problem with eccentricity e = 0.5
+/// $H(q, p) = \frac{1}{2}(p_1^2 + p_2^2) - \frac{1}{\sqrt{q_1^2 + q_2^2}}$
+/// The orbit is periodic with period $2\pi$
+pub struct Kepler
+{
+    time_span: (f64, f64),
+}
+
+impl Default for Kepler
+{
+    fn default() -> Kepler
+    {
+        Kepler { time_span: (0.0, 2.0 * std::f64::consts::PI) }
+    }
+}
+
+impl SeparableHamiltonian<f64> for Kepler
+{
+    fn dh_dq(&self, q: &Vector<f64>) -> Vector<f64>
+    {
+        let r: f64 = q.dotp(q).sqrt();
+        return q * &(1.0 / (r * r * r));
+    }
+
+    fn dh_dp(&self, p: &Vector<f64>) -> Vector<f64>
+    {
+        return p.clone();
+    }
+
+    fn hamiltonian(&self, q: &Vector<f64>, p: &Vector<f64>) -> f64
+    {
+        return 0.5 * p.dotp(p) - 1.0 / q.dotp(q).sqrt();
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self) -> (Vector<f64>, Vector<f64>)
+    {
+        let e: f64 = 0.5;
+        return (vector![1.0 - e; 0.0], vector![0.0; ((1.0 + e) / (1.0 - e)).sqrt()]);
+    }
+}
+
+/// Exponential decay with the parameters $p = (a, b)$
+/// $x^{'} = -a x, x(0) = b$
+/// $x(t) = b e^{-a t}$
+pub struct Decay
+{
+    time_span: (f64, f64),
+}
+
+impl Default for Decay
+{
+    fn default() -> Decay
+    {
+        Decay { time_span: (0.0, 1.0) }
+    }
+}
+
+impl ParameterisedODE<f64> for Decay
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>, p: &Vector<f64>) -> Vector<f64>
+    {
+        return x * &-p[0];
+    }
+
+    fn jacobian_x(&self, _t: &f64, _x: &Vector<f64>, p: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![-p[0]];
+    }
+
+    fn jacobian_p(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![-x[0], 0.0];
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self, p: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![p[1]];
+    }
+
+    fn init_cond_jacobian(&self, _p: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![0.0, 1.0];
+    }
+}
+
+/// Lotka-Volterra equations with the parameters $p = (a, b, c, d)$
+/// $x_1^{'} = a x_1 - b x_1 x_2$
+/// $x_2^{'} = c x_1 x_2 - d x_2$
+pub struct LotkaVolterra
+{
+    time_span: (f64, f64),
+}
+
+impl Default for LotkaVolterra
+{
+    fn default() -> LotkaVolterra
+    {
+        LotkaVolterra { time_span: (0.0, 2.0) }
+    }
+}
+
+impl ParameterisedODE<f64> for LotkaVolterra
+{
+    fn func(&self, _t: &f64, x: &Vector<f64>, p: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![p[0] * x[0] - p[1] * x[0] * x[1];
+                       p[2] * x[0] * x[1] - p[3] * x[1]];
+    }
+
+    fn jacobian_x(&self, _t: &f64, x: &Vector<f64>, p: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![p[0] - p[1] * x[1], -p[1] * x[0];
+                       p[2] * x[1], p[2] * x[0] - p[3]];
+    }
+
+    fn jacobian_p(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> Matrix<f64>
+    {
+        return matrix![x[0], -x[0] * x[1], 0.0, 0.0;
+                       0.0, 0.0, x[0] * x[1], -x[1]];
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        return self.time_span;
+    }
+
+    fn init_cond(&self, _p: &Vector<f64>) -> Vector<f64>
+    {
+        return vector![1.0; 0.5];
+    }
+}
+
+/// Time dependent linear ODE
+/// $x^{'}(t) = -t x(t)$
+/// $x(t) = x(t_0) e^{-\frac{1}{2}(t^2 - t_0^2)}$
+pub struct Gaussian
+{
+    time_span: (f64, f64),
+    init_cond: f64,
+}
+
+impl Gaussian
+{
+    pub fn new(time_span: (f64, f64), init_cond: f64) -> Gaussian
+    {
+        Gaussian { time_span, init_cond }
+    }
+
+    pub fn solution(&self, t: f64) -> f64
+    {
+        let t_0: f64 = self.time_span.0;
+        self.init_cond * (-0.5 * (t * t - t_0 * t_0)).exp()
+    }
+}
+
+impl ExplicitODE<f64> for Gaussian
+{
+    fn func(&self, t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        x * &-*t
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![self.init_cond]
+    }
+}
+
+impl ImplicitODE<f64> for Gaussian
+{
+    fn func(&self, t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        x * &-*t
+    }
+
+    fn jacobian(&self, t: &f64, _x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![-*t]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        self.time_span
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![self.init_cond]
+    }
+}
@@ -0,0 +1,156 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::ordinary::{
+        problem::{Arenstorf, Brusselator, Hires, Lorenz, Oregonator, Pleiades, Robertson},
+        solver::runge_kutta::{AdaptiveStepper, DormandPrince54, ImplicitEuler},
+        ClosureODE, ExplicitODE, ImplicitODE, ODEBuilder,
+    },
+};
+
+fn max_error(x: &Vector<f64>, y: &Vector<f64>) -> f64
+{
+    let (m, _n): (usize, usize) = x.dim();
+    (0..m).fold(0.0, |err, i| err.max((x[i] - y[i]).abs()))
+}
+
+fn solve_explicit<P>(problem: &P, tol: f64) -> Vector<f64>
+    where P: ExplicitODE<f64>
+{
+    let solver: AdaptiveStepper<f64> = AdaptiveStepper::new(100000, 0.0001, tol, tol);
+    let solution = solver.solve(problem, &DormandPrince54::default());
+    assert!(solution.is_success());
+
+    solution.x().last().unwrap().clone()
+}
+
+/// Compares the Jacobian with the forward difference approximation of the
+/// closure ODE
+fn assert_jacobian<P>(problem: &P, t: f64, epsilon: f64)
+    where P: ImplicitODE<f64>
+{
+    let x: Vector<f64> = ImplicitODE::init_cond(problem);
+    let (m, _n): (usize, usize) = x.dim();
+    let x: Vector<f64> = Vector::new_column((0..m).map(|i| x[i] + 0.1 * (i as f64 + 1.0).sin() + 0.05).collect());
+
+    let approx: ClosureODE<f64> = ODEBuilder::new(|t: &f64, x: &Vector<f64>| ImplicitODE::func(problem, t, x))
+        .time_span(0.0, 1.0)
+        .init_cond(x.clone())
+        .build();
+
+    let jacobian: Matrix<f64> = problem.jacobian(&t, &x);
+    let jacobian_fd: Matrix<f64> = approx.jacobian(&t, &x);
+
+    // the rounding error of the approximation grows with the magnitude of the entries
+    let scale: f64 = 1.0 + (0..m).fold(0.0f64, |s, i| (0..m).fold(s, |s, j| s.max(jacobian[[i, j]].abs())));
+    for i in 0..m
+    {
+        for j in 0..m
+        {
+            assert_relative_eq!(jacobian[[i, j]] / scale, jacobian_fd[[i, j]] / scale, epsilon = epsilon);
+        }
+    }
+}
+
+#[test]
+fn jacobians()
+{
+    assert_jacobian(&Robertson::default(), 0.0, 1.0e-6);
+    assert_jacobian(&Hires::default(), 0.0, 1.0e-6);
+    assert_jacobian(&Brusselator::default(), 0.0, 1.0e-6);
+    assert_jacobian(&Lorenz::default(), 0.0, 1.0e-6);
+    assert_jacobian(&Arenstorf::default(), 0.0, 1.0e-5);
+    assert_jacobian(&Oregonator::default(), 0.0, 1.0e-6);
+    assert_jacobian(&Pleiades::default(), 0.0, 1.0e-5);
+}
+
+#[test]
+fn arenstorf()
+{
+    let problem: Arenstorf<f64> = Arenstorf::default();
+    let x: Vector<f64> = solve_explicit(&problem, 1.0e-12);
+
+    assert!(max_error(&problem.reference_solution(), &x) < 1.0e-6);
+}
+
+#[test]
+fn brusselator()
+{
+    let problem: Brusselator<f64> = Brusselator::default();
+    let x: Vector<f64> = solve_explicit(&problem, 1.0e-10);
+
+    assert!(max_error(&problem.reference_solution(), &x) < 1.0e-7);
+}
+
+#[test]
+fn lorenz()
+{
+    let problem: Lorenz<f64> = Lorenz::default();
+    let x: Vector<f64> = solve_explicit(&problem, 1.0e-12);
+
+    assert!(max_error(&problem.reference_solution(), &x) < 1.0e-5);
+}
+
+#[test]
+fn pleiades()
+{
+    let problem: Pleiades<f64> = Pleiades::default();
+    let x: Vector<f64> = solve_explicit(&problem, 1.0e-10);
+
+    assert!(max_error(&problem.reference_solution(), &x) < 1.0e-6);
+}
+
+#[test]
+fn robertson()
+{
+    let problem: Robertson<f64> = Robertson::default();
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.01).solve(&problem).unwrap();
+    let x_n: &Vector<f64> = x.last().unwrap();
+    let reference: Vector<f64> = problem.reference_solution();
+
+    assert_relative_eq!(reference[0], x_n[0], epsilon = 1.0e-3);
+    assert_relative_eq!(reference[1], x_n[1], epsilon = 1.0e-7);
+    assert_relative_eq!(reference[2], x_n[2], epsilon = 1.0e-3);
+}
+
+#[test]
+fn hires()
+{
+    let problem: Hires<f64> = Hires::default();
+    let (_t, x): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.05).solve(&problem).unwrap();
+
+    assert!(max_error(&problem.reference_solution(), x.last().unwrap()) < 1.0e-4);
+}
+
+#[test]
+fn builder_numerical_jacobian()
+{
+    let problem: ClosureODE<f64> = ODEBuilder::new(|t: &f64, x: &Vector<f64>| vector![x[1]; -x[0] * t.cos()])
+        .time_span(0.0, 2.0)
+        .init_cond(vector![1.0; 0.0])
+        .build();
+    assert!(!problem.has_jacobian());
+
+    let jacobian: Matrix<f64> = problem.jacobian(&1.0, &vector![1.0; 2.0]);
+    assert_relative_eq!(0.0, jacobian[[0, 0]], epsilon = 1.0e-7);
+    assert_relative_eq!(1.0, jacobian[[0, 1]], epsilon = 1.0e-7);
+    assert_relative_eq!(-1.0f64.cos(), jacobian[[1, 0]], epsilon = 1.0e-7);
+    assert_relative_eq!(0.0, jacobian[[1, 1]], epsilon = 1.0e-7);
+
+    // the closure ODE reproduces the solution of the dedicated struct
+    let brusselator: Brusselator<f64> = Brusselator::default();
+    let closure: ClosureODE<f64> = ODEBuilder::new(|t: &f64, x: &Vector<f64>| ExplicitODE::func(&brusselator, t, x))
+        .jacobian(|t: &f64, x: &Vector<f64>| brusselator.jacobian(t, x))
+        .time_span(0.0, 20.0)
+        .init_cond(vector![1.5; 3.0])
+        .build();
+    assert!(closure.has_jacobian());
+
+    assert_relative_eq!(solve_explicit(&brusselator, 1.0e-8), solve_explicit(&closure, 1.0e-8));
+}
+
+#[test]
+#[should_panic]
+fn builder_without_init_cond()
+{
+    let _problem: ClosureODE<f64> = ODEBuilder::new(|_t: &f64, x: &Vector<f64>| x.clone()).time_span(0.0, 1.0).build();
+}
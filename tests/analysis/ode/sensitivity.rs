@@ -0,0 +1,249 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::ordinary::{
+        sensitivity::{AdjointSensitivity, ForwardSensitivity, Objective},
+        solver::{
+            runge_kutta::{FixedStepper, ImplicitEuler, RungeKutta4},
+            BDF,
+        },
+        ParameterisedODE,
+    },
+    optimization::{LevenbergMarquardt, Optim},
+};
+
+use super::problem::{Decay, LotkaVolterra};
+
+/// Solution at the end of the time span
+fn solve<P>(problem: &P, p: &Vector<f64>) -> (Vector<f64>, Matrix<f64>)
+    where P: ParameterisedODE<f64>
+{
+    let sensitivity: ForwardSensitivity<f64, P> = ForwardSensitivity::new(problem, p.clone());
+    let (_t, y): (Vec<f64>, Vec<Vector<f64>>) =
+        FixedStepper::new(0.001).solve(&sensitivity, &RungeKutta4::default()).unwrap();
+
+    sensitivity.split(y.last().unwrap())
+}
+
+#[test]
+fn forward_decay()
+{
+    let problem: Decay = Decay::default();
+    let (x, s): (Vector<f64>, Matrix<f64>) = solve(&problem, &vector![1.5; 2.0]);
+
+    assert_relative_eq!(2.0 * (-1.5f64).exp(), x[0], epsilon=10e-10);
+    assert_relative_eq!(-2.0 * (-1.5f64).exp(), s[[0, 0]], epsilon=10e-10);
+    assert_relative_eq!((-1.5f64).exp(), s[[0, 1]], epsilon=10e-10);
+}
+
+#[test]
+fn forward_lotka_volterra()
+{
+    let problem: LotkaVolterra = LotkaVolterra::default();
+    let p: Vector<f64> = vector![1.5; 1.0; 1.0; 3.0];
+    let (_x, s): (Vector<f64>, Matrix<f64>) = solve(&problem, &p);
+
+    let h: f64 = 10e-6;
+    for j in 0..4
+    {
+        let mut p_plus: Vector<f64> = p.clone();
+        p_plus[j] += h;
+        let mut p_minus: Vector<f64> = p.clone();
+        p_minus[j] -= h;
+
+        let (x_plus, _): (Vector<f64>, Matrix<f64>) = solve(&problem, &p_plus);
+        let (x_minus, _): (Vector<f64>, Matrix<f64>) = solve(&problem, &p_minus);
+
+        assert_relative_eq!((x_plus - x_minus) / (2.0 * h), s.get_column(j), epsilon=10e-7);
+    }
+}
+
+#[test]
+fn forward_implicit()
+{
+    let problem: Decay = Decay::default();
+    let sensitivity: ForwardSensitivity<f64, Decay> = ForwardSensitivity::new(&problem, vector![1.5; 2.0]);
+
+    let (_t, y): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.0001).solve(&sensitivity).unwrap();
+    let (_x, s): (Vector<f64>, Matrix<f64>) = sensitivity.split(y.last().unwrap());
+    assert_relative_eq!(-2.0 * (-1.5f64).exp(), s[[0, 0]], epsilon=0.001);
+    assert_relative_eq!((-1.5f64).exp(), s[[0, 1]], epsilon=0.001);
+
+    let (_t, y): (Vec<f64>, Vec<Vector<f64>>) = BDF::new(4, 0.001).solve(&sensitivity).unwrap();
+    let (_x, s): (Vector<f64>, Matrix<f64>) = sensitivity.split(y.last().unwrap());
+    assert_relative_eq!(-2.0 * (-1.5f64).exp(), s[[0, 0]], epsilon=0.0001);
+    assert_relative_eq!((-1.5f64).exp(), s[[0, 1]], epsilon=0.0001);
+}
+
+/// G(p) = x_1(t_f)^2 + \int_{t_0}^{t_f} x_2(t)^2 dt
+struct Cost;
+
+impl Objective<f64> for Cost
+{
+    fn terminal(&self, x: &Vector<f64>, _p: &Vector<f64>) -> f64
+    {
+        x[0] * x[0]
+    }
+
+    fn terminal_gradient_x(&self, x: &Vector<f64>, _p: &Vector<f64>) -> Vector<f64>
+    {
+        vector![2.0 * x[0]; 0.0]
+    }
+
+    fn running(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> f64
+    {
+        x[1] * x[1]
+    }
+
+    fn running_gradient_x(&self, _t: &f64, x: &Vector<f64>, _p: &Vector<f64>) -> Vector<f64>
+    {
+        vector![0.0; 2.0 * x[1]]
+    }
+}
+
+/// G(p) = x_2(t_f)
+struct Terminal;
+
+impl Objective<f64> for Terminal
+{
+    fn terminal(&self, x: &Vector<f64>, _p: &Vector<f64>) -> f64
+    {
+        x[1]
+    }
+
+    fn terminal_gradient_x(&self, _x: &Vector<f64>, _p: &Vector<f64>) -> Vector<f64>
+    {
+        vector![0.0; 1.0]
+    }
+}
+
+#[test]
+fn adjoint_terminal()
+{
+    let problem: LotkaVolterra = LotkaVolterra::default();
+    let p: Vector<f64> = vector![1.5; 1.0; 1.0; 3.0];
+    let adjoint: AdjointSensitivity<f64> = AdjointSensitivity::new(0.001);
+
+    let (g, dg): (f64, Vector<f64>) = adjoint.gradient(&problem, &Terminal, &p, &RungeKutta4::default()).unwrap();
+    let (x, s): (Vector<f64>, Matrix<f64>) = solve(&problem, &p);
+
+    assert_relative_eq!(x[1], g, epsilon=10e-10);
+    for j in 0..4
+    {
+        assert_relative_eq!(s[[1, j]], dg[j], epsilon=10e-8);
+    }
+}
+
+#[test]
+fn adjoint_running_cost()
+{
+    let problem: LotkaVolterra = LotkaVolterra::default();
+    let p: Vector<f64> = vector![1.5; 1.0; 1.0; 3.0];
+    let adjoint: AdjointSensitivity<f64> = AdjointSensitivity::new(0.001);
+    let method: RungeKutta4<f64> = RungeKutta4::default();
+
+    let (_g, dg): (f64, Vector<f64>) = adjoint.gradient(&problem, &Cost, &p, &method).unwrap();
+
+    let h: f64 = 10e-6;
+    for j in 0..4
+    {
+        let mut p_plus: Vector<f64> = p.clone();
+        p_plus[j] += h;
+        let mut p_minus: Vector<f64> = p.clone();
+        p_minus[j] -= h;
+
+        let (g_plus, _): (f64, Vector<f64>) = adjoint.gradient(&problem, &Cost, &p_plus, &method).unwrap();
+        let (g_minus, _): (f64, Vector<f64>) = adjoint.gradient(&problem, &Cost, &p_minus, &method).unwrap();
+
+        assert_relative_eq!((g_plus - g_minus) / (2.0 * h), dg[j], epsilon=10e-7);
+    }
+}
+
+#[test]
+fn adjoint_initial_condition()
+{
+    let problem: Decay = Decay::default();
+    let adjoint: AdjointSensitivity<f64> = AdjointSensitivity::new(0.01);
+
+    let (g, dg): (f64, Vector<f64>) =
+        adjoint.gradient(&problem, &Terminal1D, &vector![1.5; 2.0], &RungeKutta4::default()).unwrap();
+
+    assert_relative_eq!(2.0 * (-1.5f64).exp(), g, epsilon=10e-8);
+    assert_relative_eq!(-2.0 * (-1.5f64).exp(), dg[0], epsilon=10e-8);
+    assert_relative_eq!((-1.5f64).exp(), dg[1], epsilon=10e-8);
+}
+
+/// G(p) = x(t_f)
+struct Terminal1D;
+
+impl Objective<f64> for Terminal1D
+{
+    fn terminal(&self, x: &Vector<f64>, _p: &Vector<f64>) -> f64
+    {
+        x[0]
+    }
+
+    fn terminal_gradient_x(&self, _x: &Vector<f64>, _p: &Vector<f64>) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+}
+
+/// Residuals of the decay model at t = 0.1, 0.2, ..., 1.0
+struct DecayFit
+{
+    data: Vec<f64>,
+}
+
+impl DecayFit
+{
+    fn new(p: &Vector<f64>) -> DecayFit
+    {
+        DecayFit { data: (1..=10).map(|i| p[1] * (-p[0] * 0.1 * i as f64).exp()).collect() }
+    }
+
+    fn solve(&self, p: &Vector<f64>) -> Vec<(Vector<f64>, Matrix<f64>)>
+    {
+        let problem: Decay = Decay::default();
+        let sensitivity: ForwardSensitivity<f64, Decay> = ForwardSensitivity::new(&problem, p.clone());
+        let (_t, y): (Vec<f64>, Vec<Vector<f64>>) =
+            FixedStepper::new(0.01).solve(&sensitivity, &RungeKutta4::default()).unwrap();
+
+        (1..=10).map(|i| sensitivity.split(&y[10 * i])).collect()
+    }
+}
+
+impl Optim<f64> for DecayFit
+{
+    fn eval(&self, p: &Vector<f64>) -> Vector<f64>
+    {
+        let residuals: Vec<f64> = self.solve(p)
+                                      .iter()
+                                      .zip(self.data.iter())
+                                      .map(|((x, _s), d)| x[0] - d)
+                                      .collect();
+        Vector::new_column(residuals)
+    }
+
+    fn jacobian(&self, p: &Vector<f64>) -> Matrix<f64>
+    {
+        let mut jacobian: Matrix<f64> = Matrix::zero(10, 2);
+        for (i, (_x, s)) in self.solve(p).iter().enumerate()
+        {
+            jacobian[[i, 0]] = s[[0, 0]];
+            jacobian[[i, 1]] = s[[0, 1]];
+        }
+        jacobian
+    }
+}
+
+#[test]
+fn levenberg_marquardt_fit()
+{
+    let p_ref: Vector<f64> = vector![1.3; 2.0];
+    let fit: DecayFit = DecayFit::new(&p_ref);
+
+    let lm: LevenbergMarquardt<f64> = LevenbergMarquardt::new(50, 0.3, 0.95);
+    let p_opt: Vector<f64> = lm.minimize(&fit, &vector![0.5; 1.0]).unwrap().arg();
+
+    assert_relative_eq!(p_ref, p_opt, epsilon=10e-6);
+}
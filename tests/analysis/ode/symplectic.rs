@@ -0,0 +1,161 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::ordinary::{
+        SeparableHamiltonian,
+        solver::symplectic::{GaussLegendreCollocation, ImplicitMidpoint, StormerVerlet, SymplecticEuler, SymplecticMethod,
+                             SymplecticStepper, Yoshida4, Yoshida6},
+    },
+};
+
+use super::problem::{HarmonicOscillator, Kepler};
+
+fn final_error<M>(method: &M, step_size: f64) -> f64
+    where M: SymplecticMethod<f64>
+{
+    let problem: HarmonicOscillator = HarmonicOscillator::default();
+    let solver: SymplecticStepper<f64> = SymplecticStepper::new(step_size);
+
+    let (t, q, p): (Vec<f64>, Vec<Vector<f64>>, Vec<Vector<f64>>) = solver.solve(&problem, method).unwrap();
+
+    let t_n: f64 = *t.last().unwrap();
+    assert_relative_eq!(10.0, t_n, epsilon=0.000000001);
+
+    (q.last().unwrap()[0] - t_n.cos()).abs() + (p.last().unwrap()[0] + t_n.sin()).abs()
+}
+
+fn max_energy_error<M>(method: &M, step_size: f64) -> f64
+    where M: SymplecticMethod<f64>
+{
+    let problem: HarmonicOscillator = HarmonicOscillator::new((0.0, 1000.0));
+    let solver: SymplecticStepper<f64> = SymplecticStepper::new(step_size);
+
+    let (_t, q, p): (Vec<f64>, Vec<Vector<f64>>, Vec<Vector<f64>>) = solver.solve(&problem, method).unwrap();
+
+    q.iter().zip(p.iter()).map(|(q_i, p_i)| (problem.hamiltonian(q_i, p_i) - 0.5).abs()).fold(0.0, f64::max)
+}
+
+#[test]
+fn symplectic_euler()
+{
+    assert!(final_error(&SymplecticEuler::default(), 0.001) < 0.01);
+    assert!(max_energy_error(&SymplecticEuler::default(), 0.01) < 0.006);
+}
+
+#[test]
+fn stormer_verlet()
+{
+    assert!(final_error(&StormerVerlet::default(), 0.01) < 0.0001);
+    assert!(max_energy_error(&StormerVerlet::default(), 0.01) < 0.00002);
+}
+
+#[test]
+fn stormer_verlet_order()
+{
+    let ratio: f64 = final_error(&StormerVerlet::default(), 0.02) / final_error(&StormerVerlet::default(), 0.01);
+    assert_relative_eq!(4.0, ratio, epsilon=0.1);
+}
+
+#[test]
+fn yoshida4()
+{
+    assert!(final_error(&Yoshida4::default(), 0.01) < 0.000001);
+    assert!(max_energy_error(&Yoshida4::default(), 0.05) < 0.00001);
+
+    let ratio: f64 = final_error(&Yoshida4::default(), 0.1) / final_error(&Yoshida4::default(), 0.05);
+    assert_relative_eq!(16.0, ratio, epsilon=0.5);
+}
+
+#[test]
+fn yoshida6()
+{
+    assert!(final_error(&Yoshida6::default(), 0.05) < 0.0000001);
+
+    let ratio: f64 = final_error(&Yoshida6::default(), 0.2) / final_error(&Yoshida6::default(), 0.1);
+    assert_relative_eq!(64.0, ratio, epsilon=4.0);
+}
+
+#[test]
+fn implicit_midpoint()
+{
+    assert!(final_error(&ImplicitMidpoint::default(), 0.01) < 0.0002);
+    assert!(max_energy_error(&ImplicitMidpoint::default(), 0.05) < 0.0000001);
+}
+
+#[test]
+fn gauss_legendre_collocation()
+{
+    assert!(final_error(&GaussLegendreCollocation::new(2, 100, 10e-14), 0.1) < 0.00001);
+    assert!(final_error(&GaussLegendreCollocation::new(3, 100, 10e-14), 0.1) < 0.00000001);
+}
+
+/// Pendulum in single precision
+/// $H(q, p) = \frac{1}{2}p^2 - cos(q)$
+struct PendulumF32;
+
+impl SeparableHamiltonian<f32> for PendulumF32
+{
+    fn dh_dq(&self, q: &Vector<f32>) -> Vector<f32>
+    {
+        vector![q[0].sin()]
+    }
+
+    fn dh_dp(&self, p: &Vector<f32>) -> Vector<f32>
+    {
+        p.clone()
+    }
+
+    fn hamiltonian(&self, q: &Vector<f32>, p: &Vector<f32>) -> f32
+    {
+        0.5 * p.dotp(p) - q[0].cos()
+    }
+
+    fn time_span(&self) -> (f32, f32)
+    {
+        (0.0, 10.0)
+    }
+
+    fn init_cond(&self) -> (Vector<f32>, Vector<f32>)
+    {
+        (vector![2.0], vector![0.0])
+    }
+}
+
+#[test]
+fn implicit_f32()
+{
+    let solver: SymplecticStepper<f32> = SymplecticStepper::new(0.1);
+    let (q_0, p_0): (Vector<f32>, Vector<f32>) = PendulumF32.init_cond();
+    let h_0: f32 = PendulumF32.hamiltonian(&q_0, &p_0);
+
+    let (_t, q, p): (Vec<f32>, Vec<Vector<f32>>, Vec<Vector<f32>>) =
+        solver.solve(&PendulumF32, &ImplicitMidpoint::default()).unwrap();
+    assert_relative_eq!(h_0, PendulumF32.hamiltonian(q.last().unwrap(), p.last().unwrap()), epsilon=0.001);
+
+    let (_t, q, p): (Vec<f32>, Vec<Vector<f32>>, Vec<Vector<f32>>) =
+        solver.solve(&PendulumF32, &GaussLegendreCollocation::default()).unwrap();
+    assert_relative_eq!(h_0, PendulumF32.hamiltonian(q.last().unwrap(), p.last().unwrap()), epsilon=0.001);
+}
+
+#[test]
+fn gauss_legendre_collocation_not_converging()
+{
+    let problem: HarmonicOscillator = HarmonicOscillator::default();
+    let solver: SymplecticStepper<f64> = SymplecticStepper::new(5.0);
+
+    assert!(solver.solve(&problem, &GaussLegendreCollocation::new(2, 100, 10e-14)).is_err());
+}
+
+#[test]
+fn kepler_periodic_orbit()
+{
+    let problem: Kepler = Kepler::default();
+    let solver: SymplecticStepper<f64> = SymplecticStepper::new(0.001);
+    let (q_0, p_0): (Vector<f64>, Vector<f64>) = problem.init_cond();
+    let h_0: f64 = problem.hamiltonian(&q_0, &p_0);
+
+    let (_t, q, p): (Vec<f64>, Vec<Vector<f64>>, Vec<Vector<f64>>) = solver.solve(&problem, &Yoshida4::default()).unwrap();
+
+    assert_relative_eq!(q_0, *q.last().unwrap(), epsilon=0.00001);
+    assert_relative_eq!(p_0, *p.last().unwrap(), epsilon=0.00001);
+    assert_relative_eq!(h_0, problem.hamiltonian(q.last().unwrap(), p.last().unwrap()), epsilon=0.0000001);
+}
@@ -0,0 +1,119 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::partial::{BoundaryCondition, FiniteDifference, Grid},
+};
+use std::f64::consts::PI;
+
+fn non_uniform() -> Grid<f64>
+{
+    Grid::new(vec![0.0, 0.1, 0.25, 0.3, 0.5, 0.65, 0.8, 0.85, 1.0])
+}
+
+#[test]
+#[should_panic]
+fn grid_not_increasing()
+{
+    let _ = Grid::new(vec![0.0, 0.5, 0.4, 1.0]);
+}
+
+#[test]
+#[should_panic]
+fn periodic_one_side()
+{
+    let _ = FiniteDifference::new(Grid::uniform(0.0, 1.0, 5),
+                                  BoundaryCondition::Periodic,
+                                  BoundaryCondition::Dirichlet(0.0));
+}
+
+#[test]
+fn gradient_dirichlet_non_uniform()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(non_uniform(),
+                                                          BoundaryCondition::Dirichlet(1.0),
+                                                          BoundaryCondition::Dirichlet(2.0));
+    let u: Vector<f64> = fd.discretise(|x| x * x + 1.0);
+    let u_x: Vector<f64> = fd.gradient().apply(&u);
+
+    assert_eq!(7, fd.dim());
+    assert_relative_eq!(fd.discretise(|x| 2.0 * x), u_x, epsilon=10e-10);
+}
+
+#[test]
+fn laplacian_dirichlet_non_uniform()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(non_uniform(),
+                                                          BoundaryCondition::Dirichlet(1.0),
+                                                          BoundaryCondition::Dirichlet(2.0));
+    let u: Vector<f64> = fd.discretise(|x| x * x + 1.0);
+    let u_xx: Vector<f64> = fd.laplacian().apply(&u);
+
+    assert_relative_eq!(fd.discretise(|_x| 2.0), u_xx, epsilon=10e-9);
+}
+
+#[test]
+fn laplacian_neumann()
+{
+    // u(x) = x^2 - x, u'(0) = -1, u'(1) = 1
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 11),
+                                                          BoundaryCondition::Neumann(-1.0),
+                                                          BoundaryCondition::Neumann(1.0));
+    let u: Vector<f64> = fd.discretise(|x| x * x - x);
+
+    assert_eq!(11, fd.dim());
+    assert_relative_eq!(fd.discretise(|_x| 2.0), fd.laplacian().apply(&u), epsilon=10e-9);
+    assert_relative_eq!(fd.discretise(|x| 2.0 * x - 1.0), fd.gradient().apply(&u), epsilon=10e-10);
+}
+
+#[test]
+fn laplacian_periodic()
+{
+    let mut error: Vec<f64> = Vec::new();
+
+    for n in [21, 41]
+    {
+        let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, n),
+                                                              BoundaryCondition::Periodic,
+                                                              BoundaryCondition::Periodic);
+        assert_eq!(n - 1, fd.dim());
+
+        let u: Vector<f64> = fd.discretise(|x| (2.0 * PI * x).sin());
+        let u_xx: Vector<f64> = fd.laplacian().apply(&u);
+        let exact: Vector<f64> = fd.discretise(|x| -4.0 * PI * PI * (2.0 * PI * x).sin());
+
+        error.push((u_xx - exact).iter().fold(0.0, |e, e_i| e.max(e_i.abs())));
+    }
+
+    assert_relative_eq!(4.0, error[0] / error[1], epsilon=0.05);
+}
+
+#[test]
+fn advection_upwind()
+{
+    let grid: Grid<f64> = Grid::new(vec![0.0, 0.2, 0.3, 0.6, 0.7, 1.0]);
+
+    let fd: FiniteDifference<f64> = FiniteDifference::new(grid.clone(),
+                                                          BoundaryCondition::Dirichlet(1.0),
+                                                          BoundaryCondition::Neumann(3.0));
+    let u: Vector<f64> = fd.discretise(|x| 3.0 * x + 1.0);
+    assert_relative_eq!(fd.discretise(|_x| 6.0), fd.advection(2.0).apply(&u), epsilon=10e-10);
+
+    let fd: FiniteDifference<f64> = FiniteDifference::new(grid,
+                                                          BoundaryCondition::Neumann(3.0),
+                                                          BoundaryCondition::Dirichlet(4.0));
+    let u: Vector<f64> = fd.discretise(|x| 3.0 * x + 1.0);
+    assert_relative_eq!(fd.discretise(|_x| -6.0), fd.advection(-2.0).apply(&u), epsilon=10e-10);
+}
+
+#[test]
+fn expand()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 5),
+                                                          BoundaryCondition::Dirichlet(1.0),
+                                                          BoundaryCondition::Dirichlet(2.0));
+    assert_relative_eq!(vector![1.0; 3.0; 4.0; 5.0; 2.0], fd.expand(&vector![3.0; 4.0; 5.0]));
+
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 4),
+                                                          BoundaryCondition::Periodic,
+                                                          BoundaryCondition::Periodic);
+    assert_relative_eq!(vector![3.0; 4.0; 5.0; 3.0], fd.expand(&vector![3.0; 4.0; 5.0]));
+}
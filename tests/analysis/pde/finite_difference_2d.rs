@@ -0,0 +1,75 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::partial::{BoundaryCondition, FiniteDifference, FiniteDifference2D, Grid},
+};
+
+/// u(x, y) = (x^2 - 1) y (2 - y) + 2 on [0, 1] x [0, 2]
+fn solution(x: f64, y: f64) -> f64
+{
+    (x * x - 1.0) * y * (2.0 - y) + 2.0
+}
+
+fn discretisation() -> FiniteDifference2D<f64>
+{
+    let x: FiniteDifference<f64> = FiniteDifference::new(Grid::new(vec![0.0, 0.3, 0.4, 0.7, 1.0]),
+                                                         BoundaryCondition::Neumann(0.0),
+                                                         BoundaryCondition::Dirichlet(2.0));
+    let y: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 2.0, 6),
+                                                         BoundaryCondition::Dirichlet(2.0),
+                                                         BoundaryCondition::Dirichlet(2.0));
+    FiniteDifference2D::new(x, y)
+}
+
+#[test]
+fn points()
+{
+    let fd: FiniteDifference2D<f64> = discretisation();
+    let points: Vec<Vec<f64>> = fd.points();
+
+    assert_eq!(16, fd.dim());
+    assert_eq!(vec![0.0, 0.4], points[0]);
+    assert_eq!(vec![0.3, 0.8], points[5]);
+}
+
+#[test]
+fn gradient()
+{
+    let fd: FiniteDifference2D<f64> = discretisation();
+    let u: Vector<f64> = fd.discretise(solution);
+
+    assert_relative_eq!(fd.discretise(|x, y| 2.0 * x * y * (2.0 - y)),
+                        fd.gradient_x().apply(&u),
+                        epsilon=10e-10);
+    assert_relative_eq!(fd.discretise(|x, y| (x * x - 1.0) * (2.0 - 2.0 * y)),
+                        fd.gradient_y().apply(&u),
+                        epsilon=10e-10);
+}
+
+#[test]
+fn laplacian()
+{
+    let fd: FiniteDifference2D<f64> = discretisation();
+    let u: Vector<f64> = fd.discretise(solution);
+
+    assert_eq!(64, fd.laplacian().nnz());
+    assert_relative_eq!(fd.discretise(|x, y| 2.0 * y * (2.0 - y) - 2.0 * (x * x - 1.0)),
+                        fd.laplacian().apply(&u),
+                        epsilon=10e-9);
+}
+
+#[test]
+fn expand()
+{
+    let fd: FiniteDifference2D<f64> = discretisation();
+    let u: Matrix<f64> = fd.expand(&fd.discretise(solution));
+
+    let x: [f64; 5] = [0.0, 0.3, 0.4, 0.7, 1.0];
+    assert_eq!((5, 6), u.dim());
+    for (i, x_i) in x.iter().enumerate()
+    {
+        for j in 0..6
+        {
+            assert_relative_eq!(solution(*x_i, 0.4 * j as f64), u[[i, j]], epsilon=10e-10);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::{
+        ordinary::{
+            solver::runge_kutta::{FixedStepper, ImplicitEuler, RungeKutta4},
+            ExplicitODE, ImplicitODE,
+        },
+        partial::{BoundaryCondition, FiniteDifference, FiniteDifference2D, Grid, MethodOfLines},
+    },
+};
+use std::f64::consts::PI;
+
+use super::problem::{Decay, Logistic};
+
+fn heat() -> FiniteDifference<f64>
+{
+    FiniteDifference::new(Grid::uniform(0.0, 1.0, 41),
+                          BoundaryCondition::Dirichlet(0.0),
+                          BoundaryCondition::Dirichlet(0.0))
+}
+
+#[test]
+#[should_panic]
+fn dimension_mismatch()
+{
+    let fd: FiniteDifference<f64> = heat();
+    let _ = MethodOfLines::new(fd.laplacian(), (0.0, 0.1), vector![1.0; 2.0]);
+}
+
+#[test]
+fn heat_explicit()
+{
+    let fd: FiniteDifference<f64> = heat();
+    let problem = MethodOfLines::new(fd.laplacian(), (0.0, 0.1), fd.discretise(|x| (PI * x).sin()));
+
+    let (t, u): (Vec<f64>, Vec<Vector<f64>>) =
+        FixedStepper::new(0.0002).solve(&problem, &RungeKutta4::default()).unwrap();
+
+    let t_end: f64 = *t.last().unwrap();
+    assert_relative_eq!(0.1, t_end, epsilon=10e-10);
+    let exact: Vector<f64> = fd.discretise(|x| (PI * x).sin() * (-PI * PI * t_end).exp());
+    assert_relative_eq!(exact, *u.last().unwrap(), epsilon=0.001);
+}
+
+#[test]
+fn heat_implicit()
+{
+    let fd: FiniteDifference<f64> = heat();
+    let problem = MethodOfLines::new(fd.laplacian(), (0.0, 0.1), fd.discretise(|x| (PI * x).sin()));
+
+    let (t, u): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.001).solve(&problem).unwrap();
+
+    let t_end: f64 = *t.last().unwrap();
+    let exact: Vector<f64> = fd.discretise(|x| (PI * x).sin() * (-PI * PI * t_end).exp());
+    assert_relative_eq!(exact, *u.last().unwrap(), epsilon=0.005);
+}
+
+#[test]
+fn heat_neumann_conserves_mass()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 21),
+                                                          BoundaryCondition::Neumann(0.0),
+                                                          BoundaryCondition::Neumann(0.0));
+    let problem = MethodOfLines::new(fd.laplacian(), (0.0, 1.0), fd.discretise(|x| (PI * x).cos() + 1.0));
+
+    let (_t, u): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.01).solve(&problem).unwrap();
+
+    // The solution tends to the mean value
+    let u_end: &Vector<f64> = u.last().unwrap();
+    assert_relative_eq!(fd.discretise(|_x| 1.0), *u_end, epsilon=0.001);
+}
+
+#[test]
+fn heat_2d()
+{
+    let dirichlet = || {
+        FiniteDifference::new(Grid::uniform(0.0, 1.0, 11),
+                              BoundaryCondition::Dirichlet(0.0),
+                              BoundaryCondition::Dirichlet(0.0))
+    };
+    let fd: FiniteDifference2D<f64> = FiniteDifference2D::new(dirichlet(), dirichlet());
+    let init: Vector<f64> = fd.discretise(|x, y| (PI * x).sin() * (PI * y).sin());
+    let problem = MethodOfLines::new(fd.laplacian(), (0.0, 0.05), init);
+
+    let (t, u): (Vec<f64>, Vec<Vector<f64>>) = ImplicitEuler::new(0.0025).solve(&problem).unwrap();
+
+    let t_end: f64 = *t.last().unwrap();
+    let exact: Vector<f64> = fd.discretise(|x, y| (PI * x).sin() * (PI * y).sin() * (-2.0 * PI * PI * t_end).exp());
+    assert_relative_eq!(exact, *u.last().unwrap(), epsilon=0.02);
+}
+
+#[test]
+fn advection_periodic()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::uniform(0.0, 1.0, 201),
+                                                          BoundaryCondition::Periodic,
+                                                          BoundaryCondition::Periodic);
+    let init: Vector<f64> = fd.discretise(|x| (2.0 * PI * x).sin());
+    let problem = MethodOfLines::new(fd.advection(1.0) * -1.0, (0.0, 0.25), init);
+
+    let (_t, u): (Vec<f64>, Vec<Vector<f64>>) =
+        FixedStepper::new(0.002).solve(&problem, &RungeKutta4::default()).unwrap();
+
+    // first order upwind is diffusive
+    let exact: Vector<f64> = fd.discretise(|x| (2.0 * PI * (x - 0.25)).sin());
+    assert_relative_eq!(exact, *u.last().unwrap(), epsilon=0.05);
+}
+
+#[test]
+fn reaction_decay()
+{
+    let fd: FiniteDifference<f64> = heat();
+    let problem = MethodOfLines::new(fd.laplacian(), (0.0, 0.1), fd.discretise(|x| (PI * x).sin()))
+        .with_reaction(fd.points(), Decay { k: 2.0 });
+
+    let (t, u): (Vec<f64>, Vec<Vector<f64>>) =
+        FixedStepper::new(0.0002).solve(&problem, &RungeKutta4::default()).unwrap();
+
+    let t_end: f64 = *t.last().unwrap();
+    let exact: Vector<f64> = fd.discretise(|x| (PI * x).sin() * (-(PI * PI + 2.0) * t_end).exp());
+    assert_relative_eq!(exact, *u.last().unwrap(), epsilon=0.001);
+}
+
+#[test]
+fn sparse_jacobian()
+{
+    let fd: FiniteDifference<f64> = FiniteDifference::new(Grid::new(vec![0.0, 0.2, 0.5, 0.6, 1.0]),
+                                                          BoundaryCondition::Neumann(1.0),
+                                                          BoundaryCondition::Dirichlet(3.0));
+    let operator = fd.laplacian() + fd.gradient() * 0.5;
+    let problem = MethodOfLines::new(operator, (0.0, 1.0), fd.discretise(|x| x)).with_reaction(fd.points(), Logistic);
+
+    let u: Vector<f64> = vector![0.1; 0.4; 0.7; 0.2];
+    let sparse = problem.sparse_jacobian(&0.0, &u);
+    let dense: Matrix<f64> = problem.jacobian(&0.0, &u);
+
+    assert_eq!(4 + 3 + 3, sparse.nnz());
+    assert_relative_eq!(dense, sparse.matrix(), epsilon=10e-12);
+    assert_relative_eq!(Vector::zero(4), *sparse.offset(), epsilon=10e-12);
+
+    // finite difference approximation of the jacobian
+    let h: f64 = 10e-7;
+    for j in 0..4
+    {
+        let mut u_h: Vector<f64> = u.clone();
+        u_h[j] += h;
+        let column: Vector<f64> = (ExplicitODE::func(&problem, &0.0, &u_h) - ExplicitODE::func(&problem, &0.0, &u)) / h;
+        assert_relative_eq!(dense.get_column(j), column, epsilon=10e-5);
+    }
+    assert_relative_eq!(ImplicitODE::func(&problem, &0.0, &u), ExplicitODE::func(&problem, &0.0, &u));
+}
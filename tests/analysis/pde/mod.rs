@@ -0,0 +1,5 @@
+mod problem;
+
+mod finite_difference;
+mod finite_difference_2d;
+mod method_of_lines;
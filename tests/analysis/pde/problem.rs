@@ -0,0 +1,42 @@
+use mathru::analysis::differential_equation::partial::Reaction;
+
+/// Linear decay
+/// ```math
+/// R(t, x, u) = -k u
+/// ```
+pub struct Decay
+{
+    pub k: f64,
+}
+
+impl Reaction<f64> for Decay
+{
+    fn eval(&self, _t: &f64, _x: &[f64], u: &f64) -> f64
+    {
+        -self.k * u
+    }
+
+    fn derivative(&self, _t: &f64, _x: &[f64], _u: &f64) -> f64
+    {
+        -self.k
+    }
+}
+
+/// Logistic growth
+/// ```math
+/// R(t, x, u) = u (1 - u)
+/// ```
+pub struct Logistic;
+
+impl Reaction<f64> for Logistic
+{
+    fn eval(&self, _t: &f64, _x: &[f64], u: &f64) -> f64
+    {
+        u * (1.0 - u)
+    }
+
+    fn derivative(&self, _t: &f64, _x: &[f64], u: &f64) -> f64
+    {
+        1.0 - 2.0 * u
+    }
+}
@@ -0,0 +1,82 @@
+use mathru::analysis::root::{Bisection, RootResult};
+
+#[test]
+fn find_root()
+{
+    let result: RootResult<f64> = Bisection::default().find_root(|x: f64| x * x - 2.0, 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-15);
+    assert!(result.error() <= 4.0 * f64::EPSILON);
+    assert_eq!(result.evaluations(), result.iterations() + 2);
+}
+
+#[test]
+fn find_root_tolerance()
+{
+    let result: RootResult<f64> = Bisection::new(1.0e-3, 0.0, 100).find_root(|x: f64| x - 0.3, 0.0, 1.0).unwrap();
+
+    assert!(result.is_converged());
+    assert!(result.error() <= 1.0e-3);
+    assert!((result.root() - 0.3).abs() <= result.error());
+    assert_eq!(result.iterations(), 9);
+}
+
+#[test]
+fn find_root_reversed_bracket()
+{
+    let result: RootResult<f64> = Bisection::default().find_root(|x: f64| x * x - 2.0, 2.0, 0.0).unwrap();
+
+    assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_at_bound()
+{
+    let result: RootResult<f64> = Bisection::default().find_root(|x: f64| x - 1.0, 1.0, 2.0).unwrap();
+
+    assert_eq!(result.root(), 1.0);
+    assert_eq!(result.iterations(), 0);
+}
+
+#[test]
+fn find_root_discontinuous()
+{
+    // sign change without root
+    let result: RootResult<f64> = Bisection::default().find_root(|x: f64| 1.0 / (x - 0.5), 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.5, epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_iterations_exceeded()
+{
+    let result: RootResult<f64> = Bisection::new(1.0e-15, 0.0, 5).find_root(|x: f64| x - 0.3, 0.0, 1.0).unwrap();
+
+    assert!(!result.is_converged());
+    assert_eq!(result.iterations(), 5);
+    assert_relative_eq!(result.error(), 1.0 / 64.0);
+}
+
+#[test]
+fn find_root_no_bracket()
+{
+    assert!(Bisection::default().find_root(|x: f64| x * x + 1.0, -1.0, 1.0).is_err());
+}
+
+#[test]
+#[should_panic]
+fn new_negative_tolerance()
+{
+    let _ = Bisection::new(-1.0, 1.0e-10, 100);
+}
+
+#[test]
+fn find_root_f32()
+{
+    let result: RootResult<f32> = Bisection::default().find_root(|x: f32| x * x - 2.0, 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 2.0f32.sqrt(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,67 @@
+use mathru::analysis::root::{BracketExpansion, Brent, RootResult};
+
+#[test]
+fn expand_upper()
+{
+    let f = |x: f64| x.exp() - 1000.0;
+
+    let (a, b): (f64, f64) = BracketExpansion::default().expand(f, 0.0, 1.0).unwrap();
+
+    assert!(a <= 1000.0f64.ln() && 1000.0f64.ln() <= b);
+    assert!(f(a) * f(b) <= 0.0);
+}
+
+#[test]
+fn expand_lower()
+{
+    let f = |x: f64| x + 100.0;
+
+    let (a, b): (f64, f64) = BracketExpansion::default().expand(f, 1.0, 2.0).unwrap();
+
+    assert!(a <= -100.0 && b >= -100.0);
+}
+
+#[test]
+fn expand_bracket_unchanged()
+{
+    let (a, b): (f64, f64) = BracketExpansion::default().expand(|x: f64| x, 2.0, -1.0).unwrap();
+
+    assert_eq!((a, b), (-1.0, 2.0));
+}
+
+#[test]
+fn expand_bounds()
+{
+    // ln is only defined for positive arguments
+    let f = |x: f64| x.ln() + 10.0;
+    let mut expansion: BracketExpansion<f64> = BracketExpansion::default();
+    expansion.set_bounds(1.0e-300, f64::INFINITY);
+
+    let (a, b): (f64, f64) = expansion.expand(f, 1.0, 2.0).unwrap();
+    let result: RootResult<f64> = Brent::default().find_root(f, a, b).unwrap();
+
+    assert!(a > 0.0);
+    assert_relative_eq!(result.root(), (-10.0f64).exp(), epsilon = 1.0e-15);
+}
+
+#[test]
+fn expand_bounds_exceeded()
+{
+    let mut expansion: BracketExpansion<f64> = BracketExpansion::default();
+    expansion.set_bounds(0.0, 10.0);
+
+    assert!(expansion.expand(|x: f64| x + 1.0, 1.0, 2.0).is_err());
+}
+
+#[test]
+fn expand_no_root()
+{
+    assert!(BracketExpansion::new(1.6, 20).expand(|x: f64| x * x + 1.0, -1.0, 1.0).is_err());
+}
+
+#[test]
+#[should_panic]
+fn expand_empty()
+{
+    let _ = BracketExpansion::default().expand(|x: f64| x, 1.0, 1.0);
+}
@@ -0,0 +1,105 @@
+use mathru::{
+    analysis::root::{Bisection, Brent, RootResult},
+    special::{error, gamma},
+};
+
+#[test]
+fn find_root()
+{
+    let result: RootResult<f64> = Brent::default().find_root(|x: f64| x.cos() - x, 0.0, 1.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.7390851332151607, epsilon = 1.0e-15);
+    assert!(result.iterations() < 10);
+}
+
+#[test]
+fn find_root_faster_than_bisection()
+{
+    let f = |x: f64| x.exp() - 10.0;
+
+    let brent: RootResult<f64> = Brent::default().find_root(f, 0.0, 10.0).unwrap();
+    let bisection: RootResult<f64> = Bisection::default().find_root(f, 0.0, 10.0).unwrap();
+
+    assert_relative_eq!(brent.root(), 10.0f64.ln(), epsilon = 1.0e-14);
+    assert!(brent.evaluations() < bisection.evaluations() / 3);
+}
+
+#[test]
+fn find_root_multiple_root()
+{
+    // root of multiplicity 3, the interpolation steps converge slowly
+    let result: RootResult<f64> = Brent::default().find_root(|x: f64| (x - 1.0) * (x - 1.0) * (x - 1.0), 0.0, 3.0)
+                                                  .unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 1.0, epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_steep()
+{
+    let result: RootResult<f64> = Brent::default().find_root(|x: f64| (20.0 * (x - 0.2)).exp() - 1.0, -1.0, 10.0)
+                                                  .unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.2, epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_error_bound()
+{
+    let result: RootResult<f64> = Brent::new(1.0e-6, 0.0, 100).find_root(|x: f64| x * x * x - 2.0, 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert!(result.error() <= 1.0e-6);
+    assert!((result.root() - 2.0f64.cbrt()).abs() <= result.error());
+}
+
+#[test]
+fn find_root_no_bracket()
+{
+    assert!(Brent::default().find_root(|x: f64| x * x + 1.0, -1.0, 1.0).is_err());
+}
+
+#[test]
+fn gamma_quantile()
+{
+    let (shape, p): (f64, f64) = (2.5, 0.95);
+
+    let result: RootResult<f64> = Brent::default().find_root(|x: f64| gamma::gamma_lr(shape, x) - p, 1.0e-3, 50.0)
+                                                  .unwrap();
+
+    assert_relative_eq!(gamma::gamma_lr(shape, result.root()), p, epsilon = 1.0e-14);
+}
+
+/// Black-Scholes price of a European call
+fn call(s: f64, k: f64, r: f64, t: f64, sigma: f64) -> f64
+{
+    let phi = |x: f64| 0.5 * (1.0 + error::erf(x / 2.0f64.sqrt()));
+    let d_1: f64 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+    let d_2: f64 = d_1 - sigma * t.sqrt();
+    s * phi(d_1) - k * (-r * t).exp() * phi(d_2)
+}
+
+#[test]
+fn implied_volatility()
+{
+    let price: f64 = call(100.0, 110.0, 0.02, 0.5, 0.3);
+
+    let result: RootResult<f64> = Brent::default().find_root(|sigma: f64| call(100.0, 110.0, 0.02, 0.5, sigma) - price,
+                                                              0.01,
+                                                              2.0)
+                                                  .unwrap();
+
+    assert_relative_eq!(result.root(), 0.3, epsilon = 1.0e-10);
+}
+
+#[test]
+fn find_root_f32()
+{
+    let result: RootResult<f32> = Brent::default().find_root(|x: f32| x.cos() - x, 0.0, 1.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.739_085_1, epsilon = 1.0e-6);
+}
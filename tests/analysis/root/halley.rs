@@ -0,0 +1,45 @@
+use mathru::analysis::root::{Halley, Newton, RootResult};
+
+#[test]
+fn find_root()
+{
+    let f = |x: f64| x.exp() - 10.0;
+    let df = |x: f64| x.exp();
+
+    let halley: RootResult<f64> = Halley::default().find_root(f, df, df, 4.0).unwrap();
+    let newton: RootResult<f64> = Newton::default().find_root(f, df, 4.0).unwrap();
+
+    assert!(halley.is_converged());
+    assert_relative_eq!(halley.root(), 10.0f64.ln(), epsilon = 1.0e-15);
+    assert!(halley.iterations() < newton.iterations());
+}
+
+#[test]
+fn find_root_bracketed()
+{
+    let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+    let df = |x: f64| 3.0 * x * x - 2.0;
+    let d2f = |x: f64| 6.0 * x;
+
+    let result: RootResult<f64> = Halley::default().find_root_bracketed(f, df, d2f, 0.0, 0.0, 3.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 2.0945514815423265, epsilon = 1.0e-14);
+}
+
+#[test]
+fn find_root_bracketed_start_outside()
+{
+    let f = |x: f64| x * x * x - 2.0 * x - 5.0;
+    let df = |x: f64| 3.0 * x * x - 2.0;
+    let d2f = |x: f64| 6.0 * x;
+
+    assert!(Halley::default().find_root_bracketed(f, df, d2f, 5.0, 0.0, 3.0).is_err());
+}
+
+#[test]
+fn find_root_zero_derivative()
+{
+    assert!(Halley::default().find_root(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, |_x: f64| 2.0, 0.0)
+                             .is_err());
+}
@@ -0,0 +1,39 @@
+use mathru::analysis::root::{Bisection, Illinois, RootResult};
+
+#[test]
+fn find_root()
+{
+    let result: RootResult<f64> = Illinois::default().find_root(|x: f64| x * x - 2.0, 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_convex()
+{
+    // the plain regula falsi stagnates at the bound 0
+    let f = |x: f64| x.exp() - 10.0;
+
+    let illinois: RootResult<f64> = Illinois::default().find_root(f, 0.0, 10.0).unwrap();
+    let bisection: RootResult<f64> = Bisection::default().find_root(f, 0.0, 10.0).unwrap();
+
+    assert!(illinois.is_converged());
+    assert_relative_eq!(illinois.root(), 10.0f64.ln(), epsilon = 1.0e-14);
+    assert!(illinois.evaluations() < bisection.evaluations());
+}
+
+#[test]
+fn find_root_tolerance()
+{
+    let result: RootResult<f64> = Illinois::new(1.0e-8, 0.0, 100).find_root(|x: f64| x * x * x - 2.0, 0.0, 2.0).unwrap();
+
+    assert!(result.is_converged());
+    assert!((result.root() - 2.0f64.cbrt()).abs() <= 1.0e-8);
+}
+
+#[test]
+fn find_root_no_bracket()
+{
+    assert!(Illinois::default().find_root(|x: f64| x * x + 1.0, -1.0, 1.0).is_err());
+}
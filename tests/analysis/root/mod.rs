@@ -0,0 +1,7 @@
+mod bisection;
+mod bracket;
+mod brent;
+mod halley;
+mod illinois;
+mod newton;
+mod secant;
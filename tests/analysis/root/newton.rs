@@ -0,0 +1,71 @@
+use mathru::analysis::root::{Newton, RootResult};
+
+#[test]
+fn find_root()
+{
+    let result: RootResult<f64> = Newton::default().find_root(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, 1.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-15);
+    assert!(result.iterations() <= 6);
+}
+
+#[test]
+fn find_root_zero_derivative()
+{
+    assert!(Newton::default().find_root(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, 0.0).is_err());
+}
+
+#[test]
+fn find_root_divergent()
+{
+    // Newton's method cycles between -1 and 1 for arctan
+    let f = |x: f64| x.atan();
+    let df = |x: f64| 1.0 / (1.0 + x * x);
+
+    let result: RootResult<f64> = Newton::new(1.0e-14, 0.0, 20).find_root(f, df, 1.5).unwrap_or_else(|_| {
+                                                                       RootResult::new(f64::NAN, f64::NAN, 0, 0, false)
+                                                                   });
+    assert!(!result.is_converged());
+
+    let result: RootResult<f64> = Newton::default().find_root_bracketed(f, df, 1.5, -1.0, 2.0).unwrap();
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.0, epsilon = 1.0e-14);
+}
+
+#[test]
+fn find_root_bracketed_zero_derivative()
+{
+    let result: RootResult<f64> = Newton::default().find_root_bracketed(|x: f64| x * x - 2.0,
+                                                                        |x: f64| 2.0 * x,
+                                                                        0.0,
+                                                                        0.0,
+                                                                        3.0)
+                                                   .unwrap();
+
+    assert_relative_eq!(result.root(), 2.0f64.sqrt(), epsilon = 1.0e-15);
+}
+
+#[test]
+fn find_root_bracketed_no_bracket()
+{
+    assert!(Newton::default().find_root_bracketed(|x: f64| x.exp(), |x: f64| x.exp(), 0.0, -1.0, 1.0)
+                             .is_err());
+}
+
+#[test]
+fn find_root_bracketed_start_outside()
+{
+    assert!(Newton::default().find_root_bracketed(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, 4.0, 0.0, 3.0)
+                             .is_err());
+    assert!(Newton::default().find_root_bracketed(|x: f64| x * x - 2.0, |x: f64| 2.0 * x, -1.0, 3.0, 0.0)
+                             .is_err());
+}
+
+#[test]
+fn find_root_f32()
+{
+    let result: RootResult<f32> = Newton::default().find_root(|x: f32| x.exp() - 2.0, |x: f32| x.exp(), 0.0).unwrap();
+
+    assert_relative_eq!(result.root(), 2.0f32.ln(), epsilon = 1.0e-6);
+}
@@ -0,0 +1,36 @@
+use mathru::analysis::root::{RootResult, Secant};
+
+#[test]
+fn find_root()
+{
+    let result: RootResult<f64> = Secant::default().find_root(|x: f64| x.cos() - x, 0.0, 1.0).unwrap();
+
+    assert!(result.is_converged());
+    assert_relative_eq!(result.root(), 0.7390851332151607, epsilon = 1.0e-15);
+    assert!(result.iterations() < 10);
+}
+
+#[test]
+fn find_root_outside_start()
+{
+    // the starting values do not bracket the root
+    let result: RootResult<f64> = Secant::default().find_root(|x: f64| x.exp() - 10.0, 1.0, 1.5).unwrap();
+
+    assert_relative_eq!(result.root(), 10.0f64.ln(), epsilon = 1.0e-14);
+}
+
+#[test]
+fn find_root_horizontal()
+{
+    assert!(Secant::default().find_root(|x: f64| x * x - 1.0, -2.0, 2.0).is_err());
+}
+
+#[test]
+fn find_root_iterations_exceeded()
+{
+    let result: RootResult<f64> = Secant::new(0.0, 1.0e-15, 2).find_root(|x: f64| x.exp() - 10.0, 1.0, 1.5).unwrap();
+
+    assert!(!result.is_converged());
+    assert_eq!(result.iterations(), 2);
+    assert_eq!(result.evaluations(), 4);
+}
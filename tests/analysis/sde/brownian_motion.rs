@@ -0,0 +1,74 @@
+use mathru::{algebra::linear::Vector, analysis::differential_equation::sde::BrownianMotion};
+
+#[test]
+fn same_seed_same_path()
+{
+    let mut b_1: BrownianMotion<f64> = BrownianMotion::new(7);
+    let mut b_2: BrownianMotion<f64> = BrownianMotion::new(7);
+
+    let (_t_1, w_1): (Vec<f64>, Vec<Vector<f64>>) = b_1.path(2, (0.0, 1.0), 0.1);
+    let (_t_2, w_2): (Vec<f64>, Vec<Vector<f64>>) = b_2.path(2, (0.0, 1.0), 0.1);
+
+    assert_eq!(w_1, w_2);
+}
+
+#[test]
+fn different_seed_different_path()
+{
+    let mut b_1: BrownianMotion<f64> = BrownianMotion::new(7);
+    let mut b_2: BrownianMotion<f64> = BrownianMotion::new(8);
+
+    assert_ne!(b_1.increment(3, &0.1), b_2.increment(3, &0.1));
+}
+
+#[test]
+fn path()
+{
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(1);
+
+    let (t, w): (Vec<f64>, Vec<Vector<f64>>) = brownian.path(1, (0.0, 1.0), 0.25);
+
+    assert_eq!(5, t.len());
+    assert_relative_eq!(1.0, t[4], epsilon=0.000000001);
+    assert_eq!(0.0, w[0][0]);
+}
+
+#[test]
+fn increment_moments()
+{
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(3);
+    let h: f64 = 0.5;
+    let n: usize = 100000;
+
+    let mut mean: f64 = 0.0;
+    let mut variance: f64 = 0.0;
+    for _i in 0..n
+    {
+        let dw: f64 = brownian.increment(1, &h)[0];
+        mean += dw;
+        variance += dw * dw;
+    }
+
+    assert_relative_eq!(0.0, mean / n as f64, epsilon=0.01);
+    assert_relative_eq!(h, variance / n as f64, epsilon=0.01);
+}
+
+#[test]
+fn increment_with_integral_moments()
+{
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(4);
+    let h: f64 = 0.5;
+    let n: usize = 100000;
+
+    let mut dz_dz: f64 = 0.0;
+    let mut dw_dz: f64 = 0.0;
+    for _i in 0..n
+    {
+        let (dw, dz): (f64, f64) = brownian.increment_with_integral(&h);
+        dz_dz += dz * dz;
+        dw_dz += dw * dz;
+    }
+
+    assert_relative_eq!(h * h * h / 3.0, dz_dz / n as f64, epsilon=0.002);
+    assert_relative_eq!(h * h / 2.0, dw_dz / n as f64, epsilon=0.002);
+}
@@ -0,0 +1,72 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::sde::{
+        solver::{EulerMaruyama, SDEStepper},
+        BrownianMotion, Ensemble,
+    },
+    elementary::Exponential,
+};
+
+use super::problem::GeometricBrownianMotion;
+
+#[test]
+fn moments()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion { mu: 0.05,
+                                                                     sigma: 0.2,
+                                                                     ..GeometricBrownianMotion::default() };
+    let solver: SDEStepper<f64> = SDEStepper::new(0.05);
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(2);
+
+    let ensemble: Ensemble<f64> =
+        solver.solve_ensemble(&problem, &EulerMaruyama::default(), &mut brownian, 4000).unwrap();
+
+    assert_eq!(4000, ensemble.paths().len());
+    assert_eq!(21, ensemble.time().len());
+
+    let mean: Vec<Vector<f64>> = ensemble.mean();
+    let variance: Vec<Vector<f64>> = ensemble.variance();
+
+    assert_relative_eq!(1.0, mean[0][0], epsilon=0.000000001);
+    assert_relative_eq!(0.0, variance[0][0], epsilon=0.000000001);
+
+    // E(X_1) = exp(mu), Var(X_1) = exp(2 mu) (exp(sigma^2) - 1)
+    assert_relative_eq!(0.05f64.exp(), mean[20][0], epsilon=0.01);
+    assert_relative_eq!(0.1f64.exp() * (0.04f64.exp() - 1.0), variance[20][0], epsilon=0.005);
+}
+
+#[test]
+fn paths_are_independent()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion::default();
+    let solver: SDEStepper<f64> = SDEStepper::new(0.1);
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(2);
+
+    let ensemble: Ensemble<f64> = solver.solve_ensemble(&problem, &EulerMaruyama::default(), &mut brownian, 2).unwrap();
+
+    assert_ne!(ensemble.paths()[0][10], ensemble.paths()[1][10]);
+}
+
+#[test]
+#[should_panic]
+fn new_empty()
+{
+    let _: Ensemble<f64> = Ensemble::new(vec![0.0, 1.0], Vec::new());
+}
+
+#[test]
+#[should_panic]
+fn variance_single_path()
+{
+    let ensemble: Ensemble<f64> = Ensemble::new(vec![0.0, 1.0], vec![vec![vector![1.0], vector![2.0]]]);
+
+    ensemble.variance();
+}
+
+#[test]
+fn mean_single_path()
+{
+    let ensemble: Ensemble<f64> = Ensemble::new(vec![0.0, 1.0], vec![vec![vector![1.0], vector![2.0]]]);
+
+    assert_eq!(vec![vector![1.0], vector![2.0]], ensemble.mean());
+}
@@ -0,0 +1,57 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::sde::{
+        solver::{EulerMaruyama, SDEStepper},
+        BrownianMotion, Calculus,
+    },
+};
+
+use super::problem::{order, strong_error, GeometricBrownianMotion};
+
+fn increment(brownian: &mut BrownianMotion<f64>, h: f64) -> f64
+{
+    brownian.increment(1, &h)[0]
+}
+
+#[test]
+fn ito_strong_order()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion::default();
+    let method: EulerMaruyama<f64> = EulerMaruyama::default();
+
+    let error_1: f64 = strong_error(&problem, &method, 1.0 / 16.0, 200, increment);
+    let error_2: f64 = strong_error(&problem, &method, 1.0 / 64.0, 200, increment);
+
+    assert_relative_eq!(0.5, order(error_1, error_2), epsilon=0.25);
+}
+
+#[test]
+fn stratonovich_strong_order()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion { calculus: Calculus::Stratonovich,
+                                                                     ..GeometricBrownianMotion::default() };
+    let method: EulerMaruyama<f64> = EulerMaruyama::default();
+
+    let error_1: f64 = strong_error(&problem, &method, 1.0 / 16.0, 200, increment);
+    let error_2: f64 = strong_error(&problem, &method, 1.0 / 64.0, 200, increment);
+
+    assert!(error_2 < error_1);
+    assert!(error_2 < 0.5);
+}
+
+#[test]
+fn seeded_paths_are_reproducible()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion::default();
+    let solver: SDEStepper<f64> = SDEStepper::new(0.01);
+
+    let (t_1, x_1): (Vec<f64>, Vec<Vector<f64>>) =
+        solver.solve(&problem, &EulerMaruyama::default(), &mut BrownianMotion::new(5)).unwrap();
+    let (t_2, x_2): (Vec<f64>, Vec<Vector<f64>>) =
+        solver.solve(&problem, &EulerMaruyama::default(), &mut BrownianMotion::new(5)).unwrap();
+
+    assert_eq!(t_1, t_2);
+    assert_eq!(x_1, x_2);
+    assert_eq!(101, t_1.len());
+    assert_relative_eq!(1.0, t_1[100], epsilon=0.000000001);
+}
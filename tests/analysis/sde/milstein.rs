@@ -0,0 +1,65 @@
+use mathru::{
+    algebra::linear::Vector,
+    analysis::differential_equation::sde::{
+        solver::{Milstein, SDEStepper},
+        BrownianMotion, Calculus, ExplicitSDE,
+    },
+    elementary::Exponential,
+};
+
+use super::problem::{order, strong_error, DiagonalNoise, GeometricBrownianMotion};
+
+fn increment(brownian: &mut BrownianMotion<f64>, h: f64) -> f64
+{
+    brownian.increment(1, &h)[0]
+}
+
+#[test]
+fn ito_strong_order()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion::default();
+    let method: Milstein<f64> = Milstein::default();
+
+    let error_1: f64 = strong_error(&problem, &method, 1.0 / 16.0, 200, increment);
+    let error_2: f64 = strong_error(&problem, &method, 1.0 / 64.0, 200, increment);
+
+    assert_relative_eq!(1.0, order(error_1, error_2), epsilon=0.25);
+}
+
+#[test]
+fn stratonovich_strong_order()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion { calculus: Calculus::Stratonovich,
+                                                                     ..GeometricBrownianMotion::default() };
+    let method: Milstein<f64> = Milstein::default();
+
+    let error_1: f64 = strong_error(&problem, &method, 1.0 / 16.0, 200, increment);
+    let error_2: f64 = strong_error(&problem, &method, 1.0 / 64.0, 200, increment);
+
+    assert_relative_eq!(1.0, order(error_1, error_2), epsilon=0.25);
+}
+
+#[test]
+fn diagonal_noise()
+{
+    let problem: DiagonalNoise = DiagonalNoise;
+    let solver: SDEStepper<f64> = SDEStepper::new(1.0 / 1024.0);
+    let mut brownian: BrownianMotion<f64> = BrownianMotion::new(11);
+    let mut replica: BrownianMotion<f64> = brownian.clone();
+
+    let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(&problem, &Milstein::default(), &mut brownian).unwrap();
+
+    let mut w: Vector<f64> = Vector::zero(2);
+    for _i in 1..t.len()
+    {
+        w += replica.increment(2, &(1.0 / 1024.0));
+    }
+
+    let x_0: Vector<f64> = problem.init_cond();
+    let x_1: f64 = x_0[0] * ((0.5 - 0.32) + 0.8 * w[0]).exp();
+    let x_2: f64 = x_0[1] * ((-0.5 - 0.08) + 0.4 * w[1]).exp();
+
+    let len: usize = x.len();
+    assert_relative_eq!(x_1, x[len - 1][0], epsilon=0.01);
+    assert_relative_eq!(x_2, x[len - 1][1], epsilon=0.01);
+}
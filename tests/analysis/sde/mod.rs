@@ -0,0 +1,7 @@
+mod problem;
+
+mod brownian_motion;
+mod eulermaruyama;
+mod milstein;
+mod srk15;
+mod ensemble;
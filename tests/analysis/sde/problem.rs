@@ -0,0 +1,128 @@
+use mathru::{
+    algebra::linear::{Matrix, Vector},
+    analysis::differential_equation::sde::{solver::{SDEMethod, SDEStepper}, BrownianMotion, Calculus, ExplicitSDE},
+    elementary::Exponential,
+};
+
+/// Geometric Brownian motion dX = mu X dt + sigma X dW
+///
+/// The closed solution is X_t = X_0 exp((mu - sigma^2 / 2) t + sigma W_t) for
+/// the Itô and X_t = X_0 exp(mu t + sigma W_t) for the Stratonovich
+/// interpretation.
+pub struct GeometricBrownianMotion
+{
+    pub mu: f64,
+    pub sigma: f64,
+    pub calculus: Calculus,
+}
+
+impl Default for GeometricBrownianMotion
+{
+    fn default() -> GeometricBrownianMotion
+    {
+        GeometricBrownianMotion { mu: 1.5,
+                                  sigma: 1.0,
+                                  calculus: Calculus::Ito }
+    }
+}
+
+impl GeometricBrownianMotion
+{
+    pub fn solution(&self, t: f64, w: f64) -> f64
+    {
+        let x_0: f64 = self.init_cond()[0];
+        match self.calculus
+        {
+            Calculus::Ito => x_0 * ((self.mu - self.sigma * self.sigma / 2.0) * t + self.sigma * w).exp(),
+            Calculus::Stratonovich => x_0 * (self.mu * t + self.sigma * w).exp(),
+        }
+    }
+}
+
+impl ExplicitSDE<f64> for GeometricBrownianMotion
+{
+    fn drift(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        x * &self.mu
+    }
+
+    fn diffusion(&self, _t: &f64, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![self.sigma * x[0]]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![1.0]
+    }
+
+    fn calculus(&self) -> Calculus
+    {
+        self.calculus
+    }
+}
+
+/// Two independent geometric Brownian motions driven by a two-dimensional
+/// Wiener process
+pub struct DiagonalNoise;
+
+impl ExplicitSDE<f64> for DiagonalNoise
+{
+    fn drift(&self, _t: &f64, x: &Vector<f64>) -> Vector<f64>
+    {
+        vector![0.5 * x[0]; -0.5 * x[1]]
+    }
+
+    fn diffusion(&self, _t: &f64, x: &Vector<f64>) -> Matrix<f64>
+    {
+        matrix![0.8 * x[0], 0.0;
+                0.0, 0.4 * x[1]]
+    }
+
+    fn time_span(&self) -> (f64, f64)
+    {
+        (0.0, 1.0)
+    }
+
+    fn init_cond(&self) -> Vector<f64>
+    {
+        vector![1.0; 2.0]
+    }
+}
+
+/// Mean absolute error of the solution at the end of the time span. The
+/// Wiener process is reproduced with a generator of the same seed, `wiener`
+/// has to draw the increment of a step in the same way as the method does.
+pub fn strong_error<M, W>(problem: &GeometricBrownianMotion, method: &M, h: f64, paths: u64, wiener: W) -> f64
+    where M: SDEMethod<f64>,
+          W: Fn(&mut BrownianMotion<f64>, f64) -> f64
+{
+    let solver: SDEStepper<f64> = SDEStepper::new(h);
+    let t_end: f64 = problem.time_span().1;
+
+    let mut error: f64 = 0.0;
+    for seed in 0..paths
+    {
+        let mut brownian: BrownianMotion<f64> = BrownianMotion::new(seed);
+        let mut replica: BrownianMotion<f64> = brownian.clone();
+
+        let (t, x): (Vec<f64>, Vec<Vector<f64>>) = solver.solve(problem, method, &mut brownian).unwrap();
+        let w: f64 = (1..t.len()).map(|_| wiener(&mut replica, h)).sum();
+
+        error += (problem.solution(t_end, w) - x[x.len() - 1][0]).abs();
+    }
+
+    error / paths as f64
+}
+
+/// Estimated order of convergence from the strong errors with the step sizes
+/// h and h / 4
+pub fn order(error_h: f64, error_h_4: f64) -> f64
+{
+    (error_h / error_h_4).ln() / 4.0f64.ln()
+}
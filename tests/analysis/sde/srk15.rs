@@ -0,0 +1,41 @@
+use mathru::analysis::differential_equation::sde::{
+    solver::{SDEStepper, Srk15},
+    BrownianMotion, Calculus,
+};
+
+use super::problem::{order, strong_error, DiagonalNoise, GeometricBrownianMotion};
+
+fn increment(brownian: &mut BrownianMotion<f64>, h: f64) -> f64
+{
+    brownian.increment_with_integral(&h).0
+}
+
+#[test]
+fn ito_strong_order()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion::default();
+    let method: Srk15<f64> = Srk15::default();
+
+    let error_1: f64 = strong_error(&problem, &method, 1.0 / 16.0, 200, increment);
+    let error_2: f64 = strong_error(&problem, &method, 1.0 / 64.0, 200, increment);
+
+    assert_relative_eq!(1.5, order(error_1, error_2), epsilon=0.3);
+}
+
+#[test]
+fn stratonovich_not_supported()
+{
+    let problem: GeometricBrownianMotion = GeometricBrownianMotion { calculus: Calculus::Stratonovich,
+                                                                     ..GeometricBrownianMotion::default() };
+    let solver: SDEStepper<f64> = SDEStepper::new(0.1);
+
+    assert!(solver.solve(&problem, &Srk15::default(), &mut BrownianMotion::new(1)).is_err());
+}
+
+#[test]
+fn multi_dimensional_noise_not_supported()
+{
+    let solver: SDEStepper<f64> = SDEStepper::new(0.1);
+
+    assert!(solver.solve(&DiagonalNoise, &Srk15::default(), &mut BrownianMotion::new(1)).is_err());
+}
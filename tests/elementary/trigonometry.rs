@@ -13,7 +13,7 @@ fn sin_complex_f64()
     let uut: Complex<f64> = z.sin();
 
     let refer: Complex<f64> = Complex::new(real, imag);
-    assert_eq!(refer, uut);
+    assert_relative_eq!(refer, uut);
 }
 
 #[test]
@@ -28,7 +28,7 @@ fn sin_complex_f32()
     let uut: Complex<f32> = z.sin();
 
     let refer: Complex<f32> = Complex::new(real, imag);
-    assert_eq!(refer, uut);
+    assert_relative_eq!(refer, uut);
 }
 
 #[test]
@@ -61,7 +61,7 @@ fn cos()
 
     let refer: Complex<f64> = Complex::new(real, imag);
 
-    assert_eq!(refer, uut);
+    assert_relative_eq!(refer, uut);
 }
 
 #[test]